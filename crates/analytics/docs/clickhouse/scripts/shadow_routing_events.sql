@@ -0,0 +1,105 @@
+CREATE TABLE shadow_routing_events_queue (
+    `merchant_id` String,
+    `profile_id` String,
+    `payment_id` String,
+    `attempt_id` String,
+    `shadow_algorithm_id` String,
+    `active_connector` Nullable(String),
+    `active_merchant_connector_id` Nullable(String),
+    `active_rule_name` Nullable(String),
+    `shadow_connector` Nullable(String),
+    `shadow_merchant_connector_id` Nullable(String),
+    `shadow_rule_name` Nullable(String),
+    `is_connector_divergent` Bool,
+    `is_rule_divergent` Bool,
+    `created_at_timestamp` DateTime64(3)
+) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
+kafka_topic_list = 'hyperswitch-shadow-routing-events',
+kafka_group_name = 'hyper',
+kafka_format = 'JSONEachRow',
+kafka_handle_error_mode = 'stream';
+
+CREATE TABLE shadow_routing_events (
+    `merchant_id` LowCardinality(String),
+    `profile_id` LowCardinality(String),
+    `payment_id` String,
+    `attempt_id` String,
+    `shadow_algorithm_id` LowCardinality(String),
+    `active_connector` LowCardinality(Nullable(String)),
+    `active_merchant_connector_id` Nullable(String),
+    `active_rule_name` Nullable(String),
+    `shadow_connector` LowCardinality(Nullable(String)),
+    `shadow_merchant_connector_id` Nullable(String),
+    `shadow_rule_name` Nullable(String),
+    `is_connector_divergent` Bool,
+    `is_rule_divergent` Bool,
+    `created_at` DateTime64(3),
+    `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
+    INDEX shadowAlgorithmIndex shadow_algorithm_id TYPE bloom_filter GRANULARITY 1,
+    INDEX paymentIndex payment_id TYPE bloom_filter GRANULARITY 1
+) ENGINE = MergeTree PARTITION BY toStartOfDay(created_at)
+ORDER BY
+    (
+        created_at,
+        merchant_id,
+        profile_id,
+        shadow_algorithm_id
+    ) TTL inserted_at + toIntervalMonth(18) SETTINGS index_granularity = 8192;
+
+CREATE MATERIALIZED VIEW shadow_routing_events_mv TO shadow_routing_events (
+    `merchant_id` String,
+    `profile_id` String,
+    `payment_id` String,
+    `attempt_id` String,
+    `shadow_algorithm_id` String,
+    `active_connector` Nullable(String),
+    `active_merchant_connector_id` Nullable(String),
+    `active_rule_name` Nullable(String),
+    `shadow_connector` Nullable(String),
+    `shadow_merchant_connector_id` Nullable(String),
+    `shadow_rule_name` Nullable(String),
+    `is_connector_divergent` Bool,
+    `is_rule_divergent` Bool,
+    `created_at` DateTime64(3),
+    `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4)
+) AS
+SELECT
+    merchant_id,
+    profile_id,
+    payment_id,
+    attempt_id,
+    shadow_algorithm_id,
+    active_connector,
+    active_merchant_connector_id,
+    active_rule_name,
+    shadow_connector,
+    shadow_merchant_connector_id,
+    shadow_rule_name,
+    is_connector_divergent,
+    is_rule_divergent,
+    created_at_timestamp AS created_at,
+    now() AS inserted_at
+FROM
+    shadow_routing_events_queue
+WHERE
+    length(_error) = 0;
+
+CREATE MATERIALIZED VIEW shadow_routing_parse_errors (
+    `topic` String,
+    `partition` Int64,
+    `offset` Int64,
+    `raw` String,
+    `error` String
+) ENGINE = MergeTree
+ORDER BY
+    (topic, partition, offset) SETTINGS index_granularity = 8192 AS
+SELECT
+    _topic AS topic,
+    _partition AS partition,
+    _offset AS offset,
+    _raw_message AS raw,
+    _error AS error
+FROM
+    shadow_routing_events_queue
+WHERE
+    length(_error) > 0;
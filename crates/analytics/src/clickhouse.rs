@@ -30,6 +30,7 @@ use crate::{
     disputes::{filters::DisputeFilterRow, metrics::DisputeMetricRow},
     outgoing_webhook_event::events::OutgoingWebhookLogsResult,
     sdk_events::events::SdkEventsResult,
+    shadow_routing_event::events::ShadowRoutingLogsResult,
     types::TableEngine,
 };
 
@@ -142,6 +143,7 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::ConnectorEvents
             | AnalyticsCollection::ApiEventsAnalytics
             | AnalyticsCollection::OutgoingWebhookEvent
+            | AnalyticsCollection::ShadowRoutingEvent
             | AnalyticsCollection::ActivePaymentsAnalytics => TableEngine::BasicTree,
         }
     }
@@ -179,6 +181,7 @@ impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
     for ClickhouseClient
 {
 }
+impl super::shadow_routing_event::events::ShadowRoutingLogsFilterAnalytics for ClickhouseClient {}
 impl super::disputes::filters::DisputeFilterAnalytics for ClickhouseClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {}
 
@@ -403,6 +406,16 @@ impl TryInto<OutgoingWebhookLogsResult> for serde_json::Value {
     }
 }
 
+impl TryInto<ShadowRoutingLogsResult> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ShadowRoutingLogsResult, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ShadowRoutingLogsResult in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<ActivePaymentsMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -432,6 +445,7 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::PaymentIntent => Ok("payment_intents".to_string()),
             Self::ConnectorEvents => Ok("connector_events_audit".to_string()),
             Self::OutgoingWebhookEvent => Ok("outgoing_webhook_events_audit".to_string()),
+            Self::ShadowRoutingEvent => Ok("shadow_routing_events".to_string()),
             Self::Dispute => Ok("dispute".to_string()),
             Self::ActivePaymentsAnalytics => Ok("active_payments".to_string()),
         }
@@ -18,6 +18,7 @@ mod query;
 pub mod refunds;
 pub mod sdk_events;
 pub mod search;
+pub mod shadow_routing_event;
 mod sqlx;
 mod types;
 use api_event::metrics::{ApiEventMetric, ApiEventMetricRow};
@@ -992,6 +993,8 @@ pub enum AnalyticsFlow {
     GetApiEventFilters,
     GetConnectorEvents,
     GetOutgoingWebhookEvents,
+    GetShadowRoutingEvents,
+    GetConnectorCostEstimates,
     GetGlobalSearchResults,
     GetSearchResults,
     GetDisputeFilters,
@@ -0,0 +1,6 @@
+mod core;
+pub mod events;
+
+pub trait ShadowRoutingEventAnalytics: events::ShadowRoutingLogsFilterAnalytics {}
+
+pub use self::core::shadow_routing_events_core;
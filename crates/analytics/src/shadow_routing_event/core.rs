@@ -0,0 +1,27 @@
+use api_models::analytics::shadow_routing_event::ShadowRoutingLogsRequest;
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+
+use super::events::{get_shadow_routing_event, ShadowRoutingLogsResult};
+use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
+
+pub async fn shadow_routing_events_core(
+    pool: &AnalyticsProvider,
+    req: ShadowRoutingLogsRequest,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+) -> AnalyticsResult<Vec<ShadowRoutingLogsResult>> {
+    let data = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
+            "Shadow Routing Events Logs not implemented for SQLX",
+        ))
+        .attach_printable("SQL Analytics is not implemented for Shadow Routing Events"),
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            get_shadow_routing_event(merchant_id, profile_id, req, ckh_pool).await
+        }
+    }
+    .switch()?;
+    Ok(data)
+}
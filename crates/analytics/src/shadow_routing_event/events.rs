@@ -0,0 +1,79 @@
+use api_models::analytics::{shadow_routing_event::ShadowRoutingLogsRequest, Granularity};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, FiltersError, FiltersResult, LoadRow},
+};
+pub trait ShadowRoutingLogsFilterAnalytics: LoadRow<ShadowRoutingLogsResult> {}
+
+pub async fn get_shadow_routing_event<T>(
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+    query_param: ShadowRoutingLogsRequest,
+    pool: &T,
+) -> FiltersResult<Vec<ShadowRoutingLogsResult>>
+where
+    T: AnalyticsDataSource + ShadowRoutingLogsFilterAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> =
+        QueryBuilder::new(AnalyticsCollection::ShadowRoutingEvent);
+    query_builder.add_select_column("*").switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    if let Some(profile_id) = profile_id {
+        query_builder
+            .add_filter_clause("profile_id", profile_id.clone())
+            .switch()?;
+    }
+    if let Some(payment_id) = &query_param.payment_id {
+        query_builder
+            .add_filter_clause("payment_id", payment_id)
+            .switch()?;
+    }
+    if let Some(shadow_algorithm_id) = &query_param.shadow_algorithm_id {
+        query_builder
+            .add_filter_clause("shadow_algorithm_id", shadow_algorithm_id)
+            .switch()?;
+    }
+    query_param
+        .time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    //TODO!: update the execute_query function to return reports instead of plain errors...
+    query_builder
+        .execute_query::<ShadowRoutingLogsResult, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ShadowRoutingLogsResult {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub shadow_algorithm_id: String,
+    pub active_connector: Option<String>,
+    pub active_merchant_connector_id: Option<String>,
+    pub active_rule_name: Option<String>,
+    pub shadow_connector: Option<String>,
+    pub shadow_merchant_connector_id: Option<String>,
+    pub shadow_rule_name: Option<String>,
+    pub is_connector_divergent: bool,
+    pub is_rule_divergent: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
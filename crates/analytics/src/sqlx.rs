@@ -733,6 +733,8 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
                 .attach_printable("ActivePaymentsAnalytics table is not implemented for Sqlx"))?,
             Self::OutgoingWebhookEvent => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("OutgoingWebhookEvents table is not implemented for Sqlx"))?,
+            Self::ShadowRoutingEvent => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("ShadowRoutingEvents table is not implemented for Sqlx"))?,
             Self::Dispute => Ok("dispute".to_string()),
         }
     }
@@ -34,6 +34,7 @@ pub enum AnalyticsCollection {
     PaymentIntent,
     ConnectorEvents,
     OutgoingWebhookEvent,
+    ShadowRoutingEvent,
     Dispute,
     ApiEventsAnalytics,
     ActivePaymentsAnalytics,
@@ -1124,6 +1124,10 @@ impl MerchantConnectorResponse {
             merchant_connector_id: self.id.clone(),
         }
     }
+
+    pub fn get_id(&self) -> id_type::MerchantConnectorAccountId {
+        self.id.clone()
+    }
 }
 
 /// Response of creating a new Merchant Connector for the merchant account."
@@ -1247,6 +1251,10 @@ impl MerchantConnectorResponse {
             merchant_connector_id: self.merchant_connector_id.clone(),
         }
     }
+
+    pub fn get_id(&self) -> id_type::MerchantConnectorAccountId {
+        self.merchant_connector_id.clone()
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -1832,6 +1840,60 @@ pub struct ToggleAllKVResponse {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StorageSchemeMigrationRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Whether the merchant is to be migrated to KV or back to Postgres
+    #[schema(example = true)]
+    pub kv_enabled: bool,
+    /// Reports what the migration would do without switching the storage scheme or warming Redis
+    #[serde(default)]
+    #[schema(example = false)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StorageSchemeMigrationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Status of KV for the merchant once this step of the migration is done
+    #[schema(example = true)]
+    pub kv_enabled: bool,
+    /// Progress of the migration
+    pub status: StorageSchemeMigrationStatus,
+    /// Whether the storage scheme was left untouched since this was a dry run
+    #[schema(example = false)]
+    pub dry_run: bool,
+    /// Number of open payment intents warmed in Redis when migrating to KV, or which would be
+    /// warmed for a dry run
+    #[schema(example = 42)]
+    pub warmed_payment_intents: Option<usize>,
+    /// Number of the merchant's entries yet to be drained to Postgres from the drainer streams,
+    /// absent when migrating to Postgres and the drainer streams are too long to be scanned
+    #[schema(example = 0)]
+    pub pending_drainer_entries: Option<usize>,
+    /// Seconds after which the migration can be retried when it has been deferred
+    #[schema(example = 300)]
+    pub retry_after: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageSchemeMigrationStatus {
+    /// The migration can proceed, returned only for dry runs
+    Ready,
+    /// Cutover is not safe yet and the storage scheme has been left as is
+    Deferred,
+    /// The entries of the merchant are still being drained from Redis. A merchant on KV has its KV
+    /// writes stopped and moves to Postgres once they have been drained.
+    Draining,
+    /// The merchant is on the requested storage scheme with nothing left to drain
+    Completed,
+}
+
 /// Merchant connector details used to make payments.
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
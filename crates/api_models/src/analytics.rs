@@ -27,6 +27,7 @@ pub mod payments;
 pub mod refunds;
 pub mod sdk_events;
 pub mod search;
+pub mod shadow_routing_event;
 
 #[derive(Debug, serde::Serialize)]
 pub struct NameDescription {
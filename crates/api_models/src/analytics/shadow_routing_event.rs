@@ -0,0 +1,9 @@
+use super::TimeRange;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShadowRoutingLogsRequest {
+    pub time_range: TimeRange,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub shadow_algorithm_id: Option<common_utils::id_type::RoutingId>,
+}
@@ -1,9 +1,13 @@
+use std::net::IpAddr;
+
 use common_utils::custom_serde;
 use masking::StrongSecret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+use crate::user_role::Permission;
+
 /// The request body for creating an API Key.
 #[derive(Debug, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -23,6 +27,22 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions the API Key is restricted to. If not specified, the API Key can access all
+    /// resources of the merchant account.
+    #[schema(value_type = Option<Vec<Permission>>, example = json!(["RefundRead", "RefundWrite"]))]
+    pub permissions: Option<Vec<Permission>>,
+
+    /// The business profile the API Key is restricted to. If not specified, the API Key can access
+    /// resources of all business profiles of the merchant account. API Keys restricted to a business
+    /// profile are rejected by endpoints which are not scoped to a business profile.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The IP addresses the API Key can be used from. If not specified, the API Key can be used
+    /// from any IP address.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["192.0.2.10"]))]
+    pub ip_allowlist: Option<Vec<IpAddr>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +80,18 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions the API Key is restricted to, if any.
+    #[schema(value_type = Option<Vec<Permission>>, example = json!(["RefundRead", "RefundWrite"]))]
+    pub permissions: Option<Vec<Permission>>,
+
+    /// The business profile the API Key is restricted to, if any.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The IP addresses the API Key can be used from, if restricted.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["192.0.2.10"]))]
+    pub ip_allowlist: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +134,18 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions the API Key is restricted to, if any.
+    #[schema(value_type = Option<Vec<Permission>>, example = json!(["RefundRead", "RefundWrite"]))]
+    pub permissions: Option<Vec<Permission>>,
+
+    /// The business profile the API Key is restricted to, if any.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The IP addresses the API Key can be used from, if restricted.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["192.0.2.10"]))]
+    pub ip_allowlist: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -130,6 +174,16 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// The permissions the API Key is restricted to. Specify an empty list to remove the
+    /// restriction, allowing the API Key to access all resources of the merchant account.
+    #[schema(value_type = Option<Vec<Permission>>, example = json!(["RefundRead", "RefundWrite"]))]
+    pub permissions: Option<Vec<Permission>>,
+
+    /// The IP addresses the API Key can be used from. Specify an empty list to remove the
+    /// restriction, allowing the API Key to be used from any IP address.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["192.0.2.10"]))]
+    pub ip_allowlist: Option<Vec<IpAddr>>,
+
     #[serde(skip_deserializing)]
     pub key_id: String,
 
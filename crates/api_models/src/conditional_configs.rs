@@ -70,6 +70,14 @@ impl EuclidDirFilter for ConditionalConfigs {
         DirKeyKind::CaptureMethod,
         DirKeyKind::BillingCountry,
         DirKeyKind::BusinessCountry,
+        DirKeyKind::IssuerCountry,
+        DirKeyKind::CardIssuer,
+        DirKeyKind::IsNetworkToken,
+        DirKeyKind::CustomerId,
+        DirKeyKind::CustomerSegment,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::HourOfDay,
+        DirKeyKind::ConvertedAmount,
     ];
 }
 
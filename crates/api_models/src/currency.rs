@@ -7,6 +7,9 @@ pub struct CurrencyConversionParams {
     pub amount: MinorUnit,
     pub to_currency: String,
     pub from_currency: String,
+    /// The snapshot of rates to convert with, to reproduce an earlier conversion. The latest rates
+    /// are used if not specified.
+    pub snapshot_id: Option<String>,
 }
 
 /// Response to be send for convert currency route
@@ -15,7 +18,29 @@ pub struct CurrencyConversionParams {
 pub struct CurrencyConversionResponse {
     pub converted_amount: String,
     pub currency: String,
+    /// The snapshot of rates used for the conversion
+    pub snapshot_id: Option<String>,
+    /// The rate between the currencies, before applying the markup
+    pub market_rate: String,
+    /// The markup configured for the merchant, in basis points
+    pub markup_bps: u32,
+    /// The rate used for the conversion, after applying the markup
+    pub applied_rate: String,
+}
+
+/// Response to be send for retrieve forex rate snapshot route
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ForexRateSnapshotResponse {
+    pub snapshot_id: String,
+    /// The provider the rates were fetched from
+    pub provider: String,
+    pub base_currency: common_enums::Currency,
+    pub rates: serde_json::Value,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
 }
 
 impl ApiEventMetric for CurrencyConversionResponse {}
 impl ApiEventMetric for CurrencyConversionParams {}
+impl ApiEventMetric for ForexRateSnapshotResponse {}
@@ -203,6 +203,9 @@ impl Connector {
     pub fn requires_defend_dispute(&self) -> bool {
         matches!(self, Self::Checkout)
     }
+    pub fn supports_dispute_sync(&self) -> bool {
+        matches!(self, Self::Adyen | Self::Stripe)
+    }
     pub fn is_separate_authentication_supported(&self) -> bool {
         match self {
             #[cfg(feature = "dummy_connector")]
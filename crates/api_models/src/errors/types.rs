@@ -97,6 +97,7 @@ pub enum ApiErrorResponse {
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
     DomainError(ApiError),
+    TooManyRequests(ApiError),
 }
 
 impl ::core::fmt::Display for ApiErrorResponse {
@@ -126,6 +127,7 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
+            | Self::TooManyRequests(i)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -144,6 +146,7 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
+            | Self::TooManyRequests(i)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -162,6 +165,7 @@ impl ApiErrorResponse {
             | Self::BadRequest(_) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::DomainError(_) => "blocked",
+            Self::TooManyRequests(_) => "rate_limit",
             Self::ConnectorError(_, _) => "connector",
         }
     }
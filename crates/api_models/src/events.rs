@@ -11,8 +11,10 @@ pub mod payouts;
 pub mod recon;
 pub mod refund;
 pub mod routing;
+pub mod subscription;
 pub mod user;
 pub mod user_role;
+pub mod webhook_endpoints;
 
 use common_utils::{
     events::{ApiEventMetric, ApiEventsType},
@@ -25,7 +27,8 @@ use crate::{
     admin::*,
     analytics::{
         api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
-        outgoing_webhook_event::OutgoingWebhookLogsRequest, sdk_events::*, search::*, *,
+        outgoing_webhook_event::OutgoingWebhookLogsRequest, sdk_events::*, search::*,
+        shadow_routing_event::ShadowRoutingLogsRequest, *,
     },
     api_keys::*,
     cards_info::*,
@@ -85,6 +88,8 @@ impl_api_event_type!(
         ToggleKVRequest,
         ToggleAllKVRequest,
         ToggleAllKVResponse,
+        StorageSchemeMigrationRequest,
+        StorageSchemeMigrationResponse,
         MerchantAccountDeleteResponse,
         MerchantAccountUpdate,
         CardInfoResponse,
@@ -124,6 +129,7 @@ impl_api_event_type!(
         ReportRequest,
         ConnectorEventsRequest,
         OutgoingWebhookLogsRequest,
+        ShadowRoutingLogsRequest,
         GetGlobalSearchRequest,
         GetSearchRequest,
         GetSearchResponse,
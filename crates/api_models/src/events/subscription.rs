@@ -0,0 +1,79 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::subscription::{
+    PlanCreateRequest, PlanId, PlanListConstraints, PlanResponse, PlanUpdateRequest,
+    SubscriptionCancelRequest, SubscriptionCreateRequest, SubscriptionId,
+    SubscriptionListConstraints, SubscriptionResponse, SubscriptionUpdateRequest,
+};
+
+impl ApiEventMetric for PlanCreateRequest {}
+
+impl ApiEventMetric for PlanUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Plan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PlanId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Plan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PlanResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Plan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PlanListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for SubscriptionCreateRequest {}
+
+impl ApiEventMetric for SubscriptionUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionCancelRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
@@ -0,0 +1,32 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::webhook_endpoints::{
+    WebhookEndpointCreateRequest, WebhookEndpointId, WebhookEndpointResponse,
+    WebhookEndpointUpdateRequest,
+};
+
+impl ApiEventMetric for WebhookEndpointCreateRequest {}
+
+impl ApiEventMetric for WebhookEndpointUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookEndpoint {
+            endpoint_id: self.endpoint_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookEndpoint {
+            endpoint_id: self.endpoint_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookEndpoint {
+            endpoint_id: self.endpoint_id.clone(),
+        })
+    }
+}
@@ -31,10 +31,12 @@ pub mod poll;
 pub mod recon;
 pub mod refunds;
 pub mod routing;
+pub mod subscription;
 pub mod surcharge_decision_configs;
 pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 pub mod webhooks;
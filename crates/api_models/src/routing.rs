@@ -1,6 +1,10 @@
 use std::fmt::Debug;
 
 use common_utils::{errors::ParsingError, ext_traits::ValueExt, pii};
+use euclid::frontend::ast::{
+    parser::{EuclidParsable, ParseResult},
+    printer::EuclidPrintable,
+};
 pub use euclid::{
     dssa::types::EuclidAnalysable,
     frontend::{
@@ -11,7 +15,7 @@ pub use euclid::{
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::enums::{RoutableConnectors, TransactionType};
+use crate::enums::{RoutableConnectors, RoutingActivationType, TransactionType};
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
@@ -50,6 +54,299 @@ pub struct RoutingConfigRequest {
     pub profile_id: Option<common_utils::id_type::ProfileId>,
 }
 
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// An advanced routing algorithm, authored in the textual routing DSL
+pub struct RoutingDslConfigRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// The routing program, in the textual routing DSL
+    #[schema(
+        example = "default: [stripe]\n\ncard_rule: [adyen]\n{\n    payment_method = card\n}\n"
+    )]
+    pub program: String,
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// An advanced routing algorithm, printed in the textual routing DSL
+pub struct RoutingDslResponse {
+    #[schema(value_type = String)]
+    pub id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    pub description: String,
+    /// The routing program, in the textual routing DSL
+    pub program: String,
+    /// Metadata of the routing program which cannot be represented in the routing DSL, and is
+    /// hence not part of `program`
+    pub omitted_metadata: Vec<String>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// Replays the payments of a profile made in the given time range through a routing algorithm,
+/// without affecting the payments or the active routing configuration
+pub struct RoutingSimulationRequest {
+    /// The candidate algorithm to be simulated. Exactly one of `algorithm` and `algorithm_id`
+    /// must be provided.
+    pub algorithm: Option<RoutingAlgorithm>,
+    /// The id of an existing routing algorithm of the profile to be simulated
+    #[schema(value_type = Option<String>)]
+    pub algorithm_id: Option<common_utils::id_type::RoutingId>,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The time range in which the replayed payments were created
+    #[schema(value_type = TimeRange)]
+    pub time_range: common_utils::types::TimeRange,
+    /// The maximum number of payments to be replayed, the most recent ones first
+    #[schema(example = 1000)]
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    /// The number of payments replayed through the algorithm
+    pub total_payments: usize,
+    /// Payments which could not be replayed, such as those created without a currency
+    #[schema(value_type = Vec<String>)]
+    pub skipped_payments: Vec<common_utils::id_type::PaymentId>,
+    /// The number of payments which would have been routed to each connector
+    pub connector_volumes: Vec<SimulatedConnectorVolume>,
+    /// The number of payments which matched each rule of the algorithm
+    pub rule_hits: Vec<SimulatedRuleHit>,
+    /// Payments for which none of the connectors selected by the algorithm would have been
+    /// eligible
+    #[schema(value_type = Vec<String>)]
+    pub unroutable_payments: Vec<common_utils::id_type::PaymentId>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SimulatedConnectorVolume {
+    #[schema(value_type = Connector)]
+    pub connector: RoutableConnectors,
+    #[schema(value_type = Option<String>)]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub payment_count: usize,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SimulatedRuleHit {
+    /// The name of the rule, or `null` for the default selection of the algorithm
+    pub rule_name: Option<String>,
+    pub payment_count: usize,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// Explains which of the connectors of a profile are eligible for a payment, according to the
+/// payment methods enabled on the merchant connector accounts and the payment method filters
+pub struct EligibilityExplanationRequest {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The payment to be explained. Exactly one of `payment_id` and `backend_input` must be
+    /// provided.
+    #[schema(value_type = Option<String>)]
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    /// A synthetic set of routing inputs to be explained. If no payment method is set, the
+    /// payment methods enabled on the merchant connector accounts of the profile are explained.
+    #[schema(value_type = Option<Object>)]
+    pub backend_input: Option<euclid::backend::inputs::BackendInput>,
+    /// Restricts the explanation to the given connectors
+    #[schema(value_type = Option<Vec<Connector>>)]
+    pub connectors: Option<Vec<RoutableConnectors>>,
+    /// Whether the constraint graph of the profile should be included in DOT format
+    #[serde(default)]
+    pub include_graph: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EligibilityExplanationResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub explanations: Vec<ConnectorEligibilityExplanation>,
+    /// The constraint graph of the profile in DOT format, whose node identifiers are referred to
+    /// by the reasons
+    pub graph: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorEligibilityExplanation {
+    #[schema(value_type = Connector)]
+    pub connector: RoutableConnectors,
+    #[schema(value_type = Option<PaymentMethod>)]
+    pub payment_method: Option<crate::enums::PaymentMethod>,
+    #[schema(value_type = Option<PaymentMethodType>)]
+    pub payment_method_type: Option<crate::enums::PaymentMethodType>,
+    pub eligible: bool,
+    /// The constraints which made the connector ineligible, empty if the connector is eligible
+    pub reasons: Vec<IneligibilityReason>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct IneligibilityReason {
+    /// A human readable description of the unsatisfied constraint
+    pub message: String,
+    /// The identifier of the node of the constraint graph at which the constraint failed
+    pub node: Option<String>,
+    /// The description of the node in the constraint graph
+    pub node_description: Option<String>,
+    /// The edge through which the failure of the node made its successor fail
+    pub edge: Option<ConstraintGraphEdge>,
+    /// The merchant connector account the constraint belongs to
+    #[schema(value_type = Option<String>)]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub source: Option<EligibilityConstraintSource>,
+    pub constraint: Option<EligibilityConstraintKind>,
+    /// The values accepted by the constraint
+    pub expected: Vec<String>,
+    /// The value of the payment which didn't satisfy the constraint
+    pub found: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConstraintGraphEdge {
+    pub from: String,
+    pub to: String,
+    /// Whether the successor requires the predecessor to be satisfied (`Positive`) or to not be
+    /// satisfied (`Negative`)
+    pub relation: String,
+    /// One of `Weak`, `Normal` and `Strong`
+    pub strength: String,
+}
+
+/// Where a constraint of the eligibility graph is configured
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EligibilityConstraintSource {
+    /// The payment methods enabled on the merchant connector account
+    MerchantConnectorAccount,
+    /// The payment method filters of the connector in the application configuration
+    PmFilters,
+}
+
+/// The kind of filter a constraint of the eligibility graph applies
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EligibilityConstraintKind {
+    Connector,
+    PaymentMethod,
+    PaymentMethodType,
+    CardNetwork,
+    Currency,
+    Country,
+    Amount,
+    MinimumAmount,
+    MaximumAmount,
+    CaptureMethod,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingActivationHistoryQuery {
+    /// The profile whose history is retrieved, defaults to the profile of the authentication
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// The changes made to the active routing algorithm of a profile, the most recent first
+pub struct RoutingActivationHistoryResponse {
+    pub activations: Vec<RoutingActivationRecord>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingActivationRecord {
+    pub id: String,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The algorithm which became active, or `null` if the active algorithm was deactivated
+    #[schema(value_type = Option<String>)]
+    pub algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// The algorithm which was active before the change
+    #[schema(value_type = Option<String>)]
+    pub previous_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    pub algorithm_for: TransactionType,
+    pub activation_type: RoutingActivationType,
+    /// The id of the user or API key which made the change
+    pub activated_by: Option<String>,
+    pub created_at: i64,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingAlgorithmDiffQuery {
+    pub from_algorithm_id: common_utils::id_type::RoutingId,
+    pub to_algorithm_id: common_utils::id_type::RoutingId,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// The semantic differences between two routing algorithms. Algorithms which are not advanced are
+/// compared as programs without rules, whose default selection is the algorithm itself.
+pub struct RoutingAlgorithmDiff {
+    #[schema(value_type = String)]
+    pub from_algorithm_id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub to_algorithm_id: common_utils::id_type::RoutingId,
+    /// The names of the rules only present in the second algorithm
+    pub added_rules: Vec<String>,
+    /// The names of the rules only present in the first algorithm
+    pub removed_rules: Vec<String>,
+    /// The rules present in both algorithms which were modified
+    pub changed_rules: Vec<RoutingRuleDiff>,
+    /// The change in the default connector selection, if any
+    pub default_selection: Option<ConnectorSelectionDiff>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingRuleDiff {
+    pub rule_name: String,
+    /// Whether the conditions of the rule were modified
+    pub conditions_changed: bool,
+    /// Whether the rule is evaluated in a different order relative to the other rules present in
+    /// both algorithms
+    pub order_changed: bool,
+    /// The change in the connector selection of the rule, if any
+    pub connector_selection: Option<ConnectorSelectionDiff>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorSelectionDiff {
+    pub from: ConnectorSelection,
+    pub to: ConnectorSelection,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// Activates the routing algorithm which was active before the most recent change
+pub struct RoutingRollbackRequest {
+    /// The profile whose algorithm is rolled back, defaults to the profile of the authentication
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+}
+
 #[derive(Debug, serde::Serialize, ToSchema)]
 pub struct ProfileDefaultRoutingConfig {
     #[schema(value_type = String)]
@@ -131,6 +428,14 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CardRedirectType,
         DirKeyKind::BankTransferType,
         DirKeyKind::RealTimePaymentType,
+        DirKeyKind::IssuerCountry,
+        DirKeyKind::CardIssuer,
+        DirKeyKind::IsNetworkToken,
+        DirKeyKind::CustomerId,
+        DirKeyKind::CustomerSegment,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::HourOfDay,
+        DirKeyKind::ConvertedAmount,
     ];
 }
 
@@ -161,6 +466,57 @@ impl EuclidAnalysable for ConnectorSelection {
     }
 }
 
+impl EuclidParsable for ConnectorSelection {
+    fn parse_output(input: &str) -> ParseResult<&str, Self> {
+        ast::ConnectorSelection::parse_output(input)
+            .map(|(remaining, selection)| (remaining, selection.into()))
+    }
+}
+
+impl EuclidPrintable for ConnectorSelection {
+    fn print_output(&self) -> String {
+        ast::ConnectorSelection::from(self.clone()).print_output()
+    }
+}
+
+impl From<ast::ConnectorSelection> for ConnectorSelection {
+    fn from(value: ast::ConnectorSelection) -> Self {
+        match value {
+            ast::ConnectorSelection::Priority(choices) => {
+                Self::Priority(choices.into_iter().map(Into::into).collect())
+            }
+            ast::ConnectorSelection::VolumeSplit(splits) => Self::VolumeSplit(
+                splits
+                    .into_iter()
+                    .map(|split| ConnectorVolumeSplit {
+                        connector: split.connector.into(),
+                        split: split.split,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<ConnectorSelection> for ast::ConnectorSelection {
+    fn from(value: ConnectorSelection) -> Self {
+        match value {
+            ConnectorSelection::Priority(choices) => {
+                Self::Priority(choices.into_iter().map(Into::into).collect())
+            }
+            ConnectorSelection::VolumeSplit(splits) => Self::VolumeSplit(
+                splits
+                    .into_iter()
+                    .map(|split| ast::ConnectorVolumeSplit {
+                        connector: split.connector.into(),
+                        split: split.split,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ConnectorVolumeSplit {
     pub connector: RoutableConnectorChoice,
@@ -212,6 +568,32 @@ impl From<RoutableConnectorChoice> for ast::ConnectorChoice {
     }
 }
 
+impl From<ast::RoutableConnectorChoice> for RoutableConnectorChoice {
+    fn from(value: ast::RoutableConnectorChoice) -> Self {
+        Self {
+            choice_kind: match value.choice_kind {
+                ast::RoutableChoiceKind::OnlyConnector => RoutableChoiceKind::OnlyConnector,
+                ast::RoutableChoiceKind::FullStruct => RoutableChoiceKind::FullStruct,
+            },
+            connector: value.connector,
+            merchant_connector_id: value.merchant_connector_id,
+        }
+    }
+}
+
+impl From<RoutableConnectorChoice> for ast::RoutableConnectorChoice {
+    fn from(value: RoutableConnectorChoice) -> Self {
+        Self {
+            choice_kind: match value.choice_kind {
+                RoutableChoiceKind::OnlyConnector => ast::RoutableChoiceKind::OnlyConnector,
+                RoutableChoiceKind::FullStruct => ast::RoutableChoiceKind::FullStruct,
+            },
+            connector: value.connector,
+            merchant_connector_id: value.merchant_connector_id,
+        }
+    }
+}
+
 impl PartialEq for RoutableConnectorChoice {
     fn eq(&self, other: &Self) -> bool {
         self.connector.eq(&other.connector)
@@ -439,12 +821,44 @@ impl RoutingAlgorithm {
     }
 }
 
+/// The alternative routes of a profile which are selected by routing algorithms, when the
+/// transaction cannot be processed through its usual route
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FallbackRoutingKind {
+    /// Selects the payout connector through which a refund is processed as a payout, when the
+    /// connector of the payment can no longer process the refund
+    Refund,
+    /// Selects the connectors through which a failed payout is retried
+    #[cfg(feature = "payouts")]
+    PayoutRetry,
+}
+
+impl FallbackRoutingKind {
+    /// The transactions whose routing algorithms can be linked for this kind of fallback
+    pub fn get_transaction_type(&self) -> TransactionType {
+        match self {
+            Self::Refund => TransactionType::Payment,
+            #[cfg(feature = "payouts")]
+            Self::PayoutRetry => TransactionType::Payout,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RoutingAlgorithmRef {
     pub algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub timestamp: i64,
     pub config_algo_id: Option<String>,
     pub surcharge_config_algo_id: Option<String>,
+    /// An algorithm evaluated alongside the active one, whose decisions do not affect payments
+    pub shadow_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// An algorithm selecting the payout connector through which refunds are processed, when the
+    /// connector of the payment can no longer process them
+    pub refund_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// An algorithm selecting the connectors through which failed payouts are retried
+    pub retry_algorithm_id: Option<common_utils::id_type::RoutingId>,
 }
 
 impl RoutingAlgorithmRef {
@@ -453,6 +867,35 @@ impl RoutingAlgorithmRef {
         self.timestamp = common_utils::date_time::now_unix_timestamp();
     }
 
+    pub fn update_shadow_algorithm_id(&mut self, new_id: Option<common_utils::id_type::RoutingId>) {
+        self.shadow_algorithm_id = new_id;
+        self.timestamp = common_utils::date_time::now_unix_timestamp();
+    }
+
+    pub fn get_fallback_algorithm_id(
+        &self,
+        kind: FallbackRoutingKind,
+    ) -> Option<&common_utils::id_type::RoutingId> {
+        match kind {
+            FallbackRoutingKind::Refund => self.refund_algorithm_id.as_ref(),
+            #[cfg(feature = "payouts")]
+            FallbackRoutingKind::PayoutRetry => self.retry_algorithm_id.as_ref(),
+        }
+    }
+
+    pub fn update_fallback_algorithm_id(
+        &mut self,
+        kind: FallbackRoutingKind,
+        new_id: Option<common_utils::id_type::RoutingId>,
+    ) {
+        match kind {
+            FallbackRoutingKind::Refund => self.refund_algorithm_id = new_id,
+            #[cfg(feature = "payouts")]
+            FallbackRoutingKind::PayoutRetry => self.retry_algorithm_id = new_id,
+        }
+        self.timestamp = common_utils::date_time::now_unix_timestamp();
+    }
+
     pub fn update_conditional_config_id(&mut self, ids: String) {
         self.config_algo_id = Some(ids);
         self.timestamp = common_utils::date_time::now_unix_timestamp();
@@ -524,6 +967,10 @@ pub struct DynamicAlgorithmWithTimestamp<T> {
 pub struct DynamicRoutingAlgorithmRef {
     pub success_based_algorithm:
         Option<DynamicAlgorithmWithTimestamp<common_utils::id_type::RoutingId>>,
+    pub least_cost_algorithm:
+        Option<DynamicAlgorithmWithTimestamp<common_utils::id_type::RoutingId>>,
+    pub elimination_algorithm:
+        Option<DynamicAlgorithmWithTimestamp<common_utils::id_type::RoutingId>>,
 }
 
 impl DynamicRoutingAlgorithmRef {
@@ -533,6 +980,26 @@ impl DynamicRoutingAlgorithmRef {
             timestamp: common_utils::date_time::now_unix_timestamp(),
         })
     }
+
+    pub fn update_least_cost_algorithm_id(
+        &mut self,
+        new_id: Option<common_utils::id_type::RoutingId>,
+    ) {
+        self.least_cost_algorithm = Some(DynamicAlgorithmWithTimestamp {
+            algorithm_id: new_id,
+            timestamp: common_utils::date_time::now_unix_timestamp(),
+        })
+    }
+
+    pub fn update_elimination_algorithm_id(
+        &mut self,
+        new_id: Option<common_utils::id_type::RoutingId>,
+    ) {
+        self.elimination_algorithm = Some(DynamicAlgorithmWithTimestamp {
+            algorithm_id: new_id,
+            timestamp: common_utils::date_time::now_unix_timestamp(),
+        })
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -648,3 +1115,425 @@ impl CurrentBlockThreshold {
         }
     }
 }
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct LeastCostRoutingConfig {
+    /// The fee assumed for connectors without a fee schedule rule applicable to the payment. When
+    /// not set, such connectors are ranked after all connectors whose cost is known.
+    pub default_fee: Option<ConnectorFee>,
+}
+
+impl LeastCostRoutingConfig {
+    pub fn update(&mut self, new: Self) {
+        if let Some(default_fee) = new.default_fee {
+            self.default_fee = Some(default_fee)
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ToggleLeastCostRoutingQuery {
+    pub status: bool,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ToggleLeastCostRoutingPath {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ToggleLeastCostRoutingWrapper {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub status: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct LeastCostRoutingUpdateConfigQuery {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LeastCostRoutingPayloadWrapper {
+    pub updated_config: LeastCostRoutingConfig,
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub profile_id: common_utils::id_type::ProfileId,
+}
+
+/// Configs for excluding connectors from routing for a payment method while their payments fail
+/// due to the connector, such as on timeouts or errors for which the GSM rules of the connector
+/// suggest retrying through another connector
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EliminationRoutingConfig {
+    /// The number of failures within a failure window after which the connector is eliminated
+    pub failure_threshold: Option<u32>,
+    /// The duration of the windows in which failures are counted, in seconds
+    pub failure_window_in_secs: Option<u64>,
+    /// The duration for which an eliminated connector is excluded from routing, in seconds. After
+    /// this period, a single payment at a time is routed through the connector as a probe, until a
+    /// probe succeeds and the connector is restored, or fails and the connector is eliminated
+    /// again.
+    pub cooldown_in_secs: Option<u64>,
+    /// The duration after which a probe without an outcome no longer prevents another probe, in
+    /// seconds
+    pub probe_timeout_in_secs: Option<u64>,
+}
+
+impl Default for EliminationRoutingConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: Some(5),
+            failure_window_in_secs: Some(60),
+            cooldown_in_secs: Some(300),
+            probe_timeout_in_secs: Some(60),
+        }
+    }
+}
+
+impl EliminationRoutingConfig {
+    pub fn update(&mut self, new: Self) {
+        if let Some(failure_threshold) = new.failure_threshold {
+            self.failure_threshold = Some(failure_threshold)
+        }
+        if let Some(failure_window_in_secs) = new.failure_window_in_secs {
+            self.failure_window_in_secs = Some(failure_window_in_secs)
+        }
+        if let Some(cooldown_in_secs) = new.cooldown_in_secs {
+            self.cooldown_in_secs = Some(cooldown_in_secs)
+        }
+        if let Some(probe_timeout_in_secs) = new.probe_timeout_in_secs {
+            self.probe_timeout_in_secs = Some(probe_timeout_in_secs)
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ToggleEliminationRoutingQuery {
+    pub status: bool,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct EliminationRoutingPath {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ToggleEliminationRoutingWrapper {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub status: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EliminationRoutingUpdateConfigQuery {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EliminationRoutingPayloadWrapper {
+    pub updated_config: EliminationRoutingConfig,
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub profile_id: common_utils::id_type::ProfileId,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EliminationStatus {
+    /// The connector is routed through as usual
+    Active,
+    /// The connector is excluded from routing until the end of its cooldown period
+    Eliminated,
+    /// The cooldown period of the connector has ended, and payments are routed through it one at
+    /// a time as probes
+    HalfOpen,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EliminationReason {
+    /// The failures of the connector crossed the failure threshold, or a probe failed
+    FailureThreshold,
+    /// The connector was eliminated through the override endpoint
+    Manual,
+}
+
+/// The elimination state of a connector for a payment method
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EliminationBucket {
+    #[schema(value_type = Connector)]
+    pub connector: RoutableConnectors,
+    #[schema(value_type = String)]
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    #[schema(value_type = PaymentMethod)]
+    pub payment_method: crate::enums::PaymentMethod,
+    pub status: EliminationStatus,
+    /// The reason of the latest elimination of the connector, if it has not been restored since
+    pub reason: Option<EliminationReason>,
+    /// The time at which the connector was last eliminated, as a UNIX timestamp
+    pub eliminated_at: Option<i64>,
+    /// The end of the cooldown period of the connector, as a UNIX timestamp
+    pub eliminated_until: Option<i64>,
+    /// The time at which the state was last updated, as a UNIX timestamp
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EliminationStatusResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The connectors which have been eliminated for a payment method, and have not expired since
+    pub buckets: Vec<EliminationBucket>,
+}
+
+/// Eliminates or restores a connector for a payment method, irrespective of its failures
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EliminationOverrideRequest {
+    #[schema(value_type = String)]
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    #[schema(value_type = PaymentMethod)]
+    pub payment_method: crate::enums::PaymentMethod,
+    pub action: EliminationOverrideAction,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EliminationOverrideAction {
+    /// Excludes the connector from routing for the given duration, after which it is probed
+    Eliminate {
+        /// The duration of the elimination, in seconds
+        duration_in_secs: u64,
+    },
+    /// Routes through the connector as usual, discarding its failures counted so far
+    Restore,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EliminationOverrideWrapper {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub request: EliminationOverrideRequest,
+}
+
+/// The fees charged by a connector for processing payments through a merchant connector account
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFeeSchedule {
+    /// The fee rules of the connector. A payment is charged the fee of the most specific rule it
+    /// matches, the rule listed first being chosen among equally specific rules.
+    pub rules: Vec<ConnectorFeeRule>,
+}
+
+/// A fee which applies to the payments matching all the criteria specified in the rule. Criteria
+/// which are not specified match any payment.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFeeRule {
+    #[schema(value_type = Option<PaymentMethodType>)]
+    pub payment_method_type: Option<crate::enums::PaymentMethodType>,
+    #[schema(value_type = Option<CardNetwork>)]
+    pub card_network: Option<crate::enums::CardNetwork>,
+    pub card_type: Option<CardFundingType>,
+    pub region: Option<FeeRegion>,
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<crate::enums::Currency>,
+    pub fee: ConnectorFee,
+}
+
+impl ConnectorFeeRule {
+    /// The number of criteria specified in the rule
+    pub fn specificity(&self) -> usize {
+        [
+            self.payment_method_type.is_some(),
+            self.card_network.is_some(),
+            self.card_type.is_some(),
+            self.region.is_some(),
+            self.currency.is_some(),
+        ]
+        .into_iter()
+        .filter(|is_specified| *is_specified)
+        .count()
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConnectorFee {
+    /// A fee made up of a percentage of the amount of the payment and a fixed amount
+    Blended {
+        /// The percentage of the amount of the payment, such as `2.9` for 2.9%
+        percentage: f64,
+        /// The fixed amount, in the minor unit of the currency of the payment
+        #[schema(value_type = i64)]
+        fixed_amount: common_utils::types::MinorUnit,
+    },
+    /// The interchange fee of the card network, which is passed through by the connector, along
+    /// with the markup charged by the connector
+    InterchangePlus {
+        /// The interchange fee expected for the payment, as a percentage of its amount
+        interchange_percentage: f64,
+        /// The markup charged by the connector, as a percentage of the amount of the payment
+        markup_percentage: f64,
+        /// The fixed amount, in the minor unit of the currency of the payment
+        #[schema(value_type = i64)]
+        fixed_amount: common_utils::types::MinorUnit,
+    },
+}
+
+impl ConnectorFee {
+    /// The fee expected for a payment of the specified amount, in the minor unit of the currency
+    /// of the payment
+    pub fn calculate(&self, amount: common_utils::types::MinorUnit) -> f64 {
+        let (percentage, fixed_amount) = match self {
+            Self::Blended {
+                percentage,
+                fixed_amount,
+            } => (*percentage, fixed_amount),
+            Self::InterchangePlus {
+                interchange_percentage,
+                markup_percentage,
+                fixed_amount,
+            } => (interchange_percentage + markup_percentage, fixed_amount),
+        };
+
+        #[allow(clippy::as_conversions)]
+        let (amount, fixed_amount) = (
+            amount.get_amount_as_i64() as f64,
+            fixed_amount.get_amount_as_i64() as f64,
+        );
+        amount * percentage / 100.0 + fixed_amount
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardFundingType {
+    Credit,
+    Debit,
+}
+
+/// Whether the payment is made from the country the business of the payment operates in, as
+/// determined by the billing country of the payment
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FeeRegion {
+    Domestic,
+    International,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorFeeScheduleResponse {
+    #[schema(value_type = String)]
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub rules: Vec<ConnectorFeeRule>,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorFeeScheduleId {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+}
+
+/// Estimates the fees of the payments of a profile made in the given time range, based on the
+/// current fee schedules of the connectors the payments were processed through
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorCostEstimateRequest {
+    #[schema(value_type = TimeRange)]
+    pub time_range: common_utils::types::TimeRange,
+    /// The maximum number of payments to be considered, the most recent ones first
+    #[schema(example = 1000)]
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorCostEstimateResponse {
+    /// The number of successful payments considered
+    pub total_payments: usize,
+    /// The number of payments for which no fee schedule rule of the connector applies
+    pub unpriced_payments: usize,
+    /// The estimated cost of the payments processed through each connector, in each currency
+    pub connectors: Vec<ConnectorCostEstimate>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorCostEstimate {
+    #[schema(value_type = Connector)]
+    pub connector: RoutableConnectors,
+    #[schema(value_type = String)]
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    #[schema(value_type = Currency)]
+    pub currency: crate::enums::Currency,
+    /// The number of payments processed for which the cost could be estimated
+    pub payment_count: usize,
+    /// The total amount of these payments
+    #[schema(value_type = i64)]
+    pub processed_amount: common_utils::types::MinorUnit,
+    /// The estimated total cost of processing these payments
+    #[schema(value_type = i64)]
+    pub estimated_cost: common_utils::types::MinorUnit,
+}
+
+/// Configures how the routing inputs which are not part of the payment are derived for the
+/// payments of a profile. It is stored in the configs, under the key `routing_inputs_{profile_id}`.
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingInputsConfig {
+    /// Offset of the timezone of the merchant from UTC in minutes, used to derive the day of the
+    /// week and the hour of the day of payments. UTC is assumed when not configured.
+    pub timezone_offset_in_minutes: Option<i16>,
+    /// The currency to which payment amounts are converted for routing on the converted amount
+    pub reference_currency: Option<crate::enums::Currency>,
+    /// The key of the customer metadata holding the segment of the customer
+    pub customer_segment_key: Option<String>,
+}
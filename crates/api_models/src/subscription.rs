@@ -0,0 +1,257 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PlanCreateRequest {
+    /// The name of the plan
+    #[schema(example = "Premium Monthly")]
+    pub name: String,
+
+    /// A description of the plan
+    #[schema(example = "Access to all premium features, billed monthly")]
+    pub description: Option<String>,
+
+    /// The amount to be charged for every billing interval, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The currency in which the plan is billed
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The unit of time after which a subscription to this plan is billed again
+    #[schema(value_type = BillingInterval, example = "month")]
+    pub billing_interval: api_enums::BillingInterval,
+
+    /// The number of `billing_interval` units between two billing cycles, defaults to 1
+    #[schema(example = 1)]
+    pub billing_interval_count: Option<u16>,
+
+    /// The number of days for which a new subscription is not billed
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long.
+    #[schema(value_type = Option<Object>, example = r#"{ "tier": "premium" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PlanUpdateRequest {
+    /// The identifier of the plan
+    #[serde(skip)]
+    pub plan_id: String,
+
+    /// The name of the plan
+    pub name: Option<String>,
+
+    /// A description of the plan
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long.
+    #[schema(value_type = Option<Object>, example = r#"{ "tier": "premium" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PlanResponse {
+    /// The identifier of the plan
+    #[schema(example = "plan_qa3UjSMnfJWtgiRC2BCx")]
+    pub plan_id: String,
+
+    /// The identifier of the merchant to which the plan belongs
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The name of the plan
+    pub name: String,
+
+    /// A description of the plan
+    pub description: Option<String>,
+
+    /// The amount charged for every billing interval, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The currency in which the plan is billed
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The unit of time after which a subscription to this plan is billed again
+    #[schema(value_type = BillingInterval, example = "month")]
+    pub billing_interval: api_enums::BillingInterval,
+
+    /// The number of `billing_interval` units between two billing cycles
+    pub billing_interval_count: i32,
+
+    /// The number of days for which a new subscription is not billed
+    pub trial_period_days: Option<i32>,
+
+    /// Metadata associated with the plan
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Whether new subscriptions can be created for the plan
+    pub is_active: bool,
+
+    /// The time at which the plan was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PlanId {
+    pub plan_id: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PlanListConstraints {
+    /// The maximum number of plans to include in the response, defaults to 10
+    pub limit: Option<u16>,
+
+    /// The number of plans to skip when retrieving the list
+    pub offset: Option<u16>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The identifier of the customer who is subscribing to the plan
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier of the plan to subscribe to
+    #[schema(example = "plan_qa3UjSMnfJWtgiRC2BCx")]
+    pub plan_id: String,
+
+    /// The identifier of an active mandate of the customer, which is used for charging the customer in every billing cycle
+    #[schema(example = "man_6hjYZNiyWaXHVzDa5KRQ")]
+    pub mandate_id: String,
+
+    /// The business profile to be used for the payments of the subscription and its webhooks
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long.
+    #[schema(value_type = Option<Object>, example = r#"{ "order_reference": "or_1234" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionUpdateRequest {
+    /// The identifier of the subscription
+    #[serde(skip)]
+    pub subscription_id: String,
+
+    /// The identifier of an active mandate of the customer to be used for charging the customer from the next billing cycle
+    pub mandate_id: Option<String>,
+
+    /// Whether the subscription has to be cancelled at the end of the current billing period
+    pub cancel_at_period_end: Option<bool>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// The identifier of the subscription
+    #[schema(example = "sub_a8Vj3OdBzBi5qY7hVHZe")]
+    pub subscription_id: String,
+
+    /// The identifier of the merchant to which the subscription belongs
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier of the subscribed customer
+    #[schema(value_type = String)]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier of the subscribed plan
+    pub plan_id: String,
+
+    /// The identifier of the mandate used for charging the customer
+    pub mandate_id: String,
+
+    /// The business profile used for the payments of the subscription
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The status of the subscription
+    #[schema(value_type = SubscriptionStatus, example = "active")]
+    pub status: api_enums::SubscriptionStatus,
+
+    /// The start of the current billing period
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_start: PrimitiveDateTime,
+
+    /// The end of the current billing period, at which the customer is charged for the next period
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_end: PrimitiveDateTime,
+
+    /// The end of the trial period, if the plan has one
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+
+    /// Whether the subscription will be cancelled at the end of the current billing period
+    pub cancel_at_period_end: bool,
+
+    /// The time at which the subscription was cancelled
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+
+    /// The number of consecutive failed payment attempts for the current billing period
+    pub failed_payment_count: i32,
+
+    /// The identifier of the latest payment made for the subscription
+    #[schema(value_type = Option<String>)]
+    pub latest_payment_id: Option<id_type::PaymentId>,
+
+    /// Metadata associated with the subscription
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// The time at which the subscription was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SubscriptionId {
+    pub subscription_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCancelRequest {
+    /// The identifier of the subscription
+    #[serde(skip)]
+    pub subscription_id: String,
+
+    /// If `true`, the subscription stays active until the end of the current billing period,
+    /// otherwise it is cancelled immediately. Defaults to `false`
+    pub cancel_at_period_end: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionListConstraints {
+    /// Only return the subscriptions of the given customer
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// The maximum number of subscriptions to include in the response, defaults to 10
+    pub limit: Option<u16>,
+
+    /// The number of subscriptions to skip when retrieving the list
+    pub offset: Option<u16>,
+}
@@ -4,7 +4,7 @@ use masking::Secret;
 
 pub mod role;
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
 pub enum Permission {
     PaymentRead,
     PaymentWrite,
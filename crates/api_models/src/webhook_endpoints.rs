@@ -0,0 +1,109 @@
+use common_enums::EventType;
+use common_utils::id_type;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for creating a webhook endpoint for a business profile.
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The URL to which the webhooks are sent.
+    #[schema(value_type = String, example = "https://ledger.example.com/webhooks")]
+    pub url: url::Url,
+
+    /// A description to provide more context about the webhook endpoint.
+    #[schema(max_length = 255, example = "Refund events for the ledger service")]
+    pub description: Option<String>,
+
+    /// The secret used to sign the webhooks sent to the endpoint. A secret is generated if it is
+    /// not provided.
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub secret: Option<Secret<String>>,
+
+    /// The event types for which webhooks are sent to the endpoint.
+    #[schema(value_type = Vec<EventType>, example = json!(["refund_succeeded", "refund_failed"]))]
+    pub enabled_events: Vec<EventType>,
+
+    /// Whether webhooks are sent to the endpoint. Defaults to `true`.
+    #[schema(example = true)]
+    pub is_enabled: Option<bool>,
+}
+
+/// The request body for updating a webhook endpoint.
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The identifier for the webhook endpoint.
+    #[serde(skip)]
+    pub endpoint_id: String,
+
+    /// The URL to which the webhooks are sent.
+    #[schema(value_type = Option<String>, example = "https://ledger.example.com/webhooks")]
+    pub url: Option<url::Url>,
+
+    /// A description to provide more context about the webhook endpoint.
+    #[schema(max_length = 255, example = "Refund events for the ledger service")]
+    pub description: Option<String>,
+
+    /// The secret used to sign the webhooks sent to the endpoint.
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub secret: Option<Secret<String>>,
+
+    /// The event types for which webhooks are sent to the endpoint. Replaces the existing list of
+    /// event types.
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["refund_succeeded"]))]
+    pub enabled_events: Option<Vec<EventType>>,
+
+    /// Whether webhooks are sent to the endpoint.
+    #[schema(example = false)]
+    pub is_enabled: Option<bool>,
+}
+
+/// The identifier for a webhook endpoint.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WebhookEndpointId {
+    pub endpoint_id: String,
+}
+
+/// The response body for a webhook endpoint.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_8fR3nE3ZlE6jcXgTCaf2")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The URL to which the webhooks are sent.
+    #[schema(value_type = String, example = "https://ledger.example.com/webhooks")]
+    pub url: Secret<String>,
+
+    /// The description to provide more context about the webhook endpoint.
+    #[schema(max_length = 255, example = "Refund events for the ledger service")]
+    pub description: Option<String>,
+
+    /// The secret used to sign the webhooks sent to the endpoint.
+    #[schema(value_type = String, max_length = 255)]
+    pub secret: Secret<String>,
+
+    /// The event types for which webhooks are sent to the endpoint.
+    #[schema(value_type = Vec<EventType>, example = json!(["refund_succeeded", "refund_failed"]))]
+    pub enabled_events: Vec<EventType>,
+
+    /// Whether webhooks are sent to the endpoint.
+    #[schema(example = true)]
+    pub is_enabled: bool,
+
+    /// The time at which the webhook endpoint was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
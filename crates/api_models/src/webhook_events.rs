@@ -1,4 +1,4 @@
-use common_enums::{EventClass, EventType, WebhookDeliveryAttempt};
+use common_enums::{EventClass, EventType, IncomingWebhookEventStatus, WebhookDeliveryAttempt};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -76,6 +76,11 @@ pub struct EventListItemResponse {
     #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
     pub initial_attempt_id: String,
 
+    /// The identifier for the webhook endpoint the event was delivered to. This is not present
+    /// for events delivered to the webhook URL configured in the business profile.
+    #[schema(max_length = 64, example = "whe_8fR3nE3ZlE6jcXgTCaf2")]
+    pub webhook_endpoint_id: Option<String>,
+
     /// Time at which the event was created.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601")]
@@ -187,3 +192,145 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The constraints to apply when filtering incoming webhooks received from connectors.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct IncomingWebhookEventListConstraints {
+    /// Filter incoming webhooks received for the specified connector name or merchant connector
+    /// account ID, as present in the webhook URL.
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// Filter incoming webhooks with the specified processing status.
+    pub status: Option<IncomingWebhookEventStatus>,
+
+    /// Filter incoming webhooks received after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter incoming webhooks received before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Include at most the specified number of incoming webhooks.
+    pub limit: Option<u16>,
+
+    /// Include incoming webhooks after the specified offset.
+    pub offset: Option<u16>,
+}
+
+/// The response body for each item when listing incoming webhooks.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IncomingWebhookEventListItemResponse {
+    /// The identifier for the incoming webhook.
+    #[schema(max_length = 64, example = "iwh_018e31720d1b7a2b82677d3032cab959")]
+    pub webhook_event_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The connector name or merchant connector account ID the webhook was received for.
+    #[schema(max_length = 64, example = "stripe")]
+    pub connector: String,
+
+    /// The outcome of the last attempt at processing the webhook.
+    pub status: IncomingWebhookEventStatus,
+
+    /// The effect the webhook had on the object it is associated with, if it was processed
+    /// successfully.
+    #[schema(value_type = Option<Object>)]
+    pub webhook_effect: Option<serde_json::Value>,
+
+    /// The error which occurred when processing the webhook, if any.
+    pub error_message: Option<String>,
+
+    /// The number of times the webhook has been replayed.
+    #[schema(example = 0)]
+    pub replay_count: i32,
+
+    /// Time at which the webhook was received.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which the webhook was last processed.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified: PrimitiveDateTime,
+}
+
+/// The response body for retrieving or replaying an incoming webhook.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IncomingWebhookEventRetrieveResponse {
+    #[serde(flatten)]
+    pub event_information: IncomingWebhookEventListItemResponse,
+
+    /// The request information (method, URI, headers and body) received in the webhook.
+    pub request: IncomingWebhookRequestContent,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookEventRetrieveResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.event_information.merchant_id.clone(),
+        })
+    }
+}
+
+/// The request information (method, URI, headers and body) received in an incoming webhook.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct IncomingWebhookRequestContent {
+    /// The HTTP method of the webhook request.
+    #[schema(example = "POST")]
+    pub method: String,
+
+    /// The URI the webhook request was received on.
+    #[schema(example = "/webhooks/y3oqhf46pyzuxjbcn2giaqnb44/stripe")]
+    pub uri: String,
+
+    /// The query parameters of the webhook request.
+    #[schema(value_type = String)]
+    pub query_params: Secret<String>,
+
+    /// The request headers received in the webhook. Bytes which are not valid UTF-8 are shown as
+    /// replacement characters, the webhook is replayed with the headers exactly as received.
+    #[schema(
+        value_type = Vec<(String, String)>,
+        example = json!([["content-type", "application/json"], ["content-length", "1024"]]))
+    ]
+    pub headers: Vec<(String, Secret<String>)>,
+
+    /// The request body received in the webhook. Bytes which are not valid UTF-8 are shown as
+    /// replacement characters, the webhook is replayed with the body exactly as received.
+    #[schema(value_type = String)]
+    pub body: Secret<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct IncomingWebhookEventListRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: IncomingWebhookEventListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookEventListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct IncomingWebhookEventRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub webhook_event_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookEventRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
@@ -78,6 +78,11 @@ pub enum ApiClientError {
     GatewayTimeoutReceived,
     #[error("Server responded with unexpected response")]
     UnexpectedServerResponse,
+
+    #[error("No recorded fixture found for the request")]
+    FixtureNotFound,
+    #[error("Failed to record the fixture for the request")]
+    FixtureRecordingFailed,
 }
 impl ApiClientError {
     pub fn is_upstream_timeout(&self) -> bool {
@@ -1231,6 +1236,7 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    Subscriptions,
 }
 
 #[derive(
@@ -1276,6 +1282,10 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    SubscriptionCreated,
+    SubscriptionRenewed,
+    SubscriptionPaymentFailed,
+    SubscriptionCancelled,
 }
 
 #[derive(
@@ -1766,6 +1776,81 @@ pub enum MandateStatus {
     Revoked,
 }
 
+/// The status of a subscription, which indicates whether the customer is being billed for the plan.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The subscription is in its trial period, the first payment is made once the trial ends
+    Trialing,
+    /// The payment for the current billing period has succeeded
+    #[default]
+    Active,
+    /// The payment for the current billing period has failed and is being retried
+    PastDue,
+    /// The subscription has been cancelled and will not be billed any further
+    Cancelled,
+}
+
+/// The unit of time after which a subscription is billed again.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BillingInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// The outcome of processing an incoming webhook received from a connector.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum IncomingWebhookEventStatus {
+    /// The webhook was processed successfully
+    Processed,
+    /// An error occurred when processing the webhook, the webhook can be replayed
+    Failed,
+}
+
 /// Indicates the card network.
 #[derive(
     Clone,
@@ -2723,6 +2808,31 @@ pub enum TransactionType {
     Payout,
 }
 
+/// The change made to the active routing algorithm of a profile
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingActivationType {
+    /// An algorithm was activated
+    Activation,
+    /// The active algorithm was deactivated
+    Deactivation,
+    /// The previously active algorithm was activated again
+    Rollback,
+}
+
 #[derive(
     Clone,
     Copy,
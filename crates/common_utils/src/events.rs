@@ -77,6 +77,15 @@ pub enum ApiEventsType {
         poll_id: String,
     },
     Analytics,
+    Plan {
+        plan_id: String,
+    },
+    Subscription {
+        subscription_id: String,
+    },
+    WebhookEndpoint {
+        endpoint_id: String,
+    },
 }
 
 impl ApiEventMetric for serde_json::Value {}
@@ -20,3 +20,11 @@ impl crate::events::ApiEventMetric for ProfileId {
         })
     }
 }
+
+/// All the keys that can be formed from profile id
+impl ProfileId {
+    /// get_routing_inputs_config_key
+    pub fn get_routing_inputs_config_key(&self) -> String {
+        format!("routing_inputs_{}", self.get_string_repr())
+    }
+}
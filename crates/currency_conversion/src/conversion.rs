@@ -4,7 +4,7 @@ use rusty_money::Money;
 
 use crate::{
     error::CurrencyConversionError,
-    types::{currency_match, ExchangeRates},
+    types::{currency_match, ConversionDetails, ExchangeRates},
 };
 
 pub fn convert(
@@ -13,17 +13,47 @@ pub fn convert(
     to_currency: Currency,
     amount: i64,
 ) -> Result<Decimal, CurrencyConversionError> {
+    convert_with_markup(ex_rates, from_currency, to_currency, amount, 0)
+        .map(|conversion| conversion.converted_amount)
+}
+
+/// Converts the amount with the markup applied on top of the market rate. A markup increases the
+/// converted amount, i.e. the applied rate is `market_rate * (1 + markup_bps / 10000)`.
+pub fn convert_with_markup(
+    ex_rates: &ExchangeRates,
+    from_currency: Currency,
+    to_currency: Currency,
+    amount: i64,
+    markup_bps: u32,
+) -> Result<ConversionDetails, CurrencyConversionError> {
     let money_minor = Money::from_minor(amount, currency_match(from_currency));
-    let base_currency = ex_rates.base_currency;
-    if to_currency == base_currency {
-        ex_rates.forward_conversion(*money_minor.amount(), from_currency)
-    } else if from_currency == base_currency {
-        ex_rates.backward_conversion(*money_minor.amount(), to_currency)
-    } else {
-        let base_conversion_amt =
-            ex_rates.forward_conversion(*money_minor.amount(), from_currency)?;
-        ex_rates.backward_conversion(base_conversion_amt, to_currency)
-    }
+    let market_rate = ex_rates.get_rate(from_currency, to_currency)?;
+    let applied_rate = apply_markup(market_rate, markup_bps)?;
+    let converted_amount = money_minor
+        .amount()
+        .checked_mul(applied_rate)
+        .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)?;
+
+    Ok(ConversionDetails {
+        from_currency,
+        to_currency,
+        amount,
+        market_rate,
+        markup_bps,
+        applied_rate,
+        converted_amount,
+    })
+}
+
+fn apply_markup(rate: Decimal, markup_bps: u32) -> Result<Decimal, CurrencyConversionError> {
+    const BASIS_POINTS_PER_UNIT: i64 = 10_000;
+
+    let markup_factor = Decimal::from(markup_bps)
+        .checked_div(Decimal::from(BASIS_POINTS_PER_UNIT))
+        .and_then(|markup| markup.checked_add(Decimal::ONE))
+        .ok_or(CurrencyConversionError::DecimalDivisionFailed)?;
+    rate.checked_mul(markup_factor)
+        .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)
 }
 
 #[cfg(test)]
@@ -98,4 +128,56 @@ mod tests {
             amount, convert_from, convert_to, res
         );
     }
+
+    #[test]
+    fn direct_rate_takes_precedence_over_cross_rate() {
+        use super::*;
+        let mut conversion: HashMap<Currency, CurrencyFactors> = HashMap::new();
+        conversion.insert(
+            Currency::EUR,
+            CurrencyFactors::new(Decimal::new(9, 1), Decimal::new(11, 1)),
+        );
+        conversion.insert(
+            Currency::GBP,
+            CurrencyFactors::new(Decimal::new(8, 1), Decimal::new(125, 2)),
+        );
+        let mut sample_rate = ExchangeRates::new(Currency::USD, conversion);
+
+        let cross_rate = sample_rate
+            .get_rate(Currency::EUR, Currency::GBP)
+            .expect("cross rate");
+        assert_eq!(cross_rate, Decimal::new(88, 2));
+
+        sample_rate.insert_direct_rate(Currency::EUR, Currency::GBP, Decimal::new(85, 2));
+        let res =
+            convert(&sample_rate, Currency::EUR, Currency::GBP, 2000).expect("converted_currency");
+        assert_eq!(res, Decimal::new(17, 0));
+
+        // The inverse of the direct rate is used for the reverse pair
+        let res =
+            convert(&sample_rate, Currency::GBP, Currency::EUR, 1700).expect("converted_currency");
+        assert_eq!(res.round_dp(2), Decimal::new(20, 0));
+    }
+
+    #[test]
+    fn conversion_with_markup() {
+        use super::*;
+        let mut sample_rate = ExchangeRates::new(Currency::USD, HashMap::new());
+        sample_rate.insert_direct_rate(Currency::USD, Currency::INR, Decimal::new(80, 0));
+
+        let res = convert_with_markup(&sample_rate, Currency::USD, Currency::INR, 1000, 250)
+            .expect("converted_currency");
+        assert_eq!(res.market_rate, Decimal::new(80, 0));
+        assert_eq!(res.applied_rate, Decimal::new(82, 0));
+        assert_eq!(res.converted_amount, Decimal::new(820, 0));
+    }
+
+    #[test]
+    fn same_currency_conversion() {
+        use super::*;
+        let sample_rate = ExchangeRates::new(Currency::USD, HashMap::new());
+        let res =
+            convert(&sample_rate, Currency::EUR, Currency::EUR, 2000).expect("converted_currency");
+        assert_eq!(res, Decimal::new(20, 0));
+    }
 }
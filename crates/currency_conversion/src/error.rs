@@ -3,6 +3,8 @@
 pub enum CurrencyConversionError {
     #[error("Currency Conversion isn't possible")]
     DecimalMultiplicationFailed,
+    #[error("Currency Conversion isn't possible")]
+    DecimalDivisionFailed,
     #[error("Currency not supported: '{0}'")]
     ConversionNotSupported(String),
 }
@@ -11,6 +11,10 @@ use crate::error::CurrencyConversionError;
 pub struct ExchangeRates {
     pub base_currency: Currency,
     pub conversion: HashMap<Currency, CurrencyFactors>,
+    /// Rates quoted directly for a currency pair, keyed by the source and then the target
+    /// currency. These take precedence over the cross rates derived through the base currency.
+    #[serde(default)]
+    pub direct_rates: HashMap<Currency, HashMap<Currency, Decimal>>,
 }
 
 /// Stores the multiplicative factor for  conversion between currency to base and vice versa
@@ -36,6 +40,75 @@ impl ExchangeRates {
         Self {
             base_currency,
             conversion,
+            direct_rates: HashMap::new(),
+        }
+    }
+
+    /// Adds a rate quoted directly for the currency pair, overriding the cross rate derived
+    /// through the base currency
+    pub fn insert_direct_rate(
+        &mut self,
+        from_currency: Currency,
+        to_currency: Currency,
+        rate: Decimal,
+    ) {
+        self.direct_rates
+            .entry(from_currency)
+            .or_default()
+            .insert(to_currency, rate);
+    }
+
+    /// The rate quoted directly for the currency pair, or the inverse of the rate quoted for the
+    /// reverse pair
+    pub fn get_direct_rate(
+        &self,
+        from_currency: Currency,
+        to_currency: Currency,
+    ) -> Result<Option<Decimal>, CurrencyConversionError> {
+        let pair_rate = |from: Currency, to: Currency| {
+            self.direct_rates
+                .get(&from)
+                .and_then(|rates| rates.get(&to))
+                .copied()
+        };
+
+        if let Some(rate) = pair_rate(from_currency, to_currency) {
+            Ok(Some(rate))
+        } else if let Some(reverse_rate) = pair_rate(to_currency, from_currency) {
+            Decimal::ONE
+                .checked_div(reverse_rate)
+                .map(Some)
+                .ok_or(CurrencyConversionError::DecimalDivisionFailed)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// The rate to be multiplied to an amount in `from_currency` to obtain the amount in
+    /// `to_currency`. Direct rates are preferred, falling back to the cross rate
+    /// from_currency -> base_currency -> to_currency.
+    pub fn get_rate(
+        &self,
+        from_currency: Currency,
+        to_currency: Currency,
+    ) -> Result<Decimal, CurrencyConversionError> {
+        if from_currency == to_currency {
+            return Ok(Decimal::ONE);
+        }
+
+        if let Some(rate) = self.get_direct_rate(from_currency, to_currency)? {
+            return Ok(rate);
+        }
+
+        let to_base_rate = if from_currency == self.base_currency {
+            Decimal::ONE
+        } else {
+            self.forward_conversion(Decimal::ONE, from_currency)?
+        };
+        if to_currency == self.base_currency {
+            Ok(to_base_rate)
+        } else {
+            self.backward_conversion(to_base_rate, to_currency)
         }
     }
 
@@ -75,6 +148,48 @@ impl ExchangeRates {
     }
 }
 
+/// Markup applied by a merchant on top of the market rate of a conversion, in basis points
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct MarkupConfig {
+    /// The markup applied to conversions between currency pairs not present in `currency_pairs`
+    #[serde(default)]
+    pub default_markup_bps: u32,
+    /// The markup for specific currency pairs, keyed by the source and then the target currency
+    #[serde(default)]
+    pub currency_pairs: HashMap<Currency, HashMap<Currency, u32>>,
+}
+
+impl MarkupConfig {
+    pub fn get_markup_bps(&self, from_currency: Currency, to_currency: Currency) -> u32 {
+        if from_currency == to_currency {
+            return 0;
+        }
+
+        self.currency_pairs
+            .get(&from_currency)
+            .and_then(|markups| markups.get(&to_currency))
+            .copied()
+            .unwrap_or(self.default_markup_bps)
+    }
+}
+
+/// Details of a conversion, sufficient to reproduce it from the exchange rates it used
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConversionDetails {
+    pub from_currency: Currency,
+    pub to_currency: Currency,
+    /// The amount in the minor unit of `from_currency`
+    pub amount: i64,
+    /// The rate obtained from the exchange rates
+    pub market_rate: Decimal,
+    /// The markup applied on top of the market rate, in basis points
+    pub markup_bps: u32,
+    /// The rate the amount was converted with, after applying the markup
+    pub applied_rate: Decimal,
+    /// The converted amount in the major unit of `to_currency`
+    pub converted_amount: Decimal,
+}
+
 pub fn currency_match(currency: Currency) -> &'static iso::Currency {
     match currency {
         Currency::AED => iso::AED,
@@ -18,6 +18,13 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    /// The permissions the API key is restricted to, `None` if the API key is unrestricted
+    pub permissions: Option<Vec<String>>,
+    /// The business profile the API key is restricted to
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    /// The IP addresses the API key can be used from, `None` if the API key can be used from any
+    /// IP address
+    pub ip_allowlist: Option<Vec<String>>,
 }
 
 #[derive(Debug, Insertable)]
@@ -32,6 +39,9 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permissions: Option<Vec<String>>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub ip_allowlist: Option<Vec<String>>,
 }
 
 #[derive(Debug)]
@@ -41,6 +51,8 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        permissions: Option<Option<Vec<String>>>,
+        ip_allowlist: Option<Option<Vec<String>>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -54,6 +66,8 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permissions: Option<Option<Vec<String>>>,
+    pub ip_allowlist: Option<Option<Vec<String>>>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -64,17 +78,23 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                permissions,
+                ip_allowlist,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                permissions,
+                ip_allowlist,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                permissions: None,
+                ip_allowlist: None,
             },
         }
     }
@@ -0,0 +1,52 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::connector_fee_schedule;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_fee_schedule)]
+pub struct ConnectorFeeScheduleNew {
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub fee_rules: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = connector_fee_schedule, primary_key(merchant_connector_id), check_for_backend(diesel::pg::Pg))]
+pub struct ConnectorFeeSchedule {
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub fee_rules: serde_json::Value,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub struct ConnectorFeeScheduleUpdate {
+    pub fee_rules: serde_json::Value,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_fee_schedule)]
+pub struct ConnectorFeeScheduleUpdateInternal {
+    fee_rules: serde_json::Value,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<ConnectorFeeScheduleUpdate> for ConnectorFeeScheduleUpdateInternal {
+    fn from(connector_fee_schedule_update: ConnectorFeeScheduleUpdate) -> Self {
+        let ConnectorFeeScheduleUpdate { fee_rules } = connector_fee_schedule_update;
+
+        Self {
+            fee_rules,
+            modified_at: common_utils::date_time::now(),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use common_utils::custom_serde;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use masking::Secret;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::dispute};
@@ -147,3 +147,10 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
         }
     }
 }
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DisputeSyncTrackingData {
+    pub dispute_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -73,6 +73,7 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    SubscriptionDetails,
 }
 
 #[derive(
@@ -30,6 +30,7 @@ pub struct EventNew {
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -61,6 +62,7 @@ pub struct Event {
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 pub struct EventWithEncryption {
@@ -117,6 +119,9 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    Subscription {
+        subscription_id: String,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
@@ -0,0 +1,26 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::fx_rate_snapshot};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = fx_rate_snapshot)]
+pub struct FxRateSnapshotNew {
+    pub snapshot_id: String,
+    pub provider: String,
+    pub base_currency: storage_enums::Currency,
+    pub rates: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = fx_rate_snapshot, primary_key(snapshot_id), check_for_backend(diesel::pg::Pg))]
+pub struct FxRateSnapshot {
+    pub snapshot_id: String,
+    pub provider: String,
+    pub base_currency: storage_enums::Currency,
+    pub rates: serde_json::Value,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
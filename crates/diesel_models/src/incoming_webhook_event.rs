@@ -0,0 +1,82 @@
+use common_utils::encryption::Encryption;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::incoming_webhook_event};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_event)]
+pub struct IncomingWebhookEventNew {
+    pub webhook_event_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_name_or_mca_id: String,
+    pub request_details: Encryption,
+    pub request_body: Encryption,
+    pub status: storage_enums::IncomingWebhookEventStatus,
+    pub webhook_effect: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    pub replay_count: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = incoming_webhook_event, primary_key(webhook_event_id), check_for_backend(diesel::pg::Pg))]
+pub struct IncomingWebhookEvent {
+    pub webhook_event_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_name_or_mca_id: String,
+    pub request_details: Encryption,
+    pub request_body: Encryption,
+    pub status: storage_enums::IncomingWebhookEventStatus,
+    pub webhook_effect: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    pub replay_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum IncomingWebhookEventUpdate {
+    ReplayUpdate {
+        status: storage_enums::IncomingWebhookEventStatus,
+        webhook_effect: Option<serde_json::Value>,
+        error_message: Option<String>,
+        replay_count: i32,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_event)]
+pub struct IncomingWebhookEventUpdateInternal {
+    status: storage_enums::IncomingWebhookEventStatus,
+    // Overwrite the outcome of the previous attempt, even if the replay had no effect
+    #[diesel(treat_none_as_null = true)]
+    webhook_effect: Option<serde_json::Value>,
+    #[diesel(treat_none_as_null = true)]
+    error_message: Option<String>,
+    replay_count: i32,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<IncomingWebhookEventUpdate> for IncomingWebhookEventUpdateInternal {
+    fn from(update: IncomingWebhookEventUpdate) -> Self {
+        match update {
+            IncomingWebhookEventUpdate::ReplayUpdate {
+                status,
+                webhook_effect,
+                error_message,
+                replay_count,
+            } => Self {
+                status,
+                webhook_effect,
+                error_message,
+                replay_count,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
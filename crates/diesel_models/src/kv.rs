@@ -1,13 +1,17 @@
 use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "v2")]
-use crate::payment_attempt::PaymentAttemptUpdateInternal;
+#[cfg(feature = "v1")]
+use crate::payment_intent::PaymentIntentUpdateInternal;
 use crate::{
     address::{Address, AddressNew, AddressUpdateInternal},
+    capture::{Capture, CaptureNew, CaptureUpdate},
     customers::{Customer, CustomerNew, CustomerUpdateInternal},
     errors,
-    payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
+    fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate},
+    payment_attempt::{
+        PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate, PaymentAttemptUpdateInternal,
+    },
     payment_intent::{PaymentIntentNew, PaymentIntentUpdate},
     payout_attempt::{PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate},
     payouts::{Payouts, PayoutsNew, PayoutsUpdate},
@@ -22,6 +26,7 @@ use crate::{
 pub enum DBOperation {
     Insert { insertable: Insertable },
     Update { updatable: Updateable },
+    Delete { deletable: Deletable },
 }
 
 impl DBOperation {
@@ -29,6 +34,7 @@ impl DBOperation {
         match self {
             Self::Insert { .. } => "insert",
             Self::Update { .. } => "update",
+            Self::Delete { .. } => "delete",
         }
     }
     pub fn table<'a>(&self) -> &'a str {
@@ -44,6 +50,8 @@ impl DBOperation {
                 Insertable::ReverseLookUp(_) => "reverse_lookup",
                 Insertable::PaymentMethod(_) => "payment_method",
                 Insertable::Mandate(_) => "mandate",
+                Insertable::Capture(_) => "captures",
+                Insertable::FraudCheck(_) => "fraud_check",
             },
             Self::Update { updatable } => match updatable {
                 Updateable::PaymentIntentUpdate(_) => "payment_intent",
@@ -55,6 +63,17 @@ impl DBOperation {
                 Updateable::PayoutAttemptUpdate(_) => "payout_attempt",
                 Updateable::PaymentMethodUpdate(_) => "payment_method",
                 Updateable::MandateUpdate(_) => " mandate",
+                Updateable::CaptureUpdate(_) => "captures",
+                Updateable::FraudCheckUpdate(_) => "fraud_check",
+            },
+            Self::Delete { deletable } => match deletable {
+                #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+                Deletable::Customer(_) => "customer",
+                #[cfg(all(
+                    any(feature = "v1", feature = "v2"),
+                    not(feature = "payment_methods_v2")
+                ))]
+                Deletable::PaymentMethod(_) => "payment_method",
             },
         }
     }
@@ -72,6 +91,10 @@ pub enum DBResult {
     PayoutAttempt(Box<PayoutAttempt>),
     PaymentMethod(Box<PaymentMethod>),
     Mandate(Box<Mandate>),
+    Capture(Box<Capture>),
+    FraudCheck(Box<FraudCheck>),
+    /// Whether the row was still present in the database when it was deleted
+    Deleted(bool),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -106,6 +129,8 @@ impl DBOperation {
                     DBResult::PaymentMethod(Box::new(rev.insert(conn).await?))
                 }
                 Insertable::Mandate(m) => DBResult::Mandate(Box::new(m.insert(conn).await?)),
+                Insertable::Capture(c) => DBResult::Capture(Box::new(c.insert(conn).await?)),
+                Insertable::FraudCheck(f) => DBResult::FraudCheck(Box::new(f.insert(conn).await?)),
             },
             Self::Update { updatable } => match updatable {
                 Updateable::PaymentIntentUpdate(a) => {
@@ -172,11 +197,59 @@ impl DBOperation {
                 Updateable::CustomerUpdate(cust) => DBResult::Customer(Box::new(
                     Customer::update_by_id(conn, cust.orig.id.clone(), cust.update_data).await?,
                 )),
+                Updateable::CaptureUpdate(c) => DBResult::Capture(Box::new(
+                    c.orig.update_with_capture_id(conn, c.update_data).await?,
+                )),
+                Updateable::FraudCheckUpdate(f) => DBResult::FraudCheck(Box::new(
+                    f.orig.update_with_attempt_id(conn, f.update_data).await?,
+                )),
+            },
+            Self::Delete { deletable } => match deletable {
+                #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+                Deletable::Customer(cust) => DBResult::Deleted(ignore_not_found(
+                    Customer::delete_by_customer_id_merchant_id(
+                        conn,
+                        &cust.orig.customer_id,
+                        &cust.orig.merchant_id,
+                    )
+                    .await,
+                )?),
+                #[cfg(all(
+                    any(feature = "v1", feature = "v2"),
+                    not(feature = "payment_methods_v2")
+                ))]
+                Deletable::PaymentMethod(pm) => DBResult::Deleted(ignore_not_found(
+                    PaymentMethod::delete_by_merchant_id_payment_method_id(
+                        conn,
+                        &pm.orig.merchant_id,
+                        &pm.orig.payment_method_id,
+                    )
+                    .await
+                    .map(|_| true),
+                )?),
             },
         })
     }
 }
 
+/// A deletion of a row which is no longer present in the database has nothing left to do, the
+/// row having been deleted by an earlier attempt at draining the same entry
+#[cfg(any(
+    all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")),
+    all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    )
+))]
+fn ignore_not_found(result: crate::StorageResult<bool>) -> crate::StorageResult<bool> {
+    match result {
+        Err(error) if matches!(error.current_context(), errors::DatabaseError::NotFound) => {
+            Ok(false)
+        }
+        result => result,
+    }
+}
+
 impl TypedSql {
     pub fn to_field_value_pairs(
         &self,
@@ -211,6 +284,8 @@ pub enum Insertable {
     PayoutAttempt(PayoutAttemptNew),
     PaymentMethod(PaymentMethodNew),
     Mandate(MandateNew),
+    Capture(CaptureNew),
+    FraudCheck(FraudCheckNew),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -225,6 +300,21 @@ pub enum Updateable {
     PayoutAttemptUpdate(PayoutAttemptUpdateMems),
     PaymentMethodUpdate(PaymentMethodUpdateMems),
     MandateUpdate(MandateUpdateMems),
+    CaptureUpdate(CaptureUpdateMems),
+    FraudCheckUpdate(FraudCheckUpdateMems),
+}
+
+/// Rows deleted in Redis, which are left as tombstones in Redis until the deletion is drained
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "table", content = "data")]
+pub enum Deletable {
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    Customer(CustomerDeleteMems),
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    PaymentMethod(PaymentMethodDeleteMems),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -280,3 +370,542 @@ pub struct MandateUpdateMems {
     pub orig: Mandate,
     pub update_data: MandateUpdateInternal,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CaptureUpdateMems {
+    pub orig: Capture,
+    pub update_data: CaptureUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FraudCheckUpdateMems {
+    pub orig: FraudCheck,
+    pub update_data: FraudCheckUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomerDeleteMems {
+    pub orig: Customer,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaymentMethodDeleteMems {
+    pub orig: PaymentMethod,
+}
+
+impl DBOperation {
+    /// Whether the operation can be executed in the same query as the operation preceding it in
+    /// the stream. Insertions into the hot tables are combined into multi-row inserts, and
+    /// updates to the same payment intent or payment attempt are coalesced into a single update.
+    pub fn is_batchable_with(&self, previous: &Self) -> bool {
+        match (self, previous) {
+            (
+                Self::Insert { insertable },
+                Self::Insert {
+                    insertable: previous,
+                },
+            ) => matches!(
+                (insertable, previous),
+                (Insertable::PaymentIntent(_), Insertable::PaymentIntent(_))
+                    | (Insertable::PaymentAttempt(_), Insertable::PaymentAttempt(_))
+                    | (Insertable::Refund(_), Insertable::Refund(_))
+                    | (Insertable::ReverseLookUp(_), Insertable::ReverseLookUp(_))
+            ),
+            #[cfg(feature = "v1")]
+            (
+                Self::Update { updatable },
+                Self::Update {
+                    updatable: previous,
+                },
+            ) => match (updatable, previous) {
+                (
+                    Updateable::PaymentIntentUpdate(update),
+                    Updateable::PaymentIntentUpdate(previous),
+                ) => {
+                    update.orig.payment_id == previous.orig.payment_id
+                        && update.orig.merchant_id == previous.orig.merchant_id
+                }
+                (
+                    Updateable::PaymentAttemptUpdate(update),
+                    Updateable::PaymentAttemptUpdate(previous),
+                ) => {
+                    update.orig.attempt_id == previous.orig.attempt_id
+                        && update.orig.merchant_id == previous.orig.merchant_id
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Consecutive operations from the stream executed as a single query
+#[derive(Debug)]
+pub enum BatchedOperation {
+    Insert(InsertBatch),
+    #[cfg(feature = "v1")]
+    Update(CoalescedUpdate),
+}
+
+/// Insertions into the same table, executed as a multi-row insert
+#[derive(Debug)]
+pub enum InsertBatch {
+    PaymentIntent(Vec<PaymentIntentNew>),
+    PaymentAttempt(Vec<PaymentAttemptNew>),
+    Refund(Vec<RefundNew>),
+    ReverseLookUp(Vec<ReverseLookupNew>),
+}
+
+/// Updates to the same row, coalesced into a single update leaving the row in the state the
+/// updates would have left it in when executed one after the other
+#[cfg(feature = "v1")]
+#[derive(Debug)]
+pub enum CoalescedUpdate {
+    PaymentIntent {
+        orig: Box<PaymentIntent>,
+        updates: Vec<PaymentIntentUpdateInternal>,
+    },
+    PaymentAttempt {
+        orig: Box<PaymentAttempt>,
+        updates: Vec<PaymentAttemptUpdateInternal>,
+    },
+}
+
+impl BatchedOperation {
+    /// Combines the operations into a single query, returning `None` when any of them cannot be
+    /// batched with the operation preceding it
+    pub fn from_operations(operations: Vec<DBOperation>) -> Option<Self> {
+        let mut operations = operations.into_iter();
+        let mut batch = Self::new(operations.next()?)?;
+        for operation in operations {
+            batch.push(operation)?;
+        }
+        Some(batch)
+    }
+
+    fn new(operation: DBOperation) -> Option<Self> {
+        match operation {
+            DBOperation::Insert { insertable } => match insertable {
+                Insertable::PaymentIntent(a) => {
+                    Some(Self::Insert(InsertBatch::PaymentIntent(vec![a])))
+                }
+                Insertable::PaymentAttempt(a) => {
+                    Some(Self::Insert(InsertBatch::PaymentAttempt(vec![a])))
+                }
+                Insertable::Refund(a) => Some(Self::Insert(InsertBatch::Refund(vec![a]))),
+                Insertable::ReverseLookUp(rev) => {
+                    Some(Self::Insert(InsertBatch::ReverseLookUp(vec![rev])))
+                }
+                Insertable::Address(_)
+                | Insertable::Customer(_)
+                | Insertable::Payouts(_)
+                | Insertable::PayoutAttempt(_)
+                | Insertable::PaymentMethod(_)
+                | Insertable::Mandate(_)
+                | Insertable::Capture(_)
+                | Insertable::FraudCheck(_) => None,
+            },
+            #[cfg(feature = "v1")]
+            DBOperation::Update { updatable } => match updatable {
+                Updateable::PaymentIntentUpdate(a) => {
+                    Some(Self::Update(CoalescedUpdate::PaymentIntent {
+                        updates: vec![PaymentIntentUpdateInternal::from(a.update_data)],
+                        orig: Box::new(a.orig),
+                    }))
+                }
+                Updateable::PaymentAttemptUpdate(a) => {
+                    Some(Self::Update(CoalescedUpdate::PaymentAttempt {
+                        updates: vec![PaymentAttemptUpdateInternal::from(a.update_data)
+                            .populate_derived_fields(&a.orig)],
+                        orig: Box::new(a.orig),
+                    }))
+                }
+                _ => None,
+            },
+            #[cfg(feature = "v2")]
+            DBOperation::Update { .. } => None,
+            DBOperation::Delete { .. } => None,
+        }
+    }
+
+    fn push(&mut self, operation: DBOperation) -> Option<()> {
+        match (self, operation) {
+            (
+                Self::Insert(InsertBatch::PaymentIntent(batch)),
+                DBOperation::Insert {
+                    insertable: Insertable::PaymentIntent(a),
+                },
+            ) => batch.push(a),
+            (
+                Self::Insert(InsertBatch::PaymentAttempt(batch)),
+                DBOperation::Insert {
+                    insertable: Insertable::PaymentAttempt(a),
+                },
+            ) => batch.push(a),
+            (
+                Self::Insert(InsertBatch::Refund(batch)),
+                DBOperation::Insert {
+                    insertable: Insertable::Refund(a),
+                },
+            ) => batch.push(a),
+            (
+                Self::Insert(InsertBatch::ReverseLookUp(batch)),
+                DBOperation::Insert {
+                    insertable: Insertable::ReverseLookUp(rev),
+                },
+            ) => batch.push(rev),
+            #[cfg(feature = "v1")]
+            (
+                Self::Update(CoalescedUpdate::PaymentIntent { orig, updates }),
+                DBOperation::Update {
+                    updatable: Updateable::PaymentIntentUpdate(a),
+                },
+            ) if orig.payment_id == a.orig.payment_id && orig.merchant_id == a.orig.merchant_id => {
+                updates.push(PaymentIntentUpdateInternal::from(a.update_data));
+                *orig = Box::new(a.orig);
+            }
+            #[cfg(feature = "v1")]
+            (
+                Self::Update(CoalescedUpdate::PaymentAttempt { orig, updates }),
+                DBOperation::Update {
+                    updatable: Updateable::PaymentAttemptUpdate(a),
+                },
+            ) if orig.attempt_id == a.orig.attempt_id && orig.merchant_id == a.orig.merchant_id => {
+                // The derived fields of each update are populated against the payment attempt
+                // the update was made on, which already reflects the updates preceding it
+                updates.push(
+                    PaymentAttemptUpdateInternal::from(a.update_data)
+                        .populate_derived_fields(&a.orig),
+                );
+                *orig = Box::new(a.orig);
+            }
+            _ => return None,
+        }
+        Some(())
+    }
+
+    /// The number of operations combined into the query
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Insert(batch) => match batch {
+                InsertBatch::PaymentIntent(batch) => batch.len(),
+                InsertBatch::PaymentAttempt(batch) => batch.len(),
+                InsertBatch::Refund(batch) => batch.len(),
+                InsertBatch::ReverseLookUp(batch) => batch.len(),
+            },
+            #[cfg(feature = "v1")]
+            Self::Update(update) => match update {
+                CoalescedUpdate::PaymentIntent { updates, .. } => updates.len(),
+                CoalescedUpdate::PaymentAttempt { updates, .. } => updates.len(),
+            },
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn operation<'a>(&self) -> &'a str {
+        match self {
+            Self::Insert(_) => "batch_insert",
+            #[cfg(feature = "v1")]
+            Self::Update(_) => "coalesced_update",
+        }
+    }
+
+    pub fn table<'a>(&self) -> &'a str {
+        match self {
+            Self::Insert(batch) => match batch {
+                InsertBatch::PaymentIntent(_) => "payment_intent",
+                InsertBatch::PaymentAttempt(_) => "payment_attempt",
+                InsertBatch::Refund(_) => "refund",
+                InsertBatch::ReverseLookUp(_) => "reverse_lookup",
+            },
+            #[cfg(feature = "v1")]
+            Self::Update(update) => match update {
+                CoalescedUpdate::PaymentIntent { .. } => "payment_intent",
+                CoalescedUpdate::PaymentAttempt { .. } => "payment_attempt",
+            },
+        }
+    }
+
+    pub async fn execute(self, conn: &PgPooledConn) -> crate::StorageResult<()> {
+        match self {
+            Self::Insert(batch) => match batch {
+                InsertBatch::PaymentIntent(batch) => {
+                    PaymentIntentNew::batch_insert(batch, conn).await
+                }
+                InsertBatch::PaymentAttempt(batch) => {
+                    PaymentAttemptNew::batch_insert(batch, conn).await
+                }
+                InsertBatch::Refund(batch) => RefundNew::batch_insert(batch, conn).await,
+                InsertBatch::ReverseLookUp(batch) => {
+                    ReverseLookupNew::batch_insert(batch, conn).await
+                }
+            },
+            #[cfg(feature = "v1")]
+            Self::Update(update) => match update {
+                CoalescedUpdate::PaymentIntent { orig, updates } => {
+                    if let Some(update_data) = updates
+                        .into_iter()
+                        .reduce(PaymentIntentUpdateInternal::merge)
+                    {
+                        orig.update_internal(conn, update_data).await?;
+                    }
+                    Ok(())
+                }
+                CoalescedUpdate::PaymentAttempt { orig, updates } => {
+                    if let Some(update_data) = updates
+                        .into_iter()
+                        .reduce(PaymentAttemptUpdateInternal::merge)
+                    {
+                        orig.update_internal_with_attempt_id(conn, update_data)
+                            .await?;
+                    }
+                    Ok(())
+                }
+            },
+        }
+    }
+}
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+    use crate::enums as storage_enums;
+
+    fn get_payment_intent() -> PaymentIntent {
+        serde_json::from_value(serde_json::json!({
+            "payment_id": "pay_1",
+            "merchant_id": "merchant_1",
+            "status": "requires_payment_method",
+            "amount": 10000,
+            "currency": "USD",
+            "created_at": "2024-10-01T12:00:00Z",
+            "modified_at": "2024-10-01T12:00:00Z",
+            "active_attempt_id": "pay_1_1",
+            "attempt_count": 1,
+            "updated_by": "redis_kv",
+            "organization_id": "org_1"
+        }))
+        .unwrap()
+    }
+
+    fn get_payment_attempt() -> PaymentAttempt {
+        serde_json::from_value(serde_json::json!({
+            "payment_id": "pay_1",
+            "merchant_id": "merchant_1",
+            "attempt_id": "pay_1_1",
+            "status": "started",
+            "amount": 10000,
+            "currency": "USD",
+            "surcharge_amount": 500,
+            "tax_amount": 800,
+            "net_amount": 11300,
+            "confirm": true,
+            "created_at": "2024-10-01T12:00:00Z",
+            "modified_at": "2024-10-01T12:00:00Z",
+            "amount_capturable": 11300,
+            "updated_by": "redis_kv",
+            "profile_id": "pro_1",
+            "organization_id": "org_1"
+        }))
+        .unwrap()
+    }
+
+    fn get_payment_attempt_updates() -> Vec<PaymentAttemptUpdate> {
+        vec![
+            PaymentAttemptUpdate::UpdateTrackers {
+                payment_token: Some("token_1".to_string()),
+                connector: Some("adyen".to_string()),
+                straight_through_algorithm: None,
+                amount_capturable: Some(MinorUnit::new(10400)),
+                surcharge_amount: Some(MinorUnit::new(300)),
+                tax_amount: Some(MinorUnit::new(100)),
+                updated_by: "redis_kv".to_string(),
+                merchant_connector_id: None,
+            },
+            PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+                amount: MinorUnit::new(12000),
+                amount_capturable: MinorUnit::new(12400),
+            },
+            PaymentAttemptUpdate::StatusUpdate {
+                status: storage_enums::AttemptStatus::Charged,
+                updated_by: "redis_kv".to_string(),
+            },
+        ]
+    }
+
+    /// Builds the update operations pushed to the stream, each made on the row left by the
+    /// preceding update, along with the row left by applying the updates one after the other
+    fn get_payment_attempt_update_operations(
+        orig: PaymentAttempt,
+        updates: Vec<PaymentAttemptUpdate>,
+    ) -> (Vec<DBOperation>, PaymentAttempt) {
+        let mut operations = Vec::new();
+        let mut current = orig;
+        for update_data in updates {
+            let next = update_data.clone().apply_changeset(current.clone());
+            operations.push(DBOperation::Update {
+                updatable: Updateable::PaymentAttemptUpdate(PaymentAttemptUpdateMems {
+                    orig: current,
+                    update_data,
+                }),
+            });
+            current = next;
+        }
+        (operations, current)
+    }
+
+    #[test]
+    fn test_coalesced_payment_intent_updates_match_sequential_updates() {
+        let orig = get_payment_intent();
+        let updates = vec![
+            PaymentIntentUpdate::ResponseUpdate {
+                status: storage_enums::IntentStatus::Processing,
+                amount_captured: None,
+                fingerprint_id: Some("fingerprint_1".to_string()),
+                return_url: Some("https://example.com/return".to_string()),
+                updated_by: "redis_kv".to_string(),
+                incremental_authorization_allowed: Some(true),
+            },
+            PaymentIntentUpdate::PGStatusUpdate {
+                status: storage_enums::IntentStatus::Succeeded,
+                updated_by: "redis_kv".to_string(),
+                incremental_authorization_allowed: None,
+            },
+            PaymentIntentUpdate::MetadataUpdate {
+                metadata: serde_json::json!({ "order_id": "order_1" }),
+                updated_by: "redis_kv".to_string(),
+            },
+        ];
+
+        let mut operations = Vec::new();
+        let mut sequential = orig.clone();
+        for update_data in updates {
+            let next = update_data.clone().apply_changeset(sequential.clone());
+            operations.push(DBOperation::Update {
+                updatable: Updateable::PaymentIntentUpdate(PaymentIntentUpdateMems {
+                    orig: sequential,
+                    update_data,
+                }),
+            });
+            sequential = next;
+        }
+
+        let Some(BatchedOperation::Update(CoalescedUpdate::PaymentIntent { updates, .. })) =
+            BatchedOperation::from_operations(operations)
+        else {
+            panic!("Expected the updates to be coalesced");
+        };
+        assert_eq!(updates.len(), 3);
+
+        let mut coalesced = updates
+            .into_iter()
+            .reduce(PaymentIntentUpdateInternal::merge)
+            .unwrap()
+            .apply_changeset(orig);
+        coalesced.modified_at = sequential.modified_at;
+
+        assert_eq!(coalesced, sequential);
+        assert_eq!(coalesced.status, storage_enums::IntentStatus::Succeeded);
+        assert_eq!(coalesced.incremental_authorization_allowed, Some(true));
+    }
+
+    #[test]
+    fn test_coalesced_payment_attempt_updates_match_sequential_updates() {
+        let orig = get_payment_attempt();
+        let (operations, sequential) =
+            get_payment_attempt_update_operations(orig.clone(), get_payment_attempt_updates());
+
+        let Some(BatchedOperation::Update(CoalescedUpdate::PaymentAttempt { updates, .. })) =
+            BatchedOperation::from_operations(operations)
+        else {
+            panic!("Expected the updates to be coalesced");
+        };
+        assert_eq!(updates.len(), 3);
+
+        let mut coalesced = updates
+            .into_iter()
+            .reduce(PaymentAttemptUpdateInternal::merge)
+            .unwrap()
+            .apply_changeset(orig);
+        coalesced.modified_at = sequential.modified_at;
+
+        assert_eq!(coalesced, sequential);
+    }
+
+    #[test]
+    fn test_coalesced_payment_attempt_updates_chain_derived_fields() {
+        let (operations, _) = get_payment_attempt_update_operations(
+            get_payment_attempt(),
+            get_payment_attempt_updates(),
+        );
+
+        let Some(BatchedOperation::Update(CoalescedUpdate::PaymentAttempt { updates, .. })) =
+            BatchedOperation::from_operations(operations)
+        else {
+            panic!("Expected the updates to be coalesced");
+        };
+
+        // The net amount of the amount update includes the surcharge and tax of the update
+        // preceding it, not those of the row the updates were coalesced on
+        let net_amounts = updates
+            .iter()
+            .map(|update| update.net_amount)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            net_amounts,
+            vec![
+                Some(MinorUnit::new(10400)),
+                Some(MinorUnit::new(12400)),
+                Some(MinorUnit::new(12400)),
+            ]
+        );
+        assert_eq!(
+            updates
+                .into_iter()
+                .reduce(PaymentAttemptUpdateInternal::merge)
+                .unwrap()
+                .net_amount,
+            Some(MinorUnit::new(12400))
+        );
+    }
+
+    #[cfg(any(
+        all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")),
+        all(
+            any(feature = "v1", feature = "v2"),
+            not(feature = "payment_methods_v2")
+        )
+    ))]
+    #[test]
+    fn test_deleting_rows_already_deleted_is_drained() {
+        assert!(
+            !ignore_not_found(Err(error_stack::report!(errors::DatabaseError::NotFound))).unwrap()
+        );
+        assert!(ignore_not_found(Ok(true)).unwrap());
+        assert!(ignore_not_found(Err(error_stack::report!(
+            errors::DatabaseError::DatabaseConnectionError
+        )))
+        .is_err());
+    }
+
+    #[test]
+    fn test_updates_to_different_rows_are_not_coalesced() {
+        let (mut operations, _) = get_payment_attempt_update_operations(
+            get_payment_attempt(),
+            get_payment_attempt_updates(),
+        );
+        let mut other_attempt = get_payment_attempt();
+        other_attempt.attempt_id = "pay_1_2".to_string();
+        let (other_operations, _) =
+            get_payment_attempt_update_operations(other_attempt, get_payment_attempt_updates());
+        operations.extend(other_operations);
+
+        assert!(BatchedOperation::from_operations(operations).is_none());
+    }
+}
@@ -5,6 +5,7 @@ pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_fee_schedule;
 
 pub mod authentication;
 pub mod authorization;
@@ -19,8 +20,10 @@ pub mod events;
 pub mod file;
 #[allow(unused)]
 pub mod fraud_check;
+pub mod fx_rate_snapshot;
 pub mod generic_link;
 pub mod gsm;
+pub mod incoming_webhook_event;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -35,13 +38,17 @@ pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
+pub mod plan;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
+pub mod routing_activation_history;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
+pub mod webhook_endpoint;
 
 #[allow(unused_qualifications)]
 pub mod schema;
@@ -59,10 +66,11 @@ pub type StorageResult<T> = error_stack::Result<T, errors::DatabaseError>;
 pub type PgPooledConn = async_bb8_diesel::Connection<diesel::PgConnection>;
 pub use self::{
     address::*, api_keys::*, cards_info::*, configs::*, customers::*, dispute::*, ephemeral_key::*,
-    events::*, file::*, generic_link::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, payment_attempt::*, payment_intent::*, payment_method::*,
-    payout_attempt::*, payouts::*, process_tracker::*, refund::*, reverse_lookup::*,
-    user_authentication_method::*,
+    events::*, file::*, fx_rate_snapshot::*, generic_link::*, incoming_webhook_event::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    payment_attempt::*, payment_intent::*, payment_method::*, payout_attempt::*, payouts::*,
+    plan::*, process_tracker::*, refund::*, reverse_lookup::*, subscription::*,
+    user_authentication_method::*, webhook_endpoint::*,
 };
 
 /// The types and implementations provided by this module are required for the schema generated by
@@ -818,6 +818,78 @@ pub struct PaymentAttemptUpdateInternal {
     pub order_tax_amount: Option<MinorUnit>,
 }
 
+#[cfg(feature = "v1")]
+impl PaymentAttemptUpdateInternal {
+    /// Combines two successive changesets of the same payment attempt into one, with the fields
+    /// set by the later changeset taking precedence. The changesets are expected to have their
+    /// derived fields populated against the payment attempt they were made on.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            amount: other.amount.or(self.amount),
+            net_amount: other.net_amount.or(self.net_amount),
+            currency: other.currency.or(self.currency),
+            status: other.status.or(self.status),
+            connector_transaction_id: other
+                .connector_transaction_id
+                .or(self.connector_transaction_id),
+            amount_to_capture: other.amount_to_capture.or(self.amount_to_capture),
+            connector: other.connector.or(self.connector),
+            authentication_type: other.authentication_type.or(self.authentication_type),
+            payment_method: other.payment_method.or(self.payment_method),
+            error_message: other.error_message.or(self.error_message),
+            payment_method_id: other.payment_method_id.or(self.payment_method_id),
+            cancellation_reason: other.cancellation_reason.or(self.cancellation_reason),
+            modified_at: other.modified_at,
+            mandate_id: other.mandate_id.or(self.mandate_id),
+            browser_info: other.browser_info.or(self.browser_info),
+            payment_token: other.payment_token.or(self.payment_token),
+            error_code: other.error_code.or(self.error_code),
+            connector_metadata: other.connector_metadata.or(self.connector_metadata),
+            payment_method_data: other.payment_method_data.or(self.payment_method_data),
+            payment_method_type: other.payment_method_type.or(self.payment_method_type),
+            payment_experience: other.payment_experience.or(self.payment_experience),
+            business_sub_label: other.business_sub_label.or(self.business_sub_label),
+            straight_through_algorithm: other
+                .straight_through_algorithm
+                .or(self.straight_through_algorithm),
+            preprocessing_step_id: other.preprocessing_step_id.or(self.preprocessing_step_id),
+            error_reason: other.error_reason.or(self.error_reason),
+            capture_method: other.capture_method.or(self.capture_method),
+            connector_response_reference_id: other
+                .connector_response_reference_id
+                .or(self.connector_response_reference_id),
+            multiple_capture_count: other.multiple_capture_count.or(self.multiple_capture_count),
+            surcharge_amount: other.surcharge_amount.or(self.surcharge_amount),
+            tax_amount: other.tax_amount.or(self.tax_amount),
+            amount_capturable: other.amount_capturable.or(self.amount_capturable),
+            updated_by: other.updated_by,
+            merchant_connector_id: other.merchant_connector_id.or(self.merchant_connector_id),
+            authentication_data: other.authentication_data.or(self.authentication_data),
+            encoded_data: other.encoded_data.or(self.encoded_data),
+            unified_code: other.unified_code.or(self.unified_code),
+            unified_message: other.unified_message.or(self.unified_message),
+            external_three_ds_authentication_attempted: other
+                .external_three_ds_authentication_attempted
+                .or(self.external_three_ds_authentication_attempted),
+            authentication_connector: other
+                .authentication_connector
+                .or(self.authentication_connector),
+            authentication_id: other.authentication_id.or(self.authentication_id),
+            fingerprint_id: other.fingerprint_id.or(self.fingerprint_id),
+            payment_method_billing_address_id: other
+                .payment_method_billing_address_id
+                .or(self.payment_method_billing_address_id),
+            charge_id: other.charge_id.or(self.charge_id),
+            client_source: other.client_source.or(self.client_source),
+            client_version: other.client_version.or(self.client_version),
+            customer_acceptance: other.customer_acceptance.or(self.customer_acceptance),
+            card_network: other.card_network.or(self.card_network),
+            shipping_cost: other.shipping_cost.or(self.shipping_cost),
+            order_tax_amount: other.order_tax_amount.or(self.order_tax_amount),
+        }
+    }
+}
+
 #[cfg(feature = "v2")]
 impl PaymentAttemptUpdateInternal {
     pub fn populate_derived_fields(self, source: &PaymentAttempt) -> Self {
@@ -954,7 +1026,18 @@ impl PaymentAttemptUpdate {
 #[cfg(feature = "v1")]
 impl PaymentAttemptUpdate {
     pub fn apply_changeset(self, source: PaymentAttempt) -> PaymentAttempt {
-        let PaymentAttemptUpdateInternal {
+        PaymentAttemptUpdateInternal::from(self)
+            .populate_derived_fields(&source)
+            .apply_changeset(source)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl PaymentAttemptUpdateInternal {
+    /// Applies the changeset to the payment attempt, in the same way the changeset is applied to the
+    /// row when updating it in the database. The derived fields are expected to be populated.
+    pub fn apply_changeset(self, source: PaymentAttempt) -> PaymentAttempt {
+        let Self {
             amount,
             net_amount,
             currency,
@@ -1004,7 +1087,7 @@ impl PaymentAttemptUpdate {
             card_network,
             shipping_cost,
             order_tax_amount,
-        } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
+        } = self;
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
             net_amount: net_amount.or(source.net_amount),
@@ -622,6 +622,64 @@ pub struct PaymentIntentUpdateInternal {
     pub tax_details: Option<TaxDetails>,
 }
 
+#[cfg(feature = "v1")]
+impl PaymentIntentUpdateInternal {
+    /// Combines two successive changesets of the same payment intent into one, with the fields
+    /// set by the later changeset taking precedence
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            amount: other.amount.or(self.amount),
+            currency: other.currency.or(self.currency),
+            status: other.status.or(self.status),
+            amount_captured: other.amount_captured.or(self.amount_captured),
+            customer_id: other.customer_id.or(self.customer_id),
+            return_url: other.return_url.or(self.return_url),
+            setup_future_usage: other.setup_future_usage.or(self.setup_future_usage),
+            off_session: other.off_session.or(self.off_session),
+            metadata: other.metadata.or(self.metadata),
+            billing_address_id: other.billing_address_id.or(self.billing_address_id),
+            shipping_address_id: other.shipping_address_id.or(self.shipping_address_id),
+            modified_at: other.modified_at,
+            active_attempt_id: other.active_attempt_id.or(self.active_attempt_id),
+            business_country: other.business_country.or(self.business_country),
+            business_label: other.business_label.or(self.business_label),
+            description: other.description.or(self.description),
+            statement_descriptor_name: other
+                .statement_descriptor_name
+                .or(self.statement_descriptor_name),
+            statement_descriptor_suffix: other
+                .statement_descriptor_suffix
+                .or(self.statement_descriptor_suffix),
+            order_details: other.order_details.or(self.order_details),
+            attempt_count: other.attempt_count.or(self.attempt_count),
+            merchant_decision: other.merchant_decision.or(self.merchant_decision),
+            payment_confirm_source: other.payment_confirm_source.or(self.payment_confirm_source),
+            updated_by: other.updated_by,
+            surcharge_applicable: other.surcharge_applicable.or(self.surcharge_applicable),
+            incremental_authorization_allowed: other
+                .incremental_authorization_allowed
+                .or(self.incremental_authorization_allowed),
+            authorization_count: other.authorization_count.or(self.authorization_count),
+            session_expiry: other.session_expiry.or(self.session_expiry),
+            fingerprint_id: other.fingerprint_id.or(self.fingerprint_id),
+            request_external_three_ds_authentication: other
+                .request_external_three_ds_authentication
+                .or(self.request_external_three_ds_authentication),
+            frm_metadata: other.frm_metadata.or(self.frm_metadata),
+            customer_details: other.customer_details.or(self.customer_details),
+            billing_details: other.billing_details.or(self.billing_details),
+            merchant_order_reference_id: other
+                .merchant_order_reference_id
+                .or(self.merchant_order_reference_id),
+            shipping_details: other.shipping_details.or(self.shipping_details),
+            is_payment_processor_token_flow: other
+                .is_payment_processor_token_flow
+                .or(self.is_payment_processor_token_flow),
+            tax_details: other.tax_details.or(self.tax_details),
+        }
+    }
+}
+
 #[cfg(feature = "v2")]
 impl PaymentIntentUpdate {
     pub fn apply_changeset(self, source: PaymentIntent) -> PaymentIntent {
@@ -696,7 +754,16 @@ impl PaymentIntentUpdate {
 #[cfg(feature = "v1")]
 impl PaymentIntentUpdate {
     pub fn apply_changeset(self, source: PaymentIntent) -> PaymentIntent {
-        let PaymentIntentUpdateInternal {
+        PaymentIntentUpdateInternal::from(self).apply_changeset(source)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl PaymentIntentUpdateInternal {
+    /// Applies the changeset to the payment intent, in the same way the changeset is applied to the
+    /// row when updating it in the database
+    pub fn apply_changeset(self, source: PaymentIntent) -> PaymentIntent {
+        let Self {
             amount,
             currency,
             status,
@@ -733,7 +800,7 @@ impl PaymentIntentUpdate {
             shipping_details,
             is_payment_processor_token_flow,
             tax_details,
-        } = self.into();
+        } = self;
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
             currency: currency.or(source.currency),
@@ -0,0 +1,91 @@
+use common_utils::{pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::plan};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = plan)]
+pub struct PlanNew {
+    pub plan_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub name: String,
+    pub description: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::BillingInterval,
+    pub billing_interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = plan, primary_key(plan_id), check_for_backend(diesel::pg::Pg))]
+pub struct Plan {
+    pub plan_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub name: String,
+    pub description: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::BillingInterval,
+    pub billing_interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The amount, currency and billing interval of a plan cannot be updated, since existing
+/// subscriptions are billed based on them. A new plan has to be created instead.
+#[derive(Debug)]
+pub enum PlanUpdate {
+    Update {
+        name: Option<String>,
+        description: Option<String>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+    Deactivate,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = plan)]
+pub struct PlanUpdateInternal {
+    name: Option<String>,
+    description: Option<String>,
+    metadata: Option<pii::SecretSerdeValue>,
+    is_active: Option<bool>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<PlanUpdate> for PlanUpdateInternal {
+    fn from(plan_update: PlanUpdate) -> Self {
+        match plan_update {
+            PlanUpdate::Update {
+                name,
+                description,
+                metadata,
+            } => Self {
+                name,
+                description,
+                metadata,
+                is_active: None,
+                modified_at: common_utils::date_time::now(),
+            },
+            PlanUpdate::Deactivate => Self {
+                name: None,
+                description: None,
+                metadata: None,
+                is_active: Some(false),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
@@ -38,6 +38,8 @@ pub struct ProcessTracker {
     pub created_at: PrimitiveDateTime,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub updated_at: PrimitiveDateTime,
+    pub cron: Option<String>,
+    pub catch_up_policy: Option<String>,
 }
 
 impl ProcessTracker {
@@ -63,6 +65,8 @@ pub struct ProcessTrackerNew {
     pub event: Vec<String>,
     pub created_at: PrimitiveDateTime,
     pub updated_at: PrimitiveDateTime,
+    pub cron: Option<String>,
+    pub catch_up_policy: Option<String>,
 }
 
 impl ProcessTrackerNew {
@@ -95,8 +99,18 @@ impl ProcessTrackerNew {
             event: vec![],
             created_at: current_time,
             updated_at: current_time,
+            cron: None,
+            catch_up_policy: None,
         })
     }
+
+    /// Makes the task recur on the schedule of the cron expression, with the missed occurrences
+    /// being handled as per the catch-up policy
+    pub fn with_cron(mut self, cron: impl Into<String>, catch_up_policy: CatchUpPolicy) -> Self {
+        self.cron = Some(cron.into());
+        self.catch_up_policy = Some(catch_up_policy.to_string());
+        self
+    }
 }
 
 #[derive(Debug)]
@@ -210,6 +224,34 @@ pub enum ProcessTrackerRunner {
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
+    SubscriptionBillingWorkflow,
+    DisputeSyncWorkflow,
+}
+
+/// Handling of the occurrences of a recurring task which were missed, such as when the scheduler
+/// was down at the time of the occurrence
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    strum::EnumString,
+    strum::Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum CatchUpPolicy {
+    /// The missed occurrences are dropped, and the task runs at its next occurrence
+    Skip,
+    /// The task runs once in place of all the missed occurrences
+    #[default]
+    RunOnce,
+    /// The task runs for every missed occurrence, one after the other
+    RunAll,
 }
 
 #[cfg(test)]
@@ -265,4 +307,9 @@ pub mod business_status {
 
     /// Business status set for newly created tasks.
     pub const PENDING: &str = "Pending";
+
+    /// The occurrence of a recurring task was missed, and was not run as per the catch-up policy
+    /// of the task.
+    /// The next occurrence of the task is scheduled in its place.
+    pub const MISSED_OCCURRENCE_SKIPPED: &str = "MISSED_OCCURRENCE_SKIPPED";
 }
@@ -5,6 +5,7 @@ pub mod business_profile;
 mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_fee_schedule;
 
 pub mod authentication;
 pub mod authorization;
@@ -16,9 +17,11 @@ pub mod dispute;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod fx_rate_snapshot;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
+pub mod incoming_webhook_event;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -31,13 +34,17 @@ pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
+pub mod plan;
 pub mod process_tracker;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
+pub mod routing_activation_history;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
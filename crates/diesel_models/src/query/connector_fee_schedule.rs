@@ -0,0 +1,85 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::report;
+
+use super::generics;
+use crate::{
+    connector_fee_schedule::{
+        ConnectorFeeSchedule, ConnectorFeeScheduleNew, ConnectorFeeScheduleUpdate,
+        ConnectorFeeScheduleUpdateInternal,
+    },
+    errors,
+    schema::connector_fee_schedule::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ConnectorFeeScheduleNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ConnectorFeeSchedule> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ConnectorFeeSchedule {
+    pub async fn find_by_merchant_id_merchant_connector_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merchant_connector_id.eq(merchant_connector_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id.eq(profile_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_merchant_connector_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        connector_fee_schedule: ConnectorFeeScheduleUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merchant_connector_id.eq(merchant_connector_id.to_owned())),
+            ConnectorFeeScheduleUpdateInternal::from(connector_fee_schedule),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Error while updating connector fee schedule")
+        })
+    }
+
+    pub async fn delete_by_merchant_id_merchant_connector_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merchant_connector_id.eq(merchant_connector_id.to_owned())),
+        )
+        .await
+    }
+}
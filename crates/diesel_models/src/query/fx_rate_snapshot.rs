@@ -0,0 +1,27 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    fx_rate_snapshot::{FxRateSnapshot, FxRateSnapshotNew},
+    schema::fx_rate_snapshot::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl FxRateSnapshotNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<FxRateSnapshot> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl FxRateSnapshot {
+    pub async fn find_by_snapshot_id(
+        conn: &PgPooledConn,
+        snapshot_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::snapshot_id.eq(snapshot_id.to_owned()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,112 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::report;
+
+use super::generics;
+use crate::{
+    errors,
+    incoming_webhook_event::{
+        IncomingWebhookEvent, IncomingWebhookEventNew, IncomingWebhookEventUpdate,
+        IncomingWebhookEventUpdateInternal,
+    },
+    schema::incoming_webhook_event::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl IncomingWebhookEventNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<IncomingWebhookEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl IncomingWebhookEvent {
+    pub async fn find_by_merchant_id_webhook_event_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_event_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::webhook_event_id.eq(webhook_event_id.to_owned())),
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector_name_or_mca_id: Option<String>,
+        status: Option<crate::enums::IncomingWebhookEventStatus>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(connector_name_or_mca_id) = connector_name_or_mca_id {
+            query = query.filter(dsl::connector_name_or_mca_id.eq(connector_name_or_mca_id));
+        }
+
+        if let Some(status) = status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        if let Some(created_after) = created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering incoming webhook events by constraints")
+    }
+
+    pub async fn update_by_merchant_id_webhook_event_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_event_id: &str,
+        incoming_webhook_event_update: IncomingWebhookEventUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::webhook_event_id.eq(webhook_event_id.to_owned())),
+            IncomingWebhookEventUpdateInternal::from(incoming_webhook_event_update),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Error while updating incoming webhook event")
+        })
+    }
+}
@@ -26,6 +26,21 @@ impl PaymentAttemptNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentAttempt> {
         generics::generic_insert(conn, self.populate_derived_fields()).await
     }
+
+    pub async fn batch_insert(
+        payment_attempts: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PaymentAttempt>(
+            conn,
+            payment_attempts
+                .into_iter()
+                .map(Self::populate_derived_fields)
+                .collect::<Vec<_>>(),
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 impl PaymentAttempt {
@@ -34,6 +49,19 @@ impl PaymentAttempt {
         self,
         conn: &PgPooledConn,
         payment_attempt: PaymentAttemptUpdate,
+    ) -> StorageResult<Self> {
+        let payment_attempt =
+            PaymentAttemptUpdateInternal::from(payment_attempt).populate_derived_fields(&self);
+        self.update_internal_with_attempt_id(conn, payment_attempt)
+            .await
+    }
+
+    /// Updates the payment attempt with a changeset whose derived fields are already populated
+    #[cfg(feature = "v1")]
+    pub async fn update_internal_with_attempt_id(
+        self,
+        conn: &PgPooledConn,
+        payment_attempt: PaymentAttemptUpdateInternal,
     ) -> StorageResult<Self> {
         match generics::generic_update_with_unique_predicate_get_result::<
             <Self as HasTable>::Table,
@@ -45,7 +73,7 @@ impl PaymentAttempt {
             dsl::attempt_id
                 .eq(self.attempt_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            PaymentAttemptUpdateInternal::from(payment_attempt).populate_derived_fields(&self),
+            payment_attempt,
         )
         .await
         {
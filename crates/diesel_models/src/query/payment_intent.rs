@@ -17,6 +17,14 @@ impl PaymentIntentNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentIntent> {
         generics::generic_insert(conn, self).await
     }
+
+    pub async fn batch_insert(
+        payment_intents: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PaymentIntent>(conn, payment_intents).await?;
+        Ok(())
+    }
 }
 
 impl PaymentIntent {
@@ -54,13 +62,23 @@ impl PaymentIntent {
         self,
         conn: &PgPooledConn,
         payment_intent: PaymentIntentUpdate,
+    ) -> StorageResult<Self> {
+        self.update_internal(conn, PaymentIntentUpdateInternal::from(payment_intent))
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn update_internal(
+        self,
+        conn: &PgPooledConn,
+        payment_intent: PaymentIntentUpdateInternal,
     ) -> StorageResult<Self> {
         match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
             conn,
             dsl::payment_id
                 .eq(self.payment_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            PaymentIntentUpdateInternal::from(payment_intent),
+            payment_intent,
         )
         .await
         {
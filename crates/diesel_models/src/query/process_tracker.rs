@@ -1,5 +1,7 @@
+use async_bb8_diesel::AsyncConnection;
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use router_env::{instrument, tracing};
+use error_stack::report;
+use router_env::{instrument, logger, tracing};
 use time::PrimitiveDateTime;
 
 use super::generics;
@@ -41,6 +43,35 @@ impl ProcessTracker {
         }
     }
 
+    /// Updates the process and inserts the next occurrence of the recurring task in a single
+    /// transaction, returning the next occurrence
+    #[instrument(skip(conn))]
+    pub async fn update_and_insert_next_occurrence(
+        self,
+        conn: &PgPooledConn,
+        process: ProcessTrackerUpdate,
+        next_occurrence: ProcessTrackerNew,
+    ) -> StorageResult<Self> {
+        conn.transaction_async(|conn| async move {
+            self.update(&conn, process).await.map_err(|error| {
+                logger::error!(?error, "Failed to update the process");
+                *error.current_context()
+            })?;
+            next_occurrence
+                .insert_process(&conn)
+                .await
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to insert the next occurrence of the process"
+                    );
+                    *error.current_context()
+                })
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
     #[instrument(skip(conn))]
     pub async fn update_process_status_by_ids(
         conn: &PgPooledConn,
@@ -64,6 +95,30 @@ impl ProcessTracker {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_unfinished_recurring_process_by_name(
+        conn: &PgPooledConn,
+        name: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::name
+                .eq(name.to_owned())
+                .and(dsl::cron.is_not_null())
+                .and(dsl::status.ne(enums::ProcessTrackerStatus::Finish)),
+            Some(1),
+            None,
+            None,
+        )
+        .await
+        .map(|processes| processes.into_iter().next())
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_processes_by_time_status(
         conn: &PgPooledConn,
@@ -0,0 +1,36 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums,
+    routing_activation_history::{RoutingActivationHistory, RoutingActivationHistoryNew},
+    schema::routing_activation_history::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RoutingActivationHistoryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RoutingActivationHistory> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RoutingActivationHistory {
+    pub async fn list_by_profile_id_algorithm_for(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        algorithm_for: &enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::algorithm_for.eq(algorithm_for.to_owned())),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+}
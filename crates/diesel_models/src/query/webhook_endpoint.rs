@@ -0,0 +1,87 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::report;
+
+use super::generics;
+use crate::{
+    errors,
+    schema::webhook_endpoint::dsl,
+    webhook_endpoint::{
+        WebhookEndpoint, WebhookEndpointNew, WebhookEndpointUpdate, WebhookEndpointUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl WebhookEndpointNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookEndpoint> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookEndpoint {
+    pub async fn find_by_merchant_id_endpoint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_profile_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::profile_id.eq(profile_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_endpoint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        webhook_endpoint: WebhookEndpointUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+            WebhookEndpointUpdateInternal::from(webhook_endpoint),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Error while updating webhook endpoint")
+        })
+    }
+
+    pub async fn delete_by_merchant_id_endpoint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+        )
+        .await
+    }
+}
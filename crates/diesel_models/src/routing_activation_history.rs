@@ -0,0 +1,35 @@
+use common_utils::id_type;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::routing_activation_history};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = routing_activation_history)]
+pub struct RoutingActivationHistoryNew {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub algorithm_id: Option<id_type::RoutingId>,
+    pub previous_algorithm_id: Option<id_type::RoutingId>,
+    pub algorithm_for: enums::TransactionType,
+    pub activation_type: enums::RoutingActivationType,
+    pub activated_by: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = routing_activation_history, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct RoutingActivationHistory {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub algorithm_id: Option<id_type::RoutingId>,
+    pub previous_algorithm_id: Option<id_type::RoutingId>,
+    pub algorithm_for: enums::TransactionType,
+    pub activation_type: enums::RoutingActivationType,
+    pub activated_by: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
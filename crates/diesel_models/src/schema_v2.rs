@@ -55,6 +55,10 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        permissions -> Nullable<Array<Nullable<Text>>>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        ip_allowlist -> Nullable<Array<Nullable<Text>>>,
     }
 }
 
@@ -294,6 +298,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_fee_schedule (merchant_connector_id) {
+        #[max_length = 64]
+        merchant_connector_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        fee_rules -> Jsonb,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -415,7 +436,7 @@ diesel::table! {
         #[max_length = 64]
         business_profile_id -> Nullable<Varchar>,
         primary_object_created_at -> Nullable<Timestamp>,
-        #[max_length = 64]
+        #[max_length = 255]
         idempotent_event_id -> Nullable<Varchar>,
         #[max_length = 64]
         initial_attempt_id -> Nullable<Varchar>,
@@ -423,6 +444,8 @@ diesel::table! {
         response -> Nullable<Bytea>,
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
     }
 }
 
@@ -488,6 +511,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    fx_rate_snapshot (snapshot_id) {
+        #[max_length = 64]
+        snapshot_id -> Varchar,
+        #[max_length = 64]
+        provider -> Varchar,
+        base_currency -> Currency,
+        rates -> Jsonb,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -541,6 +579,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incoming_webhook_event (webhook_event_id) {
+        #[max_length = 64]
+        webhook_event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector_name_or_mca_id -> Varchar,
+        request_details -> Bytea,
+        request_body -> Bytea,
+        #[max_length = 32]
+        status -> Varchar,
+        webhook_effect -> Nullable<Jsonb>,
+        error_message -> Nullable<Text>,
+        replay_count -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1054,6 +1115,32 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    plan (plan_id) {
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 32]
+        billing_interval -> Varchar,
+        billing_interval_count -> Int4,
+        trial_period_days -> Nullable<Int4>,
+        metadata -> Nullable<Jsonb>,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1077,6 +1164,10 @@ diesel::table! {
         event -> Array<Nullable<Text>>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        #[max_length = 255]
+        cron -> Nullable<Varchar>,
+        #[max_length = 32]
+        catch_up_policy -> Nullable<Varchar>,
     }
 }
 
@@ -1178,6 +1269,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_activation_history (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        algorithm_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        previous_algorithm_id -> Nullable<Varchar>,
+        algorithm_for -> TransactionType,
+        #[max_length = 32]
+        activation_type -> Varchar,
+        #[max_length = 255]
+        activated_by -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1201,6 +1316,41 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription (subscription_id) {
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 64]
+        mandate_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        current_period_start -> Timestamp,
+        current_period_end -> Timestamp,
+        trial_end -> Nullable<Timestamp>,
+        cancel_at_period_end -> Bool,
+        cancelled_at -> Nullable<Timestamp>,
+        failed_payment_count -> Int4,
+        #[max_length = 64]
+        latest_payment_id -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        billing_anchor -> Timestamp,
+        billed_period_count -> Int4,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1309,6 +1459,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint (endpoint_id) {
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        url -> Text,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        #[max_length = 255]
+        secret -> Varchar,
+        enabled_events -> Array<Nullable<EventType>>,
+        is_enabled -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
@@ -1320,14 +1493,17 @@ diesel::allow_tables_to_appear_in_same_query!(
     captures,
     cards_info,
     configs,
+    connector_fee_schedule,
     customers,
     dashboard_metadata,
     dispute,
     events,
     file_metadata,
     fraud_check,
+    fx_rate_snapshot,
     gateway_status_map,
     generic_link,
+    incoming_webhook_event,
     incremental_authorization,
     locker_mock_up,
     mandate,
@@ -1341,14 +1517,18 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_methods,
     payout_attempt,
     payouts,
+    plan,
     process_tracker,
     refund,
     reverse_lookup,
     roles,
+    routing_activation_history,
     routing_algorithm,
+    subscription,
     unified_translations,
     user_authentication_methods,
     user_key_store,
     user_roles,
     users,
+    webhook_endpoint,
 );
@@ -0,0 +1,187 @@
+use common_utils::pii;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::subscription};
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub plan_id: String,
+    pub mandate_id: String,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub status: storage_enums::SubscriptionStatus,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: bool,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub failed_payment_count: i32,
+    pub latest_payment_id: Option<common_utils::id_type::PaymentId>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub billing_anchor: PrimitiveDateTime,
+    pub billed_period_count: i32,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = subscription, primary_key(subscription_id), check_for_backend(diesel::pg::Pg))]
+pub struct Subscription {
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub plan_id: String,
+    pub mandate_id: String,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub status: storage_enums::SubscriptionStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_end: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub failed_payment_count: i32,
+    pub latest_payment_id: Option<common_utils::id_type::PaymentId>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    /// The time from which all billing periods of the subscription are computed
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub billing_anchor: PrimitiveDateTime,
+    /// The number of billing periods which have been paid for since the billing anchor
+    pub billed_period_count: i32,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionUpdate {
+    Update {
+        mandate_id: Option<String>,
+        cancel_at_period_end: Option<bool>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+    /// The payment for a billing period has succeeded and the subscription moves to the next period
+    RenewalUpdate {
+        current_period_start: PrimitiveDateTime,
+        current_period_end: PrimitiveDateTime,
+        billed_period_count: i32,
+        latest_payment_id: Option<common_utils::id_type::PaymentId>,
+    },
+    /// The payment for the current billing period has failed
+    PaymentFailureUpdate {
+        status: storage_enums::SubscriptionStatus,
+        failed_payment_count: i32,
+        latest_payment_id: Option<common_utils::id_type::PaymentId>,
+    },
+    StatusUpdate {
+        status: storage_enums::SubscriptionStatus,
+        cancelled_at: Option<PrimitiveDateTime>,
+    },
+    /// A payment for the current billing period has been created, but its outcome is not known yet
+    LatestPaymentUpdate {
+        latest_payment_id: common_utils::id_type::PaymentId,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionUpdateInternal {
+    mandate_id: Option<String>,
+    status: Option<storage_enums::SubscriptionStatus>,
+    current_period_start: Option<PrimitiveDateTime>,
+    current_period_end: Option<PrimitiveDateTime>,
+    cancel_at_period_end: Option<bool>,
+    cancelled_at: Option<PrimitiveDateTime>,
+    failed_payment_count: Option<i32>,
+    latest_payment_id: Option<common_utils::id_type::PaymentId>,
+    metadata: Option<pii::SecretSerdeValue>,
+    modified_at: PrimitiveDateTime,
+    billed_period_count: Option<i32>,
+}
+
+impl Default for SubscriptionUpdateInternal {
+    fn default() -> Self {
+        Self {
+            mandate_id: None,
+            status: None,
+            current_period_start: None,
+            current_period_end: None,
+            cancel_at_period_end: None,
+            cancelled_at: None,
+            failed_payment_count: None,
+            latest_payment_id: None,
+            metadata: None,
+            modified_at: common_utils::date_time::now(),
+            billed_period_count: None,
+        }
+    }
+}
+
+impl From<SubscriptionUpdate> for SubscriptionUpdateInternal {
+    fn from(subscription_update: SubscriptionUpdate) -> Self {
+        match subscription_update {
+            SubscriptionUpdate::Update {
+                mandate_id,
+                cancel_at_period_end,
+                metadata,
+            } => Self {
+                mandate_id,
+                cancel_at_period_end,
+                metadata,
+                ..Default::default()
+            },
+            SubscriptionUpdate::RenewalUpdate {
+                current_period_start,
+                current_period_end,
+                billed_period_count,
+                latest_payment_id,
+            } => Self {
+                status: Some(storage_enums::SubscriptionStatus::Active),
+                current_period_start: Some(current_period_start),
+                current_period_end: Some(current_period_end),
+                billed_period_count: Some(billed_period_count),
+                failed_payment_count: Some(0),
+                latest_payment_id,
+                ..Default::default()
+            },
+            SubscriptionUpdate::PaymentFailureUpdate {
+                status,
+                failed_payment_count,
+                latest_payment_id,
+            } => Self {
+                status: Some(status),
+                failed_payment_count: Some(failed_payment_count),
+                latest_payment_id,
+                ..Default::default()
+            },
+            SubscriptionUpdate::StatusUpdate {
+                status,
+                cancelled_at,
+            } => Self {
+                status: Some(status),
+                cancelled_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::LatestPaymentUpdate { latest_payment_id } => Self {
+                latest_payment_id: Some(latest_payment_id),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SubscriptionBillingTrackingData {
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -0,0 +1,87 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::webhook_endpoint};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointNew {
+    pub endpoint_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub url: Secret<String>,
+    pub description: Option<String>,
+    pub secret: Secret<String>,
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub is_enabled: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = webhook_endpoint, primary_key(endpoint_id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub url: Secret<String>,
+    pub description: Option<String>,
+    pub secret: Secret<String>,
+    #[diesel(deserialize_as = super::DieselArray<storage_enums::EventType>)]
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub is_enabled: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl WebhookEndpoint {
+    /// Whether the endpoint is enabled and subscribed to the specified event type.
+    pub fn is_subscribed_to(&self, event_type: storage_enums::EventType) -> bool {
+        self.is_enabled && self.enabled_events.contains(&event_type)
+    }
+}
+
+#[derive(Debug)]
+pub struct WebhookEndpointUpdate {
+    pub url: Option<Secret<String>>,
+    pub description: Option<String>,
+    pub secret: Option<Secret<String>>,
+    pub enabled_events: Option<Vec<storage_enums::EventType>>,
+    pub is_enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointUpdateInternal {
+    url: Option<Secret<String>>,
+    description: Option<String>,
+    secret: Option<Secret<String>>,
+    enabled_events: Option<Vec<storage_enums::EventType>>,
+    is_enabled: Option<bool>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(webhook_endpoint_update: WebhookEndpointUpdate) -> Self {
+        let WebhookEndpointUpdate {
+            url,
+            description,
+            secret,
+            enabled_events,
+            is_enabled,
+        } = webhook_endpoint_update;
+
+        Self {
+            url,
+            description,
+            secret,
+            enabled_events,
+            is_enabled,
+            modified_at: common_utils::date_time::now(),
+        }
+    }
+}
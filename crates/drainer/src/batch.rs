@@ -0,0 +1,378 @@
+//! Draining of consecutive entries of a stream in a single transaction
+//!
+//! Consecutive insertions into the same table are combined into a multi-row insert, and
+//! consecutive updates to the same payment intent or payment attempt are coalesced into a single
+//! update. When the transaction fails, the entries are drained one by one instead, so that the
+//! failing entry can be retried or moved to the dead letter stream on its own.
+
+use std::{collections::HashMap, sync::Arc};
+
+use async_bb8_diesel::AsyncConnection;
+use diesel_models::errors::DatabaseError;
+
+use crate::{
+    kv, logger, metrics, pg_connection, query::push_drainer_delay, services::Store,
+    stream::StreamEntries, StreamData,
+};
+
+/// Consecutive operations of the stream executed as a single query
+enum OperationGroup {
+    Single(kv::DBOperation),
+    Batched(kv::BatchedOperation),
+}
+
+impl OperationGroup {
+    fn operation<'a>(&self) -> &'a str {
+        match self {
+            Self::Single(operation) => operation.operation(),
+            Self::Batched(batch) => batch.operation(),
+        }
+    }
+
+    fn table<'a>(&self) -> &'a str {
+        match self {
+            Self::Single(operation) => operation.table(),
+            Self::Batched(batch) => batch.table(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Single(_) => 1,
+            Self::Batched(batch) => batch.len(),
+        }
+    }
+
+    async fn execute(self, conn: &diesel_models::PgPooledConn) -> diesel_models::StorageResult<()> {
+        match self {
+            Self::Single(operation) => operation.execute(conn).await.map(|_| ()),
+            Self::Batched(batch) => batch.execute(conn).await,
+        }
+    }
+}
+
+/// Groups the consecutive operations which can be executed as a single query, returning `None`
+/// when a group could not be combined
+fn group_operations(operations: Vec<kv::DBOperation>) -> Option<Vec<OperationGroup>> {
+    let mut groups = Vec::new();
+    let mut current = Vec::<kv::DBOperation>::new();
+
+    for operation in operations {
+        let is_batchable = current
+            .last()
+            .is_some_and(|previous| operation.is_batchable_with(previous));
+        if !is_batchable && !current.is_empty() {
+            groups.push(into_group(std::mem::take(&mut current))?);
+        }
+        current.push(operation);
+    }
+    if !current.is_empty() {
+        groups.push(into_group(current)?);
+    }
+
+    Some(groups)
+}
+
+fn into_group(mut operations: Vec<kv::DBOperation>) -> Option<OperationGroup> {
+    if operations.len() == 1 {
+        operations.pop().map(OperationGroup::Single)
+    } else {
+        kv::BatchedOperation::from_operations(operations).map(OperationGroup::Batched)
+    }
+}
+
+/// Deserializes the entries at the start of the read batch, stopping at the first entry which
+/// cannot be deserialized
+fn get_leading_stream_data(entries: &StreamEntries) -> Vec<StreamData> {
+    entries
+        .iter()
+        .map_while(|(_, entry)| StreamData::from_hashmap(entry.clone()).ok())
+        .collect()
+}
+
+/// Skips the entries drained in the transaction, returning the ID of the last drained entry along
+/// with the entries which are left to be drained one by one
+pub(crate) fn skip_drained_entries(
+    entries: &StreamEntries,
+    drained_count: usize,
+) -> (
+    Option<&String>,
+    std::slice::Iter<'_, (String, HashMap<String, String>)>,
+) {
+    let mut remaining_entries = entries.iter();
+    let last_drained_id = drained_count
+        .checked_sub(1)
+        .and_then(|index| remaining_entries.nth(index))
+        .map(|(entry_id, _)| entry_id);
+
+    (last_drained_id, remaining_entries)
+}
+
+/// Drains the entries at the start of the read batch in a single transaction, returning the
+/// number of entries drained. Returns zero when the entries are to be drained one by one, either
+/// because none of them could be combined or because the transaction failed.
+///
+/// Draining stops at the first entry which cannot be deserialized, leaving it to be handled when
+/// draining the remaining entries one by one.
+pub(crate) async fn drain_in_transaction(store: &Arc<Store>, entries: &StreamEntries) -> usize {
+    let stream_data = get_leading_stream_data(entries);
+    let drained_count = stream_data.len();
+
+    let mut delays = Vec::with_capacity(drained_count);
+    let mut operations = Vec::with_capacity(drained_count);
+    for data in stream_data {
+        delays.push((
+            data.pushed_at,
+            data.typed_sql.operation(),
+            data.typed_sql.table(),
+        ));
+        operations.push(data.typed_sql);
+    }
+
+    let Some(groups) = group_operations(operations) else {
+        return 0;
+    };
+    if groups.len() == drained_count {
+        // None of the entries could be combined, draining them one by one is just as fast
+        return 0;
+    }
+
+    let executed_groups = groups
+        .iter()
+        .map(|group| (group.operation(), group.table(), group.len()))
+        .collect::<Vec<_>>();
+
+    let conn = pg_connection(&store.master_pool).await;
+    let (result, execution_time) = Box::pin(common_utils::date_time::time_it(|| {
+        conn.transaction_async(|conn| async move {
+            for group in groups {
+                group.execute(&conn).await.map_err(|error| {
+                    logger::error!(?error, "Failed to execute the query in the transaction");
+                    *error.current_context()
+                })?;
+            }
+            Ok::<_, DatabaseError>(())
+        })
+    }))
+    .await;
+
+    metrics::QUERY_EXECUTION_TIME.record(
+        &metrics::CONTEXT,
+        execution_time,
+        &[metrics::KeyValue {
+            key: "operation".into(),
+            value: "transaction".into(),
+        }],
+    );
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            entries = drained_count,
+            "Failed to drain the entries in a transaction, draining them one by one"
+        );
+        metrics::BATCH_FALLBACKS.add(&metrics::CONTEXT, 1, &[]);
+        return 0;
+    }
+
+    for (operation, table, count) in executed_groups {
+        let tags: &[metrics::KeyValue] = &[
+            metrics::KeyValue {
+                key: "operation".into(),
+                value: operation.into(),
+            },
+            metrics::KeyValue {
+                key: "table".into(),
+                value: table.into(),
+            },
+        ];
+        let count = u64::try_from(count).unwrap_or(u64::MIN);
+
+        logger::info!(operation = operation, table = table, entries = count);
+        metrics::SUCCESSFUL_QUERY_EXECUTION.add(&metrics::CONTEXT, 1, tags);
+        if count > 1 {
+            metrics::BATCHED_ENTRIES.add(&metrics::CONTEXT, count, tags);
+            metrics::BATCH_SIZE.record(&metrics::CONTEXT, count, tags);
+        }
+    }
+
+    for (pushed_at, operation, table) in delays {
+        let tags: &[metrics::KeyValue] = &[
+            metrics::KeyValue {
+                key: "operation".into(),
+                value: operation.into(),
+            },
+            metrics::KeyValue {
+                key: "table".into(),
+                value: table.into(),
+            },
+        ];
+        push_drainer_delay(pushed_at, operation, table, tags);
+    }
+
+    drained_count
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use diesel_models::reverse_lookup::ReverseLookupNew;
+
+    use super::*;
+
+    fn get_reverse_lookup_insert(lookup_id: &str) -> kv::DBOperation {
+        kv::DBOperation::Insert {
+            insertable: kv::Insertable::ReverseLookUp(ReverseLookupNew {
+                lookup_id: lookup_id.to_string(),
+                pk_id: format!("pk_{lookup_id}"),
+                sk_id: format!("sk_{lookup_id}"),
+                source: "payment_attempt".to_string(),
+                updated_by: "redis_kv".to_string(),
+            }),
+        }
+    }
+
+    #[cfg(feature = "v1")]
+    fn get_customer_delete(customer_id: &'static str) -> kv::DBOperation {
+        let now = common_utils::date_time::now();
+        kv::DBOperation::Delete {
+            deletable: kv::Deletable::Customer(kv::CustomerDeleteMems {
+                orig: diesel_models::Customer {
+                    customer_id: common_utils::id_type::CustomerId::try_from(
+                        std::borrow::Cow::from(customer_id),
+                    )
+                    .unwrap(),
+                    merchant_id: common_utils::id_type::MerchantId::try_from(
+                        std::borrow::Cow::from("merchant_1"),
+                    )
+                    .unwrap(),
+                    name: None,
+                    email: None,
+                    phone: None,
+                    phone_country_code: None,
+                    description: None,
+                    created_at: now,
+                    metadata: None,
+                    connector_customer: None,
+                    modified_at: now,
+                    address_id: None,
+                    default_payment_method_id: None,
+                    updated_by: Some("redis_kv".to_string()),
+                    version: diesel_models::enums::ApiVersion::V1,
+                },
+            }),
+        }
+    }
+
+    fn get_stream_entry(entry_id: &str, lookup_id: &str) -> (String, HashMap<String, String>) {
+        get_stream_entry_of(entry_id, &get_reverse_lookup_insert(lookup_id))
+    }
+
+    fn get_stream_entry_of(
+        entry_id: &str,
+        operation: &kv::DBOperation,
+    ) -> (String, HashMap<String, String>) {
+        let entry = HashMap::from([
+            ("request_id".to_string(), "req_1".to_string()),
+            ("global_id".to_string(), "mid_merchant_1".to_string()),
+            (
+                "typed_sql".to_string(),
+                serde_json::to_string(operation).unwrap(),
+            ),
+            ("pushed_at".to_string(), "1729000000".to_string()),
+        ]);
+        (entry_id.to_string(), entry)
+    }
+
+    #[test]
+    fn test_leading_stream_data_stops_at_invalid_entry() {
+        let mut entries = vec![
+            get_stream_entry("1-0", "lookup_1"),
+            get_stream_entry("2-0", "lookup_2"),
+            get_stream_entry("3-0", "lookup_3"),
+            get_stream_entry("4-0", "lookup_4"),
+        ];
+        if let Some((_, entry)) = entries.get_mut(2) {
+            entry.insert("typed_sql".to_string(), "{}".to_string());
+        }
+
+        assert_eq!(get_leading_stream_data(&entries).len(), 2);
+    }
+
+    #[test]
+    fn test_grouped_operations_cover_every_entry_once() {
+        let operations = vec![
+            get_reverse_lookup_insert("lookup_1"),
+            get_reverse_lookup_insert("lookup_2"),
+            get_reverse_lookup_insert("lookup_3"),
+        ];
+
+        let groups = group_operations(operations).unwrap();
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups.iter().map(OperationGroup::len).sum::<usize>(), 3);
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_deletes_are_drained_on_their_own() {
+        let entries = vec![
+            get_stream_entry("1-0", "lookup_1"),
+            get_stream_entry_of("2-0", &get_customer_delete("cus_1")),
+            get_stream_entry_of("3-0", &get_customer_delete("cus_2")),
+            get_stream_entry("4-0", "lookup_2"),
+        ];
+
+        let stream_data = get_leading_stream_data(&entries);
+        assert_eq!(stream_data.len(), 4);
+
+        let groups =
+            group_operations(stream_data.into_iter().map(|data| data.typed_sql).collect()).unwrap();
+
+        // Deletions are neither combined with each other nor with the inserts around them, each
+        // of them being applied in the order it was pushed in
+        assert_eq!(
+            groups
+                .iter()
+                .map(OperationGroup::operation)
+                .collect::<Vec<_>>(),
+            vec!["insert", "delete", "delete", "insert"]
+        );
+        assert!(groups
+            .iter()
+            .all(|group| matches!(group, OperationGroup::Single(_))));
+    }
+
+    #[test]
+    fn test_failed_transaction_drains_every_entry_one_by_one() {
+        let entries = vec![
+            get_stream_entry("1-0", "lookup_1"),
+            get_stream_entry("2-0", "lookup_2"),
+            get_stream_entry("3-0", "lookup_3"),
+        ];
+
+        // A failed transaction drains nothing, leaving every entry to be drained on its own
+        let (last_drained_id, remaining_entries) = skip_drained_entries(&entries, 0);
+        assert_eq!(last_drained_id, None);
+        assert_eq!(
+            remaining_entries
+                .map(|(entry_id, _)| entry_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["1-0", "2-0", "3-0"]
+        );
+
+        // The entries drained in the transaction are neither drained again nor skipped
+        let (last_drained_id, remaining_entries) = skip_drained_entries(&entries, 2);
+        assert_eq!(last_drained_id.map(String::as_str), Some("2-0"));
+        assert_eq!(
+            remaining_entries
+                .map(|(entry_id, _)| entry_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["3-0"]
+        );
+
+        let (last_drained_id, mut remaining_entries) = skip_drained_entries(&entries, 3);
+        assert_eq!(last_drained_id.map(String::as_str), Some("3-0"));
+        assert!(remaining_entries.next().is_none());
+    }
+}
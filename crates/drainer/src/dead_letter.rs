@@ -0,0 +1,568 @@
+//! Dead letter streams of the drainer
+//!
+//! An entry which cannot be deserialized or whose query fails is retried on the following passes
+//! over its stream, up to the configured number of attempts. Transient failures of the database,
+//! such as deadlocks or closed connections, do not count as attempts. Once it has exhausted its
+//! attempts, it is moved to the dead letter stream of its shard along with the last error, so that
+//! the entries after it can be drained. Dead lettered entries can be inspected, re-driven into the
+//! stream of their shard or discarded through the endpoints of the drainer server. The entries
+//! hold the full queries of their writes, so these endpoints require the admin API key.
+
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use masking::{PeekInterface, Secret};
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    errors::{self, DeadLetterError},
+    metrics,
+    services::{self, Store},
+    Settings, StreamData,
+};
+
+/// Retry counts expire in case their entry is removed from the stream by other means
+const RETRY_COUNT_EXPIRY_SECONDS: i64 = 24 * 60 * 60;
+
+/// Re-drive markers outlive the retries of a re-drive request which failed halfway
+const REDRIVE_MARKER_EXPIRY_SECONDS: i64 = 24 * 60 * 60;
+
+const DEFAULT_LIST_COUNT: u64 = 20;
+
+const API_KEY_HEADER: &str = "api-key";
+
+const FIELD_PREFIX: &str = "dead_letter_";
+const SOURCE_ENTRY_ID_FIELD: &str = "dead_letter_source_entry_id";
+const ERROR_FIELD: &str = "dead_letter_error";
+const REASON_FIELD: &str = "dead_letter_reason";
+const RETRY_COUNT_FIELD: &str = "dead_letter_retry_count";
+const FAILED_AT_FIELD: &str = "dead_letter_failed_at";
+
+/// The reason an entry failed to be drained
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum FailureReason {
+    Deserialization,
+    QueryExecution,
+}
+
+impl FailureReason {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Deserialization => "deserialization",
+            Self::QueryExecution => "query_execution",
+        }
+    }
+}
+
+impl Store {
+    #[inline(always)]
+    pub(crate) fn get_dead_letter_stream_name(&self, stream_index: u8) -> String {
+        // Example: {shard_5}_drainer_dead_letter_stream
+        format!(
+            "{{shard_{stream_index}}}_{}",
+            self.config.dead_letter_stream_name
+        )
+    }
+
+    #[inline(always)]
+    fn get_retry_count_key(stream_name: &str, entry_id: &str) -> String {
+        format!("{stream_name}_retry_count_{entry_id}")
+    }
+
+    #[inline(always)]
+    fn get_redrive_marker_key(dead_letter_stream_name: &str, entry_id: &str) -> String {
+        format!("{dead_letter_stream_name}_redriven_{entry_id}")
+    }
+
+    /// Records a failed attempt at draining the entry, and moves the entry to the dead letter
+    /// stream once it has exhausted its attempts. Returns whether the entry was moved, in which
+    /// case it can be removed from the stream.
+    pub(crate) async fn record_failed_attempt(
+        &self,
+        stream_name: &str,
+        dead_letter_stream_name: &str,
+        (entry_id, entry): (&str, &HashMap<String, String>),
+        reason: FailureReason,
+        error: String,
+    ) -> errors::DrainerResult<bool> {
+        let retry_count_key = Self::get_retry_count_key(stream_name, entry_id);
+
+        let retry_count = self
+            .redis_conn
+            .increment_fields_in_hash(&retry_count_key, &[("retry_count", 1)])
+            .await
+            .map_err(errors::DrainerError::from)?
+            .first()
+            .copied()
+            .unwrap_or_default();
+        self.redis_conn
+            .set_expiry(&retry_count_key, RETRY_COUNT_EXPIRY_SECONDS)
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        let tags = &[
+            metrics::KeyValue::new("stream", stream_name.to_owned()),
+            metrics::KeyValue::new("reason", reason.as_str()),
+        ];
+        metrics::ENTRY_RETRIES.add(&metrics::CONTEXT, 1, tags);
+
+        if !has_exhausted_retries(retry_count, self.config.drainer_max_retry_count) {
+            return Ok(false);
+        }
+
+        let dead_letter_entry = build_dead_letter_entry(
+            (entry_id, entry),
+            reason,
+            error,
+            retry_count,
+            common_utils::date_time::now_unix_timestamp(),
+        );
+
+        self.redis_conn
+            .stream_append_entry(
+                dead_letter_stream_name,
+                &redis::RedisEntryId::AutoGeneratedID,
+                dead_letter_entry,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        logger::error!(
+            stream = stream_name,
+            entry_id,
+            retry_count,
+            reason = reason.as_str(),
+            "Moved entry to the dead letter stream"
+        );
+        metrics::DEAD_LETTERED_ENTRIES.add(&metrics::CONTEXT, 1, tags);
+
+        if let Err(error) = self.redis_conn.delete_key(&retry_count_key).await {
+            logger::error!(?error, "Failed to delete the retry count of the entry");
+        }
+
+        Ok(true)
+    }
+
+    async fn read_dead_letter_entry(
+        &self,
+        dead_letter_stream_name: &str,
+        entry_id: &str,
+    ) -> Result<HashMap<String, String>, DeadLetterError> {
+        self.redis_conn
+            .stream_read_range(dead_letter_stream_name, entry_id, entry_id, Some(1))
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to read the dead letter stream");
+                DeadLetterError::RedisError {
+                    message: error.to_string(),
+                }
+            })?
+            .into_iter()
+            .next()
+            .map(|(_, entry)| entry)
+            .ok_or_else(|| DeadLetterError::EntryNotFound {
+                entry_id: entry_id.to_string(),
+            })
+    }
+
+    async fn delete_dead_letter_entry(
+        &self,
+        dead_letter_stream_name: &str,
+        entry_id: &str,
+    ) -> Result<(), DeadLetterError> {
+        let deleted = self
+            .redis_conn
+            .stream_delete_entries(dead_letter_stream_name, entry_id)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to delete from the dead letter stream");
+                DeadLetterError::RedisError {
+                    message: error.to_string(),
+                }
+            })?;
+
+        common_utils::fp_utils::when(deleted == 0, || {
+            Err(DeadLetterError::EntryNotFound {
+                entry_id: entry_id.to_string(),
+            })
+        })
+    }
+}
+
+/// Whether an entry which failed the given number of attempts is to be dead lettered
+fn has_exhausted_retries(retry_count: i64, max_retry_count: u32) -> bool {
+    u64::try_from(retry_count).unwrap_or(u64::MAX) >= u64::from(max_retry_count)
+}
+
+/// The fields of the entry in the dead letter stream, made up of the fields of the original entry
+/// and the details of its failure
+fn build_dead_letter_entry(
+    (entry_id, entry): (&str, &HashMap<String, String>),
+    reason: FailureReason,
+    error: String,
+    retry_count: i64,
+    failed_at: i64,
+) -> Vec<(String, String)> {
+    let mut dead_letter_entry = entry
+        .iter()
+        .map(|(field, value)| (field.clone(), value.clone()))
+        .collect::<Vec<_>>();
+    dead_letter_entry.extend([
+        (SOURCE_ENTRY_ID_FIELD.to_string(), entry_id.to_string()),
+        (REASON_FIELD.to_string(), reason.as_str().to_string()),
+        (ERROR_FIELD.to_string(), error),
+        (RETRY_COUNT_FIELD.to_string(), retry_count.to_string()),
+        (FAILED_AT_FIELD.to_string(), failed_at.to_string()),
+    ]);
+    dead_letter_entry
+}
+
+/// The fields of the original entry of a dead lettered entry
+fn get_source_entry(dead_letter_entry: HashMap<String, String>) -> Vec<(String, String)> {
+    dead_letter_entry
+        .into_iter()
+        .filter(|(field, _)| !field.starts_with(FIELD_PREFIX))
+        .collect()
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetterEntry {
+    pub entry_id: String,
+    pub source_entry_id: Option<String>,
+    pub reason: Option<String>,
+    pub error: Option<String>,
+    pub retry_count: Option<String>,
+    pub failed_at: Option<String>,
+    /// The operation and table of the query, when the entry can be deserialized
+    pub operation: Option<String>,
+    pub table: Option<String>,
+    pub request_id: Option<String>,
+    pub global_id: Option<String>,
+}
+
+impl DeadLetterEntry {
+    fn new(entry_id: String, mut entry: HashMap<String, String>) -> Self {
+        let source_entry_id = entry.remove(SOURCE_ENTRY_ID_FIELD);
+        let reason = entry.remove(REASON_FIELD);
+        let error = entry.remove(ERROR_FIELD);
+        let retry_count = entry.remove(RETRY_COUNT_FIELD);
+        let failed_at = entry.remove(FAILED_AT_FIELD);
+        let request_id = entry.get("request_id").cloned();
+        let global_id = entry.get("global_id").cloned();
+        let typed_sql = StreamData::from_hashmap(entry)
+            .map(|data| data.typed_sql)
+            .ok();
+
+        Self {
+            entry_id,
+            source_entry_id,
+            reason,
+            error,
+            retry_count,
+            failed_at,
+            operation: typed_sql.as_ref().map(|sql| sql.operation().to_string()),
+            table: typed_sql.as_ref().map(|sql| sql.table().to_string()),
+            request_id,
+            global_id,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct DeadLetterListQuery {
+    pub count: Option<u64>,
+}
+
+/// The admin API key required by the dead letter endpoints
+pub struct AdminApiKey(Secret<String>);
+
+pub struct DeadLetter;
+
+impl DeadLetter {
+    pub fn server(conf: Settings, stores: HashMap<String, Arc<Store>>) -> Scope {
+        let admin_api_key = AdminApiKey(conf.secrets.get_inner().admin_api_key.clone());
+
+        web::scope("dead_letter")
+            .app_data(web::Data::new(stores))
+            .app_data(web::Data::new(admin_api_key))
+            .service(
+                web::resource("/{tenant}/{shard_index}").route(web::get().to(list_dead_letters)),
+            )
+            .service(
+                web::resource("/{tenant}/{shard_index}/{entry_id}")
+                    .route(web::delete().to(discard_dead_letter)),
+            )
+            .service(
+                web::resource("/{tenant}/{shard_index}/{entry_id}/redrive")
+                    .route(web::post().to(redrive_dead_letter)),
+            )
+    }
+}
+
+fn authenticate(req: &HttpRequest, admin_api_key: &AdminApiKey) -> Result<(), DeadLetterError> {
+    let admin_api_key = admin_api_key.0.peek();
+    let api_key = req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|api_key| api_key.to_str().ok());
+
+    common_utils::fp_utils::when(
+        admin_api_key.is_empty() || api_key != Some(admin_api_key.as_str()),
+        || Err(DeadLetterError::Unauthorized),
+    )
+}
+
+fn get_store(
+    stores: &HashMap<String, Arc<Store>>,
+    tenant: &str,
+    shard_index: u8,
+) -> Result<Arc<Store>, DeadLetterError> {
+    let store = stores
+        .get(tenant)
+        .cloned()
+        .ok_or_else(|| DeadLetterError::TenantNotFound {
+            tenant: tenant.to_string(),
+        })?;
+
+    common_utils::fp_utils::when(shard_index >= store.config.drainer_num_partitions, || {
+        Err(DeadLetterError::ShardNotFound { shard_index })
+    })?;
+
+    Ok(store)
+}
+
+/// Lists the oldest entries of the dead letter stream of the shard
+#[instrument(skip_all)]
+pub async fn list_dead_letters(
+    req: HttpRequest,
+    admin_api_key: web::Data<AdminApiKey>,
+    stores: web::Data<HashMap<String, Arc<Store>>>,
+    path: web::Path<(String, u8)>,
+    query: web::Query<DeadLetterListQuery>,
+) -> Result<HttpResponse, DeadLetterError> {
+    authenticate(&req, &admin_api_key)?;
+
+    let (tenant, shard_index) = path.into_inner();
+    let store = get_store(&stores, &tenant, shard_index)?;
+    let dead_letter_stream_name = store.get_dead_letter_stream_name(shard_index);
+
+    let entries = store
+        .redis_conn
+        .stream_read_range(
+            &dead_letter_stream_name,
+            "-",
+            "+",
+            Some(query.count.unwrap_or(DEFAULT_LIST_COUNT)),
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Failed to read the dead letter stream");
+            DeadLetterError::RedisError {
+                message: error.to_string(),
+            }
+        })?
+        .into_iter()
+        .map(|(entry_id, entry)| DeadLetterEntry::new(entry_id, entry))
+        .collect::<Vec<_>>();
+
+    Ok(services::http_response_json(
+        serde_json::to_string(&entries)
+            .map_err(|err| {
+                logger::error!(serialization_error=?err);
+            })
+            .unwrap_or_default(),
+    ))
+}
+
+/// Appends the dead lettered entry to the stream of its shard, and removes it from the dead
+/// letter stream. The entry is drained after the entries already in the stream, so re-driving
+/// an update is only safe when no later writes to the same record are pending.
+///
+/// Appending and removing the entry are separate steps, so the entry is first marked as re-driven.
+/// A request which fails after appending the entry can then be retried, removing the entry without
+/// appending it a second time.
+#[instrument(skip_all)]
+pub async fn redrive_dead_letter(
+    req: HttpRequest,
+    admin_api_key: web::Data<AdminApiKey>,
+    stores: web::Data<HashMap<String, Arc<Store>>>,
+    path: web::Path<(String, u8, String)>,
+) -> Result<HttpResponse, DeadLetterError> {
+    authenticate(&req, &admin_api_key)?;
+
+    let (tenant, shard_index, entry_id) = path.into_inner();
+    let store = get_store(&stores, &tenant, shard_index)?;
+    let dead_letter_stream_name = store.get_dead_letter_stream_name(shard_index);
+    let stream_name = store.get_drainer_stream_name(shard_index);
+
+    let entry = get_source_entry(
+        store
+            .read_dead_letter_entry(&dead_letter_stream_name, &entry_id)
+            .await?,
+    );
+
+    let redis_error = |error: error_stack::Report<redis::errors::RedisError>| {
+        logger::error!(?error, "Failed to re-drive the dead lettered entry");
+        DeadLetterError::RedisError {
+            message: error.to_string(),
+        }
+    };
+
+    let redrive_marker_key = Store::get_redrive_marker_key(&dead_letter_stream_name, &entry_id);
+    let is_marked = store
+        .redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &redrive_marker_key,
+            true,
+            Some(REDRIVE_MARKER_EXPIRY_SECONDS),
+        )
+        .await
+        .map_err(redis_error)?;
+
+    match is_marked {
+        redis::types::SetnxReply::KeySet => {
+            if let Err(error) = store
+                .redis_conn
+                .stream_append_entry(&stream_name, &redis::RedisEntryId::AutoGeneratedID, entry)
+                .await
+            {
+                // The entry was not appended, so that it can be re-driven again
+                if let Err(marker_error) = store.redis_conn.delete_key(&redrive_marker_key).await {
+                    logger::error!(
+                        error = ?marker_error,
+                        "Failed to delete the re-drive marker of the entry"
+                    );
+                }
+                return Err(redis_error(error));
+            }
+        }
+        redis::types::SetnxReply::KeyNotSet => {
+            logger::info!(
+                entry_id,
+                "Dead lettered entry was already appended to its stream"
+            );
+        }
+    }
+
+    store
+        .delete_dead_letter_entry(&dead_letter_stream_name, &entry_id)
+        .await?;
+
+    logger::info!(
+        stream = stream_name,
+        entry_id,
+        "Re-drove dead lettered entry"
+    );
+    metrics::DEAD_LETTER_REDRIVEN_ENTRIES.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue::new("stream", stream_name)],
+    );
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// Removes the dead lettered entry without draining it
+#[instrument(skip_all)]
+pub async fn discard_dead_letter(
+    req: HttpRequest,
+    admin_api_key: web::Data<AdminApiKey>,
+    stores: web::Data<HashMap<String, Arc<Store>>>,
+    path: web::Path<(String, u8, String)>,
+) -> Result<HttpResponse, DeadLetterError> {
+    authenticate(&req, &admin_api_key)?;
+
+    let (tenant, shard_index, entry_id) = path.into_inner();
+    let store = get_store(&stores, &tenant, shard_index)?;
+    let dead_letter_stream_name = store.get_dead_letter_stream_name(shard_index);
+
+    store
+        .delete_dead_letter_entry(&dead_letter_stream_name, &entry_id)
+        .await?;
+
+    logger::info!(
+        stream = dead_letter_stream_name,
+        entry_id,
+        "Discarded dead lettered entry"
+    );
+    metrics::DEAD_LETTER_DISCARDED_ENTRIES.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue::new("stream", dead_letter_stream_name)],
+    );
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_budget() {
+        assert!(!has_exhausted_retries(1, 3));
+        assert!(!has_exhausted_retries(2, 3));
+        assert!(has_exhausted_retries(3, 3));
+        assert!(has_exhausted_retries(4, 3));
+        assert!(has_exhausted_retries(1, 1));
+    }
+
+    #[test]
+    fn test_dead_letter_entry_round_trip() {
+        let entry = HashMap::from([
+            ("request_id".to_string(), "req_1".to_string()),
+            (
+                "global_id".to_string(),
+                "mid_merchant_pid_pay_1".to_string(),
+            ),
+            ("typed_sql".to_string(), "{}".to_string()),
+            ("pushed_at".to_string(), "1700000000".to_string()),
+        ]);
+
+        let dead_letter_entry = build_dead_letter_entry(
+            ("1700000000000-0", &entry),
+            FailureReason::QueryExecution,
+            "duplicate key".to_string(),
+            10,
+            1_700_000_100,
+        )
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+        let details =
+            DeadLetterEntry::new("1700000100000-0".to_string(), dead_letter_entry.clone());
+        assert_eq!(details.source_entry_id.as_deref(), Some("1700000000000-0"));
+        assert_eq!(details.reason.as_deref(), Some("query_execution"));
+        assert_eq!(details.error.as_deref(), Some("duplicate key"));
+        assert_eq!(details.retry_count.as_deref(), Some("10"));
+        assert_eq!(details.failed_at.as_deref(), Some("1700000100"));
+        assert_eq!(details.global_id.as_deref(), Some("mid_merchant_pid_pay_1"));
+
+        // Re-driving restores the original entry
+        let source_entry = get_source_entry(dead_letter_entry)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        assert_eq!(source_entry, entry);
+    }
+
+    #[test]
+    fn test_authentication() {
+        let admin_api_key = AdminApiKey(Secret::new("test_admin".to_string()));
+        let request = |api_key: Option<&str>| {
+            let request = actix_web::test::TestRequest::default();
+            match api_key {
+                Some(api_key) => request.insert_header((API_KEY_HEADER, api_key)),
+                None => request,
+            }
+            .to_http_request()
+        };
+
+        assert!(authenticate(&request(Some("test_admin")), &admin_api_key).is_ok());
+        assert!(authenticate(&request(Some("other")), &admin_api_key).is_err());
+        assert!(authenticate(&request(None), &admin_api_key).is_err());
+
+        let empty_admin_api_key = AdminApiKey(Secret::new(String::new()));
+        assert!(authenticate(&request(Some("")), &empty_admin_api_key).is_err());
+    }
+}
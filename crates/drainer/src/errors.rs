@@ -27,6 +27,20 @@ pub enum HealthCheckError {
     RedisError { message: String },
 }
 
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum DeadLetterError {
+    #[error("Admin API key is missing or invalid")]
+    Unauthorized,
+    #[error("Tenant {tenant} was not found")]
+    TenantNotFound { tenant: String },
+    #[error("Shard {shard_index} does not exist")]
+    ShardNotFound { shard_index: u8 },
+    #[error("Dead lettered entry {entry_id} was not found")]
+    EntryNotFound { entry_id: String },
+    #[error("Redis operation on the dead letter stream failed with error: {message}")]
+    RedisError { message: String },
+}
+
 impl From<std::io::Error> for DrainerError {
     fn from(err: std::io::Error) -> Self {
         Self::IoError(err)
@@ -56,3 +70,17 @@ impl actix_web::ResponseError for HealthCheckError {
         }
     }
 }
+
+impl actix_web::ResponseError for DeadLetterError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::TenantNotFound { .. }
+            | Self::ShardNotFound { .. }
+            | Self::EntryNotFound { .. } => StatusCode::NOT_FOUND,
+            Self::RedisError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+        }
+    }
+}
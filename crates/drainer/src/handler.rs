@@ -10,8 +10,11 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    batch,
+    dead_letter::FailureReason,
+    errors, instrument, logger, metrics,
+    query::{self, ExecuteQuery, QueryFailureHandling},
+    tracing, utils, DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -176,11 +179,13 @@ async fn drainer_handler(
     active_tasks.fetch_add(1, atomic::Ordering::Release);
 
     let stream_name = store.get_drainer_stream_name(stream_index);
+    let dead_letter_stream_name = store.get_dead_letter_stream_name(stream_index);
 
     let drainer_result = Box::pin(drainer(
         store.clone(),
         max_read_count,
         stream_name.as_str(),
+        dead_letter_stream_name.as_str(),
         jobs_picked,
     ))
     .await;
@@ -201,6 +206,7 @@ async fn drainer(
     store: Arc<Store>,
     max_read_count: u64,
     stream_name: &str,
+    dead_letter_stream_name: &str,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
     let stream_read = match store.read_from_stream(stream_name, max_read_count).await {
@@ -244,8 +250,18 @@ async fn drainer(
 
     let mut last_processed_id = String::new();
 
-    for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
+    let drained_count = if store.config.batch_writes {
+        batch::drain_in_transaction(&store, entries).await
+    } else {
+        0
+    };
+    let (last_drained_id, remaining_entries) = batch::skip_drained_entries(entries, drained_count);
+    if let Some(entry_id) = last_drained_id {
+        last_processed_id.clone_from(entry_id);
+    }
+
+    for (entry_id, entry) in remaining_entries {
+        let data = match StreamData::from_hashmap(entry.clone()) {
             Ok(data) => data,
             Err(err) => {
                 logger::error!(operation = "deserialization", err=?err);
@@ -258,7 +274,20 @@ async fn drainer(
                     }],
                 );
 
-                // break from the loop in case of a deser error
+                // Skip the entry once it has been moved to the dead letter stream, else break
+                // from the loop to retry it on the next pass
+                if handle_failed_entry(
+                    &store,
+                    (stream_name, dead_letter_stream_name),
+                    (entry_id.as_str(), entry),
+                    FailureReason::Deserialization,
+                    format!("{err:?}"),
+                )
+                .await
+                {
+                    last_processed_id.clone_from(entry_id);
+                    continue;
+                }
                 break;
             }
         };
@@ -269,16 +298,34 @@ async fn drainer(
 
         match data.typed_sql.execute_query(&store, data.pushed_at).await {
             Ok(_) => {
-                last_processed_id = entry_id;
+                last_processed_id.clone_from(entry_id);
             }
-            Err(err) => match err.current_context() {
+            Err(err) => match query::get_query_failure_handling(&err) {
                 // In case of Uniqueviolation we can't really do anything to fix it so just clear
                 // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
-                    last_processed_id = entry_id;
+                QueryFailureHandling::Clear => {
+                    last_processed_id.clone_from(entry_id);
+                }
+                // Transient failures of the database are not a fault of the entry, so the entry is
+                // retried on the next pass without counting the attempt
+                QueryFailureHandling::Retry => break,
+                // Skip the entry once it has been moved to the dead letter stream, else break
+                // from the loop to retry it on the next pass
+                QueryFailureHandling::RecordAttempt => {
+                    if handle_failed_entry(
+                        &store,
+                        (stream_name, dead_letter_stream_name),
+                        (entry_id.as_str(), entry),
+                        FailureReason::QueryExecution,
+                        format!("{err:?}"),
+                    )
+                    .await
+                    {
+                        last_processed_id.clone_from(entry_id);
+                        continue;
+                    }
+                    break;
                 }
-                // break from the loop in case of an error in query
-                _ => break,
             },
         }
     }
@@ -287,6 +334,11 @@ async fn drainer(
         let entries_trimmed = store
             .trim_from_stream(stream_name, &last_processed_id)
             .await?;
+        metrics::ENTRIES_DRAINED.add(
+            &metrics::CONTEXT,
+            u64::try_from(entries_trimmed).unwrap_or(u64::MIN),
+            &[],
+        );
         if read_count != entries_trimmed {
             logger::error!(
                 read_entries = %read_count,
@@ -301,3 +353,28 @@ async fn drainer(
 
     Ok(())
 }
+
+/// Records the failed attempt at draining the entry, returning whether it was moved to the dead
+/// letter stream. Failures to record the attempt are only logged, leaving the entry to be retried,
+/// so that the entries drained before it are still trimmed from the stream.
+async fn handle_failed_entry(
+    store: &Store,
+    (stream_name, dead_letter_stream_name): (&str, &str),
+    (entry_id, entry): (&str, &HashMap<String, String>),
+    reason: FailureReason,
+    error: String,
+) -> bool {
+    store
+        .record_failed_attempt(
+            stream_name,
+            dead_letter_stream_name,
+            (entry_id, entry),
+            reason,
+            error,
+        )
+        .await
+        .unwrap_or_else(|error| {
+            logger::error!(?error, "Failed to record the failed attempt of the entry");
+            false
+        })
+}
@@ -1,6 +1,7 @@
 pub use router_env::opentelemetry::KeyValue;
 use router_env::{
-    counter_metric, global_meter, histogram_metric, histogram_metric_i64, metrics_context,
+    counter_metric, global_meter, histogram_metric, histogram_metric_i64, histogram_metric_u64,
+    metrics_context,
 };
 
 metrics_context!(CONTEXT);
@@ -16,9 +17,17 @@ counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
 counter_metric!(STREAM_EMPTY, DRAINER_METER);
 counter_metric!(STREAM_PARSE_FAIL, DRAINER_METER);
 counter_metric!(DRAINER_HEALTH, DRAINER_METER);
+counter_metric!(ENTRY_RETRIES, DRAINER_METER);
+counter_metric!(DEAD_LETTERED_ENTRIES, DRAINER_METER);
+counter_metric!(DEAD_LETTER_REDRIVEN_ENTRIES, DRAINER_METER);
+counter_metric!(DEAD_LETTER_DISCARDED_ENTRIES, DRAINER_METER);
+counter_metric!(ENTRIES_DRAINED, DRAINER_METER);
+counter_metric!(BATCHED_ENTRIES, DRAINER_METER);
+counter_metric!(BATCH_FALLBACKS, DRAINER_METER);
 
 histogram_metric!(QUERY_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric!(REDIS_STREAM_READ_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric!(REDIS_STREAM_TRIM_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric!(CLEANUP_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric_i64!(DRAINER_DELAY_SECONDS, DRAINER_METER); // Time in (s) seconds
+histogram_metric_u64!(BATCH_SIZE, DRAINER_METER); // Entries executed as a single query
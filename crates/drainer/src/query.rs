@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use common_utils::errors::CustomResult;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
 use diesel_models::errors::DatabaseError;
 
 use crate::{kv, logger, metrics, pg_connection, services::Store};
@@ -58,7 +59,12 @@ impl ExecuteQuery for kv::DBOperation {
 }
 
 #[inline(always)]
-fn push_drainer_delay(pushed_at: i64, operation: &str, table: &str, tags: &[metrics::KeyValue]) {
+pub(crate) fn push_drainer_delay(
+    pushed_at: i64,
+    operation: &str,
+    table: &str,
+    tags: &[metrics::KeyValue],
+) {
     let drained_at = common_utils::date_time::now_unix_timestamp();
     let delay = drained_at - pushed_at;
 
@@ -70,3 +76,127 @@ fn push_drainer_delay(pushed_at: i64, operation: &str, table: &str, tags: &[metr
 
     metrics::DRAINER_DELAY_SECONDS.record(&metrics::CONTEXT, delay, tags);
 }
+
+/// How the drainer proceeds with an entry whose query failed
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum QueryFailureHandling {
+    /// The query can never succeed, so the entry is cleared from the stream
+    Clear,
+    /// The failure is not a fault of the entry, which is retried without counting the attempt
+    Retry,
+    /// The attempt counts towards moving the entry to the dead letter stream
+    RecordAttempt,
+}
+
+/// Messages of the PostgreSQL errors which diesel does not tell apart, and which are resolved by
+/// retrying the query: deadlocks, and statements cancelled by the lock or statement timeouts
+const TRANSIENT_ERROR_MESSAGES: [&str; 3] = [
+    "deadlock detected",
+    "canceling statement due to statement timeout",
+    "canceling statement due to lock timeout",
+];
+
+pub(crate) fn get_query_failure_handling(
+    error: &error_stack::Report<DatabaseError>,
+) -> QueryFailureHandling {
+    match error.current_context() {
+        DatabaseError::UniqueViolation => QueryFailureHandling::Clear,
+        DatabaseError::DatabaseConnectionError => QueryFailureHandling::Retry,
+        _ if error
+            .downcast_ref::<DieselError>()
+            .is_some_and(is_transient_error) =>
+        {
+            QueryFailureHandling::Retry
+        }
+        _ => QueryFailureHandling::RecordAttempt,
+    }
+}
+
+fn is_transient_error(error: &DieselError) -> bool {
+    match error {
+        DieselError::DatabaseError(
+            DatabaseErrorKind::SerializationFailure
+            | DatabaseErrorKind::ClosedConnection
+            | DatabaseErrorKind::UnableToSendCommand,
+            _,
+        ) => true,
+        DieselError::DatabaseError(DatabaseErrorKind::Unknown, information) => {
+            TRANSIENT_ERROR_MESSAGES
+                .iter()
+                .any(|message| information.message().starts_with(message))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error_stack::report;
+
+    use super::*;
+
+    fn database_error(
+        kind: DatabaseErrorKind,
+        message: &str,
+    ) -> error_stack::Report<DatabaseError> {
+        report!(DieselError::DatabaseError(
+            kind,
+            Box::new(message.to_string())
+        ))
+        .change_context(DatabaseError::Others)
+    }
+
+    #[test]
+    fn test_transient_errors_are_retried_without_counting_the_attempt() {
+        let transient_errors = [
+            database_error(
+                DatabaseErrorKind::SerializationFailure,
+                "could not serialize access due to concurrent update",
+            ),
+            database_error(
+                DatabaseErrorKind::ClosedConnection,
+                "server closed the connection unexpectedly",
+            ),
+            database_error(DatabaseErrorKind::Unknown, "deadlock detected"),
+            database_error(
+                DatabaseErrorKind::Unknown,
+                "canceling statement due to statement timeout",
+            ),
+            report!(DatabaseError::DatabaseConnectionError),
+        ];
+
+        for error in transient_errors {
+            assert_eq!(
+                get_query_failure_handling(&error),
+                QueryFailureHandling::Retry
+            );
+        }
+    }
+
+    #[test]
+    fn test_entry_failures_count_towards_dead_lettering() {
+        let entry_errors = [
+            database_error(
+                DatabaseErrorKind::NotNullViolation,
+                "null value in column \"amount\" violates not-null constraint",
+            ),
+            database_error(
+                DatabaseErrorKind::Unknown,
+                "value too long for type character varying(64)",
+            ),
+            report!(DatabaseError::NotFound),
+        ];
+
+        for error in entry_errors {
+            assert_eq!(
+                get_query_failure_handling(&error),
+                QueryFailureHandling::RecordAttempt
+            );
+        }
+
+        assert_eq!(
+            get_query_failure_handling(&report!(DatabaseError::UniqueViolation)),
+            QueryFailureHandling::Clear
+        );
+    }
+}
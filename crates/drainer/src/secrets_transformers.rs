@@ -5,7 +5,7 @@ use hyperswitch_interfaces::secrets_interface::{
     SecretManagementInterface, SecretsManagementError,
 };
 
-use crate::settings::{Database, Settings};
+use crate::settings::{Database, Secrets, Settings};
 
 #[async_trait::async_trait]
 impl SecretsHandler for Database {
@@ -25,6 +25,21 @@ impl SecretsHandler for Database {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for Secrets {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let secrets = value.get_inner();
+        let admin_api_key = secret_management_client
+            .get_secret(secrets.admin_api_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|_| Self { admin_api_key }))
+    }
+}
+
 /// # Panics
 ///
 /// Will panic even if fetching raw secret fails for at least one config value
@@ -37,6 +52,11 @@ pub async fn fetch_raw_secrets(
         .await
         .expect("Failed to decrypt database password");
 
+    #[allow(clippy::expect_used)]
+    let secrets = Secrets::convert_to_raw_secret(conf.secrets, secret_management_client)
+        .await
+        .expect("Failed to decrypt admin API key");
+
     Settings {
         server: conf.server,
         master_database: database,
@@ -46,5 +66,6 @@ pub async fn fetch_raw_secrets(
         encryption_management: conf.encryption_management,
         secrets_management: conf.secrets_management,
         multitenancy: conf.multitenancy,
+        secrets,
     }
 }
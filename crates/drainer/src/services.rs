@@ -22,6 +22,9 @@ pub struct Store {
 pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
+    pub drainer_max_retry_count: u32,
+    pub dead_letter_stream_name: String,
+    pub batch_writes: bool,
 }
 
 impl Store {
@@ -46,6 +49,9 @@ impl Store {
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
+                drainer_max_retry_count: config.drainer.max_retry_count,
+                dead_letter_stream_name: config.drainer.dead_letter_stream_name.clone(),
+                batch_writes: config.drainer.batch_writes,
             },
             request_id: None,
         }
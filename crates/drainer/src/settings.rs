@@ -74,6 +74,15 @@ pub struct Settings<S: SecretState> {
     pub encryption_management: EncryptionManagementConfig,
     pub secrets_management: SecretsManagementConfig,
     pub multitenancy: Multitenancy,
+    pub secrets: SecretStateContainer<Secrets, S>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Secrets {
+    /// Admin API key required by the endpoints managing dead lettered entries, which are rejected
+    /// altogether when it is empty
+    pub admin_api_key: Secret<String>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -114,6 +123,11 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    /// The number of times an entry is attempted before it is moved to the dead letter stream
+    pub max_retry_count: u32,
+    pub dead_letter_stream_name: String,
+    /// Whether consecutive entries read from the stream are batched into fewer queries
+    pub batch_writes: bool,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -186,6 +200,9 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            max_retry_count: 10,
+            dead_letter_stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
+            batch_writes: true,
         }
     }
 }
@@ -236,6 +253,18 @@ impl DrainerSettings {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        common_utils::fp_utils::when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead letter stream name must not be empty".into(),
+            ))
+        })?;
+
+        common_utils::fp_utils::when(self.max_retry_count == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer max retry count must be greater than zero".into(),
+            ))
         })
     }
 }
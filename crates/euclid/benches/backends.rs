@@ -3,7 +3,9 @@
 use common_utils::types::MinorUnit;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use euclid::{
-    backend::{inputs, EuclidBackend, InterpreterBackend, VirInterpreterBackend},
+    backend::{
+        inputs, DecisionTableBackend, EuclidBackend, InterpreterBackend, VirInterpreterBackend,
+    },
     enums,
     frontend::ast::{self, parser},
     types::DummyOutput,
@@ -47,17 +49,23 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             billing_country: Some(enums::Country::France),
             business_label: None,
             setup_future_usage: None,
+            converted_amount: None,
         },
         payment_method: inputs::PaymentMethodInput {
             payment_method: Some(enums::PaymentMethod::PayLater),
             payment_method_type: Some(enums::PaymentMethodType::Sofort),
             card_network: None,
+            issuer_country: None,
+            card_issuer: None,
+            is_network_token: None,
         },
         mandate: inputs::MandateData {
             mandate_acceptance_type: None,
             mandate_type: None,
             payment_type: None,
         },
+        customer: inputs::CustomerInput::default(),
+        temporal: inputs::TemporalInput::default(),
     };
 
     let (_, program) = parser::program(code1).expect("Parser");
@@ -71,7 +79,10 @@ fn interpreter_vs_jit_vs_vir_interpreter(c: &mut Criterion) {
     let interp_b = InterpreterBackend::with_program(program.clone()).expect("Interpreter backend");
 
     let vir_interp_b =
-        VirInterpreterBackend::with_program(program).expect("Vir Interpreter Backend");
+        VirInterpreterBackend::with_program(program.clone()).expect("Vir Interpreter Backend");
+
+    let decision_table_b =
+        DecisionTableBackend::with_program(program).expect("Decision Table Backend");
 
     c.bench_function("Raw Interpreter Backend", |b| {
         b.iter(|| {
@@ -88,6 +99,14 @@ fn interpreter_vs_jit_vs_vir_interpreter(c: &mut Criterion) {
                 .expect("Vir Interpreter execution");
         })
     });
+
+    c.bench_function("Decision Table Backend", |b| {
+        b.iter(|| {
+            decision_table_b
+                .execute(binputs.clone())
+                .expect("Decision Table execution");
+        })
+    });
 }
 
 criterion_group!(benches, interpreter_vs_jit_vs_vir_interpreter);
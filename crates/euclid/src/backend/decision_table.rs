@@ -0,0 +1,373 @@
+pub mod types;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    backend::{self, inputs, vir_interpreter::types::Context, EuclidBackend},
+    frontend::{
+        ast,
+        dir::{self, EuclidDirFilter},
+        vir,
+    },
+    types::{DataType, EuclidValue},
+};
+
+/// A backend which compiles the program ahead of time into a decision table
+///
+/// Each comparison of the program is evaluated at most once per input, and the rules are matched
+/// by checking their compiled paths against the set of satisfied comparisons, rather than walking
+/// the rule tree. The outputs are identical to those of the [`backend::VirInterpreterBackend`].
+#[derive(Debug, Clone)]
+pub struct DecisionTableBackend<O> {
+    table: types::DecisionTable<O>,
+}
+
+#[derive(Default)]
+struct TableBuilder {
+    predicates: FxHashMap<(bool, Vec<EuclidValue>), usize>,
+    positive_index: FxHashMap<EuclidValue, Vec<usize>>,
+    negative_index: FxHashMap<EuclidValue, Vec<usize>>,
+    negative_predicates: Vec<usize>,
+    numeric_predicates: Vec<types::NumericPredicate>,
+}
+
+impl TableBuilder {
+    fn predicate_index(&mut self, comparison: &vir::ValuedComparison) -> usize {
+        let is_negative = matches!(
+            comparison.logic,
+            vir::ValuedComparisonLogic::NegativeConjunction
+        );
+        let key = (is_negative, comparison.values.clone());
+        if let Some(index) = self.predicates.get(&key) {
+            return *index;
+        }
+
+        let index = self.predicates.len();
+        self.predicates.insert(key, index);
+
+        let is_numeric = comparison
+            .values
+            .first()
+            .is_some_and(|value| matches!(value.get_key().key_type(), DataType::Number));
+
+        if is_numeric {
+            self.numeric_predicates.push(types::NumericPredicate {
+                index,
+                comparison: comparison.clone(),
+            });
+        } else if is_negative {
+            self.negative_predicates.push(index);
+            for value in &comparison.values {
+                self.negative_index
+                    .entry(value.clone())
+                    .or_default()
+                    .push(index);
+            }
+        } else {
+            for value in &comparison.values {
+                self.positive_index
+                    .entry(value.clone())
+                    .or_default()
+                    .push(index);
+            }
+        }
+
+        index
+    }
+
+    fn compile_statement(
+        &mut self,
+        statement: &vir::ValuedIfStatement,
+        prefix: &types::Bitset,
+        paths: &mut Vec<types::Bitset>,
+    ) {
+        let mut path = prefix.clone();
+        for comparison in &statement.condition {
+            path.insert(self.predicate_index(comparison));
+        }
+
+        match &statement.nested {
+            None => paths.push(path),
+            Some(nested_statements) => {
+                for nested in nested_statements {
+                    self.compile_statement(nested, &path, paths);
+                }
+            }
+        }
+    }
+
+    fn compile_rule<O>(&mut self, rule: vir::ValuedRule<O>) -> types::CompiledRule<O> {
+        let mut paths = Vec::new();
+        for statement in &rule.statements {
+            self.compile_statement(statement, &types::Bitset::default(), &mut paths);
+        }
+
+        types::CompiledRule {
+            name: rule.name,
+            connector_selection: rule.connector_selection,
+            paths,
+        }
+    }
+}
+
+impl<O> DecisionTableBackend<O>
+where
+    O: Clone,
+{
+    fn compile(program: vir::ValuedProgram<O>) -> types::DecisionTable<O> {
+        let mut builder = TableBuilder::default();
+        let rules = program
+            .rules
+            .into_iter()
+            .map(|rule| builder.compile_rule(rule))
+            .collect();
+
+        let mut negative_predicates = types::Bitset::with_capacity(builder.predicates.len());
+        for index in &builder.negative_predicates {
+            negative_predicates.insert(*index);
+        }
+
+        types::DecisionTable {
+            default_selection: program.default_selection,
+            rules,
+            positive_index: builder.positive_index,
+            negative_index: builder.negative_index,
+            negative_predicates,
+            numeric_predicates: builder.numeric_predicates,
+        }
+    }
+
+    fn satisfied_predicates(&self, ctx: &Context) -> types::Bitset {
+        let mut satisfied = self.table.negative_predicates.clone();
+        for value in ctx.atomic_values() {
+            if let Some(indices) = self.table.positive_index.get(value) {
+                indices.iter().for_each(|index| satisfied.insert(*index));
+            }
+            if let Some(indices) = self.table.negative_index.get(value) {
+                indices.iter().for_each(|index| satisfied.remove(*index));
+            }
+        }
+
+        for predicate in &self.table.numeric_predicates {
+            let is_satisfied = match predicate.comparison.logic {
+                vir::ValuedComparisonLogic::PositiveDisjunction => predicate
+                    .comparison
+                    .values
+                    .iter()
+                    .any(|value| ctx.check_presence(value)),
+                vir::ValuedComparisonLogic::NegativeConjunction => predicate
+                    .comparison
+                    .values
+                    .iter()
+                    .all(|value| !ctx.check_presence(value)),
+            };
+
+            if is_satisfied {
+                satisfied.insert(predicate.index);
+            }
+        }
+
+        satisfied
+    }
+
+    fn eval_table(&self, ctx: &Context) -> backend::BackendOutput<O> {
+        let satisfied = self.satisfied_predicates(ctx);
+
+        self.table
+            .rules
+            .iter()
+            .find(|rule| rule.paths.iter().any(|path| satisfied.is_superset(path)))
+            .map_or_else(
+                || backend::BackendOutput {
+                    connector_selection: self.table.default_selection.clone(),
+                    rule_name: None,
+                },
+                |rule| backend::BackendOutput {
+                    connector_selection: rule.connector_selection.clone(),
+                    rule_name: Some(rule.name.clone()),
+                },
+            )
+    }
+}
+
+impl<O> EuclidBackend<O> for DecisionTableBackend<O>
+where
+    O: Clone + EuclidDirFilter,
+{
+    type Error = types::DecisionTableError;
+
+    fn with_program(program: ast::Program<O>) -> Result<Self, Self::Error> {
+        let dir_program = ast::lowering::lower_program(program)
+            .map_err(types::DecisionTableError::LoweringError)?;
+
+        let vir_program = dir::lowering::lower_program(dir_program)
+            .map_err(types::DecisionTableError::LoweringError)?;
+
+        Ok(Self {
+            table: Self::compile(vir_program),
+        })
+    }
+
+    fn execute(
+        &self,
+        input: inputs::BackendInput,
+    ) -> Result<backend::BackendOutput<O>, Self::Error> {
+        let ctx = Context::from_input(input);
+        Ok(self.eval_table(&ctx))
+    }
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod test {
+    #![allow(clippy::expect_used)]
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+    use crate::{backend::VirInterpreterBackend, enums, types::DummyOutput};
+
+    fn get_input() -> inputs::BackendInput {
+        inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(32),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                converted_amount: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: Some(enums::CardNetwork::Visa),
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
+        }
+    }
+
+    #[test]
+    fn test_matches_vir_interpreter() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+            payment_method = card & amount > 100 {
+                card_network = (visa, mastercard)
+                currency /= (INR, EUR) & amount <= 1000
+            }
+        }
+
+        rule_2: ["adyen"]
+        {
+            "merchant_tier" = "gold"
+            payment_method /= (card, wallet)
+        }
+
+        rule_3: ["checkout"]
+        {
+            billing_country = (Germany, Spain) & amount /= 500
+            payment_method = card {
+                card_network /= visa
+            }
+        }
+
+        rule_4: ["braintree"]
+        {
+            payment_method = card & amount > 100 {
+                card_network = amex
+            }
+            currency = USD & amount = (32, 64)
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let decision_table =
+            DecisionTableBackend::<DummyOutput>::with_program(program.clone()).expect("Program");
+        let vir_interpreter =
+            VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let mut metadata = FxHashMap::default();
+        metadata.insert("merchant_tier".to_string(), "gold".to_string());
+        let mut inputs = Vec::new();
+        for amount in [32, 64, 150, 500, 2000] {
+            for currency in [enums::Currency::USD, enums::Currency::EUR] {
+                for card_network in [
+                    enums::CardNetwork::Visa,
+                    enums::CardNetwork::AmericanExpress,
+                ] {
+                    for payment_method in [enums::PaymentMethod::Card, enums::PaymentMethod::Wallet]
+                    {
+                        let mut input = get_input();
+                        input.payment.amount = MinorUnit::new(amount);
+                        input.payment.currency = currency;
+                        input.payment_method.card_network = Some(card_network.clone());
+                        input.payment_method.payment_method = Some(payment_method);
+                        inputs.push(input.clone());
+
+                        input.metadata = Some(metadata.clone());
+                        input.payment_method.payment_method = None;
+                        inputs.push(input);
+                    }
+                }
+            }
+        }
+
+        let mut matched_rules = Vec::new();
+        for input in inputs {
+            let expected = vir_interpreter.execute(input.clone()).expect("Execution");
+            let result = decision_table.execute(input).expect("Execution");
+            assert_eq!(result.rule_name, expected.rule_name);
+            assert_eq!(
+                result.connector_selection.outputs,
+                expected.connector_selection.outputs
+            );
+            matched_rules.push(result.rule_name);
+        }
+
+        for rule_name in [
+            None,
+            Some("rule_1"),
+            Some("rule_2"),
+            Some("rule_3"),
+            Some("rule_4"),
+        ] {
+            assert!(
+                matched_rules
+                    .iter()
+                    .any(|matched| matched.as_deref() == rule_name),
+                "{rule_name:?} was never matched"
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_selection() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+            pay_later = klarna
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let backend = DecisionTableBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let result = backend.execute(get_input()).expect("Execution");
+        assert!(result.rule_name.is_none());
+        assert_eq!(result.connector_selection.outputs, ["stripe", "adyen"]);
+    }
+}
@@ -0,0 +1,86 @@
+use rustc_hash::FxHashMap;
+
+use crate::{dssa, frontend::vir, types::EuclidValue};
+
+#[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
+pub enum DecisionTableError {
+    #[error("Error when lowering the program: {0:?}")]
+    LoweringError(dssa::types::AnalysisError),
+}
+
+/// A fixed size set of predicate indices
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bitset {
+    words: Vec<u64>,
+}
+
+impl Bitset {
+    const WORD_BITS: usize = 64;
+
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            words: vec![0; bits.div_ceil(Self::WORD_BITS)],
+        }
+    }
+
+    pub fn insert(&mut self, bit: usize) {
+        let word_index = bit / Self::WORD_BITS;
+        if word_index >= self.words.len() {
+            self.words.resize(word_index + 1, 0);
+        }
+
+        if let Some(word) = self.words.get_mut(word_index) {
+            *word |= 1 << (bit % Self::WORD_BITS);
+        }
+    }
+
+    pub fn remove(&mut self, bit: usize) {
+        if let Some(word) = self.words.get_mut(bit / Self::WORD_BITS) {
+            *word &= !(1 << (bit % Self::WORD_BITS));
+        }
+    }
+
+    /// Whether every bit set in `other` is also set in `self`
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.words.iter().enumerate().all(|(index, other_word)| {
+            self.words
+                .get(index)
+                .map_or(*other_word == 0, |word| word & other_word == *other_word)
+        })
+    }
+}
+
+/// A comparison whose outcome depends on the numeric value of a key, evaluated directly against
+/// the context
+#[derive(Debug, Clone)]
+pub struct NumericPredicate {
+    pub index: usize,
+    pub comparison: vir::ValuedComparison,
+}
+
+/// A rule flattened into the alternative sets of predicates which satisfy it
+#[derive(Debug, Clone)]
+pub struct CompiledRule<O> {
+    pub name: String,
+    pub connector_selection: O,
+    pub paths: Vec<Bitset>,
+}
+
+/// A program compiled into predicate indices
+///
+/// Every distinct comparison of the program is a predicate. Comparisons over enum variants,
+/// strings and metadata are resolved by looking up the values present in the input, comparisons
+/// over numbers are evaluated one by one.
+#[derive(Debug, Clone)]
+pub struct DecisionTable<O> {
+    pub default_selection: O,
+    pub rules: Vec<CompiledRule<O>>,
+    /// Predicates satisfied when the value is present in the input
+    pub positive_index: FxHashMap<EuclidValue, Vec<usize>>,
+    /// Predicates violated when the value is present in the input
+    pub negative_index: FxHashMap<EuclidValue, Vec<usize>>,
+    /// Predicates which hold unless one of their values is present in the input, sized to hold
+    /// every predicate of the program
+    pub negative_predicates: Bitset,
+    pub numeric_predicates: Vec<NumericPredicate>,
+}
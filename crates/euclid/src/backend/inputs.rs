@@ -15,6 +15,12 @@ pub struct PaymentMethodInput {
     pub payment_method: Option<enums::PaymentMethod>,
     pub payment_method_type: Option<enums::PaymentMethodType>,
     pub card_network: Option<enums::CardNetwork>,
+    #[serde(default)]
+    pub issuer_country: Option<enums::Country>,
+    #[serde(default)]
+    pub card_issuer: Option<String>,
+    #[serde(default)]
+    pub is_network_token: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +34,22 @@ pub struct PaymentInput {
     pub billing_country: Option<enums::Country>,
     pub business_label: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
+    /// Amount of the payment converted to the reference currency configured by the merchant
+    #[serde(default)]
+    pub converted_amount: Option<common_utils::types::MinorUnit>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CustomerInput {
+    pub customer_id: Option<String>,
+    pub customer_segment: Option<String>,
+}
+
+/// Time at which the payment was made, in the timezone of the merchant
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TemporalInput {
+    pub day_of_week: Option<enums::DayOfWeek>,
+    pub hour_of_day: Option<u8>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,4 +58,8 @@ pub struct BackendInput {
     pub payment: PaymentInput,
     pub payment_method: PaymentMethodInput,
     pub mandate: MandateData,
+    #[serde(default)]
+    pub customer: CustomerInput,
+    #[serde(default)]
+    pub temporal: TemporalInput,
 }
@@ -140,17 +140,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
                 mandate_type: None,
                 payment_type: None,
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -180,17 +186,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
                 mandate_type: None,
                 payment_type: Some(enums::PaymentType::SetupMandate),
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -221,17 +233,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
                 mandate_type: None,
                 payment_type: Some(enums::PaymentType::PptMandate),
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -262,17 +280,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
                 mandate_type: Some(enums::MandateType::SingleUse),
                 payment_type: None,
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -303,17 +327,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: Some(enums::MandateAcceptanceType::Online),
                 mandate_type: None,
                 payment_type: None,
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -344,17 +374,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
                 mandate_type: None,
                 payment_type: None,
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -385,17 +421,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
                 mandate_type: None,
                 payment_type: None,
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -426,17 +468,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
                 mandate_type: None,
                 payment_type: None,
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -467,17 +515,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
                 mandate_type: None,
                 payment_type: None,
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -510,17 +564,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
                 mandate_type: None,
                 payment_type: None,
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -551,17 +611,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
                 mandate_type: None,
                 payment_type: None,
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -601,17 +667,23 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                converted_amount: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                issuer_country: None,
+                card_issuer: None,
+                is_network_token: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
                 mandate_type: None,
                 payment_type: None,
             },
+            customer: inputs::CustomerInput::default(),
+            temporal: inputs::TemporalInput::default(),
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -627,4 +699,80 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_extended_routing_inputs() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+           customer_segment = "premium"
+           is_network_token = true
+        }
+
+        rule_2: ["adyen"]
+        {
+           day_of_week = (saturday, sunday)
+           hour_of_day >= 18
+        }
+
+        rule_3: ["checkout"]
+        {
+           issuer_country = Germany
+           converted_amount > 1000
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(1200),
+                card_bin: None,
+                currency: enums::Currency::EUR,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::Germany),
+                billing_country: Some(enums::Country::Germany),
+                business_label: None,
+                setup_future_usage: None,
+                converted_amount: Some(MinorUnit::new(1300)),
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: Some(enums::CardNetwork::Visa),
+                issuer_country: Some(enums::Country::Germany),
+                card_issuer: Some("Deutsche Bank".to_string()),
+                is_network_token: Some(false),
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            customer: inputs::CustomerInput {
+                customer_id: Some("cus_123".to_string()),
+                customer_segment: Some("premium".to_string()),
+            },
+            temporal: inputs::TemporalInput {
+                day_of_week: Some(enums::DayOfWeek::Friday),
+                hour_of_day: Some(20),
+            },
+        };
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let result = backend.execute(inp.clone()).expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_3");
+
+        let mut inp_weekend = inp.clone();
+        inp_weekend.temporal.day_of_week = Some(enums::DayOfWeek::Sunday);
+        let result = backend.execute(inp_weekend).expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_2");
+
+        let mut inp_network_token = inp;
+        inp_network_token.payment_method.is_network_token = Some(true);
+        let result = backend.execute(inp_network_token).expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_1");
+    }
 }
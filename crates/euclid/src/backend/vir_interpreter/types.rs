@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
@@ -18,6 +19,10 @@ pub struct Context {
 }
 
 impl Context {
+    pub fn atomic_values(&self) -> impl Iterator<Item = &EuclidValue> {
+        self.atomic_values.iter()
+    }
+
     pub fn check_presence(&self, value: &EuclidValue) -> bool {
         let key = value.get_key();
 
@@ -56,6 +61,8 @@ impl Context {
         let payment_method = input.payment_method;
         let meta_data = input.metadata;
         let payment_mandate = input.mandate;
+        let customer = input.customer;
+        let temporal = input.temporal;
 
         let mut enum_values: FxHashSet<EuclidValue> =
             FxHashSet::from_iter([EuclidValue::PaymentCurrency(payment.currency)]);
@@ -113,14 +120,52 @@ impl Context {
         if let Some(mandate_acceptance_type) = payment_mandate.mandate_acceptance_type {
             enum_values.insert(EuclidValue::MandateAcceptanceType(mandate_acceptance_type));
         }
+        if let Some(issuer_country) = payment_method.issuer_country {
+            enum_values.insert(EuclidValue::IssuerCountry(issuer_country));
+        }
+        if let Some(card_issuer) = payment_method.card_issuer {
+            enum_values.insert(EuclidValue::CardIssuer(StrValue { value: card_issuer }));
+        }
+        if let Some(is_network_token) = payment_method.is_network_token {
+            enum_values.insert(EuclidValue::IsNetworkToken(is_network_token.into()));
+        }
+        if let Some(customer_id) = customer.customer_id {
+            enum_values.insert(EuclidValue::CustomerId(StrValue { value: customer_id }));
+        }
+        if let Some(customer_segment) = customer.customer_segment {
+            enum_values.insert(EuclidValue::CustomerSegment(StrValue {
+                value: customer_segment,
+            }));
+        }
+        if let Some(day_of_week) = temporal.day_of_week {
+            enum_values.insert(EuclidValue::DayOfWeek(day_of_week));
+        }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
                 refinement: None,
             }),
         )]);
+        if let Some(hour_of_day) = temporal.hour_of_day {
+            numeric_values.insert(
+                EuclidKey::HourOfDay,
+                EuclidValue::HourOfDay(types::NumValue {
+                    number: MinorUnit::new(i64::from(hour_of_day)),
+                    refinement: None,
+                }),
+            );
+        }
+        if let Some(converted_amount) = payment.converted_amount {
+            numeric_values.insert(
+                EuclidKey::ConvertedAmount,
+                EuclidValue::ConvertedAmount(types::NumValue {
+                    number: converted_amount,
+                    refinement: None,
+                }),
+            );
+        }
 
         Self {
             atomic_values: enum_values,
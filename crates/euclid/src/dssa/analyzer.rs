@@ -9,7 +9,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     dssa::{
-        graph::CgraphExt,
+        graph::{self, CgraphExt},
         state_machine, truth,
         types::{self, EuclidAnalysable},
     },
@@ -35,23 +35,26 @@ pub fn analyze_conflicting_assertions(
 ) -> Result<(), types::AnalysisError> {
     for (key, value_set) in keywise_assertions {
         if value_set.len() > 1 {
+            let values: Vec<types::ValueData> = value_set
+                .iter()
+                .map(|val| types::ValueData {
+                    value: (*val).clone(),
+                    metadata: assertion_metadata
+                        .get(val)
+                        .map(|meta| (*meta).clone())
+                        .unwrap_or_default(),
+                })
+                .collect();
+            let span = get_last_span(values.iter().map(|value| &value.metadata));
             let err_type = types::AnalysisErrorType::ConflictingAssertions {
                 key: key.clone(),
-                values: value_set
-                    .iter()
-                    .map(|val| types::ValueData {
-                        value: (*val).clone(),
-                        metadata: assertion_metadata
-                            .get(val)
-                            .map(|meta| (*meta).clone())
-                            .unwrap_or_default(),
-                    })
-                    .collect(),
+                values,
             };
 
             Err(types::AnalysisError {
                 error_type: err_type,
                 metadata: Default::default(),
+                span,
             })?;
         }
     }
@@ -80,21 +83,24 @@ pub fn analyze_exhaustive_negations(
         value_set.retain(|val| !negation_set.contains(val));
 
         if value_set.is_empty() {
+            let metadata: Vec<Metadata> = keywise_negation_metadata
+                .get(key)
+                .cloned()
+                .unwrap_or_default()
+                .iter()
+                .cloned()
+                .cloned()
+                .collect();
+            let span = get_last_span(metadata.iter());
             let error_type = types::AnalysisErrorType::ExhaustiveNegation {
                 key: key.clone(),
-                metadata: keywise_negation_metadata
-                    .get(key)
-                    .cloned()
-                    .unwrap_or_default()
-                    .iter()
-                    .cloned()
-                    .cloned()
-                    .collect(),
+                metadata,
             };
 
             Err(types::AnalysisError {
                 error_type,
                 metadata: Default::default(),
+                span,
             })?;
         }
     }
@@ -117,6 +123,12 @@ fn analyze_negated_assertions(
         let intersection = negation_set & assertion_set;
 
         intersection.iter().next().map_or(Ok(()), |val| {
+            let span = get_last_span(
+                [assertion_metadata.get(*val), negation_metadata.get(*val)]
+                    .into_iter()
+                    .flatten()
+                    .copied(),
+            );
             let error_type = types::AnalysisErrorType::NegatedAssertion {
                 value: (*val).clone(),
                 assertion_metadata: assertion_metadata
@@ -134,12 +146,21 @@ fn analyze_negated_assertions(
             Err(types::AnalysisError {
                 error_type,
                 metadata: Default::default(),
+                span,
             })
         })?;
     }
     Ok(())
 }
 
+/// Returns the span of the comparison appearing last in the text of the program, which is where
+/// an error involving all of the given comparisons becomes apparent
+fn get_last_span<'a>(metadata: impl Iterator<Item = &'a Metadata>) -> Option<ast::Span> {
+    metadata
+        .filter_map(ast::Span::from_metadata)
+        .max_by_key(|span| (span.line, span.column))
+}
+
 fn perform_condition_analyses(
     context: &types::ConjunctiveContext<'_>,
 ) -> Result<(), types::AnalysisError> {
@@ -213,9 +234,22 @@ fn perform_context_analyses(
     let mut memo = Memoization::new();
     knowledge_graph
         .perform_context_analysis(context, &mut memo, None)
-        .map_err(|err| types::AnalysisError {
-            error_type: types::AnalysisErrorType::GraphAnalysis(err, memo),
-            metadata: Default::default(),
+        .map_err(|err| {
+            let span = match &err {
+                graph::AnalysisError::AssertionTrace { metadata, .. } => {
+                    ast::Span::from_metadata(metadata)
+                }
+                graph::AnalysisError::NegationTrace { metadata, .. } => {
+                    get_last_span(metadata.iter())
+                }
+                graph::AnalysisError::Graph(_) => None,
+            };
+
+            types::AnalysisError {
+                error_type: types::AnalysisErrorType::GraphAnalysis(err, memo),
+                metadata: Default::default(),
+                span,
+            }
         })?;
     Ok(())
 }
@@ -231,6 +265,7 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
     while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
         metadata: Default::default(),
         error_type: types::AnalysisErrorType::StateMachine(err),
+        span: None,
     })? {
         perform_context_analyses(ctx, knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH))?;
     }
@@ -295,6 +330,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_analysis_error_span() {
+        let program_str = "default: [\"stripe\"]\n\nrule_1: [\"adyen\"]\n{\n    payment_method = wallet {\n        amount > 500 & payment_method = card\n    }\n}\n";
+
+        let (program, source_map) =
+            ast::parser::parse_program_with_source_map::<DummyOutput>(program_str)
+                .expect("Program");
+
+        // Spans are only reported for programs annotated with them
+        let analysis_result = analyze(program.clone(), None);
+        assert!(matches!(
+            analysis_result,
+            Err(types::AnalysisError { span: None, .. })
+        ));
+
+        let analysis_result = analyze(source_map.annotate(&program), None);
+
+        if let Err(types::AnalysisError {
+            error_type: types::AnalysisErrorType::ConflictingAssertions { .. },
+            span,
+            ..
+        }) = analysis_result
+        {
+            assert_eq!(
+                span,
+                Some(ast::Span {
+                    line: 6,
+                    column: 24
+                }),
+                "Span should point to payment_method = card"
+            );
+        } else {
+            panic!("Did not receive conflicting assertions error");
+        }
+    }
+
     #[test]
     fn test_exhaustive_negation_detection() {
         let program_str = r#"
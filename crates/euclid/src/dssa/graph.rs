@@ -70,6 +70,14 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::CardRedirectType(crt) => crt.to_string(),
             Self::RealTimePaymentType(rtpt) => rtpt.to_string(),
             Self::OpenBankingType(ob) => ob.to_string(),
+            Self::IssuerCountry(ic) => ic.to_string(),
+            Self::CardIssuer(ci) => ci.value.clone(),
+            Self::IsNetworkToken(int) => int.to_string(),
+            Self::CustomerId(cid) => cid.value.clone(),
+            Self::CustomerSegment(cs) => cs.value.clone(),
+            Self::DayOfWeek(dow) => dow.to_string(),
+            Self::HourOfDay(hod) => hod.number.to_string(),
+            Self::ConvertedAmount(ca) => ca.number.to_string(),
         }
     }
 }
@@ -243,6 +251,19 @@ pub trait CgraphExt {
         domains: Option<&[String]>,
     ) -> Result<bool, cgraph::GraphError<dir::DirValue>>;
 
+    /// Same as `check_value_validity`, but returns the constraints which made the value invalid
+    /// instead, which is empty if the value is valid. Returns `None` if the value isn't present
+    /// in the graph.
+    fn explain_value_validity(
+        &self,
+        val: dir::DirValue,
+        analysis_ctx: &AnalysisContext,
+        domains: Option<&[String]>,
+    ) -> Result<
+        Option<Vec<cgraph::UnsatisfiedConstraint<dir::DirValue>>>,
+        cgraph::GraphError<dir::DirValue>,
+    >;
+
     fn key_value_analysis(
         &self,
         val: dir::DirValue,
@@ -360,6 +381,44 @@ impl CgraphExt for cgraph::ConstraintGraph<dir::DirValue> {
         }
     }
 
+    fn explain_value_validity(
+        &self,
+        val: dir::DirValue,
+        analysis_ctx: &AnalysisContext,
+        domains: Option<&[String]>,
+    ) -> Result<
+        Option<Vec<cgraph::UnsatisfiedConstraint<dir::DirValue>>>,
+        cgraph::GraphError<dir::DirValue>,
+    > {
+        let Some(node_id) = self.value_map.get(&cgraph::NodeValue::Value(val)).copied() else {
+            return Ok(None);
+        };
+
+        let mut memo = cgraph::Memoization::new();
+        let result = self.check_node(
+            analysis_ctx,
+            node_id,
+            cgraph::Relation::Positive,
+            cgraph::Strength::Weak,
+            &mut memo,
+            &mut cgraph::CycleCheck::new(),
+            domains,
+        );
+
+        match result {
+            Ok(_) => Ok(Some(Vec::new())),
+            Err(e) => {
+                e.get_analysis_trace()?;
+                Ok(Some(self.get_unsatisfied_constraints(
+                    node_id,
+                    cgraph::Relation::Positive,
+                    cgraph::Strength::Weak,
+                    &memo,
+                )))
+            }
+        }
+    }
+
     fn key_value_analysis(
         &self,
         val: dir::DirValue,
@@ -807,6 +866,68 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_explain_value_validity() {
+        let graph = knowledge! {
+            PaymentMethod(in [Card, Wallet]) ->> CaptureMethod(Automatic);
+        };
+        let capture_node = *graph
+            .value_map
+            .get(&cgraph::NodeValue::Value(dirval!(
+                CaptureMethod = Automatic
+            )))
+            .expect("Expected capture method node");
+
+        let satisfied = graph
+            .explain_value_validity(
+                dirval!(CaptureMethod = Automatic),
+                &AnalysisContext::from_dir_values([
+                    dirval!(CaptureMethod = Automatic),
+                    dirval!(PaymentMethod = Card),
+                ]),
+                None,
+            )
+            .expect("Expected analysis to succeed");
+        assert!(satisfied.expect("Expected value in graph").is_empty());
+
+        let unsatisfied = graph
+            .explain_value_validity(
+                dirval!(CaptureMethod = Automatic),
+                &AnalysisContext::from_dir_values([
+                    dirval!(CaptureMethod = Automatic),
+                    dirval!(PaymentMethod = PayLater),
+                ]),
+                None,
+            )
+            .expect("Expected analysis to succeed")
+            .expect("Expected value in graph");
+
+        assert_eq!(unsatisfied.len(), 1);
+        let constraint = unsatisfied
+            .first()
+            .expect("Expected unsatisfied constraint");
+        assert_eq!(constraint.path.len(), 1);
+        let edge = constraint.path.first().expect("Expected edge in path");
+        assert_eq!(edge.succ, capture_node);
+        assert_eq!(edge.pred, constraint.node_id);
+        assert!(matches!(
+            constraint.trace.as_ref(),
+            cgraph::AnalysisTrace::InAggregation {
+                found: Some(dir::DirValue::PaymentMethod(enums::PaymentMethod::PayLater)),
+                ..
+            }
+        ));
+
+        let missing = graph
+            .explain_value_validity(
+                dirval!(CaptureMethod = Manual),
+                &AnalysisContext::from_dir_values([dirval!(CaptureMethod = Manual)]),
+                None,
+            )
+            .expect("Expected analysis to succeed");
+        assert!(missing.is_none());
+    }
+
     #[test]
     fn test_memoization_in_kgraph() {
         let mut builder = cgraph::ConstraintGraphBuilder::new();
@@ -79,10 +79,17 @@ pub struct AnalysisError {
     #[serde(flatten)]
     pub error_type: AnalysisErrorType,
     pub metadata: Metadata,
+    /// The position of the offending comparison, when the program was parsed from text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<ast::Span>,
 }
 impl fmt::Display for AnalysisError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.error_type.fmt(f)
+        self.error_type.fmt(f)?;
+        if let Some(span) = &self.span {
+            write!(f, " at {span}")?;
+        }
+        Ok(())
     }
 }
 #[derive(Debug, Clone, Serialize)]
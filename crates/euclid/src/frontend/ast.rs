@@ -1,6 +1,7 @@
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
+pub mod printer;
 
 use common_enums::RoutableConnectors;
 use common_utils::types::MinorUnit;
@@ -9,6 +10,94 @@ use utoipa::ToSchema;
 
 use crate::types::{DataType, Metadata};
 
+/// The key under which [`SourceMap::annotate`] records the [`Span`] of a comparison in its metadata
+pub const SPAN_METADATA_KEY: &str = "span";
+
+/// The position of a comparison in the text of a program, with lines and columns starting at 1
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    /// Computes the line and column of the byte `offset` into `source`
+    pub fn from_offset(source: &str, offset: usize) -> Self {
+        let preceding = source.get(..offset).unwrap_or(source);
+        let line = preceding.matches('\n').count() + 1;
+        let column = preceding
+            .rsplit('\n')
+            .next()
+            .map_or(0, |line| line.chars().count())
+            + 1;
+
+        Self { line, column }
+    }
+
+    /// Returns the span recorded in the metadata of a comparison by [`SourceMap::annotate`]
+    pub fn from_metadata(metadata: &Metadata) -> Option<Self> {
+        metadata
+            .get(SPAN_METADATA_KEY)
+            .and_then(|span| serde_json::from_value(span.clone()).ok())
+    }
+}
+
+impl std::fmt::Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// The positions of the comparisons of a program parsed from text.
+///
+/// The positions are kept apart from the program, so that they are not persisted along with it
+/// and the program is the same as one parsed from a differently formatted text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    /// Spans of the comparisons, in the order in which they appear in the text
+    spans: Vec<Span>,
+}
+
+impl SourceMap {
+    pub fn new(spans: Vec<Span>) -> Self {
+        Self { spans }
+    }
+
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// Returns a copy of the program with the span of each comparison recorded in its metadata,
+    /// for the analyzer to report along with the errors the comparison leads to. The copy is only
+    /// meant to be analyzed, and not to be persisted.
+    pub fn annotate<O: Clone>(&self, program: &Program<O>) -> Program<O> {
+        let mut program = program.clone();
+        let mut spans = self.spans.iter();
+
+        program
+            .rules
+            .iter_mut()
+            .flat_map(|rule| rule.statements.iter_mut())
+            .for_each(|statement| annotate_statement(statement, &mut spans));
+
+        program
+    }
+}
+
+/// Records spans in the comparisons of the statement in the order in which they appear in the
+/// text, which is that of the conditions of the statement followed by its nested statements
+fn annotate_statement<'a>(statement: &mut IfStatement, spans: &mut impl Iterator<Item = &'a Span>) {
+    for (comparison, span) in statement.condition.iter_mut().zip(spans.by_ref()) {
+        comparison
+            .metadata
+            .insert(SPAN_METADATA_KEY.to_string(), serde_json::json!(span));
+    }
+
+    for nested in statement.nested.iter_mut().flatten() {
+        annotate_statement(nested, spans);
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct ConnectorChoice {
     pub connector: RoutableConnectors,
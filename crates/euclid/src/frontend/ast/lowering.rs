@@ -276,6 +276,12 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::RealTimePaymentType => lower_enum!(RealTimePaymentType, value),
 
+        dir::DirKeyKind::IssuerCountry => lower_enum!(IssuerCountry, value),
+
+        dir::DirKeyKind::IsNetworkToken => lower_enum!(IsNetworkToken, value),
+
+        dir::DirKeyKind::DayOfWeek => lower_enum!(DayOfWeek, value),
+
         dir::DirKeyKind::CardBin => {
             let validation_closure = |st: &String| -> Result<(), AnalysisErrorType> {
                 if st.len() == 6 && st.chars().all(|x| x.is_ascii_digit()) {
@@ -293,10 +299,34 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::BusinessLabel => lower_str!(BusinessLabel, value),
 
+        dir::DirKeyKind::CardIssuer => lower_str!(CardIssuer, value),
+
+        dir::DirKeyKind::CustomerId => lower_str!(CustomerId, value),
+
+        dir::DirKeyKind::CustomerSegment => lower_str!(CustomerSegment, value),
+
         dir::DirKeyKind::MetaData => lower_metadata!(MetaData, value),
 
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
 
+        dir::DirKeyKind::HourOfDay => {
+            let values = lower_number!(HourOfDay, value, comparison)?;
+            for value in &values {
+                if let dir::DirValue::HourOfDay(hour) = value {
+                    if !(0..24).contains(&hour.number.get_amount_as_i64()) {
+                        Err(AnalysisErrorType::InvalidValue {
+                            key: dir::DirKeyKind::HourOfDay,
+                            value: hour.number.to_string(),
+                            message: Some("Expected an hour between 0 and 23".to_string()),
+                        })?;
+                    }
+                }
+            }
+            Ok(values)
+        }
+
+        dir::DirKeyKind::ConvertedAmount => lower_number!(ConvertedAmount, value, comparison),
+
         dir::DirKeyKind::Connector => Err(AnalysisErrorType::InvalidKey(
             dir::DirKeyKind::Connector.to_string(),
         )),
@@ -319,6 +349,7 @@ fn lower_comparison<O: EuclidDirFilter>(
     };
     let values = lower_comparison_inner::<O>(comp).map_err(|etype| AnalysisError {
         error_type: etype,
+        span: ast::Span::from_metadata(&metadata),
         metadata: metadata.clone(),
     })?;
 
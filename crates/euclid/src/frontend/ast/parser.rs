@@ -1,4 +1,51 @@
-use common_utils::types::MinorUnit;
+//! Parser for the textual syntax of the Euclid Rule DSL
+//!
+//! A program consists of a default output followed by any number of rules, which are tried in
+//! order. Whitespace is insignificant, and `//` starts a comment running to the end of the line.
+//!
+//! ```text
+//! default: [stripe, adyen]
+//!
+//! // Rule names may also be given as strings, eg. "card rule"
+//! card_rule: [70% stripe, 30% adyen:mca_123]
+//! {
+//!     payment_method = card & amount > 1000 {
+//!         card_network = (visa, mastercard)
+//!         card_bin = "424242"
+//!     }
+//!
+//!     "merchant_tier" = "gold"
+//! }
+//! ```
+//!
+//! A rule selects its output when any of its top level statements holds. Each statement is a
+//! conjunction of comparisons joined by `&`, optionally followed by a block of nested statements,
+//! in which case the statement holds when its comparisons and any one of the nested statements
+//! hold. Sibling statements thus act as `else if` branches, and nesting refines a branch.
+//!
+//! Comparisons are made between a key and a value using one of `=`, `/=`, `<`, `<=`, `>` and
+//! `>=`. Values can be
+//! - numbers, eg. `amount >= 500`
+//! - enum variants, eg. `payment_method = card`
+//! - strings in double quotes with `\\` and `\"` escapes, eg. `card_bin = "424242"`
+//! - arrays of numbers or enum variants, matching any of the elements, eg. `currency = (USD, EUR)`
+//! - arrays of number comparisons, matching when any of them holds, eg. `amount = (< 500, > 1000)`
+//!
+//! Metadata is compared by giving both the key and the value as strings, eg. `"tier" = "gold"`.
+//!
+//! Connector selections are lists of connectors in priority order, eg. `[stripe, adyen]`, or
+//! volume splits with the percentage of traffic preceding each connector, eg.
+//! `[70% stripe, 30% adyen]`. A connector may be pinned to a merchant connector account by
+//! following it with the account ID, eg. `stripe:mca_123`.
+//!
+//! The position of every comparison is returned by [`parse_program_with_source_map`] in an
+//! [`ast::SourceMap`], kept apart from the program. The analyzer reports these positions along
+//! with any errors a comparison leads to when given a program annotated with them.
+
+use std::str::FromStr;
+
+use common_enums::RoutableConnectors;
+use common_utils::{id_type::MerchantConnectorAccountId, types::MinorUnit};
 use nom::{
     branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
 };
@@ -9,10 +56,19 @@ pub type ParseResult<T, U> = nom::IResult<T, U, error::VerboseError<T>>;
 pub enum EuclidError {
     InvalidPercentage(String),
     InvalidConnector(String),
+    InvalidMerchantConnectorId(String),
     InvalidOperator(String),
     InvalidNumber(String),
 }
 
+/// An error in the text of a program, along with where it was encountered
+#[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
+#[error("{message} at {span}")]
+pub struct ParseError {
+    pub message: String,
+    pub span: ast::Span,
+}
+
 pub trait EuclidParsable: Sized {
     fn parse_output(input: &str) -> ParseResult<&str, Self>;
 }
@@ -51,18 +107,117 @@ impl EuclidParsable for DummyOutput {
         )(input)
     }
 }
+
+impl EuclidParsable for ast::ConnectorSelection {
+    fn parse_output(input: &str) -> ParseResult<&str, Self> {
+        let volume_split =
+            combinator::map(comma_separated(connector_volume_split), Self::VolumeSplit);
+        let priority = combinator::map(
+            combinator::opt(comma_separated(routable_connector_choice)),
+            |choices| Self::Priority(choices.unwrap_or_default()),
+        );
+
+        error::context(
+            "connector_selection",
+            sequence::delimited(
+                skip_ws(complete::tag("[")),
+                branch::alt((volume_split, priority)),
+                skip_ws(complete::tag("]")),
+            ),
+        )(input)
+    }
+}
+
+/// Parses a comma separated list of elements. Once a comma has been seen, the element following
+/// it must parse, so that errors in the list are reported where they occur.
+fn comma_separated<'a, O>(
+    element: fn(&'a str) -> ParseResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> ParseResult<&'a str, Vec<O>> {
+    combinator::map(
+        sequence::pair(
+            element,
+            multi::many0(sequence::preceded(
+                skip_ws(complete::tag(",")),
+                combinator::cut(element),
+            )),
+        ),
+        |tup: (O, Vec<O>)| {
+            let mut rest = tup.1;
+            rest.insert(0, tup.0);
+            rest
+        },
+    )
+}
+
+pub fn routable_connector_choice(input: &str) -> ParseResult<&str, ast::RoutableConnectorChoice> {
+    let connector = combinator::map_res(identifier, |name: String| {
+        RoutableConnectors::from_str(&name).map_err(|_| EuclidError::InvalidConnector(name))
+    });
+    let merchant_connector_id = combinator::map_res(
+        sequence::preceded(
+            complete::tag(":"),
+            complete::take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-'),
+        ),
+        |id: &str| {
+            MerchantConnectorAccountId::wrap(id.to_string())
+                .map_err(|_| EuclidError::InvalidMerchantConnectorId(id.to_string()))
+        },
+    );
+
+    error::context(
+        "connector",
+        combinator::map(
+            sequence::pair(skip_ws(connector), combinator::opt(merchant_connector_id)),
+            |tup: (RoutableConnectors, Option<MerchantConnectorAccountId>)| {
+                ast::RoutableConnectorChoice {
+                    choice_kind: ast::RoutableChoiceKind::FullStruct,
+                    connector: tup.0,
+                    merchant_connector_id: tup.1,
+                }
+            },
+        ),
+    )(input)
+}
+
+pub fn connector_volume_split(input: &str) -> ParseResult<&str, ast::ConnectorVolumeSplit> {
+    error::context(
+        "connector_volume_split",
+        combinator::map(
+            sequence::pair(skip_ws(percentage), routable_connector_choice),
+            |tup: (u8, ast::RoutableConnectorChoice)| ast::ConnectorVolumeSplit {
+                connector: tup.1,
+                split: tup.0,
+            },
+        ),
+    )(input)
+}
+
+/// Skips whitespace and `//` comments
+pub fn whitespace(input: &str) -> ParseResult<&str, ()> {
+    combinator::value(
+        (),
+        multi::many0_count(branch::alt((
+            pchar::multispace1,
+            sequence::preceded(complete::tag("//"), pchar::not_line_ending),
+        ))),
+    )(input)
+}
+
 pub fn skip_ws<'a, F, O>(inner: F) -> impl FnMut(&'a str) -> ParseResult<&str, O>
 where
     F: FnMut(&'a str) -> ParseResult<&str, O> + 'a,
 {
-    sequence::preceded(pchar::multispace0, inner)
+    sequence::preceded(whitespace, inner)
 }
 
 pub fn num_i64(input: &str) -> ParseResult<&str, i64> {
     error::context(
         "num_i32",
         combinator::map_res(
-            complete::take_while1(|c: char| c.is_ascii_digit()),
+            combinator::recognize(sequence::pair(
+                combinator::opt(complete::tag("-")),
+                complete::take_while1(|c: char| c.is_ascii_digit()),
+            )),
             |o: &str| {
                 o.parse::<i64>()
                     .map_err(|_| EuclidError::InvalidNumber(o.to_string()))
@@ -72,15 +227,24 @@ pub fn num_i64(input: &str) -> ParseResult<&str, i64> {
 }
 
 pub fn string_str(input: &str) -> ParseResult<&str, String> {
+    let escaped = complete::escaped_transform(
+        complete::is_not("\\\""),
+        '\\',
+        branch::alt((
+            combinator::value("\\", complete::tag("\\")),
+            combinator::value("\"", complete::tag("\"")),
+        )),
+    );
+
     error::context(
         "String",
         combinator::map(
             sequence::delimited(
                 complete::tag("\""),
-                complete::take_while1(|c: char| c != '"'),
+                combinator::opt(escaped),
                 complete::tag("\""),
             ),
-            |val: &str| val.to_string(),
+            Option::unwrap_or_default,
         ),
     )(input)
 }
@@ -102,7 +266,7 @@ pub fn percentage(input: &str) -> ParseResult<&str, u8> {
         "volume_split_percentage",
         combinator::map_res(
             sequence::terminated(
-                complete::take_while_m_n(1, 2, |c: char| c.is_ascii_digit()),
+                complete::take_while_m_n(1, 3, |c: char| c.is_ascii_digit()),
                 complete::tag("%"),
             ),
             |o: &str| {
@@ -201,14 +365,18 @@ pub fn number_comparison(input: &str) -> ParseResult<&str, ast::NumberComparison
         branch::alt((
             complete::tag(">="),
             complete::tag("<="),
+            complete::tag("/="),
             complete::tag(">"),
             complete::tag("<"),
+            complete::tag("="),
         )),
         |s: &str| match s {
             ">=" => Ok(ast::ComparisonType::GreaterThanEqual),
             "<=" => Ok(ast::ComparisonType::LessThanEqual),
+            "/=" => Ok(ast::ComparisonType::NotEqual),
             ">" => Ok(ast::ComparisonType::GreaterThan),
             "<" => Ok(ast::ComparisonType::LessThan),
+            "=" => Ok(ast::ComparisonType::Equal),
             _ => Err(EuclidError::InvalidOperator(s.to_string())),
         },
     );
@@ -216,7 +384,7 @@ pub fn number_comparison(input: &str) -> ParseResult<&str, ast::NumberComparison
     error::context(
         "number_comparison",
         combinator::map(
-            sequence::pair(operator, num_i64),
+            sequence::pair(operator, skip_ws(num_i64)),
             |tup: (ast::ComparisonType, i64)| ast::NumberComparison {
                 comparison_type: tup.0,
                 number: MinorUnit::new(tup.1),
@@ -332,71 +500,113 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
     )(input)
 }
 
-pub fn comparison_array(input: &str) -> ParseResult<&str, Vec<ast::Comparison>> {
+/// Parses a comparison along with the length of the input remaining at its start, from which
+/// [`parse_program_with_source_map`] resolves the position of the comparison in the text
+fn spanned_comparison(input: &str) -> ParseResult<&str, (ast::Comparison, usize)> {
+    let (input, _) = whitespace(input)?;
+    let remaining = input.len();
+
+    combinator::map(
+        branch::alt((comparison, arbitrary_comparison)),
+        move |comparison: ast::Comparison| (comparison, remaining),
+    )(input)
+}
+
+/// Separates parsed elements from the remaining input lengths recorded for their comparisons,
+/// retaining the order of the comparisons
+fn unzip_remaining_lengths<T>(elements: Vec<(T, Vec<usize>)>) -> (Vec<T>, Vec<usize>) {
+    elements.into_iter().fold(
+        (Vec::new(), Vec::new()),
+        |(mut elements, mut remaining_lengths), (element, element_remaining_lengths)| {
+            elements.push(element);
+            remaining_lengths.extend(element_remaining_lengths);
+            (elements, remaining_lengths)
+        },
+    )
+}
+
+fn spanned_comparison_array(input: &str) -> ParseResult<&str, (Vec<ast::Comparison>, Vec<usize>)> {
     let many_with_ampersand = error::context(
         "many_with_amp",
-        multi::many0(sequence::preceded(skip_ws(complete::tag("&")), comparison)),
+        multi::many0(sequence::preceded(
+            skip_ws(complete::tag("&")),
+            spanned_comparison,
+        )),
     );
 
-    let full_sequence = sequence::pair(
-        skip_ws(branch::alt((comparison, arbitrary_comparison))),
-        many_with_ampersand,
-    );
+    let full_sequence = sequence::pair(spanned_comparison, many_with_ampersand);
 
     error::context(
         "comparison_array",
         combinator::map(
             full_sequence,
-            |tup: (ast::Comparison, Vec<ast::Comparison>)| {
+            |tup: ((ast::Comparison, usize), Vec<(ast::Comparison, usize)>)| {
                 let mut rest = tup.1;
                 rest.insert(0, tup.0);
-                rest
+                rest.into_iter().unzip::<_, _, Vec<_>, Vec<_>>()
             },
         ),
     )(input)
 }
 
-pub fn if_statement(input: &str) -> ParseResult<&str, ast::IfStatement> {
+pub fn comparison_array(input: &str) -> ParseResult<&str, Vec<ast::Comparison>> {
+    combinator::map(spanned_comparison_array, |tup| tup.0)(input)
+}
+
+fn spanned_if_statement(input: &str) -> ParseResult<&str, (ast::IfStatement, Vec<usize>)> {
     let nested_block = sequence::delimited(
         skip_ws(complete::tag("{")),
-        multi::many0(if_statement),
+        multi::many0(spanned_if_statement),
         skip_ws(complete::tag("}")),
     );
 
     error::context(
         "if_statement",
         combinator::map(
-            sequence::pair(comparison_array, combinator::opt(nested_block)),
-            |tup: (ast::IfCondition, Option<Vec<ast::IfStatement>>)| ast::IfStatement {
-                condition: tup.0,
-                nested: tup.1,
+            sequence::pair(spanned_comparison_array, combinator::opt(nested_block)),
+            |tup: (
+                (ast::IfCondition, Vec<usize>),
+                Option<Vec<(ast::IfStatement, Vec<usize>)>>,
+            )| {
+                let ((condition, mut remaining_lengths), nested) = tup;
+                let nested = nested.map(|nested| {
+                    let (nested, nested_remaining_lengths) = unzip_remaining_lengths(nested);
+                    remaining_lengths.extend(nested_remaining_lengths);
+                    nested
+                });
+
+                (ast::IfStatement { condition, nested }, remaining_lengths)
             },
         ),
     )(input)
 }
 
-pub fn rule_conditions_array(input: &str) -> ParseResult<&str, Vec<ast::IfStatement>> {
+pub fn if_statement(input: &str) -> ParseResult<&str, ast::IfStatement> {
+    combinator::map(spanned_if_statement, |tup| tup.0)(input)
+}
+
+fn spanned_rule_conditions_array(
+    input: &str,
+) -> ParseResult<&str, (Vec<ast::IfStatement>, Vec<usize>)> {
     error::context(
         "rules_array",
-        sequence::delimited(
-            skip_ws(complete::tag("{")),
-            multi::many1(if_statement),
-            skip_ws(complete::tag("}")),
+        combinator::map(
+            sequence::delimited(
+                skip_ws(complete::tag("{")),
+                multi::many0(spanned_if_statement),
+                skip_ws(complete::tag("}")),
+            ),
+            unzip_remaining_lengths,
         ),
     )(input)
 }
 
-pub fn rule<O: EuclidParsable>(input: &str) -> ParseResult<&str, ast::Rule<O>> {
-    let rule_name = error::context(
-        "rule_name",
-        combinator::map(
-            skip_ws(sequence::pair(
-                complete::take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
-                complete::take_while(|c: char| c.is_ascii_alphanumeric() || c == '_'),
-            )),
-            |out: (&str, &str)| out.0.to_string() + out.1,
-        ),
-    );
+pub fn rule_conditions_array(input: &str) -> ParseResult<&str, Vec<ast::IfStatement>> {
+    combinator::map(spanned_rule_conditions_array, |tup| tup.0)(input)
+}
+
+fn spanned_rule<O: EuclidParsable>(input: &str) -> ParseResult<&str, (ast::Rule<O>, Vec<usize>)> {
+    let rule_name = error::context("rule_name", skip_ws(branch::alt((identifier, string_str))));
 
     let connector_selection = error::context(
         "parse_output",
@@ -406,16 +616,31 @@ pub fn rule<O: EuclidParsable>(input: &str) -> ParseResult<&str, ast::Rule<O>> {
     error::context(
         "rule",
         combinator::map(
-            sequence::tuple((rule_name, connector_selection, rule_conditions_array)),
-            |tup: (String, O, Vec<ast::IfStatement>)| ast::Rule {
-                name: tup.0,
-                connector_selection: tup.1,
-                statements: tup.2,
+            // Once the name of a rule has been parsed, errors are reported from within the rule
+            // instead of ending the list of rules
+            sequence::tuple((
+                rule_name,
+                combinator::cut(connector_selection),
+                combinator::cut(spanned_rule_conditions_array),
+            )),
+            |tup: (String, O, (Vec<ast::IfStatement>, Vec<usize>))| {
+                let (statements, remaining_lengths) = tup.2;
+                let rule = ast::Rule {
+                    name: tup.0,
+                    connector_selection: tup.1,
+                    statements,
+                };
+
+                (rule, remaining_lengths)
             },
         ),
     )(input)
 }
 
+pub fn rule<O: EuclidParsable>(input: &str) -> ParseResult<&str, ast::Rule<O>> {
+    combinator::map(spanned_rule::<O>, |tup| tup.0)(input)
+}
+
 pub fn output<O: EuclidParsable>(input: &str) -> ParseResult<&str, O> {
     O::parse_output(input)
 }
@@ -430,16 +655,152 @@ pub fn default_output<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&
     )(input)
 }
 
-pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, ast::Program<O>> {
+fn spanned_program<O: EuclidParsable + 'static>(
+    input: &str,
+) -> ParseResult<&str, (ast::Program<O>, Vec<usize>)> {
     error::context(
         "program",
         combinator::map(
-            sequence::pair(default_output, multi::many1(skip_ws(rule::<O>))),
-            |tup: (O, Vec<ast::Rule<O>>)| ast::Program {
-                default_selection: tup.0,
-                rules: tup.1,
-                metadata: std::collections::HashMap::new(),
+            sequence::pair(default_output, multi::many0(skip_ws(spanned_rule::<O>))),
+            |tup: (O, Vec<(ast::Rule<O>, Vec<usize>)>)| {
+                let (rules, remaining_lengths) = unzip_remaining_lengths(tup.1);
+                let program = ast::Program {
+                    default_selection: tup.0,
+                    rules,
+                    metadata: std::collections::HashMap::new(),
+                };
+
+                (program, remaining_lengths)
             },
         ),
     )(input)
 }
+
+pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, ast::Program<O>> {
+    combinator::map(spanned_program::<O>, |tup| tup.0)(input)
+}
+
+/// Parses the complete text of a program, reporting where in the text any error was encountered
+pub fn parse_program<O: EuclidParsable + 'static>(
+    source: &str,
+) -> Result<ast::Program<O>, ParseError> {
+    parse_program_with_source_map(source).map(|(program, _)| program)
+}
+
+/// Parses the complete text of a program along with the positions of its comparisons, reporting
+/// where in the text any error was encountered
+pub fn parse_program_with_source_map<O: EuclidParsable + 'static>(
+    source: &str,
+) -> Result<(ast::Program<O>, ast::SourceMap), ParseError> {
+    let span_at =
+        |remaining: usize| ast::Span::from_offset(source, source.len().saturating_sub(remaining));
+    let error_at = |remaining: &str, message: String| ParseError {
+        message,
+        span: span_at(remaining.len()),
+    };
+
+    match sequence::terminated(spanned_program::<O>, whitespace)(source) {
+        Ok(("", (program, remaining_lengths))) => {
+            let spans = remaining_lengths.into_iter().map(&span_at).collect();
+            Ok((program, ast::SourceMap::new(spans)))
+        }
+        Ok((remaining, _)) => Err(error_at(remaining, "Unexpected input".to_string())),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            // The first error is the innermost one, and hence points closest to the problem
+            let remaining = err.errors.first().map_or("", |(remaining, _)| remaining);
+            let message = err
+                .errors
+                .iter()
+                .find_map(|(_, kind)| match kind {
+                    error::VerboseErrorKind::Context(context) => {
+                        Some(format!("Failed to parse {context}"))
+                    }
+                    error::VerboseErrorKind::Char(_) | error::VerboseErrorKind::Nom(_) => None,
+                })
+                .unwrap_or_else(|| "Invalid syntax".to_string());
+
+            Err(error_at(remaining, message))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(error_at("", "Unexpected end of input".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_parse_error_span() {
+        let program_str =
+            "default: [stripe]\n\nrule_1: [stripe, not_a_connector]\n{\n    amount > 100\n}\n";
+
+        let error = parse_program::<ast::ConnectorSelection>(program_str)
+            .expect_err("Program should not parse");
+
+        assert_eq!(
+            error.span,
+            ast::Span {
+                line: 3,
+                column: 18
+            }
+        );
+    }
+
+    #[test]
+    fn test_source_map_is_kept_apart_from_program() {
+        let program_str = "default: [stripe]\n\nrule_1: [adyen]\n{\n    payment_method = card & amount > 100 {\n        card_network = visa\n    }\n    currency = USD\n}\n";
+
+        let (program, source_map) =
+            parse_program_with_source_map::<ast::ConnectorSelection>(program_str).expect("Program");
+
+        assert_eq!(
+            source_map.spans(),
+            [
+                ast::Span { line: 5, column: 5 },
+                ast::Span {
+                    line: 5,
+                    column: 29
+                },
+                ast::Span { line: 6, column: 9 },
+                ast::Span { line: 8, column: 5 },
+            ]
+        );
+
+        let comparison_metadata = program
+            .rules
+            .iter()
+            .flat_map(|rule| rule.statements.iter())
+            .flat_map(|statement| {
+                statement.condition.iter().chain(
+                    statement
+                        .nested
+                        .iter()
+                        .flatten()
+                        .flat_map(|nested| nested.condition.iter()),
+                )
+            })
+            .map(|comparison| &comparison.metadata)
+            .collect::<Vec<_>>();
+        assert_eq!(comparison_metadata.len(), 4);
+        assert!(comparison_metadata
+            .iter()
+            .all(|metadata| metadata.is_empty()));
+        assert!(program.metadata.is_empty());
+
+        let annotated = source_map.annotate(&program);
+        let nested_comparison = annotated
+            .rules
+            .first()
+            .and_then(|rule| rule.statements.first())
+            .and_then(|statement| statement.nested.as_ref())
+            .and_then(|nested| nested.first())
+            .and_then(|nested| nested.condition.first())
+            .expect("Nested comparison");
+        assert_eq!(
+            ast::Span::from_metadata(&nested_comparison.metadata),
+            Some(ast::Span { line: 6, column: 9 })
+        );
+    }
+}
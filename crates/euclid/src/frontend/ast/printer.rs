@@ -0,0 +1,382 @@
+//! Pretty printer for the Euclid Rule DSL
+//!
+//! Emits programs in the textual syntax accepted by the [`parser`](super::parser), such that
+//! parsing the printed text yields the same program. Metadata of the program and its comparisons
+//! has no textual representation, hence it is left out of the printed text and reported alongside
+//! it instead.
+
+use crate::{frontend::ast, types::DummyOutput};
+
+const INDENT: &str = "    ";
+
+/// Metadata which was left out of a printed program, as it cannot be represented in the DSL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OmittedMetadata {
+    Program,
+    Comparison { rule: String, lhs: String },
+}
+
+impl std::fmt::Display for OmittedMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Program => write!(f, "Metadata of the program"),
+            Self::Comparison { rule, lhs } => {
+                write!(f, "Metadata of the comparison on `{lhs}` in rule `{rule}`")
+            }
+        }
+    }
+}
+
+/// A program printed in the textual syntax of the DSL
+#[derive(Debug, Clone)]
+pub struct PrintedProgram {
+    pub text: String,
+    /// Metadata of the program which is not part of the text, parsing the text yields the program
+    /// without it
+    pub omitted_metadata: Vec<OmittedMetadata>,
+}
+
+pub trait EuclidPrintable {
+    fn print_output(&self) -> String;
+}
+
+impl EuclidPrintable for DummyOutput {
+    fn print_output(&self) -> String {
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| print_string(output))
+            .collect::<Vec<_>>();
+
+        format!("[{}]", outputs.join(", "))
+    }
+}
+
+impl EuclidPrintable for ast::ConnectorSelection {
+    fn print_output(&self) -> String {
+        let connectors = match self {
+            Self::Priority(choices) => choices
+                .iter()
+                .map(print_connector_choice)
+                .collect::<Vec<_>>(),
+            Self::VolumeSplit(splits) => splits
+                .iter()
+                .map(|split| {
+                    format!(
+                        "{}% {}",
+                        split.split,
+                        print_connector_choice(&split.connector)
+                    )
+                })
+                .collect(),
+        };
+
+        format!("[{}]", connectors.join(", "))
+    }
+}
+
+fn print_connector_choice(choice: &ast::RoutableConnectorChoice) -> String {
+    match &choice.merchant_connector_id {
+        Some(merchant_connector_id) => format!(
+            "{}:{}",
+            choice.connector,
+            merchant_connector_id.get_string_repr()
+        ),
+        None => choice.connector.to_string(),
+    }
+}
+
+fn print_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn print_comparison_type(comparison_type: &ast::ComparisonType) -> &'static str {
+    match comparison_type {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+fn print_value(value: &ast::ValueType) -> String {
+    match value {
+        ast::ValueType::Number(number) => number.get_amount_as_i64().to_string(),
+        ast::ValueType::EnumVariant(variant) => variant.clone(),
+        ast::ValueType::MetadataVariant(metadata) => print_string(&metadata.value),
+        ast::ValueType::StrValue(value) => print_string(value),
+        ast::ValueType::NumberArray(numbers) => {
+            let numbers = numbers
+                .iter()
+                .map(|number| number.get_amount_as_i64().to_string())
+                .collect::<Vec<_>>();
+            format!("({})", numbers.join(", "))
+        }
+        ast::ValueType::EnumVariantArray(variants) => format!("({})", variants.join(", ")),
+        ast::ValueType::NumberComparisonArray(comparisons) => {
+            let comparisons = comparisons
+                .iter()
+                .map(|comparison| {
+                    format!(
+                        "{} {}",
+                        print_comparison_type(&comparison.comparison_type),
+                        comparison.number.get_amount_as_i64()
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!("({})", comparisons.join(", "))
+        }
+    }
+}
+
+fn print_comparison(comparison: &ast::Comparison) -> String {
+    let lhs = match &comparison.value {
+        ast::ValueType::MetadataVariant(metadata) => print_string(&metadata.key),
+        _ => comparison.lhs.clone(),
+    };
+
+    format!(
+        "{} {} {}",
+        lhs,
+        print_comparison_type(&comparison.comparison),
+        print_value(&comparison.value)
+    )
+}
+
+fn print_if_statement(
+    out: &mut String,
+    omitted_metadata: &mut Vec<OmittedMetadata>,
+    rule_name: &str,
+    statement: &ast::IfStatement,
+    depth: usize,
+) {
+    let indent = INDENT.repeat(depth);
+    let condition = statement
+        .condition
+        .iter()
+        .map(|comparison| {
+            if !comparison.metadata.is_empty() {
+                omitted_metadata.push(OmittedMetadata::Comparison {
+                    rule: rule_name.to_string(),
+                    lhs: comparison.lhs.clone(),
+                });
+            }
+            print_comparison(comparison)
+        })
+        .collect::<Vec<_>>();
+
+    out.push_str(&indent);
+    out.push_str(&condition.join(" & "));
+
+    match &statement.nested {
+        Some(nested) if nested.is_empty() => out.push_str(" {}\n"),
+        Some(nested) => {
+            out.push_str(" {\n");
+            for nested_statement in nested {
+                print_if_statement(
+                    out,
+                    omitted_metadata,
+                    rule_name,
+                    nested_statement,
+                    depth + 1,
+                );
+            }
+            out.push_str(&indent);
+            out.push_str("}\n");
+        }
+        None => out.push('\n'),
+    }
+}
+
+fn print_rule<O: EuclidPrintable>(
+    out: &mut String,
+    omitted_metadata: &mut Vec<OmittedMetadata>,
+    rule: &ast::Rule<O>,
+) {
+    let name = if is_identifier(&rule.name) {
+        rule.name.clone()
+    } else {
+        print_string(&rule.name)
+    };
+
+    out.push_str(&format!(
+        "{}: {}\n{{\n",
+        name,
+        rule.connector_selection.print_output()
+    ));
+    for statement in &rule.statements {
+        print_if_statement(out, omitted_metadata, &rule.name, statement, 1);
+    }
+    out.push_str("}\n");
+}
+
+/// Prints a program in the textual syntax of the DSL, along with the metadata left out of it
+pub fn print_program<O: EuclidPrintable>(program: &ast::Program<O>) -> PrintedProgram {
+    let mut omitted_metadata = Vec::new();
+    if !program.metadata.is_empty() {
+        omitted_metadata.push(OmittedMetadata::Program);
+    }
+
+    let mut text = format!("default: {}\n", program.default_selection.print_output());
+
+    for rule in &program.rules {
+        text.push('\n');
+        print_rule(&mut text, &mut omitted_metadata, rule);
+    }
+
+    PrintedProgram {
+        text,
+        omitted_metadata,
+    }
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    #![allow(clippy::expect_used, clippy::indexing_slicing)]
+
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    #[test]
+    fn test_program_round_trip() {
+        let program_str = r#"
+            default: [stripe, adyen]
+
+            // Comments are not retained
+            "card rule": [70% stripe, 30% adyen:mca_123]
+            {
+                payment_method = card & amount = (> 500, <= 1000) {
+                    card_network = (visa, mastercard)
+                    card_bin = "42\"42"
+                }
+
+                "merchant_tier" = "gold" & amount >= -10 {}
+            }
+
+            wallet_rule: []
+            {
+                payment_method /= wallet
+            }
+        "#;
+
+        let program =
+            parser::parse_program::<ast::ConnectorSelection>(program_str).expect("Program");
+        let printed = print_program(&program);
+        assert!(printed.omitted_metadata.is_empty());
+        let printed = printed.text;
+        let reparsed =
+            parser::parse_program::<ast::ConnectorSelection>(&printed).expect("Printed program");
+
+        // Printing and parsing again yields the same program, down to its metadata
+        assert_eq!(
+            serde_json::to_value(&program).expect("Program JSON"),
+            serde_json::to_value(&reparsed).expect("Reparsed program JSON")
+        );
+        assert_eq!(printed, print_program(&reparsed).text);
+        assert_eq!(reparsed.rules.len(), 2);
+        assert_eq!(reparsed.rules[0].name, "card rule");
+        assert!(matches!(
+            &reparsed.rules[0].connector_selection,
+            ast::ConnectorSelection::VolumeSplit(splits) if splits.len() == 2
+        ));
+        assert_eq!(
+            reparsed.rules[0].statements[0]
+                .nested
+                .as_ref()
+                .map(Vec::len),
+            Some(2)
+        );
+        assert_eq!(
+            reparsed.rules[0].statements[1]
+                .nested
+                .as_ref()
+                .map(Vec::len),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_dummy_output_round_trip() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen", "checkout"]
+            {
+                amount > 100
+            }
+        "#;
+
+        let program = parser::parse_program::<DummyOutput>(program_str).expect("Program");
+        let printed = print_program(&program).text;
+
+        assert_eq!(
+            printed,
+            "default: [\"stripe\"]\n\nrule_1: [\"adyen\", \"checkout\"]\n{\n    amount > 100\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_metadata_is_omitted_from_printed_program() {
+        let program_str = r#"
+            default: [stripe]
+
+            card_rule: [adyen]
+            {
+                payment_method = card {
+                    amount > 100
+                }
+            }
+        "#;
+
+        let program =
+            parser::parse_program::<ast::ConnectorSelection>(program_str).expect("Program");
+        let printed_without_metadata = print_program(&program).text;
+
+        let mut with_metadata = program.clone();
+        with_metadata
+            .metadata
+            .insert("author".to_string(), serde_json::json!("dashboard"));
+        with_metadata.rules[0].statements[0]
+            .nested
+            .as_mut()
+            .expect("Nested statements")[0]
+            .condition[0]
+            .metadata
+            .insert("note".to_string(), serde_json::json!("threshold"));
+
+        let printed = print_program(&with_metadata);
+        assert_eq!(printed.text, printed_without_metadata);
+        assert_eq!(
+            printed.omitted_metadata,
+            vec![
+                OmittedMetadata::Program,
+                OmittedMetadata::Comparison {
+                    rule: "card_rule".to_string(),
+                    lhs: "amount".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            printed.omitted_metadata[1].to_string(),
+            "Metadata of the comparison on `amount` in rule `card_rule`"
+        );
+
+        // Parsing the printed text yields the program without its metadata
+        let reparsed =
+            parser::parse_program::<ast::ConnectorSelection>(&printed.text).expect("Program");
+        assert_eq!(
+            serde_json::to_value(&program).expect("Program JSON"),
+            serde_json::to_value(&reparsed).expect("Reparsed program JSON")
+        );
+    }
+}
@@ -278,6 +278,62 @@ pub enum DirKeyKind {
         props(Category = "Payment Method Types")
     )]
     OpenBankingType,
+    #[strum(
+        serialize = "issuer_country",
+        detailed_message = "Country of the bank which issued the payment card",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "issuer_country")]
+    IssuerCountry,
+    #[strum(
+        serialize = "card_issuer",
+        detailed_message = "Name of the bank which issued the payment card",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "card_issuer")]
+    CardIssuer,
+    #[strum(
+        serialize = "is_network_token",
+        detailed_message = "Indicates if the payment is made with a network token",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "is_network_token")]
+    IsNetworkToken,
+    #[strum(
+        serialize = "customer_id",
+        detailed_message = "Identifier of the customer making the payment",
+        props(Category = "Customer")
+    )]
+    #[serde(rename = "customer_id")]
+    CustomerId,
+    #[strum(
+        serialize = "customer_segment",
+        detailed_message = "Segment of the customer, as recorded in the customer metadata",
+        props(Category = "Customer")
+    )]
+    #[serde(rename = "customer_segment")]
+    CustomerSegment,
+    #[strum(
+        serialize = "day_of_week",
+        detailed_message = "Day of the week of the payment in the timezone of the merchant",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "day_of_week")]
+    DayOfWeek,
+    #[strum(
+        serialize = "hour_of_day",
+        detailed_message = "Hour of the day (0 - 23) of the payment in the timezone of the merchant",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "hour_of_day")]
+    HourOfDay,
+    #[strum(
+        serialize = "converted_amount",
+        detailed_message = "Value of the transaction in the reference currency of the merchant",
+        props(Category = "Payments")
+    )]
+    #[serde(rename = "converted_amount")]
+    ConvertedAmount,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -327,6 +383,14 @@ impl DirKeyKind {
             Self::CardRedirectType => types::DataType::EnumVariant,
             Self::RealTimePaymentType => types::DataType::EnumVariant,
             Self::OpenBankingType => types::DataType::EnumVariant,
+            Self::IssuerCountry => types::DataType::EnumVariant,
+            Self::CardIssuer => types::DataType::StrValue,
+            Self::IsNetworkToken => types::DataType::EnumVariant,
+            Self::CustomerId => types::DataType::StrValue,
+            Self::CustomerSegment => types::DataType::StrValue,
+            Self::DayOfWeek => types::DataType::EnumVariant,
+            Self::HourOfDay => types::DataType::Number,
+            Self::ConvertedAmount => types::DataType::Number,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -459,6 +523,22 @@ impl DirKeyKind {
                     .map(DirValue::OpenBankingType)
                     .collect(),
             ),
+            Self::IssuerCountry => Some(
+                enums::IssuerCountry::iter()
+                    .map(DirValue::IssuerCountry)
+                    .collect(),
+            ),
+            Self::CardIssuer => None,
+            Self::IsNetworkToken => Some(
+                enums::IsNetworkToken::iter()
+                    .map(DirValue::IsNetworkToken)
+                    .collect(),
+            ),
+            Self::CustomerId => None,
+            Self::CustomerSegment => None,
+            Self::DayOfWeek => Some(enums::DayOfWeek::iter().map(DirValue::DayOfWeek).collect()),
+            Self::HourOfDay => None,
+            Self::ConvertedAmount => None,
         }
     }
 }
@@ -528,6 +608,22 @@ pub enum DirValue {
     RealTimePaymentType(enums::RealTimePaymentType),
     #[serde(rename = "open_banking")]
     OpenBankingType(enums::OpenBankingType),
+    #[serde(rename = "issuer_country")]
+    IssuerCountry(enums::IssuerCountry),
+    #[serde(rename = "card_issuer")]
+    CardIssuer(types::StrValue),
+    #[serde(rename = "is_network_token")]
+    IsNetworkToken(enums::IsNetworkToken),
+    #[serde(rename = "customer_id")]
+    CustomerId(types::StrValue),
+    #[serde(rename = "customer_segment")]
+    CustomerSegment(types::StrValue),
+    #[serde(rename = "day_of_week")]
+    DayOfWeek(enums::DayOfWeek),
+    #[serde(rename = "hour_of_day")]
+    HourOfDay(types::NumValue),
+    #[serde(rename = "converted_amount")]
+    ConvertedAmount(types::NumValue),
 }
 
 impl DirValue {
@@ -563,6 +659,14 @@ impl DirValue {
             Self::GiftCardType(_) => (DirKeyKind::GiftCardType, None),
             Self::RealTimePaymentType(_) => (DirKeyKind::RealTimePaymentType, None),
             Self::OpenBankingType(_) => (DirKeyKind::OpenBankingType, None),
+            Self::IssuerCountry(_) => (DirKeyKind::IssuerCountry, None),
+            Self::CardIssuer(_) => (DirKeyKind::CardIssuer, None),
+            Self::IsNetworkToken(_) => (DirKeyKind::IsNetworkToken, None),
+            Self::CustomerId(_) => (DirKeyKind::CustomerId, None),
+            Self::CustomerSegment(_) => (DirKeyKind::CustomerSegment, None),
+            Self::DayOfWeek(_) => (DirKeyKind::DayOfWeek, None),
+            Self::HourOfDay(_) => (DirKeyKind::HourOfDay, None),
+            Self::ConvertedAmount(_) => (DirKeyKind::ConvertedAmount, None),
         };
 
         DirKey::new(kind, data)
@@ -599,19 +703,32 @@ impl DirValue {
             Self::CardRedirectType(_) => None,
             Self::RealTimePaymentType(_) => None,
             Self::OpenBankingType(_) => None,
+            Self::IssuerCountry(_) => None,
+            Self::CardIssuer(_) => None,
+            Self::IsNetworkToken(_) => None,
+            Self::CustomerId(_) => None,
+            Self::CustomerSegment(_) => None,
+            Self::DayOfWeek(_) => None,
+            Self::HourOfDay(_) => None,
+            Self::ConvertedAmount(_) => None,
         }
     }
 
     pub fn get_str_val(&self) -> Option<types::StrValue> {
         match self {
-            Self::CardBin(val) => Some(val.clone()),
+            Self::CardBin(val)
+            | Self::CardIssuer(val)
+            | Self::CustomerId(val)
+            | Self::CustomerSegment(val) => Some(val.clone()),
             _ => None,
         }
     }
 
     pub fn get_num_value(&self) -> Option<types::NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val) | Self::HourOfDay(val) | Self::ConvertedAmount(val) => {
+                Some(val.clone())
+            }
             _ => None,
         }
     }
@@ -645,6 +762,12 @@ impl DirValue {
             (Self::UpiType(ut1), Self::UpiType(ut2)) => ut1 == ut2,
             (Self::VoucherType(vt1), Self::VoucherType(vt2)) => vt1 == vt2,
             (Self::CardRedirectType(crt1), Self::CardRedirectType(crt2)) => crt1 == crt2,
+            (Self::IssuerCountry(c1), Self::IssuerCountry(c2)) => c1 == c2,
+            (Self::CardIssuer(ci1), Self::CardIssuer(ci2)) => ci1 == ci2,
+            (Self::IsNetworkToken(int1), Self::IsNetworkToken(int2)) => int1 == int2,
+            (Self::CustomerId(cid1), Self::CustomerId(cid2)) => cid1 == cid2,
+            (Self::CustomerSegment(cs1), Self::CustomerSegment(cs2)) => cs1 == cs2,
+            (Self::DayOfWeek(dow1), Self::DayOfWeek(dow2)) => dow1 == dow2,
             _ => false,
         }
     }
@@ -829,6 +952,14 @@ mod test {
             dirval!(CaptureMethod = Manual),
             dirval!(BillingCountry = UnitedStatesOfAmerica),
             dirval!(BusinessCountry = France),
+            dirval!(IssuerCountry = Germany),
+            dirval!(CardIssuer s= "HDFC Bank"),
+            dirval!(IsNetworkToken = True),
+            dirval!(CustomerId s= "cus_123"),
+            dirval!(CustomerSegment s= "premium"),
+            dirval!(DayOfWeek = Saturday),
+            dirval!(HourOfDay = 18),
+            dirval!(ConvertedAmount = 1000),
         ];
 
         for val in values {
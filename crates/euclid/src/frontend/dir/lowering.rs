@@ -247,6 +247,14 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::BusinessLabel(bl) => EuclidValue::BusinessLabel(bl),
         dir::DirValue::SetupFutureUsage(sfu) => EuclidValue::SetupFutureUsage(sfu),
         dir::DirValue::OpenBankingType(ob) => EuclidValue::PaymentMethodType(ob.into()),
+        dir::DirValue::IssuerCountry(ic) => EuclidValue::IssuerCountry(ic),
+        dir::DirValue::CardIssuer(ci) => EuclidValue::CardIssuer(ci),
+        dir::DirValue::IsNetworkToken(int) => EuclidValue::IsNetworkToken(int),
+        dir::DirValue::CustomerId(cid) => EuclidValue::CustomerId(cid),
+        dir::DirValue::CustomerSegment(cs) => EuclidValue::CustomerSegment(cs),
+        dir::DirValue::DayOfWeek(dow) => EuclidValue::DayOfWeek(dow),
+        dir::DirValue::HourOfDay(hod) => EuclidValue::HourOfDay(hod),
+        dir::DirValue::ConvertedAmount(ca) => EuclidValue::ConvertedAmount(ca),
     })
 }
 
@@ -316,6 +324,7 @@ pub fn lower_program<O>(
             .map_err(|e| AnalysisError {
                 error_type: e,
                 metadata: Default::default(),
+                span: None,
             })?,
         metadata: dir_program.metadata,
     })
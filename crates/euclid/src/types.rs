@@ -60,6 +60,22 @@ pub enum EuclidKey {
     BusinessLabel,
     #[strum(serialize = "setup_future_usage")]
     SetupFutureUsage,
+    #[strum(serialize = "issuer_country")]
+    IssuerCountry,
+    #[strum(serialize = "card_issuer")]
+    CardIssuer,
+    #[strum(serialize = "is_network_token")]
+    IsNetworkToken,
+    #[strum(serialize = "customer_id")]
+    CustomerId,
+    #[strum(serialize = "customer_segment")]
+    CustomerSegment,
+    #[strum(serialize = "day_of_week")]
+    DayOfWeek,
+    #[strum(serialize = "hour_of_day")]
+    HourOfDay,
+    #[strum(serialize = "converted_amount")]
+    ConvertedAmount,
 }
 impl EuclidDirFilter for DummyOutput {
     const ALLOWED: &'static [DirKeyKind] = &[
@@ -77,6 +93,14 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::MandateType,
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
+        DirKeyKind::IssuerCountry,
+        DirKeyKind::CardIssuer,
+        DirKeyKind::IsNetworkToken,
+        DirKeyKind::CustomerId,
+        DirKeyKind::CustomerSegment,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::HourOfDay,
+        DirKeyKind::ConvertedAmount,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -138,6 +162,14 @@ impl EuclidKey {
             Self::PaymentType => DataType::EnumVariant,
             Self::BusinessLabel => DataType::StrValue,
             Self::SetupFutureUsage => DataType::EnumVariant,
+            Self::IssuerCountry => DataType::EnumVariant,
+            Self::CardIssuer => DataType::StrValue,
+            Self::IsNetworkToken => DataType::EnumVariant,
+            Self::CustomerId => DataType::StrValue,
+            Self::CustomerSegment => DataType::StrValue,
+            Self::DayOfWeek => DataType::EnumVariant,
+            Self::HourOfDay => DataType::Number,
+            Self::ConvertedAmount => DataType::Number,
         }
     }
 }
@@ -253,12 +285,22 @@ pub enum EuclidValue {
     BillingCountry(enums::Country),
     BusinessLabel(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
+    IssuerCountry(enums::Country),
+    CardIssuer(StrValue),
+    IsNetworkToken(enums::IsNetworkToken),
+    CustomerId(StrValue),
+    CustomerSegment(StrValue),
+    DayOfWeek(enums::DayOfWeek),
+    HourOfDay(NumValue),
+    ConvertedAmount(NumValue),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val) | Self::HourOfDay(val) | Self::ConvertedAmount(val) => {
+                Some(val.clone())
+            }
             _ => None,
         }
     }
@@ -281,6 +323,14 @@ impl EuclidValue {
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
+            Self::IssuerCountry(_) => EuclidKey::IssuerCountry,
+            Self::CardIssuer(_) => EuclidKey::CardIssuer,
+            Self::IsNetworkToken(_) => EuclidKey::IsNetworkToken,
+            Self::CustomerId(_) => EuclidKey::CustomerId,
+            Self::CustomerSegment(_) => EuclidKey::CustomerSegment,
+            Self::DayOfWeek(_) => EuclidKey::DayOfWeek,
+            Self::HourOfDay(_) => EuclidKey::HourOfDay,
+            Self::ConvertedAmount(_) => EuclidKey::ConvertedAmount,
         }
     }
 }
@@ -262,11 +262,19 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::BankDebitType => dir_enums::BankDebitType::VARIANTS,
         dir::DirKeyKind::RealTimePaymentType => dir_enums::RealTimePaymentType::VARIANTS,
         dir::DirKeyKind::OpenBankingType => dir_enums::OpenBankingType::VARIANTS,
+        dir::DirKeyKind::IssuerCountry => dir_enums::IssuerCountry::VARIANTS,
+        dir::DirKeyKind::IsNetworkToken => dir_enums::IsNetworkToken::VARIANTS,
+        dir::DirKeyKind::DayOfWeek => dir_enums::DayOfWeek::VARIANTS,
 
         dir::DirKeyKind::PaymentAmount
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
         | dir::DirKeyKind::BusinessLabel
+        | dir::DirKeyKind::CardIssuer
+        | dir::DirKeyKind::CustomerId
+        | dir::DirKeyKind::CustomerSegment
+        | dir::DirKeyKind::HourOfDay
+        | dir::DirKeyKind::ConvertedAmount
         | dir::DirKeyKind::MetaData => Err("Key does not have variants".to_string())?,
     };
 
@@ -1,11 +1,14 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 use api_models::routing::{
     CurrentBlockThreshold, RoutableConnectorChoice, RoutableConnectorChoiceWithStatus,
-    SuccessBasedRoutingConfig, SuccessBasedRoutingConfigBody,
+    SuccessBasedRoutingConfig, SuccessBasedRoutingConfigBody, SuccessBasedRoutingConfigParams,
 };
 use common_utils::{errors::CustomResult, ext_traits::OptionExt, transformers::ForeignTryFrom};
+use embedded::{EmbeddedSuccessRateCalculator, EmbeddedSuccessRateConfig};
 use error_stack::ResultExt;
+use redis_interface::RedisConnectionPool;
+use router_env::logger;
 use serde;
 use success_rate::{
     success_rate_calculator_client::SuccessRateCalculatorClient, CalSuccessRateConfig,
@@ -23,6 +26,9 @@ use tonic::transport::Channel;
 pub mod success_rate {
     tonic::include_proto!("success_rate");
 }
+/// In-process success rate calculator
+pub mod embedded;
+
 /// Result type for Dynamic Routing
 pub type DynamicRoutingResult<T> = CustomResult<T, DynamicRoutingError>;
 
@@ -45,6 +51,30 @@ pub enum DynamicRoutingError {
 pub struct RoutingStrategy {
     /// success rate service for Dynamic Routing
     pub success_rate_client: Option<SuccessRateCalculatorClient<Channel>>,
+    /// Configs of the embedded success rate calculator, if it is used either instead of or as a
+    /// fallback for the success rate service
+    pub embedded_success_rate: Option<EmbeddedSuccessRateConfig>,
+}
+
+impl RoutingStrategy {
+    /// Returns the success rate calculator, if one has been configured. The embedded calculator
+    /// stores its windows through the given Redis connection.
+    pub fn get_success_rate_calculator(
+        &self,
+        redis_conn: Arc<RedisConnectionPool>,
+    ) -> Option<SuccessRateCalculator> {
+        if self.success_rate_client.is_none() && self.embedded_success_rate.is_none() {
+            return None;
+        }
+
+        Some(SuccessRateCalculator {
+            client: self.success_rate_client.clone(),
+            embedded: self
+                .embedded_success_rate
+                .clone()
+                .map(|config| EmbeddedSuccessRateCalculator::new(redis_conn, config)),
+        })
+    }
 }
 
 /// Contains the Dynamic Routing Client Config
@@ -57,6 +87,14 @@ pub enum DynamicRoutingClientConfig {
         host: String,
         /// The port of the client
         port: u16,
+        /// Configs of the embedded calculator used while the server is unreachable
+        #[serde(default)]
+        embedded_fallback: Option<EmbeddedSuccessRateConfig>,
+    },
+    /// If the embedded success rate calculator is used instead of the server
+    Embedded {
+        /// Configs of the embedded calculator
+        embedded: EmbeddedSuccessRateConfig,
     },
     #[default]
     /// If the dynamic routing client config has been disabled
@@ -68,20 +106,139 @@ impl DynamicRoutingClientConfig {
     pub async fn get_dynamic_routing_connection(
         self,
     ) -> Result<RoutingStrategy, Box<dyn std::error::Error>> {
-        let success_rate_client = match self {
-            Self::Enabled { host, port } => {
+        let (success_rate_client, embedded_success_rate) = match self {
+            Self::Enabled {
+                host,
+                port,
+                embedded_fallback,
+            } => {
                 let uri = format!("http://{}:{}", host, port);
-                let channel = tonic::transport::Endpoint::new(uri)?.connect().await?;
-                Some(SuccessRateCalculatorClient::new(channel))
+                let endpoint = tonic::transport::Endpoint::new(uri)?;
+                // The connection is established on first use when a fallback is configured, so
+                // that the application can start while the server is unreachable
+                let channel = match embedded_fallback {
+                    Some(_) => endpoint.connect_lazy(),
+                    None => endpoint.connect().await?,
+                };
+                (
+                    Some(SuccessRateCalculatorClient::new(channel)),
+                    embedded_fallback,
+                )
             }
-            Self::Disabled => None,
+            Self::Embedded { embedded } => (None, Some(embedded)),
+            Self::Disabled => (None, None),
         };
         Ok(RoutingStrategy {
             success_rate_client,
+            embedded_success_rate,
         })
     }
 }
 
+/// Success rate calculator which uses the success rate service if it is configured, and the
+/// embedded calculator either if the service is not configured or while it is unreachable
+#[derive(Debug, Clone)]
+pub struct SuccessRateCalculator {
+    client: Option<SuccessRateCalculatorClient<Channel>>,
+    embedded: Option<EmbeddedSuccessRateCalculator>,
+}
+
+impl SuccessRateCalculator {
+    /// Returns the embedded calculator if the error was caused by the service being unreachable
+    fn get_fallback<T>(
+        &self,
+        result: &DynamicRoutingResult<T>,
+    ) -> Option<&EmbeddedSuccessRateCalculator> {
+        let error = result.as_ref().err()?;
+        let is_unavailable = error
+            .downcast_ref::<tonic::Status>()
+            .is_some_and(|status| status.code() == tonic::Code::Unavailable);
+
+        is_unavailable
+            .then_some(self.embedded.as_ref())
+            .flatten()
+            .inspect(|_| {
+                logger::warn!(
+                    ?error,
+                    "Success rate service is unreachable, falling back to the embedded calculator"
+                )
+            })
+    }
+}
+
+#[async_trait::async_trait]
+impl SuccessBasedDynamicRouting for SuccessRateCalculator {
+    async fn calculate_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        label_input: Vec<RoutableConnectorChoice>,
+    ) -> DynamicRoutingResult<CalSuccessRateResponse> {
+        let Some(client) = &self.client else {
+            return self
+                .embedded
+                .as_ref()
+                .get_required_value("embedded")
+                .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                    "No success rate calculator has been configured".to_string(),
+                ))?
+                .calculate_success_rate(id, success_rate_based_config, label_input)
+                .await;
+        };
+
+        let result = client
+            .calculate_success_rate(
+                id.clone(),
+                success_rate_based_config.clone(),
+                label_input.clone(),
+            )
+            .await;
+        match self.get_fallback(&result) {
+            Some(embedded) => {
+                embedded
+                    .calculate_success_rate(id, success_rate_based_config, label_input)
+                    .await
+            }
+            None => result,
+        }
+    }
+
+    async fn update_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        label_input: Vec<RoutableConnectorChoiceWithStatus>,
+    ) -> DynamicRoutingResult<UpdateSuccessRateWindowResponse> {
+        let Some(client) = &self.client else {
+            return self
+                .embedded
+                .as_ref()
+                .get_required_value("embedded")
+                .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                    "No success rate calculator has been configured".to_string(),
+                ))?
+                .update_success_rate(id, success_rate_based_config, label_input)
+                .await;
+        };
+
+        let result = client
+            .update_success_rate(
+                id.clone(),
+                success_rate_based_config.clone(),
+                label_input.clone(),
+            )
+            .await;
+        match self.get_fallback(&result) {
+            Some(embedded) => {
+                embedded
+                    .update_success_rate(id, success_rate_based_config, label_input)
+                    .await
+            }
+            None => result,
+        }
+    }
+}
+
 /// The trait Success Based Dynamic Routing would have the functions required to support the calculation and updation window
 #[async_trait::async_trait]
 pub trait SuccessBasedDynamicRouting: dyn_clone::DynClone + Send + Sync {
@@ -109,21 +266,7 @@ impl SuccessBasedDynamicRouting for SuccessRateCalculatorClient<Channel> {
         success_rate_based_config: SuccessBasedRoutingConfig,
         label_input: Vec<RoutableConnectorChoice>,
     ) -> DynamicRoutingResult<CalSuccessRateResponse> {
-        let params = success_rate_based_config
-            .params
-            .map(|vec| {
-                vec.into_iter().fold(String::new(), |mut acc_str, params| {
-                    if !acc_str.is_empty() {
-                        acc_str.push(':')
-                    }
-                    acc_str.push_str(params.to_string().as_str());
-                    acc_str
-                })
-            })
-            .get_required_value("params")
-            .change_context(DynamicRoutingError::MissingRequiredField {
-                field: "params".to_string(),
-            })?;
+        let params = get_params_string(success_rate_based_config.params)?;
 
         let labels = label_input
             .into_iter()
@@ -174,21 +317,7 @@ impl SuccessBasedDynamicRouting for SuccessRateCalculatorClient<Channel> {
             })
             .collect();
 
-        let params = success_rate_based_config
-            .params
-            .map(|vec| {
-                vec.into_iter().fold(String::new(), |mut acc_str, params| {
-                    if !acc_str.is_empty() {
-                        acc_str.push(':')
-                    }
-                    acc_str.push_str(params.to_string().as_str());
-                    acc_str
-                })
-            })
-            .get_required_value("params")
-            .change_context(DynamicRoutingError::MissingRequiredField {
-                field: "params".to_string(),
-            })?;
+        let params = get_params_string(success_rate_based_config.params)?;
 
         let request = tonic::Request::new(UpdateSuccessRateWindowRequest {
             id,
@@ -211,6 +340,26 @@ impl SuccessBasedDynamicRouting for SuccessRateCalculatorClient<Channel> {
     }
 }
 
+/// Joins the params used to group the outcomes of the labels
+fn get_params_string(
+    params: Option<Vec<SuccessBasedRoutingConfigParams>>,
+) -> DynamicRoutingResult<String> {
+    params
+        .map(|vec| {
+            vec.into_iter().fold(String::new(), |mut acc_str, params| {
+                if !acc_str.is_empty() {
+                    acc_str.push(':')
+                }
+                acc_str.push_str(params.to_string().as_str());
+                acc_str
+            })
+        })
+        .get_required_value("params")
+        .change_context(DynamicRoutingError::MissingRequiredField {
+            field: "params".to_string(),
+        })
+}
+
 impl ForeignTryFrom<CurrentBlockThreshold> for DynamicCurrentThreshold {
     type Error = error_stack::Report<DynamicRoutingError>;
     fn foreign_try_from(current_threshold: CurrentBlockThreshold) -> Result<Self, Self::Error> {
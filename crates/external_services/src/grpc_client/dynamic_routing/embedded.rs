@@ -0,0 +1,410 @@
+//! In-process implementation of the success rate calculator
+//!
+//! The success rate of each label is calculated over a sliding window of blocks stored in Redis.
+//! Outcomes are counted in the current block of the label until it holds `max_total_count`
+//! outcomes or is older than `duration_in_mins`, after which it is moved to the list of completed
+//! blocks. At most `max_aggregates_size` completed blocks are retained, the oldest being dropped
+//! first. The score of a label is its success rate over the completed blocks, in percent, or the
+//! `default_success_rate` while fewer than `min_aggregates_size` blocks have been completed.
+//!
+//! Counts are incremented atomically, but outcomes recorded while a completed block is being
+//! moved may not be counted, which only affects the scores marginally.
+
+use std::sync::Arc;
+
+use api_models::routing::{
+    RoutableConnectorChoice, RoutableConnectorChoiceWithStatus, SuccessBasedRoutingConfig,
+};
+use common_utils::{ext_traits::OptionExt, transformers::ForeignTryFrom};
+use error_stack::ResultExt;
+use redis_interface::{types::SetnxReply, RedisConnectionPool};
+
+use super::{
+    get_params_string,
+    success_rate::{
+        CalSuccessRateConfig, CalSuccessRateResponse, CurrentBlockThreshold, LabelWithScore,
+        UpdateSuccessRateWindowConfig, UpdateSuccessRateWindowResponse,
+    },
+    DynamicRoutingError, DynamicRoutingResult, SuccessBasedDynamicRouting,
+};
+
+/// The time for which a block which is being moved is locked, in seconds
+const BLOCK_ROTATION_LOCK_TTL_IN_SECS: i64 = 5;
+
+/// Configs of the embedded success rate calculator
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct EmbeddedSuccessRateConfig {
+    /// The time after which the window of a label which is no longer updated expires, in seconds
+    pub window_ttl_in_secs: i64,
+}
+
+impl Default for EmbeddedSuccessRateConfig {
+    fn default() -> Self {
+        Self {
+            // 7 days
+            window_ttl_in_secs: 604800,
+        }
+    }
+}
+
+/// Success rate calculator which stores the windows of the labels in Redis
+#[derive(Clone)]
+pub struct EmbeddedSuccessRateCalculator {
+    redis_conn: Arc<RedisConnectionPool>,
+    config: EmbeddedSuccessRateConfig,
+}
+
+impl std::fmt::Debug for EmbeddedSuccessRateCalculator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmbeddedSuccessRateCalculator")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl EmbeddedSuccessRateCalculator {
+    /// Creates a calculator storing the windows of the labels through the given connection
+    pub fn new(redis_conn: Arc<RedisConnectionPool>, config: EmbeddedSuccessRateConfig) -> Self {
+        Self { redis_conn, config }
+    }
+
+    async fn fetch_completed_blocks(&self, window_key: &str) -> DynamicRoutingResult<Vec<Block>> {
+        let blocks = self
+            .redis_conn
+            .get_list_elements(&get_completed_blocks_key(window_key), 0, -1)
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to fetch the success rate window".to_string(),
+            ))?;
+
+        Ok(blocks
+            .iter()
+            .filter_map(|block| Block::parse(block))
+            .collect())
+    }
+
+    async fn record_outcome(
+        &self,
+        window_key: &str,
+        status: bool,
+        config: &UpdateSuccessRateWindowConfig,
+    ) -> DynamicRoutingResult<()> {
+        let current_block_key = get_current_block_key(window_key);
+        let now = common_utils::date_time::now_unix_timestamp();
+        let ttl = u32::try_from(self.config.window_ttl_in_secs).unwrap_or(u32::MAX);
+
+        self.redis_conn
+            .set_hash_field_if_not_exist(&current_block_key, "started_at", now, Some(ttl))
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to update the success rate window".to_string(),
+            ))?;
+        let counts = self
+            .redis_conn
+            .increment_fields_in_hash(
+                &current_block_key,
+                &[("total", 1), ("success", i64::from(status))],
+            )
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to update the success rate window".to_string(),
+            ))?;
+        let started_at = self
+            .redis_conn
+            .get_hash_field::<Option<i64>>(&current_block_key, "started_at")
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to update the success rate window".to_string(),
+            ))?
+            .unwrap_or(now);
+
+        let total = counts.first().copied().unwrap_or_default();
+        if is_block_complete(
+            total,
+            started_at,
+            now,
+            config.current_block_threshold.as_ref(),
+        ) {
+            self.complete_current_block(window_key, config.max_aggregates_size)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Moves the current block of the window to its completed blocks, unless another update is
+    /// already doing so
+    async fn complete_current_block(
+        &self,
+        window_key: &str,
+        max_aggregates_size: u32,
+    ) -> DynamicRoutingResult<()> {
+        let current_block_key = get_current_block_key(window_key);
+        let completed_blocks_key = get_completed_blocks_key(window_key);
+        let lock_key = format!("{window_key}:rotation_lock");
+
+        let lock = self
+            .redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &lock_key,
+                "locked",
+                Some(BLOCK_ROTATION_LOCK_TTL_IN_SECS),
+            )
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to lock the success rate window".to_string(),
+            ))?;
+        if lock != SetnxReply::KeySet {
+            return Ok(());
+        }
+
+        let result = async {
+            let success = self
+                .redis_conn
+                .get_hash_field::<Option<u64>>(&current_block_key, "success")
+                .await?
+                .unwrap_or_default();
+            let total = self
+                .redis_conn
+                .get_hash_field::<Option<u64>>(&current_block_key, "total")
+                .await?
+                .unwrap_or_default();
+            self.redis_conn.delete_key(&current_block_key).await?;
+
+            self.redis_conn
+                .append_elements_to_list(
+                    &completed_blocks_key,
+                    Block { success, total }.to_string(),
+                )
+                .await?;
+            let completed_blocks = self
+                .redis_conn
+                .get_list_length(&completed_blocks_key)
+                .await?;
+            let excess_blocks = completed_blocks
+                .saturating_sub(usize::try_from(max_aggregates_size).unwrap_or(usize::MAX));
+            if excess_blocks > 0 {
+                self.redis_conn
+                    .lpop_list_elements(&completed_blocks_key, Some(excess_blocks))
+                    .await?;
+            }
+            self.redis_conn
+                .set_expiry(&completed_blocks_key, self.config.window_ttl_in_secs)
+                .await
+        }
+        .await
+        .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+            "Failed to update the success rate window".to_string(),
+        ));
+
+        let _ = self
+            .redis_conn
+            .delete_key(&lock_key)
+            .await
+            .map_err(|error| {
+                router_env::logger::error!(
+                    ?error,
+                    "Failed to release the lock of the success rate window"
+                )
+            });
+
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl SuccessBasedDynamicRouting for EmbeddedSuccessRateCalculator {
+    async fn calculate_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        label_input: Vec<RoutableConnectorChoice>,
+    ) -> DynamicRoutingResult<CalSuccessRateResponse> {
+        let params = get_params_string(success_rate_based_config.params)?;
+        let config = success_rate_based_config
+            .config
+            .get_required_value("config")
+            .change_context(DynamicRoutingError::MissingRequiredField {
+                field: "config".to_string(),
+            })
+            .and_then(CalSuccessRateConfig::foreign_try_from)?;
+
+        let mut labels_with_score = Vec::with_capacity(label_input.len());
+        for label in label_input {
+            let label = label.to_string();
+            let blocks = self
+                .fetch_completed_blocks(&get_window_key(&id, &params, &label))
+                .await?;
+            labels_with_score.push(LabelWithScore {
+                score: calculate_score(&blocks, &config),
+                label,
+            });
+        }
+        labels_with_score.sort_by(|label, other_label| other_label.score.total_cmp(&label.score));
+
+        Ok(CalSuccessRateResponse { labels_with_score })
+    }
+
+    async fn update_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        label_input: Vec<RoutableConnectorChoiceWithStatus>,
+    ) -> DynamicRoutingResult<UpdateSuccessRateWindowResponse> {
+        let params = get_params_string(success_rate_based_config.params)?;
+        let config = success_rate_based_config
+            .config
+            .get_required_value("config")
+            .change_context(DynamicRoutingError::MissingRequiredField {
+                field: "config".to_string(),
+            })
+            .and_then(UpdateSuccessRateWindowConfig::foreign_try_from)?;
+
+        for label in label_input {
+            let window_key =
+                get_window_key(&id, &params, &label.routable_connector_choice.to_string());
+            self.record_outcome(&window_key, label.status, &config)
+                .await?;
+        }
+
+        Ok(UpdateSuccessRateWindowResponse {
+            message: "success".to_string(),
+        })
+    }
+}
+
+/// The outcomes counted in a block of the window of a label
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Block {
+    success: u64,
+    total: u64,
+}
+
+impl Block {
+    fn parse(block: &str) -> Option<Self> {
+        let (success, total) = block.split_once(':')?;
+        Some(Self {
+            success: success.parse().ok()?,
+            total: total.parse().ok()?,
+        })
+    }
+}
+
+impl std::fmt::Display for Block {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.success, self.total)
+    }
+}
+
+fn get_window_key(id: &str, params: &str, label: &str) -> String {
+    format!("success_rate:{id}:{params}:{label}")
+}
+
+fn get_current_block_key(window_key: &str) -> String {
+    format!("{window_key}:current_block")
+}
+
+fn get_completed_blocks_key(window_key: &str) -> String {
+    format!("{window_key}:completed_blocks")
+}
+
+fn is_block_complete(
+    total: usize,
+    started_at: i64,
+    now: i64,
+    threshold: Option<&CurrentBlockThreshold>,
+) -> bool {
+    let Some(threshold) = threshold else {
+        return true;
+    };
+
+    let is_full = u64::try_from(total).unwrap_or(u64::MAX) >= threshold.max_total_count;
+    let is_expired = threshold.duration_in_mins.is_some_and(|duration_in_mins| {
+        let duration_in_secs =
+            i64::try_from(duration_in_mins.saturating_mul(60)).unwrap_or(i64::MAX);
+        now.saturating_sub(started_at) >= duration_in_secs
+    });
+
+    is_full || is_expired
+}
+
+fn calculate_score(blocks: &[Block], config: &CalSuccessRateConfig) -> f64 {
+    let (success, total) = blocks.iter().fold((0u64, 0u64), |(success, total), block| {
+        (success + block.success, total + block.total)
+    });
+
+    if blocks.len() < usize::try_from(config.min_aggregates_size).unwrap_or(usize::MAX)
+        || total == 0
+    {
+        return config.default_success_rate;
+    }
+
+    #[allow(clippy::as_conversions)]
+    let score = success as f64 * 100.0 / total as f64;
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn config(min_aggregates_size: u32) -> CalSuccessRateConfig {
+        CalSuccessRateConfig {
+            min_aggregates_size,
+            default_success_rate: 100.0,
+        }
+    }
+
+    #[test]
+    fn test_block_round_trip() {
+        let block = Block {
+            success: 7,
+            total: 10,
+        };
+
+        assert_eq!(Block::parse(&block.to_string()), Some(block));
+        assert_eq!(Block::parse("7"), None);
+    }
+
+    #[test]
+    fn test_default_score_until_enough_blocks_are_completed() {
+        let blocks = [Block {
+            success: 0,
+            total: 10,
+        }];
+
+        assert_eq!(calculate_score(&blocks, &config(2)), 100.0);
+        assert_eq!(calculate_score(&blocks, &config(1)), 0.0);
+    }
+
+    #[test]
+    fn test_score_over_completed_blocks() {
+        let blocks = [
+            Block {
+                success: 1,
+                total: 2,
+            },
+            Block {
+                success: 2,
+                total: 2,
+            },
+        ];
+
+        assert_eq!(calculate_score(&blocks, &config(2)), 75.0);
+    }
+
+    #[test]
+    fn test_block_completion() {
+        let threshold = CurrentBlockThreshold {
+            duration_in_mins: Some(5),
+            max_total_count: 3,
+        };
+
+        assert!(!is_block_complete(2, 0, 60, Some(&threshold)));
+        assert!(is_block_complete(3, 0, 60, Some(&threshold)));
+        assert!(is_block_complete(1, 0, 300, Some(&threshold)));
+    }
+}
@@ -24,7 +24,7 @@ use hyperswitch_domain_models::{
 };
 use hyperswitch_domain_models::{
     router_flow_types::{
-        dispute::{Accept, Defend, Evidence},
+        dispute::{Accept, Defend, DisputeSync, Evidence},
         files::{Retrieve, Upload},
         mandate_revoke::MandateRevoke,
         payments::{
@@ -36,16 +36,17 @@ use hyperswitch_domain_models::{
     },
     router_request_types::{
         AcceptDisputeRequestData, AuthorizeSessionTokenData, CompleteAuthorizeData,
-        ConnectorCustomerData, DefendDisputeRequestData, MandateRevokeRequestData,
+        ConnectorCustomerData, DefendDisputeRequestData, DisputeSyncData, MandateRevokeRequestData,
         PaymentsApproveData, PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
         PaymentsPreProcessingData, PaymentsRejectData, PaymentsTaxCalculationData,
         RetrieveFileRequestData, SdkPaymentsSessionUpdateData, SubmitEvidenceRequestData,
         UploadFileRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
-        PaymentsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
-        TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
+        AcceptDisputeResponse, DefendDisputeResponse, DisputeSyncResponse,
+        MandateRevokeResponseData, PaymentsResponseData, RetrieveFileResponse,
+        SubmitEvidenceResponse, TaxCalculationResponseData, UploadFileResponse,
+        VerifyWebhookSourceResponseData,
     },
 };
 #[cfg(feature = "frm")]
@@ -61,7 +62,7 @@ use hyperswitch_interfaces::api::payouts::{
 use hyperswitch_interfaces::{
     api::{
         self,
-        disputes::{AcceptDispute, DefendDispute, Dispute, SubmitEvidence},
+        disputes::{AcceptDispute, DefendDispute, Dispute, SubmitEvidence, SyncDispute},
         files::{FileUpload, RetrieveFile, UploadFile},
         payments::{
             ConnectorCustomer, PaymentApprove, PaymentAuthorizeSessionToken,
@@ -651,6 +652,45 @@ default_imp_for_defend_dispute!(
     connectors::Volt
 );
 
+macro_rules! default_imp_for_dispute_sync {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl SyncDispute for $path::$connector {}
+            impl
+                ConnectorIntegration<
+                DisputeSync,
+                DisputeSyncData,
+                DisputeSyncResponse,
+            > for $path::$connector
+            {}
+        )*
+    };
+}
+
+default_imp_for_dispute_sync!(
+    connectors::Bambora,
+    connectors::Bitpay,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Deutschebank,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Globepay,
+    connectors::Helcim,
+    connectors::Novalnet,
+    connectors::Nexixpay,
+    connectors::Powertranz,
+    connectors::Mollie,
+    connectors::Stax,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Worldline,
+    connectors::Volt
+);
+
 macro_rules! default_imp_for_file_upload {
     ($($path:ident::$connector:ident),*) => {
         $(
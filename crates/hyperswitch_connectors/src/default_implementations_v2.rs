@@ -8,7 +8,7 @@ use hyperswitch_domain_models::{
         AccessTokenFlowData, FilesFlowData,
     },
     router_flow_types::{
-        dispute::{Accept, Defend, Evidence},
+        dispute::{Accept, Defend, DisputeSync, Evidence},
         files::{Retrieve, Upload},
         mandate_revoke::MandateRevoke,
         payments::{
@@ -22,7 +22,7 @@ use hyperswitch_domain_models::{
     },
     router_request_types::{
         AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
-        CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData,
+        CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData, DisputeSyncData,
         MandateRevokeRequestData, PaymentMethodTokenizationData, PaymentsApproveData,
         PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
         PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
@@ -32,9 +32,10 @@ use hyperswitch_domain_models::{
         UploadFileRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
-        PaymentsResponseData, RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
-        TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
+        AcceptDisputeResponse, DefendDisputeResponse, DisputeSyncResponse,
+        MandateRevokeResponseData, PaymentsResponseData, RefundsResponseData, RetrieveFileResponse,
+        SubmitEvidenceResponse, TaxCalculationResponseData, UploadFileResponse,
+        VerifyWebhookSourceResponseData,
     },
 };
 #[cfg(feature = "frm")]
@@ -69,7 +70,9 @@ use hyperswitch_interfaces::api::payouts_v2::{
 };
 use hyperswitch_interfaces::{
     api::{
-        disputes_v2::{AcceptDisputeV2, DefendDisputeV2, DisputeV2, SubmitEvidenceV2},
+        disputes_v2::{
+            AcceptDisputeV2, DefendDisputeV2, DisputeV2, SubmitEvidenceV2, SyncDisputeV2,
+        },
         files_v2::{FileUploadV2, RetrieveFileV2, UploadFileV2},
         payments_v2::{
             ConnectorCustomerV2, MandateSetupV2, PaymentApproveV2, PaymentAuthorizeSessionTokenV2,
@@ -415,6 +418,46 @@ default_imp_for_new_connector_integration_defend_dispute!(
     connectors::Volt
 );
 
+macro_rules! default_imp_for_new_connector_integration_dispute_sync {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl SyncDisputeV2 for $path::$connector {}
+            impl
+                ConnectorIntegrationV2<
+                DisputeSync,
+                DisputesFlowData,
+                DisputeSyncData,
+                DisputeSyncResponse,
+            > for $path::$connector
+            {}
+        )*
+    };
+}
+
+default_imp_for_new_connector_integration_dispute_sync!(
+    connectors::Bambora,
+    connectors::Bitpay,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Deutschebank,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Globepay,
+    connectors::Helcim,
+    connectors::Novalnet,
+    connectors::Nexixpay,
+    connectors::Powertranz,
+    connectors::Mollie,
+    connectors::Stax,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Worldline,
+    connectors::Volt
+);
+
 macro_rules! default_imp_for_new_connector_integration_file_upload {
     ($($path:ident::$connector:ident),*) => {
         $(
@@ -1,6 +1,6 @@
 use std::sync::{Arc, Weak};
 
-use crate::types::{Metadata, NodeValue, Relation, RelationResolution, ValueNode};
+use crate::types::{Edge, Metadata, NodeId, NodeValue, Relation, RelationResolution, ValueNode};
 
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(tag = "type", content = "predecessor", rename_all = "snake_case")]
@@ -44,6 +44,16 @@ pub enum AnalysisTrace<V: ValueNode> {
     },
 }
 
+/// A node at which the failure of an analysis originated
+#[derive(Debug, Clone)]
+pub struct UnsatisfiedConstraint<V: ValueNode> {
+    pub node_id: NodeId,
+    /// The edges followed from the analysed node down to this node, the last of which connects
+    /// this node to its successor
+    pub path: Vec<Edge>,
+    pub trace: Arc<AnalysisTrace<V>>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 #[serde(tag = "type", content = "info", rename_all = "snake_case")]
 pub enum GraphError<V: ValueNode> {
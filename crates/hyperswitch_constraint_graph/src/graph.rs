@@ -5,7 +5,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use crate::{
     builder,
     dense_map::DenseMap,
-    error::{self, AnalysisTrace, GraphError},
+    error::{self, AnalysisTrace, GraphError, UnsatisfiedConstraint},
     types::{
         CheckingContext, CycleCheck, DomainId, DomainIdentifier, DomainInfo, Edge, EdgeId,
         Memoization, Metadata, Node, NodeId, NodeType, NodeValue, Relation, RelationResolution,
@@ -482,6 +482,91 @@ where
         Ok(())
     }
 
+    /// Follows the failed predecessors of a node recorded in `memo` during its analysis, down to
+    /// the nodes at which the failures originated. Returns an empty list if the node was not
+    /// found to be unsatisfied.
+    pub fn get_unsatisfied_constraints(
+        &self,
+        node_id: NodeId,
+        relation: Relation,
+        strength: Strength,
+        memo: &Memoization<V>,
+    ) -> Vec<UnsatisfiedConstraint<V>> {
+        let mut unsatisfied = Vec::new();
+        self.collect_unsatisfied_constraints(
+            (node_id, relation, strength),
+            &mut Vec::new(),
+            memo,
+            &mut unsatisfied,
+        );
+
+        unsatisfied
+    }
+
+    fn collect_unsatisfied_constraints(
+        &self,
+        memo_key: (NodeId, Relation, Strength),
+        path: &mut Vec<Edge>,
+        memo: &Memoization<V>,
+        unsatisfied: &mut Vec<UnsatisfiedConstraint<V>>,
+    ) {
+        let (node_id, _, _) = memo_key;
+        let (Some(Err(trace)), Some(node)) = (memo.get(&memo_key), self.nodes.get(node_id)) else {
+            return;
+        };
+
+        let mandatory_only = matches!(
+            trace.as_ref(),
+            AnalysisTrace::Value {
+                predecessors: Some(error::ValueTracePredecessor::Mandatory(_)),
+                ..
+            }
+        );
+        let has_predecessors = match trace.as_ref() {
+            AnalysisTrace::Value { predecessors, .. } => predecessors.is_some(),
+            AnalysisTrace::AllAggregation { .. } | AnalysisTrace::AnyAggregation { .. } => true,
+            AnalysisTrace::InAggregation { .. } | AnalysisTrace::Contradiction { .. } => false,
+        };
+
+        let failed_edges = node
+            .preds
+            .iter()
+            .filter_map(|edge_id| self.edges.get(*edge_id))
+            .filter(|edge| {
+                has_predecessors && (!mandatory_only || edge.strength == Strength::Strong)
+            })
+            .filter(|edge| {
+                matches!(
+                    memo.get(&(edge.pred, edge.relation, edge.strength)),
+                    Some(Err(_))
+                )
+            })
+            .filter(|edge| {
+                edge.pred != node_id && !path.iter().any(|visited| visited.succ == edge.pred)
+            })
+            .collect::<Vec<_>>();
+
+        if failed_edges.is_empty() {
+            unsatisfied.push(UnsatisfiedConstraint {
+                node_id,
+                path: path.clone(),
+                trace: Arc::clone(trace),
+            });
+            return;
+        }
+
+        for edge in failed_edges {
+            path.push(edge.clone());
+            self.collect_unsatisfied_constraints(
+                (edge.pred, edge.relation, edge.strength),
+                path,
+                memo,
+                unsatisfied,
+            );
+            path.pop();
+        }
+    }
+
     pub fn combine(g1: &Self, g2: &Self) -> Result<Self, GraphError<V>> {
         let mut node_builder = builder::ConstraintGraphBuilder::new();
         let mut g1_old2new_id = DenseMap::<NodeId, NodeId>::new();
@@ -604,6 +689,13 @@ mod viz {
         format!("N{}", node_id.get_id())
     }
 
+    impl types::NodeId {
+        /// The identifier of the node in the graph returned by `get_viz_digraph`
+        pub fn get_viz_id(&self) -> String {
+            get_node_id(*self)
+        }
+    }
+
     impl<V> ConstraintGraph<V>
     where
         V: ValueNode + NodeViz,
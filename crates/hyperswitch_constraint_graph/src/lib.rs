@@ -5,7 +5,7 @@ pub mod graph;
 pub mod types;
 
 pub use builder::ConstraintGraphBuilder;
-pub use error::{AnalysisTrace, GraphError};
+pub use error::{AnalysisTrace, GraphError, UnsatisfiedConstraint};
 pub use graph::ConstraintGraph;
 #[cfg(feature = "viz")]
 pub use types::NodeViz;
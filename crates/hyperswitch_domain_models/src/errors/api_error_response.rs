@@ -17,6 +17,7 @@ pub enum ErrorType {
     ValidationError,
     ConnectorError,
     LockTimeout,
+    RateLimitError,
 }
 
 // CE	Connector Error	Errors originating from connector's end
@@ -105,6 +106,12 @@ pub enum ApiErrorResponse {
     PollNotFound { id: String },
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Resource ID does not exist in our records")]
     ResourceIdNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Plan with the given id '{plan_id}' does not exist in our records")]
+    PlanNotFound { plan_id: String },
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Subscription with the given id '{subscription_id}' does not exist in our records")]
+    SubscriptionNotFound { subscription_id: String },
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Webhook endpoint with the given id '{endpoint_id}' does not exist in our records")]
+    WebhookEndpointNotFound { endpoint_id: String },
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Mandate does not exist in our records")]
     MandateNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Authentication does not exist in our records")]
@@ -160,6 +167,8 @@ pub enum ApiErrorResponse {
     InvalidTenant { tenant_id: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_06", message = "Failed to convert amount to {amount_type} type")]
     AmountConversionFailed { amount_type: &'static str },
+    #[error(error_type = ErrorType::RateLimitError, code = "HE_07", message = "Too many requests, please retry after {retry_after_seconds} seconds")]
+    RateLimitExceeded { retry_after_seconds: u64 },
     #[error(error_type = ErrorType::ServerNotAvailable, code = "IR_00", message = "{message:?}")]
     NotImplemented { message: NotImplementedMessage },
     #[error(
@@ -274,6 +283,8 @@ pub enum ApiErrorResponse {
     LinkConfigurationError { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_41", message = "Payout validation failed")]
     PayoutFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_42", message = "Idempotency key '{idempotency_key}' has already been used with a different request body")]
+    IdempotencyKeyReused { idempotency_key: String },
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
@@ -414,6 +425,15 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::PollNotFound { .. } => {
                 AER::NotFound(ApiError::new("HE", 2, "Poll does not exist in our records", None))
             },
+            Self::PlanNotFound { .. } => {
+                AER::NotFound(ApiError::new("HE", 2, "Plan does not exist in our records", None))
+            }
+            Self::SubscriptionNotFound { .. } => {
+                AER::NotFound(ApiError::new("HE", 2, "Subscription does not exist in our records", None))
+            }
+            Self::WebhookEndpointNotFound { .. } => {
+                AER::NotFound(ApiError::new("HE", 2, "Webhook endpoint does not exist in our records", None))
+            }
             Self::ResourceIdNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Resource ID does not exist in our records", None))
             }
@@ -487,6 +507,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::AmountConversionFailed { amount_type }  => {
                 AER::InternalServerError(ApiError::new("HE", 6, format!("Failed to convert amount to {amount_type} type"), None))
             }
+            Self::RateLimitExceeded { retry_after_seconds } => {
+                AER::TooManyRequests(ApiError::new("HE", 7, format!("Too many requests, please retry after {retry_after_seconds} seconds"), None))
+            }
 
             Self::NotImplemented { message } => {
                 AER::NotImplemented(ApiError::new("IR", 0, format!("{message:?}"), None))
@@ -627,6 +650,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::PayoutFailed { data } => {
                 AER::BadRequest(ApiError::new("IR", 41, "Payout failed while processing with connector.", Some(Extra { data: data.clone(), ..Default::default()})))
             },
+            Self::IdempotencyKeyReused { idempotency_key } => {
+                AER::BadRequest(ApiError::new("IR", 42, format!("Idempotency key '{idempotency_key}' has already been used with a different request body"), None))
+            },
 
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
@@ -5,3 +5,6 @@ pub struct Evidence;
 
 #[derive(Debug, Clone)]
 pub struct Defend;
+
+#[derive(Debug, Clone)]
+pub struct DisputeSync;
@@ -687,6 +687,14 @@ pub struct DefendDisputeRequestData {
     pub connector_dispute_id: String,
 }
 
+#[derive(Default, Debug, Clone)]
+pub struct DisputeSyncData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+    pub dispute_stage: storage_enums::DisputeStage,
+    pub dispute_status: storage_enums::DisputeStatus,
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct SubmitEvidenceRequestData {
     pub dispute_id: String,
@@ -3,7 +3,9 @@ pub mod fraud_check;
 use std::collections::HashMap;
 
 use common_utils::{request::Method, types as common_types, types::MinorUnit};
-pub use disputes::{AcceptDisputeResponse, DefendDisputeResponse, SubmitEvidenceResponse};
+pub use disputes::{
+    AcceptDisputeResponse, DefendDisputeResponse, DisputeSyncResponse, SubmitEvidenceResponse,
+};
 
 use crate::router_request_types::{authentication::AuthNFlowType, ResponseId};
 #[derive(Debug, Clone)]
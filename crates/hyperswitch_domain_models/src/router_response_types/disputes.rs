@@ -16,6 +16,17 @@ pub struct DefendDisputeResponse {
     pub connector_status: Option<String>,
 }
 
+#[derive(Default, Debug, Clone)]
+pub struct DisputeSyncResponse {
+    pub dispute_stage: api_models::enums::DisputeStage,
+    pub dispute_status: api_models::enums::DisputeStatus,
+    pub connector_status: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<time::PrimitiveDateTime>,
+    pub updated_at: Option<time::PrimitiveDateTime>,
+}
+
 pub struct FileInfo {
     pub file_data: Option<Vec<u8>>,
     pub provider_file_id: Option<String>,
@@ -1,11 +1,14 @@
 //! Disputes interface
 
 use hyperswitch_domain_models::{
-    router_flow_types::dispute::{Accept, Defend, Evidence},
+    router_flow_types::dispute::{Accept, Defend, DisputeSync, Evidence},
     router_request_types::{
-        AcceptDisputeRequestData, DefendDisputeRequestData, SubmitEvidenceRequestData,
+        AcceptDisputeRequestData, DefendDisputeRequestData, DisputeSyncData,
+        SubmitEvidenceRequestData,
+    },
+    router_response_types::{
+        AcceptDisputeResponse, DefendDisputeResponse, DisputeSyncResponse, SubmitEvidenceResponse,
     },
-    router_response_types::{AcceptDisputeResponse, DefendDisputeResponse, SubmitEvidenceResponse},
 };
 
 use crate::api::ConnectorIntegration;
@@ -28,5 +31,14 @@ pub trait DefendDispute:
 {
 }
 
+/// trait SyncDispute
+pub trait SyncDispute:
+    ConnectorIntegration<DisputeSync, DisputeSyncData, DisputeSyncResponse>
+{
+}
+
 /// trait Dispute
-pub trait Dispute: super::ConnectorCommon + AcceptDispute + SubmitEvidence + DefendDispute {}
+pub trait Dispute:
+    super::ConnectorCommon + AcceptDispute + SubmitEvidence + DefendDispute + SyncDispute
+{
+}
@@ -1,11 +1,14 @@
 //! Disputes V2 interface
 use hyperswitch_domain_models::{
     router_data_v2::DisputesFlowData,
-    router_flow_types::dispute::{Accept, Defend, Evidence},
+    router_flow_types::dispute::{Accept, Defend, DisputeSync, Evidence},
     router_request_types::{
-        AcceptDisputeRequestData, DefendDisputeRequestData, SubmitEvidenceRequestData,
+        AcceptDisputeRequestData, DefendDisputeRequestData, DisputeSyncData,
+        SubmitEvidenceRequestData,
+    },
+    router_response_types::{
+        AcceptDisputeResponse, DefendDisputeResponse, DisputeSyncResponse, SubmitEvidenceResponse,
     },
-    router_response_types::{AcceptDisputeResponse, DefendDisputeResponse, SubmitEvidenceResponse},
 };
 
 use crate::api::ConnectorIntegrationV2;
@@ -33,8 +36,14 @@ pub trait DefendDisputeV2:
 {
 }
 
+/// trait SyncDisputeV2
+pub trait SyncDisputeV2:
+    ConnectorIntegrationV2<DisputeSync, DisputesFlowData, DisputeSyncData, DisputeSyncResponse>
+{
+}
+
 /// trait DisputeV2
 pub trait DisputeV2:
-    super::ConnectorCommon + AcceptDisputeV2 + SubmitEvidenceV2 + DefendDisputeV2
+    super::ConnectorCommon + AcceptDisputeV2 + SubmitEvidenceV2 + DefendDisputeV2 + SyncDisputeV2
 {
 }
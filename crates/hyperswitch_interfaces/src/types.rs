@@ -3,7 +3,7 @@ use hyperswitch_domain_models::{
     router_data::AccessToken,
     router_flow_types::{
         access_token_auth::AccessTokenAuth,
-        dispute::{Accept, Defend, Evidence},
+        dispute::{Accept, Defend, DisputeSync, Evidence},
         files::{Retrieve, Upload},
         mandate_revoke::MandateRevoke,
         payments::{
@@ -16,7 +16,7 @@ use hyperswitch_domain_models::{
     },
     router_request_types::{
         AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
-        CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData,
+        CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData, DisputeSyncData,
         MandateRevokeRequestData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
         PaymentsCancelData, PaymentsCaptureData, PaymentsIncrementalAuthorizationData,
         PaymentsPostProcessingData, PaymentsPreProcessingData, PaymentsSessionData,
@@ -25,9 +25,10 @@ use hyperswitch_domain_models::{
         VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
-        PaymentsResponseData, RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
-        TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
+        AcceptDisputeResponse, DefendDisputeResponse, DisputeSyncResponse,
+        MandateRevokeResponseData, PaymentsResponseData, RefundsResponseData, RetrieveFileResponse,
+        SubmitEvidenceResponse, TaxCalculationResponseData, UploadFileResponse,
+        VerifyWebhookSourceResponseData,
     },
 };
 #[cfg(feature = "payouts")]
@@ -175,3 +176,7 @@ pub type RetrieveFileType =
 /// Type alias for `ConnectorIntegration<Defend, DefendDisputeRequestData, DefendDisputeResponse>`
 pub type DefendDisputeType =
     dyn ConnectorIntegration<Defend, DefendDisputeRequestData, DefendDisputeResponse>;
+
+/// Type alias for `ConnectorIntegration<DisputeSync, DisputeSyncData, DisputeSyncResponse>`
+pub type DisputeSyncType =
+    dyn ConnectorIntegration<DisputeSync, DisputeSyncData, DisputeSyncResponse>;
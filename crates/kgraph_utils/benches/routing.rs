@@ -0,0 +1,120 @@
+#![allow(unused, clippy::expect_used)]
+
+use std::fmt::Write;
+
+use common_utils::types::MinorUnit;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use euclid::{
+    backend::{inputs, DecisionTableBackend, EuclidBackend, VirInterpreterBackend},
+    enums,
+    frontend::ast::{self, parser},
+    types::DummyOutput,
+};
+
+/// Builds a program of `total_rules` rules, of which only the last one matches the input returned
+/// alongside it, so that every rule has to be evaluated
+fn build_test_data(total_rules: usize) -> (ast::Program<DummyOutput>, inputs::BackendInput) {
+    let mut program = String::from(r#"default: ["stripe", "adyen"]"#);
+    for index in 0..total_rules {
+        write!(
+            program,
+            r#"
+            rule_{index}: ["stripe", "adyen"]
+            {{
+                payment_method = card & amount > {minimum_amount} {{
+                    card_network = (visa, mastercard)
+                    currency /= (INR, EUR) & amount <= {maximum_amount}
+                }}
+                "merchant_tier" = "tier_{index}" & billing_country = (France, Germany)
+                payment_method /= (card, wallet) & capture_method = manual
+            }}
+            "#,
+            minimum_amount = (index + 1) * 10,
+            maximum_amount = (index + 1) * 100,
+        )
+        .expect("Program construction");
+    }
+
+    let (_, program) = parser::program(&program).expect("Parser");
+
+    let metadata = [(
+        "merchant_tier".to_string(),
+        format!("tier_{}", total_rules.saturating_sub(1)),
+    )];
+    let input = inputs::BackendInput {
+        metadata: Some(metadata.into_iter().collect()),
+        payment: inputs::PaymentInput {
+            amount: MinorUnit::new(5),
+            card_bin: None,
+            currency: enums::Currency::USD,
+            authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+            capture_method: Some(enums::CaptureMethod::Automatic),
+            business_country: Some(enums::Country::UnitedStatesOfAmerica),
+            billing_country: Some(enums::Country::France),
+            business_label: None,
+            setup_future_usage: None,
+            converted_amount: None,
+        },
+        payment_method: inputs::PaymentMethodInput {
+            payment_method: Some(enums::PaymentMethod::Card),
+            payment_method_type: Some(enums::PaymentMethodType::Credit),
+            card_network: Some(enums::CardNetwork::Visa),
+            issuer_country: None,
+            card_issuer: None,
+            is_network_token: None,
+        },
+        mandate: inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: None,
+        },
+        customer: inputs::CustomerInput::default(),
+        temporal: inputs::TemporalInput::default(),
+    };
+
+    (program, input)
+}
+
+fn routing(c: &mut Criterion) {
+    for total_rules in [10, 100, 500] {
+        let (program, input) = build_test_data(total_rules);
+
+        let vir_interpreter =
+            VirInterpreterBackend::with_program(program.clone()).expect("Vir Interpreter Backend");
+        let decision_table =
+            DecisionTableBackend::with_program(program).expect("Decision Table Backend");
+
+        let expected = vir_interpreter
+            .execute(input.clone())
+            .expect("Vir Interpreter execution");
+        let output = decision_table
+            .execute(input.clone())
+            .expect("Decision Table execution");
+        assert_eq!(output.rule_name, expected.rule_name);
+
+        c.bench_function(
+            &format!("Valued Interpreter Backend ({total_rules} rules)"),
+            |b| {
+                b.iter(|| {
+                    vir_interpreter
+                        .execute(black_box(input.clone()))
+                        .expect("Vir Interpreter execution");
+                })
+            },
+        );
+
+        c.bench_function(
+            &format!("Decision Table Backend ({total_rules} rules)"),
+            |b| {
+                b.iter(|| {
+                    decision_table
+                        .execute(black_box(input.clone()))
+                        .expect("Decision Table execution");
+                })
+            },
+        );
+    }
+}
+
+criterion_group!(benches, routing);
+criterion_main!(benches);
@@ -2,7 +2,8 @@ use std::str::FromStr;
 
 use api_models::{
     admin as admin_api, enums as api_enums, payment_methods::RequestPaymentMethodTypes,
-    refunds::MinorUnit,
+    refunds::MinorUnit, routing::EligibilityConstraintKind as ConstraintKind,
+    routing::EligibilityConstraintSource as ConstraintSource,
 };
 use euclid::{
     dirval,
@@ -16,6 +17,36 @@ use crate::{error::KgraphError, transformers::IntoDirValue, types as kgraph_type
 
 pub const DOMAIN_IDENTIFIER: &str = "payment_methods_enabled_for_merchantconnectoraccount";
 
+/// The merchant connector account a part of the graph is compiled for, recorded in the metadata
+/// of the aggregators of that part
+struct MetadataContext {
+    merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    connector: api_enums::RoutableConnectors,
+}
+
+impl MetadataContext {
+    fn make(
+        &self,
+        source: ConstraintSource,
+        constraint: ConstraintKind,
+    ) -> kgraph_types::NodeMetadata {
+        kgraph_types::NodeMetadata {
+            merchant_connector_id: Some(self.merchant_connector_id.clone()),
+            connector: Some(self.connector),
+            source: Some(source),
+            constraint,
+        }
+    }
+
+    fn mca(&self, constraint: ConstraintKind) -> kgraph_types::NodeMetadata {
+        self.make(ConstraintSource::MerchantConnectorAccount, constraint)
+    }
+
+    fn pm_filters(&self, constraint: ConstraintKind) -> kgraph_types::NodeMetadata {
+        self.make(ConstraintSource::PmFilters, constraint)
+    }
+}
+
 fn get_dir_value_payment_method(
     from: api_enums::PaymentMethodType,
 ) -> Result<dir::DirValue, KgraphError> {
@@ -153,6 +184,7 @@ fn compile_request_pm_types(
     builder: &mut cgraph::ConstraintGraphBuilder<dir::DirValue>,
     pm_types: RequestPaymentMethodTypes,
     pm: api_enums::PaymentMethod,
+    metadata_ctx: &MetadataContext,
 ) -> Result<cgraph::NodeId, KgraphError> {
     let mut agg_nodes: Vec<(cgraph::NodeId, cgraph::Relation, cgraph::Strength)> = Vec::new();
 
@@ -162,7 +194,9 @@ fn compile_request_pm_types(
             .into_dir_value()
             .map(Into::into)?,
         Some(pmt_info),
-        None::<()>,
+        Some(kgraph_types::NodeMetadata::for_constraint(
+            ConstraintKind::PaymentMethodType,
+        )),
     );
     agg_nodes.push((
         pmt_id,
@@ -185,7 +219,11 @@ fn compile_request_pm_types(
 
             let card_network_info = "Card Networks";
             let card_network_id = builder
-                .make_in_aggregator(dir_vals, Some(card_network_info), None::<()>)
+                .make_in_aggregator(
+                    dir_vals,
+                    Some(card_network_info),
+                    Some(metadata_ctx.mca(ConstraintKind::CardNetwork)),
+                )
                 .map_err(KgraphError::GraphConstructionError)?;
 
             agg_nodes.push((
@@ -221,7 +259,11 @@ fn compile_request_pm_types(
     if let Some((currencies, relation)) = currencies_data {
         let accepted_currencies_info = "Accepted Currencies";
         let accepted_currencies_id = builder
-            .make_in_aggregator(currencies, Some(accepted_currencies_info), None::<()>)
+            .make_in_aggregator(
+                currencies,
+                Some(accepted_currencies_info),
+                Some(metadata_ctx.mca(ConstraintKind::Currency)),
+            )
             .map_err(KgraphError::GraphConstructionError)?;
 
         agg_nodes.push((accepted_currencies_id, relation, cgraph::Strength::Strong));
@@ -239,7 +281,9 @@ fn compile_request_pm_types(
         let min_amt_id = builder.make_value_node(
             dir::DirValue::PaymentAmount(num_val).into(),
             Some(min_amt_info),
-            None::<()>,
+            Some(kgraph_types::NodeMetadata::for_constraint(
+                ConstraintKind::MinimumAmount,
+            )),
         );
 
         amount_nodes.push(min_amt_id);
@@ -255,7 +299,9 @@ fn compile_request_pm_types(
         let max_amt_id = builder.make_value_node(
             dir::DirValue::PaymentAmount(num_val).into(),
             Some(max_amt_info),
-            None::<()>,
+            Some(kgraph_types::NodeMetadata::for_constraint(
+                ConstraintKind::MaximumAmount,
+            )),
         );
 
         amount_nodes.push(max_amt_id);
@@ -295,7 +341,7 @@ fn compile_request_pm_types(
                 .make_all_aggregator(
                     &nodes,
                     Some("amount_constraint_aggregator"),
-                    None::<()>,
+                    Some(metadata_ctx.mca(ConstraintKind::Amount)),
                     None,
                 )
                 .map_err(KgraphError::GraphConstructionError)?
@@ -316,7 +362,7 @@ fn compile_request_pm_types(
                     ),
                 ],
                 Some("zero_plus_limits_amount_aggregator"),
-                None::<()>,
+                Some(metadata_ctx.mca(ConstraintKind::Amount)),
                 None,
             )
             .map_err(KgraphError::GraphConstructionError)?;
@@ -330,13 +376,19 @@ fn compile_request_pm_types(
 
     let pmt_all_aggregator_info = "All Aggregator for PaymentMethodType";
     builder
-        .make_all_aggregator(&agg_nodes, Some(pmt_all_aggregator_info), None::<()>, None)
+        .make_all_aggregator(
+            &agg_nodes,
+            Some(pmt_all_aggregator_info),
+            Some(metadata_ctx.mca(ConstraintKind::PaymentMethodType)),
+            None,
+        )
         .map_err(KgraphError::GraphConstructionError)
 }
 
 fn compile_payment_method_enabled(
     builder: &mut cgraph::ConstraintGraphBuilder<dir::DirValue>,
     enabled: admin_api::PaymentMethodsEnabled,
+    metadata_ctx: &MetadataContext,
 ) -> Result<Option<cgraph::NodeId>, KgraphError> {
     let agg_id = if !enabled
         .payment_method_types
@@ -348,14 +400,21 @@ fn compile_payment_method_enabled(
         let pm_id = builder.make_value_node(
             enabled.payment_method.into_dir_value().map(Into::into)?,
             Some(pm_info),
-            None::<()>,
+            Some(kgraph_types::NodeMetadata::for_constraint(
+                ConstraintKind::PaymentMethod,
+            )),
         );
 
         let mut agg_nodes: Vec<(cgraph::NodeId, cgraph::Relation, cgraph::Strength)> = Vec::new();
 
         if let Some(pm_types) = enabled.payment_method_types {
             for pm_type in pm_types {
-                let node_id = compile_request_pm_types(builder, pm_type, enabled.payment_method)?;
+                let node_id = compile_request_pm_types(
+                    builder,
+                    pm_type,
+                    enabled.payment_method,
+                    metadata_ctx,
+                )?;
                 agg_nodes.push((
                     node_id,
                     cgraph::Relation::Positive,
@@ -366,7 +425,12 @@ fn compile_payment_method_enabled(
 
         let any_aggregator_info = "Any aggregation for PaymentMethodsType";
         let pm_type_agg_id = builder
-            .make_any_aggregator(&agg_nodes, Some(any_aggregator_info), None::<()>, None)
+            .make_any_aggregator(
+                &agg_nodes,
+                Some(any_aggregator_info),
+                Some(metadata_ctx.mca(ConstraintKind::PaymentMethodType)),
+                None,
+            )
             .map_err(KgraphError::GraphConstructionError)?;
 
         let all_aggregator_info = "All aggregation for PaymentMethod";
@@ -381,7 +445,7 @@ fn compile_payment_method_enabled(
                     ),
                 ],
                 Some(all_aggregator_info),
-                None::<()>,
+                Some(metadata_ctx.mca(ConstraintKind::PaymentMethod)),
                 None,
             )
             .map_err(KgraphError::GraphConstructionError)?;
@@ -435,7 +499,9 @@ fn global_vec_pmt(
             builder.make_value_node(
                 cgraph::NodeValue::Value(dir_v),
                 Some("Payment Method Type"),
-                None::<()>,
+                Some(kgraph_types::NodeMetadata::for_constraint(
+                    ConstraintKind::PaymentMethodType,
+                )),
             )
         })
         .collect::<Vec<_>>()
@@ -445,6 +511,7 @@ fn compile_graph_for_countries_and_currencies(
     builder: &mut cgraph::ConstraintGraphBuilder<dir::DirValue>,
     config: &kgraph_types::CurrencyCountryFlowFilter,
     payment_method_type_node: cgraph::NodeId,
+    metadata_ctx: &MetadataContext,
 ) -> Result<cgraph::NodeId, KgraphError> {
     let mut agg_nodes: Vec<(cgraph::NodeId, cgraph::Relation, cgraph::Strength)> = Vec::new();
     agg_nodes.push((
@@ -458,7 +525,11 @@ fn compile_graph_for_countries_and_currencies(
             .map(|country| dir::DirValue::BillingCountry(api_enums::Country::from_alpha2(country)))
             .collect();
         let country_agg = builder
-            .make_in_aggregator(node_country, Some("Configs for Country"), None::<()>)
+            .make_in_aggregator(
+                node_country,
+                Some("Configs for Country"),
+                Some(metadata_ctx.pm_filters(ConstraintKind::Country)),
+            )
             .map_err(KgraphError::GraphConstructionError)?;
         agg_nodes.push((
             country_agg,
@@ -473,7 +544,11 @@ fn compile_graph_for_countries_and_currencies(
             .map(IntoDirValue::into_dir_value)
             .collect::<Result<Vec<_>, _>>()?;
         let currency_agg = builder
-            .make_in_aggregator(node_currency, Some("Configs for Currency"), None::<()>)
+            .make_in_aggregator(
+                node_currency,
+                Some("Configs for Currency"),
+                Some(metadata_ctx.pm_filters(ConstraintKind::Currency)),
+            )
             .map_err(KgraphError::GraphConstructionError)?;
         agg_nodes.push((
             currency_agg,
@@ -488,7 +563,9 @@ fn compile_graph_for_countries_and_currencies(
         let make_capture_node = builder.make_value_node(
             cgraph::NodeValue::Value(dir::DirValue::CaptureMethod(capture_method)),
             Some("Configs for CaptureMethod"),
-            None::<()>,
+            Some(kgraph_types::NodeMetadata::for_constraint(
+                ConstraintKind::CaptureMethod,
+            )),
         );
         agg_nodes.push((
             make_capture_node,
@@ -501,7 +578,7 @@ fn compile_graph_for_countries_and_currencies(
         .make_all_aggregator(
             &agg_nodes,
             Some("Country & Currency Configs With Payment Method Type"),
-            None::<()>,
+            Some(metadata_ctx.pm_filters(ConstraintKind::PaymentMethodType)),
             None,
         )
         .map_err(KgraphError::GraphConstructionError)
@@ -511,6 +588,7 @@ fn compile_config_graph(
     builder: &mut cgraph::ConstraintGraphBuilder<dir::DirValue>,
     config: &kgraph_types::CountryCurrencyFilter,
     connector: &api_enums::RoutableConnectors,
+    metadata_ctx: &MetadataContext,
 ) -> Result<cgraph::NodeId, KgraphError> {
     let mut agg_node_id: Vec<(cgraph::NodeId, cgraph::Relation, cgraph::Strength)> = Vec::new();
     let mut pmt_enabled: Vec<dir::DirValue> = Vec::new();
@@ -535,19 +613,27 @@ fn compile_config_graph(
                                 dir::enums::PaymentMethod::Card,
                             )),
                             Some("PaymentMethod"),
-                            None::<()>,
+                            Some(kgraph_types::NodeMetadata::for_constraint(
+                                ConstraintKind::PaymentMethod,
+                            )),
                         )
                     } else {
                         pmt_enabled.push(dir_val_pm.clone());
                         builder.make_value_node(
                             cgraph::NodeValue::Value(dir_val_pm),
                             Some("PaymentMethodType"),
-                            None::<()>,
+                            Some(kgraph_types::NodeMetadata::for_constraint(
+                                ConstraintKind::PaymentMethodType,
+                            )),
                         )
                     };
 
-                    let node_config =
-                        compile_graph_for_countries_and_currencies(builder, &filter, pm_node)?;
+                    let node_config = compile_graph_for_countries_and_currencies(
+                        builder,
+                        &filter,
+                        pm_node,
+                        metadata_ctx,
+                    )?;
 
                     agg_node_id.push((
                         node_config,
@@ -561,10 +647,16 @@ fn compile_config_graph(
                     let cn_node = builder.make_value_node(
                         cn.clone().into_dir_value().map(Into::into)?,
                         Some("CardNetwork"),
-                        None::<()>,
+                        Some(kgraph_types::NodeMetadata::for_constraint(
+                            ConstraintKind::CardNetwork,
+                        )),
                     );
-                    let node_config =
-                        compile_graph_for_countries_and_currencies(builder, &filter, cn_node)?;
+                    let node_config = compile_graph_for_countries_and_currencies(
+                        builder,
+                        &filter,
+                        cn_node,
+                        metadata_ctx,
+                    )?;
 
                     agg_node_id.push((
                         node_config,
@@ -584,7 +676,7 @@ fn compile_config_graph(
         .make_any_aggregator(
             &any_agg_pmt,
             Some("Any Aggregator For Payment Method Types"),
-            None::<()>,
+            Some(metadata_ctx.pm_filters(ConstraintKind::PaymentMethodType)),
             None,
         )
         .map_err(KgraphError::GraphConstructionError)?;
@@ -596,7 +688,12 @@ fn compile_config_graph(
     ));
 
     builder
-        .make_any_aggregator(&agg_node_id, Some("Configs"), None::<()>, None)
+        .make_any_aggregator(
+            &agg_node_id,
+            Some("Configs"),
+            Some(metadata_ctx.pm_filters(ConstraintKind::PaymentMethodType)),
+            None,
+        )
         .map_err(KgraphError::GraphConstructionError)
 }
 
@@ -607,12 +704,17 @@ fn compile_merchant_connector_graph(
 ) -> Result<(), KgraphError> {
     let connector = common_enums::RoutableConnectors::from_str(&mca.connector_name)
         .map_err(|_| KgraphError::InvalidConnectorName(mca.connector_name.clone()))?;
+    let metadata_ctx = MetadataContext {
+        merchant_connector_id: mca.get_id(),
+        connector,
+    };
 
     let mut agg_nodes: Vec<(cgraph::NodeId, cgraph::Relation, cgraph::Strength)> = Vec::new();
 
     if let Some(pms_enabled) = mca.payment_methods_enabled.clone() {
         for pm_enabled in pms_enabled {
-            let maybe_pm_enabled_id = compile_payment_method_enabled(builder, pm_enabled)?;
+            let maybe_pm_enabled_id =
+                compile_payment_method_enabled(builder, pm_enabled, &metadata_ctx)?;
             if let Some(pm_enabled_id) = maybe_pm_enabled_id {
                 agg_nodes.push((
                     pm_enabled_id,
@@ -625,12 +727,17 @@ fn compile_merchant_connector_graph(
 
     let aggregator_info = "Available Payment methods for connector";
     let pms_enabled_agg_id = builder
-        .make_any_aggregator(&agg_nodes, Some(aggregator_info), None::<()>, None)
+        .make_any_aggregator(
+            &agg_nodes,
+            Some(aggregator_info),
+            Some(metadata_ctx.mca(ConstraintKind::PaymentMethod)),
+            None,
+        )
         .map_err(KgraphError::GraphConstructionError)?;
 
     let config_info = "Config for respective PaymentMethodType for the connector";
 
-    let config_enabled_agg_id = compile_config_graph(builder, config, &connector)?;
+    let config_enabled_agg_id = compile_config_graph(builder, config, &connector, &metadata_ctx)?;
 
     let domain_level_node_id = builder
         .make_all_aggregator(
@@ -647,15 +754,21 @@ fn compile_merchant_connector_graph(
                 ),
             ],
             Some(config_info),
-            None::<()>,
+            Some(metadata_ctx.mca(ConstraintKind::Connector)),
             None,
         )
         .map_err(KgraphError::GraphConstructionError)?;
     let connector_dir_val = dir::DirValue::Connector(Box::new(ast::ConnectorChoice { connector }));
 
     let connector_info = "Connector";
-    let connector_node_id =
-        builder.make_value_node(connector_dir_val.into(), Some(connector_info), None::<()>);
+    let connector_node_id = builder.make_value_node(
+        connector_dir_val.into(),
+        Some(connector_info),
+        Some(kgraph_types::NodeMetadata {
+            connector: Some(connector),
+            ..kgraph_types::NodeMetadata::for_constraint(ConstraintKind::Connector)
+        }),
+    );
 
     builder
         .make_edge(
@@ -930,6 +1043,57 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_single_mismatch_failure_explanation() {
+        let graph = build_test_data();
+
+        let unsatisfied = graph
+            .explain_value_validity(
+                dirval!(Connector = Stripe),
+                &AnalysisContext::from_dir_values([
+                    dirval!(Connector = Stripe),
+                    dirval!(PaymentMethod = Card),
+                    dirval!(CardType = Debit),
+                    dirval!(CardNetwork = Maestro),
+                    dirval!(PaymentCurrency = PHP),
+                    dirval!(PaymentAmount = 100),
+                ]),
+                None,
+            )
+            .expect("Failed analysis")
+            .expect("Connector not found in graph");
+
+        let currency_constraint = unsatisfied
+            .iter()
+            .find(|constraint| {
+                matches!(
+                    constraint.trace.as_ref(),
+                    cgraph::AnalysisTrace::InAggregation {
+                        found: Some(dir::DirValue::PaymentCurrency(api_enums::Currency::PHP)),
+                        ..
+                    }
+                )
+            })
+            .expect("Currency constraint not found");
+
+        let metadata = graph
+            .node_metadata
+            .get(currency_constraint.node_id)
+            .cloned()
+            .flatten()
+            .expect("Metadata not found for currency constraint");
+        let metadata: kgraph_types::NodeMetadata =
+            serde_json::from_value(serde_json::to_value(&*metadata).expect("Failed serialization"))
+                .expect("Failed deserialization");
+
+        assert_eq!(metadata.constraint, ConstraintKind::Currency);
+        assert_eq!(
+            metadata.source,
+            Some(ConstraintSource::MerchantConnectorAccount)
+        );
+        assert!(metadata.merchant_connector_id.is_some());
+    }
+
     #[test]
     fn test_amount_mismatch_failure_case() {
         let graph = build_test_data();
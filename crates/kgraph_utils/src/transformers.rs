@@ -1,4 +1,5 @@
 use api_models::enums as api_enums;
+use common_utils::types::MinorUnit;
 use euclid::{
     backend::BackendInput,
     dirval,
@@ -72,6 +73,38 @@ impl IntoContext for BackendInput {
         if let Some(payment_type) = self.mandate.payment_type {
             ctx.push(dir::DirValue::PaymentType(payment_type));
         }
+        if let Some(issuer_country) = self.payment_method.issuer_country {
+            ctx.push(dir::DirValue::IssuerCountry(issuer_country));
+        }
+        if let Some(card_issuer) = self.payment_method.card_issuer {
+            ctx.push(dir::DirValue::CardIssuer(StrValue { value: card_issuer }));
+        }
+        if let Some(is_network_token) = self.payment_method.is_network_token {
+            ctx.push(dir::DirValue::IsNetworkToken(is_network_token.into()));
+        }
+        if let Some(customer_id) = self.customer.customer_id {
+            ctx.push(dir::DirValue::CustomerId(StrValue { value: customer_id }));
+        }
+        if let Some(customer_segment) = self.customer.customer_segment {
+            ctx.push(dir::DirValue::CustomerSegment(StrValue {
+                value: customer_segment,
+            }));
+        }
+        if let Some(day_of_week) = self.temporal.day_of_week {
+            ctx.push(dir::DirValue::DayOfWeek(day_of_week));
+        }
+        if let Some(hour_of_day) = self.temporal.hour_of_day {
+            ctx.push(dir::DirValue::HourOfDay(NumValue {
+                number: MinorUnit::new(i64::from(hour_of_day)),
+                refinement: None,
+            }));
+        }
+        if let Some(converted_amount) = self.payment.converted_amount {
+            ctx.push(dir::DirValue::ConvertedAmount(NumValue {
+                number: converted_amount,
+                refinement: None,
+            }));
+        }
 
         Ok(ctx)
     }
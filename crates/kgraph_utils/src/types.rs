@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
-use api_models::enums as api_enums;
-use serde::Deserialize;
+use api_models::{enums as api_enums, routing as routing_api};
+use serde::{Deserialize, Serialize};
 #[derive(Debug, Deserialize, Clone, Default)]
 
 pub struct CountryCurrencyFilter {
@@ -33,3 +33,25 @@ pub struct CurrencyCountryFlowFilter {
 pub struct NotAvailableFlows {
     pub capture_method: Option<api_enums::CaptureMethod>,
 }
+
+/// Metadata attached to the nodes of the graph, describing the constraint a node represents.
+/// Value nodes are shared between merchant connector accounts, so their metadata only carries the
+/// kind of the constraint, and the account is to be taken from the aggregators they lead to.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NodeMetadata {
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub connector: Option<api_enums::RoutableConnectors>,
+    pub source: Option<routing_api::EligibilityConstraintSource>,
+    pub constraint: routing_api::EligibilityConstraintKind,
+}
+
+impl NodeMetadata {
+    pub fn for_constraint(constraint: routing_api::EligibilityConstraintKind) -> Self {
+        Self {
+            merchant_connector_id: None,
+            connector: None,
+            source: None,
+            constraint,
+        }
+    }
+}
@@ -58,6 +58,8 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
         (name = "Mandates", description = "Manage mandates"),
+        (name = "Plans", description = "Create and manage plans for subscriptions"),
+        (name = "Subscriptions", description = "Create and manage recurring billing of customers"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
         (name = "Disputes", description = "Manage disputes"),
@@ -66,6 +68,8 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
+        (name = "Webhook Endpoint", description = "Create and manage webhook endpoints of profiles"),
+        (name = "Incoming Webhook Event", description = "Manage incoming webhooks received from connectors"),
     ),
     // The paths will be displayed in the same order as they are registered here
     paths(
@@ -100,6 +104,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_account::update_merchant_account,
         routes::merchant_account::delete_merchant_account,
         routes::merchant_account::merchant_account_kv_status,
+        routes::merchant_account::merchant_account_kv_migrate,
 
         // Routes for merchant connector account
         routes::merchant_connector_account::connector_create,
@@ -119,6 +124,18 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::mandates::revoke_mandate,
         routes::mandates::customers_mandates_list,
 
+        // Routes for plans and subscriptions
+        routes::subscriptions::create_plan,
+        routes::subscriptions::retrieve_plan,
+        routes::subscriptions::list_plans,
+        routes::subscriptions::update_plan,
+        routes::subscriptions::deactivate_plan,
+        routes::subscriptions::create_subscription,
+        routes::subscriptions::retrieve_subscription,
+        routes::subscriptions::list_subscriptions,
+        routes::subscriptions::update_subscription,
+        routes::subscriptions::cancel_subscription,
+
         //Routes for customers
         routes::customers::customers_create,
         routes::customers::customers_retrieve,
@@ -145,14 +162,26 @@ Never share your secret api keys. Keep them guarded and secure.
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
+        routes::disputes::sync_dispute,
         routes::disputes::retrieve_disputes_list,
 
         // Routes for routing
         routes::routing::routing_create_config,
         routes::routing::routing_link_config,
+        routes::routing::routing_link_shadow_config,
+        routes::routing::routing_link_refund_config,
         routes::routing::routing_retrieve_config,
+        routes::routing::routing_create_dsl_config,
+        routes::routing::routing_retrieve_dsl_config,
+        routes::routing::routing_simulate,
+        routes::routing::routing_explain_eligibility,
+        routes::routing::routing_retrieve_activation_history,
+        routes::routing::routing_diff_configs,
+        routes::routing::routing_rollback_config,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
+        routes::routing::routing_unlink_shadow_config,
+        routes::routing::routing_unlink_refund_config,
         routes::routing::routing_update_default_config,
         routes::routing::routing_retrieve_default_config,
         routes::routing::routing_retrieve_linked_config,
@@ -160,6 +189,15 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_update_default_config_for_profile,
         routes::routing::toggle_success_based_routing,
         routes::routing::success_based_routing_update_configs,
+        routes::routing::toggle_least_cost_routing,
+        routes::routing::least_cost_routing_update_configs,
+        routes::routing::toggle_elimination_routing,
+        routes::routing::elimination_routing_update_configs,
+        routes::routing::elimination_status_retrieve,
+        routes::routing::elimination_state_override,
+        routes::routing::connector_fee_schedule_upsert,
+        routes::routing::connector_fee_schedule_retrieve,
+        routes::routing::connector_fee_schedule_delete,
 
         // Routes for blocklist
         routes::blocklist::remove_entry_from_blocklist,
@@ -185,11 +223,23 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::api_keys::api_key_revoke,
         routes::api_keys::api_key_list,
 
+        // Routes for webhook endpoints
+        routes::webhook_endpoints::create_webhook_endpoint,
+        routes::webhook_endpoints::list_webhook_endpoints,
+        routes::webhook_endpoints::retrieve_webhook_endpoint,
+        routes::webhook_endpoints::update_webhook_endpoint,
+        routes::webhook_endpoints::delete_webhook_endpoint,
+
         // Routes for events
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
 
+        // Routes for incoming webhook events
+        routes::incoming_webhook_events::list_incoming_webhook_events,
+        routes::incoming_webhook_events::retrieve_incoming_webhook_event,
+        routes::incoming_webhook_events::replay_incoming_webhook_event,
+
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
     ),
@@ -263,6 +313,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
+        api_models::enums::SubscriptionStatus,
+        api_models::enums::BillingInterval,
+        api_models::enums::IncomingWebhookEventStatus,
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
         api_models::enums::BankType,
@@ -493,6 +546,15 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::mandates::MandateCardDetails,
         api_models::mandates::RecurringDetails,
         api_models::mandates::ProcessorPaymentToken,
+        api_models::subscription::PlanCreateRequest,
+        api_models::subscription::PlanUpdateRequest,
+        api_models::subscription::PlanResponse,
+        api_models::subscription::PlanListConstraints,
+        api_models::subscription::SubscriptionCreateRequest,
+        api_models::subscription::SubscriptionUpdateRequest,
+        api_models::subscription::SubscriptionResponse,
+        api_models::subscription::SubscriptionCancelRequest,
+        api_models::subscription::SubscriptionListConstraints,
         api_models::ephemeral_key::EphemeralKeyCreateResponse,
         api_models::payments::CustomerDetails,
         api_models::payments::GiftCardData,
@@ -529,6 +591,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PayoutStatus,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
+        api_models::enums::RoutingActivationType,
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
         api_models::webhooks::OutgoingWebhookContent,
@@ -541,6 +604,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantDetails,
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
+        api_models::admin::StorageSchemeMigrationRequest,
+        api_models::admin::StorageSchemeMigrationResponse,
+        api_models::admin::StorageSchemeMigrationStatus,
         api_models::admin::WebhookDetails,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
@@ -548,6 +614,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
+        api_models::user_role::Permission,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
@@ -556,6 +623,25 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ExtendedCardInfoResponse,
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
+        api_models::routing::RoutingDslConfigRequest,
+        api_models::routing::RoutingDslResponse,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationResponse,
+        api_models::routing::SimulatedConnectorVolume,
+        api_models::routing::SimulatedRuleHit,
+        api_models::routing::EligibilityExplanationRequest,
+        api_models::routing::EligibilityExplanationResponse,
+        api_models::routing::ConnectorEligibilityExplanation,
+        api_models::routing::IneligibilityReason,
+        api_models::routing::ConstraintGraphEdge,
+        api_models::routing::EligibilityConstraintSource,
+        api_models::routing::EligibilityConstraintKind,
+        api_models::routing::RoutingActivationHistoryResponse,
+        api_models::routing::RoutingActivationRecord,
+        api_models::routing::RoutingAlgorithmDiff,
+        api_models::routing::RoutingRuleDiff,
+        api_models::routing::ConnectorSelectionDiff,
+        api_models::routing::RoutingRollbackRequest,
         api_models::routing::RoutingDictionaryRecord,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
@@ -576,6 +662,26 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::CurrentBlockThreshold,
         api_models::routing::SuccessBasedRoutingUpdateConfigQuery,
         api_models::routing::ToggleSuccessBasedRoutingPath,
+        api_models::routing::ToggleLeastCostRoutingQuery,
+        api_models::routing::ToggleLeastCostRoutingPath,
+        api_models::routing::LeastCostRoutingConfig,
+        api_models::routing::LeastCostRoutingUpdateConfigQuery,
+        api_models::routing::ToggleEliminationRoutingQuery,
+        api_models::routing::EliminationRoutingPath,
+        api_models::routing::EliminationRoutingConfig,
+        api_models::routing::EliminationRoutingUpdateConfigQuery,
+        api_models::routing::EliminationStatus,
+        api_models::routing::EliminationReason,
+        api_models::routing::EliminationBucket,
+        api_models::routing::EliminationStatusResponse,
+        api_models::routing::EliminationOverrideRequest,
+        api_models::routing::EliminationOverrideAction,
+        api_models::routing::ConnectorFeeSchedule,
+        api_models::routing::ConnectorFeeRule,
+        api_models::routing::ConnectorFee,
+        api_models::routing::CardFundingType,
+        api_models::routing::FeeRegion,
+        api_models::routing::ConnectorFeeScheduleResponse,
         api_models::routing::ast::RoutableChoiceKind,
         api_models::enums::RoutableConnectors,
         api_models::routing::ast::ProgramConnectorSelection,
@@ -593,10 +699,16 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
         api_models::enums::BlocklistDataKind,
+        api_models::webhook_endpoints::WebhookEndpointCreateRequest,
+        api_models::webhook_endpoints::WebhookEndpointUpdateRequest,
+        api_models::webhook_endpoints::WebhookEndpointResponse,
         api_models::webhook_events::EventListItemResponse,
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_events::IncomingWebhookEventListItemResponse,
+        api_models::webhook_events::IncomingWebhookEventRetrieveResponse,
+        api_models::webhook_events::IncomingWebhookRequestContent,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
@@ -484,6 +484,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
+        api_models::user_role::Permission,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
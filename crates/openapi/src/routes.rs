@@ -5,6 +5,7 @@ pub mod blocklist;
 pub mod customers;
 pub mod disputes;
 pub mod gsm;
+pub mod incoming_webhook_events;
 pub mod mandates;
 pub mod merchant_account;
 pub mod merchant_connector_account;
@@ -17,6 +18,8 @@ pub mod poll;
 pub mod profile;
 pub mod refunds;
 pub mod routing;
+pub mod subscriptions;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 
 pub use self::{
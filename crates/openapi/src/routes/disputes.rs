@@ -16,6 +16,24 @@
 )]
 pub async fn retrieve_dispute() {}
 
+/// Disputes - Sync Dispute
+/// Retrieves the latest state of a dispute from the connector and updates the dispute with it
+#[utoipa::path(
+    get,
+    path = "/disputes/{dispute_id}/sync",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute was synced successfully", body = DisputeResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Sync a Dispute",
+    security(("api_key" = []))
+)]
+pub async fn sync_dispute() {}
+
 /// Disputes - List Disputes
 /// Lists all the Disputes for a merchant
 #[utoipa::path(
@@ -0,0 +1,92 @@
+/// Incoming Webhook Events - List
+///
+/// List all incoming webhooks received from connectors for a Merchant Account.
+#[utoipa::path(
+    get,
+    path = "/incoming_webhook_events/{merchant_id}",
+    params(
+        (
+            "merchant_id" = String,
+            Path,
+            description = "The unique identifier for the Merchant Account."
+        ),
+        (
+            "connector" = Option<String>,
+            Query,
+            description = "Only include incoming webhooks received for the specified connector name or merchant connector account ID."
+        ),
+        (
+            "status" = Option<IncomingWebhookEventStatus>,
+            Query,
+            description = "Only include incoming webhooks with the specified processing status."
+        ),
+        (
+            "created_after" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Only include incoming webhooks received after the specified time."
+        ),
+        (
+            "created_before" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Only include incoming webhooks received before the specified time."
+        ),
+        (
+            "limit" = Option<u16>,
+            Query,
+            description = "The maximum number of incoming webhooks to include in the response."
+        ),
+        (
+            "offset" = Option<u16>,
+            Query,
+            description = "The number of incoming webhooks to skip when retrieving the list of incoming webhooks."
+        ),
+    ),
+    responses(
+        (status = 200, description = "List of incoming webhooks retrieved successfully", body = Vec<IncomingWebhookEventListItemResponse>),
+    ),
+    tag = "Incoming Webhook Event",
+    operation_id = "List all incoming webhooks received for a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub fn list_incoming_webhook_events() {}
+
+/// Incoming Webhook Events - Retrieve
+///
+/// Retrieve an incoming webhook, along with the request received from the connector.
+#[utoipa::path(
+    get,
+    path = "/incoming_webhook_events/{merchant_id}/{webhook_event_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("webhook_event_id" = String, Path, description = "The unique identifier for the incoming webhook"),
+    ),
+    responses(
+        (status = 200, description = "Incoming webhook retrieved successfully", body = IncomingWebhookEventRetrieveResponse),
+        (status = 404, description = "Incoming webhook does not exist in our records"),
+    ),
+    tag = "Incoming Webhook Event",
+    operation_id = "Retrieve an incoming webhook",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_incoming_webhook_event() {}
+
+/// Incoming Webhook Events - Replay
+///
+/// Process the stored request of an incoming webhook again, as if it was just received from the
+/// connector.
+#[utoipa::path(
+    post,
+    path = "/incoming_webhook_events/{merchant_id}/{webhook_event_id}/replay",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("webhook_event_id" = String, Path, description = "The unique identifier for the incoming webhook"),
+    ),
+    responses(
+        (status = 200, description = "Incoming webhook replayed, the response contains the outcome of the replay", body = IncomingWebhookEventRetrieveResponse),
+        (status = 404, description = "Incoming webhook does not exist in our records"),
+    ),
+    tag = "Incoming Webhook Event",
+    operation_id = "Replay an incoming webhook",
+    security(("admin_api_key" = []))
+)]
+pub fn replay_incoming_webhook_event() {}
@@ -270,6 +270,42 @@ pub async fn delete_merchant_account() {}
 )]
 pub async fn merchant_account_kv_status() {}
 
+#[cfg(feature = "v1")]
+/// Merchant Account - Migrate Storage Scheme
+///
+/// Migrate the Merchant Account between Postgres and KV. Moving to KV warms Redis with the open
+/// payment intents of the merchant, and moving back to Postgres waits for the drainer to flush the
+/// entries of the merchant. The request is to be repeated until the migration is completed.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/kv/migrate",
+    request_body (
+        content = StorageSchemeMigrationRequest,
+        examples (
+            ("Migrate Merchant to KV" = (
+                value = json!({
+                "kv_enabled": true
+                })
+        )),
+        ("Check whether Merchant can move back to Postgres" = (
+                value = json!({
+                "kv_enabled": false,
+                "dry_run": true
+                })
+        )))
+    ),
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Progress of the storage scheme migration", body = StorageSchemeMigrationResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Migrate the storage scheme of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_kv_migrate() {}
+
 /// Merchant Connector - List
 ///
 /// List Merchant Connector Details for the merchant
@@ -20,6 +20,28 @@
 )]
 pub async fn routing_create_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Create from DSL
+///
+/// Create an advanced routing config from a program in the textual routing DSL
+#[utoipa::path(
+    post,
+    path = "/routing/dsl",
+    request_body = RoutingDslConfigRequest,
+    responses(
+        (status = 200, description = "Routing config created", body = RoutingDictionaryRecord),
+        (status = 400, description = "Routing program is invalid"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Create a routing config from DSL",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_create_dsl_config() {}
+
 #[cfg(feature = "v2")]
 /// Routing - Create
 ///
@@ -64,6 +86,53 @@ pub async fn routing_create_config() {}
 )]
 pub async fn routing_link_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Activate shadow config
+///
+/// Activate a routing config as the shadow algorithm of the profile. The shadow algorithm is
+/// evaluated alongside the active one, and its decisions do not affect payments.
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_algorithm_id}/shadow/activate",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Shadow routing config activated", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Bad request")
+    ),
+   tag = "Routing",
+   operation_id = "Activate a shadow routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_link_shadow_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Activate refund config
+///
+/// Activate a routing config as the refund algorithm of the profile. The refund algorithm selects
+/// the payout connector through which a refund is paid out, when the connector of the payment can
+/// no longer process it.
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_algorithm_id}/refund/activate",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Refund routing config activated", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Bad request")
+    ),
+   tag = "Routing",
+   operation_id = "Activate a refund routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_link_refund_config() {}
+
 #[cfg(feature = "v1")]
 /// Routing - Retrieve
 ///
@@ -87,6 +156,142 @@ pub async fn routing_link_config() {}
 )]
 pub async fn routing_retrieve_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Retrieve as DSL
+///
+/// Retrieve an advanced routing config, with its program printed in the textual routing DSL
+#[utoipa::path(
+    get,
+    path = "/routing/{routing_algorithm_id}/dsl",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Successfully fetched routing config", body = RoutingDslResponse),
+        (status = 400, description = "Routing config is not an advanced routing config"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve a routing config as DSL",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_retrieve_dsl_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Simulate
+///
+/// Replay the payments of a profile made in a time range through a candidate or an existing routing algorithm, and report the connectors they would have been routed to, the rules they matched and the payments for which no connector would have been eligible
+#[utoipa::path(
+    post,
+    path = "/routing/simulate",
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Routing simulation completed", body = RoutingSimulationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Simulate a routing algorithm",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Explain Eligibility
+///
+/// Explain why each connector of a profile is eligible or not for a payment, or for a synthetic set of routing inputs, naming the node of the constraint graph, the edge and the merchant connector account or payment method filter configuration that excluded it
+#[utoipa::path(
+    post,
+    path = "/routing/explain_eligibility",
+    request_body = EligibilityExplanationRequest,
+    responses(
+        (status = 200, description = "Eligibility of the connectors explained", body = EligibilityExplanationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Explain the eligibility of connectors",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_explain_eligibility() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Activation History
+///
+/// List the changes made to the active routing algorithm of a profile, along with who made them, the most recent first
+#[utoipa::path(
+    get,
+    path = "/routing/history",
+    params(
+        ("profile_id" = Option<String>, Query, description = "The unique identifier for a merchant profile"),
+        ("limit" = Option<u16>, Query, description = "The number of records to be returned"),
+        ("offset" = Option<u32>, Query, description = "The record offset from which to start gathering of results"),
+    ),
+    responses(
+        (status = 200, description = "Successfully fetched routing activation history", body = RoutingActivationHistoryResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve the routing activation history",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_retrieve_activation_history() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Diff
+///
+/// Compare two routing algorithms, reporting the rules which were added, removed or changed and the changes to the default connector selection
+#[utoipa::path(
+    get,
+    path = "/routing/diff",
+    params(
+        ("from_algorithm_id" = String, Query, description = "The unique identifier of the routing algorithm to compare from"),
+        ("to_algorithm_id" = String, Query, description = "The unique identifier of the routing algorithm to compare to"),
+    ),
+    responses(
+        (status = 200, description = "Successfully compared routing configs", body = RoutingAlgorithmDiff),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Compare two routing configs",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_diff_configs() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Rollback
+///
+/// Activate the routing config which was active before the most recent change to the active routing config of the profile
+#[utoipa::path(
+    post,
+    path = "/routing/rollback",
+    request_body = RoutingRollbackRequest,
+    responses(
+        (status = 200, description = "Successfully rolled back routing config", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "No previously active routing config to roll back to")
+    ),
+   tag = "Routing",
+   operation_id = "Roll back the active routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_rollback_config() {}
+
 #[cfg(feature = "v2")]
 /// Routing - Retrieve
 ///
@@ -154,6 +359,48 @@ pub async fn list_routing_configs() {}
 )]
 pub async fn routing_unlink_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Deactivate shadow config
+///
+/// Deactivates the shadow routing config of the profile
+#[utoipa::path(
+    post,
+    path = "/routing/shadow/deactivate",
+    request_body = RoutingConfigRequest,
+    responses(
+        (status = 200, description = "Successfully deactivated shadow routing config", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Malformed request"),
+        (status = 422, description = "Unprocessable request")
+    ),
+   tag = "Routing",
+   operation_id = "Deactivate a shadow routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_unlink_shadow_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Deactivate refund config
+///
+/// Deactivates the refund routing config of the profile
+#[utoipa::path(
+    post,
+    path = "/routing/refund/deactivate",
+    request_body = RoutingConfigRequest,
+    responses(
+        (status = 200, description = "Successfully deactivated refund routing config", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Malformed request"),
+        (status = 422, description = "Unprocessable request")
+    ),
+   tag = "Routing",
+   operation_id = "Deactivate a refund routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_unlink_refund_config() {}
+
 #[cfg(feature = "v1")]
 /// Routing - Update Default Config
 ///
@@ -308,3 +555,225 @@ pub async fn toggle_success_based_routing() {}
    security(("admin_api_key" = []))
 )]
 pub async fn success_based_routing_update_configs() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Toggle least cost routing for profile
+///
+/// Create a least cost routing algorithm, which orders the eligible connectors by the estimated cost of the payment
+#[utoipa::path(
+    post,
+    path = "/account/:account_id/business_profile/:profile_id/dynamic_routing/least_cost/toggle",
+    params(
+        ("account_id" = String, Path, description = "Merchant id"),
+        ("profile_id" = String, Path, description = "Profile id under which least cost routing needs to be toggled"),
+        ("status" = bool, Query, description = "Boolean value for mentioning the expected state of least cost routing"),
+    ),
+    responses(
+        (status = 200, description = "Routing Algorithm created", body = RoutingDictionaryRecord),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "Algorithm is already inactive"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Toggle least cost dynamic routing algorithm",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn toggle_least_cost_routing() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Update config for least cost routing
+///
+/// Update the config of the active least cost routing algorithm of the profile
+#[utoipa::path(
+    patch,
+    path = "/account/:account_id/business_profile/:profile_id/dynamic_routing/least_cost/config/:algorithm_id",
+    request_body = LeastCostRoutingConfig,
+    params(
+        ("account_id" = String, Path, description = "Merchant id"),
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+        ("algorithm_id" = String, Path, description = "The unique identifier for the active least cost routing algorithm"),
+    ),
+    responses(
+        (status = 200, description = "Routing Algorithm updated", body = RoutingDictionaryRecord),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "The algorithm is not the active least cost routing algorithm of the profile"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Update configs for least cost dynamic routing algorithm",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn least_cost_routing_update_configs() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Toggle elimination routing for profile
+///
+/// Create an elimination routing algorithm, which excludes connectors from routing for a payment method while their payments fail due to the connector
+#[utoipa::path(
+    post,
+    path = "/account/:account_id/business_profile/:profile_id/dynamic_routing/elimination/toggle",
+    params(
+        ("account_id" = String, Path, description = "Merchant id"),
+        ("profile_id" = String, Path, description = "Profile id under which elimination routing needs to be toggled"),
+        ("status" = bool, Query, description = "Boolean value for mentioning the expected state of elimination routing"),
+    ),
+    responses(
+        (status = 200, description = "Routing Algorithm created", body = RoutingDictionaryRecord),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "Algorithm is already inactive"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Toggle elimination dynamic routing algorithm",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn toggle_elimination_routing() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Update config for elimination routing
+///
+/// Update the config of the active elimination routing algorithm of the profile
+#[utoipa::path(
+    patch,
+    path = "/account/:account_id/business_profile/:profile_id/dynamic_routing/elimination/config/:algorithm_id",
+    request_body = EliminationRoutingConfig,
+    params(
+        ("account_id" = String, Path, description = "Merchant id"),
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+        ("algorithm_id" = String, Path, description = "The unique identifier for the active elimination routing algorithm"),
+    ),
+    responses(
+        (status = 200, description = "Routing Algorithm updated", body = RoutingDictionaryRecord),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "The algorithm is not the active elimination routing algorithm of the profile"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Update configs for elimination dynamic routing algorithm",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn elimination_routing_update_configs() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve elimination status
+///
+/// Retrieve the connectors of the profile which have been eliminated for a payment method, along with their state
+#[utoipa::path(
+    get,
+    path = "/account/:account_id/business_profile/:profile_id/dynamic_routing/elimination/status",
+    params(
+        ("account_id" = String, Path, description = "Merchant id"),
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Elimination state retrieved", body = EliminationStatusResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve elimination status",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn elimination_status_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Override elimination state
+///
+/// Eliminate or restore a connector of the profile for a payment method, irrespective of its failures
+#[utoipa::path(
+    post,
+    path = "/account/:account_id/business_profile/:profile_id/dynamic_routing/elimination/override",
+    request_body = EliminationOverrideRequest,
+    params(
+        ("account_id" = String, Path, description = "Merchant id"),
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Elimination state updated", body = EliminationBucket),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Override elimination state",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn elimination_state_override() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Connector - Create or Update Fee Schedule
+///
+/// Create or replace the fee schedule of a Merchant Connector, which is used to estimate the cost of payments for least cost routing
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/connectors/{merchant_connector_id}/fee_schedule",
+    request_body = ConnectorFeeSchedule,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("merchant_connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Fee schedule updated", body = ConnectorFeeScheduleResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Upsert a Merchant Connector Fee Schedule",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_fee_schedule_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Connector - Retrieve Fee Schedule
+///
+/// Retrieve the fee schedule of a Merchant Connector
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/connectors/{merchant_connector_id}/fee_schedule",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("merchant_connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Fee schedule retrieved successfully", body = ConnectorFeeScheduleResponse),
+        (status = 404, description = "Merchant Connector or its fee schedule does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Retrieve a Merchant Connector Fee Schedule",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_fee_schedule_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Connector - Delete Fee Schedule
+///
+/// Delete the fee schedule of a Merchant Connector
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/connectors/{merchant_connector_id}/fee_schedule",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("merchant_connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Fee schedule deleted successfully", body = ConnectorFeeScheduleResponse),
+        (status = 404, description = "Merchant Connector or its fee schedule does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Delete a Merchant Connector Fee Schedule",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_fee_schedule_delete() {}
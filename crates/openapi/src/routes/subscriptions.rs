@@ -0,0 +1,193 @@
+/// Plans - Create
+///
+/// Creates a plan, which defines the amount and the billing cycle of subscriptions
+#[utoipa::path(
+    post,
+    path = "/plans",
+    request_body = PlanCreateRequest,
+    responses(
+        (status = 200, description = "Plan created", body = PlanResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Plans",
+    operation_id = "Create a Plan",
+    security(("api_key" = []))
+)]
+pub async fn create_plan() {}
+
+/// Plans - Retrieve
+///
+/// Retrieves a plan
+#[utoipa::path(
+    get,
+    path = "/plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the plan")
+    ),
+    responses(
+        (status = 200, description = "Plan retrieved", body = PlanResponse),
+        (status = 404, description = "Plan does not exist in our records")
+    ),
+    tag = "Plans",
+    operation_id = "Retrieve a Plan",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_plan() {}
+
+/// Plans - List
+///
+/// Lists the plans of a merchant
+#[utoipa::path(
+    get,
+    path = "/plans/list",
+    params(
+        ("limit" = Option<u16>, Query, description = "The maximum number of plans to include in the response"),
+        ("offset" = Option<u16>, Query, description = "The number of plans to skip"),
+    ),
+    responses(
+        (status = 200, description = "Plans retrieved", body = Vec<PlanResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Plans",
+    operation_id = "List all Plans",
+    security(("api_key" = []))
+)]
+pub async fn list_plans() {}
+
+/// Plans - Update
+///
+/// Updates the name, description or metadata of a plan
+#[utoipa::path(
+    post,
+    path = "/plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the plan")
+    ),
+    request_body = PlanUpdateRequest,
+    responses(
+        (status = 200, description = "Plan updated", body = PlanResponse),
+        (status = 404, description = "Plan does not exist in our records")
+    ),
+    tag = "Plans",
+    operation_id = "Update a Plan",
+    security(("api_key" = []))
+)]
+pub async fn update_plan() {}
+
+/// Plans - Deactivate
+///
+/// Deactivates a plan, after which no new subscriptions can be created for it. Existing
+/// subscriptions continue to be billed.
+#[utoipa::path(
+    post,
+    path = "/plans/{plan_id}/deactivate",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the plan")
+    ),
+    responses(
+        (status = 200, description = "Plan deactivated", body = PlanResponse),
+        (status = 404, description = "Plan does not exist in our records")
+    ),
+    tag = "Plans",
+    operation_id = "Deactivate a Plan",
+    security(("api_key" = []))
+)]
+pub async fn deactivate_plan() {}
+
+/// Subscriptions - Create
+///
+/// Subscribes a customer to a plan. The customer is charged using the given mandate at the end of
+/// every billing period.
+#[utoipa::path(
+    post,
+    path = "/subscriptions",
+    request_body = SubscriptionCreateRequest,
+    responses(
+        (status = 200, description = "Subscription created", body = SubscriptionResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn create_subscription() {}
+
+/// Subscriptions - Retrieve
+///
+/// Retrieves a subscription
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription retrieved", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_subscription() {}
+
+/// Subscriptions - List
+///
+/// Lists the subscriptions of a merchant, optionally filtered by customer
+#[utoipa::path(
+    get,
+    path = "/subscriptions/list",
+    params(
+        ("customer_id" = Option<String>, Query, description = "The identifier for the customer"),
+        ("limit" = Option<u16>, Query, description = "The maximum number of subscriptions to include in the response"),
+        ("offset" = Option<u16>, Query, description = "The number of subscriptions to skip"),
+    ),
+    responses(
+        (status = 200, description = "Subscriptions retrieved", body = Vec<SubscriptionResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Subscriptions",
+    operation_id = "List all Subscriptions",
+    security(("api_key" = []))
+)]
+pub async fn list_subscriptions() {}
+
+/// Subscriptions - Update
+///
+/// Updates the mandate, metadata or `cancel_at_period_end` flag of a subscription
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    request_body = SubscriptionUpdateRequest,
+    responses(
+        (status = 200, description = "Subscription updated", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Update a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn update_subscription() {}
+
+/// Subscriptions - Cancel
+///
+/// Cancels a subscription, either immediately or at the end of the current billing period
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/cancel",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    request_body = SubscriptionCancelRequest,
+    responses(
+        (status = 200, description = "Subscription cancelled", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Cancel a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn cancel_subscription() {}
@@ -0,0 +1,129 @@
+/// Webhook Endpoint - Create
+///
+/// Create a webhook endpoint for a Profile. Webhooks for the event types the endpoint is
+/// subscribed to are sent to the endpoint, in addition to the webhook URL configured in the
+/// Profile.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = WebhookEndpointCreateRequest,
+        examples(
+            (
+                "Create a webhook endpoint for refund events" = (
+                    value = json!({
+                        "url": "https://ledger.example.com/webhooks",
+                        "enabled_events": ["refund_succeeded", "refund_failed"]
+                    })
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint created", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Create a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn create_webhook_endpoint() {}
+
+/// Webhook Endpoint - List
+///
+/// List all webhook endpoints of a Profile.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoints retrieved", body = Vec<WebhookEndpointResponse>),
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "List all Webhook Endpoints of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn list_webhook_endpoints() {}
+
+/// Webhook Endpoint - Retrieve
+///
+/// Retrieve a webhook endpoint.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint retrieved", body = WebhookEndpointResponse),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Retrieve a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn retrieve_webhook_endpoint() {}
+
+/// Webhook Endpoint - Update
+///
+/// Update the URL, secret, subscribed event types or status of a webhook endpoint.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    request_body(
+        content = WebhookEndpointUpdateRequest,
+        examples(
+            (
+                "Disable a webhook endpoint" = (
+                    value = json!({
+                        "is_enabled": false
+                    })
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint updated", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Update a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn update_webhook_endpoint() {}
+
+/// Webhook Endpoint - Delete
+///
+/// Delete a webhook endpoint. Pending retries of webhooks sent to the endpoint are abandoned.
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint deleted", body = WebhookEndpointResponse),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Delete a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn delete_webhook_endpoint() {}
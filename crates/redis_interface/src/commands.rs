@@ -630,6 +630,23 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetLengthFailed)
     }
 
+    /// Reads the entries of the stream with IDs between `start` and `end`, both inclusive. The
+    /// special IDs `-` and `+` denote the first and the last entry of the stream respectively.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_range(
+        &self,
+        stream: &str,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<(String, std::collections::HashMap<String, String>)>, errors::RedisError>
+    {
+        self.pool
+            .xrange_values(self.add_prefix(stream), start, end, count)
+            .await
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     pub fn get_keys_with_prefix<K>(&self, keys: K) -> MultipleKeys
     where
         K: Into<MultipleKeys> + Debug + Send + Sync,
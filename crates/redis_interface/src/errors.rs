@@ -56,6 +56,8 @@ pub enum RedisError {
     GetHashFieldFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
+    #[error("The requested value has been deleted in Redis")]
+    ValueDeleted,
     #[error("Invalid RedisEntryId provided")]
     InvalidRedisEntryId,
     #[error("Failed to establish Redis connection")]
@@ -6,7 +6,7 @@ pub mod routes {
         api_event::api_events_core, connector_events::connector_events_core, enums::AuthInfo,
         errors::AnalyticsError, lambda_utils::invoke_lambda, opensearch::OpenSearchError,
         outgoing_webhook_event::outgoing_webhook_events_core, sdk_events::sdk_events_core,
-        AnalyticsFlow,
+        shadow_routing_event::shadow_routing_events_core, AnalyticsFlow,
     };
     use api_models::analytics::{
         api_event::QueryType,
@@ -113,6 +113,10 @@ pub mod routes {
                             web::resource("outgoing_webhook_event_logs")
                                 .route(web::get().to(get_profile_outgoing_webhook_events)),
                         )
+                        .service(
+                            web::resource("shadow_routing_event_logs")
+                                .route(web::post().to(get_profile_shadow_routing_events)),
+                        )
                         .service(
                             web::resource("metrics/api_events")
                                 .route(web::post().to(get_merchant_api_events_metrics)),
@@ -229,8 +233,8 @@ pub mod routes {
                                         .route(web::post().to(generate_org_payment_report)),
                                 ),
                         )
-                        .service(
-                            web::scope("/profile")
+                        .service({
+                            let profile_scope = web::scope("/profile")
                                 .service(
                                     web::resource("{domain}/info").route(web::get().to(get_info)),
                                 )
@@ -266,6 +270,10 @@ pub mod routes {
                                     web::resource("outgoing_webhook_event_logs")
                                         .route(web::get().to(get_profile_outgoing_webhook_events)),
                                 )
+                                .service(
+                                    web::resource("shadow_routing_event_logs")
+                                        .route(web::post().to(get_profile_shadow_routing_events)),
+                                )
                                 .service(
                                     web::resource("report/dispute")
                                         .route(web::post().to(generate_profile_dispute_report)),
@@ -285,8 +293,14 @@ pub mod routes {
                                 .service(
                                     web::resource("sdk_event_logs")
                                         .route(web::post().to(get_profile_sdk_events)),
-                                ),
-                        ),
+                                );
+                            #[cfg(feature = "v1")]
+                            let profile_scope = profile_scope.service(
+                                web::resource("connector_cost_estimates")
+                                    .route(web::post().to(get_profile_connector_cost_estimates)),
+                            );
+                            profile_scope
+                        }),
                 )
                 .service(
                     web::scope("/v2")
@@ -1221,6 +1235,75 @@ pub mod routes {
         .await
     }
 
+    pub async fn get_profile_shadow_routing_events(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<
+            api_models::analytics::shadow_routing_event::ShadowRoutingLogsRequest,
+        >,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetShadowRoutingEvents;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                if let Some(payment_id) = req.payment_id.clone() {
+                    utils::check_if_profile_id_is_present_in_payment_intent(
+                        payment_id, &state, &auth,
+                    )
+                    .await
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                }
+                shadow_routing_events_core(
+                    &state.pool,
+                    req,
+                    auth.merchant_account.get_id(),
+                    auth.profile_id.as_ref(),
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::Analytics,
+                minimum_entity_level: EntityType::Profile,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_connector_cost_estimates(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<api_models::routing::ConnectorCostEstimateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetConnectorCostEstimates;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| {
+                crate::core::routing::estimate_connector_costs(
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    auth.profile_id,
+                    req,
+                )
+            },
+            &auth::JWTAuth {
+                permission: Permission::Analytics,
+                minimum_entity_level: EntityType::Profile,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_profile_sdk_events(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
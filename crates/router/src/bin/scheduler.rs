@@ -34,17 +34,8 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
     #[allow(clippy::expect_used)]
     let conf = Settings::with_config_path(cmd_line.config_path)
         .expect("Unable to construct application configuration");
-    let api_client = Box::new(
-        services::ProxyClient::new(
-            conf.proxy.clone(),
-            services::proxy_bypass_urls(
-                conf.key_manager.get_inner(),
-                &conf.locker,
-                &conf.proxy.bypass_proxy_urls,
-            ),
-        )
-        .change_context(ProcessTrackerError::ConfigurationError)?,
-    );
+    let api_client = services::create_api_client(&conf)
+        .change_context(ProcessTrackerError::ConfigurationError)?;
     // channel for listening to redis disconnect events
     let (redis_shutdown_signal_tx, redis_shutdown_signal_rx) = oneshot::channel();
     let state = Box::pin(routes::AppState::new(
@@ -329,6 +320,12 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
+                storage::ProcessTrackerRunner::SubscriptionBillingWorkflow => Ok(Box::new(
+                    workflows::subscription_billing::SubscriptionBillingWorkflow,
+                )),
+                storage::ProcessTrackerRunner::DisputeSyncWorkflow => {
+                    Ok(Box::new(workflows::dispute_sync::DisputeSyncWorkflow))
+                }
             }
         };
 
@@ -105,6 +105,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "duplicate_resource", message = "{message}")]
     GenericDuplicateError { message: String },
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "Keys for idempotent requests can only be used with the same parameters they were first used with")]
+    IdempotencyKeyReused,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such merchant account")]
     MerchantAccountNotFound,
 
@@ -258,6 +261,8 @@ pub enum StripeErrorCode {
     PaymentLinkNotFound,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Resource Busy. Please try again later")]
     LockTimeout,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly")]
+    RateLimit,
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "Merchant connector account is configured with invalid {config}")]
     InvalidConnectorConfiguration { config: String },
     #[error(error_type = StripeErrorType::HyperswitchError, code = "HE_01", message = "Failed to convert currency to minor unit")]
@@ -500,6 +505,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::RefundNotPossible { connector: _ } => Self::RefundFailed,
             errors::ApiErrorResponse::RefundFailed { data: _ } => Self::RefundFailed, // Nothing at stripe to map
             errors::ApiErrorResponse::PayoutFailed { data: _ } => Self::PayoutFailed,
+            errors::ApiErrorResponse::IdempotencyKeyReused { .. } => Self::IdempotencyKeyReused,
 
             errors::ApiErrorResponse::MandateUpdateFailed
             | errors::ApiErrorResponse::MandateSerializationFailed
@@ -619,6 +625,22 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 object: "poll".to_owned(),
                 id,
             },
+            errors::ApiErrorResponse::PlanNotFound { plan_id } => Self::ResourceMissing {
+                object: "plan".to_owned(),
+                id: plan_id,
+            },
+            errors::ApiErrorResponse::SubscriptionNotFound { subscription_id } => {
+                Self::ResourceMissing {
+                    object: "subscription".to_owned(),
+                    id: subscription_id,
+                }
+            }
+            errors::ApiErrorResponse::WebhookEndpointNotFound { endpoint_id } => {
+                Self::ResourceMissing {
+                    object: "webhook_endpoint".to_owned(),
+                    id: endpoint_id,
+                }
+            }
             errors::ApiErrorResponse::DisputeStatusValidationFailed { reason: _ } => {
                 Self::InternalServerError
             }
@@ -677,6 +699,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::AmountConversionFailed { amount_type } => {
                 Self::AmountConversionFailed { amount_type }
             }
+            errors::ApiErrorResponse::RateLimitExceeded { .. } => Self::RateLimit,
         }
     }
 }
@@ -733,6 +756,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::PaymentIntentUnexpectedState { .. }
             | Self::DuplicatePayment { .. }
             | Self::GenericDuplicateError { .. }
+            | Self::IdempotencyKeyReused
             | Self::IncorrectConnectorNameGiven
             | Self::ResourceMissing { .. }
             | Self::FileValidationFailed
@@ -769,6 +793,7 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*code).unwrap_or(StatusCode::OK)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::RateLimit => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
@@ -1,7 +1,7 @@
 #[cfg(feature = "payouts")]
 use api_models::payouts as payout_models;
 use api_models::{
-    enums::{DisputeStatus, MandateStatus},
+    enums::{DisputeStatus, MandateStatus, SubscriptionStatus},
     webhooks::{self as api},
 };
 #[cfg(feature = "payouts")]
@@ -87,6 +87,7 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    Subscription(StripeSubscriptionResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -107,6 +108,28 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripeSubscriptionResponse {
+    pub id: String,
+    pub customer: common_utils::id_type::CustomerId,
+    pub plan: String,
+    pub status: StripeSubscriptionStatus,
+    pub current_period_start: i64,
+    pub current_period_end: i64,
+    pub cancel_at_period_end: bool,
+    pub canceled_at: Option<i64>,
+    pub latest_invoice: Option<common_utils::id_type::PaymentId>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeSubscriptionStatus {
+    Trialing,
+    Active,
+    PastDue,
+    Canceled,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Clone, Serialize, Debug)]
 pub struct StripePayoutResponse {
@@ -248,6 +271,35 @@ impl From<MandateStatus> for StripeMandateStatus {
     }
 }
 
+impl From<api_models::subscription::SubscriptionResponse> for StripeSubscriptionResponse {
+    fn from(res: api_models::subscription::SubscriptionResponse) -> Self {
+        Self {
+            id: res.subscription_id,
+            customer: res.customer_id,
+            plan: res.plan_id,
+            status: StripeSubscriptionStatus::from(res.status),
+            current_period_start: res.current_period_start.assume_utc().unix_timestamp(),
+            current_period_end: res.current_period_end.assume_utc().unix_timestamp(),
+            cancel_at_period_end: res.cancel_at_period_end,
+            canceled_at: res
+                .cancelled_at
+                .map(|cancelled_at| cancelled_at.assume_utc().unix_timestamp()),
+            latest_invoice: res.latest_payment_id,
+        }
+    }
+}
+
+impl From<SubscriptionStatus> for StripeSubscriptionStatus {
+    fn from(status: SubscriptionStatus) -> Self {
+        match status {
+            SubscriptionStatus::Trialing => Self::Trialing,
+            SubscriptionStatus::Active => Self::Active,
+            SubscriptionStatus::PastDue => Self::PastDue,
+            SubscriptionStatus::Cancelled => Self::Canceled,
+        }
+    }
+}
+
 impl From<DisputeStatus> for StripeDisputeStatus {
     fn from(status: DisputeStatus) -> Self {
         match status {
@@ -296,6 +348,10 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::SubscriptionCreated => "customer.subscription.created",
+        api_models::enums::EventType::SubscriptionRenewed => "invoice.paid",
+        api_models::enums::EventType::SubscriptionPaymentFailed => "invoice.payment_failed",
+        api_models::enums::EventType::SubscriptionCancelled => "customer.subscription.deleted",
     }
 }
 
@@ -338,6 +394,9 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout(payout.into()),
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                Self::Subscription((*subscription).into())
+            }
         }
     }
 }
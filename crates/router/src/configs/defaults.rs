@@ -109,6 +109,34 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::IdempotencySettings {
+    fn default() -> Self {
+        Self {
+            // 24 hours
+            key_ttl_in_seconds: 86400,
+        }
+    }
+}
+
+impl Default for super::settings::ForexApi {
+    fn default() -> Self {
+        Self {
+            local_fetch_retry_count: 0,
+            api_key: String::new().into(),
+            fallback_api_key: String::new().into(),
+            call_delay: 0,
+            local_fetch_retry_delay: 0,
+            api_timeout: 0,
+            redis_lock_timeout: 0,
+            providers: vec![
+                super::settings::ForexProvider::OpenExchangeRates,
+                super::settings::ForexProvider::ApiLayer,
+            ],
+            static_rates_file: None,
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -12509,6 +12537,9 @@ impl Default for super::settings::ApiKeys {
             #[cfg(feature = "email")]
             expiry_reminder_days: vec![7, 3, 1],
 
+            // The `X-Forwarded-For` header is not trusted unless the proxies are configured
+            trusted_proxy_count: 0,
+
             // Hex-encoded key used for calculating checksum for partial auth
             #[cfg(feature = "partial-auth")]
             checksum_auth_key: String::new().into(),
@@ -114,6 +114,8 @@ impl SecretsHandler for settings::ApiKeys {
         #[cfg(feature = "email")]
         let expiry_reminder_days = api_keys.expiry_reminder_days.clone();
 
+        let trusted_proxy_count = api_keys.trusted_proxy_count;
+
         #[cfg(feature = "partial-auth")]
         let enable_partial_auth = api_keys.enable_partial_auth;
 
@@ -137,6 +139,8 @@ impl SecretsHandler for settings::ApiKeys {
             #[cfg(feature = "email")]
             expiry_reminder_days,
 
+            trusted_proxy_count,
+
             #[cfg(feature = "partial-auth")]
             checksum_auth_key,
             #[cfg(feature = "partial-auth")]
@@ -443,6 +447,7 @@ pub(crate) async fn fetch_raw_secrets(
         encryption_management: conf.encryption_management,
         secrets_management: conf.secrets_management,
         proxy: conf.proxy,
+        mock_api_client: conf.mock_api_client,
         env: conf.env,
         key_manager,
         #[cfg(feature = "olap")]
@@ -482,6 +487,8 @@ pub(crate) async fn fetch_raw_secrets(
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
+        idempotency: conf.idempotency,
+        rate_limit: conf.rate_limit,
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
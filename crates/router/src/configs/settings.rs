@@ -37,6 +37,7 @@ use crate::{
     core::errors::{ApplicationError, ApplicationResult},
     env::{self, Env},
     events::EventsConfig,
+    routes::lock_utils::ApiIdentifier,
 };
 
 #[derive(clap::Parser, Default)]
@@ -53,6 +54,7 @@ pub struct CmdLineConf {
 pub struct Settings<S: SecretState> {
     pub server: Server,
     pub proxy: Proxy,
+    pub mock_api_client: MockApiClientConfig,
     pub env: Env,
     pub master_database: SecretStateContainer<Database, S>,
     #[cfg(feature = "olap")]
@@ -99,6 +101,8 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub idempotency: IdempotencySettings,
+    pub rate_limit: RateLimitSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -129,6 +133,32 @@ pub struct Settings<S: SecretState> {
     pub network_tokenization_supported_connectors: NetworkTokenizationSupportedConnectors,
 }
 
+/// Configuration of the mock API client, which is used in place of the proxy client to record
+/// outgoing requests and their responses to fixtures, or to replay responses from the fixtures.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct MockApiClientConfig {
+    pub mode: MockApiClientMode,
+    /// Directory the fixtures are recorded to and replayed from
+    pub fixtures_path: String,
+    /// Names of the query parameters and body fields whose values differ between runs, such as
+    /// references generated for every payment, which are left out when matching requests with
+    /// fixtures
+    pub volatile_fields: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MockApiClientMode {
+    /// Requests are sent as is, the mock API client is not used by the application
+    #[default]
+    Passthrough,
+    /// Requests are sent as is, and the requests along with their responses are recorded
+    Record,
+    /// Responses are replayed from the recorded fixtures, without sending any request
+    Replay,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct Multitenancy {
     pub tenants: TenantConfig,
@@ -279,7 +309,7 @@ pub struct PaymentLink {
     pub sdk_url: String,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct ForexApi {
     pub local_fetch_retry_count: u64,
@@ -293,6 +323,18 @@ pub struct ForexApi {
     pub api_timeout: u64,
     /// in ms
     pub redis_lock_timeout: u64,
+    /// The rate providers, in the order in which they are tried
+    pub providers: Vec<ForexProvider>,
+    /// Path to the file with the rates served by the static file provider
+    pub static_rates_file: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ForexProvider {
+    OpenExchangeRates,
+    ApiLayer,
+    StaticFile,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -312,9 +354,19 @@ pub struct EligiblePaymentMethods {
 pub struct DefaultExchangeRates {
     pub base_currency: String,
     pub conversion: HashMap<String, Conversion>,
+    #[serde(default)]
+    pub direct_rates: Vec<DirectExchangeRate>,
     pub timestamp: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct DirectExchangeRate {
+    pub from_currency: String,
+    pub to_currency: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub rate: Decimal,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct Conversion {
     #[serde(with = "rust_decimal::serde::str")]
@@ -693,6 +745,11 @@ pub struct ApiKeys {
     #[cfg(feature = "email")]
     pub expiry_reminder_days: Vec<u8>,
 
+    /// Number of proxies in front of the application which append the address of their peer to the
+    /// `X-Forwarded-For` header, used to obtain the client IP address checked against the IP
+    /// allowlists of API keys. The header is not trusted when this is zero.
+    pub trusted_proxy_count: usize,
+
     #[cfg(feature = "partial-auth")]
     pub checksum_auth_context: Secret<String>,
 
@@ -781,7 +838,9 @@ impl Settings<SecuredSecret> {
                     .with_list_parse_key("redis.cluster_urls")
                     .with_list_parse_key("events.kafka.brokers")
                     .with_list_parse_key("proxy.bypass_proxy_urls")
+                    .with_list_parse_key("mock_api_client.volatile_fields")
                     .with_list_parse_key("connectors.supported.wallets")
+                    .with_list_parse_key("forex_api.providers")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
 
             )
@@ -836,12 +895,15 @@ impl Settings<SecuredSecret> {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.get_inner().validate()?;
+        self.forex_api.get_inner().validate()?;
 
         self.file_storage
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.idempotency.validate()?;
+        self.rate_limit.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
@@ -919,6 +981,29 @@ impl<'de> Deserialize<'de> for LockSettings {
     }
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct IdempotencySettings {
+    /// Number of seconds for which the response of a request with an `Idempotency-Key` is retained
+    pub key_ttl_in_seconds: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    /// Limit applied to the APIs which do not have a limit configured in `apis`
+    pub default: Option<RateLimit>,
+    pub apis: HashMap<ApiIdentifier, RateLimit>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct RateLimit {
+    /// Maximum number of requests allowed within the window
+    pub max_requests: u64,
+    pub window_in_seconds: u64,
+}
+
 #[cfg(feature = "olap")]
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorOnboarding {
@@ -153,6 +153,32 @@ impl super::settings::ApiKeys {
     }
 }
 
+impl super::settings::ForexApi {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.providers.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "At least one forex rate provider must be configured".into(),
+            ))
+        })?;
+
+        when(
+            self.providers
+                .contains(&super::settings::ForexProvider::StaticFile)
+                && self.static_rates_file.is_none(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Static rates file must be specified when using the static file forex rate provider"
+                        .into(),
+                ))
+            },
+        )?;
+
+        Ok(())
+    }
+}
+
 impl super::settings::LockSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -181,6 +207,35 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::IdempotencySettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.key_ttl_in_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency key_ttl_in_seconds must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.default
+            .iter()
+            .chain(self.apis.values())
+            .try_for_each(|rate_limit| {
+                when(rate_limit.window_in_seconds == 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "rate limit window_in_seconds must not be 0".into(),
+                    ))
+                })
+            })
+    }
+}
+
 impl super::settings::GenericLinkEnvConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -1910,6 +1910,7 @@ impl api::Dispute for Adyen {}
 impl api::DefendDispute for Adyen {}
 impl api::AcceptDispute for Adyen {}
 impl api::SubmitEvidence for Adyen {}
+impl api::SyncDispute for Adyen {}
 
 impl
     services::ConnectorIntegration<
@@ -2092,6 +2093,97 @@ impl
     }
 }
 
+impl
+    services::ConnectorIntegration<
+        api::DisputeSync,
+        types::DisputeSyncData,
+        types::DisputeSyncResponse,
+    > for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::DisputeSyncRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::DisputeSyncType::get_content_type(self)
+                .to_string()
+                .into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::DisputeSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let endpoint = build_env_specific_endpoint(
+            connectors.adyen.dispute_base_url.as_str(),
+            req.test_mode,
+            &req.connector_meta_data,
+        )?;
+        Ok(format!(
+            "{}ca/services/DisputeService/v30/retrieveApplicableDefenseReasons",
+            endpoint
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::DisputeSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::DisputeSyncType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::DisputeSyncType::get_headers(self, req, connectors)?)
+                .set_body(types::DisputeSyncType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::DisputeSyncRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = adyen::AdyenDisputeSyncRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::DisputeSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::DisputeSyncRouterData, errors::ConnectorError> {
+        let response: adyen::AdyenDefenseReasonsResponse = res
+            .response
+            .parse_struct("AdyenDefenseReasonsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::foreign_try_from((data, response))
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 impl
     services::ConnectorIntegration<
         api::Evidence,
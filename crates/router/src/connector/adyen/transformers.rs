@@ -4885,7 +4885,7 @@ pub struct AdyenAcceptDisputeRequest {
     merchant_account_code: Secret<String>,
 }
 
-#[derive(Default, Debug, Deserialize)]
+#[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdyenDisputeResponse {
     pub error_message: Option<String>,
@@ -5160,3 +5160,237 @@ impl ForeignTryFrom<(&Self, AdyenDisputeResponse)> for types::DefendDisputeRoute
         }
     }
 }
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenDisputeSyncRequest {
+    dispute_psp_reference: String,
+    merchant_account_code: Secret<String>,
+}
+
+impl TryFrom<&types::DisputeSyncRouterData> for AdyenDisputeSyncRequest {
+    type Error = Error;
+    fn try_from(item: &types::DisputeSyncRouterData) -> Result<Self, Self::Error> {
+        let merchant_account_code = get_merchant_account_code(&item.connector_auth_type)?;
+        Ok(Self {
+            dispute_psp_reference: item.request.connector_dispute_id.clone(),
+            merchant_account_code,
+        })
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenDefenseReason {
+    pub defense_reason_code: String,
+    #[serde(default)]
+    pub satisfied: bool,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenDefenseReasonsResponse {
+    pub dispute_service_result: AdyenDisputeResponse,
+    #[serde(default)]
+    pub defense_reasons: Vec<AdyenDefenseReason>,
+}
+
+/// Adyen has no endpoint returning the status of a dispute, the applicable defense reasons are
+/// the closest thing to it: they are returned while the dispute can still be defended, and the
+/// request fails with a message naming the reason once the dispute was accepted, defended or has
+/// expired. Returns `None` when the status can't be told from the response.
+fn get_dispute_sync_status(
+    current_status: api_enums::DisputeStatus,
+    response: &AdyenDefenseReasonsResponse,
+) -> Option<(api_enums::DisputeStatus, String)> {
+    if response.dispute_service_result.success {
+        let is_defended = current_status == api_enums::DisputeStatus::DisputeChallenged
+            || response
+                .defense_reasons
+                .iter()
+                .any(|reason| reason.satisfied);
+        return Some(if is_defended {
+            (
+                api_enums::DisputeStatus::DisputeChallenged,
+                "defended".to_string(),
+            )
+        } else {
+            (
+                api_enums::DisputeStatus::DisputeOpened,
+                "defendable".to_string(),
+            )
+        });
+    }
+
+    let error_message = response
+        .dispute_service_result
+        .error_message
+        .as_deref()?
+        .to_lowercase();
+    if error_message.contains("accepted") {
+        Some((
+            api_enums::DisputeStatus::DisputeAccepted,
+            "accepted".to_string(),
+        ))
+    } else if error_message.contains("defended") {
+        Some((
+            api_enums::DisputeStatus::DisputeChallenged,
+            "defended".to_string(),
+        ))
+    } else if ["not defendable", "defense period", "expired"]
+        .iter()
+        .any(|message| error_message.contains(message))
+    {
+        // A dispute that was defended in time waits for the decision of the issuer
+        Some(
+            if current_status == api_enums::DisputeStatus::DisputeChallenged {
+                (
+                    api_enums::DisputeStatus::DisputeChallenged,
+                    "defended".to_string(),
+                )
+            } else {
+                (
+                    api_enums::DisputeStatus::DisputeExpired,
+                    "expired".to_string(),
+                )
+            },
+        )
+    } else {
+        None
+    }
+}
+
+impl ForeignTryFrom<(&Self, AdyenDefenseReasonsResponse)> for types::DisputeSyncRouterData {
+    type Error = errors::ConnectorError;
+
+    fn foreign_try_from(item: (&Self, AdyenDefenseReasonsResponse)) -> Result<Self, Self::Error> {
+        let (data, response) = item;
+
+        match get_dispute_sync_status(data.request.dispute_status, &response) {
+            Some((dispute_status, connector_status)) => Ok(types::DisputeSyncRouterData {
+                response: Ok(types::DisputeSyncResponse {
+                    dispute_stage: data.request.dispute_stage,
+                    dispute_status,
+                    connector_status,
+                    connector_reason: None,
+                    connector_reason_code: None,
+                    // Kept from the webhooks, the defense reasons don't carry the deadline
+                    challenge_required_by: None,
+                    updated_at: None,
+                }),
+                ..data.clone()
+            }),
+            None => {
+                let error_message = response.dispute_service_result.error_message;
+                Ok(types::DisputeSyncRouterData {
+                    response: Err(types::ErrorResponse {
+                        code: error_message
+                            .clone()
+                            .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                        message: error_message
+                            .clone()
+                            .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                        reason: error_message,
+                        status_code: data.connector_http_status_code.ok_or(
+                            errors::ConnectorError::MissingRequiredField {
+                                field_name: "http code",
+                            },
+                        )?,
+                        attempt_status: None,
+                        connector_transaction_id: None,
+                    }),
+                    ..data.clone()
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod dispute_sync_tests {
+    use super::*;
+
+    fn defense_reasons_response(
+        success: bool,
+        error_message: Option<&str>,
+        satisfied: bool,
+    ) -> AdyenDefenseReasonsResponse {
+        AdyenDefenseReasonsResponse {
+            dispute_service_result: AdyenDisputeResponse {
+                error_message: error_message.map(str::to_string),
+                success,
+            },
+            defense_reasons: vec![AdyenDefenseReason {
+                defense_reason_code: "SupplyDefenseMaterial".to_string(),
+                satisfied,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_defendable_dispute_stays_open() {
+        let response = defense_reasons_response(true, None, false);
+        assert_eq!(
+            get_dispute_sync_status(api_enums::DisputeStatus::DisputeOpened, &response)
+                .map(|(status, _)| status),
+            Some(api_enums::DisputeStatus::DisputeOpened)
+        );
+    }
+
+    #[test]
+    fn test_satisfied_defense_reason_challenges_the_dispute() {
+        let response = defense_reasons_response(true, None, true);
+        assert_eq!(
+            get_dispute_sync_status(api_enums::DisputeStatus::DisputeOpened, &response)
+                .map(|(status, _)| status),
+            Some(api_enums::DisputeStatus::DisputeChallenged)
+        );
+    }
+
+    #[test]
+    fn test_closed_dispute_statuses_are_told_from_the_error_message() {
+        let cases = [
+            (
+                "Dispute has already been accepted",
+                api_enums::DisputeStatus::DisputeOpened,
+                api_enums::DisputeStatus::DisputeAccepted,
+            ),
+            (
+                "Dispute has already been defended",
+                api_enums::DisputeStatus::DisputeOpened,
+                api_enums::DisputeStatus::DisputeChallenged,
+            ),
+            (
+                "Defense period has expired",
+                api_enums::DisputeStatus::DisputeOpened,
+                api_enums::DisputeStatus::DisputeExpired,
+            ),
+            (
+                "Defense period has expired",
+                api_enums::DisputeStatus::DisputeChallenged,
+                api_enums::DisputeStatus::DisputeChallenged,
+            ),
+        ];
+        for (error_message, current_status, expected_status) in cases {
+            let response = defense_reasons_response(false, Some(error_message), false);
+            assert_eq!(
+                get_dispute_sync_status(current_status, &response).map(|(status, _)| status),
+                Some(expected_status),
+                "{error_message}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_errors_are_not_mapped_to_a_status() {
+        let response = defense_reasons_response(false, Some("Invalid merchant account"), false);
+        assert!(
+            get_dispute_sync_status(api_enums::DisputeStatus::DisputeOpened, &response).is_none()
+        );
+
+        let response = defense_reasons_response(false, None, false);
+        assert!(
+            get_dispute_sync_status(api_enums::DisputeStatus::DisputeOpened, &response).is_none()
+        );
+    }
+}
@@ -2138,6 +2138,130 @@ impl
     }
 }
 
+impl api::SyncDispute for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::DisputeSync,
+        types::DisputeSyncData,
+        types::DisputeSyncResponse,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::DisputeSyncRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::DisputeSyncType::get_content_type(self)
+                .to_string()
+                .into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        req: &types::DisputeSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}{}",
+            self.base_url(connectors),
+            "v1/disputes/",
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::DisputeSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&types::DisputeSyncType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::DisputeSyncType::get_headers(self, req, connectors)?)
+            .build();
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::DisputeSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::DisputeSyncRouterData, errors::ConnectorError> {
+        let response: stripe::StripeDisputeResponse = res
+            .response
+            .parse_struct("Stripe StripeDisputeResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        Ok(types::DisputeSyncRouterData {
+            response: Ok(types::DisputeSyncResponse {
+                dispute_stage: api_models::enums::DisputeStage::Dispute,
+                dispute_status: api_models::enums::DisputeStatus::from(response.status.clone()),
+                connector_status: response.status.to_string(),
+                connector_reason: response.reason,
+                connector_reason_code: None,
+                challenge_required_by: response
+                    .evidence_details
+                    .and_then(|evidence_details| evidence_details.due_by),
+                updated_at: None,
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_error_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: response.error.message.map(|message| {
+                response
+                    .error
+                    .decline_code
+                    .map(|decline_code| {
+                        format!("message - {}, decline_code - {}", message, decline_code)
+                    })
+                    .unwrap_or(message)
+            }),
+            attempt_status: None,
+            connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+        })
+    }
+}
+
 fn get_signature_elements_from_header(
     headers: &actix_web::http::header::HeaderMap,
 ) -> CustomResult<HashMap<String, Vec<u8>>, errors::ConnectorError> {
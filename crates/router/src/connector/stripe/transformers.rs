@@ -3962,6 +3962,49 @@ pub struct DisputeObj {
     pub status: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StripeDisputeStatus {
+    WarningNeedsResponse,
+    WarningUnderReview,
+    WarningClosed,
+    NeedsResponse,
+    UnderReview,
+    Won,
+    Lost,
+}
+
+impl From<StripeDisputeStatus> for api_models::enums::DisputeStatus {
+    fn from(status: StripeDisputeStatus) -> Self {
+        match status {
+            StripeDisputeStatus::WarningNeedsResponse | StripeDisputeStatus::NeedsResponse => {
+                Self::DisputeOpened
+            }
+            StripeDisputeStatus::WarningUnderReview | StripeDisputeStatus::UnderReview => {
+                Self::DisputeChallenged
+            }
+            StripeDisputeStatus::WarningClosed => Self::DisputeCancelled,
+            StripeDisputeStatus::Won => Self::DisputeWon,
+            StripeDisputeStatus::Lost => Self::DisputeLost,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StripeDisputeResponse {
+    pub id: String,
+    pub status: StripeDisputeStatus,
+    pub reason: Option<String>,
+    pub evidence_details: Option<StripeDisputeEvidenceDetails>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StripeDisputeEvidenceDetails {
+    #[serde(default, with = "common_utils::custom_serde::timestamp::option")]
+    pub due_by: Option<PrimitiveDateTime>,
+}
+
 fn get_transaction_metadata(
     merchant_metadata: Option<Secret<Value>>,
     order_id: String,
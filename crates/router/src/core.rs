@@ -23,6 +23,7 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod idempotency;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
@@ -36,11 +37,16 @@ pub mod payout_link;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+pub mod rate_limiter;
 #[cfg(feature = "recon")]
 pub mod recon;
 #[cfg(feature = "v1")]
 pub mod refunds;
 pub mod routing;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod storage_scheme_migration;
+#[cfg(feature = "v1")]
+pub mod subscriptions;
 pub mod surcharge_decision_config;
 #[cfg(feature = "olap")]
 pub mod user;
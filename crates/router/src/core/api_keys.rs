@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use common_utils::date_time;
 #[cfg(feature = "email")]
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
@@ -11,7 +13,7 @@ use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
     db::domain,
     routes::{metrics, SessionState},
-    services::{authentication, ApplicationResponse},
+    services::{authentication, authorization::permissions::Permission, ApplicationResponse},
     types::{api, storage, transformers::ForeignInto},
     utils,
 };
@@ -120,6 +122,29 @@ pub async fn create_api_key(
 
     let merchant_id = key_store.merchant_id.clone();
 
+    if api_key
+        .permissions
+        .as_ref()
+        .is_some_and(|permissions| permissions.is_empty())
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`permissions` must contain at least one permission if specified".to_string(),
+        }));
+    }
+    if let Some(profile_id) = api_key.profile_id.as_ref() {
+        store
+            .find_business_profile_by_merchant_id_profile_id(
+                &(&state).into(),
+                &key_store,
+                &merchant_id,
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+    }
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -132,6 +157,11 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        permissions: api_key.permissions.map(get_permissions_to_store),
+        profile_id: api_key.profile_id,
+        ip_allowlist: api_key
+            .ip_allowlist
+            .map(|ip_allowlist| ip_allowlist.iter().map(ToString::to_string).collect()),
     };
 
     let api_key = store
@@ -534,6 +564,32 @@ pub async fn list_api_keys(
     Ok(ApplicationResponse::Json(api_keys))
 }
 
+/// Converts the permissions an API key is restricted to into their stored representation.
+pub(crate) fn get_permissions_to_store(
+    permissions: Vec<api_models::user_role::Permission>,
+) -> Vec<String> {
+    permissions
+        .into_iter()
+        .map(|permission| Permission::from(permission).to_string())
+        .collect()
+}
+
+/// Parses the permissions stored for an API key.
+pub(crate) fn parse_stored_permissions(
+    permissions: &[String],
+) -> errors::RouterResult<Vec<Permission>> {
+    permissions
+        .iter()
+        .map(|permission| {
+            Permission::from_str(permission)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!("Invalid permission `{permission}` stored for API key")
+                })
+        })
+        .collect()
+}
+
 #[cfg(feature = "email")]
 fn generate_task_id_for_api_key_expiry_workflow(key_id: &str) -> String {
     format!("{API_KEY_EXPIRY_RUNNER}_{API_KEY_EXPIRY_NAME}_{key_id}")
@@ -1,9 +1,11 @@
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, ext_traits::StringExt};
+use currency_conversion::types::MarkupConfig;
 use error_stack::ResultExt;
 
 use crate::{
-    core::errors::ApiErrorResponse,
+    core::errors::{ApiErrorResponse, StorageErrorExt},
     services::ApplicationResponse,
+    types::domain,
     utils::currency::{self, convert_currency, get_forex_rates},
     SessionState,
 };
@@ -26,23 +28,85 @@ pub async fn retrieve_forex(
     ))
 }
 
+pub async fn retrieve_forex_snapshot(
+    state: SessionState,
+    snapshot_id: String,
+) -> CustomResult<
+    ApplicationResponse<api_models::currency::ForexRateSnapshotResponse>,
+    ApiErrorResponse,
+> {
+    let fx_rate_snapshot = state
+        .store
+        .find_fx_rate_snapshot_by_snapshot_id(&snapshot_id)
+        .await
+        .to_not_found_response(ApiErrorResponse::GenericNotFoundError {
+            message: "Forex rate snapshot not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        api_models::currency::ForexRateSnapshotResponse {
+            snapshot_id: fx_rate_snapshot.snapshot_id,
+            provider: fx_rate_snapshot.provider,
+            base_currency: fx_rate_snapshot.base_currency,
+            rates: fx_rate_snapshot.rates,
+            created_at: fx_rate_snapshot.created_at,
+        },
+    ))
+}
+
 pub async fn convert_forex(
     state: SessionState,
+    merchant_account: domain::MerchantAccount,
     amount: i64,
     to_currency: String,
     from_currency: String,
+    snapshot_id: Option<String>,
 ) -> CustomResult<
     ApplicationResponse<api_models::currency::CurrencyConversionResponse>,
     ApiErrorResponse,
 > {
-    Ok(ApplicationResponse::Json(
-        Box::pin(convert_currency(
-            state.clone(),
-            amount,
-            to_currency,
-            from_currency,
-        ))
+    let rates = match snapshot_id {
+        Some(snapshot_id) => {
+            let fx_rate_snapshot = state
+                .store
+                .find_fx_rate_snapshot_by_snapshot_id(&snapshot_id)
+                .await
+                .to_not_found_response(ApiErrorResponse::GenericNotFoundError {
+                    message: "Forex rate snapshot not found".to_string(),
+                })?;
+            currency::FxExchangeRatesCacheEntry::try_from(fx_rate_snapshot)
+                .change_context(ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse forex rate snapshot")?
+        }
+        None => {
+            let forex_api = state.conf.forex_api.get_inner();
+            get_forex_rates(
+                &state,
+                forex_api.call_delay,
+                forex_api.local_fetch_retry_delay,
+                forex_api.local_fetch_retry_count,
+            )
+            .await
+            .change_context(ApiErrorResponse::InternalServerError)?
+        }
+    };
+
+    let markup_config: MarkupConfig = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &currency::get_fx_markup_config_key(merchant_account.get_id()),
+            Some("{}".to_string()),
+        )
         .await
-        .change_context(ApiErrorResponse::InternalServerError)?,
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch forex markup config")?
+        .config
+        .parse_struct("MarkupConfig")
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid forex markup config")?;
+
+    Ok(ApplicationResponse::Json(
+        convert_currency(&rates, &markup_config, amount, to_currency, from_currency)
+            .change_context(ApiErrorResponse::InternalServerError)?,
     ))
 }
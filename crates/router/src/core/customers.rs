@@ -806,6 +806,7 @@ impl CustomerDeleteBridge for customers::CustomerId {
                         key_store,
                         merchant_account.get_id(),
                         &pm.payment_method_id,
+                        merchant_account.storage_scheme,
                     )
                     .await
                     .switch()?;
@@ -1,11 +1,12 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
 use api_models::{
     admin::MerchantConnectorInfo, disputes as dispute_models, files as files_api_models,
 };
 use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::process_tracker::business_status;
 use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 use strum::IntoEnumIterator;
 pub mod transformers;
 
@@ -14,19 +15,23 @@ use super::{
     metrics,
 };
 use crate::{
-    core::{files, payments, utils as core_utils},
+    core::{files, payments, utils as core_utils, webhooks},
+    db::StorageInterface,
     routes::SessionState,
     services,
     types::{
         api::{self, disputes},
         domain,
-        storage::enums as storage_enums,
-        transformers::ForeignFrom,
+        storage::{self, enums as storage_enums},
+        transformers::{ForeignFrom, ForeignInto},
         AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
-        DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
+        DefendDisputeResponse, DisputeSyncData, DisputeSyncResponse, SubmitEvidenceRequestData,
+        SubmitEvidenceResponse,
     },
 };
 
+pub const DISPUTE_SYNC_TASK: &str = "DISPUTE_SYNC";
+
 #[instrument(skip(state))]
 pub async fn retrieve_dispute(
     state: SessionState,
@@ -239,6 +244,316 @@ pub async fn accept_dispute(
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
 
+#[cfg(feature = "v2")]
+#[instrument(skip(state))]
+pub async fn sync_dispute(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: disputes::DisputeId,
+) -> RouterResponse<dispute_models::DisputeResponse> {
+    todo!()
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip(state))]
+pub async fn sync_dispute(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: disputes::DisputeId,
+) -> RouterResponse<dispute_models::DisputeResponse> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &req.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id,
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+    let updated_dispute =
+        sync_dispute_with_connector(&state, merchant_account, &key_store, dispute).await?;
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
+    Ok(services::ApplicationResponse::Json(dispute_response))
+}
+
+/// Retrieves the latest state of the dispute from the connector and updates the dispute with it.
+/// The merchant is notified with an outgoing webhook if the stage or status of the dispute has
+/// changed.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn sync_dispute_with_connector(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: storage::Dispute,
+) -> errors::RouterResult<storage::Dispute> {
+    let db = &state.store;
+    let dispute_id = dispute.dispute_id.clone();
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+        dispute.merchant_connector_id.clone(),
+    )?;
+    common_utils::fp_utils::when(
+        !connector_data.connector_name.supports_dispute_sync(),
+        || {
+            Err(errors::ApiErrorResponse::FlowNotSupported {
+                flow: "Dispute sync".to_string(),
+                connector: dispute.connector.clone(),
+            })
+        },
+    )?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &dispute.payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            merchant_account.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let connector_integration: services::BoxedDisputeConnectorIntegrationInterface<
+        api::DisputeSync,
+        DisputeSyncData,
+        DisputeSyncResponse,
+    > = connector_data.connector.get_connector_integration();
+    let router_data = core_utils::construct_dispute_sync_router_data(
+        state,
+        &payment_intent,
+        &payment_attempt,
+        &merchant_account,
+        key_store,
+        &dispute,
+    )
+    .await?;
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .to_dispute_failed_response()
+    .attach_printable("Failed while calling dispute sync connector api")?;
+    let dispute_sync_response =
+        response
+            .response
+            .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: dispute.connector.clone(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })?;
+
+    let is_status_changed = dispute.dispute_stage != dispute_sync_response.dispute_stage
+        || dispute.dispute_status != dispute_sync_response.dispute_status;
+    if is_status_changed {
+        core_utils::validate_dispute_stage_and_dispute_status(
+            dispute.dispute_stage,
+            dispute.dispute_status,
+            dispute_sync_response.dispute_stage,
+            dispute_sync_response.dispute_status,
+        )
+        .change_context(errors::ApiErrorResponse::DisputeStatusValidationFailed {
+            reason: format!(
+                "The dispute cannot move from {} stage and {} status to {} stage and {} status",
+                dispute.dispute_stage,
+                dispute.dispute_status,
+                dispute_sync_response.dispute_stage,
+                dispute_sync_response.dispute_status
+            ),
+        })?;
+    }
+
+    let update_dispute = diesel_models::dispute::DisputeUpdate::Update {
+        dispute_stage: dispute_sync_response.dispute_stage,
+        dispute_status: dispute_sync_response.dispute_status,
+        connector_status: dispute_sync_response.connector_status,
+        connector_reason: dispute_sync_response
+            .connector_reason
+            .or(dispute.connector_reason.clone()),
+        connector_reason_code: dispute_sync_response
+            .connector_reason_code
+            .or(dispute.connector_reason_code.clone()),
+        challenge_required_by: dispute_sync_response
+            .challenge_required_by
+            .or(dispute.challenge_required_by),
+        connector_updated_at: dispute_sync_response
+            .updated_at
+            .or(dispute.connector_updated_at),
+    };
+    let updated_dispute = db
+        .update_dispute(dispute, update_dispute)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute_id.to_owned(),
+        })
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+
+    if is_status_changed {
+        trigger_dispute_webhook(state, merchant_account, key_store, &updated_dispute).await?;
+    }
+
+    Ok(updated_dispute)
+}
+
+#[cfg(feature = "v1")]
+async fn trigger_dispute_webhook(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+) -> errors::RouterResult<()> {
+    let Some(profile_id) = dispute.profile_id.as_ref() else {
+        logger::warn!(
+            dispute_id = %dispute.dispute_id,
+            "Outgoing webhook not sent as the dispute is not associated with a business profile"
+        );
+        return Ok(());
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+    let dispute_response = Box::new(dispute.clone().foreign_into());
+    let event_type: storage_enums::EventType = dispute.dispute_status.foreign_into();
+
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        key_store,
+        event_type,
+        storage_enums::EventClass::Disputes,
+        dispute.dispute_id.clone(),
+        storage_enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(dispute_response),
+        Some(dispute.created_at),
+    ))
+    .await
+}
+
+/// Whether the dispute can still change, either through the actions of the merchant or through
+/// the review of the card network.
+pub fn is_dispute_open(dispute_status: storage_enums::DisputeStatus) -> bool {
+    matches!(
+        dispute_status,
+        storage_enums::DisputeStatus::DisputeOpened
+            | storage_enums::DisputeStatus::DisputeChallenged
+    )
+}
+
+/// Whether the dispute should be periodically synced with the connector.
+///
+/// Disputes are only synced while they are open, and only for connectors which expose the status
+/// of a dispute through their API. The other connectors notify about dispute updates through
+/// webhooks only.
+pub fn should_sync_dispute(connector: &str, dispute_status: storage_enums::DisputeStatus) -> bool {
+    is_dispute_open(dispute_status)
+        && api_models::enums::Connector::from_str(connector)
+            .is_ok_and(|connector| connector.supports_dispute_sync())
+}
+
+/// Adds a task to periodically sync the dispute with the connector until the dispute is closed.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn add_dispute_sync_task_to_process_tracker(
+    db: &dyn StorageInterface,
+    dispute: &storage::Dispute,
+) -> errors::RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::DisputeSyncWorkflow;
+    let tag = ["DISPUTES"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        DISPUTE_SYNC_TASK,
+        &dispute.dispute_id,
+        &dispute.merchant_id,
+    );
+    let tracking_data = storage::DisputeSyncTrackingData {
+        dispute_id: dispute.dispute_id.clone(),
+        merchant_id: dispute.merchant_id.clone(),
+    };
+    let schedule_time =
+        crate::workflows::dispute_sync::get_dispute_sync_schedule_time(db, &dispute.merchant_id, 0)
+            .await
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to compute the schedule time of the dispute sync task")?;
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        DISPUTE_SYNC_TASK,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct dispute sync process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting dispute sync task in process_tracker")?;
+
+    Ok(())
+}
+
+/// Reschedules the dispute sync task, or finishes it once the dispute has been closed or the
+/// maximum number of syncs has been reached.
+#[cfg(feature = "v1")]
+pub async fn update_dispute_sync_task(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    dispute: &storage::Dispute,
+) -> errors::CustomResult<(), errors::StorageError> {
+    if !should_sync_dispute(&dispute.connector, dispute.dispute_status) {
+        return db
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await;
+    }
+
+    let schedule_time = crate::workflows::dispute_sync::get_dispute_sync_schedule_time(
+        db,
+        &dispute.merchant_id,
+        process.retry_count + 1,
+    )
+    .await;
+    match schedule_time {
+        Some(schedule_time) => {
+            db.as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+        }
+        None => {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                .await
+        }
+    }
+}
+
 #[cfg(feature = "v2")]
 #[instrument(skip(state))]
 pub async fn submit_evidence(
@@ -594,3 +909,48 @@ pub async fn get_aggregates_for_disputes(
         },
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_sync_dispute() {
+        assert!(should_sync_dispute(
+            "stripe",
+            storage_enums::DisputeStatus::DisputeOpened
+        ));
+        assert!(should_sync_dispute(
+            "stripe",
+            storage_enums::DisputeStatus::DisputeChallenged
+        ));
+        assert!(!should_sync_dispute(
+            "stripe",
+            storage_enums::DisputeStatus::DisputeWon
+        ));
+        assert!(!should_sync_dispute(
+            "stripe",
+            storage_enums::DisputeStatus::DisputeExpired
+        ));
+        assert!(should_sync_dispute(
+            "adyen",
+            storage_enums::DisputeStatus::DisputeOpened
+        ));
+        assert!(!should_sync_dispute(
+            "adyen",
+            storage_enums::DisputeStatus::DisputeLost
+        ));
+    }
+
+    #[test]
+    fn test_should_not_sync_disputes_of_webhook_only_connectors() {
+        assert!(!should_sync_dispute(
+            "checkout",
+            storage_enums::DisputeStatus::DisputeChallenged
+        ));
+        assert!(!should_sync_dispute(
+            "unknown_connector",
+            storage_enums::DisputeStatus::DisputeOpened
+        ));
+    }
+}
@@ -196,6 +196,10 @@ pub enum WebhooksFlowError {
     OutgoingWebhookRetrySchedulingFailed,
     #[error("Outgoing webhook response encoding failed")]
     OutgoingWebhookResponseEncodingFailed,
+    #[error("Webhook endpoint not found")]
+    WebhookEndpointNotFound,
+    #[error("Webhook endpoint is disabled")]
+    WebhookEndpointDisabled,
 }
 
 impl WebhooksFlowError {
@@ -204,7 +208,9 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
-            | Self::OutgoingWebhookResponseEncodingFailed => false,
+            | Self::OutgoingWebhookResponseEncodingFailed
+            | Self::WebhookEndpointNotFound
+            | Self::WebhookEndpointDisabled => false,
 
             Self::WebhookEventUpdationFailed
             | Self::OutgoingWebhookSigningFailed
@@ -318,6 +324,16 @@ pub enum RoutingError {
     VolumeSplitFailed,
     #[error("Unable to parse metadata")]
     MetadataParsingError,
+    #[error("Unable to retrieve the least cost routing config")]
+    LeastCostConfigRetrievalFailed,
+    #[error("Failed to fetch the connector fee schedules of the profile")]
+    FeeScheduleFetchFailed,
+    #[error("Unable to retrieve the elimination routing config")]
+    EliminationConfigRetrievalFailed,
+    #[error("Failed to fetch the elimination state of the connectors")]
+    EliminationStateFetchFailed,
+    #[error("Failed to update the elimination state of the connectors")]
+    EliminationStateUpdateFailed,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
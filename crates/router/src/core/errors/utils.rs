@@ -518,7 +518,7 @@ pub trait RedisErrorExt {
 impl RedisErrorExt for error_stack::Report<errors::RedisError> {
     fn to_redis_failed_response(self, key: &str) -> error_stack::Report<errors::StorageError> {
         match self.current_context() {
-            errors::RedisError::NotFound => self.change_context(
+            errors::RedisError::NotFound | errors::RedisError::ValueDeleted => self.change_context(
                 errors::StorageError::ValueNotFound(format!("Data does not exist for key {key}",)),
             ),
             errors::RedisError::SetNxFailed => {
@@ -804,6 +804,7 @@ pub async fn frm_fulfillment_core(
                 .find_fraud_check_by_payment_id_if_present(
                     req.payment_id.clone(),
                     merchant_account.get_id().clone(),
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(invalid_request_error.to_owned())?;
@@ -894,7 +895,11 @@ pub async fn make_fulfillment_api_call(
         payment_capture_method: fraud_check.payment_capture_method,
     };
     let _updated = db
-        .update_fraud_check_response_with_attempt_id(fraud_check_copy, fraud_check_update)
+        .update_fraud_check_response_with_attempt_id(
+            fraud_check_copy,
+            fraud_check_update,
+            merchant_account.storage_scheme,
+        )
         .await
         .map_err(|error| error.change_context(errors::ApiErrorResponse::PaymentNotFound))?;
     let fulfillment_response =
@@ -0,0 +1,306 @@
+use std::future::Future;
+
+use actix_web::http::header::HeaderMap;
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    events::{ApiEventMetric, ApiEventsType},
+};
+use error_stack::{report, ResultExt};
+use redis_interface::errors::RedisError;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    api_locking,
+    errors::{self, RouterResponse, RouterResult},
+    metrics,
+};
+use crate::{
+    headers,
+    routes::{lock_utils, SessionState},
+    services::{authentication::get_header_value_by_key, ApplicationResponse},
+};
+
+pub const IDEMPOTENCY_KEY_PREFIX: &str = "IDEMPOTENCY";
+
+/// Response header set on responses that were replayed from a previously stored response
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "Idempotent-Replayed";
+
+/// Maximum length allowed for a merchant provided idempotency key
+const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+#[derive(Clone, Debug)]
+pub struct IdempotencyInput {
+    pub idempotency_key: String,
+    pub request_fingerprint: String,
+    pub api_identifier: lock_utils::ApiIdentifier,
+}
+
+impl IdempotencyInput {
+    /// Builds the idempotency input from the `Idempotency-Key` header, if present.
+    ///
+    /// The fingerprint has to be computed on the request exactly as it was received from the
+    /// merchant, before any server generated identifiers are populated on it.
+    pub fn from_headers<F, T>(
+        headers: &HeaderMap,
+        flow: F,
+        request: &T,
+    ) -> RouterResult<Option<Self>>
+    where
+        T: Serialize,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        get_header_value_by_key(headers::IDEMPOTENCY_KEY.into(), headers)?
+            .map(|idempotency_key| {
+                let idempotency_key = idempotency_key.trim();
+
+                if idempotency_key.is_empty() || idempotency_key.len() > MAX_IDEMPOTENCY_KEY_LENGTH
+                {
+                    return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "`{}` header must be between 1 and {MAX_IDEMPOTENCY_KEY_LENGTH} characters",
+                            headers::IDEMPOTENCY_KEY
+                        ),
+                    }));
+                }
+
+                Ok(Self {
+                    idempotency_key: idempotency_key.to_owned(),
+                    request_fingerprint: generate_request_fingerprint(request)?,
+                    api_identifier: lock_utils::ApiIdentifier::from(flow),
+                })
+            })
+            .transpose()
+    }
+
+    fn get_redis_key(&self, merchant_id: &common_utils::id_type::MerchantId) -> String {
+        format!(
+            "{}_{}_{}_{}",
+            IDEMPOTENCY_KEY_PREFIX,
+            merchant_id.get_string_repr(),
+            self.api_identifier,
+            self.idempotency_key
+        )
+    }
+
+    /// Requests sharing an idempotency key are serialized so that an in-flight duplicate waits
+    /// for the first request to complete and then replays its stored response.
+    pub fn get_locking_action(&self) -> api_locking::LockAction {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: format!("{}_{}", IDEMPOTENCY_KEY_PREFIX, self.idempotency_key),
+                api_identifier: self.api_identifier.clone(),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+/// Locking action to be used for an API which honors the `Idempotency-Key` header
+pub fn get_locking_action(
+    idempotency_input: Option<&IdempotencyInput>,
+    default_action: api_locking::LockAction,
+) -> api_locking::LockAction {
+    idempotency_input
+        .map(IdempotencyInput::get_locking_action)
+        .unwrap_or(default_action)
+}
+
+fn generate_request_fingerprint<T: Serialize>(request: &T) -> RouterResult<String> {
+    let serialized_request = serde_json::to_vec(request)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize request for idempotency fingerprint")?;
+
+    Sha256
+        .generate_digest(&serialized_request)
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate idempotency fingerprint of the request")
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredIdempotentResponse {
+    request_fingerprint: String,
+    response: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum IdempotentResponse<T> {
+    /// Response of a request which was processed as part of the current call
+    Processed(T),
+    /// Response which was stored by an earlier request with the same idempotency key
+    Replayed(serde_json::Value),
+}
+
+impl<T: ApiEventMetric> ApiEventMetric for IdempotentResponse<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        match self {
+            Self::Processed(response) => response.get_api_event_type(),
+            Self::Replayed(_) => None,
+        }
+    }
+}
+
+/// Executes `func` at most once per merchant and idempotency key.
+///
+/// A retried request with the same body receives the stored response, while a request reusing
+/// the key with a different body is rejected. Only successful JSON responses are stored, so a
+/// failed request can be retried with the same key.
+#[instrument(skip_all)]
+pub async fn call_idempotently<T, F, Fut>(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    idempotency_input: Option<IdempotencyInput>,
+    func: F,
+) -> RouterResponse<IdempotentResponse<T>>
+where
+    T: Serialize,
+    F: FnOnce(SessionState) -> Fut,
+    Fut: Future<Output = RouterResponse<T>>,
+{
+    let Some(idempotency_input) = idempotency_input else {
+        return func(state).await.map(into_idempotent_response);
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let redis_key = idempotency_input.get_redis_key(&merchant_id);
+
+    match redis_conn
+        .get_and_deserialize_key::<StoredIdempotentResponse>(
+            &redis_key,
+            "StoredIdempotentResponse",
+        )
+        .await
+    {
+        Ok(stored_response)
+            if stored_response.request_fingerprint == idempotency_input.request_fingerprint =>
+        {
+            logger::info!(
+                "Replaying stored response for idempotency key {}",
+                idempotency_input.idempotency_key
+            );
+            metrics::IDEMPOTENT_REQUEST_REPLAYED.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue::new(
+                    "api",
+                    idempotency_input.api_identifier.to_string(),
+                )],
+            );
+            return Ok(ApplicationResponse::JsonWithHeaders((
+                IdempotentResponse::Replayed(stored_response.response),
+                vec![(IDEMPOTENT_REPLAYED_HEADER.to_string(), "true".into())],
+            )));
+        }
+        Ok(_) => {
+            metrics::IDEMPOTENCY_KEY_REUSED.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue::new(
+                    "api",
+                    idempotency_input.api_identifier.to_string(),
+                )],
+            );
+            return Err(report!(errors::ApiErrorResponse::IdempotencyKeyReused {
+                idempotency_key: idempotency_input.idempotency_key,
+            }));
+        }
+        Err(error) if error.current_context() == &RedisError::NotFound => {}
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch stored idempotent response");
+        }
+    }
+
+    let response = func(state.clone()).await?;
+
+    let response_body = match &response {
+        ApplicationResponse::Json(data) | ApplicationResponse::JsonWithHeaders((data, _)) => {
+            Some(
+                serde_json::to_value(data)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize response for idempotency store")?,
+            )
+        }
+        _ => None,
+    };
+
+    if let Some(response_body) = response_body {
+        let stored_response = StoredIdempotentResponse {
+            request_fingerprint: idempotency_input.request_fingerprint,
+            response: response_body,
+        };
+
+        // The request has already been processed at this point, failing to persist the response
+        // should not fail the request itself
+        redis_conn
+            .serialize_and_set_key_with_expiry(
+                &redis_key,
+                stored_response,
+                state.conf.idempotency.key_ttl_in_seconds,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to store idempotent response in redis");
+            })
+            .ok();
+    }
+
+    Ok(into_idempotent_response(response))
+}
+
+fn into_idempotent_response<T>(
+    response: ApplicationResponse<T>,
+) -> ApplicationResponse<IdempotentResponse<T>> {
+    match response {
+        ApplicationResponse::Json(data) => {
+            ApplicationResponse::Json(IdempotentResponse::Processed(data))
+        }
+        ApplicationResponse::JsonWithHeaders((data, headers)) => {
+            ApplicationResponse::JsonWithHeaders((IdempotentResponse::Processed(data), headers))
+        }
+        ApplicationResponse::StatusOk => ApplicationResponse::StatusOk,
+        ApplicationResponse::TextPlain(text) => ApplicationResponse::TextPlain(text),
+        ApplicationResponse::JsonForRedirection(redirection_response) => {
+            ApplicationResponse::JsonForRedirection(redirection_response)
+        }
+        ApplicationResponse::Form(redirection_data) => ApplicationResponse::Form(redirection_data),
+        ApplicationResponse::PaymentLinkForm(payment_link_action) => {
+            ApplicationResponse::PaymentLinkForm(payment_link_action)
+        }
+        ApplicationResponse::FileData(file_data) => ApplicationResponse::FileData(file_data),
+        ApplicationResponse::GenericLinkForm(generic_link) => {
+            ApplicationResponse::GenericLinkForm(generic_link)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_request_fingerprint_is_stable_and_body_sensitive() {
+        let request = serde_json::json!({"amount": 6540, "currency": "USD"});
+        let same_request = serde_json::json!({"amount": 6540, "currency": "USD"});
+        let different_request = serde_json::json!({"amount": 6541, "currency": "USD"});
+
+        let fingerprint = generate_request_fingerprint(&request).unwrap();
+
+        assert_eq!(
+            fingerprint,
+            generate_request_fingerprint(&same_request).unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            generate_request_fingerprint(&different_request).unwrap()
+        );
+    }
+}
@@ -88,5 +88,8 @@ counter_metric!(DYNAMIC_SUCCESS_BASED_ROUTING, GLOBAL_METER);
 #[cfg(feature = "partial-auth")]
 counter_metric!(PARTIAL_AUTH_FAILURE, GLOBAL_METER);
 
+counter_metric!(IDEMPOTENT_REQUEST_REPLAYED, GLOBAL_METER); // No. of requests answered with a stored idempotent response
+counter_metric!(IDEMPOTENCY_KEY_REUSED, GLOBAL_METER); // No. of requests rejected for reusing an idempotency key with a different body
+
 counter_metric!(API_KEY_REQUEST_INITIATED, GLOBAL_METER);
 counter_metric!(API_KEY_REQUEST_COMPLETED, GLOBAL_METER);
@@ -1308,6 +1308,7 @@ pub async fn add_payment_method(
                             key_store,
                             merchant_id,
                             &resp.payment_method_id,
+                            merchant_account.storage_scheme,
                         )
                         .await
                         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
@@ -5488,6 +5489,7 @@ pub async fn delete_payment_method(
         &key_store,
         merchant_account.get_id(),
         pm_id.payment_method_id.as_str(),
+        merchant_account.storage_scheme,
     )
     .await
     .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
@@ -182,8 +182,14 @@ where
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
         .attach_printable("Failed while fetching/creating customer")?;
 
-    let authentication_type =
-        call_decision_manager(state, &merchant_account, &business_profile, &payment_data).await?;
+    let authentication_type = call_decision_manager(
+        state,
+        &merchant_account,
+        &key_store,
+        &business_profile,
+        &payment_data,
+    )
+    .await?;
 
     payment_data.set_authentication_type_in_attempt(authentication_type);
 
@@ -672,7 +678,8 @@ where
 pub async fn call_decision_manager<F, D>(
     state: &SessionState,
     merchant_account: &domain::MerchantAccount,
-    _business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
     payment_data: &D,
 ) -> RouterResult<Option<enums::AuthenticationType>>
 where
@@ -681,6 +688,15 @@ where
 {
     let setup_mandate = payment_data.get_setup_mandate();
     let payment_method_data = payment_data.get_payment_method_data();
+    let additional_inputs = core_routing::inputs::get_additional_routing_inputs(
+        state,
+        merchant_account,
+        key_store,
+        business_profile.get_id(),
+        payment_data.get_payment_intent(),
+        payment_data.get_currency(),
+    )
+    .await;
     let payment_dsl_data = core_routing::PaymentsDslInput::new(
         setup_mandate,
         payment_data.get_payment_attempt(),
@@ -689,6 +705,7 @@ where
         payment_data.get_address(),
         payment_data.get_recurring_details(),
         payment_data.get_currency(),
+        additional_inputs,
     );
     let algorithm_ref: api::routing::RoutingAlgorithmRef = merchant_account
         .routing_algorithm
@@ -3811,6 +3828,15 @@ where
         .attach_printable("Failed execution of straight through routing")?;
 
         if check_eligibility {
+            let additional_inputs = core_routing::inputs::get_additional_routing_inputs(
+                &state,
+                merchant_account,
+                key_store,
+                business_profile.get_id(),
+                payment_data.get_payment_intent(),
+                payment_data.get_currency(),
+            )
+            .await;
             let transaction_data = core_routing::PaymentsDslInput::new(
                 payment_data.get_setup_mandate(),
                 payment_data.get_payment_attempt(),
@@ -3819,6 +3845,7 @@ where
                 payment_data.get_address(),
                 payment_data.get_recurring_details(),
                 payment_data.get_currency(),
+                additional_inputs,
             );
 
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -3869,6 +3896,15 @@ where
         .attach_printable("Failed execution of straight through routing")?;
 
         if check_eligibility {
+            let additional_inputs = core_routing::inputs::get_additional_routing_inputs(
+                &state,
+                merchant_account,
+                key_store,
+                business_profile.get_id(),
+                payment_data.get_payment_intent(),
+                payment_data.get_currency(),
+            )
+            .await;
             let transaction_data = core_routing::PaymentsDslInput::new(
                 payment_data.get_setup_mandate(),
                 payment_data.get_payment_attempt(),
@@ -3877,6 +3913,7 @@ where
                 payment_data.get_address(),
                 payment_data.get_recurring_details(),
                 payment_data.get_currency(),
+                additional_inputs,
             );
 
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -3918,6 +3955,15 @@ where
         .await;
     }
 
+    let additional_inputs = core_routing::inputs::get_additional_routing_inputs(
+        &state,
+        merchant_account,
+        key_store,
+        business_profile.get_id(),
+        payment_data.get_payment_intent(),
+        payment_data.get_currency(),
+    )
+    .await;
     let new_pd = payment_data.clone();
     let transaction_data = core_routing::PaymentsDslInput::new(
         new_pd.get_setup_mandate(),
@@ -3927,6 +3973,7 @@ where
         new_pd.get_address(),
         new_pd.get_recurring_details(),
         new_pd.get_currency(),
+        additional_inputs,
     );
 
     route_connector_v1_for_payments(
@@ -4573,7 +4620,7 @@ where
         &state.clone(),
         key_store,
         connectors,
-        &TransactionData::Payment(transaction_data),
+        &TransactionData::Payment(transaction_data.clone()),
         eligible_connectors,
         business_profile,
     )
@@ -4581,6 +4628,18 @@ where
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    let connectors =
+        routing::perform_least_cost_routing(state, connectors, &transaction_data, business_profile)
+            .await;
+
+    let connectors = routing::perform_elimination_routing(
+        state,
+        connectors,
+        &transaction_data,
+        business_profile,
+    )
+    .await;
+
     let connector_data = connectors
         .into_iter()
         .map(|conn| {
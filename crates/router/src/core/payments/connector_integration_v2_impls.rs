@@ -285,6 +285,18 @@ mod dummy_connector_default_impl {
     {
     }
 
+    impl<const T: u8> api::SyncDisputeV2 for connector::DummyConnector<T> {}
+
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::DisputeSync,
+            types::DisputesFlowData,
+            types::DisputeSyncData,
+            types::DisputeSyncResponse,
+        > for connector::DummyConnector<T>
+    {
+    }
+
     impl<const T: u8> api::FileUploadV2 for connector::DummyConnector<T> {}
 
     impl<const T: u8> api::UploadFileV2 for connector::DummyConnector<T> {}
@@ -1035,6 +1047,79 @@ default_imp_for_new_connector_integration_defend_dispute!(
     connector::Zsl,
     connector::Plaid
 );
+
+macro_rules! default_imp_for_new_connector_integration_dispute_sync {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::SyncDisputeV2 for $path::$connector {}
+            impl
+                services::ConnectorIntegrationV2<
+                api::DisputeSync,
+                types::DisputesFlowData,
+                types::DisputeSyncData,
+                types::DisputeSyncResponse,
+            > for $path::$connector
+            {}
+        )*
+    };
+}
+default_imp_for_new_connector_integration_dispute_sync!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Adyenplatform,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bamboraapac,
+    connector::Bankofamerica,
+    connector::Billwerk,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Dlocal,
+    connector::Ebanx,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Gocardless,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Multisafepay,
+    connector::Netcetera,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payeezy,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Payu,
+    connector::Placetopay,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Razorpay,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Square,
+    connector::Stripe,
+    connector::Shift4,
+    connector::Trustpay,
+    connector::Threedsecureio,
+    connector::Wellsfargo,
+    connector::Wise,
+    connector::Worldpay,
+    connector::Zen,
+    connector::Zsl,
+    connector::Plaid
+);
 default_imp_for_new_connector_integration_submit_evidence!(
     connector::Aci,
     connector::Adyen,
@@ -934,6 +934,90 @@ default_imp_for_defend_dispute!(
     connector::Zsl
 );
 
+macro_rules! default_imp_for_dispute_sync {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::SyncDispute for $path::$connector {}
+            impl
+                services::ConnectorIntegration<
+                api::DisputeSync,
+                types::DisputeSyncData,
+                types::DisputeSyncResponse,
+            > for $path::$connector
+            {}
+        )*
+    };
+}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::SyncDispute for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::DisputeSync,
+        types::DisputeSyncData,
+        types::DisputeSyncResponse,
+    > for connector::DummyConnector<T>
+{
+}
+
+default_imp_for_dispute_sync!(
+    connector::Adyenplatform,
+    connector::Aci,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bamboraapac,
+    connector::Bankofamerica,
+    connector::Billwerk,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Dlocal,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Forte,
+    connector::Gocardless,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Multisafepay,
+    connector::Netcetera,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Paybox,
+    connector::Payeezy,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Payu,
+    connector::Placetopay,
+    connector::Plaid,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Razorpay,
+    connector::Riskified,
+    connector::Shift4,
+    connector::Signifyd,
+    connector::Square,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Opennode,
+    connector::Wellsfargo,
+    connector::Wellsfargopayout,
+    connector::Wise,
+    connector::Worldpay,
+    connector::Zen,
+    connector::Zsl
+);
+
 macro_rules! default_imp_for_pre_processing_steps{
     ($($path:ident::$connector:ident),*)=> {
         $(
@@ -18,7 +18,9 @@ use tracing_futures::Instrument;
 
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-use crate::core::routing::helpers::push_metrics_for_success_based_routing;
+use crate::core::routing::helpers::{
+    push_metrics_for_success_based_routing, update_elimination_state_for_payment,
+};
 use crate::{
     connector::utils::PaymentResponseRouterData,
     consts,
@@ -1715,6 +1717,26 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     {
         if let Some(dynamic_routing_algorithm) = business_profile.dynamic_routing_algorithm.clone()
         {
+            let elimination_state = state.clone();
+            let elimination_business_profile = business_profile.clone();
+            let elimination_payment_attempt = payment_attempt.clone();
+            let elimination_routing_algorithm = dynamic_routing_algorithm.clone();
+            tokio::spawn(
+                async move {
+                    update_elimination_state_for_payment(
+                        &elimination_state,
+                        &elimination_payment_attempt,
+                        &elimination_business_profile,
+                        elimination_routing_algorithm,
+                        flow_name,
+                    )
+                    .await
+                    .map_err(|e| logger::error!(elimination_routing_error=?e))
+                    .ok();
+                }
+                .in_current_span(),
+            );
+
             let state = state.clone();
             let business_profile = business_profile.clone();
             let payment_attempt = payment_attempt.clone();
@@ -371,7 +371,7 @@ async fn get_tracker_for_sync<
         })?;
 
     let frm_response = if cfg!(feature = "frm") {
-        db.find_fraud_check_by_payment_id(payment_id.to_owned(), merchant_account.get_id().clone())
+        db.find_fraud_check_by_payment_id(payment_id.to_owned(), merchant_account.get_id().clone(), merchant_account.storage_scheme)
             .await
             .change_context(errors::ApiErrorResponse::PaymentNotFound)
             .attach_printable_lazy(|| {
@@ -33,11 +33,21 @@ use rand::{
 };
 use rustc_hash::FxHashMap;
 use storage_impl::redis::cache::{CacheKey, CGRAPH_CACHE, ROUTING_CACHE};
+#[cfg(feature = "v1")]
+use strum::IntoEnumIterator;
+#[cfg(feature = "v1")]
+use tracing_futures::Instrument;
 
 #[cfg(feature = "v2")]
 use crate::core::admin;
 #[cfg(feature = "payouts")]
 use crate::core::payouts;
+#[cfg(feature = "v1")]
+use crate::{
+    core::routing::{elimination, least_cost},
+    events::shadow_routing_logs::ShadowRoutingEvent,
+    routes::app::SessionStateInfo,
+};
 use crate::{
     core::{
         errors, errors as oss_errors, payments as payments_oss,
@@ -57,7 +67,7 @@ pub enum CachedAlgorithm {
     Single(Box<routing_types::RoutableConnectorChoice>),
     Priority(Vec<routing_types::RoutableConnectorChoice>),
     VolumeSplit(Vec<routing_types::ConnectorVolumeSplit>),
-    Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
+    Advanced(backend::DecisionTableBackend<ConnectorSelection>),
 }
 
 pub struct SessionFlowRoutingInput<'a> {
@@ -138,6 +148,7 @@ pub fn make_dsl_input_for_payouts(
             .map(api_enums::Country::from_alpha2),
         business_label: payout_data.payout_attempt.business_label.clone(),
         setup_future_usage: None,
+        converted_amount: None,
     };
     let payment_method = dsl_inputs::PaymentMethodInput {
         payment_method: payout_data
@@ -149,12 +160,25 @@ pub fn make_dsl_input_for_payouts(
             .clone()
             .map(api_enums::PaymentMethodType::foreign_from),
         card_network: None,
+        issuer_country: None,
+        card_issuer: None,
+        is_network_token: None,
+    };
+    let customer = dsl_inputs::CustomerInput {
+        customer_id: payout_data
+            .payouts
+            .customer_id
+            .as_ref()
+            .map(|customer_id| customer_id.get_string_repr().to_string()),
+        customer_segment: None,
     };
     Ok(dsl_inputs::BackendInput {
         mandate,
         metadata,
         payment,
         payment_method,
+        customer,
+        temporal: dsl_inputs::TemporalInput::default(),
     })
 }
 
@@ -218,6 +242,17 @@ pub fn make_dsl_input(
             },
         ),
     };
+    let (card_issuer, card_issuing_country) = match payments_dsl_input.payment_method_data {
+        Some(domain::PaymentMethodData::Card(card)) => (
+            card.card_issuer.clone(),
+            card.card_issuing_country.as_deref(),
+        ),
+        Some(domain::PaymentMethodData::NetworkToken(token)) => (
+            token.card_issuer.clone(),
+            token.card_issuing_country.as_deref(),
+        ),
+        _ => (None, None),
+    };
     let payment_method_input = dsl_inputs::PaymentMethodInput {
         payment_method: payments_dsl_input.payment_attempt.payment_method,
         payment_method_type: payments_dsl_input.payment_attempt.payment_method_type,
@@ -229,6 +264,11 @@ pub fn make_dsl_input(
 
                 _ => None,
             }),
+        issuer_country: card_issuing_country.and_then(parse_issuer_country),
+        card_issuer,
+        is_network_token: payments_dsl_input
+            .payment_method_data
+            .map(|pm_data| matches!(pm_data, domain::PaymentMethodData::NetworkToken(_))),
     };
 
     let payment_input = dsl_inputs::PaymentInput {
@@ -259,6 +299,19 @@ pub fn make_dsl_input(
             .map(api_enums::Country::from_alpha2),
         business_label: payments_dsl_input.payment_intent.business_label.clone(),
         setup_future_usage: payments_dsl_input.payment_intent.setup_future_usage,
+        converted_amount: payments_dsl_input.additional_inputs.converted_amount,
+    };
+
+    let customer_input = dsl_inputs::CustomerInput {
+        customer_id: payments_dsl_input
+            .payment_intent
+            .customer_id
+            .as_ref()
+            .map(|customer_id| customer_id.get_string_repr().to_string()),
+        customer_segment: payments_dsl_input
+            .additional_inputs
+            .customer_segment
+            .clone(),
     };
 
     let metadata = payments_dsl_input
@@ -276,9 +329,35 @@ pub fn make_dsl_input(
         payment: payment_input,
         payment_method: payment_method_input,
         mandate: mandate_data,
+        customer: customer_input,
+        temporal: payments_dsl_input
+            .additional_inputs
+            .get_temporal_input(payments_dsl_input.payment_intent.created_at),
     })
 }
 
+/// Parses the issuing country of a card, which is recorded either as the alpha-2 code or as the
+/// name of the country
+#[cfg(feature = "v1")]
+pub fn parse_issuer_country(issuing_country: &str) -> Option<api_enums::Country> {
+    let normalize = |country: &str| {
+        country
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_ascii_lowercase()
+    };
+
+    CountryAlpha2::from_str(issuing_country.trim())
+        .map(api_enums::Country::from_alpha2)
+        .ok()
+        .or_else(|| {
+            let issuing_country = normalize(issuing_country);
+            api_enums::Country::iter()
+                .find(|country| normalize(&country.to_string()) == issuing_country)
+        })
+}
+
 pub async fn perform_static_routing_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -286,8 +365,24 @@ pub async fn perform_static_routing_v1(
     business_profile: &domain::Profile,
     transaction_data: &routing::TransactionData<'_>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let algorithm_id = if let Some(id) = algorithm_id {
-        id
+    #[cfg_attr(not(feature = "v1"), allow(unused_variables))]
+    let (connectors, rule_name, is_volume_split) = if let Some(algorithm_id) = algorithm_id {
+        let cached_algorithm = ensure_algorithm_cached_v1(
+            state,
+            merchant_id,
+            algorithm_id,
+            business_profile.get_id(),
+            &api_enums::TransactionType::from(transaction_data),
+        )
+        .await?;
+
+        let (connectors, rule_name) =
+            evaluate_cached_algorithm_v1(cached_algorithm.as_ref(), transaction_data)?;
+        (
+            connectors,
+            rule_name,
+            matches!(cached_algorithm.as_ref(), CachedAlgorithm::VolumeSplit(_)),
+        )
     } else {
         #[cfg(feature = "v1")]
         let fallback_config = routing::helpers::get_merchant_default_config(
@@ -302,24 +397,44 @@ pub async fn perform_static_routing_v1(
             .get_default_fallback_list_of_connector_under_profile()
             .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
 
-        return Ok(fallback_config);
+        (fallback_config, None, false)
     };
-    let cached_algorithm = ensure_algorithm_cached_v1(
-        state,
-        merchant_id,
-        algorithm_id,
-        business_profile.get_id(),
-        &api_enums::TransactionType::from(transaction_data),
-    )
-    .await?;
 
-    Ok(match cached_algorithm.as_ref() {
-        CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
+    // A volume split picks connectors at random, so its decisions can't be compared with those of
+    // the shadow algorithm
+    #[cfg(feature = "v1")]
+    if let routing::TransactionData::Payment(payments_dsl_input) = transaction_data {
+        if !is_volume_split {
+            spawn_shadow_routing_v1(
+                state,
+                merchant_id,
+                business_profile,
+                payments_dsl_input,
+                &connectors,
+                rule_name,
+            );
+        }
+    }
+
+    Ok(connectors)
+}
 
-        CachedAlgorithm::Priority(plist) => plist.clone(),
+/// Evaluates a cached algorithm for the transaction, returning the selected connectors along with
+/// the name of the rule which matched, if any
+fn evaluate_cached_algorithm_v1(
+    cached_algorithm: &CachedAlgorithm,
+    transaction_data: &routing::TransactionData<'_>,
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, Option<String>)> {
+    Ok(match cached_algorithm {
+        CachedAlgorithm::Single(conn) => (vec![(**conn).clone()], None),
 
-        CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec(), None)
-            .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+        CachedAlgorithm::Priority(plist) => (plist.clone(), None),
+
+        CachedAlgorithm::VolumeSplit(splits) => (
+            perform_volume_split(splits.to_vec(), None)
+                .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+            None,
+        ),
 
         CachedAlgorithm::Advanced(interpreter) => {
             let backend_input = match transaction_data {
@@ -330,11 +445,126 @@ pub async fn perform_static_routing_v1(
                 }
             };
 
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            execute_dsl_v1(backend_input, interpreter)?
         }
     })
 }
 
+/// Evaluates the shadow algorithm linked to the profile, if any, in a separate task and records an
+/// event when its decision diverges from the decision of the active algorithm. The shadow decision
+/// never affects the payment, so failures to evaluate it are only logged.
+#[cfg(feature = "v1")]
+fn spawn_shadow_routing_v1(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    business_profile: &domain::Profile,
+    payments_dsl_input: &routing::PaymentsDslInput<'_>,
+    active_connectors: &[routing_types::RoutableConnectorChoice],
+    active_rule_name: Option<String>,
+) {
+    let shadow_algorithm_id = match business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value::<routing_types::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+        .transpose()
+    {
+        Ok(routing_ref) => routing_ref.and_then(|routing_ref| routing_ref.shadow_algorithm_id),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to parse routing algorithm ref for shadow routing"
+            );
+            None
+        }
+    };
+    let Some(shadow_algorithm_id) = shadow_algorithm_id else {
+        return;
+    };
+
+    // The payment data is borrowed, so the input of the shadow algorithm is built beforehand
+    let backend_input = match make_dsl_input(payments_dsl_input) {
+        Ok(backend_input) => backend_input,
+        Err(error) => {
+            logger::error!(?error, "Failed to construct the shadow routing input");
+            return;
+        }
+    };
+
+    let state = state.clone();
+    let merchant_id = merchant_id.to_owned();
+    let profile_id = business_profile.get_id().to_owned();
+    let payment_id = payments_dsl_input.payment_intent.payment_id.clone();
+    let attempt_id = payments_dsl_input.payment_attempt.attempt_id.clone();
+    // Only the first connector of each decision is compared, as it is the one the payment is
+    // routed to when eligible
+    let active_connector = active_connectors.first().cloned();
+
+    tokio::spawn(
+        async move {
+            let shadow_decision = async {
+                let cached_algorithm = ensure_shadow_algorithm_cached_v1(
+                    &state,
+                    &merchant_id,
+                    &shadow_algorithm_id,
+                    &profile_id,
+                )
+                .await?;
+
+                evaluate_shadow_algorithm_v1(cached_algorithm.as_ref(), backend_input)
+            }
+            .await;
+
+            let (shadow_connector, shadow_rule_name) = match shadow_decision {
+                Ok(Some(shadow_decision)) => shadow_decision,
+                Ok(None) => return,
+                Err(error) => {
+                    logger::error!(?error, "Failed to perform shadow routing");
+                    return;
+                }
+            };
+
+            let shadow_routing_event = ShadowRoutingEvent::new(
+                merchant_id,
+                profile_id,
+                payment_id,
+                attempt_id,
+                shadow_algorithm_id,
+                active_connector,
+                active_rule_name,
+                shadow_connector,
+                shadow_rule_name,
+            );
+
+            if shadow_routing_event.is_divergent() {
+                state.event_handler().log_event(&shadow_routing_event);
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+/// Returns the first connector selected by the shadow algorithm along with the name of the rule
+/// which matched, if any. Returns `None` for volume splits, whose decisions can't be compared.
+#[cfg(feature = "v1")]
+fn evaluate_shadow_algorithm_v1(
+    cached_algorithm: &CachedAlgorithm,
+    backend_input: dsl_inputs::BackendInput,
+) -> RoutingResult<
+    Option<(
+        Option<routing_types::RoutableConnectorChoice>,
+        Option<String>,
+    )>,
+> {
+    let (connectors, rule_name) = match cached_algorithm {
+        CachedAlgorithm::Single(conn) => (vec![(**conn).clone()], None),
+        CachedAlgorithm::Priority(plist) => (plist.clone(), None),
+        CachedAlgorithm::VolumeSplit(_) => return Ok(None),
+        CachedAlgorithm::Advanced(interpreter) => execute_dsl_v1(backend_input, interpreter)?,
+    };
+
+    Ok(Some((connectors.into_iter().next(), rule_name)))
+}
+
 async fn ensure_algorithm_cached_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -378,6 +608,167 @@ async fn ensure_algorithm_cached_v1(
     Ok(algorithm)
 }
 
+#[cfg(feature = "v1")]
+async fn ensure_shadow_algorithm_cached_v1(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    algorithm_id: &common_utils::id_type::RoutingId,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RoutingResult<Arc<CachedAlgorithm>> {
+    let key = format!(
+        "routing_config_shadow_{}_{}",
+        merchant_id.get_string_repr(),
+        profile_id.get_string_repr(),
+    );
+
+    let cached_algorithm = ROUTING_CACHE
+        .get_val::<Arc<CachedAlgorithm>>(CacheKey {
+            key: key.clone(),
+            prefix: state.tenant.redis_key_prefix.clone(),
+        })
+        .await;
+
+    match cached_algorithm {
+        Some(algorithm) => Ok(algorithm),
+        None => refresh_routing_cache_v1(state, key, algorithm_id, profile_id).await,
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn ensure_fallback_algorithm_cached_v1(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    algorithm_id: &common_utils::id_type::RoutingId,
+    profile_id: &common_utils::id_type::ProfileId,
+    fallback_kind: routing_types::FallbackRoutingKind,
+) -> RoutingResult<Arc<CachedAlgorithm>> {
+    let key =
+        routing::helpers::get_fallback_routing_cache_key(merchant_id, profile_id, fallback_kind);
+
+    let cached_algorithm = ROUTING_CACHE
+        .get_val::<Arc<CachedAlgorithm>>(CacheKey {
+            key: key.clone(),
+            prefix: state.tenant.redis_key_prefix.clone(),
+        })
+        .await;
+
+    match cached_algorithm {
+        Some(algorithm) => Ok(algorithm),
+        None => refresh_routing_cache_v1(state, key, algorithm_id, profile_id).await,
+    }
+}
+
+/// Evaluates the refund algorithm linked to the profile for the payment, returning `None` when the
+/// profile has no refund algorithm
+#[cfg(all(feature = "v1", feature = "payouts"))]
+pub async fn perform_refund_routing(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    payment_intent: &oss_storage::PaymentIntent,
+    payment_attempt: &oss_storage::PaymentAttempt,
+) -> RoutingResult<Option<Vec<routing_types::RoutableConnectorChoice>>> {
+    let refund_algorithm_id = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value::<routing_types::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::RoutingError::InvalidRoutingAlgorithmStructure)?
+        .and_then(|routing_ref| routing_ref.refund_algorithm_id);
+    let Some(refund_algorithm_id) = refund_algorithm_id else {
+        return Ok(None);
+    };
+
+    let currency = payment_attempt.currency.or(payment_intent.currency).ok_or(
+        errors::RoutingError::DslMissingRequiredField {
+            field_name: "currency".to_string(),
+        },
+    )?;
+
+    let cached_algorithm = ensure_fallback_algorithm_cached_v1(
+        state,
+        merchant_account.get_id(),
+        &refund_algorithm_id,
+        business_profile.get_id(),
+        routing_types::FallbackRoutingKind::Refund,
+    )
+    .await?;
+
+    let additional_inputs = routing::inputs::get_additional_routing_inputs(
+        state,
+        merchant_account,
+        key_store,
+        business_profile.get_id(),
+        payment_intent,
+        currency,
+    )
+    .await;
+    let address = hyperswitch_domain_models::payment_address::PaymentAddress::default();
+    let payments_dsl_input = routing::PaymentsDslInput::new(
+        None,
+        payment_attempt,
+        payment_intent,
+        None,
+        &address,
+        None,
+        currency,
+        additional_inputs,
+    );
+
+    let (connectors, _) = evaluate_cached_algorithm_v1(
+        cached_algorithm.as_ref(),
+        &routing::TransactionData::Payment(payments_dsl_input),
+    )?;
+
+    Ok(Some(connectors))
+}
+
+/// Evaluates the payout retry algorithm linked to the profile for the payout, returning the
+/// eligible connectors, or `None` when the profile has no payout retry algorithm
+#[cfg(all(feature = "v1", feature = "payouts"))]
+pub async fn perform_payout_retry_routing(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payout_data: &payouts::PayoutData,
+) -> RoutingResult<Option<Vec<routing_types::RoutableConnectorChoice>>> {
+    let business_profile = &payout_data.business_profile;
+    let retry_algorithm_id = business_profile
+        .payout_routing_algorithm
+        .clone()
+        .map(|val| val.parse_value::<routing_types::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::RoutingError::InvalidRoutingAlgorithmStructure)?
+        .and_then(|routing_ref| routing_ref.retry_algorithm_id);
+    let Some(retry_algorithm_id) = retry_algorithm_id else {
+        return Ok(None);
+    };
+
+    let cached_algorithm = ensure_fallback_algorithm_cached_v1(
+        state,
+        &business_profile.merchant_id,
+        &retry_algorithm_id,
+        business_profile.get_id(),
+        routing_types::FallbackRoutingKind::PayoutRetry,
+    )
+    .await?;
+
+    let transaction_data = routing::TransactionData::Payout(payout_data);
+    let (connectors, _) =
+        evaluate_cached_algorithm_v1(cached_algorithm.as_ref(), &transaction_data)?;
+
+    perform_eligibility_analysis(
+        state,
+        key_store,
+        connectors,
+        &transaction_data,
+        None,
+        business_profile.get_id(),
+    )
+    .await
+    .map(Some)
+}
+
 pub fn perform_straight_through_routing(
     algorithm: &routing_types::StraightThroughAlgorithm,
     creds_identifier: Option<&str>,
@@ -402,14 +793,22 @@ pub fn perform_straight_through_routing(
 
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
-    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+    interpreter: &backend::DecisionTableBackend<ConnectorSelection>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::RoutingAlgorithm = interpreter
+    execute_dsl_v1(backend_input, interpreter).map(|(connectors, _)| connectors)
+}
+
+fn execute_dsl_v1(
+    backend_input: dsl_inputs::BackendInput,
+    interpreter: &backend::DecisionTableBackend<ConnectorSelection>,
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, Option<String>)> {
+    let routing_output = interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
         .change_context(errors::RoutingError::DslExecutionError)?;
+    let connector_selection: routing_types::RoutingAlgorithm =
+        routing_output.connector_selection.foreign_into();
 
-    Ok(match routing_output {
+    let connectors = match connector_selection {
         routing_types::RoutingAlgorithm::Priority(plist) => plist,
 
         routing_types::RoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits, None)
@@ -417,7 +816,9 @@ fn execute_dsl_and_get_connector_v1(
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
-    })
+    };
+
+    Ok((connectors, routing_output.rule_name))
 }
 
 pub async fn refresh_routing_cache_v1(
@@ -446,7 +847,7 @@ pub async fn refresh_routing_cache_v1(
             CachedAlgorithm::VolumeSplit(splits)
         }
         routing_types::RoutingAlgorithm::Advanced(program) => {
-            let interpreter = backend::VirInterpreterBackend::with_program(program)
+            let interpreter = backend::DecisionTableBackend::with_program(program)
                 .change_context(errors::RoutingError::DslBackendInitError)
                 .attach_printable("Error initializing DSL interpreter backend")?;
 
@@ -646,7 +1047,7 @@ pub async fn refresh_cgraph_cache<'a>(
 }
 
 #[allow(clippy::too_many_arguments)]
-async fn perform_cgraph_filtering(
+pub async fn perform_cgraph_filtering(
     state: &SessionState,
     key_store: &domain::MerchantKeyStore,
     chosen: Vec<routing_types::RoutableConnectorChoice>,
@@ -812,6 +1213,294 @@ pub async fn perform_eligibility_analysis_with_fallback(
     Ok(final_selection)
 }
 
+/// Orders the eligible connectors by the estimated cost of processing the payment, when least
+/// cost routing is enabled for the profile. The ranking only refines the routing decision, so the
+/// connectors are left in their order when it cannot be performed.
+#[cfg(feature = "v1")]
+pub async fn perform_least_cost_routing(
+    state: &SessionState,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+    payments_dsl_input: &routing::PaymentsDslInput<'_>,
+    business_profile: &domain::Profile,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    let least_cost_algorithm_id = match business_profile
+        .dynamic_routing_algorithm
+        .clone()
+        .map(|val| {
+            val.parse_value::<api_models::routing::DynamicRoutingAlgorithmRef>(
+                "DynamicRoutingAlgorithmRef",
+            )
+        })
+        .transpose()
+    {
+        Ok(dynamic_routing_ref) => dynamic_routing_ref
+            .and_then(|dynamic_routing_ref| dynamic_routing_ref.least_cost_algorithm)
+            .and_then(|least_cost_algorithm| least_cost_algorithm.algorithm_id),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to parse dynamic routing algorithm ref for least cost routing"
+            );
+            None
+        }
+    };
+    let Some(least_cost_algorithm_id) = least_cost_algorithm_id else {
+        return connectors;
+    };
+    if connectors.len() < 2 {
+        return connectors;
+    }
+
+    let ranking_input = async {
+        let config = ensure_least_cost_config_cached(
+            state,
+            business_profile.get_id(),
+            &least_cost_algorithm_id,
+        )
+        .await?;
+        let fee_schedules = ensure_fee_schedules_cached(state, business_profile.get_id()).await?;
+
+        let backend_input = make_dsl_input(payments_dsl_input)?;
+        let card_type = match payments_dsl_input.payment_method_data {
+            Some(domain::PaymentMethodData::Card(card)) => card.card_type.as_deref(),
+            _ => None,
+        };
+
+        Ok::<_, error_stack::Report<errors::RoutingError>>((
+            config,
+            fee_schedules,
+            least_cost::FeeInput::new(&backend_input, card_type),
+        ))
+    }
+    .await;
+
+    match ranking_input {
+        Ok((config, fee_schedules, fee_input)) => {
+            let ranked_connectors = least_cost::rank_connectors_by_cost(
+                connectors,
+                &fee_schedules,
+                config.default_fee.as_ref(),
+                &fee_input,
+            );
+            logger::debug!(least_cost_ranked_connectors=?ranked_connectors, "List of connectors ranked by cost");
+            ranked_connectors
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to perform least cost routing");
+            connectors
+        }
+    }
+}
+
+/// Excludes the connectors eliminated for the payment method of the payment, when elimination
+/// routing is enabled for the profile. When all the eligible connectors are eliminated, or the
+/// elimination state cannot be retrieved, the connectors are left unchanged rather than failing
+/// the payment.
+#[cfg(feature = "v1")]
+pub async fn perform_elimination_routing(
+    state: &SessionState,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+    payments_dsl_input: &routing::PaymentsDslInput<'_>,
+    business_profile: &domain::Profile,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    let elimination_algorithm_id = match business_profile
+        .dynamic_routing_algorithm
+        .clone()
+        .map(|val| {
+            val.parse_value::<api_models::routing::DynamicRoutingAlgorithmRef>(
+                "DynamicRoutingAlgorithmRef",
+            )
+        })
+        .transpose()
+    {
+        Ok(dynamic_routing_ref) => dynamic_routing_ref
+            .and_then(|dynamic_routing_ref| dynamic_routing_ref.elimination_algorithm)
+            .and_then(|elimination_algorithm| elimination_algorithm.algorithm_id),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to parse dynamic routing algorithm ref for elimination routing"
+            );
+            None
+        }
+    };
+    let (Some(elimination_algorithm_id), Some(payment_method)) = (
+        elimination_algorithm_id,
+        payments_dsl_input.payment_attempt.payment_method,
+    ) else {
+        return connectors;
+    };
+
+    let filtered_connectors = async {
+        let config = ensure_elimination_config_cached(
+            state,
+            business_profile.get_id(),
+            &elimination_algorithm_id,
+        )
+        .await?;
+
+        elimination::filter_eliminated_connectors(
+            state,
+            business_profile.get_id(),
+            elimination::EliminationParams::from(config.as_ref()),
+            connectors.clone(),
+            payment_method,
+        )
+        .await
+    }
+    .await;
+
+    match filtered_connectors {
+        Ok(filtered_connectors) if filtered_connectors.is_empty() => {
+            logger::warn!(
+                eliminated_connectors=?connectors,
+                "All eligible connectors are eliminated, ignoring the elimination"
+            );
+            connectors
+        }
+        Ok(filtered_connectors) => {
+            logger::debug!(elimination_filtered_connectors=?filtered_connectors, "List of connectors after elimination");
+            filtered_connectors
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to perform elimination routing");
+            connectors
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn ensure_elimination_config_cached(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    algorithm_id: &common_utils::id_type::RoutingId,
+) -> RoutingResult<Arc<api_models::routing::EliminationRoutingConfig>> {
+    // Routing algorithms are never modified, so the cached config does not have to be invalidated
+    let key = format!(
+        "elimination_config_{}_{}",
+        profile_id.get_string_repr(),
+        algorithm_id.get_string_repr()
+    );
+
+    if let Some(config) = ROUTING_CACHE
+        .get_val::<Arc<api_models::routing::EliminationRoutingConfig>>(CacheKey {
+            key: key.clone(),
+            prefix: state.tenant.redis_key_prefix.clone(),
+        })
+        .await
+    {
+        return Ok(config);
+    }
+
+    let config = state
+        .store
+        .find_routing_algorithm_by_profile_id_algorithm_id(profile_id, algorithm_id)
+        .await
+        .change_context(errors::RoutingError::EliminationConfigRetrievalFailed)?
+        .algorithm_data
+        .parse_value::<api_models::routing::EliminationRoutingConfig>("EliminationRoutingConfig")
+        .change_context(errors::RoutingError::EliminationConfigRetrievalFailed)?;
+    let config = Arc::new(config);
+
+    ROUTING_CACHE
+        .push(
+            CacheKey {
+                key,
+                prefix: state.tenant.redis_key_prefix.clone(),
+            },
+            config.clone(),
+        )
+        .await;
+
+    Ok(config)
+}
+
+#[cfg(feature = "v1")]
+async fn ensure_least_cost_config_cached(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    algorithm_id: &common_utils::id_type::RoutingId,
+) -> RoutingResult<Arc<api_models::routing::LeastCostRoutingConfig>> {
+    // Routing algorithms are never modified, so the cached config does not have to be invalidated
+    let key = format!(
+        "least_cost_config_{}_{}",
+        profile_id.get_string_repr(),
+        algorithm_id.get_string_repr()
+    );
+
+    if let Some(config) = ROUTING_CACHE
+        .get_val::<Arc<api_models::routing::LeastCostRoutingConfig>>(CacheKey {
+            key: key.clone(),
+            prefix: state.tenant.redis_key_prefix.clone(),
+        })
+        .await
+    {
+        return Ok(config);
+    }
+
+    let config = state
+        .store
+        .find_routing_algorithm_by_profile_id_algorithm_id(profile_id, algorithm_id)
+        .await
+        .change_context(errors::RoutingError::LeastCostConfigRetrievalFailed)?
+        .algorithm_data
+        .parse_value::<api_models::routing::LeastCostRoutingConfig>("LeastCostRoutingConfig")
+        .change_context(errors::RoutingError::LeastCostConfigRetrievalFailed)?;
+    let config = Arc::new(config);
+
+    ROUTING_CACHE
+        .push(
+            CacheKey {
+                key,
+                prefix: state.tenant.redis_key_prefix.clone(),
+            },
+            config.clone(),
+        )
+        .await;
+
+    Ok(config)
+}
+
+#[cfg(feature = "v1")]
+async fn ensure_fee_schedules_cached(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RoutingResult<Arc<least_cost::FeeSchedules>> {
+    let key = least_cost::get_fee_schedules_cache_key(profile_id);
+
+    if let Some(fee_schedules) = ROUTING_CACHE
+        .get_val::<Arc<least_cost::FeeSchedules>>(CacheKey {
+            key: key.clone(),
+            prefix: state.tenant.redis_key_prefix.clone(),
+        })
+        .await
+    {
+        return Ok(fee_schedules);
+    }
+
+    let fee_schedules = state
+        .store
+        .list_connector_fee_schedules_by_profile_id(profile_id)
+        .await
+        .change_context(errors::RoutingError::FeeScheduleFetchFailed)?;
+    let fee_schedules = Arc::new(
+        least_cost::fee_schedules_by_connector(fee_schedules)
+            .change_context(errors::RoutingError::FeeScheduleFetchFailed)?,
+    );
+
+    ROUTING_CACHE
+        .push(
+            CacheKey {
+                key,
+                prefix: state.tenant.redis_key_prefix.clone(),
+            },
+            fee_schedules.clone(),
+        )
+        .await;
+
+    Ok(fee_schedules)
+}
+
 pub async fn perform_session_flow_routing(
     session_input: SessionFlowRoutingInput<'_>,
     transaction_type: &api_enums::TransactionType,
@@ -860,18 +1549,35 @@ pub async fn perform_session_flow_routing(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        issuer_country: None,
+        card_issuer: None,
+        is_network_token: None,
     };
 
+    #[cfg(feature = "v1")]
+    let currency = session_input
+        .payment_intent
+        .currency
+        .get_required_value("Currency")
+        .change_context(errors::RoutingError::DslMissingRequiredField {
+            field_name: "currency".to_string(),
+        })?;
+
+    #[cfg(feature = "v1")]
+    let additional_inputs = routing::inputs::get_additional_routing_inputs(
+        session_input.state,
+        session_input.merchant_account,
+        session_input.key_store,
+        &profile_id,
+        session_input.payment_intent,
+        currency,
+    )
+    .await;
+
     #[cfg(feature = "v1")]
     let payment_input = dsl_inputs::PaymentInput {
         amount: session_input.payment_intent.amount,
-        currency: session_input
-            .payment_intent
-            .currency
-            .get_required_value("Currency")
-            .change_context(errors::RoutingError::DslMissingRequiredField {
-                field_name: "currency".to_string(),
-            })?,
+        currency,
         authentication_type: session_input.payment_attempt.authentication_type,
         card_bin: None,
         capture_method: session_input
@@ -887,10 +1593,25 @@ pub async fn perform_session_flow_routing(
             .map(storage_enums::Country::from_alpha2),
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
+        converted_amount: additional_inputs.converted_amount,
+    };
+
+    #[cfg(feature = "v1")]
+    let customer_input = dsl_inputs::CustomerInput {
+        customer_id: session_input
+            .payment_intent
+            .customer_id
+            .as_ref()
+            .map(|customer_id| customer_id.get_string_repr().to_string()),
+        customer_segment: additional_inputs.customer_segment.clone(),
     };
 
+    #[cfg(feature = "v1")]
+    let temporal_input =
+        additional_inputs.get_temporal_input(session_input.payment_intent.created_at);
+
     #[cfg(feature = "v2")]
-    let payment_input = todo!();
+    let (payment_input, customer_input, temporal_input) = todo!();
 
     let metadata = session_input
         .payment_intent
@@ -911,6 +1632,8 @@ pub async fn perform_session_flow_routing(
             mandate_type: None,
             payment_type: None,
         },
+        customer: customer_input,
+        temporal: temporal_input,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1179,6 +1902,7 @@ pub fn make_dsl_input_for_surcharge(
             .map(api_enums::Country::from_alpha2),
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
+        converted_amount: None,
     };
 
     let metadata = payment_intent
@@ -1193,12 +1917,82 @@ pub fn make_dsl_input_for_surcharge(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        issuer_country: None,
+        card_issuer: None,
+        is_network_token: None,
     };
     let backend_input = dsl_inputs::BackendInput {
         metadata,
         payment: payment_input,
         payment_method: payment_method_input,
         mandate: mandate_data,
+        customer: dsl_inputs::CustomerInput::default(),
+        temporal: dsl_inputs::TemporalInput::default(),
     };
     Ok(backend_input)
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_connector_choice(
+        connector: api_enums::RoutableConnectors,
+    ) -> routing_types::RoutableConnectorChoice {
+        routing_types::RoutableConnectorChoice {
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: None,
+        }
+    }
+
+    fn get_backend_input() -> dsl_inputs::BackendInput {
+        serde_json::from_value(serde_json::json!({
+            "metadata": null,
+            "payment": { "amount": 1000, "currency": "USD" },
+            "payment_method": { "payment_method": "card" },
+            "mandate": {}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_shadow_algorithm_selects_first_connector() {
+        let shadow_decision = evaluate_shadow_algorithm_v1(
+            &CachedAlgorithm::Priority(vec![
+                get_connector_choice(api_enums::RoutableConnectors::Stripe),
+                get_connector_choice(api_enums::RoutableConnectors::Adyen),
+            ]),
+            get_backend_input(),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            shadow_decision.0.map(|choice| choice.connector),
+            Some(api_enums::RoutableConnectors::Stripe)
+        );
+        assert_eq!(shadow_decision.1, None);
+    }
+
+    #[test]
+    fn test_shadow_volume_split_is_not_compared() {
+        let shadow_decision = evaluate_shadow_algorithm_v1(
+            &CachedAlgorithm::VolumeSplit(vec![
+                routing_types::ConnectorVolumeSplit {
+                    connector: get_connector_choice(api_enums::RoutableConnectors::Stripe),
+                    split: 50,
+                },
+                routing_types::ConnectorVolumeSplit {
+                    connector: get_connector_choice(api_enums::RoutableConnectors::Adyen),
+                    split: 50,
+                },
+            ]),
+            get_backend_input(),
+        )
+        .unwrap();
+
+        assert!(shadow_decision.is_none());
+    }
+}
@@ -575,6 +575,7 @@ where
                                         key_store,
                                         merchant_id,
                                         &resp.payment_method_id,
+                                        merchant_account.storage_scheme,
                                     )
                                     .await
                                     .to_not_found_response(
@@ -43,7 +43,7 @@ use crate::{
             self, ConnectorErrorExt, CustomResult, RouterResponse, RouterResult, StorageErrorExt,
         },
         payments::{self, customers, helpers as payment_helpers},
-        utils as core_utils,
+        refunds, utils as core_utils,
     },
     db::StorageInterface,
     routes::SessionState,
@@ -167,6 +167,11 @@ pub async fn make_connector_decision(
     connector_call_type: api::ConnectorCallType,
     payout_data: &mut PayoutData,
 ) -> RouterResult<()> {
+    #[cfg(all(feature = "v1", feature = "payout_retry"))]
+    let connector_call_type =
+        helpers::apply_payout_retry_routing(state, key_store, connector_call_type, payout_data)
+            .await?;
+
     match connector_call_type {
         api::ConnectorCallType::PreDetermined(connector_data) => {
             Box::pin(call_connector_payout(
@@ -301,6 +306,7 @@ pub async fn payouts_core(
 pub async fn payouts_create_core(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
+    auth_profile_id: Option<common_utils::id_type::ProfileId>,
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutCreateRequest,
     locale: &str,
@@ -309,6 +315,19 @@ pub async fn payouts_create_core(
     let (payout_id, payout_method_data, profile_id, customer) =
         validator::validate_create_request(&state, &merchant_account, &req, &key_store).await?;
 
+    utils::when(
+        auth_profile_id
+            .as_ref()
+            .is_some_and(|auth_profile_id| auth_profile_id != &profile_id),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Profile id authentication failed. Please use the correct API key"
+                    .to_string(),
+            }))
+            .attach_printable("Payout profile does not match the profile of the API key")
+        },
+    )?;
+
     // Create DB entries
     let mut payout_data = payout_create_db_entries(
         &state,
@@ -359,6 +378,8 @@ pub async fn payouts_create_core(
         .await?
     };
 
+    update_refund_of_payout(&state, &merchant_account, &payout_data).await;
+
     response_handler(&state, &merchant_account, &payout_data).await
 }
 
@@ -432,6 +453,8 @@ pub async fn payouts_confirm_core(
     )
     .await?;
 
+    update_refund_of_payout(&state, &merchant_account, &payout_data).await;
+
     response_handler(&state, &merchant_account, &payout_data).await
 }
 
@@ -560,6 +583,8 @@ pub async fn payouts_retrieve_core(
         .await?;
     }
 
+    update_refund_of_payout(&state, &merchant_account, &payout_data).await;
+
     response_handler(&state, &merchant_account, &payout_data).await
 }
 
@@ -654,6 +679,8 @@ pub async fn payouts_cancel_core(
             .attach_printable("Payout cancellation failed for given Payout request")?;
     }
 
+    update_refund_of_payout(&state, &merchant_account, &payout_data).await;
+
     response_handler(&state, &merchant_account, &payout_data).await
 }
 
@@ -740,6 +767,8 @@ pub async fn payouts_fulfill_core(
     .await
     .attach_printable("Payout fulfillment failed for given Payout request")?;
 
+    update_refund_of_payout(&state, &merchant_account, &payout_data).await;
+
     if helpers::is_payout_err_state(status) {
         return Err(report!(errors::ApiErrorResponse::PayoutFailed {
             data: Some(
@@ -2332,6 +2361,29 @@ pub async fn fulfill_payout(
     Ok(())
 }
 
+/// Propagates the status of a payout created for a refund to the refund. Failures are only logged,
+/// as the refund is synced with the payout again when it is retrieved.
+async fn update_refund_of_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &PayoutData,
+) {
+    if let Err(error) = refunds::routing::update_refund_from_payout(
+        state,
+        merchant_account,
+        &payout_data.payouts,
+        payout_data.payout_attempt.status,
+    )
+    .await
+    {
+        logger::error!(
+            ?error,
+            payout_id = %payout_data.payouts.payout_id,
+            "Failed to update the refund of the payout"
+        );
+    }
+}
+
 pub async fn response_handler(
     state: &SessionState,
     merchant_account: &domain::MerchantAccount,
@@ -587,6 +587,7 @@ pub async fn save_payout_data_to_locker(
                 key_store,
                 merchant_account.get_id(),
                 &existing_pm.payment_method_id,
+                merchant_account.storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
@@ -904,6 +905,54 @@ pub async fn decide_payout_connector(
     .await
 }
 
+/// Replaces the connectors through which the payout is retried with the chain selected by the
+/// payout retry algorithm of the profile, if one is linked. The connector decided for the first
+/// attempt is always kept first.
+#[cfg(all(feature = "v1", feature = "payout_retry"))]
+pub async fn apply_payout_retry_routing(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    connector_call_type: api::ConnectorCallType,
+    payout_data: &PayoutData,
+) -> RouterResult<api::ConnectorCallType> {
+    let primary_connector = match &connector_call_type {
+        api::ConnectorCallType::PreDetermined(connector_data) => connector_data,
+        api::ConnectorCallType::Retryable(connectors) => match connectors.first() {
+            Some(connector_data) => connector_data,
+            None => return Ok(connector_call_type),
+        },
+        api::ConnectorCallType::SessionMultiple(_) => return Ok(connector_call_type),
+    };
+
+    let Some(retry_chain) = routing::perform_payout_retry_routing(state, key_store, payout_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to perform payout retry routing")?
+    else {
+        return Ok(connector_call_type);
+    };
+
+    let mut connectors = vec![primary_connector.clone()];
+    for choice in retry_chain {
+        if choice.connector.to_string() == primary_connector.connector_name.to_string() {
+            continue;
+        }
+
+        connectors.push(
+            api::ConnectorData::get_payout_connector_by_name(
+                &state.conf.connectors,
+                &choice.connector.to_string(),
+                api::GetToken::Connector,
+                choice.merchant_connector_id,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid connector name received from payout retry routing")?,
+        );
+    }
+
+    Ok(api::ConnectorCallType::Retryable(connectors))
+}
+
 pub async fn get_default_payout_connector(
     _state: &SessionState,
     request_connector: Option<serde_json::Value>,
@@ -0,0 +1,534 @@
+use std::{collections::HashMap, str::FromStr};
+
+use actix_web::http::header::HeaderMap;
+use common_utils::{date_time, ext_traits::StringExt};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use redis_interface::errors::RedisError;
+use router_env::{instrument, logger, metrics::add_attributes, tracing, Flow};
+
+use super::{
+    api_keys,
+    errors::{self, RouterResult},
+};
+use crate::{
+    configs::settings::RateLimit,
+    routes::{lock_utils::ApiIdentifier, metrics, SessionState},
+    services::authentication::{self, AuthenticationType},
+    types::storage,
+};
+
+pub const RATE_LIMIT_PREFIX: &str = "RATE_LIMIT";
+
+/// Duration for which the owner of an API key is cached against the hash of the key
+const API_KEY_OWNER_TTL_IN_SECONDS: i64 = 60 * 60;
+
+/// Limits which override the ones in the application configuration for a merchant or an API key.
+///
+/// These are stored in the `configs` table against the keys returned by
+/// [`get_merchant_rate_limit_key`] and [`get_api_key_rate_limit_key`].
+#[derive(Debug, Default, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct RateLimitOverride {
+    pub default: Option<RateLimit>,
+    pub apis: HashMap<ApiIdentifier, RateLimit>,
+}
+
+impl RateLimitOverride {
+    fn get_limit(&self, api_identifier: &ApiIdentifier) -> Option<RateLimit> {
+        self.apis.get(api_identifier).copied().or(self.default)
+    }
+}
+
+/// Outcome of the rate limit check for a request, used for populating the `X-RateLimit-*`
+/// response headers.
+#[derive(Clone, Debug)]
+pub struct RateLimitStatus {
+    pub limit: u64,
+    pub remaining: u64,
+    pub reset_after_seconds: u64,
+    pub is_throttled: bool,
+}
+
+impl RateLimitStatus {
+    /// The status of a request counted against several limits, which is throttled by any of them
+    pub fn most_restrictive(self, other: Self) -> Self {
+        match (self.is_throttled, other.is_throttled) {
+            (true, false) => self,
+            (false, true) => other,
+            _ if other.remaining < self.remaining => other,
+            _ => self,
+        }
+    }
+}
+
+/// Owner of an API key, cached against the hash of the key once a request made with the key is
+/// authenticated, so that the limits of the merchant can be applied to the subsequent requests
+/// before authenticating them.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct ApiKeyOwner {
+    /// `None` for keys which do not belong to a merchant, such as the admin API key, whose
+    /// requests are not rate limited
+    merchant_id: Option<common_utils::id_type::MerchantId>,
+    key_id: Option<String>,
+}
+
+impl ApiKeyOwner {
+    fn from_auth_type(auth_type: &AuthenticationType) -> Self {
+        match auth_type {
+            AuthenticationType::ApiKey {
+                merchant_id,
+                key_id,
+            } => Self {
+                merchant_id: Some(merchant_id.clone()),
+                key_id: Some(key_id.clone()),
+            },
+            AuthenticationType::PublishableKey { merchant_id } => Self {
+                merchant_id: Some(merchant_id.clone()),
+                key_id: None,
+            },
+            _ => Self {
+                merchant_id: None,
+                key_id: None,
+            },
+        }
+    }
+}
+
+/// Entity against which the requests are counted
+enum RateLimitSubject<'a> {
+    Merchant(&'a common_utils::id_type::MerchantId),
+    /// Requests made with an API key are counted against the hash of the key, since they are
+    /// counted before the key is authenticated
+    ApiKey {
+        hashed_api_key: &'a str,
+    },
+}
+
+impl RateLimitSubject<'_> {
+    fn get_redis_key(&self, api_identifier: &ApiIdentifier, window_index: i64) -> String {
+        match self {
+            Self::Merchant(merchant_id) => format!(
+                "{RATE_LIMIT_PREFIX}_{}_{api_identifier}_{window_index}",
+                merchant_id.get_string_repr()
+            ),
+            Self::ApiKey { hashed_api_key } => {
+                format!("{RATE_LIMIT_PREFIX}_{hashed_api_key}_{api_identifier}_{window_index}")
+            }
+        }
+    }
+}
+
+fn get_api_key_owner_redis_key(hashed_api_key: &str) -> String {
+    format!("{RATE_LIMIT_PREFIX}_OWNER_{hashed_api_key}")
+}
+
+pub fn get_merchant_rate_limit_key(merchant_id: &common_utils::id_type::MerchantId) -> String {
+    format!("rate_limit_{}", merchant_id.get_string_repr())
+}
+
+pub fn get_api_key_rate_limit_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    key_id: &str,
+) -> String {
+    format!("rate_limit_{}_{key_id}", merchant_id.get_string_repr())
+}
+
+async fn find_rate_limit_override(
+    state: &SessionState,
+    config_key: &str,
+) -> RouterResult<RateLimitOverride> {
+    state
+        .store
+        .find_config_by_key_unwrap_or(config_key, Some("{}".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch rate limit override config")?
+        .config
+        .parse_struct("RateLimitOverride")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid rate limit override config")
+}
+
+/// Resolves the limit applicable for a request, in decreasing order of precedence from the API
+/// key override, the merchant override and the application configuration.
+async fn get_applicable_rate_limit(
+    state: &SessionState,
+    merchant_id: Option<&common_utils::id_type::MerchantId>,
+    key_id: Option<&str>,
+    api_identifier: &ApiIdentifier,
+) -> RouterResult<Option<RateLimit>> {
+    if let Some(merchant_id) = merchant_id {
+        if let Some(key_id) = key_id {
+            let api_key_override =
+                find_rate_limit_override(state, &get_api_key_rate_limit_key(merchant_id, key_id))
+                    .await?;
+            if let Some(rate_limit) = api_key_override.get_limit(api_identifier) {
+                return Ok(Some(rate_limit));
+            }
+        }
+
+        let merchant_override =
+            find_rate_limit_override(state, &get_merchant_rate_limit_key(merchant_id)).await?;
+        if let Some(rate_limit) = merchant_override.get_limit(api_identifier) {
+            return Ok(Some(rate_limit));
+        }
+    }
+
+    Ok(state
+        .conf
+        .rate_limit
+        .apis
+        .get(api_identifier)
+        .copied()
+        .or(state.conf.rate_limit.default))
+}
+
+fn get_hashed_api_key(state: &SessionState, request_headers: &HeaderMap) -> Option<String> {
+    let api_key = authentication::get_api_key(request_headers).ok()?.trim();
+    if api_key.is_empty() {
+        return None;
+    }
+
+    let hash_key = state
+        .conf
+        .api_keys
+        .get_inner()
+        .get_hash_key()
+        .map_err(|error| logger::error!(?error, "Failed to get the API key hash key"))
+        .ok()?;
+    let hashed_api_key = api_keys::PlaintextApiKey::from(api_key).keyed_hash(hash_key.peek());
+
+    Some(storage::HashedApiKey::from(hashed_api_key).into_inner())
+}
+
+async fn find_api_key_owner(
+    state: &SessionState,
+    hashed_api_key: &str,
+) -> RouterResult<Option<ApiKeyOwner>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    match redis_conn
+        .get_and_deserialize_key::<ApiKeyOwner>(
+            &get_api_key_owner_redis_key(hashed_api_key),
+            "ApiKeyOwner",
+        )
+        .await
+    {
+        Ok(owner) => Ok(Some(owner)),
+        Err(error) if error.current_context() == &RedisError::NotFound => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the owner of the API key")),
+    }
+}
+
+async fn cache_api_key_owner(
+    state: &SessionState,
+    hashed_api_key: &str,
+    auth_type: &AuthenticationType,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    // The owner is only written when it is not already cached, and expires so that changes to
+    // the key are eventually picked up
+    redis_conn
+        .serialize_and_set_key_if_not_exist(
+            &get_api_key_owner_redis_key(hashed_api_key),
+            ApiKeyOwner::from_auth_type(auth_type),
+            Some(API_KEY_OWNER_TTL_IN_SECONDS),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cache the owner of the API key")?;
+
+    Ok(())
+}
+
+/// Counts a request made with an API key against the hash of the key, before the key is
+/// authenticated, so that throttled requests do not cost an API key lookup.
+///
+/// The limits of the API key and of the merchant owning it are applied once a request made with
+/// the key has been authenticated, until then the limits in the application configuration apply.
+/// Authenticated requests are counted against the merchant as well by
+/// [`check_merchant_rate_limit`].
+#[instrument(skip_all)]
+pub async fn check_api_key_rate_limit(
+    state: &SessionState,
+    flow: &impl router_env::types::FlowMetric,
+    request_headers: &HeaderMap,
+) -> Option<RateLimitStatus> {
+    if !state.conf.rate_limit.enabled {
+        return None;
+    }
+
+    let api_identifier = ApiIdentifier::from(Flow::from_str(&flow.to_string()).ok()?);
+    let hashed_api_key = get_hashed_api_key(state, request_headers)?;
+
+    let owner = find_api_key_owner(state, &hashed_api_key)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to find the owner of the API key"))
+        .ok()
+        .flatten();
+    let (merchant_id, key_id) = match &owner {
+        Some(ApiKeyOwner {
+            merchant_id: None, ..
+        }) => return None,
+        Some(owner) => (owner.merchant_id.as_ref(), owner.key_id.as_deref()),
+        None => (None, None),
+    };
+
+    check_rate_limit(
+        state,
+        RateLimitSubject::ApiKey {
+            hashed_api_key: &hashed_api_key,
+        },
+        merchant_id,
+        key_id,
+        &api_identifier,
+    )
+    .await
+}
+
+/// Counts an authenticated request against the merchant it was made for, so that the requests
+/// made with all the API keys, publishable keys and JWTs of the merchant share the limits of the
+/// merchant.
+///
+/// Requests made with an API key have also been counted against the key by
+/// [`check_api_key_rate_limit`], for which the owner of the key is cached here.
+#[instrument(skip_all)]
+pub async fn check_merchant_rate_limit(
+    state: &SessionState,
+    flow: &impl router_env::types::FlowMetric,
+    request_headers: &HeaderMap,
+    auth_type: &AuthenticationType,
+) -> Option<RateLimitStatus> {
+    if !state.conf.rate_limit.enabled {
+        return None;
+    }
+
+    if let Some(hashed_api_key) = get_hashed_api_key(state, request_headers) {
+        cache_api_key_owner(state, &hashed_api_key, auth_type)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to cache the owner of the API key"))
+            .ok();
+    }
+
+    let api_identifier = ApiIdentifier::from(Flow::from_str(&flow.to_string()).ok()?);
+    let merchant_id = auth_type.get_merchant_id()?;
+
+    check_rate_limit(
+        state,
+        RateLimitSubject::Merchant(merchant_id),
+        Some(merchant_id),
+        None,
+        &api_identifier,
+    )
+    .await
+}
+
+/// Counts the request against a sliding window maintained in redis and returns whether the
+/// request should be throttled.
+///
+/// Errors while talking to redis are logged and the request is allowed, so that an unavailable
+/// redis does not take the APIs down with it.
+async fn check_rate_limit(
+    state: &SessionState,
+    subject: RateLimitSubject<'_>,
+    merchant_id: Option<&common_utils::id_type::MerchantId>,
+    key_id: Option<&str>,
+    api_identifier: &ApiIdentifier,
+) -> Option<RateLimitStatus> {
+    let rate_limit = get_applicable_rate_limit(state, merchant_id, key_id, api_identifier)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to resolve the applicable rate limit"))
+        .ok()
+        .flatten()?;
+
+    count_request(state, &subject, api_identifier, rate_limit)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to count request for rate limiting"))
+        .ok()
+        .inspect(|status| {
+            if status.is_throttled {
+                metrics::RATE_LIMITED_REQUESTS.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &add_attributes([
+                        ("api", api_identifier.to_string()),
+                        (
+                            "merchant_id",
+                            merchant_id
+                                .map(|merchant_id| merchant_id.get_string_repr().to_owned())
+                                .unwrap_or_default(),
+                        ),
+                    ]),
+                );
+            }
+        })
+}
+
+async fn count_request(
+    state: &SessionState,
+    subject: &RateLimitSubject<'_>,
+    api_identifier: &ApiIdentifier,
+    rate_limit: RateLimit,
+) -> RouterResult<RateLimitStatus> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let window_in_seconds = i64::try_from(rate_limit.window_in_seconds)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Rate limit window is too large")?
+        .max(1);
+    let now = date_time::now_unix_timestamp();
+    let window_index = now / window_in_seconds;
+    let elapsed_in_window = now % window_in_seconds;
+
+    let current_window_key = subject.get_redis_key(api_identifier, window_index);
+    let previous_window_key = subject.get_redis_key(api_identifier, window_index - 1);
+
+    let current_count = redis_conn
+        .increment_fields_in_hash(&current_window_key, &[("count", 1)])
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .first()
+        .copied()
+        .unwrap_or_default();
+
+    // The counter has to outlive the next window, where it is used as the previous window
+    redis_conn
+        .set_expiry(&current_window_key, window_in_seconds * 2)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let previous_count = redis_conn
+        .get_hash_field::<Option<u64>>(&previous_window_key, "count")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .unwrap_or_default();
+
+    Ok(calculate_rate_limit_status(
+        rate_limit,
+        u64::try_from(current_count).unwrap_or(u64::MAX),
+        previous_count,
+        u64::try_from(elapsed_in_window).unwrap_or_default(),
+    ))
+}
+
+/// Approximates the number of requests in the sliding window ending now using the counters of the
+/// current and the previous fixed windows, with the previous window's count weighted by its
+/// overlap with the sliding window.
+fn calculate_rate_limit_status(
+    rate_limit: RateLimit,
+    current_count: u64,
+    previous_count: u64,
+    elapsed_in_window: u64,
+) -> RateLimitStatus {
+    let window = rate_limit.window_in_seconds.max(1);
+    let remaining_in_window = window.saturating_sub(elapsed_in_window);
+    let weighted_count =
+        current_count.saturating_add(previous_count.saturating_mul(remaining_in_window) / window);
+
+    RateLimitStatus {
+        limit: rate_limit.max_requests,
+        remaining: rate_limit.max_requests.saturating_sub(weighted_count),
+        reset_after_seconds: remaining_in_window,
+        is_throttled: weighted_count > rate_limit.max_requests,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE_LIMIT: RateLimit = RateLimit {
+        max_requests: 50,
+        window_in_seconds: 60,
+    };
+
+    #[test]
+    fn test_rate_limit_status_without_previous_window() {
+        let status = calculate_rate_limit_status(RATE_LIMIT, 20, 0, 15);
+
+        assert_eq!(status.limit, 50);
+        assert_eq!(status.remaining, 30);
+        assert_eq!(status.reset_after_seconds, 45);
+        assert!(!status.is_throttled);
+    }
+
+    #[test]
+    fn test_rate_limit_status_weighs_previous_window_by_overlap() {
+        // A quarter into the current window, three quarters of the previous window overlap with
+        // the sliding window
+        let status = calculate_rate_limit_status(RATE_LIMIT, 5, 40, 15);
+
+        assert_eq!(status.remaining, 15);
+        assert!(!status.is_throttled);
+
+        // At the start of the current window, the previous window overlaps entirely
+        let status = calculate_rate_limit_status(RATE_LIMIT, 1, 40, 0);
+
+        assert_eq!(status.remaining, 9);
+        assert_eq!(status.reset_after_seconds, 60);
+    }
+
+    #[test]
+    fn test_rate_limit_status_throttles_requests_beyond_limit() {
+        let at_limit = calculate_rate_limit_status(RATE_LIMIT, 20, 40, 15);
+        assert_eq!(at_limit.remaining, 0);
+        assert!(!at_limit.is_throttled);
+
+        let beyond_limit = calculate_rate_limit_status(RATE_LIMIT, 21, 40, 15);
+        assert_eq!(beyond_limit.remaining, 0);
+        assert!(beyond_limit.is_throttled);
+    }
+
+    #[test]
+    fn test_most_restrictive_rate_limit_status() {
+        let api_key_status = calculate_rate_limit_status(RATE_LIMIT, 10, 0, 15);
+        let merchant_status = calculate_rate_limit_status(RATE_LIMIT, 45, 0, 15);
+
+        let status = api_key_status
+            .clone()
+            .most_restrictive(merchant_status.clone());
+        assert_eq!(status.remaining, 5);
+        assert!(!status.is_throttled);
+
+        // The merchant budget throttles the request, even when the API key has requests left
+        let throttled_merchant_status = calculate_rate_limit_status(RATE_LIMIT, 60, 0, 15);
+        let status = api_key_status.most_restrictive(throttled_merchant_status);
+        assert!(status.is_throttled);
+
+        let throttled_api_key_status = calculate_rate_limit_status(RATE_LIMIT, 51, 0, 15);
+        let status = throttled_api_key_status.most_restrictive(merchant_status);
+        assert!(status.is_throttled);
+    }
+
+    #[test]
+    fn test_rate_limit_status_with_zero_window() {
+        let status = calculate_rate_limit_status(
+            RateLimit {
+                max_requests: 1,
+                window_in_seconds: 0,
+            },
+            2,
+            u64::MAX,
+            0,
+        );
+
+        assert_eq!(status.reset_after_seconds, 1);
+        assert!(status.is_throttled);
+    }
+}
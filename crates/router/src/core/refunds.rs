@@ -1,3 +1,5 @@
+#[cfg(feature = "payouts")]
+pub mod routing;
 pub mod transformers;
 pub mod validator;
 
@@ -48,7 +50,7 @@ use crate::{
 pub async fn refund_create_core(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
-    _profile_id: Option<common_utils::id_type::ProfileId>,
+    profile_id: Option<common_utils::id_type::ProfileId>,
     key_store: domain::MerchantKeyStore,
     req: refunds::RefundRequest,
 ) -> RouterResponse<refunds::RefundResponse> {
@@ -68,6 +70,8 @@ pub async fn refund_create_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
     utils::when(
         !(payment_intent.status == enums::IntentStatus::Succeeded
             || payment_intent.status == enums::IntentStatus::PartiallyCaptured),
@@ -454,7 +458,21 @@ pub async fn refund_retrieve_core(
         })
         .transpose()?;
 
-    let response = if should_call_refund(&refund, request.force_sync.unwrap_or(false)) {
+    // Refunds processed as payouts through another connector are not synced with the connector of
+    // the payment. Such refunds are linked to the merchant connector account of the payout
+    // connector, while refunds created before they were linked to any are processed by the
+    // connector of the payment.
+    let is_routed_through_payment_connector = refund.merchant_connector_id.is_none()
+        || refund.merchant_connector_id == payment_attempt.merchant_connector_id;
+
+    #[cfg(feature = "payouts")]
+    if !is_routed_through_payment_connector {
+        return routing::sync_refund_with_payout(&state, &merchant_account, refund).await;
+    }
+
+    let response = if is_routed_through_payment_connector
+        && should_call_refund(&refund, request.force_sync.unwrap_or(false))
+    {
         sync_refund_with_gateway(
             &state,
             &merchant_account,
@@ -800,6 +818,35 @@ pub async fn validate_and_create_refund(
         .clone()
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("No connector populated in payment attempt")?;
+
+    #[cfg(feature = "payouts")]
+    let refund_payout_connector =
+        if routing::is_refund_unavailable(state, merchant_account, key_store, payment_attempt)
+            .await?
+        {
+            routing::select_refund_payout_connector(
+                state,
+                merchant_account,
+                key_store,
+                payment_intent,
+                payment_attempt,
+            )
+            .await?
+        } else {
+            None
+        };
+
+    #[cfg(feature = "payouts")]
+    let (connector, merchant_connector_id) = match refund_payout_connector.as_ref() {
+        Some(refund_payout_connector) => (
+            refund_payout_connector.connector.to_string(),
+            Some(refund_payout_connector.merchant_connector_id.clone()),
+        ),
+        None => (connector, payment_attempt.merchant_connector_id.clone()),
+    };
+    #[cfg(not(feature = "payouts"))]
+    let merchant_connector_id = payment_attempt.merchant_connector_id.clone();
+
     let refund_create_req = storage::RefundNew {
         refund_id: refund_id.to_string(),
         internal_reference_id: utils::generate_id(consts::ID_LENGTH, "refid"),
@@ -820,7 +867,7 @@ pub async fn validate_and_create_refund(
         attempt_id: payment_attempt.attempt_id.clone(),
         refund_reason: req.reason,
         profile_id: payment_intent.profile_id.clone(),
-        merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+        merchant_connector_id,
         charges: req.charges,
         connector_refund_id: None,
         sent_to_gateway: Default::default(),
@@ -834,6 +881,23 @@ pub async fn validate_and_create_refund(
         .await
     {
         Ok(refund) => {
+            // Refunds processed as payouts are tracked through the payout, instead of the
+            // connector of the payment
+            #[cfg(feature = "payouts")]
+            if let Some(refund_payout_connector) = refund_payout_connector.as_ref() {
+                let refund = Box::pin(routing::create_refund_payout(
+                    state,
+                    merchant_account,
+                    key_store,
+                    payment_intent,
+                    payment_attempt,
+                    refund,
+                    refund_payout_connector,
+                ))
+                .await?;
+                return Ok(refund.foreign_into());
+            }
+
             Box::pin(schedule_refund_execution(
                 state,
                 refund.clone(),
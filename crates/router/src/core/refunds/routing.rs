@@ -0,0 +1,427 @@
+//! Routing of refunds which cannot be processed by the connector of the payment
+//!
+//! When the connector of the payment does not support refunds for the payment method, or its
+//! merchant connector account has been disabled, the refund algorithm linked to the profile
+//! selects a payout connector through which the amount is paid back to the customer instead. The
+//! payout is created unconfirmed, to be confirmed with the payout method of the customer, and the
+//! refund tracks it through its connector refund id. The refund stays pending until the payout
+//! succeeds or fails, which is propagated to the refund as the status of the payout changes.
+
+use std::str::FromStr;
+
+use api_models::{enums as api_enums, payouts as payout_types};
+use common_utils::consts::DEFAULT_LOCALE;
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::logger;
+
+use crate::{
+    core::{
+        errors::{self, utils::StorageErrorExt, RouterResult},
+        payments::routing as payments_routing,
+        payouts, utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+    utils::OptionExt,
+};
+
+/// Key of the refund identifier in the metadata of the payouts created for refunds
+const REFUND_ID_METADATA_KEY: &str = "refund_id";
+
+/// The payout connector selected to process a refund in place of the connector of the payment
+#[derive(Debug, Clone)]
+pub struct RefundPayoutConnector {
+    pub connector: api_enums::PayoutConnectors,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+}
+
+/// Whether the connector of the payment can no longer process refunds for it, as it does not
+/// support refunds for the payment method or its merchant connector account has been disabled or
+/// deleted
+pub async fn is_refund_unavailable(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<bool> {
+    let is_refund_supported = payment_attempt
+        .connector
+        .as_deref()
+        .and_then(|connector| api_enums::Connector::from_str(connector).ok())
+        .is_some_and(|connector| {
+            super::validator::validate_for_valid_refunds(payment_attempt, connector).is_ok()
+        });
+    if !is_refund_supported {
+        return Ok(true);
+    }
+
+    let Some(merchant_connector_id) = payment_attempt.merchant_connector_id.as_ref() else {
+        return Ok(false);
+    };
+
+    match state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_account.get_id(),
+            merchant_connector_id,
+            key_store,
+        )
+        .await
+    {
+        Ok(merchant_connector_account) => Ok(merchant_connector_account.disabled.unwrap_or(false)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(true),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the merchant connector account of the payment"),
+    }
+}
+
+/// Selects the payout connector through which the refund is processed, as per the refund algorithm
+/// of the profile. Returns `None` when the profile has no refund algorithm or none of the selected
+/// connectors can process payouts for the merchant.
+pub async fn select_refund_payout_connector(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<RefundPayoutConnector>> {
+    let Some(profile_id) = payment_intent.profile_id.as_ref() else {
+        return Ok(None);
+    };
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &state.into(),
+        key_store,
+        Some(profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    let Some(connectors) = payments_routing::perform_refund_routing(
+        state,
+        merchant_account,
+        key_store,
+        &business_profile,
+        payment_intent,
+        payment_attempt,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to perform refund routing")?
+    else {
+        return Ok(None);
+    };
+
+    for choice in connectors {
+        let Ok(connector) = api_enums::PayoutConnectors::from_str(&choice.connector.to_string())
+        else {
+            logger::debug!(connector = %choice.connector, "Skipping connector without payouts");
+            continue;
+        };
+
+        let merchant_connector_account = match choice.merchant_connector_id.as_ref() {
+            Some(merchant_connector_id) => {
+                state
+                    .store
+                    .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                        &state.into(),
+                        merchant_account.get_id(),
+                        merchant_connector_id,
+                        key_store,
+                    )
+                    .await
+            }
+            None => {
+                state
+                    .store
+                    .find_merchant_connector_account_by_profile_id_connector_name(
+                        &state.into(),
+                        profile_id,
+                        &connector.to_string(),
+                        key_store,
+                    )
+                    .await
+            }
+        };
+        match merchant_connector_account {
+            Ok(merchant_connector_account)
+                if !merchant_connector_account.disabled.unwrap_or(false) =>
+            {
+                return Ok(Some(RefundPayoutConnector {
+                    connector,
+                    merchant_connector_id: merchant_connector_account.get_id(),
+                }));
+            }
+            _ => continue,
+        }
+    }
+
+    Ok(None)
+}
+
+/// Creates the payout for a refund which has been inserted with the selected payout connector, and
+/// links the payout to the refund through its connector refund id. The payout is created after the
+/// refund, so that duplicate refund requests never leave payouts behind. The refund is failed if
+/// the payout cannot be created.
+pub async fn create_refund_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    refund: storage::Refund,
+    refund_payout_connector: &RefundPayoutConnector,
+) -> RouterResult<storage::Refund> {
+    let payout_create_request = payout_types::PayoutCreateRequest {
+        amount: Some(refund.refund_amount.into()),
+        currency: payment_attempt.currency.or(payment_intent.currency),
+        customer_id: payment_intent.customer_id.clone(),
+        payout_type: Some(api_enums::PayoutType::Bank),
+        connector: Some(vec![refund_payout_connector.connector]),
+        confirm: Some(false),
+        profile_id: payment_intent.profile_id.clone(),
+        description: Some(format!("Refund {}", refund.refund_id)),
+        metadata: Some(masking::Secret::new(serde_json::json!({
+            REFUND_ID_METADATA_KEY: refund.refund_id,
+            "payment_id": payment_intent.payment_id,
+        }))),
+        ..Default::default()
+    };
+
+    let payout_id = Box::pin(payouts::payouts_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        payout_create_request,
+        DEFAULT_LOCALE,
+    ))
+    .await
+    .and_then(|response| match response {
+        services::ApplicationResponse::Json(payout) => Ok(payout.payout_id),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response when creating the payout for the refund"),
+    });
+
+    let (refund_update, payout_result) = match payout_id {
+        Ok(payout_id) => (
+            storage::RefundUpdate::StatusUpdate {
+                connector_refund_id: Some(payout_id),
+                sent_to_gateway: false,
+                refund_status: enums::RefundStatus::Pending,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            Ok(()),
+        ),
+        Err(error) => (
+            storage::RefundUpdate::ErrorUpdate {
+                refund_status: Some(enums::RefundStatus::Failure),
+                refund_error_message: Some(
+                    "Failed to create the payout for the refund".to_string(),
+                ),
+                refund_error_code: None,
+                updated_by: merchant_account.storage_scheme.to_string(),
+                connector_refund_id: None,
+            },
+            Err(error),
+        ),
+    };
+
+    let refund = state
+        .store
+        .update_refund(refund, refund_update, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to link the payout to the refund")?;
+
+    payout_result.map(|()| refund)
+}
+
+/// The status of a refund processed as a payout with the given status, `None` while the payout is
+/// yet to be processed
+pub fn get_refund_status_for_payout(
+    payout_status: api_enums::PayoutStatus,
+) -> Option<enums::RefundStatus> {
+    match payout_status {
+        api_enums::PayoutStatus::Success => Some(enums::RefundStatus::Success),
+        api_enums::PayoutStatus::Failed
+        | api_enums::PayoutStatus::Cancelled
+        | api_enums::PayoutStatus::Expired
+        | api_enums::PayoutStatus::Reversed
+        | api_enums::PayoutStatus::Ineligible => Some(enums::RefundStatus::Failure),
+        api_enums::PayoutStatus::Initiated
+        | api_enums::PayoutStatus::Pending
+        | api_enums::PayoutStatus::RequiresCreation
+        | api_enums::PayoutStatus::RequiresConfirmation
+        | api_enums::PayoutStatus::RequiresPayoutMethodData
+        | api_enums::PayoutStatus::RequiresFulfillment
+        | api_enums::PayoutStatus::RequiresVendorAccountCreation => None,
+    }
+}
+
+/// The refund a payout was created for, from the metadata of the payout
+fn get_refund_id_from_payout(payouts: &storage::Payouts) -> Option<&str> {
+    payouts
+        .metadata
+        .as_ref()?
+        .peek()
+        .get(REFUND_ID_METADATA_KEY)?
+        .as_str()
+}
+
+/// Moves a pending refund processed as a payout to the final status of the payout
+async fn apply_payout_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    refund: storage::Refund,
+    payout_status: api_enums::PayoutStatus,
+) -> RouterResult<storage::Refund> {
+    let Some(refund_status) = get_refund_status_for_payout(payout_status)
+        .filter(|_| refund.refund_status == enums::RefundStatus::Pending)
+    else {
+        return Ok(refund);
+    };
+
+    let refund_update = match refund_status {
+        enums::RefundStatus::Success => storage::RefundUpdate::StatusUpdate {
+            connector_refund_id: refund.connector_refund_id.clone(),
+            sent_to_gateway: true,
+            refund_status,
+            updated_by: merchant_account.storage_scheme.to_string(),
+        },
+        _ => storage::RefundUpdate::ErrorUpdate {
+            refund_status: Some(refund_status),
+            refund_error_message: Some(format!("Payout for the refund is {payout_status}")),
+            refund_error_code: None,
+            updated_by: merchant_account.storage_scheme.to_string(),
+            connector_refund_id: refund.connector_refund_id.clone(),
+        },
+    };
+
+    state
+        .store
+        .update_refund(refund, refund_update, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the refund with the status of its payout")
+}
+
+/// Updates the refund a payout was created for with the status of the payout. Called whenever the
+/// status of a payout changes, be it through the payout APIs or through connector webhooks.
+pub async fn update_refund_from_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payouts: &storage::Payouts,
+    payout_status: api_enums::PayoutStatus,
+) -> RouterResult<()> {
+    // Payouts which are yet to be processed do not change the status of the refund
+    let Some(refund_id) = get_refund_id_from_payout(payouts)
+        .filter(|_| get_refund_status_for_payout(payout_status).is_some())
+    else {
+        return Ok(());
+    };
+
+    let refund = state
+        .store
+        .find_refund_by_merchant_id_refund_id(
+            merchant_account.get_id(),
+            refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    // The metadata of a payout can be updated by the merchant, so that only the payout linked to
+    // the refund can update it
+    if refund.connector_refund_id.as_deref() != Some(payouts.payout_id.as_str()) {
+        logger::warn!(
+            refund_id,
+            payout_id = %payouts.payout_id,
+            "Payout is not linked to the refund in its metadata"
+        );
+        return Ok(());
+    }
+
+    apply_payout_status(state, merchant_account, refund, payout_status)
+        .await
+        .map(|_| ())
+}
+
+/// Syncs a refund processed as a payout with the status of the payout
+pub async fn sync_refund_with_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    refund: storage::Refund,
+) -> RouterResult<storage::Refund> {
+    let Some(payout_id) = refund.connector_refund_id.clone() else {
+        return Ok(refund);
+    };
+
+    let payouts = match state
+        .store
+        .find_payout_by_merchant_id_payout_id(
+            merchant_account.get_id(),
+            &payout_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payouts) => payouts,
+        // The refund is not processed as a payout
+        Err(error) if error.current_context().is_db_not_found() => return Ok(refund),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payout of the refund")
+        }
+    };
+
+    apply_payout_status(state, merchant_account, refund, payouts.status).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refund_status_for_payout() {
+        assert_eq!(
+            get_refund_status_for_payout(api_enums::PayoutStatus::Success),
+            Some(enums::RefundStatus::Success)
+        );
+        for payout_status in [
+            api_enums::PayoutStatus::Failed,
+            api_enums::PayoutStatus::Cancelled,
+            api_enums::PayoutStatus::Expired,
+            api_enums::PayoutStatus::Reversed,
+            api_enums::PayoutStatus::Ineligible,
+        ] {
+            assert_eq!(
+                get_refund_status_for_payout(payout_status),
+                Some(enums::RefundStatus::Failure),
+                "{payout_status}"
+            );
+        }
+        for payout_status in [
+            api_enums::PayoutStatus::RequiresCreation,
+            api_enums::PayoutStatus::RequiresPayoutMethodData,
+            api_enums::PayoutStatus::RequiresFulfillment,
+            api_enums::PayoutStatus::Pending,
+        ] {
+            assert_eq!(
+                get_refund_status_for_payout(payout_status),
+                None,
+                "{payout_status}"
+            );
+        }
+    }
+}
@@ -1,12 +1,30 @@
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod diff;
+#[cfg(feature = "v1")]
+pub mod elimination;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod explanation;
 pub mod helpers;
+pub mod inputs;
+#[cfg(feature = "v1")]
+pub mod least_cost;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod simulation;
 pub mod transformers;
 
+#[cfg(feature = "v1")]
+use std::str::FromStr;
+
 use api_models::{
     enums, mandates as mandates_api, routing,
     routing::{self as routing_types, RoutingRetrieveQuery},
 };
 use diesel_models::routing_algorithm::RoutingAlgorithm;
 use error_stack::ResultExt;
+#[cfg(all(feature = "v1", feature = "olap"))]
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
 use hyperswitch_domain_models::{mandates, payment_address};
 #[cfg(feature = "v1")]
 use router_env::logger;
@@ -39,6 +57,26 @@ use crate::{
     utils::{self, OptionExt},
 };
 
+/// The number of payments replayed by a routing simulation when no limit is specified
+#[cfg(all(feature = "v1", feature = "olap"))]
+const SIMULATION_DEFAULT_LIMIT: u32 = 1000;
+
+/// The maximum number of payments replayed by a single routing simulation
+#[cfg(all(feature = "v1", feature = "olap"))]
+const SIMULATION_MAX_LIMIT: u32 = 10000;
+
+/// The number of payments considered for estimating connector costs when no limit is specified
+#[cfg(all(feature = "v1", feature = "olap"))]
+const COST_ESTIMATE_DEFAULT_LIMIT: u32 = 1000;
+
+/// The maximum number of payments considered by a single connector cost estimation
+#[cfg(all(feature = "v1", feature = "olap"))]
+const COST_ESTIMATE_MAX_LIMIT: u32 = 10000;
+
+/// The number of activation history records returned when no limit is specified
+#[cfg(feature = "v1")]
+const ACTIVATION_HISTORY_DEFAULT_LIMIT: i64 = 20;
+
 pub enum TransactionData<'a> {
     Payment(PaymentsDslInput<'a>),
     #[cfg(feature = "payouts")]
@@ -54,6 +92,7 @@ pub struct PaymentsDslInput<'a> {
     pub address: &'a payment_address::PaymentAddress,
     pub recurring_details: Option<&'a mandates_api::RecurringDetails>,
     pub currency: storage_enums::Currency,
+    pub additional_inputs: inputs::AdditionalRoutingInputs,
 }
 
 impl<'a> PaymentsDslInput<'a> {
@@ -65,6 +104,7 @@ impl<'a> PaymentsDslInput<'a> {
         address: &'a payment_address::PaymentAddress,
         recurring_details: Option<&'a mandates_api::RecurringDetails>,
         currency: storage_enums::Currency,
+        additional_inputs: inputs::AdditionalRoutingInputs,
     ) -> Self {
         Self {
             setup_mandate,
@@ -74,6 +114,7 @@ impl<'a> PaymentsDslInput<'a> {
             address,
             recurring_details,
             currency,
+            additional_inputs,
         }
     }
 }
@@ -311,6 +352,48 @@ pub async fn create_routing_algorithm_under_profile(
     Ok(service_api::ApplicationResponse::Json(new_record))
 }
 
+#[cfg(feature = "v1")]
+pub async fn create_routing_algorithm_from_dsl(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingDslConfigRequest,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    let (program, source_map) = euclid::frontend::ast::parser::parse_program_with_source_map::<
+        routing_types::ConnectorSelection,
+    >(&request.program)
+    .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("Invalid routing program: {error}"),
+    })?;
+
+    // The program is analyzed with the positions of its comparisons for them to be reported
+    // along with any errors, while it is persisted without them
+    euclid::dssa::analyzer::analyze(source_map.annotate(&program), None).map_err(|error| {
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid routing program: {error}"),
+        }
+    })?;
+
+    let request = routing_types::RoutingConfigRequest {
+        name: request.name,
+        description: request.description,
+        algorithm: Some(routing_types::RoutingAlgorithm::Advanced(program)),
+        profile_id: request.profile_id,
+    };
+
+    Box::pin(create_routing_algorithm_under_profile(
+        state,
+        merchant_account,
+        key_store,
+        authentication_profile_id,
+        request,
+        transaction_type,
+    ))
+    .await
+}
+
 #[cfg(feature = "v2")]
 pub async fn link_routing_config_under_profile(
     state: SessionState,
@@ -389,6 +472,7 @@ pub async fn link_routing_config(
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     algorithm_id: common_utils::id_type::RoutingId,
     transaction_type: &enums::TransactionType,
+    activated_by: Option<String>,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_LINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
@@ -488,6 +572,15 @@ pub async fn link_routing_config(
                     })
                 },
             )?;
+            // A shadow algorithm which is activated is no longer evaluated as the shadow
+            if routing_ref.shadow_algorithm_id.as_ref() == Some(&algorithm_id) {
+                routing_ref.update_shadow_algorithm_id(None);
+            }
+            let routing_activation = helpers::RoutingActivation {
+                activation_type: storage_enums::RoutingActivationType::Activation,
+                previous_algorithm_id: routing_ref.algorithm_id.clone(),
+                activated_by,
+            };
             routing_ref.update_algorithm_id(algorithm_id);
             helpers::update_profile_active_algorithm_ref(
                 db,
@@ -496,6 +589,7 @@ pub async fn link_routing_config(
                 business_profile,
                 routing_ref,
                 transaction_type,
+                Some(routing_activation),
             )
             .await?;
         }
@@ -507,150 +601,267 @@ pub async fn link_routing_config(
     ))
 }
 
-#[cfg(feature = "v2")]
-pub async fn retrieve_routing_algorithm_from_algorithm_id(
+#[cfg(feature = "v1")]
+pub async fn link_shadow_routing_config(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     algorithm_id: common_utils::id_type::RoutingId,
-) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
-    metrics::ROUTING_RETRIEVE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
-    let routing_algorithm =
-        RoutingAlgorithmUpdate::fetch_routing_algo(merchant_account.get_id(), &algorithm_id, db)
-            .await?;
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_account.get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
     let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         &key_store,
-        Some(&routing_algorithm.0.profile_id),
+        Some(&routing_algorithm.profile_id),
         merchant_account.get_id(),
     )
     .await?
     .get_required_value("Profile")
-    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: routing_algorithm.profile_id.get_string_repr().to_owned(),
+    })?;
 
     core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm.0)
+    utils::when(
+        routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Dynamic routing algorithms cannot be used as shadow algorithms"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    utils::when(
+        routing_algorithm.algorithm_for != enums::TransactionType::Payment,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Shadow routing is only supported for payments".to_string(),
+            })
+        },
+    )?;
+
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("unable to parse routing algorithm")?;
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .unwrap_or_default();
 
-    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-    Ok(service_api::ApplicationResponse::Json(response))
+    utils::when(
+        routing_ref.algorithm_id.as_ref() == Some(&algorithm_id),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Algorithm is already active".to_string(),
+            })
+        },
+    )?;
+
+    utils::when(
+        routing_ref.shadow_algorithm_id.as_ref() == Some(&algorithm_id),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Algorithm is already the shadow algorithm".to_string(),
+            })
+        },
+    )?;
+
+    routing_ref.update_shadow_algorithm_id(Some(algorithm_id));
+    helpers::update_profile_active_algorithm_ref(
+        db,
+        key_manager_state,
+        &key_store,
+        business_profile,
+        routing_ref,
+        &enums::TransactionType::Payment,
+        None,
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_algorithm.foreign_into(),
+    ))
 }
 
 #[cfg(feature = "v1")]
-pub async fn retrieve_routing_algorithm_from_algorithm_id(
+pub async fn unlink_shadow_routing_config(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    request: routing_types::RoutingConfigRequest,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
-    algorithm_id: common_utils::id_type::RoutingId,
-) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
-    metrics::ROUTING_RETRIEVE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
-    let routing_algorithm = db
-        .find_routing_algorithm_by_algorithm_id_merchant_id(
-            &algorithm_id,
-            merchant_account.get_id(),
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    let profile_id = request
+        .profile_id
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })
+        .attach_printable("Profile_id not provided")?;
 
     let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         &key_store,
-        Some(&routing_algorithm.profile_id),
+        Some(&profile_id),
         merchant_account.get_id(),
     )
     .await?
-    .get_required_value("Profile")
-    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    .get_required_value("Profile")?;
 
     core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm)
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("unable to parse routing algorithm")?;
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .unwrap_or_default();
 
-    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-    Ok(service_api::ApplicationResponse::Json(response))
+    let algorithm_id = routing_ref.shadow_algorithm_id.clone().ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "No shadow algorithm is linked to the profile".to_string(),
+        },
+    )?;
+
+    let record = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algorithm_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    routing_ref.update_shadow_algorithm_id(None);
+    helpers::update_profile_active_algorithm_ref(
+        db,
+        key_manager_state,
+        &key_store,
+        business_profile,
+        routing_ref,
+        &enums::TransactionType::Payment,
+        None,
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        record.foreign_into(),
+    ))
 }
 
-#[cfg(feature = "v2")]
-pub async fn unlink_routing_config_under_profile(
+#[cfg(feature = "v1")]
+pub async fn link_fallback_routing_config(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
-    profile_id: common_utils::id_type::ProfileId,
-    transaction_type: &enums::TransactionType,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+    fallback_kind: routing_types::FallbackRoutingKind,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
-    metrics::ROUTING_UNLINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
+    let transaction_type = fallback_kind.get_transaction_type();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_account.get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
     let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         &key_store,
-        Some(&profile_id),
+        Some(&routing_algorithm.profile_id),
         merchant_account.get_id(),
     )
     .await?
-    .get_required_value("Profile")?;
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: routing_algorithm.profile_id.get_string_repr().to_owned(),
+    })?;
 
-    let routing_algo_id = match transaction_type {
-        enums::TransactionType::Payment => business_profile.routing_algorithm_id.clone(),
-        #[cfg(feature = "payouts")]
-        enums::TransactionType::Payout => business_profile.payout_routing_algorithm_id.clone(),
-    };
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    if let Some(algorithm_id) = routing_algo_id {
-        let record = RoutingAlgorithmUpdate::fetch_routing_algo(
-            merchant_account.get_id(),
-            &algorithm_id,
-            db,
-        )
-        .await?;
-        let response = record.0.foreign_into();
-        admin::ProfileWrapper::new(business_profile)
-            .update_profile_and_invalidate_routing_config_for_active_algorithm_id_update(
-                db,
-                key_manager_state,
-                &key_store,
-                algorithm_id,
-                transaction_type,
-            )
-            .await?;
-        metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-        Ok(service_api::ApplicationResponse::Json(response))
-    } else {
+    utils::when(
+        routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Dynamic routing algorithms cannot be used for {fallback_kind} routing"
+                ),
+            })
+        },
+    )?;
+
+    utils::when(routing_algorithm.algorithm_for != transaction_type, || {
         Err(errors::ApiErrorResponse::PreconditionFailed {
-            message: "Algorithm is already inactive".to_string(),
-        })?
-    }
+            message: format!(
+                "Cannot use {}'s routing algorithm for {fallback_kind} routing",
+                routing_algorithm.algorithm_for
+            ),
+        })
+    })?;
+
+    let mut routing_ref = get_profile_routing_algorithm_ref(&business_profile, &transaction_type)?;
+
+    utils::when(
+        routing_ref.get_fallback_algorithm_id(fallback_kind) == Some(&algorithm_id),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Algorithm is already the {fallback_kind} algorithm"),
+            })
+        },
+    )?;
+
+    routing_ref.update_fallback_algorithm_id(fallback_kind, Some(algorithm_id));
+    helpers::update_profile_active_algorithm_ref(
+        db,
+        key_manager_state,
+        &key_store,
+        business_profile,
+        routing_ref,
+        &transaction_type,
+        None,
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_algorithm.foreign_into(),
+    ))
 }
 
 #[cfg(feature = "v1")]
-pub async fn unlink_routing_config(
+pub async fn unlink_fallback_routing_config(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     request: routing_types::RoutingConfigRequest,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
-    transaction_type: &enums::TransactionType,
+    fallback_kind: routing_types::FallbackRoutingKind,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
-    metrics::ROUTING_UNLINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
-
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
+    let transaction_type = fallback_kind.get_transaction_type();
 
     let profile_id = request
         .profile_id
@@ -667,426 +878,1841 @@ pub async fn unlink_routing_config(
         Some(&profile_id),
         merchant_account.get_id(),
     )
-    .await?;
+    .await?
+    .get_required_value("Profile")?;
 
-    match business_profile {
-        Some(business_profile) => {
-            core_utils::validate_profile_id_from_auth_layer(
-                authentication_profile_id,
-                &business_profile,
-            )?;
-            let routing_algo_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
-                enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
-                #[cfg(feature = "payouts")]
-                enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
-            }
-            .map(|val| val.parse_value("RoutingAlgorithmRef"))
-            .transpose()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
-            .unwrap_or_default();
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-            let timestamp = common_utils::date_time::now_unix_timestamp();
-
-            match routing_algo_ref.algorithm_id {
-                Some(algorithm_id) => {
-                    let routing_algorithm: routing_types::RoutingAlgorithmRef =
-                        routing_types::RoutingAlgorithmRef {
-                            algorithm_id: None,
-                            timestamp,
-                            config_algo_id: routing_algo_ref.config_algo_id.clone(),
-                            surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
-                        };
+    let mut routing_ref = get_profile_routing_algorithm_ref(&business_profile, &transaction_type)?;
 
-                    let record = db
-                        .find_routing_algorithm_by_profile_id_algorithm_id(
-                            &profile_id,
-                            &algorithm_id,
-                        )
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
-                    let response = record.foreign_into();
-                    helpers::update_profile_active_algorithm_ref(
-                        db,
-                        key_manager_state,
-                        &key_store,
-                        business_profile,
-                        routing_algorithm,
-                        transaction_type,
-                    )
-                    .await?;
+    let algorithm_id = routing_ref
+        .get_fallback_algorithm_id(fallback_kind)
+        .cloned()
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("No {fallback_kind} algorithm is linked to the profile"),
+        })?;
 
-                    metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-                    Ok(service_api::ApplicationResponse::Json(response))
-                }
-                None => Err(errors::ApiErrorResponse::PreconditionFailed {
-                    message: "Algorithm is already inactive".to_string(),
-                })?,
-            }
-        }
-        None => Err(errors::ApiErrorResponse::InvalidRequestData {
-            message: "The business_profile is not present".to_string(),
-        }
-        .into()),
-    }
-}
+    let record = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algorithm_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-#[cfg(feature = "v2")]
-pub async fn update_default_fallback_routing(
-    state: SessionState,
-    merchant_account: domain::MerchantAccount,
-    key_store: domain::MerchantKeyStore,
-    profile_id: common_utils::id_type::ProfileId,
-    updated_list_of_connectors: Vec<routing_types::RoutableConnectorChoice>,
-) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
-    metrics::ROUTING_UPDATE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
-    let db = state.store.as_ref();
-    let key_manager_state = &(&state).into();
-    let profile = core_utils::validate_and_get_business_profile(
+    routing_ref.update_fallback_algorithm_id(fallback_kind, None);
+    helpers::update_profile_active_algorithm_ref(
         db,
         key_manager_state,
         &key_store,
-        Some(&profile_id),
-        merchant_account.get_id(),
+        business_profile,
+        routing_ref,
+        &transaction_type,
+        None,
     )
-    .await?
-    .get_required_value("Profile")?;
-    let profile_wrapper = admin::ProfileWrapper::new(profile);
-    let default_list_of_connectors =
-        profile_wrapper.get_default_fallback_list_of_connector_under_profile()?;
-
-    utils::when(
-        default_list_of_connectors.len() != updated_list_of_connectors.len(),
-        || {
-            Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "current config and updated config have different lengths".to_string(),
-            })
-        },
-    )?;
-
-    let existing_set_of_default_connectors: FxHashSet<String> = FxHashSet::from_iter(
-        default_list_of_connectors
-            .iter()
-            .map(|conn_choice| conn_choice.to_string()),
-    );
-    let updated_set_of_default_connectors: FxHashSet<String> = FxHashSet::from_iter(
-        updated_list_of_connectors
-            .iter()
-            .map(|conn_choice| conn_choice.to_string()),
-    );
-
-    let symmetric_diff_between_existing_and_updated_connectors: Vec<String> =
-        existing_set_of_default_connectors
-            .symmetric_difference(&updated_set_of_default_connectors)
-            .cloned()
-            .collect();
-
-    utils::when(
-        !symmetric_diff_between_existing_and_updated_connectors.is_empty(),
-        || {
-            Err(errors::ApiErrorResponse::InvalidRequestData {
-                message: format!(
-                    "connector mismatch between old and new configs ({})",
-                    symmetric_diff_between_existing_and_updated_connectors.join(", ")
-                ),
-            })
-        },
-    )?;
-    profile_wrapper
-        .update_default_fallback_routing_of_connectors_under_profile(
-            db,
-            &updated_list_of_connectors,
-            key_manager_state,
-            &key_store,
-        )
-        .await?;
+    .await?;
 
-    metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
     Ok(service_api::ApplicationResponse::Json(
-        updated_list_of_connectors,
+        record.foreign_into(),
     ))
 }
 
+/// The routing algorithm ref of the profile for the transaction type
 #[cfg(feature = "v1")]
-pub async fn update_default_routing_config(
-    state: SessionState,
-    merchant_account: domain::MerchantAccount,
-    updated_config: Vec<routing_types::RoutableConnectorChoice>,
+fn get_profile_routing_algorithm_ref(
+    business_profile: &domain::Profile,
     transaction_type: &enums::TransactionType,
-) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
-    metrics::ROUTING_UPDATE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
-    let db = state.store.as_ref();
-    let default_config = helpers::get_merchant_default_config(
-        db,
-        merchant_account.get_id().get_string_repr(),
-        transaction_type,
-    )
-    .await?;
-
-    utils::when(default_config.len() != updated_config.len(), || {
-        Err(errors::ApiErrorResponse::PreconditionFailed {
-            message: "current config and updated config have different lengths".to_string(),
-        })
-    })?;
-
-    let existing_set: FxHashSet<String> =
-        FxHashSet::from_iter(default_config.iter().map(|c| c.to_string()));
-    let updated_set: FxHashSet<String> =
-        FxHashSet::from_iter(updated_config.iter().map(|c| c.to_string()));
-
-    let symmetric_diff: Vec<String> = existing_set
-        .symmetric_difference(&updated_set)
-        .cloned()
-        .collect();
-
-    utils::when(!symmetric_diff.is_empty(), || {
-        Err(errors::ApiErrorResponse::InvalidRequestData {
-            message: format!(
-                "connector mismatch between old and new configs ({})",
-                symmetric_diff.join(", ")
-            ),
-        })
-    })?;
-
-    helpers::update_merchant_default_config(
-        db,
-        merchant_account.get_id().get_string_repr(),
-        updated_config.clone(),
-        transaction_type,
-    )
-    .await?;
+) -> errors::RouterResult<routing_types::RoutingAlgorithmRef> {
+    let routing_algorithm = match transaction_type {
+        enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+        #[cfg(feature = "payouts")]
+        enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
+    };
 
-    metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-    Ok(service_api::ApplicationResponse::Json(updated_config))
+    Ok(routing_algorithm
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .unwrap_or_default())
 }
 
 #[cfg(feature = "v2")]
-pub async fn retrieve_default_fallback_algorithm_for_profile(
+pub async fn retrieve_routing_algorithm_from_algorithm_id(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
-    profile_id: common_utils::id_type::ProfileId,
-) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
-    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
+    metrics::ROUTING_RETRIEVE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
-    let profile = core_utils::validate_and_get_business_profile(
+
+    let routing_algorithm =
+        RoutingAlgorithmUpdate::fetch_routing_algo(merchant_account.get_id(), &algorithm_id, db)
+            .await?;
+    let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         &key_store,
-        Some(&profile_id),
+        Some(&routing_algorithm.0.profile_id),
         merchant_account.get_id(),
     )
     .await?
-    .get_required_value("Profile")?;
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let connectors_choice = admin::ProfileWrapper::new(profile)
-        .get_default_fallback_list_of_connector_under_profile()?;
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-    Ok(service_api::ApplicationResponse::Json(connectors_choice))
+    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm.0)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")?;
+
+    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 #[cfg(feature = "v1")]
-
-pub async fn retrieve_default_routing_config(
-    state: SessionState,
-    merchant_account: domain::MerchantAccount,
-    transaction_type: &enums::TransactionType,
-) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
-    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG.add(&metrics::CONTEXT, 1, &[]);
-    let db = state.store.as_ref();
-
-    helpers::get_merchant_default_config(
-        db,
-        merchant_account.get_id().get_string_repr(),
-        transaction_type,
-    )
-    .await
-    .map(|conn_choice| {
-        metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-        service_api::ApplicationResponse::Json(conn_choice)
-    })
-}
-#[cfg(feature = "v2")]
-pub async fn retrieve_routing_config_under_profile(
+pub async fn retrieve_routing_algorithm_from_algorithm_id(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
-    query_params: RoutingRetrieveQuery,
-    profile_id: common_utils::id_type::ProfileId,
-    transaction_type: &enums::TransactionType,
-) -> RouterResponse<routing_types::LinkedRoutingConfigRetrieveResponse> {
-    metrics::ROUTING_RETRIEVE_LINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
+    metrics::ROUTING_RETRIEVE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_account.get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
     let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         &key_store,
-        Some(&profile_id),
+        Some(&routing_algorithm.profile_id),
         merchant_account.get_id(),
     )
     .await?
-    .get_required_value("Profile")?;
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let record = db
-        .list_routing_algorithm_metadata_by_profile_id(
-            business_profile.get_id(),
-            i64::from(query_params.limit.unwrap_or_default()),
-            i64::from(query_params.offset.unwrap_or_default()),
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    let active_algorithms = record
-        .into_iter()
-        .filter(|routing_rec| &routing_rec.algorithm_for == transaction_type)
-        .map(|routing_algo| routing_algo.foreign_into())
+    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")?;
+
+    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_routing_algorithm_dsl(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::RoutingDslResponse> {
+    metrics::ROUTING_RETRIEVE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_account.get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&routing_algorithm.profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let merchant_routing_algorithm =
+        routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to parse routing algorithm")?;
+
+    let program = match merchant_routing_algorithm.algorithm {
+        routing_types::RoutingAlgorithm::Advanced(program) => program,
+        routing_types::RoutingAlgorithm::Single(_)
+        | routing_types::RoutingAlgorithm::Priority(_)
+        | routing_types::RoutingAlgorithm::VolumeSplit(_) => {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Only advanced routing algorithms can be represented in the routing DSL"
+                    .to_string(),
+            })?
+        }
+    };
+
+    let printed_program = euclid::frontend::ast::printer::print_program(&program);
+    let omitted_metadata = printed_program
+        .omitted_metadata
+        .iter()
+        .map(ToString::to_string)
         .collect::<Vec<_>>();
+    if !omitted_metadata.is_empty() {
+        logger::warn!(
+            ?omitted_metadata,
+            "Metadata of the routing algorithm was omitted from its routing DSL representation"
+        );
+    }
 
-    metrics::ROUTING_RETRIEVE_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
     Ok(service_api::ApplicationResponse::Json(
-        routing_types::LinkedRoutingConfigRetrieveResponse::ProfileBased(active_algorithms),
+        routing_types::RoutingDslResponse {
+            id: merchant_routing_algorithm.id,
+            profile_id: merchant_routing_algorithm.profile_id,
+            name: merchant_routing_algorithm.name,
+            description: merchant_routing_algorithm.description,
+            program: printed_program.text,
+            omitted_metadata,
+        },
+    ))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn simulate_routing_algorithm(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&request.profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let algorithm = match (request.algorithm, request.algorithm_id) {
+        (Some(algorithm), None) => {
+            helpers::validate_connectors_in_routing_config(
+                &state,
+                &key_store,
+                merchant_account.get_id(),
+                &request.profile_id,
+                &algorithm,
+            )
+            .await?;
+
+            if let routing_types::RoutingAlgorithm::Advanced(program) = &algorithm {
+                euclid::dssa::analyzer::analyze(program.clone(), None).map_err(|error| {
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!("Invalid routing program: {error}"),
+                    }
+                })?;
+            }
+            algorithm
+        }
+        (None, Some(algorithm_id)) => {
+            let routing_algorithm = db
+                .find_routing_algorithm_by_profile_id_algorithm_id(
+                    &request.profile_id,
+                    &algorithm_id,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+            routing_algorithm
+                .algorithm_data
+                .parse_value("RoutingAlgorithm")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("unable to parse routing algorithm")?
+        }
+        _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Exactly one of algorithm and algorithm_id must be provided".to_string(),
+        })?,
+    };
+
+    let algorithm = simulation::SimulatedAlgorithm::new(algorithm)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to initialize routing algorithm for simulation")?;
+
+    let limit = request
+        .limit
+        .unwrap_or(SIMULATION_DEFAULT_LIMIT)
+        .clamp(1, SIMULATION_MAX_LIMIT);
+    let additional_inputs = inputs::AdditionalRoutingInputs::from_config(
+        &inputs::get_routing_inputs_config(db, &request.profile_id).await,
+    );
+    let mut summary = simulation::SimulationSummary::default();
+    let mut offset = 0;
+    while offset < limit {
+        let batch_size = std::cmp::min(
+            limit - offset,
+            common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1,
+        );
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset,
+            starting_at: Some(request.time_range.start_time),
+            ending_at: request.time_range.end_time,
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: None,
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: Some(vec![request.profile_id.clone()]),
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(batch_size),
+            order: Default::default(),
+        }));
+
+        let payments = db
+            .get_filtered_payment_intents_attempt(
+                key_manager_state,
+                merchant_account.get_id(),
+                &constraints,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payments for routing simulation")?;
+
+        for (payment_intent, payment_attempt) in &payments {
+            summary
+                .replay_payment(
+                    &state,
+                    &key_store,
+                    &algorithm,
+                    &request.profile_id,
+                    &additional_inputs,
+                    payment_intent,
+                    payment_attempt,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed to replay payment {} through the routing algorithm",
+                        payment_intent.payment_id.get_string_repr()
+                    )
+                })?;
+        }
+
+        if payments.len() < usize::try_from(batch_size).unwrap_or(usize::MAX) {
+            break;
+        }
+        offset += batch_size;
+    }
+
+    Ok(service_api::ApplicationResponse::Json(summary.into()))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn explain_connector_eligibility(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::EligibilityExplanationRequest,
+) -> RouterResponse<routing_types::EligibilityExplanationResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&request.profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let backend_input = match (request.payment_id, request.backend_input) {
+        (Some(payment_id), None) => {
+            let payment_intent = db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    key_manager_state,
+                    &payment_id,
+                    merchant_account.get_id(),
+                    &key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            utils::when(
+                payment_intent.profile_id.as_ref() != Some(&request.profile_id),
+                || Err(errors::ApiErrorResponse::PaymentNotFound),
+            )?;
+
+            let payment_attempt = db
+                .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                    &payment_intent.payment_id,
+                    merchant_account.get_id(),
+                    &payment_intent.active_attempt.get_id(),
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            let additional_inputs = inputs::AdditionalRoutingInputs::from_config(
+                &inputs::get_routing_inputs_config(db, &request.profile_id).await,
+            );
+
+            simulation::make_backend_input(&payment_intent, &payment_attempt, &additional_inputs)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to construct routing inputs of the payment")?
+                .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "The payment was created without a currency".to_string(),
+                })?
+        }
+        (None, Some(backend_input)) => backend_input,
+        _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Exactly one of payment_id and backend_input must be provided".to_string(),
+        })?,
+    };
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_account.get_id(),
+            false,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant connector accounts")?;
+    let merchant_connector_accounts =
+        super::payments::helpers::filter_mca_based_on_profile_and_connector_type(
+            merchant_connector_accounts,
+            &request.profile_id,
+            common_enums::ConnectorType::PaymentProcessor,
+        )
+        .into_iter()
+        .map(api_models::admin::MerchantConnectorResponse::foreign_try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert merchant connector accounts")?;
+
+    let cgraph = super::payments::routing::get_merchant_cgraph(
+        &state,
+        &key_store,
+        &request.profile_id,
+        &enums::TransactionType::Payment,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the constraint graph of the profile")?;
+
+    let explanations = explanation::explain_connectors(
+        &cgraph,
+        &merchant_connector_accounts,
+        backend_input,
+        request.connectors.as_deref(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to explain the eligibility of the connectors")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::EligibilityExplanationResponse {
+            profile_id: request.profile_id,
+            explanations,
+            graph: request
+                .include_graph
+                .then(|| cgraph.get_viz_digraph_string()),
+        },
     ))
 }
 
+#[cfg(feature = "v2")]
+pub async fn unlink_routing_config_under_profile(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_UNLINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    let routing_algo_id = match transaction_type {
+        enums::TransactionType::Payment => business_profile.routing_algorithm_id.clone(),
+        #[cfg(feature = "payouts")]
+        enums::TransactionType::Payout => business_profile.payout_routing_algorithm_id.clone(),
+    };
+
+    if let Some(algorithm_id) = routing_algo_id {
+        let record = RoutingAlgorithmUpdate::fetch_routing_algo(
+            merchant_account.get_id(),
+            &algorithm_id,
+            db,
+        )
+        .await?;
+        let response = record.0.foreign_into();
+        admin::ProfileWrapper::new(business_profile)
+            .update_profile_and_invalidate_routing_config_for_active_algorithm_id_update(
+                db,
+                key_manager_state,
+                &key_store,
+                algorithm_id,
+                transaction_type,
+            )
+            .await?;
+        metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+        Ok(service_api::ApplicationResponse::Json(response))
+    } else {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Algorithm is already inactive".to_string(),
+        })?
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn unlink_routing_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: routing_types::RoutingConfigRequest,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    transaction_type: &enums::TransactionType,
+    activated_by: Option<String>,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_UNLINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let profile_id = request
+        .profile_id
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })
+        .attach_printable("Profile_id not provided")?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    match business_profile {
+        Some(business_profile) => {
+            core_utils::validate_profile_id_from_auth_layer(
+                authentication_profile_id,
+                &business_profile,
+            )?;
+            let routing_algo_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
+                enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+                #[cfg(feature = "payouts")]
+                enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
+            }
+            .map(|val| val.parse_value("RoutingAlgorithmRef"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
+            .unwrap_or_default();
+
+            let timestamp = common_utils::date_time::now_unix_timestamp();
+
+            match routing_algo_ref.algorithm_id {
+                Some(algorithm_id) => {
+                    let routing_algorithm: routing_types::RoutingAlgorithmRef =
+                        routing_types::RoutingAlgorithmRef {
+                            algorithm_id: None,
+                            timestamp,
+                            config_algo_id: routing_algo_ref.config_algo_id.clone(),
+                            surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                            shadow_algorithm_id: routing_algo_ref.shadow_algorithm_id,
+                            refund_algorithm_id: routing_algo_ref.refund_algorithm_id,
+                            retry_algorithm_id: routing_algo_ref.retry_algorithm_id,
+                        };
+
+                    let record = db
+                        .find_routing_algorithm_by_profile_id_algorithm_id(
+                            &profile_id,
+                            &algorithm_id,
+                        )
+                        .await
+                        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+                    let response = record.foreign_into();
+                    let routing_activation = helpers::RoutingActivation {
+                        activation_type: storage_enums::RoutingActivationType::Deactivation,
+                        previous_algorithm_id: Some(algorithm_id),
+                        activated_by,
+                    };
+                    helpers::update_profile_active_algorithm_ref(
+                        db,
+                        key_manager_state,
+                        &key_store,
+                        business_profile,
+                        routing_algorithm,
+                        transaction_type,
+                        Some(routing_activation),
+                    )
+                    .await?;
+
+                    metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+                    Ok(service_api::ApplicationResponse::Json(response))
+                }
+                None => Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Algorithm is already inactive".to_string(),
+                })?,
+            }
+        }
+        None => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The business_profile is not present".to_string(),
+        }
+        .into()),
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_routing_activation_history(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    query_params: routing_types::RoutingActivationHistoryQuery,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<routing_types::RoutingActivationHistoryResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let profile_id = query_params
+        .profile_id
+        .or(authentication_profile_id.clone())
+        .get_required_value("profile_id")?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let activations = db
+        .list_routing_activation_history_by_profile_id(
+            business_profile.get_id(),
+            transaction_type,
+            query_params
+                .limit
+                .map_or(ACTIVATION_HISTORY_DEFAULT_LIMIT, i64::from),
+            i64::from(query_params.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve routing activation history")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingActivationHistoryResponse {
+            activations: activations
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        },
+    ))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn diff_routing_algorithms(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    query_params: routing_types::RoutingAlgorithmDiffQuery,
+) -> RouterResponse<routing_types::RoutingAlgorithmDiff> {
+    let db = state.store.as_ref();
+
+    let mut algorithms = Vec::with_capacity(2);
+    for algorithm_id in [
+        &query_params.from_algorithm_id,
+        &query_params.to_algorithm_id,
+    ] {
+        let routing_algorithm = db
+            .find_routing_algorithm_by_algorithm_id_merchant_id(
+                algorithm_id,
+                merchant_account.get_id(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+        core_utils::validate_profile_id_from_auth_layer(
+            authentication_profile_id.clone(),
+            &routing_algorithm,
+        )?;
+
+        utils::when(
+            routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Dynamic routing algorithms cannot be compared".to_string(),
+                })
+            },
+        )?;
+
+        let algorithm = routing_algorithm
+            .algorithm_data
+            .parse_value::<routing_types::RoutingAlgorithm>("RoutingAlgorithm")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to parse routing algorithm")?;
+        algorithms.push(algorithm);
+    }
+
+    let to_algorithm = algorithms
+        .pop()
+        .get_required_value("to_algorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let from_algorithm = algorithms
+        .pop()
+        .get_required_value("from_algorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        diff::diff_routing_algorithms(
+            query_params.from_algorithm_id,
+            from_algorithm,
+            query_params.to_algorithm_id,
+            to_algorithm,
+        ),
+    ))
+}
+
+/// Activates the routing algorithm which was active before the most recent change recorded in the
+/// activation history of the profile. Rolling back twice hence re-activates the algorithm which
+/// was rolled back.
+#[cfg(feature = "v1")]
+pub async fn rollback_routing_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: routing_types::RoutingRollbackRequest,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    transaction_type: &enums::TransactionType,
+    activated_by: Option<String>,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let profile_id = request
+        .profile_id
+        .or(authentication_profile_id.clone())
+        .get_required_value("profile_id")?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
+        enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+        #[cfg(feature = "payouts")]
+        enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
+    }
+    .map(|val| val.parse_value("RoutingAlgorithmRef"))
+    .transpose()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+    .unwrap_or_default();
+
+    let latest_activation = db
+        .list_routing_activation_history_by_profile_id(&profile_id, transaction_type, 1, 0)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve routing activation history")?
+        .into_iter()
+        .next()
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No change of the active routing algorithm has been recorded".to_string(),
+        })?;
+
+    // The profile may have been updated without being recorded in the history, in which case the
+    // previous algorithm of the latest record is not the one active before the current one
+    utils::when(
+        latest_activation.algorithm_id != routing_ref.algorithm_id,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The active routing algorithm does not match the latest activation \
+                      history record"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let algorithm_id = latest_activation.previous_algorithm_id.ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "No routing algorithm was active before the latest change".to_string(),
+        },
+    )?;
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algorithm_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let routing_activation = helpers::RoutingActivation {
+        activation_type: storage_enums::RoutingActivationType::Rollback,
+        previous_algorithm_id: routing_ref.algorithm_id.clone(),
+        activated_by,
+    };
+    // A shadow algorithm which is activated is no longer evaluated as the shadow
+    if routing_ref.shadow_algorithm_id.as_ref() == Some(&algorithm_id) {
+        routing_ref.update_shadow_algorithm_id(None);
+    }
+    routing_ref.update_algorithm_id(algorithm_id);
+    helpers::update_profile_active_algorithm_ref(
+        db,
+        key_manager_state,
+        &key_store,
+        business_profile,
+        routing_ref,
+        transaction_type,
+        Some(routing_activation),
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_algorithm.foreign_into(),
+    ))
+}
+
+#[cfg(feature = "v2")]
+pub async fn update_default_fallback_routing(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    updated_list_of_connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
+    metrics::ROUTING_UPDATE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+    let profile_wrapper = admin::ProfileWrapper::new(profile);
+    let default_list_of_connectors =
+        profile_wrapper.get_default_fallback_list_of_connector_under_profile()?;
+
+    utils::when(
+        default_list_of_connectors.len() != updated_list_of_connectors.len(),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "current config and updated config have different lengths".to_string(),
+            })
+        },
+    )?;
+
+    let existing_set_of_default_connectors: FxHashSet<String> = FxHashSet::from_iter(
+        default_list_of_connectors
+            .iter()
+            .map(|conn_choice| conn_choice.to_string()),
+    );
+    let updated_set_of_default_connectors: FxHashSet<String> = FxHashSet::from_iter(
+        updated_list_of_connectors
+            .iter()
+            .map(|conn_choice| conn_choice.to_string()),
+    );
+
+    let symmetric_diff_between_existing_and_updated_connectors: Vec<String> =
+        existing_set_of_default_connectors
+            .symmetric_difference(&updated_set_of_default_connectors)
+            .cloned()
+            .collect();
+
+    utils::when(
+        !symmetric_diff_between_existing_and_updated_connectors.is_empty(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "connector mismatch between old and new configs ({})",
+                    symmetric_diff_between_existing_and_updated_connectors.join(", ")
+                ),
+            })
+        },
+    )?;
+    profile_wrapper
+        .update_default_fallback_routing_of_connectors_under_profile(
+            db,
+            &updated_list_of_connectors,
+            key_manager_state,
+            &key_store,
+        )
+        .await?;
+
+    metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        updated_list_of_connectors,
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn update_default_routing_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    updated_config: Vec<routing_types::RoutableConnectorChoice>,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
+    metrics::ROUTING_UPDATE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let default_config = helpers::get_merchant_default_config(
+        db,
+        merchant_account.get_id().get_string_repr(),
+        transaction_type,
+    )
+    .await?;
+
+    utils::when(default_config.len() != updated_config.len(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "current config and updated config have different lengths".to_string(),
+        })
+    })?;
+
+    let existing_set: FxHashSet<String> =
+        FxHashSet::from_iter(default_config.iter().map(|c| c.to_string()));
+    let updated_set: FxHashSet<String> =
+        FxHashSet::from_iter(updated_config.iter().map(|c| c.to_string()));
+
+    let symmetric_diff: Vec<String> = existing_set
+        .symmetric_difference(&updated_set)
+        .cloned()
+        .collect();
+
+    utils::when(!symmetric_diff.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "connector mismatch between old and new configs ({})",
+                symmetric_diff.join(", ")
+            ),
+        })
+    })?;
+
+    helpers::update_merchant_default_config(
+        db,
+        merchant_account.get_id().get_string_repr(),
+        updated_config.clone(),
+        transaction_type,
+    )
+    .await?;
+
+    metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(updated_config))
+}
+
+#[cfg(feature = "v2")]
+pub async fn retrieve_default_fallback_algorithm_for_profile(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
+    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    let connectors_choice = admin::ProfileWrapper::new(profile)
+        .get_default_fallback_list_of_connector_under_profile()?;
+
+    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(connectors_choice))
+}
+
+#[cfg(feature = "v1")]
+
+pub async fn retrieve_default_routing_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
+    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+
+    helpers::get_merchant_default_config(
+        db,
+        merchant_account.get_id().get_string_repr(),
+        transaction_type,
+    )
+    .await
+    .map(|conn_choice| {
+        metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+        service_api::ApplicationResponse::Json(conn_choice)
+    })
+}
+#[cfg(feature = "v2")]
+pub async fn retrieve_routing_config_under_profile(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    query_params: RoutingRetrieveQuery,
+    profile_id: common_utils::id_type::ProfileId,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<routing_types::LinkedRoutingConfigRetrieveResponse> {
+    metrics::ROUTING_RETRIEVE_LINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    let record = db
+        .list_routing_algorithm_metadata_by_profile_id(
+            business_profile.get_id(),
+            i64::from(query_params.limit.unwrap_or_default()),
+            i64::from(query_params.offset.unwrap_or_default()),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let active_algorithms = record
+        .into_iter()
+        .filter(|routing_rec| &routing_rec.algorithm_for == transaction_type)
+        .map(|routing_algo| routing_algo.foreign_into())
+        .collect::<Vec<_>>();
+
+    metrics::ROUTING_RETRIEVE_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::LinkedRoutingConfigRetrieveResponse::ProfileBased(active_algorithms),
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_linked_routing_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    query_params: routing_types::RoutingRetrieveLinkQuery,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<routing_types::LinkedRoutingConfigRetrieveResponse> {
+    metrics::ROUTING_RETRIEVE_LINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profiles = if let Some(profile_id) = query_params.profile_id {
+        core_utils::validate_and_get_business_profile(
+            db,
+            key_manager_state,
+            &key_store,
+            Some(&profile_id),
+            merchant_account.get_id(),
+        )
+        .await?
+        .map(|profile| vec![profile])
+        .get_required_value("Profile")
+        .change_context(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?
+    } else {
+        let business_profile = db
+            .list_profile_by_merchant_id(key_manager_state, &key_store, merchant_account.get_id())
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+        core_utils::filter_objects_based_on_profile_id_list(
+            authentication_profile_id.map(|profile_id| vec![profile_id]),
+            business_profile.clone(),
+        )
+    };
+
+    let mut active_algorithms = Vec::new();
+
+    for business_profile in business_profiles {
+        let profile_id = business_profile.get_id().to_owned();
+
+        let routing_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
+            enums::TransactionType::Payment => business_profile.routing_algorithm,
+            #[cfg(feature = "payouts")]
+            enums::TransactionType::Payout => business_profile.payout_routing_algorithm,
+        }
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
+        .unwrap_or_default();
+
+        if let Some(algorithm_id) = routing_ref.algorithm_id {
+            let record = db
+                .find_routing_algorithm_metadata_by_algorithm_id_profile_id(
+                    &algorithm_id,
+                    &profile_id,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+            active_algorithms.push(record.foreign_into());
+        }
+    }
+
+    metrics::ROUTING_RETRIEVE_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::LinkedRoutingConfigRetrieveResponse::ProfileBased(active_algorithms),
+    ))
+}
+// List all the default fallback algorithms under all the profile under a merchant
+pub async fn retrieve_default_routing_config_for_profiles(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<Vec<routing_types::ProfileDefaultRoutingConfig>> {
+    metrics::ROUTING_RETRIEVE_CONFIG_FOR_PROFILE.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let all_profiles = db
+        .list_profile_by_merchant_id(key_manager_state, &key_store, merchant_account.get_id())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("error retrieving all business profiles for merchant")?;
+
+    let retrieve_config_futures = all_profiles
+        .iter()
+        .map(|prof| {
+            helpers::get_merchant_default_config(
+                db,
+                prof.get_id().get_string_repr(),
+                transaction_type,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let configs = futures::future::join_all(retrieve_config_futures)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let default_configs = configs
+        .into_iter()
+        .zip(all_profiles.iter().map(|prof| prof.get_id().to_owned()))
+        .map(
+            |(config, profile_id)| routing_types::ProfileDefaultRoutingConfig {
+                profile_id,
+                connectors: config,
+            },
+        )
+        .collect::<Vec<_>>();
+
+    metrics::ROUTING_RETRIEVE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(default_configs))
+}
+
+pub async fn update_default_routing_config_for_profile(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    updated_config: Vec<routing_types::RoutableConnectorChoice>,
+    profile_id: common_utils::id_type::ProfileId,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<routing_types::ProfileDefaultRoutingConfig> {
+    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(&metrics::CONTEXT, 1, &[]);
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+    let default_config = helpers::get_merchant_default_config(
+        db,
+        business_profile.get_id().get_string_repr(),
+        transaction_type,
+    )
+    .await?;
+
+    utils::when(default_config.len() != updated_config.len(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "current config and updated config have different lengths".to_string(),
+        })
+    })?;
+
+    let existing_set = FxHashSet::from_iter(
+        default_config
+            .iter()
+            .map(|c| (c.connector.to_string(), c.merchant_connector_id.as_ref())),
+    );
+
+    let updated_set = FxHashSet::from_iter(
+        updated_config
+            .iter()
+            .map(|c| (c.connector.to_string(), c.merchant_connector_id.as_ref())),
+    );
+
+    let symmetric_diff = existing_set
+        .symmetric_difference(&updated_set)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    utils::when(!symmetric_diff.is_empty(), || {
+        let error_str = symmetric_diff
+            .into_iter()
+            .map(|(connector, ident)| format!("'{connector}:{ident:?}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("connector mismatch between old and new configs ({error_str})"),
+        })
+    })?;
+
+    helpers::update_merchant_default_config(
+        db,
+        business_profile.get_id().get_string_repr(),
+        updated_config.clone(),
+        transaction_type,
+    )
+    .await?;
+
+    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::ProfileDefaultRoutingConfig {
+            profile_id: business_profile.get_id().to_owned(),
+            connectors: updated_config,
+        },
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn toggle_success_based_routing(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    status: bool,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
+    );
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile: domain::Profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let mut success_based_dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef =
+        business_profile
+            .dynamic_routing_algorithm
+            .clone()
+            .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "unable to deserialize dynamic routing algorithm ref from business profile",
+            )?
+            .unwrap_or_default();
+
+    if status {
+        let default_success_based_routing_config = routing::SuccessBasedRoutingConfig::default();
+        let algorithm_id = common_utils::generate_routing_id_of_default_length();
+        let timestamp = common_utils::date_time::now();
+        let algo = RoutingAlgorithm {
+            algorithm_id: algorithm_id.clone(),
+            profile_id: business_profile.get_id().to_owned(),
+            merchant_id: merchant_account.get_id().to_owned(),
+            name: "Dynamic routing algorithm".to_string(),
+            description: None,
+            kind: diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+            algorithm_data: serde_json::json!(default_success_based_routing_config),
+            created_at: timestamp,
+            modified_at: timestamp,
+            algorithm_for: common_enums::TransactionType::Payment,
+        };
+
+        let record = db
+            .insert_routing_algorithm(algo)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to insert record in routing algorithm table")?;
+
+        success_based_dynamic_routing_algo_ref.update_algorithm_id(algorithm_id);
+        helpers::update_business_profile_active_dynamic_algorithm_ref(
+            db,
+            key_manager_state,
+            &key_store,
+            business_profile,
+            success_based_dynamic_routing_algo_ref,
+        )
+        .await?;
+
+        let new_record = record.foreign_into();
+
+        metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
+        );
+        Ok(service_api::ApplicationResponse::Json(new_record))
+    } else {
+        let timestamp = common_utils::date_time::now_unix_timestamp();
+        match success_based_dynamic_routing_algo_ref.success_based_algorithm {
+            Some(algorithm_ref) => {
+                if let Some(algorithm_id) = algorithm_ref.algorithm_id {
+                    let dynamic_routing_algorithm = routing_types::DynamicRoutingAlgorithmRef {
+                        success_based_algorithm: Some(
+                            routing_types::DynamicAlgorithmWithTimestamp {
+                                algorithm_id: None,
+                                timestamp,
+                            },
+                        ),
+                        least_cost_algorithm: success_based_dynamic_routing_algo_ref
+                            .least_cost_algorithm
+                            .clone(),
+                        elimination_algorithm: success_based_dynamic_routing_algo_ref
+                            .elimination_algorithm
+                            .clone(),
+                    };
+
+                    // redact cache for success based routing configs
+                    let cache_key = format!(
+                        "{}_{}",
+                        business_profile.get_id().get_string_repr(),
+                        algorithm_id.get_string_repr()
+                    );
+                    let cache_entries_to_redact =
+                        vec![cache::CacheKind::SuccessBasedDynamicRoutingCache(
+                            cache_key.into(),
+                        )];
+                    let _ = cache::publish_into_redact_channel(
+                        state.store.get_cache_store().as_ref(),
+                        cache_entries_to_redact,
+                    )
+                    .await
+                    .map_err(|e| {
+                        logger::error!(
+                            "unable to publish into the redact channel for evicting the success based routing config cache {e:?}"
+                        )
+                    });
+
+                    let record = db
+                        .find_routing_algorithm_by_profile_id_algorithm_id(
+                            business_profile.get_id(),
+                            &algorithm_id,
+                        )
+                        .await
+                        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+                    let response = record.foreign_into();
+                    helpers::update_business_profile_active_dynamic_algorithm_ref(
+                        db,
+                        key_manager_state,
+                        &key_store,
+                        business_profile,
+                        dynamic_routing_algorithm,
+                    )
+                    .await?;
+
+                    metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
+                    );
+
+                    Ok(service_api::ApplicationResponse::Json(response))
+                } else {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "Algorithm is already inactive".to_string(),
+                    })?
+                }
+            }
+            None => Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Algorithm is already inactive".to_string(),
+            })?,
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn success_based_routing_update_configs(
+    state: SessionState,
+    request: routing_types::SuccessBasedRoutingConfig,
+    algorithm_id: common_utils::id_type::RoutingId,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
+    );
+    let db = state.store.as_ref();
+
+    let dynamic_routing_algo_to_update = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algorithm_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let mut config_to_update: routing::SuccessBasedRoutingConfig = dynamic_routing_algo_to_update
+        .algorithm_data
+        .parse_value::<routing::SuccessBasedRoutingConfig>("SuccessBasedRoutingConfig")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize algorithm data from routing table into SuccessBasedRoutingConfig")?;
+
+    config_to_update.update(request);
+
+    let updated_algorithm_id = common_utils::generate_routing_id_of_default_length();
+    let timestamp = common_utils::date_time::now();
+    let algo = RoutingAlgorithm {
+        algorithm_id: updated_algorithm_id,
+        profile_id: dynamic_routing_algo_to_update.profile_id,
+        merchant_id: dynamic_routing_algo_to_update.merchant_id,
+        name: dynamic_routing_algo_to_update.name,
+        description: dynamic_routing_algo_to_update.description,
+        kind: dynamic_routing_algo_to_update.kind,
+        algorithm_data: serde_json::json!(config_to_update),
+        created_at: timestamp,
+        modified_at: timestamp,
+        algorithm_for: dynamic_routing_algo_to_update.algorithm_for,
+    };
+    let record = db
+        .insert_routing_algorithm(algo)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert record in routing algorithm table")?;
+
+    // redact cache for success based routing configs
+    let cache_key = format!(
+        "{}_{}",
+        profile_id.get_string_repr(),
+        algorithm_id.get_string_repr()
+    );
+    let cache_entries_to_redact = vec![cache::CacheKind::SuccessBasedDynamicRoutingCache(
+        cache_key.into(),
+    )];
+    let _ = cache::publish_into_redact_channel(
+        state.store.get_cache_store().as_ref(),
+        cache_entries_to_redact,
+    )
+    .await
+    .map_err(|e| logger::error!("unable to publish into the redact channel for evicting the success based routing config cache {e:?}"));
+
+    let new_record = record.foreign_into();
+
+    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
+    );
+    Ok(service_api::ApplicationResponse::Json(new_record))
+}
+
+#[cfg(feature = "v1")]
+async fn find_merchant_connector_account_for_fee_schedule(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+) -> errors::RouterResult<domain::MerchantConnectorAccount> {
+    let merchant_connector_account = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_account.get_id(),
+            merchant_connector_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(
+        authentication_profile_id,
+        &merchant_connector_account,
+    )?;
+
+    Ok(merchant_connector_account)
+}
+
+#[cfg(feature = "v1")]
+async fn redact_fee_schedules_cache(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+) {
+    let cache_entries_to_redact = [cache::CacheKind::Routing(
+        least_cost::get_fee_schedules_cache_key(profile_id).into(),
+    )];
+    let _ = cache::publish_into_redact_channel(
+        state.store.get_cache_store().as_ref(),
+        cache_entries_to_redact,
+    )
+    .await
+    .map_err(|e| {
+        logger::error!(
+            "unable to publish into the redact channel for evicting the fee schedules cache {e:?}"
+        )
+    });
+}
+
+#[cfg(feature = "v1")]
+pub async fn upsert_connector_fee_schedule(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    request: routing_types::ConnectorFeeSchedule,
+) -> RouterResponse<routing_types::ConnectorFeeScheduleResponse> {
+    let db = state.store.as_ref();
+    let merchant_connector_account = find_merchant_connector_account_for_fee_schedule(
+        &state,
+        &merchant_account,
+        &key_store,
+        authentication_profile_id,
+        &merchant_connector_id,
+    )
+    .await?;
+
+    for rule in &request.rules {
+        least_cost::validate_fee(&rule.fee)?;
+    }
+    let fee_rules = serde_json::json!(request.rules);
+
+    let fee_schedule = match db
+        .find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+            merchant_account.get_id(),
+            &merchant_connector_id,
+        )
+        .await
+    {
+        Ok(_) => db
+            .update_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+                merchant_account.get_id(),
+                &merchant_connector_id,
+                storage::ConnectorFeeScheduleUpdate { fee_rules },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the connector fee schedule")?,
+        Err(error) if error.current_context().is_db_not_found() => {
+            let timestamp = common_utils::date_time::now();
+            db.insert_connector_fee_schedule(storage::ConnectorFeeScheduleNew {
+                merchant_connector_id,
+                merchant_id: merchant_account.get_id().to_owned(),
+                profile_id: merchant_connector_account.profile_id,
+                fee_rules,
+                created_at: timestamp,
+                modified_at: timestamp,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the connector fee schedule")?
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the connector fee schedule")?,
+    };
+
+    redact_fee_schedules_cache(&state, &fee_schedule.profile_id).await;
+
+    let response = routing_types::ConnectorFeeScheduleResponse::foreign_try_from(fee_schedule)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connector fee schedule")?;
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_connector_fee_schedule(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+) -> RouterResponse<routing_types::ConnectorFeeScheduleResponse> {
+    find_merchant_connector_account_for_fee_schedule(
+        &state,
+        &merchant_account,
+        &key_store,
+        authentication_profile_id,
+        &merchant_connector_id,
+    )
+    .await?;
+
+    let fee_schedule = state
+        .store
+        .find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+            merchant_account.get_id(),
+            &merchant_connector_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Fee schedule of the merchant connector account not found".to_string(),
+        })?;
+
+    let response = routing_types::ConnectorFeeScheduleResponse::foreign_try_from(fee_schedule)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connector fee schedule")?;
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "v1")]
+pub async fn delete_connector_fee_schedule(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+) -> RouterResponse<routing_types::ConnectorFeeScheduleResponse> {
+    find_merchant_connector_account_for_fee_schedule(
+        &state,
+        &merchant_account,
+        &key_store,
+        authentication_profile_id,
+        &merchant_connector_id,
+    )
+    .await?;
+
+    let fee_schedule = state
+        .store
+        .delete_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+            merchant_account.get_id(),
+            &merchant_connector_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Fee schedule of the merchant connector account not found".to_string(),
+        })?;
+
+    redact_fee_schedules_cache(&state, &fee_schedule.profile_id).await;
+
+    let response = routing_types::ConnectorFeeScheduleResponse::foreign_try_from(fee_schedule)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connector fee schedule")?;
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
 #[cfg(feature = "v1")]
-pub async fn retrieve_linked_routing_config(
+pub async fn toggle_least_cost_routing(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
-    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
-    query_params: routing_types::RoutingRetrieveLinkQuery,
-    transaction_type: &enums::TransactionType,
-) -> RouterResponse<routing_types::LinkedRoutingConfigRetrieveResponse> {
-    metrics::ROUTING_RETRIEVE_LINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    status: bool,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
+    );
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
-    let business_profiles = if let Some(profile_id) = query_params.profile_id {
-        core_utils::validate_and_get_business_profile(
-            db,
-            key_manager_state,
-            &key_store,
-            Some(&profile_id),
-            merchant_account.get_id(),
-        )
-        .await?
-        .map(|profile| vec![profile])
-        .get_required_value("Profile")
-        .change_context(errors::ApiErrorResponse::ProfileNotFound {
-            id: profile_id.get_string_repr().to_owned(),
-        })?
-    } else {
-        let business_profile = db
-            .list_profile_by_merchant_id(key_manager_state, &key_store, merchant_account.get_id())
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
-        core_utils::filter_objects_based_on_profile_id_list(
-            authentication_profile_id.map(|profile_id| vec![profile_id]),
-            business_profile.clone(),
-        )
-    };
-
-    let mut active_algorithms = Vec::new();
-
-    for business_profile in business_profiles {
-        let profile_id = business_profile.get_id().to_owned();
+    let business_profile: domain::Profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
 
-        let routing_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
-            enums::TransactionType::Payment => business_profile.routing_algorithm,
-            #[cfg(feature = "payouts")]
-            enums::TransactionType::Payout => business_profile.payout_routing_algorithm,
-        }
+    let mut dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
+        .dynamic_routing_algorithm
         .clone()
-        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
         .transpose()
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
+        .attach_printable(
+            "unable to deserialize dynamic routing algorithm ref from business profile",
+        )?
         .unwrap_or_default();
 
-        if let Some(algorithm_id) = routing_ref.algorithm_id {
-            let record = db
-                .find_routing_algorithm_metadata_by_algorithm_id_profile_id(
-                    &algorithm_id,
-                    &profile_id,
-                )
-                .await
-                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    if status {
+        let algorithm_id = common_utils::generate_routing_id_of_default_length();
+        let timestamp = common_utils::date_time::now();
+        let algo = RoutingAlgorithm {
+            algorithm_id: algorithm_id.clone(),
+            profile_id: business_profile.get_id().to_owned(),
+            merchant_id: merchant_account.get_id().to_owned(),
+            name: "Least cost routing algorithm".to_string(),
+            description: None,
+            kind: diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+            algorithm_data: serde_json::json!(routing::LeastCostRoutingConfig::default()),
+            created_at: timestamp,
+            modified_at: timestamp,
+            algorithm_for: common_enums::TransactionType::Payment,
+        };
 
-            active_algorithms.push(record.foreign_into());
-        }
-    }
+        let record = db
+            .insert_routing_algorithm(algo)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to insert record in routing algorithm table")?;
 
-    metrics::ROUTING_RETRIEVE_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-    Ok(service_api::ApplicationResponse::Json(
-        routing_types::LinkedRoutingConfigRetrieveResponse::ProfileBased(active_algorithms),
-    ))
-}
-// List all the default fallback algorithms under all the profile under a merchant
-pub async fn retrieve_default_routing_config_for_profiles(
-    state: SessionState,
-    merchant_account: domain::MerchantAccount,
-    key_store: domain::MerchantKeyStore,
-    transaction_type: &enums::TransactionType,
-) -> RouterResponse<Vec<routing_types::ProfileDefaultRoutingConfig>> {
-    metrics::ROUTING_RETRIEVE_CONFIG_FOR_PROFILE.add(&metrics::CONTEXT, 1, &[]);
-    let db = state.store.as_ref();
-    let key_manager_state = &(&state).into();
+        dynamic_routing_algo_ref.update_least_cost_algorithm_id(Some(algorithm_id));
+        helpers::update_business_profile_active_dynamic_algorithm_ref(
+            db,
+            key_manager_state,
+            &key_store,
+            business_profile,
+            dynamic_routing_algo_ref,
+        )
+        .await?;
 
-    let all_profiles = db
-        .list_profile_by_merchant_id(key_manager_state, &key_store, merchant_account.get_id())
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
-        .attach_printable("error retrieving all business profiles for merchant")?;
+        metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
+        );
+        Ok(service_api::ApplicationResponse::Json(
+            record.foreign_into(),
+        ))
+    } else {
+        let algorithm_id = dynamic_routing_algo_ref
+            .least_cost_algorithm
+            .clone()
+            .and_then(|algorithm_ref| algorithm_ref.algorithm_id)
+            .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Algorithm is already inactive".to_string(),
+            })?;
 
-    let retrieve_config_futures = all_profiles
-        .iter()
-        .map(|prof| {
-            helpers::get_merchant_default_config(
-                db,
-                prof.get_id().get_string_repr(),
-                transaction_type,
+        let record = db
+            .find_routing_algorithm_by_profile_id_algorithm_id(
+                business_profile.get_id(),
+                &algorithm_id,
             )
-        })
-        .collect::<Vec<_>>();
-
-    let configs = futures::future::join_all(retrieve_config_futures)
-        .await
-        .into_iter()
-        .collect::<Result<Vec<_>, _>>()?;
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let default_configs = configs
-        .into_iter()
-        .zip(all_profiles.iter().map(|prof| prof.get_id().to_owned()))
-        .map(
-            |(config, profile_id)| routing_types::ProfileDefaultRoutingConfig {
-                profile_id,
-                connectors: config,
-            },
+        dynamic_routing_algo_ref.update_least_cost_algorithm_id(None);
+        helpers::update_business_profile_active_dynamic_algorithm_ref(
+            db,
+            key_manager_state,
+            &key_store,
+            business_profile,
+            dynamic_routing_algo_ref,
         )
-        .collect::<Vec<_>>();
+        .await?;
 
-    metrics::ROUTING_RETRIEVE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-    Ok(service_api::ApplicationResponse::Json(default_configs))
+        metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
+        );
+        Ok(service_api::ApplicationResponse::Json(
+            record.foreign_into(),
+        ))
+    }
 }
 
-pub async fn update_default_routing_config_for_profile(
+#[cfg(feature = "v1")]
+pub async fn least_cost_routing_update_configs(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
-    updated_config: Vec<routing_types::RoutableConnectorChoice>,
+    request: routing_types::LeastCostRoutingConfig,
+    algorithm_id: common_utils::id_type::RoutingId,
     profile_id: common_utils::id_type::ProfileId,
-    transaction_type: &enums::TransactionType,
-) -> RouterResponse<routing_types::ProfileDefaultRoutingConfig> {
-    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(&metrics::CONTEXT, 1, &[]);
-
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
+    );
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
-    let business_profile = core_utils::validate_and_get_business_profile(
+    let business_profile: domain::Profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         &key_store,
@@ -1098,67 +2724,91 @@ pub async fn update_default_routing_config_for_profile(
     .change_context(errors::ApiErrorResponse::ProfileNotFound {
         id: profile_id.get_string_repr().to_owned(),
     })?;
-    let default_config = helpers::get_merchant_default_config(
-        db,
-        business_profile.get_id().get_string_repr(),
-        transaction_type,
-    )
-    .await?;
 
-    utils::when(default_config.len() != updated_config.len(), || {
+    let mut dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
+        .dynamic_routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "unable to deserialize dynamic routing algorithm ref from business profile",
+        )?
+        .unwrap_or_default();
+
+    // Only the active config is updated, as the updated config replaces it for the profile
+    let active_algorithm_id = dynamic_routing_algo_ref
+        .least_cost_algorithm
+        .as_ref()
+        .and_then(|algorithm_ref| algorithm_ref.algorithm_id.as_ref());
+    if active_algorithm_id != Some(&algorithm_id) {
         Err(errors::ApiErrorResponse::PreconditionFailed {
-            message: "current config and updated config have different lengths".to_string(),
-        })
-    })?;
+            message: "The algorithm is not the active least cost routing algorithm of the profile"
+                .to_string(),
+        })?
+    }
 
-    let existing_set = FxHashSet::from_iter(
-        default_config
-            .iter()
-            .map(|c| (c.connector.to_string(), c.merchant_connector_id.as_ref())),
-    );
+    if let Some(default_fee) = &request.default_fee {
+        least_cost::validate_fee(default_fee)?;
+    }
 
-    let updated_set = FxHashSet::from_iter(
-        updated_config
-            .iter()
-            .map(|c| (c.connector.to_string(), c.merchant_connector_id.as_ref())),
-    );
+    let dynamic_routing_algo_to_update = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algorithm_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let symmetric_diff = existing_set
-        .symmetric_difference(&updated_set)
-        .cloned()
-        .collect::<Vec<_>>();
+    let mut config_to_update: routing::LeastCostRoutingConfig = dynamic_routing_algo_to_update
+        .algorithm_data
+        .parse_value::<routing::LeastCostRoutingConfig>("LeastCostRoutingConfig")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "unable to deserialize algorithm data from routing table into LeastCostRoutingConfig",
+        )?;
 
-    utils::when(!symmetric_diff.is_empty(), || {
-        let error_str = symmetric_diff
-            .into_iter()
-            .map(|(connector, ident)| format!("'{connector}:{ident:?}'"))
-            .collect::<Vec<_>>()
-            .join(", ");
+    config_to_update.update(request);
 
-        Err(errors::ApiErrorResponse::InvalidRequestData {
-            message: format!("connector mismatch between old and new configs ({error_str})"),
-        })
-    })?;
+    let updated_algorithm_id = common_utils::generate_routing_id_of_default_length();
+    let timestamp = common_utils::date_time::now();
+    let algo = RoutingAlgorithm {
+        algorithm_id: updated_algorithm_id.clone(),
+        profile_id: dynamic_routing_algo_to_update.profile_id,
+        merchant_id: dynamic_routing_algo_to_update.merchant_id,
+        name: dynamic_routing_algo_to_update.name,
+        description: dynamic_routing_algo_to_update.description,
+        kind: dynamic_routing_algo_to_update.kind,
+        algorithm_data: serde_json::json!(config_to_update),
+        created_at: timestamp,
+        modified_at: timestamp,
+        algorithm_for: dynamic_routing_algo_to_update.algorithm_for,
+    };
+    let record = db
+        .insert_routing_algorithm(algo)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert record in routing algorithm table")?;
 
-    helpers::update_merchant_default_config(
+    dynamic_routing_algo_ref.update_least_cost_algorithm_id(Some(updated_algorithm_id));
+    helpers::update_business_profile_active_dynamic_algorithm_ref(
         db,
-        business_profile.get_id().get_string_repr(),
-        updated_config.clone(),
-        transaction_type,
+        key_manager_state,
+        &key_store,
+        business_profile,
+        dynamic_routing_algo_ref,
     )
     .await?;
 
-    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
+    );
     Ok(service_api::ApplicationResponse::Json(
-        routing_types::ProfileDefaultRoutingConfig {
-            profile_id: business_profile.get_id().to_owned(),
-            connectors: updated_config,
-        },
+        record.foreign_into(),
     ))
 }
 
 #[cfg(feature = "v1")]
-pub async fn toggle_success_based_routing(
+pub async fn toggle_elimination_routing(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
@@ -1186,30 +2836,28 @@ pub async fn toggle_success_based_routing(
         id: profile_id.get_string_repr().to_owned(),
     })?;
 
-    let mut success_based_dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef =
-        business_profile
-            .dynamic_routing_algorithm
-            .clone()
-            .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
-            .transpose()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable(
-                "unable to deserialize dynamic routing algorithm ref from business profile",
-            )?
-            .unwrap_or_default();
+    let mut dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
+        .dynamic_routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "unable to deserialize dynamic routing algorithm ref from business profile",
+        )?
+        .unwrap_or_default();
 
     if status {
-        let default_success_based_routing_config = routing::SuccessBasedRoutingConfig::default();
         let algorithm_id = common_utils::generate_routing_id_of_default_length();
         let timestamp = common_utils::date_time::now();
         let algo = RoutingAlgorithm {
             algorithm_id: algorithm_id.clone(),
             profile_id: business_profile.get_id().to_owned(),
             merchant_id: merchant_account.get_id().to_owned(),
-            name: "Dynamic routing algorithm".to_string(),
+            name: "Elimination routing algorithm".to_string(),
             description: None,
             kind: diesel_models::enums::RoutingAlgorithmKind::Dynamic,
-            algorithm_data: serde_json::json!(default_success_based_routing_config),
+            algorithm_data: serde_json::json!(routing::EliminationRoutingConfig::default()),
             created_at: timestamp,
             modified_at: timestamp,
             algorithm_for: common_enums::TransactionType::Payment,
@@ -1221,100 +2869,68 @@ pub async fn toggle_success_based_routing(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Unable to insert record in routing algorithm table")?;
 
-        success_based_dynamic_routing_algo_ref.update_algorithm_id(algorithm_id);
+        dynamic_routing_algo_ref.update_elimination_algorithm_id(Some(algorithm_id));
         helpers::update_business_profile_active_dynamic_algorithm_ref(
             db,
             key_manager_state,
             &key_store,
             business_profile,
-            success_based_dynamic_routing_algo_ref,
+            dynamic_routing_algo_ref,
         )
         .await?;
 
-        let new_record = record.foreign_into();
-
         metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(
             &metrics::CONTEXT,
             1,
             &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
         );
-        Ok(service_api::ApplicationResponse::Json(new_record))
+        Ok(service_api::ApplicationResponse::Json(
+            record.foreign_into(),
+        ))
     } else {
-        let timestamp = common_utils::date_time::now_unix_timestamp();
-        match success_based_dynamic_routing_algo_ref.success_based_algorithm {
-            Some(algorithm_ref) => {
-                if let Some(algorithm_id) = algorithm_ref.algorithm_id {
-                    let dynamic_routing_algorithm = routing_types::DynamicRoutingAlgorithmRef {
-                        success_based_algorithm: Some(
-                            routing_types::DynamicAlgorithmWithTimestamp {
-                                algorithm_id: None,
-                                timestamp,
-                            },
-                        ),
-                    };
-
-                    // redact cache for success based routing configs
-                    let cache_key = format!(
-                        "{}_{}",
-                        business_profile.get_id().get_string_repr(),
-                        algorithm_id.get_string_repr()
-                    );
-                    let cache_entries_to_redact =
-                        vec![cache::CacheKind::SuccessBasedDynamicRoutingCache(
-                            cache_key.into(),
-                        )];
-                    let _ = cache::publish_into_redact_channel(
-                        state.store.get_cache_store().as_ref(),
-                        cache_entries_to_redact,
-                    )
-                    .await
-                    .map_err(|e| {
-                        logger::error!(
-                            "unable to publish into the redact channel for evicting the success based routing config cache {e:?}"
-                        )
-                    });
+        let algorithm_id = dynamic_routing_algo_ref
+            .elimination_algorithm
+            .clone()
+            .and_then(|algorithm_ref| algorithm_ref.algorithm_id)
+            .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Algorithm is already inactive".to_string(),
+            })?;
 
-                    let record = db
-                        .find_routing_algorithm_by_profile_id_algorithm_id(
-                            business_profile.get_id(),
-                            &algorithm_id,
-                        )
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
-                    let response = record.foreign_into();
-                    helpers::update_business_profile_active_dynamic_algorithm_ref(
-                        db,
-                        key_manager_state,
-                        &key_store,
-                        business_profile,
-                        dynamic_routing_algorithm,
-                    )
-                    .await?;
+        let record = db
+            .find_routing_algorithm_by_profile_id_algorithm_id(
+                business_profile.get_id(),
+                &algorithm_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-                    metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(
-                        &metrics::CONTEXT,
-                        1,
-                        &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
-                    );
+        dynamic_routing_algo_ref.update_elimination_algorithm_id(None);
+        helpers::update_business_profile_active_dynamic_algorithm_ref(
+            db,
+            key_manager_state,
+            &key_store,
+            business_profile,
+            dynamic_routing_algo_ref,
+        )
+        .await?;
 
-                    Ok(service_api::ApplicationResponse::Json(response))
-                } else {
-                    Err(errors::ApiErrorResponse::PreconditionFailed {
-                        message: "Algorithm is already inactive".to_string(),
-                    })?
-                }
-            }
-            None => Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "Algorithm is already inactive".to_string(),
-            })?,
-        }
+        metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
+        );
+        Ok(service_api::ApplicationResponse::Json(
+            record.foreign_into(),
+        ))
     }
 }
 
 #[cfg(feature = "v1")]
-pub async fn success_based_routing_update_configs(
+pub async fn elimination_routing_update_configs(
     state: SessionState,
-    request: routing_types::SuccessBasedRoutingConfig,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: routing_types::EliminationRoutingConfig,
     algorithm_id: common_utils::id_type::RoutingId,
     profile_id: common_utils::id_type::ProfileId,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
@@ -1324,24 +2940,69 @@ pub async fn success_based_routing_update_configs(
         &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
     );
     let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile: domain::Profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let mut dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
+        .dynamic_routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "unable to deserialize dynamic routing algorithm ref from business profile",
+        )?
+        .unwrap_or_default();
+
+    // Only the active config is updated, as the updated config replaces it for the profile
+    let active_algorithm_id = dynamic_routing_algo_ref
+        .elimination_algorithm
+        .as_ref()
+        .and_then(|algorithm_ref| algorithm_ref.algorithm_id.as_ref());
+    if active_algorithm_id != Some(&algorithm_id) {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The algorithm is not the active elimination routing algorithm of the profile"
+                .to_string(),
+        })?
+    }
+
+    if request.failure_threshold == Some(0) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "failure_threshold must be greater than zero".to_string(),
+        })?
+    }
 
     let dynamic_routing_algo_to_update = db
         .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algorithm_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let mut config_to_update: routing::SuccessBasedRoutingConfig = dynamic_routing_algo_to_update
+    let mut config_to_update: routing::EliminationRoutingConfig = dynamic_routing_algo_to_update
         .algorithm_data
-        .parse_value::<routing::SuccessBasedRoutingConfig>("SuccessBasedRoutingConfig")
+        .parse_value::<routing::EliminationRoutingConfig>("EliminationRoutingConfig")
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("unable to deserialize algorithm data from routing table into SuccessBasedRoutingConfig")?;
+        .attach_printable(
+            "unable to deserialize algorithm data from routing table into EliminationRoutingConfig",
+        )?;
 
     config_to_update.update(request);
 
     let updated_algorithm_id = common_utils::generate_routing_id_of_default_length();
     let timestamp = common_utils::date_time::now();
     let algo = RoutingAlgorithm {
-        algorithm_id: updated_algorithm_id,
+        algorithm_id: updated_algorithm_id.clone(),
         profile_id: dynamic_routing_algo_to_update.profile_id,
         merchant_id: dynamic_routing_algo_to_update.merchant_id,
         name: dynamic_routing_algo_to_update.name,
@@ -1358,28 +3019,240 @@ pub async fn success_based_routing_update_configs(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to insert record in routing algorithm table")?;
 
-    // redact cache for success based routing configs
-    let cache_key = format!(
-        "{}_{}",
-        profile_id.get_string_repr(),
-        algorithm_id.get_string_repr()
-    );
-    let cache_entries_to_redact = vec![cache::CacheKind::SuccessBasedDynamicRoutingCache(
-        cache_key.into(),
-    )];
-    let _ = cache::publish_into_redact_channel(
-        state.store.get_cache_store().as_ref(),
-        cache_entries_to_redact,
+    dynamic_routing_algo_ref.update_elimination_algorithm_id(Some(updated_algorithm_id));
+    helpers::update_business_profile_active_dynamic_algorithm_ref(
+        db,
+        key_manager_state,
+        &key_store,
+        business_profile,
+        dynamic_routing_algo_ref,
     )
-    .await
-    .map_err(|e| logger::error!("unable to publish into the redact channel for evicting the success based routing config cache {e:?}"));
-
-    let new_record = record.foreign_into();
+    .await?;
 
     metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(
         &metrics::CONTEXT,
         1,
         &add_attributes([("profile_id", profile_id.get_string_repr().to_owned())]),
     );
-    Ok(service_api::ApplicationResponse::Json(new_record))
+    Ok(service_api::ApplicationResponse::Json(
+        record.foreign_into(),
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_elimination_status(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<routing_types::EliminationStatusResponse> {
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let now = common_utils::date_time::now_unix_timestamp();
+    let mut buckets = elimination::get_bucket_states(&state, &profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve the elimination state of the profile")?
+        .into_values()
+        .map(|bucket_state| bucket_state.into_bucket(now))
+        .collect::<Vec<_>>();
+    buckets.sort_by(|bucket, other_bucket| {
+        (
+            bucket.merchant_connector_id.get_string_repr(),
+            bucket.payment_method.to_string(),
+        )
+            .cmp(&(
+                other_bucket.merchant_connector_id.get_string_repr(),
+                other_bucket.payment_method.to_string(),
+            ))
+    });
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::EliminationStatusResponse {
+            profile_id,
+            buckets,
+        },
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn override_elimination_state(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    request: routing_types::EliminationOverrideRequest,
+) -> RouterResponse<routing_types::EliminationBucket> {
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let merchant_connector_account = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &(&state).into(),
+            merchant_account.get_id(),
+            &request.merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: request.merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    if merchant_connector_account.profile_id != profile_id {
+        Err(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: request.merchant_connector_id.get_string_repr().to_string(),
+        })?
+    }
+    let connector =
+        enums::RoutableConnectors::from_str(merchant_connector_account.connector_name.as_str())
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The connector of the merchant connector account cannot be routed through"
+                    .to_string(),
+            })?;
+
+    let bucket = elimination::override_bucket_state(
+        &state,
+        &profile_id,
+        connector,
+        &request.merchant_connector_id,
+        request.payment_method,
+        request.action,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to override the elimination state of the connector")?;
+
+    Ok(service_api::ApplicationResponse::Json(bucket))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn estimate_connector_costs(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::ConnectorCostEstimateRequest,
+) -> RouterResponse<routing_types::ConnectorCostEstimateResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let profile_id = profile_id.get_required_value("profile_id")?;
+
+    let fee_schedules = db
+        .list_connector_fee_schedules_by_profile_id(&profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the connector fee schedules of the profile")?;
+    let fee_schedules = least_cost::fee_schedules_by_connector(fee_schedules)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let limit = request
+        .limit
+        .unwrap_or(COST_ESTIMATE_DEFAULT_LIMIT)
+        .clamp(1, COST_ESTIMATE_MAX_LIMIT);
+    let mut summary = least_cost::CostEstimateSummary::default();
+    let mut offset = 0;
+    while offset < limit {
+        let batch_size = std::cmp::min(
+            limit - offset,
+            common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1,
+        );
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset,
+            starting_at: Some(request.time_range.start_time),
+            ending_at: request.time_range.end_time,
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: Some(vec![
+                storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::PartiallyCaptured,
+            ]),
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: Some(vec![profile_id.clone()]),
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(batch_size),
+            order: Default::default(),
+        }));
+
+        let payments = db
+            .get_filtered_payment_intents_attempt(
+                key_manager_state,
+                merchant_account.get_id(),
+                &constraints,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payments for estimating connector costs")?;
+
+        for (payment_intent, payment_attempt) in &payments {
+            let connector = payment_attempt
+                .connector
+                .as_deref()
+                .and_then(|connector| connector.parse::<enums::RoutableConnectors>().ok());
+            let (Some(connector), Some(merchant_connector_id), Some(currency)) = (
+                connector,
+                payment_attempt.merchant_connector_id.as_ref(),
+                payment_attempt.currency.or(payment_intent.currency),
+            ) else {
+                continue;
+            };
+
+            let card_info = simulation::get_additional_card_info(payment_attempt);
+            let fee_input = least_cost::FeeInput {
+                amount: payment_attempt.net_amount,
+                currency,
+                payment_method_type: payment_attempt.payment_method_type,
+                card_network: card_info
+                    .as_ref()
+                    .and_then(|card_info| card_info.card_network.clone()),
+                card_type: card_info
+                    .as_ref()
+                    .and_then(|card_info| card_info.card_type.as_deref())
+                    .and_then(least_cost::parse_card_type),
+                region: None,
+            };
+
+            summary.record_payment(
+                connector,
+                merchant_connector_id,
+                fee_schedules.get(merchant_connector_id),
+                &fee_input,
+            );
+        }
+
+        if payments.len() < usize::try_from(batch_size).unwrap_or(usize::MAX) {
+            break;
+        }
+        offset += batch_size;
+    }
+
+    Ok(service_api::ApplicationResponse::Json(summary.into()))
 }
@@ -0,0 +1,239 @@
+//! Semantic comparison of routing algorithms
+//!
+//! Algorithms are compared as programs, where an algorithm which is not advanced is treated as a
+//! program without rules whose default selection is the algorithm itself. Rules are matched by
+//! their names. The conditions of rules are compared ignoring the metadata of comparisons, such
+//! as their positions in the source text, and the order of the comparisons and statements, which
+//! does not affect their evaluation.
+
+use api_models::routing::{self as routing_types, ConnectorSelection};
+use euclid::frontend::ast;
+
+type Program = ast::Program<ConnectorSelection>;
+
+pub fn diff_routing_algorithms(
+    from_algorithm_id: common_utils::id_type::RoutingId,
+    from_algorithm: routing_types::RoutingAlgorithm,
+    to_algorithm_id: common_utils::id_type::RoutingId,
+    to_algorithm: routing_types::RoutingAlgorithm,
+) -> routing_types::RoutingAlgorithmDiff {
+    let from = into_program(from_algorithm);
+    let to = into_program(to_algorithm);
+
+    let added_rules = to
+        .rules
+        .iter()
+        .filter(|rule| find_rule(&from, &rule.name).is_none())
+        .map(|rule| rule.name.clone())
+        .collect();
+    let removed_rules = from
+        .rules
+        .iter()
+        .filter(|rule| find_rule(&to, &rule.name).is_none())
+        .map(|rule| rule.name.clone())
+        .collect();
+
+    // The relative order of the rules present in both programs, so that rules which are added or
+    // removed do not cause the remaining rules to be reported as reordered
+    let common_rule_order = |program: &Program, other: &Program| -> Vec<String> {
+        program
+            .rules
+            .iter()
+            .filter(|rule| find_rule(other, &rule.name).is_some())
+            .map(|rule| rule.name.clone())
+            .collect()
+    };
+    let from_order = common_rule_order(&from, &to);
+    let to_order = common_rule_order(&to, &from);
+
+    let changed_rules = to
+        .rules
+        .iter()
+        .filter_map(|to_rule| {
+            let from_rule = find_rule(&from, &to_rule.name)?;
+            let rule_diff = routing_types::RoutingRuleDiff {
+                rule_name: to_rule.name.clone(),
+                conditions_changed: !same_elements(
+                    &from_rule.statements,
+                    &to_rule.statements,
+                    statements_eq,
+                ),
+                order_changed: from_order.iter().position(|name| name == &to_rule.name)
+                    != to_order.iter().position(|name| name == &to_rule.name),
+                connector_selection: selection_diff(
+                    &from_rule.connector_selection,
+                    &to_rule.connector_selection,
+                ),
+            };
+
+            (rule_diff.conditions_changed
+                || rule_diff.order_changed
+                || rule_diff.connector_selection.is_some())
+            .then_some(rule_diff)
+        })
+        .collect();
+
+    routing_types::RoutingAlgorithmDiff {
+        default_selection: selection_diff(&from.default_selection, &to.default_selection),
+        from_algorithm_id,
+        to_algorithm_id,
+        added_rules,
+        removed_rules,
+        changed_rules,
+    }
+}
+
+fn into_program(algorithm: routing_types::RoutingAlgorithm) -> Program {
+    let default_selection = match algorithm {
+        routing_types::RoutingAlgorithm::Advanced(program) => return program,
+        routing_types::RoutingAlgorithm::Single(connector) => {
+            ConnectorSelection::Priority(vec![*connector])
+        }
+        routing_types::RoutingAlgorithm::Priority(connectors) => {
+            ConnectorSelection::Priority(connectors)
+        }
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            ConnectorSelection::VolumeSplit(splits)
+        }
+    };
+
+    ast::Program {
+        default_selection,
+        rules: Vec::new(),
+        metadata: Default::default(),
+    }
+}
+
+fn find_rule<'a>(program: &'a Program, name: &str) -> Option<&'a ast::Rule<ConnectorSelection>> {
+    program.rules.iter().find(|rule| rule.name == name)
+}
+
+fn selection_diff(
+    from: &ConnectorSelection,
+    to: &ConnectorSelection,
+) -> Option<routing_types::ConnectorSelectionDiff> {
+    let unchanged = match (from, to) {
+        (ConnectorSelection::Priority(from), ConnectorSelection::Priority(to)) => from == to,
+        (ConnectorSelection::VolumeSplit(from), ConnectorSelection::VolumeSplit(to)) => {
+            same_elements(from, to, |from, to| {
+                from.split == to.split && from.connector == to.connector
+            })
+        }
+        (ConnectorSelection::Priority(_), ConnectorSelection::VolumeSplit(_))
+        | (ConnectorSelection::VolumeSplit(_), ConnectorSelection::Priority(_)) => false,
+    };
+
+    (!unchanged).then(|| routing_types::ConnectorSelectionDiff {
+        from: from.clone(),
+        to: to.clone(),
+    })
+}
+
+fn statements_eq(from: &ast::IfStatement, to: &ast::IfStatement) -> bool {
+    same_elements(&from.condition, &to.condition, |from, to| {
+        from.lhs == to.lhs && from.comparison == to.comparison && from.value == to.value
+    }) && same_elements(
+        from.nested.as_deref().unwrap_or_default(),
+        to.nested.as_deref().unwrap_or_default(),
+        statements_eq,
+    )
+}
+
+/// Checks whether both slices have the same elements, irrespective of their order
+fn same_elements<T>(from: &[T], to: &[T], eq: impl Fn(&T, &T) -> bool) -> bool {
+    from.len() == to.len()
+        && from.iter().all(|from| to.iter().any(|to| eq(from, to)))
+        && to.iter().all(|to| from.iter().any(|from| eq(from, to)))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    fn advanced(source: &str) -> routing_types::RoutingAlgorithm {
+        routing_types::RoutingAlgorithm::Advanced(
+            ast::parser::parse_program::<ConnectorSelection>(source)
+                .expect("Failed to parse program"),
+        )
+    }
+
+    fn algorithm_id(id: &'static str) -> common_utils::id_type::RoutingId {
+        common_utils::id_type::RoutingId::try_from(std::borrow::Cow::Borrowed(id))
+            .expect("Invalid routing id")
+    }
+
+    #[test]
+    fn test_diff_ignores_formatting_and_condition_order() {
+        let from = advanced(
+            "default: [stripe]\n\nrule_1: [adyen]\n{\n    amount > 100 & currency = USD\n}\n",
+        );
+        let to =
+            advanced("default: [stripe]\nrule_1: [adyen]\n{\n  currency = USD & amount > 100\n}\n");
+
+        let diff = diff_routing_algorithms(algorithm_id("from"), from, algorithm_id("to"), to);
+
+        assert!(diff.added_rules.is_empty());
+        assert!(diff.removed_rules.is_empty());
+        assert!(diff.changed_rules.is_empty());
+        assert!(diff.default_selection.is_none());
+    }
+
+    #[test]
+    fn test_diff_reports_rule_changes() {
+        let from = advanced(
+            "default: [stripe]\n\
+             rule_1: [adyen]\n{\n    amount > 100\n}\n\
+             rule_2: [checkout]\n{\n    currency = USD\n}\n\
+             rule_3: [stripe]\n{\n    currency = EUR\n}\n",
+        );
+        let to = advanced(
+            "default: [adyen]\n\
+             rule_2: [checkout, adyen]\n{\n    currency = USD\n}\n\
+             rule_1: [adyen]\n{\n    amount > 200\n}\n\
+             rule_4: [stripe]\n{\n    currency = GBP\n}\n",
+        );
+
+        let diff = diff_routing_algorithms(algorithm_id("from"), from, algorithm_id("to"), to);
+
+        assert_eq!(diff.added_rules, vec!["rule_4".to_string()]);
+        assert_eq!(diff.removed_rules, vec!["rule_3".to_string()]);
+        assert!(diff.default_selection.is_some());
+
+        let rule_1 = diff
+            .changed_rules
+            .iter()
+            .find(|rule| rule.rule_name == "rule_1")
+            .expect("rule_1 is not reported as changed");
+        assert!(rule_1.conditions_changed);
+        assert!(rule_1.order_changed);
+        assert!(rule_1.connector_selection.is_none());
+
+        let rule_2 = diff
+            .changed_rules
+            .iter()
+            .find(|rule| rule.rule_name == "rule_2")
+            .expect("rule_2 is not reported as changed");
+        assert!(!rule_2.conditions_changed);
+        assert!(rule_2.order_changed);
+        assert!(rule_2.connector_selection.is_some());
+    }
+
+    #[test]
+    fn test_diff_of_non_advanced_algorithms() {
+        let from = advanced("default: [stripe]\n\nrule_1: [adyen]\n{\n    amount > 100\n}\n");
+        let to = routing_types::RoutingAlgorithm::Priority(vec![
+            routing_types::RoutableConnectorChoice {
+                choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+                connector: api_models::enums::RoutableConnectors::Stripe,
+                merchant_connector_id: None,
+            },
+        ]);
+
+        let diff = diff_routing_algorithms(algorithm_id("from"), from, algorithm_id("to"), to);
+
+        assert_eq!(diff.removed_rules, vec!["rule_1".to_string()]);
+        assert!(diff.default_selection.is_none());
+    }
+}
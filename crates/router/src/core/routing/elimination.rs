@@ -0,0 +1,558 @@
+//! Elimination of connectors whose payments fail due to the connector
+//!
+//! The state of a connector for a payment method, referred to as a bucket, is stored in Redis.
+//! Payments failing with a timeout, or with an error for which the GSM rules of the connector
+//! suggest retrying or requeuing the payment, count as failures of the bucket, while other
+//! outcomes show that the connector is processing payments. Failures are counted in fixed windows,
+//! and a bucket whose failures within a window reach the failure threshold is eliminated for the
+//! cooldown period. Once the cooldown period ends, the bucket is half-open: a single payment at a
+//! time is routed through it as a probe, and the outcome of the probe either restores the bucket
+//! or eliminates it again.
+//!
+//! Failures are counted atomically, but failures recorded while a window is being reset may not be
+//! counted, which only delays the elimination marginally.
+
+use std::{collections::HashMap, str::FromStr};
+
+use api_models::{
+    enums::{PaymentMethod, RoutableConnectors},
+    routing::{
+        EliminationBucket, EliminationReason, EliminationRoutingConfig, EliminationStatus,
+        RoutableConnectorChoice,
+    },
+};
+use error_stack::ResultExt;
+use redis_interface::{types::SetnxReply, RedisConnectionPool};
+
+use crate::{
+    consts,
+    core::errors::{self, CustomResult},
+    logger,
+    routes::SessionState,
+    types::storage,
+    utils::StringExt,
+};
+
+type RoutingResult<O> = CustomResult<O, errors::RoutingError>;
+
+/// The time after which the states of the buckets of a profile which are no longer updated expire,
+/// in seconds
+const ELIMINATION_STATE_TTL_IN_SECS: i64 = 604800;
+
+/// The elimination parameters of a profile, with the defaults applied to those not configured
+#[derive(Debug, Clone, Copy)]
+pub struct EliminationParams {
+    pub failure_threshold: u64,
+    pub failure_window_in_secs: i64,
+    pub cooldown_in_secs: i64,
+    pub probe_timeout_in_secs: i64,
+}
+
+impl From<&EliminationRoutingConfig> for EliminationParams {
+    fn from(config: &EliminationRoutingConfig) -> Self {
+        let default_config = EliminationRoutingConfig::default();
+        let into_secs = |secs: Option<u64>, default_secs: Option<u64>| {
+            i64::try_from(secs.or(default_secs).unwrap_or_default()).unwrap_or(i64::MAX)
+        };
+
+        Self {
+            failure_threshold: config
+                .failure_threshold
+                .or(default_config.failure_threshold)
+                .map(u64::from)
+                .unwrap_or(1),
+            failure_window_in_secs: into_secs(
+                config.failure_window_in_secs,
+                default_config.failure_window_in_secs,
+            ),
+            cooldown_in_secs: into_secs(config.cooldown_in_secs, default_config.cooldown_in_secs),
+            probe_timeout_in_secs: into_secs(
+                config.probe_timeout_in_secs,
+                default_config.probe_timeout_in_secs,
+            ),
+        }
+    }
+}
+
+/// The outcome of a payment, as far as the health of its connector is concerned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentOutcome {
+    ConnectorFailure,
+    Processed,
+}
+
+impl PaymentOutcome {
+    pub fn new(error_code: Option<&str>, gsm: Option<&storage::gsm::GatewayStatusMap>) -> Self {
+        let is_timeout = error_code == Some(consts::REQUEST_TIMEOUT_ERROR_CODE);
+        let is_connector_error = gsm
+            .and_then(|gsm| api_models::gsm::GsmDecision::from_str(&gsm.decision).ok())
+            .is_some_and(|decision| {
+                matches!(
+                    decision,
+                    api_models::gsm::GsmDecision::Retry | api_models::gsm::GsmDecision::Requeue
+                )
+            });
+
+        if is_timeout || is_connector_error {
+            Self::ConnectorFailure
+        } else {
+            Self::Processed
+        }
+    }
+}
+
+/// The state of a bucket, as stored in Redis
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BucketState {
+    pub connector: RoutableConnectors,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub payment_method: PaymentMethod,
+    pub reason: Option<EliminationReason>,
+    pub eliminated_at: Option<i64>,
+    pub eliminated_until: Option<i64>,
+    pub updated_at: i64,
+}
+
+impl BucketState {
+    pub fn eliminated(
+        connector: RoutableConnectors,
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+        payment_method: PaymentMethod,
+        reason: EliminationReason,
+        duration_in_secs: i64,
+        now: i64,
+    ) -> Self {
+        Self {
+            connector,
+            merchant_connector_id,
+            payment_method,
+            reason: Some(reason),
+            eliminated_at: Some(now),
+            eliminated_until: Some(now.saturating_add(duration_in_secs)),
+            updated_at: now,
+        }
+    }
+
+    pub fn restored(self, now: i64) -> Self {
+        Self {
+            reason: None,
+            eliminated_until: None,
+            updated_at: now,
+            ..self
+        }
+    }
+
+    pub fn status(&self, now: i64) -> EliminationStatus {
+        match self.eliminated_until {
+            None => EliminationStatus::Active,
+            Some(eliminated_until) if now < eliminated_until => EliminationStatus::Eliminated,
+            Some(_) => EliminationStatus::HalfOpen,
+        }
+    }
+
+    pub fn into_bucket(self, now: i64) -> EliminationBucket {
+        EliminationBucket {
+            status: self.status(now),
+            connector: self.connector,
+            merchant_connector_id: self.merchant_connector_id,
+            payment_method: self.payment_method,
+            reason: self.reason,
+            eliminated_at: self.eliminated_at,
+            eliminated_until: self.eliminated_until,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+fn get_bucket_states_key(profile_id: &common_utils::id_type::ProfileId) -> String {
+    format!("elimination_routing_{}", profile_id.get_string_repr())
+}
+
+fn get_bucket_field(
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    payment_method: PaymentMethod,
+) -> String {
+    format!(
+        "{}:{payment_method}",
+        merchant_connector_id.get_string_repr()
+    )
+}
+
+fn get_failures_key(profile_id: &common_utils::id_type::ProfileId, bucket_field: &str) -> String {
+    format!(
+        "elimination_routing_failures_{}_{bucket_field}",
+        profile_id.get_string_repr()
+    )
+}
+
+fn get_probe_key(profile_id: &common_utils::id_type::ProfileId, bucket_field: &str) -> String {
+    format!(
+        "elimination_routing_probe_{}_{bucket_field}",
+        profile_id.get_string_repr()
+    )
+}
+
+fn get_redis_conn(state: &SessionState) -> RoutingResult<std::sync::Arc<RedisConnectionPool>> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::RoutingError::EliminationStateFetchFailed)
+        .attach_printable("Failed to get redis connection")
+}
+
+/// Returns the states of the buckets of the profile, keyed by their merchant connector account
+/// and payment method. States which cannot be parsed are skipped.
+pub async fn get_bucket_states(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RoutingResult<HashMap<String, BucketState>> {
+    let bucket_states = get_redis_conn(state)?
+        .get_hash_fields::<HashMap<String, String>>(&get_bucket_states_key(profile_id))
+        .await
+        .change_context(errors::RoutingError::EliminationStateFetchFailed)?;
+
+    Ok(bucket_states
+        .into_iter()
+        .filter_map(|(field, bucket_state)| {
+            bucket_state
+                .parse_struct::<BucketState>("BucketState")
+                .map_err(
+                    |error| logger::warn!(?error, %field, "Failed to parse the elimination state"),
+                )
+                .ok()
+                .map(|bucket_state| (field, bucket_state))
+        })
+        .collect())
+}
+
+async fn set_bucket_state(
+    redis_conn: &RedisConnectionPool,
+    profile_id: &common_utils::id_type::ProfileId,
+    bucket_state: &BucketState,
+) -> RoutingResult<()> {
+    let field = get_bucket_field(
+        &bucket_state.merchant_connector_id,
+        bucket_state.payment_method,
+    );
+    let bucket_state = serde_json::to_string(bucket_state)
+        .change_context(errors::RoutingError::EliminationStateUpdateFailed)?;
+
+    redis_conn
+        .set_hash_fields(
+            &get_bucket_states_key(profile_id),
+            vec![(field, bucket_state)],
+            Some(ELIMINATION_STATE_TTL_IN_SECS),
+        )
+        .await
+        .change_context(errors::RoutingError::EliminationStateUpdateFailed)
+}
+
+/// Removes the eliminated connectors, and the half-open connectors which cannot be probed, from
+/// the connectors eligible for the payment. A half-open connector is probed when it would be the
+/// first connector and no other probe is in progress; otherwise it is moved after the connectors
+/// which are active, so that it is only used as a fallback.
+pub async fn filter_eliminated_connectors(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    params: EliminationParams,
+    connectors: Vec<RoutableConnectorChoice>,
+    payment_method: PaymentMethod,
+) -> RoutingResult<Vec<RoutableConnectorChoice>> {
+    let bucket_states = get_bucket_states(state, profile_id).await?;
+    if bucket_states.is_empty() {
+        return Ok(connectors);
+    }
+
+    let redis_conn = get_redis_conn(state)?;
+    let now = common_utils::date_time::now_unix_timestamp();
+    let mut routable_connectors = Vec::with_capacity(connectors.len());
+    let mut demoted_connectors = Vec::new();
+
+    for connector in connectors {
+        let Some(merchant_connector_id) = &connector.merchant_connector_id else {
+            routable_connectors.push(connector);
+            continue;
+        };
+        let field = get_bucket_field(merchant_connector_id, payment_method);
+
+        match bucket_states
+            .get(&field)
+            .map(|bucket_state| bucket_state.status(now))
+            .unwrap_or(EliminationStatus::Active)
+        {
+            EliminationStatus::Active => routable_connectors.push(connector),
+            EliminationStatus::Eliminated => {}
+            EliminationStatus::HalfOpen => {
+                let is_probe = routable_connectors.is_empty()
+                    && redis_conn
+                        .set_key_if_not_exists_with_expiry(
+                            &get_probe_key(profile_id, &field),
+                            now,
+                            Some(params.probe_timeout_in_secs),
+                        )
+                        .await
+                        .change_context(errors::RoutingError::EliminationStateUpdateFailed)?
+                        == SetnxReply::KeySet;
+
+                if is_probe {
+                    logger::info!(
+                        connector = %connector,
+                        ?payment_method,
+                        "Probing half-open connector"
+                    );
+                    routable_connectors.push(connector);
+                } else {
+                    demoted_connectors.push(connector);
+                }
+            }
+        }
+    }
+
+    routable_connectors.extend(demoted_connectors);
+    Ok(routable_connectors)
+}
+
+/// Updates the state of the bucket of the connector through which a payment was processed
+pub async fn record_payment_outcome(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    params: EliminationParams,
+    connector: RoutableConnectors,
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    payment_method: PaymentMethod,
+    outcome: PaymentOutcome,
+) -> RoutingResult<()> {
+    let redis_conn = get_redis_conn(state)?;
+    let field = get_bucket_field(merchant_connector_id, payment_method);
+    let now = common_utils::date_time::now_unix_timestamp();
+    let bucket_state = get_bucket_states(state, profile_id).await?.remove(&field);
+    let status = bucket_state
+        .as_ref()
+        .map(|bucket_state| bucket_state.status(now))
+        .unwrap_or(EliminationStatus::Active);
+
+    match (outcome, status) {
+        // Payments routed before the connector was eliminated do not affect its elimination
+        (_, EliminationStatus::Eliminated)
+        | (PaymentOutcome::Processed, EliminationStatus::Active) => Ok(()),
+        (PaymentOutcome::Processed, EliminationStatus::HalfOpen) => {
+            if let Some(bucket_state) = bucket_state {
+                set_bucket_state(&redis_conn, profile_id, &bucket_state.restored(now)).await?;
+                logger::info!(connector = %connector, ?payment_method, "Restored connector after a successful probe");
+            }
+            release_probe(&redis_conn, profile_id, &field).await
+        }
+        (PaymentOutcome::ConnectorFailure, EliminationStatus::HalfOpen) => {
+            let bucket_state = BucketState::eliminated(
+                connector,
+                merchant_connector_id.clone(),
+                payment_method,
+                EliminationReason::FailureThreshold,
+                params.cooldown_in_secs,
+                now,
+            );
+            set_bucket_state(&redis_conn, profile_id, &bucket_state).await?;
+            logger::info!(connector = %connector, ?payment_method, "Eliminated connector after a failed probe");
+            release_probe(&redis_conn, profile_id, &field).await
+        }
+        (PaymentOutcome::ConnectorFailure, EliminationStatus::Active) => {
+            let failures_key = get_failures_key(profile_id, &field);
+            let failure_count = count_failure(
+                &redis_conn,
+                &failures_key,
+                params.failure_window_in_secs,
+                now,
+            )
+            .await?;
+            if failure_count < params.failure_threshold {
+                return Ok(());
+            }
+
+            let bucket_state = BucketState::eliminated(
+                connector,
+                merchant_connector_id.clone(),
+                payment_method,
+                EliminationReason::FailureThreshold,
+                params.cooldown_in_secs,
+                now,
+            );
+            set_bucket_state(&redis_conn, profile_id, &bucket_state).await?;
+            logger::info!(
+                connector = %connector,
+                ?payment_method,
+                failure_count,
+                "Eliminated connector after crossing the failure threshold"
+            );
+            redis_conn
+                .delete_key(&failures_key)
+                .await
+                .change_context(errors::RoutingError::EliminationStateUpdateFailed)?;
+            Ok(())
+        }
+    }
+}
+
+/// Counts a failure in the current window of the bucket, and returns the failures of the window
+async fn count_failure(
+    redis_conn: &RedisConnectionPool,
+    failures_key: &str,
+    failure_window_in_secs: i64,
+    now: i64,
+) -> RoutingResult<u64> {
+    let ttl = u32::try_from(failure_window_in_secs).unwrap_or(u32::MAX);
+
+    redis_conn
+        .set_hash_field_if_not_exist(failures_key, "window_started_at", now, Some(ttl))
+        .await
+        .change_context(errors::RoutingError::EliminationStateUpdateFailed)?;
+    let window_started_at = redis_conn
+        .get_hash_field::<Option<i64>>(failures_key, "window_started_at")
+        .await
+        .change_context(errors::RoutingError::EliminationStateUpdateFailed)?
+        .unwrap_or(now);
+
+    if now.saturating_sub(window_started_at) >= failure_window_in_secs {
+        redis_conn
+            .delete_key(failures_key)
+            .await
+            .change_context(errors::RoutingError::EliminationStateUpdateFailed)?;
+        redis_conn
+            .set_hash_field_if_not_exist(failures_key, "window_started_at", now, Some(ttl))
+            .await
+            .change_context(errors::RoutingError::EliminationStateUpdateFailed)?;
+    }
+
+    let failure_counts = redis_conn
+        .increment_fields_in_hash(failures_key, &[("failure_count", 1)])
+        .await
+        .change_context(errors::RoutingError::EliminationStateUpdateFailed)?;
+
+    Ok(failure_counts
+        .first()
+        .copied()
+        .and_then(|failure_count| u64::try_from(failure_count).ok())
+        .unwrap_or_default())
+}
+
+async fn release_probe(
+    redis_conn: &RedisConnectionPool,
+    profile_id: &common_utils::id_type::ProfileId,
+    bucket_field: &str,
+) -> RoutingResult<()> {
+    redis_conn
+        .delete_key(&get_probe_key(profile_id, bucket_field))
+        .await
+        .change_context(errors::RoutingError::EliminationStateUpdateFailed)?;
+    Ok(())
+}
+
+/// Eliminates or restores the bucket of a connector, irrespective of its failures
+pub async fn override_bucket_state(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    connector: RoutableConnectors,
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    payment_method: PaymentMethod,
+    action: api_models::routing::EliminationOverrideAction,
+) -> RoutingResult<EliminationBucket> {
+    let redis_conn = get_redis_conn(state)?;
+    let field = get_bucket_field(merchant_connector_id, payment_method);
+    let now = common_utils::date_time::now_unix_timestamp();
+
+    let bucket_state = match action {
+        api_models::routing::EliminationOverrideAction::Eliminate { duration_in_secs } => {
+            BucketState::eliminated(
+                connector,
+                merchant_connector_id.clone(),
+                payment_method,
+                EliminationReason::Manual,
+                i64::try_from(duration_in_secs).unwrap_or(i64::MAX),
+                now,
+            )
+        }
+        api_models::routing::EliminationOverrideAction::Restore => {
+            redis_conn
+                .delete_key(&get_failures_key(profile_id, &field))
+                .await
+                .change_context(errors::RoutingError::EliminationStateUpdateFailed)?;
+            BucketState {
+                connector,
+                merchant_connector_id: merchant_connector_id.clone(),
+                payment_method,
+                reason: None,
+                eliminated_at: None,
+                eliminated_until: None,
+                updated_at: now,
+            }
+        }
+    };
+
+    set_bucket_state(&redis_conn, profile_id, &bucket_state).await?;
+    release_probe(&redis_conn, profile_id, &field).await?;
+
+    Ok(bucket_state.into_bucket(now))
+}
+
+#[cfg(test)]
+mod tests {
+    use common_utils::id_type::GenerateId;
+
+    use super::*;
+
+    fn bucket_state(eliminated_until: Option<i64>) -> BucketState {
+        BucketState {
+            connector: RoutableConnectors::Stripe,
+            merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId::generate(),
+            payment_method: PaymentMethod::Card,
+            reason: eliminated_until.map(|_| EliminationReason::FailureThreshold),
+            eliminated_at: eliminated_until.map(|_| 0),
+            eliminated_until,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_bucket_status() {
+        assert_eq!(bucket_state(None).status(100), EliminationStatus::Active);
+        assert_eq!(
+            bucket_state(Some(300)).status(100),
+            EliminationStatus::Eliminated
+        );
+        assert_eq!(
+            bucket_state(Some(300)).status(300),
+            EliminationStatus::HalfOpen
+        );
+        assert_eq!(
+            bucket_state(Some(300)).restored(400).status(400),
+            EliminationStatus::Active
+        );
+    }
+
+    #[test]
+    fn test_payment_outcome() {
+        assert_eq!(
+            PaymentOutcome::new(Some(consts::REQUEST_TIMEOUT_ERROR_CODE), None),
+            PaymentOutcome::ConnectorFailure
+        );
+        assert_eq!(
+            PaymentOutcome::new(Some("card_declined"), None),
+            PaymentOutcome::Processed
+        );
+        assert_eq!(PaymentOutcome::new(None, None), PaymentOutcome::Processed);
+    }
+
+    #[test]
+    fn test_params_fall_back_to_defaults() {
+        let params = EliminationParams::from(&EliminationRoutingConfig {
+            failure_threshold: Some(3),
+            failure_window_in_secs: None,
+            cooldown_in_secs: None,
+            probe_timeout_in_secs: None,
+        });
+
+        assert_eq!(params.failure_threshold, 3);
+        assert_eq!(params.failure_window_in_secs, 60);
+        assert_eq!(params.cooldown_in_secs, 300);
+        assert_eq!(params.probe_timeout_in_secs, 60);
+    }
+}
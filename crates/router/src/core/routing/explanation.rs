@@ -0,0 +1,483 @@
+//! Explanation of the eligibility of connectors for a payment
+//!
+//! The constraint graph of the profile is analysed for every connector in the same way as during
+//! the eligibility analysis, after which the failed nodes recorded during the analysis are followed
+//! down to the constraints at which the failures originated. The merchant connector account and the
+//! configuration a constraint belongs to are taken from the metadata attached to the nodes of the
+//! graph while it was built.
+//!
+//! A payment fails the constraints of every payment method it doesn't use, so those are only
+//! reported when no other constraint failed, as the payment method not being enabled.
+
+use std::collections::HashSet;
+
+use api_models::{admin as admin_api, enums as api_enums, routing as routing_types};
+use error_stack::ResultExt;
+use euclid::{
+    backend::inputs as dsl_inputs,
+    dssa::graph::{AnalysisContext, CgraphExt},
+    frontend::{ast, dir},
+    types::NumValueRefinement,
+};
+use hyperswitch_constraint_graph::{self as cgraph, CheckingContext, NodeViz};
+use kgraph_utils::{transformers::IntoContext, types::NodeMetadata};
+
+use crate::core::errors::{self, CustomResult};
+
+type RoutingResult<O> = CustomResult<O, errors::RoutingError>;
+
+/// Explains the eligibility of the connectors of the merchant connector accounts for the routing
+/// inputs. If the inputs have no payment method set, the eligibility is explained for every
+/// payment method enabled on the accounts instead.
+pub fn explain_connectors(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    merchant_connector_accounts: &[admin_api::MerchantConnectorResponse],
+    backend_input: dsl_inputs::BackendInput,
+    connectors_filter: Option<&[api_enums::RoutableConnectors]>,
+) -> RoutingResult<Vec<routing_types::ConnectorEligibilityExplanation>> {
+    let mut connectors = Vec::new();
+    for connector in merchant_connector_accounts.iter().filter_map(|mca| {
+        mca.connector_name
+            .parse::<api_enums::RoutableConnectors>()
+            .ok()
+    }) {
+        if !connectors.contains(&connector)
+            && connectors_filter.map_or(true, |filter| filter.contains(&connector))
+        {
+            connectors.push(connector);
+        }
+    }
+
+    let inputs = if backend_input.payment_method.payment_method.is_some() {
+        vec![backend_input]
+    } else {
+        get_enabled_payment_methods(merchant_connector_accounts)
+            .into_iter()
+            .map(|(payment_method, payment_method_type)| {
+                let mut input = backend_input.clone();
+                input.payment_method.payment_method = Some(payment_method);
+                input.payment_method.payment_method_type = payment_method_type;
+                input
+            })
+            .collect()
+    };
+
+    let mut explanations = Vec::new();
+    for input in inputs {
+        let payment_method = input.payment_method.payment_method;
+        let payment_method_type = input.payment_method.payment_method_type;
+        let context = AnalysisContext::from_dir_values(
+            input
+                .into_context()
+                .change_context(errors::RoutingError::KgraphAnalysisError)?,
+        );
+
+        for connector in &connectors {
+            let reasons = explain_connector(graph, &context, *connector)?;
+            explanations.push(routing_types::ConnectorEligibilityExplanation {
+                connector: *connector,
+                payment_method,
+                payment_method_type,
+                eligible: reasons.is_empty(),
+                reasons,
+            });
+        }
+    }
+
+    Ok(explanations)
+}
+
+fn get_enabled_payment_methods(
+    merchant_connector_accounts: &[admin_api::MerchantConnectorResponse],
+) -> Vec<(
+    api_enums::PaymentMethod,
+    Option<api_enums::PaymentMethodType>,
+)> {
+    let mut payment_methods = Vec::new();
+    for enabled in merchant_connector_accounts
+        .iter()
+        .filter_map(|mca| mca.payment_methods_enabled.as_ref())
+        .flatten()
+    {
+        let payment_method_types = enabled
+            .payment_method_types
+            .iter()
+            .flatten()
+            .map(|pm_type| Some(pm_type.payment_method_type))
+            .collect::<Vec<_>>();
+
+        for payment_method_type in payment_method_types {
+            let payment_method = (enabled.payment_method, payment_method_type);
+            if !payment_methods.contains(&payment_method) {
+                payment_methods.push(payment_method);
+            }
+        }
+    }
+
+    payment_methods
+}
+
+fn explain_connector(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    context: &AnalysisContext,
+    connector: api_enums::RoutableConnectors,
+) -> RoutingResult<Vec<routing_types::IneligibilityReason>> {
+    let connector_value = dir::DirValue::Connector(Box::new(ast::ConnectorChoice { connector }));
+    let Some(unsatisfied) = graph
+        .explain_value_validity(connector_value, context, None)
+        .change_context(errors::RoutingError::KgraphAnalysisError)?
+    else {
+        return Ok(vec![routing_types::IneligibilityReason {
+            message: format!(
+                "No enabled merchant connector account of {connector} exists in the profile"
+            ),
+            node: None,
+            node_description: None,
+            edge: None,
+            merchant_connector_id: None,
+            source: None,
+            constraint: Some(routing_types::EligibilityConstraintKind::Connector),
+            expected: Vec::new(),
+            found: None,
+        }]);
+    };
+
+    let (alternatives, failures): (Vec<_>, Vec<_>) = unsatisfied
+        .iter()
+        .map(|constraint| (constraint, resolve_metadata(graph, constraint)))
+        .partition(|(constraint, metadata)| {
+            is_unused_payment_method(constraint, metadata.as_ref())
+        });
+
+    if !failures.is_empty() {
+        return Ok(failures
+            .into_iter()
+            .map(|(constraint, metadata)| make_reason(graph, context, constraint, metadata))
+            .collect());
+    }
+
+    let mut reported_nodes = HashSet::new();
+    let mut reasons = Vec::new();
+    for (constraint, metadata) in alternatives {
+        let reason = match find_payment_methods_node(graph, constraint) {
+            Some((edge, node_metadata)) => {
+                if !reported_nodes.insert(edge.pred) {
+                    continue;
+                }
+                make_payment_method_not_enabled_reason(graph, context, edge, node_metadata)
+            }
+            None => make_reason(graph, context, constraint, metadata),
+        };
+        reasons.push(reason);
+    }
+
+    Ok(reasons)
+}
+
+fn get_node_metadata(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    node_id: cgraph::NodeId,
+) -> Option<NodeMetadata> {
+    let metadata = graph.node_metadata.get(node_id).cloned().flatten()?;
+    serde_json::to_value(&*metadata)
+        .ok()
+        .and_then(|value| serde_json::from_value(value).ok())
+}
+
+/// Combines the metadata of the nodes from the analysed node down to the unsatisfied one, since the
+/// metadata of value nodes doesn't carry the merchant connector account they belong to
+fn resolve_metadata(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    constraint: &cgraph::UnsatisfiedConstraint<dir::DirValue>,
+) -> Option<NodeMetadata> {
+    constraint
+        .path
+        .iter()
+        .map(|edge| edge.succ)
+        .chain(std::iter::once(constraint.node_id))
+        .filter_map(|node_id| get_node_metadata(graph, node_id))
+        .reduce(|resolved, metadata| NodeMetadata {
+            merchant_connector_id: metadata
+                .merchant_connector_id
+                .or(resolved.merchant_connector_id),
+            connector: metadata.connector.or(resolved.connector),
+            source: metadata.source.or(resolved.source),
+            constraint: metadata.constraint,
+        })
+}
+
+/// Whether the constraint failed only because the payment doesn't use the payment method it
+/// requires
+fn is_unused_payment_method(
+    constraint: &cgraph::UnsatisfiedConstraint<dir::DirValue>,
+    metadata: Option<&NodeMetadata>,
+) -> bool {
+    let is_payment_method_constraint = metadata.map_or(false, |metadata| {
+        matches!(
+            metadata.constraint,
+            routing_types::EligibilityConstraintKind::PaymentMethod
+                | routing_types::EligibilityConstraintKind::PaymentMethodType
+                | routing_types::EligibilityConstraintKind::CardNetwork
+        )
+    });
+
+    is_payment_method_constraint
+        && matches!(
+            constraint.trace.as_ref(),
+            cgraph::AnalysisTrace::Value {
+                relation: cgraph::Relation::Positive,
+                predecessors: None,
+                ..
+            }
+        )
+}
+
+/// Finds the aggregator of the payment methods enabled on the merchant connector account, or
+/// configured in the payment method filters, which the constraint belongs to
+fn find_payment_methods_node<'a>(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    constraint: &'a cgraph::UnsatisfiedConstraint<dir::DirValue>,
+) -> Option<(&'a cgraph::Edge, NodeMetadata)> {
+    constraint.path.iter().find_map(|edge| {
+        get_node_metadata(graph, edge.pred)
+            .filter(|metadata| {
+                metadata.merchant_connector_id.is_some()
+                    && matches!(
+                        metadata.constraint,
+                        routing_types::EligibilityConstraintKind::PaymentMethod
+                            | routing_types::EligibilityConstraintKind::PaymentMethodType
+                    )
+            })
+            .map(|metadata| (edge, metadata))
+    })
+}
+
+fn make_payment_method_not_enabled_reason(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    context: &AnalysisContext,
+    edge: &cgraph::Edge,
+    metadata: NodeMetadata,
+) -> routing_types::IneligibilityReason {
+    let payment_methods = [
+        dir::DirKeyKind::PaymentMethod,
+        dir::DirKeyKind::CardType,
+        dir::DirKeyKind::PayLaterType,
+        dir::DirKeyKind::WalletType,
+        dir::DirKeyKind::BankRedirectType,
+        dir::DirKeyKind::BankDebitType,
+        dir::DirKeyKind::BankTransferType,
+        dir::DirKeyKind::CryptoType,
+        dir::DirKeyKind::RewardType,
+        dir::DirKeyKind::UpiType,
+        dir::DirKeyKind::VoucherType,
+        dir::DirKeyKind::GiftCardType,
+        dir::DirKeyKind::CardRedirectType,
+        dir::DirKeyKind::RealTimePaymentType,
+        dir::DirKeyKind::OpenBankingType,
+    ]
+    .into_iter()
+    .flat_map(|kind| get_context_values(context, &dir::DirKey::new(kind, None)))
+    .collect::<Vec<_>>()
+    .join(", ");
+
+    let location = describe_location(Some(&metadata));
+    let message = match metadata.source {
+        Some(routing_types::EligibilityConstraintSource::PmFilters) => format!(
+            "The payment method [{payment_methods}] is not allowed by the payment method \
+             filters{location}"
+        ),
+        _ => format!("The payment method [{payment_methods}] is not enabled{location}"),
+    };
+
+    routing_types::IneligibilityReason {
+        message,
+        node: Some(edge.pred.get_viz_id()),
+        node_description: get_node_description(graph, edge.pred),
+        edge: Some(make_edge(edge)),
+        merchant_connector_id: metadata.merchant_connector_id,
+        source: metadata.source,
+        constraint: Some(metadata.constraint),
+        expected: Vec::new(),
+        found: Some(payment_methods),
+    }
+}
+
+fn make_reason(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    context: &AnalysisContext,
+    constraint: &cgraph::UnsatisfiedConstraint<dir::DirValue>,
+    metadata: Option<NodeMetadata>,
+) -> routing_types::IneligibilityReason {
+    let location = describe_location(metadata.as_ref());
+    let (message, expected, found) = match constraint.trace.as_ref() {
+        cgraph::AnalysisTrace::InAggregation {
+            expected,
+            found,
+            relation,
+            ..
+        } => {
+            let key = expected
+                .first()
+                .map(|value| value.get_key().viz())
+                .unwrap_or_default();
+            let mut expected = expected.iter().map(NodeViz::viz).collect::<Vec<_>>();
+            expected.sort();
+            let found = found.as_ref().map(NodeViz::viz);
+
+            let message = match (relation, &found) {
+                (cgraph::Relation::Positive, Some(found)) => format!(
+                    "The {key} {found} is not one of the accepted values [{}]{location}",
+                    expected.join(", ")
+                ),
+                (cgraph::Relation::Positive, None) => format!(
+                    "The {key} must be one of [{}], but was not provided{location}",
+                    expected.join(", ")
+                ),
+                (cgraph::Relation::Negative, Some(found)) => format!(
+                    "The {key} {found} is one of the disallowed values [{}]{location}",
+                    expected.join(", ")
+                ),
+                (cgraph::Relation::Negative, None) => format!(
+                    "The {key} must not be one of [{}]{location}",
+                    expected.join(", ")
+                ),
+            };
+            (message, expected, found)
+        }
+        cgraph::AnalysisTrace::Value {
+            value, relation, ..
+        } => {
+            let (requirement, found) = match value {
+                cgraph::NodeValue::Key(key) => {
+                    (format!("a {}", key.viz()), get_context_values(context, key))
+                }
+                cgraph::NodeValue::Value(value) => (
+                    describe_value(value),
+                    get_context_values(context, &value.get_key()),
+                ),
+            };
+            let found = (!found.is_empty()).then(|| found.join(", "));
+
+            let message = match relation {
+                cgraph::Relation::Positive => match &found {
+                    Some(found) => {
+                        format!("Requires {requirement}, but the payment has {found}{location}")
+                    }
+                    None => format!("Requires {requirement}, which was not provided{location}"),
+                },
+                cgraph::Relation::Negative => {
+                    format!("Does not allow {requirement}{location}")
+                }
+            };
+            (message, vec![requirement], found)
+        }
+        cgraph::AnalysisTrace::Contradiction { .. } => (
+            format!("The constraints of the node contradict each other{location}"),
+            Vec::new(),
+            None,
+        ),
+        cgraph::AnalysisTrace::AllAggregation { .. }
+        | cgraph::AnalysisTrace::AnyAggregation { .. } => (
+            format!(
+                "{} is not satisfied{location}",
+                get_node_description(graph, constraint.node_id)
+                    .unwrap_or_else(|| "The aggregation".to_string())
+            ),
+            Vec::new(),
+            None,
+        ),
+    };
+
+    routing_types::IneligibilityReason {
+        message,
+        node: Some(constraint.node_id.get_viz_id()),
+        node_description: get_node_description(graph, constraint.node_id),
+        edge: constraint.path.last().map(make_edge),
+        merchant_connector_id: metadata
+            .as_ref()
+            .and_then(|metadata| metadata.merchant_connector_id.clone()),
+        source: metadata.as_ref().and_then(|metadata| metadata.source),
+        constraint: metadata.map(|metadata| metadata.constraint),
+        expected,
+        found,
+    }
+}
+
+fn describe_value(value: &dir::DirValue) -> String {
+    let key = value.get_key().viz();
+    match value {
+        dir::DirValue::PaymentAmount(amount) => {
+            let comparison = match amount.refinement {
+                None => "=",
+                Some(NumValueRefinement::NotEqual) => "!=",
+                Some(NumValueRefinement::GreaterThan) => ">",
+                Some(NumValueRefinement::LessThan) => "<",
+                Some(NumValueRefinement::GreaterThanEqual) => ">=",
+                Some(NumValueRefinement::LessThanEqual) => "<=",
+            };
+            format!("{key} {comparison} {}", amount.number)
+        }
+        _ => format!("{key} = {}", value.viz()),
+    }
+}
+
+fn describe_location(metadata: Option<&NodeMetadata>) -> String {
+    let Some(metadata) = metadata else {
+        return String::new();
+    };
+
+    match (
+        metadata.source,
+        &metadata.merchant_connector_id,
+        metadata.connector,
+    ) {
+        (Some(routing_types::EligibilityConstraintSource::PmFilters), _, Some(connector)) => {
+            format!(" in the payment method filters configured for {connector}")
+        }
+        (_, Some(merchant_connector_id), Some(connector)) => format!(
+            " on merchant connector account {} of {connector}",
+            merchant_connector_id.get_string_repr()
+        ),
+        (_, Some(merchant_connector_id), None) => format!(
+            " on merchant connector account {}",
+            merchant_connector_id.get_string_repr()
+        ),
+        (_, None, Some(connector)) => format!(" for {connector}"),
+        (_, None, None) => String::new(),
+    }
+}
+
+fn get_context_values(context: &AnalysisContext, key: &dir::DirKey) -> Vec<String> {
+    let mut values = context
+        .get_values_by_key(key)
+        .unwrap_or_default()
+        .iter()
+        .map(|value| match value {
+            dir::DirValue::PaymentAmount(amount) => amount.number.to_string(),
+            _ => value.viz(),
+        })
+        .collect::<Vec<_>>();
+    values.sort();
+    values
+}
+
+fn get_node_description(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    node_id: cgraph::NodeId,
+) -> Option<String> {
+    graph
+        .node_info
+        .get(node_id)
+        .cloned()
+        .flatten()
+        .map(str::to_string)
+}
+
+fn make_edge(edge: &cgraph::Edge) -> routing_types::ConstraintGraphEdge {
+    routing_types::ConstraintGraphEdge {
+        from: edge.pred.get_viz_id(),
+        to: edge.succ.get_viz_id(),
+        relation: edge.relation.to_string(),
+        strength: edge.strength.to_string(),
+    }
+}
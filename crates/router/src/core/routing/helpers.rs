@@ -30,7 +30,10 @@ use crate::{
     utils::StringExt,
 };
 #[cfg(all(feature = "dynamic_routing", feature = "v1"))]
-use crate::{core::metrics as core_metrics, routes::metrics};
+use crate::{
+    core::{metrics as core_metrics, payments, routing::elimination},
+    routes::metrics,
+};
 
 /// Provides us with all the configured configs of the Merchant in the ascending time configured
 /// manner and chooses the first of them
@@ -194,6 +197,15 @@ pub async fn update_merchant_active_algorithm_ref(
     todo!()
 }
 
+/// A change of the active routing algorithm of a profile, which is recorded in the activation
+/// history of the profile
+#[cfg(feature = "v1")]
+pub struct RoutingActivation {
+    pub activation_type: storage::enums::RoutingActivationType,
+    pub previous_algorithm_id: Option<id_type::RoutingId>,
+    pub activated_by: Option<String>,
+}
+
 #[cfg(feature = "v1")]
 pub async fn update_profile_active_algorithm_ref(
     db: &dyn StorageInterface,
@@ -202,7 +214,9 @@ pub async fn update_profile_active_algorithm_ref(
     current_business_profile: domain::Profile,
     algorithm_id: routing_types::RoutingAlgorithmRef,
     transaction_type: &storage::enums::TransactionType,
+    routing_activation: Option<RoutingActivation>,
 ) -> RouterResult<()> {
+    let active_algorithm_id = algorithm_id.algorithm_id.clone();
     let ref_val = algorithm_id
         .encode_to_value()
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -220,6 +234,36 @@ pub async fn update_profile_active_algorithm_ref(
         )
         .into(),
     );
+    let shadow_routing_cache_key = cache::CacheKind::Routing(
+        format!(
+            "routing_config_shadow_{}_{}",
+            merchant_id.get_string_repr(),
+            profile_id.get_string_repr(),
+        )
+        .into(),
+    );
+
+    let mut routing_cache_keys = vec![
+        routing_cache_key,
+        shadow_routing_cache_key,
+        cache::CacheKind::Routing(
+            get_fallback_routing_cache_key(
+                &merchant_id,
+                &profile_id,
+                routing_types::FallbackRoutingKind::Refund,
+            )
+            .into(),
+        ),
+    ];
+    #[cfg(feature = "payouts")]
+    routing_cache_keys.push(cache::CacheKind::Routing(
+        get_fallback_routing_cache_key(
+            &merchant_id,
+            &profile_id,
+            routing_types::FallbackRoutingKind::PayoutRetry,
+        )
+        .into(),
+    ));
 
     let (routing_algorithm, payout_routing_algorithm) = match transaction_type {
         storage::enums::TransactionType::Payment => (Some(ref_val), None),
@@ -232,23 +276,65 @@ pub async fn update_profile_active_algorithm_ref(
         payout_routing_algorithm,
     };
 
-    db.update_profile_by_profile_id(
-        key_manager_state,
-        merchant_key_store,
-        current_business_profile,
-        business_profile_update,
-    )
-    .await
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Failed to update routing algorithm ref in business profile")?;
+    match routing_activation {
+        Some(routing_activation) => {
+            let routing_activation = storage::RoutingActivationHistoryNew {
+                id: common_utils::generate_time_ordered_id("routing_activation"),
+                merchant_id,
+                profile_id,
+                algorithm_id: active_algorithm_id,
+                previous_algorithm_id: routing_activation.previous_algorithm_id,
+                algorithm_for: *transaction_type,
+                activation_type: routing_activation.activation_type,
+                activated_by: routing_activation.activated_by,
+                created_at: common_utils::date_time::now(),
+            };
+            db.update_profile_with_routing_activation(
+                key_manager_state,
+                merchant_key_store,
+                current_business_profile,
+                business_profile_update,
+                routing_activation,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to update routing algorithm ref in business profile along with the activation history",
+            )?;
+        }
+        None => {
+            db.update_profile_by_profile_id(
+                key_manager_state,
+                merchant_key_store,
+                current_business_profile,
+                business_profile_update,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update routing algorithm ref in business profile")?;
+        }
+    }
 
-    cache::publish_into_redact_channel(db.get_cache_store().as_ref(), [routing_cache_key])
+    cache::publish_into_redact_channel(db.get_cache_store().as_ref(), routing_cache_keys)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to invalidate routing cache")?;
     Ok(())
 }
 
+/// The key under which the fallback algorithm of the profile is cached in the routing cache
+pub fn get_fallback_routing_cache_key(
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    fallback_kind: routing_types::FallbackRoutingKind,
+) -> String {
+    format!(
+        "routing_config_{fallback_kind}_{}_{}",
+        merchant_id.get_string_repr(),
+        profile_id.get_string_repr(),
+    )
+}
+
 #[cfg(feature = "v1")]
 pub async fn update_business_profile_active_dynamic_algorithm_ref(
     db: &dyn StorageInterface,
@@ -659,13 +745,17 @@ pub async fn push_metrics_for_success_based_routing(
     business_profile: &domain::Profile,
     dynamic_routing_algorithm: serde_json::Value,
 ) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
     let client = state
         .grpc_client
         .dynamic_routing
-        .success_rate_client
-        .as_ref()
+        .get_success_rate_calculator(redis_conn)
         .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
-            message: "success_rate gRPC client not found".to_string(),
+            message: "success_rate calculator not found".to_string(),
         })?;
 
     let payment_connector = &payment_attempt.connector.clone().ok_or(
@@ -809,6 +899,81 @@ pub async fn push_metrics_for_success_based_routing(
     Ok(())
 }
 
+/// Records the outcome of a payment in the elimination state of its connector, when elimination
+/// routing is enabled for the profile
+#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
+#[instrument(skip_all)]
+pub async fn update_elimination_state_for_payment(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    business_profile: &domain::Profile,
+    dynamic_routing_algorithm: serde_json::Value,
+    flow_name: String,
+) -> RouterResult<()> {
+    let dynamic_routing_algorithm_ref = dynamic_routing_algorithm
+        .parse_value::<routing_types::DynamicRoutingAlgorithmRef>("DynamicRoutingAlgorithmRef")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse dynamic_routing_algorithm_ref")?;
+    let Some(elimination_algorithm_id) = dynamic_routing_algorithm_ref
+        .elimination_algorithm
+        .and_then(|elimination_algorithm| elimination_algorithm.algorithm_id)
+    else {
+        return Ok(());
+    };
+
+    // Only the outcomes of the requests routing the payment to the connector are considered
+    if flow_name != "Authorize" && flow_name != "CompleteAuthorize" {
+        return Ok(());
+    }
+    let (Some(payment_connector), Some(merchant_connector_id), Some(payment_method)) = (
+        payment_attempt.connector.as_ref(),
+        payment_attempt.merchant_connector_id.as_ref(),
+        payment_attempt.payment_method,
+    ) else {
+        return Ok(());
+    };
+
+    let gsm = match &payment_attempt.error_code {
+        Some(error_code) if error_code != crate::consts::REQUEST_TIMEOUT_ERROR_CODE => {
+            payments::helpers::get_gsm_record(
+                state,
+                Some(error_code.clone()),
+                payment_attempt.error_message.clone(),
+                payment_connector.clone(),
+                flow_name,
+            )
+            .await
+        }
+        _ => None,
+    };
+    let outcome =
+        elimination::PaymentOutcome::new(payment_attempt.error_code.as_deref(), gsm.as_ref());
+
+    let config = payments::routing::ensure_elimination_config_cached(
+        state,
+        business_profile.get_id(),
+        &elimination_algorithm_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to retrieve elimination routing config")?;
+
+    elimination::record_payment_outcome(
+        state,
+        business_profile.get_id(),
+        elimination::EliminationParams::from(config.as_ref()),
+        common_enums::RoutableConnectors::from_str(payment_connector.as_str())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to infer routable_connector from connector")?,
+        merchant_connector_id,
+        payment_method,
+        outcome,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to update the elimination state of the connector")
+}
+
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 fn get_desired_payment_status_for_success_routing_metrics(
     attempt_status: &common_enums::AttemptStatus,
@@ -0,0 +1,172 @@
+//! Routing inputs which are not recorded on the payment itself
+//!
+//! The segment of the customer and the amount of the payment in the reference currency of the
+//! merchant are looked up before routing, as configured for the profile. The day of the week and
+//! the hour of the day are derived from the creation time of the payment, in the timezone of the
+//! merchant. The inputs only refine the routing decision, so failures to look them up are logged
+//! and the inputs are left out.
+
+use api_models::routing::RoutingInputsConfig;
+use common_utils::{id_type, types::MinorUnit};
+use euclid::{backend::inputs as dsl_inputs, enums as euclid_enums};
+#[cfg(feature = "v1")]
+use masking::PeekInterface;
+use router_env::logger;
+use time::{PrimitiveDateTime, UtcOffset, Weekday};
+
+use crate::{db::StorageInterface, utils::StringExt};
+#[cfg(feature = "v1")]
+use crate::{
+    routes::SessionState,
+    types::{domain, storage},
+    utils::currency,
+};
+
+/// Routing inputs of a payment which are looked up before routing
+#[derive(Debug, Clone, Default)]
+pub struct AdditionalRoutingInputs {
+    pub customer_segment: Option<String>,
+    pub converted_amount: Option<MinorUnit>,
+    pub timezone_offset: Option<UtcOffset>,
+}
+
+impl AdditionalRoutingInputs {
+    /// The inputs which are derived from the configuration alone, without any lookups
+    pub fn from_config(config: &RoutingInputsConfig) -> Self {
+        Self {
+            customer_segment: None,
+            converted_amount: None,
+            timezone_offset: config
+                .timezone_offset_in_minutes
+                .and_then(|offset_in_minutes| {
+                    UtcOffset::from_whole_seconds(i32::from(offset_in_minutes) * 60)
+                        .map_err(|error| {
+                            logger::warn!(?error, "Invalid timezone offset configured")
+                        })
+                        .ok()
+                }),
+        }
+    }
+
+    /// The day of the week and the hour of the day of the given time, in the timezone of the
+    /// merchant
+    pub fn get_temporal_input(&self, time: PrimitiveDateTime) -> dsl_inputs::TemporalInput {
+        let local_time = time
+            .assume_utc()
+            .to_offset(self.timezone_offset.unwrap_or(UtcOffset::UTC));
+
+        dsl_inputs::TemporalInput {
+            day_of_week: Some(get_day_of_week(local_time.weekday())),
+            hour_of_day: Some(local_time.hour()),
+        }
+    }
+}
+
+fn get_day_of_week(weekday: Weekday) -> euclid_enums::DayOfWeek {
+    match weekday {
+        Weekday::Monday => euclid_enums::DayOfWeek::Monday,
+        Weekday::Tuesday => euclid_enums::DayOfWeek::Tuesday,
+        Weekday::Wednesday => euclid_enums::DayOfWeek::Wednesday,
+        Weekday::Thursday => euclid_enums::DayOfWeek::Thursday,
+        Weekday::Friday => euclid_enums::DayOfWeek::Friday,
+        Weekday::Saturday => euclid_enums::DayOfWeek::Saturday,
+        Weekday::Sunday => euclid_enums::DayOfWeek::Sunday,
+    }
+}
+
+/// Fetches the routing inputs configuration of the profile, the default configuration is returned
+/// when the profile has none
+pub async fn get_routing_inputs_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RoutingInputsConfig {
+    db.find_config_by_key_unwrap_or(
+        &profile_id.get_routing_inputs_config_key(),
+        Some("{}".to_string()),
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to fetch the routing inputs config"))
+    .ok()
+    .and_then(|config| {
+        config
+            .config
+            .parse_struct::<RoutingInputsConfig>("RoutingInputsConfig")
+            .map_err(|error| logger::error!(?error, "Failed to parse the routing inputs config"))
+            .ok()
+    })
+    .unwrap_or_default()
+}
+
+/// Looks up the routing inputs of the payment which are configured for the profile
+#[cfg(feature = "v1")]
+pub async fn get_additional_routing_inputs(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    payment_intent: &storage::PaymentIntent,
+    currency: common_enums::Currency,
+) -> AdditionalRoutingInputs {
+    let config = get_routing_inputs_config(&*state.store, profile_id).await;
+    let mut inputs = AdditionalRoutingInputs::from_config(&config);
+
+    if let Some(reference_currency) = config.reference_currency {
+        inputs.converted_amount =
+            get_converted_amount(state, payment_intent.amount, currency, reference_currency).await;
+    }
+
+    if let (Some(segment_key), Some(customer_id)) =
+        (config.customer_segment_key, &payment_intent.customer_id)
+    {
+        inputs.customer_segment = state
+            .store
+            .find_customer_optional_by_customer_id_merchant_id(
+                &state.into(),
+                customer_id,
+                merchant_account.get_id(),
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .map_err(|error| logger::warn!(?error, "Failed to fetch the customer for routing"))
+            .ok()
+            .flatten()
+            .and_then(|customer| customer.metadata)
+            .and_then(|metadata| {
+                metadata
+                    .peek()
+                    .get(&segment_key)
+                    .and_then(|segment| segment.as_str())
+                    .map(ToString::to_string)
+            });
+    }
+
+    inputs
+}
+
+#[cfg(feature = "v1")]
+async fn get_converted_amount(
+    state: &SessionState,
+    amount: MinorUnit,
+    currency: common_enums::Currency,
+    reference_currency: common_enums::Currency,
+) -> Option<MinorUnit> {
+    if currency == reference_currency {
+        return Some(amount);
+    }
+
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = currency::get_forex_rates(
+        state,
+        forex_api.call_delay,
+        forex_api.local_fetch_retry_delay,
+        forex_api.local_fetch_retry_count,
+    )
+    .await
+    .map_err(|error| logger::warn!(?error, "Failed to fetch the forex rates for routing"))
+    .ok()?;
+
+    currency::convert_minor_amount(&rates, amount, currency, reference_currency)
+        .map_err(|error| logger::warn!(?error, "Failed to convert the amount for routing"))
+        .ok()
+}
@@ -0,0 +1,517 @@
+//! Estimation of the cost of processing payments through merchant connector accounts
+//!
+//! The cost of a payment is estimated using the fee schedule of the merchant connector account
+//! it is processed through, with the fee of the most specific rule of the schedule which the
+//! payment matches. The region of a payment is derived from its billing country, which is not
+//! stored with the payment, so only rules without a region apply when estimating the cost of
+//! historical payments.
+
+use std::collections::HashMap;
+
+use api_models::routing::{
+    self as routing_types, CardFundingType, ConnectorFee, ConnectorFeeRule, ConnectorFeeSchedule,
+    FeeRegion,
+};
+use common_utils::{id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use euclid::backend::inputs as dsl_inputs;
+
+use crate::{
+    core::errors::{self, CustomResult, RouterResult},
+    types::storage,
+    utils::ValueExt,
+};
+
+pub type FeeSchedules = HashMap<id_type::MerchantConnectorAccountId, ConnectorFeeSchedule>;
+
+/// The attributes of a payment which the rules of fee schedules are matched against
+#[derive(Debug, Clone)]
+pub struct FeeInput {
+    pub amount: MinorUnit,
+    pub currency: common_enums::Currency,
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    pub card_network: Option<common_enums::CardNetwork>,
+    pub card_type: Option<CardFundingType>,
+    pub region: Option<FeeRegion>,
+}
+
+impl FeeInput {
+    pub fn new(backend_input: &dsl_inputs::BackendInput, card_type: Option<&str>) -> Self {
+        let region = backend_input
+            .payment
+            .business_country
+            .zip(backend_input.payment.billing_country)
+            .map(|(business_country, billing_country)| {
+                if business_country == billing_country {
+                    FeeRegion::Domestic
+                } else {
+                    FeeRegion::International
+                }
+            });
+
+        Self {
+            amount: backend_input.payment.amount,
+            currency: backend_input.payment.currency,
+            payment_method_type: backend_input.payment_method.payment_method_type,
+            card_network: backend_input.payment_method.card_network.clone(),
+            card_type: card_type.and_then(parse_card_type),
+            region,
+        }
+    }
+}
+
+/// Parses the card type reported by card information providers, such as `CREDIT` or `debit`
+pub fn parse_card_type(card_type: &str) -> Option<CardFundingType> {
+    match card_type.to_lowercase().as_str() {
+        "credit" => Some(CardFundingType::Credit),
+        "debit" => Some(CardFundingType::Debit),
+        _ => None,
+    }
+}
+
+/// The key under which the fee schedules of the profile are cached in the routing cache
+pub fn get_fee_schedules_cache_key(profile_id: &id_type::ProfileId) -> String {
+    format!("fee_schedules_{}", profile_id.get_string_repr())
+}
+
+pub fn parse_fee_rules(
+    fee_rules: serde_json::Value,
+) -> CustomResult<Vec<ConnectorFeeRule>, errors::ParsingError> {
+    fee_rules.parse_value("Vec<ConnectorFeeRule>")
+}
+
+pub fn fee_schedules_by_connector(
+    fee_schedules: Vec<storage::ConnectorFeeSchedule>,
+) -> CustomResult<FeeSchedules, errors::ParsingError> {
+    fee_schedules
+        .into_iter()
+        .map(|fee_schedule| {
+            let rules = parse_fee_rules(fee_schedule.fee_rules).attach_printable_lazy(|| {
+                format!(
+                    "Failed to parse the fee schedule of {}",
+                    fee_schedule.merchant_connector_id.get_string_repr()
+                )
+            })?;
+            Ok((
+                fee_schedule.merchant_connector_id,
+                ConnectorFeeSchedule { rules },
+            ))
+        })
+        .collect()
+}
+
+/// Validates that the percentages and the fixed amount of the fee are not negative
+pub fn validate_fee(fee: &ConnectorFee) -> RouterResult<()> {
+    let (percentages, fixed_amount) = match fee {
+        ConnectorFee::Blended {
+            percentage,
+            fixed_amount,
+        } => (vec![*percentage], fixed_amount),
+        ConnectorFee::InterchangePlus {
+            interchange_percentage,
+            markup_percentage,
+            fixed_amount,
+        } => (
+            vec![*interchange_percentage, *markup_percentage],
+            fixed_amount,
+        ),
+    };
+
+    let is_valid = percentages
+        .iter()
+        .all(|percentage| percentage.is_finite() && *percentage >= 0.0)
+        && fixed_amount.get_amount_as_i64() >= 0;
+    if !is_valid {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Fee percentages and fixed amounts must not be negative".to_string(),
+        })?
+    }
+
+    Ok(())
+}
+
+/// Finds the fee of the most specific rule of the fee schedule which the payment matches. Among
+/// equally specific rules, the one listed first is chosen.
+pub fn find_fee<'a>(
+    fee_schedule: &'a ConnectorFeeSchedule,
+    input: &FeeInput,
+) -> Option<&'a ConnectorFee> {
+    fee_schedule
+        .rules
+        .iter()
+        .filter(|rule| rule_matches(rule, input))
+        .fold(
+            None,
+            |best_rule: Option<&ConnectorFeeRule>, rule| match best_rule {
+                Some(best_rule) if best_rule.specificity() >= rule.specificity() => Some(best_rule),
+                _ => Some(rule),
+            },
+        )
+        .map(|rule| &rule.fee)
+}
+
+fn rule_matches(rule: &ConnectorFeeRule, input: &FeeInput) -> bool {
+    criterion_matches(
+        rule.payment_method_type.as_ref(),
+        input.payment_method_type.as_ref(),
+    ) && criterion_matches(rule.card_network.as_ref(), input.card_network.as_ref())
+        && criterion_matches(rule.card_type.as_ref(), input.card_type.as_ref())
+        && criterion_matches(rule.region.as_ref(), input.region.as_ref())
+        && criterion_matches(rule.currency.as_ref(), Some(&input.currency))
+}
+
+/// A criterion which is not specified matches any payment, while a specified criterion does not
+/// match payments for which the attribute is unknown
+fn criterion_matches<T: PartialEq>(criterion: Option<&T>, value: Option<&T>) -> bool {
+    criterion.map_or(true, |criterion| value == Some(criterion))
+}
+
+/// Estimates the cost of processing the payment through the merchant connector account with the
+/// given fee schedule, falling back to the default fee when no rule of the schedule applies
+pub fn estimate_cost(
+    fee_schedule: Option<&ConnectorFeeSchedule>,
+    default_fee: Option<&ConnectorFee>,
+    input: &FeeInput,
+) -> Option<f64> {
+    fee_schedule
+        .and_then(|fee_schedule| find_fee(fee_schedule, input))
+        .or(default_fee)
+        .map(|fee| fee.calculate(input.amount))
+}
+
+/// Orders the connectors by the estimated cost of processing the payment, the cheapest first.
+/// Connectors whose cost cannot be estimated are ranked after the others, and connectors with the
+/// same cost retain the order in which they were selected.
+pub fn rank_connectors_by_cost(
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+    fee_schedules: &FeeSchedules,
+    default_fee: Option<&ConnectorFee>,
+    input: &FeeInput,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    let mut connectors_with_cost = connectors
+        .into_iter()
+        .map(|connector| {
+            let fee_schedule = connector
+                .merchant_connector_id
+                .as_ref()
+                .and_then(|merchant_connector_id| fee_schedules.get(merchant_connector_id));
+            (estimate_cost(fee_schedule, default_fee, input), connector)
+        })
+        .collect::<Vec<_>>();
+
+    connectors_with_cost.sort_by(|(cost, _), (other_cost, _)| match (cost, other_cost) {
+        (Some(cost), Some(other_cost)) => cost.total_cmp(other_cost),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+
+    connectors_with_cost
+        .into_iter()
+        .map(|(_, connector)| connector)
+        .collect()
+}
+
+struct ConnectorCostAccumulator {
+    estimate: routing_types::ConnectorCostEstimate,
+    estimated_cost: f64,
+}
+
+/// The estimated costs of the payments considered so far
+#[derive(Default)]
+pub struct CostEstimateSummary {
+    total_payments: usize,
+    unpriced_payments: usize,
+    connectors: Vec<ConnectorCostAccumulator>,
+}
+
+impl CostEstimateSummary {
+    pub fn record_payment(
+        &mut self,
+        connector: api_models::enums::RoutableConnectors,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        fee_schedule: Option<&ConnectorFeeSchedule>,
+        input: &FeeInput,
+    ) {
+        self.total_payments += 1;
+
+        let Some(estimated_cost) = estimate_cost(fee_schedule, None, input) else {
+            self.unpriced_payments += 1;
+            return;
+        };
+
+        match self.connectors.iter_mut().find(|accumulator| {
+            accumulator.estimate.merchant_connector_id == *merchant_connector_id
+                && accumulator.estimate.currency == input.currency
+        }) {
+            Some(accumulator) => {
+                accumulator.estimate.payment_count += 1;
+                accumulator.estimate.processed_amount =
+                    accumulator.estimate.processed_amount + input.amount;
+                accumulator.estimated_cost += estimated_cost;
+            }
+            None => self.connectors.push(ConnectorCostAccumulator {
+                estimate: routing_types::ConnectorCostEstimate {
+                    connector,
+                    merchant_connector_id: merchant_connector_id.clone(),
+                    currency: input.currency,
+                    payment_count: 1,
+                    processed_amount: input.amount,
+                    estimated_cost: MinorUnit::new(0),
+                },
+                estimated_cost,
+            }),
+        }
+    }
+}
+
+impl From<CostEstimateSummary> for routing_types::ConnectorCostEstimateResponse {
+    fn from(summary: CostEstimateSummary) -> Self {
+        Self {
+            total_payments: summary.total_payments,
+            unpriced_payments: summary.unpriced_payments,
+            connectors: summary
+                .connectors
+                .into_iter()
+                .map(|accumulator| {
+                    #[allow(clippy::as_conversions)]
+                    let estimated_cost = accumulator.estimated_cost.round() as i64;
+                    routing_types::ConnectorCostEstimate {
+                        estimated_cost: MinorUnit::new(estimated_cost),
+                        ..accumulator.estimate
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use api_models::enums::RoutableConnectors;
+
+    use super::*;
+
+    fn merchant_connector_id(id: &'static str) -> id_type::MerchantConnectorAccountId {
+        id_type::MerchantConnectorAccountId::wrap(id.to_string())
+            .expect("Invalid merchant connector id")
+    }
+
+    fn blended(percentage: f64, fixed_amount: i64) -> ConnectorFee {
+        ConnectorFee::Blended {
+            percentage,
+            fixed_amount: MinorUnit::new(fixed_amount),
+        }
+    }
+
+    fn rule(fee: ConnectorFee) -> ConnectorFeeRule {
+        ConnectorFeeRule {
+            payment_method_type: None,
+            card_network: None,
+            card_type: None,
+            region: None,
+            currency: None,
+            fee,
+        }
+    }
+
+    fn card_payment(amount: i64) -> FeeInput {
+        FeeInput {
+            amount: MinorUnit::new(amount),
+            currency: common_enums::Currency::USD,
+            payment_method_type: Some(common_enums::PaymentMethodType::Credit),
+            card_network: Some(common_enums::CardNetwork::Visa),
+            card_type: Some(CardFundingType::Credit),
+            region: Some(FeeRegion::Domestic),
+        }
+    }
+
+    fn connector(
+        connector: RoutableConnectors,
+        merchant_connector_id: Option<&'static str>,
+    ) -> routing_types::RoutableConnectorChoice {
+        routing_types::RoutableConnectorChoice {
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: merchant_connector_id.map(self::merchant_connector_id),
+        }
+    }
+
+    #[test]
+    fn test_most_specific_rule_is_chosen() {
+        let fee_schedule = ConnectorFeeSchedule {
+            rules: vec![
+                rule(blended(2.9, 30)),
+                ConnectorFeeRule {
+                    card_network: Some(common_enums::CardNetwork::Visa),
+                    region: Some(FeeRegion::Domestic),
+                    ..rule(blended(1.5, 10))
+                },
+                ConnectorFeeRule {
+                    card_network: Some(common_enums::CardNetwork::Visa),
+                    ..rule(blended(2.0, 20))
+                },
+                ConnectorFeeRule {
+                    card_network: Some(common_enums::CardNetwork::Mastercard),
+                    region: Some(FeeRegion::Domestic),
+                    currency: Some(common_enums::Currency::USD),
+                    ..rule(blended(1.0, 0))
+                },
+            ],
+        };
+
+        let fee = find_fee(&fee_schedule, &card_payment(1000)).expect("No rule matched");
+        assert_eq!(fee.calculate(MinorUnit::new(1000)), 25.0);
+
+        let international_payment = FeeInput {
+            region: Some(FeeRegion::International),
+            ..card_payment(1000)
+        };
+        let fee = find_fee(&fee_schedule, &international_payment).expect("No rule matched");
+        assert_eq!(fee.calculate(MinorUnit::new(1000)), 40.0);
+    }
+
+    #[test]
+    fn test_rules_with_unknown_attributes_do_not_match() {
+        let fee_schedule = ConnectorFeeSchedule {
+            rules: vec![ConnectorFeeRule {
+                card_type: Some(CardFundingType::Debit),
+                ..rule(blended(0.5, 0))
+            }],
+        };
+        let payment = FeeInput {
+            card_type: None,
+            ..card_payment(1000)
+        };
+
+        assert!(find_fee(&fee_schedule, &payment).is_none());
+    }
+
+    #[test]
+    fn test_interchange_plus_fee() {
+        let fee = ConnectorFee::InterchangePlus {
+            interchange_percentage: 1.8,
+            markup_percentage: 0.2,
+            fixed_amount: MinorUnit::new(5),
+        };
+
+        assert_eq!(fee.calculate(MinorUnit::new(10000)), 205.0);
+    }
+
+    #[test]
+    fn test_connectors_are_ranked_by_cost() {
+        let fee_schedules = FeeSchedules::from([
+            (
+                merchant_connector_id("mca_stripe"),
+                ConnectorFeeSchedule {
+                    rules: vec![rule(blended(2.9, 30))],
+                },
+            ),
+            (
+                merchant_connector_id("mca_adyen"),
+                ConnectorFeeSchedule {
+                    rules: vec![rule(blended(2.0, 10))],
+                },
+            ),
+            (
+                merchant_connector_id("mca_checkout"),
+                ConnectorFeeSchedule {
+                    rules: vec![rule(blended(2.0, 10))],
+                },
+            ),
+        ]);
+        let connectors = vec![
+            connector(RoutableConnectors::Stripe, Some("mca_stripe")),
+            connector(RoutableConnectors::Cybersource, None),
+            connector(RoutableConnectors::Checkout, Some("mca_checkout")),
+            connector(RoutableConnectors::Adyen, Some("mca_adyen")),
+        ];
+
+        let ranked = rank_connectors_by_cost(
+            connectors.clone(),
+            &fee_schedules,
+            None,
+            &card_payment(1000),
+        )
+        .into_iter()
+        .map(|choice| choice.connector)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            ranked,
+            vec![
+                RoutableConnectors::Checkout,
+                RoutableConnectors::Adyen,
+                RoutableConnectors::Stripe,
+                RoutableConnectors::Cybersource,
+            ]
+        );
+
+        let default_fee = blended(0.0, 0);
+        let ranked = rank_connectors_by_cost(
+            connectors,
+            &fee_schedules,
+            Some(&default_fee),
+            &card_payment(1000),
+        )
+        .into_iter()
+        .map(|choice| choice.connector)
+        .collect::<Vec<_>>();
+        assert_eq!(ranked.first(), Some(&RoutableConnectors::Cybersource));
+    }
+
+    #[test]
+    fn test_cost_estimate_summary() {
+        let fee_schedule = ConnectorFeeSchedule {
+            rules: vec![ConnectorFeeRule {
+                currency: Some(common_enums::Currency::USD),
+                ..rule(blended(2.5, 10))
+            }],
+        };
+        let stripe = merchant_connector_id("mca_stripe");
+        let eur_payment = FeeInput {
+            currency: common_enums::Currency::EUR,
+            ..card_payment(1000)
+        };
+
+        let mut summary = CostEstimateSummary::default();
+        summary.record_payment(
+            RoutableConnectors::Stripe,
+            &stripe,
+            Some(&fee_schedule),
+            &card_payment(1000),
+        );
+        summary.record_payment(
+            RoutableConnectors::Stripe,
+            &stripe,
+            Some(&fee_schedule),
+            &card_payment(2001),
+        );
+        summary.record_payment(
+            RoutableConnectors::Stripe,
+            &stripe,
+            Some(&fee_schedule),
+            &eur_payment,
+        );
+        summary.record_payment(
+            RoutableConnectors::Adyen,
+            &merchant_connector_id("mca_adyen"),
+            None,
+            &card_payment(1000),
+        );
+
+        let response = routing_types::ConnectorCostEstimateResponse::from(summary);
+        assert_eq!(response.total_payments, 4);
+        assert_eq!(response.unpriced_payments, 2);
+
+        assert_eq!(response.connectors.len(), 1);
+        let estimate = response
+            .connectors
+            .first()
+            .expect("No connector estimate reported");
+        assert_eq!(estimate.payment_count, 2);
+        assert_eq!(estimate.processed_amount, MinorUnit::new(3001));
+        assert_eq!(estimate.estimated_cost, MinorUnit::new(95));
+    }
+}
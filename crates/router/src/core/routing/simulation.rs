@@ -0,0 +1,545 @@
+//! Replay of historical payments through a routing algorithm
+//!
+//! Payments are evaluated in the same way as during payment routing, followed by the eligibility
+//! analysis against the constraint graph of the profile. The payment method data and addresses
+//! used while routing are not stored, so the card network, issuer and BIN are taken from the
+//! additional payment method data recorded on the attempt instead, and address based keys are not
+//! set. Customer segments and converted amounts are not looked up for replayed payments.
+
+use api_models::{
+    enums as api_enums,
+    routing::{self as routing_types, ConnectorSelection},
+};
+use error_stack::ResultExt;
+use euclid::backend::{self, inputs as dsl_inputs, EuclidBackend};
+use hyperswitch_domain_models::payment_address::PaymentAddress;
+
+use crate::{
+    core::{
+        errors::{self, CustomResult},
+        payments::routing as payments_routing,
+        routing,
+    },
+    logger,
+    routes::SessionState,
+    types::{domain, storage},
+    utils::ValueExt,
+};
+
+type RoutingResult<O> = CustomResult<O, errors::RoutingError>;
+
+/// A routing algorithm prepared for evaluating payments
+pub enum SimulatedAlgorithm {
+    Single(Box<routing_types::RoutableConnectorChoice>),
+    Priority(Vec<routing_types::RoutableConnectorChoice>),
+    VolumeSplit(Vec<routing_types::ConnectorVolumeSplit>),
+    Advanced(backend::InterpreterBackend<ConnectorSelection>),
+}
+
+impl SimulatedAlgorithm {
+    pub fn new(algorithm: routing_types::RoutingAlgorithm) -> RoutingResult<Self> {
+        Ok(match algorithm {
+            routing_types::RoutingAlgorithm::Single(connector) => Self::Single(connector),
+            routing_types::RoutingAlgorithm::Priority(connectors) => Self::Priority(connectors),
+            routing_types::RoutingAlgorithm::VolumeSplit(splits) => Self::VolumeSplit(splits),
+            routing_types::RoutingAlgorithm::Advanced(program) => Self::Advanced(
+                backend::InterpreterBackend::with_program(program)
+                    .change_context(errors::RoutingError::DslBackendInitError)
+                    .attach_printable("Error initializing DSL interpreter backend")?,
+            ),
+        })
+    }
+
+    /// Returns the name of the rule which matched the payment, if any, along with the selected
+    /// connectors. Volume splits are seeded with the payment id, so that replaying a payment is
+    /// deterministic.
+    fn evaluate(
+        &self,
+        backend_input: dsl_inputs::BackendInput,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> RoutingResult<(Option<String>, Vec<routing_types::RoutableConnectorChoice>)> {
+        let seed = Some(payment_id.get_string_repr());
+        match self {
+            Self::Single(connector) => Ok((None, vec![connector.as_ref().clone()])),
+            Self::Priority(connectors) => Ok((None, connectors.clone())),
+            Self::VolumeSplit(splits) => Ok((
+                None,
+                payments_routing::perform_volume_split(splits.clone(), seed)?,
+            )),
+            Self::Advanced(interpreter) => {
+                let output = interpreter
+                    .execute(backend_input)
+                    .change_context(errors::RoutingError::DslExecutionError)?;
+                let connectors = match output.connector_selection {
+                    ConnectorSelection::Priority(connectors) => connectors,
+                    ConnectorSelection::VolumeSplit(splits) => {
+                        payments_routing::perform_volume_split(splits, seed).change_context(
+                            errors::RoutingError::DslFinalConnectorSelectionFailed,
+                        )?
+                    }
+                };
+                Ok((output.rule_name, connectors))
+            }
+        }
+    }
+}
+
+/// The outcome of the payments replayed so far
+#[derive(Default)]
+pub struct SimulationSummary {
+    total_payments: usize,
+    skipped_payments: Vec<common_utils::id_type::PaymentId>,
+    connector_volumes: Vec<routing_types::SimulatedConnectorVolume>,
+    rule_hits: Vec<routing_types::SimulatedRuleHit>,
+    unroutable_payments: Vec<common_utils::id_type::PaymentId>,
+}
+
+impl SimulationSummary {
+    pub async fn replay_payment(
+        &mut self,
+        state: &SessionState,
+        key_store: &domain::MerchantKeyStore,
+        algorithm: &SimulatedAlgorithm,
+        profile_id: &common_utils::id_type::ProfileId,
+        additional_inputs: &routing::inputs::AdditionalRoutingInputs,
+        payment_intent: &storage::PaymentIntent,
+        payment_attempt: &storage::PaymentAttempt,
+    ) -> RoutingResult<()> {
+        let Some(backend_input) =
+            make_backend_input(payment_intent, payment_attempt, additional_inputs)?
+        else {
+            self.skipped_payments
+                .push(payment_intent.payment_id.clone());
+            return Ok(());
+        };
+
+        let (rule_name, connectors) =
+            algorithm.evaluate(backend_input.clone(), &payment_intent.payment_id)?;
+        let eligible_connectors = payments_routing::perform_cgraph_filtering(
+            state,
+            key_store,
+            connectors,
+            backend_input,
+            None,
+            profile_id,
+            &api_enums::TransactionType::Payment,
+        )
+        .await?;
+
+        self.record_replayed_payment(&payment_intent.payment_id, rule_name, eligible_connectors);
+
+        Ok(())
+    }
+
+    /// Records the outcome of a replayed payment, which would have been routed to the first of
+    /// the eligible connectors
+    fn record_replayed_payment(
+        &mut self,
+        payment_id: &common_utils::id_type::PaymentId,
+        rule_name: Option<String>,
+        eligible_connectors: Vec<routing_types::RoutableConnectorChoice>,
+    ) {
+        self.total_payments += 1;
+        self.record_rule_hit(rule_name);
+        match eligible_connectors.into_iter().next() {
+            Some(connector) => self.record_connector(connector),
+            None => self.unroutable_payments.push(payment_id.clone()),
+        }
+    }
+
+    fn record_rule_hit(&mut self, rule_name: Option<String>) {
+        match self
+            .rule_hits
+            .iter_mut()
+            .find(|rule_hit| rule_hit.rule_name == rule_name)
+        {
+            Some(rule_hit) => rule_hit.payment_count += 1,
+            None => self.rule_hits.push(routing_types::SimulatedRuleHit {
+                rule_name,
+                payment_count: 1,
+            }),
+        }
+    }
+
+    fn record_connector(&mut self, connector: routing_types::RoutableConnectorChoice) {
+        match self.connector_volumes.iter_mut().find(|volume| {
+            volume.connector == connector.connector
+                && volume.merchant_connector_id == connector.merchant_connector_id
+        }) {
+            Some(volume) => volume.payment_count += 1,
+            None => self
+                .connector_volumes
+                .push(routing_types::SimulatedConnectorVolume {
+                    connector: connector.connector,
+                    merchant_connector_id: connector.merchant_connector_id,
+                    payment_count: 1,
+                }),
+        }
+    }
+}
+
+impl From<SimulationSummary> for routing_types::RoutingSimulationResponse {
+    fn from(summary: SimulationSummary) -> Self {
+        Self {
+            total_payments: summary.total_payments,
+            skipped_payments: summary.skipped_payments,
+            connector_volumes: summary.connector_volumes,
+            rule_hits: summary.rule_hits,
+            unroutable_payments: summary.unroutable_payments,
+        }
+    }
+}
+
+/// Builds the routing inputs of a stored payment, returns `None` if the payment was created without
+/// a currency
+pub fn make_backend_input(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    additional_inputs: &routing::inputs::AdditionalRoutingInputs,
+) -> RoutingResult<Option<dsl_inputs::BackendInput>> {
+    let Some(currency) = payment_attempt.currency.or(payment_intent.currency) else {
+        return Ok(None);
+    };
+
+    let address = PaymentAddress::default();
+    let payments_dsl_input = routing::PaymentsDslInput::new(
+        None,
+        payment_attempt,
+        payment_intent,
+        None,
+        &address,
+        None,
+        currency,
+        additional_inputs.clone(),
+    );
+    let mut backend_input = payments_routing::make_dsl_input(&payments_dsl_input)?;
+    if let Some(card_info) = get_additional_card_info(payment_attempt) {
+        backend_input.payment_method.card_network = card_info.card_network;
+        backend_input.payment_method.card_issuer = card_info.card_issuer;
+        backend_input.payment_method.issuer_country = card_info
+            .card_issuing_country
+            .as_deref()
+            .and_then(payments_routing::parse_issuer_country);
+        backend_input.payment.card_bin = card_info.card_isin;
+    }
+
+    Ok(Some(backend_input))
+}
+
+pub fn get_additional_card_info(
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<api_models::payments::AdditionalCardInfo> {
+    let additional_payment_data = payment_attempt
+        .payment_method_data
+        .clone()
+        .filter(|data| !data.is_null())?
+        .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+        .map_err(|error| logger::warn!(?error, "Failed to parse the additional payment data"))
+        .ok()?;
+
+    match additional_payment_data {
+        api_models::payments::AdditionalPaymentData::Card(card_info) => Some(*card_info),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::borrow::Cow;
+
+    use common_utils::types::MinorUnit;
+    use euclid::frontend::ast;
+
+    use super::*;
+
+    fn payment_id(id: &'static str) -> common_utils::id_type::PaymentId {
+        common_utils::id_type::PaymentId::try_from(Cow::Borrowed(id)).expect("Invalid payment id")
+    }
+
+    fn connector_choice(connector: &str, mca_id: &str) -> routing_types::RoutableConnectorChoice {
+        serde_json::from_value(serde_json::json!({
+            "connector": connector,
+            "merchant_connector_id": mca_id,
+        }))
+        .expect("Invalid connector choice")
+    }
+
+    fn get_payment_intent(currency: Option<api_enums::Currency>) -> storage::PaymentIntent {
+        let now = common_utils::date_time::now();
+        storage::PaymentIntent {
+            payment_id: payment_id("pay_1"),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            status: api_enums::IntentStatus::Succeeded,
+            amount: MinorUnit::new(1000),
+            shipping_cost: None,
+            currency,
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: now,
+            modified_at: now,
+            last_synced: None,
+            setup_future_usage: None,
+            off_session: None,
+            client_secret: None,
+            active_attempt: hyperswitch_domain_models::RemoteStorageObject::ForeignID(
+                "pay_1_1".to_string(),
+            ),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 1,
+            profile_id: None,
+            payment_link_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            updated_by: "postgres_only".to_string(),
+            surcharge_applicable: None,
+            request_incremental_authorization: None,
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            fingerprint_id: None,
+            session_expiry: None,
+            request_external_three_ds_authentication: None,
+            charges: None,
+            frm_metadata: None,
+            customer_details: None,
+            billing_details: None,
+            merchant_order_reference_id: None,
+            shipping_details: None,
+            is_payment_processor_token_flow: None,
+            organization_id: common_utils::id_type::OrganizationId::default(),
+            tax_details: None,
+            skip_external_tax_calculation: None,
+        }
+    }
+
+    fn get_payment_attempt(
+        currency: Option<api_enums::Currency>,
+        payment_method_data: serde_json::Value,
+    ) -> storage::PaymentAttempt {
+        serde_json::from_value(serde_json::json!({
+            "payment_id": "pay_1",
+            "merchant_id": "merchant_1",
+            "attempt_id": "pay_1_1",
+            "status": "charged",
+            "amount": 1000,
+            "net_amount": 1000,
+            "currency": currency,
+            "payment_method": "card",
+            "payment_method_type": "credit",
+            "confirm": true,
+            "created_at": "2024-10-01T12:00:00Z",
+            "modified_at": "2024-10-01T12:00:00Z",
+            "payment_method_data": payment_method_data,
+            "amount_capturable": 0,
+            "updated_by": "postgres_only",
+            "profile_id": "pro_1",
+            "organization_id": "org_1"
+        }))
+        .expect("Invalid payment attempt")
+    }
+
+    #[test]
+    fn test_backend_input_takes_card_details_from_the_payment_attempt() {
+        let payment_attempt = get_payment_attempt(
+            Some(api_enums::Currency::EUR),
+            serde_json::json!({
+                "card": {
+                    "card_network": "Visa",
+                    "card_issuer": "Issuer Bank",
+                    "card_issuing_country": "DE",
+                    "card_isin": "424242"
+                }
+            }),
+        );
+
+        let backend_input = make_backend_input(
+            &get_payment_intent(Some(api_enums::Currency::USD)),
+            &payment_attempt,
+            &routing::inputs::AdditionalRoutingInputs::default(),
+        )
+        .expect("Failed to build backend input")
+        .expect("Payment with a currency was skipped");
+
+        // The currency of the attempt takes precedence over the one of the intent
+        assert_eq!(backend_input.payment.currency, api_enums::Currency::EUR);
+        assert_eq!(backend_input.payment.amount, MinorUnit::new(1000));
+        assert_eq!(backend_input.payment.card_bin.as_deref(), Some("424242"));
+        assert_eq!(
+            backend_input.payment_method.card_network,
+            Some(api_enums::CardNetwork::Visa)
+        );
+        assert_eq!(
+            backend_input.payment_method.card_issuer.as_deref(),
+            Some("Issuer Bank")
+        );
+        assert_eq!(
+            backend_input.payment_method.issuer_country,
+            Some(api_enums::Country::Germany)
+        );
+    }
+
+    #[test]
+    fn test_backend_input_of_payments_without_card_details() {
+        let backend_input = make_backend_input(
+            &get_payment_intent(Some(api_enums::Currency::USD)),
+            &get_payment_attempt(None, serde_json::Value::Null),
+            &routing::inputs::AdditionalRoutingInputs::default(),
+        )
+        .expect("Failed to build backend input")
+        .expect("Payment with a currency was skipped");
+
+        assert_eq!(backend_input.payment.currency, api_enums::Currency::USD);
+        assert_eq!(backend_input.payment.card_bin, None);
+        assert_eq!(backend_input.payment_method.card_network, None);
+    }
+
+    #[test]
+    fn test_payments_without_currency_are_skipped() {
+        let backend_input = make_backend_input(
+            &get_payment_intent(None),
+            &get_payment_attempt(None, serde_json::Value::Null),
+            &routing::inputs::AdditionalRoutingInputs::default(),
+        )
+        .expect("Failed to build backend input");
+
+        assert!(backend_input.is_none());
+    }
+
+    #[test]
+    fn test_summary_counts_connector_volumes_and_rule_hits() {
+        let algorithm = SimulatedAlgorithm::new(routing_types::RoutingAlgorithm::Advanced(
+            ast::parser::parse_program::<ConnectorSelection>(
+                "default: [stripe]\nhigh_value: [adyen]\n{\n    amount > 500\n}\n",
+            )
+            .expect("Failed to parse program"),
+        ))
+        .expect("Failed to initialize algorithm");
+
+        let mut summary = SimulationSummary::default();
+        for (id, amount) in [("pay_1", 1000), ("pay_2", 2000), ("pay_3", 100)] {
+            let payment_id = payment_id(id);
+            let mut backend_input = make_backend_input(
+                &get_payment_intent(Some(api_enums::Currency::USD)),
+                &get_payment_attempt(None, serde_json::Value::Null),
+                &routing::inputs::AdditionalRoutingInputs::default(),
+            )
+            .expect("Failed to build backend input")
+            .expect("Payment with a currency was skipped");
+            backend_input.payment.amount = MinorUnit::new(amount);
+
+            let (rule_name, connectors) = algorithm
+                .evaluate(backend_input, &payment_id)
+                .expect("Failed to evaluate algorithm");
+            summary.record_replayed_payment(&payment_id, rule_name, connectors);
+        }
+
+        let response = routing_types::RoutingSimulationResponse::from(summary);
+        assert_eq!(response.total_payments, 3);
+        assert!(response.unroutable_payments.is_empty());
+
+        let connector_volumes = response
+            .connector_volumes
+            .iter()
+            .map(|volume| (volume.connector, volume.payment_count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            connector_volumes,
+            vec![
+                (api_enums::RoutableConnectors::Adyen, 2),
+                (api_enums::RoutableConnectors::Stripe, 1),
+            ]
+        );
+
+        let rule_hits = response
+            .rule_hits
+            .iter()
+            .map(|rule_hit| (rule_hit.rule_name.as_deref(), rule_hit.payment_count))
+            .collect::<Vec<_>>();
+        assert_eq!(rule_hits, vec![(Some("high_value"), 2), (None, 1)]);
+    }
+
+    #[test]
+    fn test_connectors_are_counted_per_merchant_connector_account() {
+        let mut summary = SimulationSummary::default();
+        summary.record_replayed_payment(
+            &payment_id("pay_1"),
+            None,
+            vec![connector_choice("stripe", "mca_1")],
+        );
+        summary.record_replayed_payment(
+            &payment_id("pay_2"),
+            None,
+            vec![
+                connector_choice("stripe", "mca_2"),
+                connector_choice("stripe", "mca_1"),
+            ],
+        );
+        summary.record_replayed_payment(
+            &payment_id("pay_3"),
+            None,
+            vec![connector_choice("stripe", "mca_1")],
+        );
+
+        let response = routing_types::RoutingSimulationResponse::from(summary);
+        let connector_volumes = response
+            .connector_volumes
+            .iter()
+            .map(|volume| {
+                (
+                    volume
+                        .merchant_connector_id
+                        .as_ref()
+                        .map(|mca_id| mca_id.get_string_repr().to_string()),
+                    volume.payment_count,
+                )
+            })
+            .collect::<Vec<_>>();
+        // Payments are routed to the first eligible connector only
+        assert_eq!(
+            connector_volumes,
+            vec![
+                (Some("mca_1".to_string()), 2),
+                (Some("mca_2".to_string()), 1)
+            ]
+        );
+        assert_eq!(response.rule_hits.len(), 1);
+    }
+
+    #[test]
+    fn test_payments_without_eligible_connector_are_reported() {
+        let mut summary = SimulationSummary::default();
+        summary.record_replayed_payment(&payment_id("pay_1"), Some("rule_1".to_string()), vec![]);
+        summary.record_replayed_payment(
+            &payment_id("pay_2"),
+            Some("rule_1".to_string()),
+            vec![connector_choice("adyen", "mca_1")],
+        );
+
+        let response = routing_types::RoutingSimulationResponse::from(summary);
+        assert_eq!(response.total_payments, 2);
+        assert_eq!(response.unroutable_payments, vec![payment_id("pay_1")]);
+        assert_eq!(response.connector_volumes.len(), 1);
+        // Unroutable payments still count towards the rule they matched
+        assert_eq!(
+            response
+                .rule_hits
+                .iter()
+                .map(|rule_hit| rule_hit.payment_count)
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+    }
+}
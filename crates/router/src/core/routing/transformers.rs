@@ -1,10 +1,12 @@
 use api_models::routing::{
-    MerchantRoutingAlgorithm, RoutingAlgorithm as Algorithm, RoutingAlgorithmKind,
-    RoutingDictionaryRecord,
+    ConnectorFeeScheduleResponse, MerchantRoutingAlgorithm, RoutingActivationRecord,
+    RoutingAlgorithm as Algorithm, RoutingAlgorithmKind, RoutingDictionaryRecord,
 };
 use common_utils::ext_traits::ValueExt;
 use diesel_models::{
+    connector_fee_schedule::ConnectorFeeSchedule,
     enums as storage_enums,
+    routing_activation_history::RoutingActivationHistory,
     routing_algorithm::{RoutingAlgorithm, RoutingProfileMetadata},
 };
 
@@ -45,6 +47,21 @@ impl ForeignFrom<RoutingAlgorithm> for RoutingDictionaryRecord {
     }
 }
 
+impl ForeignFrom<RoutingActivationHistory> for RoutingActivationRecord {
+    fn foreign_from(value: RoutingActivationHistory) -> Self {
+        Self {
+            id: value.id,
+            profile_id: value.profile_id,
+            algorithm_id: value.algorithm_id,
+            previous_algorithm_id: value.previous_algorithm_id,
+            algorithm_for: value.algorithm_for,
+            activation_type: value.activation_type,
+            activated_by: value.activated_by,
+            created_at: value.created_at.assume_utc().unix_timestamp(),
+        }
+    }
+}
+
 impl ForeignTryFrom<RoutingAlgorithm> for MerchantRoutingAlgorithm {
     type Error = error_stack::Report<errors::ParsingError>;
 
@@ -65,6 +82,20 @@ impl ForeignTryFrom<RoutingAlgorithm> for MerchantRoutingAlgorithm {
     }
 }
 
+impl ForeignTryFrom<ConnectorFeeSchedule> for ConnectorFeeScheduleResponse {
+    type Error = error_stack::Report<errors::ParsingError>;
+
+    fn foreign_try_from(value: ConnectorFeeSchedule) -> Result<Self, Self::Error> {
+        Ok(Self {
+            merchant_connector_id: value.merchant_connector_id,
+            profile_id: value.profile_id,
+            rules: value.fee_rules.parse_value("Vec<ConnectorFeeRule>")?,
+            created_at: value.created_at.assume_utc().unix_timestamp(),
+            modified_at: value.modified_at.assume_utc().unix_timestamp(),
+        })
+    }
+}
+
 impl ForeignFrom<storage_enums::RoutingAlgorithmKind> for RoutingAlgorithmKind {
     fn foreign_from(value: storage_enums::RoutingAlgorithmKind) -> Self {
         match value {
@@ -0,0 +1,679 @@
+//! Migration of a merchant between the `PostgresOnly` and `RedisKv` storage schemes
+//!
+//! Moving to KV switches the storage scheme first and then warms the Redis hashes with the open
+//! payment intents of the merchant. Warming only sets fields which are absent, so that it never
+//! overwrites a payment intent which has already been updated in Redis after the switch.
+//!
+//! Moving back to Postgres is deferred until the drainer has flushed every entry of the merchant,
+//! as Postgres would otherwise serve stale rows, and writes made to Postgres could be overwritten
+//! when the drainer later applies older entries. In the meantime the merchant is marked as draining,
+//! which stops its KV writes by applying the soft kill mode to its API requests: new rows are
+//! inserted in Postgres, while the rows still held in Redis keep being read and updated through
+//! Redis until they expire. The streams hence run out of entries of the merchant even under
+//! constant traffic, at which point the storage scheme is switched. Requests which fetched the
+//! merchant account right before the switch may still push entries, in which case the merchant
+//! stays in the draining state until these have been flushed as well.
+//!
+//! The hashes written while the merchant was on KV live on in Redis until the KV TTL elapses, and
+//! would be served instead of the rows updated in Postgres in the meantime if the merchant moved
+//! back to KV. Moving to KV is hence deferred until the TTL has elapsed since the merchant last
+//! left KV.
+//!
+//! Each request performs the next step of the migration and reports its progress, so the request
+//! is to be repeated until the migration is completed.
+
+use std::collections::HashMap;
+
+use api_models::admin::{
+    StorageSchemeMigrationRequest, StorageSchemeMigrationResponse, StorageSchemeMigrationStatus,
+};
+use common_enums::{IntentStatus, MerchantStorageScheme};
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    behaviour::Conversion,
+    payments::payment_intent::{PaymentIntentFetchConstraints, PaymentIntentListParams},
+};
+use redis_interface::{errors::RedisError, RedisConnectionPool};
+use router_env::logger;
+use storage_impl::redis::kv_store::PartitionKey;
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+/// Statuses of the payment intents which are still expected to be updated
+const OPEN_INTENT_STATUSES: [IntentStatus; 7] = [
+    IntentStatus::RequiresPaymentMethod,
+    IntentStatus::RequiresConfirmation,
+    IntentStatus::RequiresCustomerAction,
+    IntentStatus::RequiresMerchantAction,
+    IntentStatus::RequiresCapture,
+    IntentStatus::PartiallyCapturedAndCapturable,
+    IntentStatus::Processing,
+];
+
+/// Upper bound on the payment intents warmed in Redis, most recent first
+const MAX_WARMED_PAYMENT_INTENTS: u32 = 10_000;
+
+/// Upper bound on the length of the drainer streams scanned for the entries of the merchant. Longer
+/// streams are not scanned, as the drainer is too far behind for the merchant to be switched.
+const MAX_SCANNED_STREAM_LENGTH: usize = 10_000;
+
+/// Expiry of the marker of a merchant draining its entries, refreshed by every migration request,
+/// so that a migration which is given up on eventually lets the merchant write to KV again
+const DRAINING_MARKER_EXPIRY_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Key holding the time at which the merchant last left KV, expiring after the KV TTL
+fn left_kv_at_key(merchant_id: &id_type::MerchantId) -> String {
+    format!("storage_scheme_migration_{}", merchant_id.get_string_repr())
+}
+
+/// Key marking a merchant on KV whose KV writes are stopped until its entries have been drained
+fn draining_marker_key(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "storage_scheme_migration_draining_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Stops the KV writes of a request made for a merchant which is draining its entries to move to
+/// Postgres. Failures to check whether the merchant is draining are only logged.
+pub async fn stop_kv_writes_if_draining(
+    state: &mut SessionState,
+    merchant_id: &id_type::MerchantId,
+) {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return;
+        }
+    };
+
+    match redis_conn
+        .exists::<Vec<u8>>(&draining_marker_key(merchant_id))
+        .await
+    {
+        Ok(true) => state.store.enable_soft_kill_mode(),
+        Ok(false) => {}
+        Err(error) => logger::error!(?error, "Failed to check whether the merchant is draining"),
+    }
+}
+
+pub async fn migrate_storage_scheme(
+    state: SessionState,
+    request: StorageSchemeMigrationRequest,
+) -> RouterResponse<StorageSchemeMigrationResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &request.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &request.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let redis_conn = db
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let response = if request.kv_enabled {
+        migrate_to_kv(
+            &state,
+            &redis_conn,
+            merchant_account,
+            &key_store,
+            request.dry_run,
+        )
+        .await?
+    } else {
+        migrate_to_postgres(
+            &state,
+            &redis_conn,
+            merchant_account,
+            &key_store,
+            request.dry_run,
+        )
+        .await?
+    };
+
+    logger::info!(
+        merchant_id = ?response.merchant_id,
+        status = ?response.status,
+        dry_run = response.dry_run,
+        "Storage scheme migration step completed"
+    );
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+async fn migrate_to_kv(
+    state: &SessionState,
+    redis_conn: &RedisConnectionPool,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dry_run: bool,
+) -> RouterResult<StorageSchemeMigrationResponse> {
+    let merchant_id = merchant_account.get_id().to_owned();
+    let mut response = StorageSchemeMigrationResponse {
+        merchant_id: merchant_id.clone(),
+        kv_enabled: true,
+        status: StorageSchemeMigrationStatus::Completed,
+        dry_run,
+        warmed_payment_intents: None,
+        pending_drainer_entries: None,
+        retry_after: None,
+    };
+
+    if merchant_account.storage_scheme == MerchantStorageScheme::RedisKv {
+        // Gives up on a migration to Postgres in progress, resuming the KV writes of the merchant
+        if !dry_run {
+            redis_conn
+                .delete_key(&draining_marker_key(&merchant_id))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to resume the KV writes of the merchant")?;
+        }
+        return Ok(response);
+    }
+
+    if state.conf.as_ref().is_kv_soft_kill_mode() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Kv cannot be enabled when application is in soft_kill_mode".to_owned(),
+        })?
+    }
+
+    let left_kv_at = match redis_conn
+        .get_and_deserialize_key::<i64>(&left_kv_at_key(&merchant_id), "i64")
+        .await
+    {
+        Ok(left_kv_at) => Some(left_kv_at),
+        Err(error) if matches!(error.current_context(), RedisError::NotFound) => None,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the time at which the merchant left KV")?,
+    };
+    if let Some(left_kv_at) = left_kv_at {
+        response.kv_enabled = false;
+        response.status = StorageSchemeMigrationStatus::Deferred;
+        response.retry_after = Some(get_retry_after(
+            left_kv_at,
+            state.conf.kv_config.ttl,
+            date_time::now_unix_timestamp(),
+        ));
+        return Ok(response);
+    }
+
+    if dry_run {
+        response.kv_enabled = false;
+        response.status = StorageSchemeMigrationStatus::Ready;
+        response.warmed_payment_intents =
+            Some(warm_open_payment_intents(state, None, &merchant_account, key_store).await?);
+        return Ok(response);
+    }
+
+    let merchant_account = update_storage_scheme(
+        state,
+        merchant_account,
+        key_store,
+        MerchantStorageScheme::RedisKv,
+    )
+    .await?;
+    response.warmed_payment_intents = Some(
+        warm_open_payment_intents(state, Some(redis_conn), &merchant_account, key_store).await?,
+    );
+
+    Ok(response)
+}
+
+async fn migrate_to_postgres(
+    state: &SessionState,
+    redis_conn: &RedisConnectionPool,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dry_run: bool,
+) -> RouterResult<StorageSchemeMigrationResponse> {
+    let merchant_id = merchant_account.get_id().to_owned();
+    let pending_entries = count_pending_drainer_entries(state, redis_conn, &merchant_id).await?;
+    let mut response = StorageSchemeMigrationResponse {
+        merchant_id: merchant_id.clone(),
+        kv_enabled: false,
+        status: StorageSchemeMigrationStatus::Completed,
+        dry_run,
+        warmed_payment_intents: None,
+        pending_drainer_entries: pending_entries,
+        retry_after: None,
+    };
+
+    if let Some(status) =
+        get_postgres_migration_status(merchant_account.storage_scheme, pending_entries, dry_run)
+    {
+        response.kv_enabled = merchant_account.storage_scheme == MerchantStorageScheme::RedisKv;
+        response.status = status;
+        if response.kv_enabled && status == StorageSchemeMigrationStatus::Draining {
+            redis_conn
+                .set_key_with_expiry(
+                    &draining_marker_key(&merchant_id),
+                    true,
+                    DRAINING_MARKER_EXPIRY_SECONDS,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to stop the KV writes of the merchant")?;
+        }
+        return Ok(response);
+    }
+
+    update_storage_scheme(
+        state,
+        merchant_account,
+        key_store,
+        MerchantStorageScheme::PostgresOnly,
+    )
+    .await?;
+    redis_conn
+        .delete_key(&draining_marker_key(&merchant_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the draining marker of the merchant")?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &left_kv_at_key(&merchant_id),
+            date_time::now_unix_timestamp(),
+            i64::from(state.conf.kv_config.ttl),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the time at which the merchant left KV")?;
+
+    // Requests which fetched the merchant account before the switch may still have pushed entries
+    let pending_entries = count_pending_drainer_entries(state, redis_conn, &merchant_id).await?;
+    response.pending_drainer_entries = pending_entries;
+    if pending_entries != Some(0) {
+        response.status = StorageSchemeMigrationStatus::Draining;
+    }
+
+    Ok(response)
+}
+
+/// Seconds until the KV TTL has elapsed since the merchant left KV, at least one
+fn get_retry_after(left_kv_at: i64, ttl: u32, now: i64) -> u64 {
+    let expires_at = left_kv_at.saturating_add(i64::from(ttl));
+    u64::try_from(expires_at.saturating_sub(now))
+        .unwrap_or_default()
+        .max(1)
+}
+
+/// The status of a migration step to Postgres which leaves the storage scheme as is, or `None` when
+/// the storage scheme is to be switched. A merchant on KV is only switched once none of its entries
+/// are pending in the drainer streams, and has its KV writes stopped until then.
+fn get_postgres_migration_status(
+    storage_scheme: MerchantStorageScheme,
+    pending_entries: Option<usize>,
+    dry_run: bool,
+) -> Option<StorageSchemeMigrationStatus> {
+    let is_drained = pending_entries == Some(0);
+    match storage_scheme {
+        MerchantStorageScheme::PostgresOnly if is_drained => {
+            Some(StorageSchemeMigrationStatus::Completed)
+        }
+        MerchantStorageScheme::PostgresOnly => Some(StorageSchemeMigrationStatus::Draining),
+        MerchantStorageScheme::RedisKv if dry_run => Some(StorageSchemeMigrationStatus::Ready),
+        MerchantStorageScheme::RedisKv if !is_drained => {
+            Some(StorageSchemeMigrationStatus::Draining)
+        }
+        MerchantStorageScheme::RedisKv => None,
+    }
+}
+
+async fn update_storage_scheme(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: MerchantStorageScheme,
+) -> RouterResult<domain::MerchantAccount> {
+    state
+        .store
+        .update_merchant(
+            &state.into(),
+            merchant_account,
+            storage::MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme },
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to switch merchant_storage_scheme")
+}
+
+/// Sets the open payment intents of the merchant in their Redis hashes, returning the number of
+/// payment intents found. The payment intents are only counted when no connection is provided.
+async fn warm_open_payment_intents(
+    state: &SessionState,
+    redis_conn: Option<&RedisConnectionPool>,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<usize> {
+    let key_manager_state = &state.into();
+    let ttl = state.conf.kv_config.ttl;
+    let mut warmed_count = 0;
+    let mut offset = 0;
+
+    while offset < MAX_WARMED_PAYMENT_INTENTS {
+        let batch_size = std::cmp::min(
+            MAX_WARMED_PAYMENT_INTENTS - offset,
+            common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1,
+        );
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset,
+            starting_at: None,
+            ending_at: None,
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: Some(OPEN_INTENT_STATUSES.to_vec()),
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: None,
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(batch_size),
+            order: Default::default(),
+        }));
+
+        let payment_intents = state
+            .store
+            .filter_payment_intent_by_constraints(
+                key_manager_state,
+                merchant_account.get_id(),
+                &constraints,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the open payment intents of the merchant")?;
+        let fetched_count = payment_intents.len();
+
+        for payment_intent in payment_intents {
+            if let Some(redis_conn) = redis_conn {
+                let key = PartitionKey::MerchantIdPaymentId {
+                    merchant_id: merchant_account.get_id(),
+                    payment_id: payment_intent.get_id(),
+                }
+                .to_string();
+                let field = payment_intent.get_id().get_hash_key_for_kv_store();
+                let diesel_payment_intent = payment_intent
+                    .convert()
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to convert the payment intent")?;
+
+                redis_conn
+                    .serialize_and_set_hash_field_if_not_exist(
+                        &key,
+                        &field,
+                        diesel_payment_intent,
+                        Some(ttl),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to warm the payment intent in redis")?;
+            }
+            warmed_count += 1;
+        }
+
+        if u32::try_from(fetched_count).map_or(true, |fetched_count| fetched_count < batch_size) {
+            break;
+        }
+        offset += batch_size;
+    }
+
+    Ok(warmed_count)
+}
+
+/// Counts the entries of the merchant across the drainer streams of the tenant. Returns `None`
+/// without scanning when a stream is longer than [`MAX_SCANNED_STREAM_LENGTH`].
+async fn count_pending_drainer_entries(
+    state: &SessionState,
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<usize>> {
+    let page_size = state.conf.drainer.max_read_count;
+    let mut pending_entries = 0;
+
+    for partition in 0..state.conf.drainer.num_partitions {
+        // Matches the stream names of `KVRouterStore::get_drainer_stream_name`
+        let stream_name = format!(
+            "{{shard_{partition}}}_{}_{}",
+            state.tenant.schema, state.conf.drainer.stream_name
+        );
+
+        let stream_length = redis_conn
+            .stream_get_length(&stream_name)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get the length of the drainer stream")?;
+        if stream_length == 0 {
+            continue;
+        }
+        if stream_length > MAX_SCANNED_STREAM_LENGTH {
+            logger::info!(
+                stream_name,
+                stream_length,
+                "Drainer stream is too long to be scanned for the entries of the merchant"
+            );
+            return Ok(None);
+        }
+
+        let mut start = "-".to_owned();
+        loop {
+            let entries = redis_conn
+                .stream_read_range(&stream_name, &start, "+", Some(page_size))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to read the drainer stream")?;
+
+            pending_entries += entries
+                .iter()
+                .filter(|(_, entry)| is_merchant_entry(entry, merchant_id))
+                .count();
+
+            match entries.last() {
+                Some((last_id, _))
+                    if u64::try_from(entries.len()).is_ok_and(|count| count >= page_size) =>
+                {
+                    // Exclusive start, to continue after the last entry read
+                    start = format!("({last_id}");
+                }
+                _ => break,
+            }
+        }
+    }
+
+    Ok(Some(pending_entries))
+}
+
+/// Kinds of the rows whose partition keys follow the merchant identifier, as in
+/// `mid_{merchant_id}_pid_{payment_id}`
+const PARTITION_KEY_ROW_KINDS: [&str; 5] = ["pid", "cust", "po", "poa", "mandate"];
+
+/// Prefix of the partition keys of reverse lookups, which are followed by their lookup identifier
+const REVERSE_LOOKUP_PARTITION_KEY_PREFIX: &str = "reverse_lookup_";
+
+/// Whether the drainer stream entry was pushed for the merchant. The partition key of the rows of a
+/// merchant is made up of the merchant identifier followed by the kind of the row, while a reverse
+/// lookup belongs to the merchant of the row it points to.
+fn is_merchant_entry(entry: &HashMap<String, String>, merchant_id: &id_type::MerchantId) -> bool {
+    let Some(global_id) = entry.get("global_id") else {
+        return false;
+    };
+
+    if global_id.starts_with(REVERSE_LOOKUP_PARTITION_KEY_PREFIX) {
+        return entry
+            .get("typed_sql")
+            .and_then(|typed_sql| serde_json::from_str::<storage::kv::TypedSql>(typed_sql).ok())
+            .is_some_and(|typed_sql| match typed_sql.op {
+                storage::kv::DBOperation::Insert {
+                    insertable: storage::kv::Insertable::ReverseLookUp(reverse_lookup),
+                } => is_merchant_partition_key(&reverse_lookup.pk_id, merchant_id),
+                _ => false,
+            });
+    }
+
+    is_merchant_partition_key(global_id, merchant_id)
+}
+
+fn is_merchant_partition_key(partition_key: &str, merchant_id: &id_type::MerchantId) -> bool {
+    partition_key
+        .strip_prefix("mid_")
+        .and_then(|partition_key| partition_key.strip_prefix(merchant_id.get_string_repr()))
+        .and_then(|partition_key| partition_key.strip_prefix('_'))
+        .and_then(|partition_key| partition_key.split_once('_'))
+        .is_some_and(|(row_kind, _)| PARTITION_KEY_ROW_KINDS.contains(&row_kind))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_postgres_migration_status() {
+        let cases = [
+            (MerchantStorageScheme::RedisKv, Some(0), false, None),
+            (
+                MerchantStorageScheme::RedisKv,
+                Some(0),
+                true,
+                Some(StorageSchemeMigrationStatus::Ready),
+            ),
+            (
+                MerchantStorageScheme::RedisKv,
+                Some(1),
+                false,
+                Some(StorageSchemeMigrationStatus::Draining),
+            ),
+            (
+                MerchantStorageScheme::RedisKv,
+                Some(1),
+                true,
+                Some(StorageSchemeMigrationStatus::Ready),
+            ),
+            (
+                MerchantStorageScheme::RedisKv,
+                None,
+                false,
+                Some(StorageSchemeMigrationStatus::Draining),
+            ),
+            (
+                MerchantStorageScheme::PostgresOnly,
+                Some(3),
+                false,
+                Some(StorageSchemeMigrationStatus::Draining),
+            ),
+            (
+                MerchantStorageScheme::PostgresOnly,
+                Some(0),
+                false,
+                Some(StorageSchemeMigrationStatus::Completed),
+            ),
+        ];
+
+        for (storage_scheme, pending_entries, dry_run, expected) in cases {
+            assert_eq!(
+                get_postgres_migration_status(storage_scheme, pending_entries, dry_run),
+                expected,
+                "{storage_scheme:?} with {pending_entries:?} pending entries"
+            );
+        }
+    }
+
+    #[test]
+    fn test_retry_after() {
+        assert_eq!(get_retry_after(1_000, 300, 1_100), 200);
+        assert_eq!(get_retry_after(1_000, 300, 1_300), 1);
+        assert_eq!(get_retry_after(1_000, 300, 2_000), 1);
+    }
+
+    #[test]
+    fn test_merchant_entry() {
+        let merchant_id = id_type::MerchantId::try_from(std::borrow::Cow::from("merchant"))
+            .expect("valid merchant id");
+        let entry =
+            |global_id: &str| HashMap::from([("global_id".to_string(), global_id.to_string())]);
+
+        assert!(is_merchant_entry(
+            &entry("mid_merchant_pid_pay_1"),
+            &merchant_id
+        ));
+        assert!(is_merchant_entry(
+            &entry("mid_merchant_cust_cus_1"),
+            &merchant_id
+        ));
+        assert!(!is_merchant_entry(
+            &entry("mid_merchant2_pid_pay_1"),
+            &merchant_id
+        ));
+        // Merchant identifiers may contain underscores
+        assert!(!is_merchant_entry(
+            &entry("mid_merchant_2_pid_pay_1"),
+            &merchant_id
+        ));
+        assert!(!is_merchant_entry(&HashMap::new(), &merchant_id));
+    }
+
+    #[test]
+    fn test_reverse_lookup_entry() {
+        let merchant_id = id_type::MerchantId::try_from(std::borrow::Cow::from("merchant"))
+            .expect("valid merchant id");
+        let entry = |pk_id: &str| {
+            let typed_sql = storage::kv::TypedSql {
+                op: storage::kv::DBOperation::Insert {
+                    insertable: storage::kv::Insertable::ReverseLookUp(
+                        diesel_models::ReverseLookupNew {
+                            lookup_id: "pa_conn_trans_merchant_txn_1".to_string(),
+                            pk_id: pk_id.to_string(),
+                            sk_id: "pa_pay_1_1".to_string(),
+                            source: "payment_attempt".to_string(),
+                            updated_by: "redis_kv".to_string(),
+                        },
+                    ),
+                },
+            };
+            HashMap::from([
+                (
+                    "global_id".to_string(),
+                    "reverse_lookup_pa_conn_trans_merchant_txn_1".to_string(),
+                ),
+                (
+                    "typed_sql".to_string(),
+                    serde_json::to_string(&typed_sql).expect("serializable typed sql"),
+                ),
+            ])
+        };
+
+        assert!(is_merchant_entry(
+            &entry("mid_merchant_pid_pay_1"),
+            &merchant_id
+        ));
+        assert!(!is_merchant_entry(
+            &entry("mid_merchant_2_pid_pay_1"),
+            &merchant_id
+        ));
+    }
+}
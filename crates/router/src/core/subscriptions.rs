@@ -0,0 +1,919 @@
+pub mod transformers;
+pub mod utils;
+
+use api_models::{payments::HeaderPayload, subscription as subscription_api};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+        payments,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+    utils::generate_id,
+    workflows::subscription_billing,
+};
+
+pub const SUBSCRIPTION_BILLING_TASK: &str = "SUBSCRIPTION_BILLING";
+
+/// Interval after which a billing payment which is still being processed is synced again
+const BILLING_PAYMENT_SYNC_INTERVAL_IN_SECONDS: i64 = 15 * 60;
+
+const DEFAULT_LIST_LIMIT: i64 = 10;
+
+/// How the billing task of a subscription has to be rescheduled after a billing run
+#[derive(Debug)]
+pub enum BillingTaskSchedule {
+    /// The billing period has been paid for, the next payment is due at the given time
+    NextPeriod(PrimitiveDateTime),
+    /// The payment has failed or is still being processed, and has to be retried or synced at
+    /// the given time
+    Retry(PrimitiveDateTime),
+    /// The subscription will not be billed any further
+    Finish(&'static str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BillingPaymentOutcome {
+    Succeeded,
+    Failed,
+    Pending,
+}
+
+impl From<enums::IntentStatus> for BillingPaymentOutcome {
+    fn from(status: enums::IntentStatus) -> Self {
+        match status {
+            enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured => {
+                Self::Succeeded
+            }
+            // The customer is not present for the payments of a subscription, payments which need
+            // any action from them will not complete on their own
+            enums::IntentStatus::Failed
+            | enums::IntentStatus::Cancelled
+            | enums::IntentStatus::RequiresCustomerAction
+            | enums::IntentStatus::RequiresPaymentMethod
+            | enums::IntentStatus::RequiresConfirmation => Self::Failed,
+            enums::IntentStatus::Processing
+            | enums::IntentStatus::RequiresMerchantAction
+            | enums::IntentStatus::RequiresCapture
+            | enums::IntentStatus::PartiallyCapturedAndCapturable => Self::Pending,
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn create_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_api::PlanCreateRequest,
+) -> RouterResponse<subscription_api::PlanResponse> {
+    if req.amount.get_amount_as_i64() <= 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than zero".into(),
+        }));
+    }
+    if req.billing_interval_count == Some(0) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "billing_interval_count must be greater than zero".into(),
+        }));
+    }
+
+    let now = common_utils::date_time::now();
+    let plan_new = storage::PlanNew {
+        plan_id: generate_id(consts::ID_LENGTH, "plan"),
+        merchant_id: merchant_account.get_id().to_owned(),
+        name: req.name,
+        description: req.description,
+        amount: req.amount,
+        currency: req.currency,
+        billing_interval: req.billing_interval,
+        billing_interval_count: req.billing_interval_count.map_or(1, i32::from),
+        trial_period_days: req.trial_period_days.map(i32::from),
+        metadata: req.metadata,
+        is_active: true,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let plan = state
+        .store
+        .insert_plan(plan_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert plan")?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_api::PlanResponse::foreign_from(plan),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_api::PlanId,
+) -> RouterResponse<subscription_api::PlanResponse> {
+    let plan = state
+        .store
+        .find_plan_by_merchant_id_plan_id(merchant_account.get_id(), &req.plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PlanNotFound {
+            plan_id: req.plan_id,
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_api::PlanResponse::foreign_from(plan),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_plans(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: subscription_api::PlanListConstraints,
+) -> RouterResponse<Vec<subscription_api::PlanResponse>> {
+    let plans = state
+        .store
+        .list_plans_by_merchant_id(
+            merchant_account.get_id(),
+            constraints.limit.map_or(DEFAULT_LIST_LIMIT, i64::from),
+            constraints.offset.map_or(0, i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list plans")?;
+
+    Ok(services::ApplicationResponse::Json(
+        plans
+            .into_iter()
+            .map(subscription_api::PlanResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_api::PlanUpdateRequest,
+) -> RouterResponse<subscription_api::PlanResponse> {
+    let plan = state
+        .store
+        .update_plan_by_merchant_id_plan_id(
+            merchant_account.get_id(),
+            &req.plan_id,
+            storage::PlanUpdate::Update {
+                name: req.name,
+                description: req.description,
+                metadata: req.metadata,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PlanNotFound {
+            plan_id: req.plan_id.clone(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_api::PlanResponse::foreign_from(plan),
+    ))
+}
+
+/// Deactivated plans cannot be subscribed to, existing subscriptions of the plan continue to be
+/// billed.
+#[instrument(skip_all)]
+pub async fn deactivate_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_api::PlanId,
+) -> RouterResponse<subscription_api::PlanResponse> {
+    let plan = state
+        .store
+        .update_plan_by_merchant_id_plan_id(
+            merchant_account.get_id(),
+            &req.plan_id,
+            storage::PlanUpdate::Deactivate,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PlanNotFound {
+            plan_id: req.plan_id,
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_api::PlanResponse::foreign_from(plan),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn create_subscription(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: subscription_api::SubscriptionCreateRequest,
+) -> RouterResponse<subscription_api::SubscriptionResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+
+    let plan = db
+        .find_plan_by_merchant_id_plan_id(merchant_id, &req.plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PlanNotFound {
+            plan_id: req.plan_id.clone(),
+        })?;
+    if !plan.is_active {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "subscriptions cannot be created for an inactive plan".into(),
+        }));
+    }
+
+    db.find_customer_by_customer_id_merchant_id(
+        &(&state).into(),
+        &req.customer_id,
+        merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    utils::validate_mandate_for_subscription(
+        db,
+        &merchant_account,
+        &req.mandate_id,
+        &req.customer_id,
+    )
+    .await?;
+
+    let profile_id =
+        utils::get_subscription_profile_id(&state, &merchant_account, &key_store, req.profile_id)
+            .await?;
+
+    // The first payment is made right away, unless the plan has a trial period
+    let now = common_utils::date_time::now();
+    let trial_end = plan
+        .trial_period_days
+        .filter(|trial_period_days| *trial_period_days > 0)
+        .map(|trial_period_days| {
+            utils::add_billing_intervals(now, enums::BillingInterval::Day, trial_period_days)
+        })
+        .transpose()?;
+    let status = if trial_end.is_some() {
+        enums::SubscriptionStatus::Trialing
+    } else {
+        enums::SubscriptionStatus::Active
+    };
+
+    let subscription_new = storage::SubscriptionNew {
+        subscription_id: generate_id(consts::ID_LENGTH, "sub"),
+        merchant_id: merchant_id.to_owned(),
+        customer_id: req.customer_id,
+        plan_id: plan.plan_id,
+        mandate_id: req.mandate_id,
+        profile_id,
+        status,
+        current_period_start: now,
+        current_period_end: trial_end.unwrap_or(now),
+        trial_end,
+        billing_anchor: trial_end.unwrap_or(now),
+        billed_period_count: 0,
+        cancel_at_period_end: false,
+        cancelled_at: None,
+        failed_payment_count: 0,
+        latest_payment_id: None,
+        metadata: req.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let subscription = db
+        .insert_subscription(subscription_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription")?;
+
+    utils::add_billing_task_to_process_tracker(db, &subscription).await?;
+
+    utils::trigger_subscription_webhook(
+        &state,
+        merchant_account,
+        &key_store,
+        &subscription,
+        enums::EventType::SubscriptionCreated,
+        subscription.subscription_id.clone(),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_api::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_api::SubscriptionId,
+) -> RouterResponse<subscription_api::SubscriptionResponse> {
+    let subscription = state
+        .store
+        .find_subscription_by_merchant_id_subscription_id(
+            merchant_account.get_id(),
+            &req.subscription_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SubscriptionNotFound {
+            subscription_id: req.subscription_id,
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_api::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscriptions(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: subscription_api::SubscriptionListConstraints,
+) -> RouterResponse<Vec<subscription_api::SubscriptionResponse>> {
+    let subscriptions = state
+        .store
+        .list_subscriptions_by_merchant_id(
+            merchant_account.get_id(),
+            constraints.customer_id.as_ref(),
+            constraints.limit.map_or(DEFAULT_LIST_LIMIT, i64::from),
+            constraints.offset.map_or(0, i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscriptions")?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscriptions
+            .into_iter()
+            .map(subscription_api::SubscriptionResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_subscription(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_api::SubscriptionUpdateRequest,
+) -> RouterResponse<subscription_api::SubscriptionResponse> {
+    let db = &*state.store;
+    let subscription =
+        find_active_subscription(db, merchant_account.get_id(), &req.subscription_id).await?;
+
+    if let Some(mandate_id) = &req.mandate_id {
+        utils::validate_mandate_for_subscription(
+            db,
+            &merchant_account,
+            mandate_id,
+            &subscription.customer_id,
+        )
+        .await?;
+    }
+
+    let subscription = update_subscription_in_db(
+        db,
+        subscription,
+        storage::SubscriptionUpdate::Update {
+            mandate_id: req.mandate_id,
+            cancel_at_period_end: req.cancel_at_period_end,
+            metadata: req.metadata,
+        },
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_api::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn cancel_subscription(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: subscription_api::SubscriptionCancelRequest,
+) -> RouterResponse<subscription_api::SubscriptionResponse> {
+    let db = &*state.store;
+    let subscription =
+        find_active_subscription(db, merchant_account.get_id(), &req.subscription_id).await?;
+
+    let subscription = if req.cancel_at_period_end.unwrap_or(false) {
+        // The billing task cancels the subscription once the current period ends
+        update_subscription_in_db(
+            db,
+            subscription,
+            storage::SubscriptionUpdate::Update {
+                mandate_id: None,
+                cancel_at_period_end: Some(true),
+                metadata: None,
+            },
+        )
+        .await?
+    } else {
+        let subscription =
+            cancel_subscription_and_notify(&state, merchant_account, &key_store, subscription)
+                .await?;
+        utils::revoke_billing_task(db, &subscription).await?;
+        subscription
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_api::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+async fn find_active_subscription(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    subscription_id: &str,
+) -> RouterResult<storage::Subscription> {
+    let subscription = db
+        .find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SubscriptionNotFound {
+            subscription_id: subscription_id.to_owned(),
+        })?;
+
+    if subscription.status == enums::SubscriptionStatus::Cancelled {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "subscription has already been cancelled".into(),
+        }));
+    }
+
+    Ok(subscription)
+}
+
+async fn update_subscription_in_db(
+    db: &dyn StorageInterface,
+    subscription: storage::Subscription,
+    subscription_update: storage::SubscriptionUpdate,
+) -> RouterResult<storage::Subscription> {
+    db.update_subscription_by_merchant_id_subscription_id(
+        &subscription.merchant_id,
+        &subscription.subscription_id,
+        subscription_update,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::SubscriptionNotFound {
+        subscription_id: subscription.subscription_id,
+    })
+}
+
+async fn cancel_subscription_and_notify(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: storage::Subscription,
+) -> RouterResult<storage::Subscription> {
+    let subscription = update_subscription_in_db(
+        &*state.store,
+        subscription,
+        storage::SubscriptionUpdate::StatusUpdate {
+            status: enums::SubscriptionStatus::Cancelled,
+            cancelled_at: Some(common_utils::date_time::now()),
+        },
+    )
+    .await?;
+
+    utils::trigger_subscription_webhook(
+        state,
+        merchant_account,
+        key_store,
+        &subscription,
+        enums::EventType::SubscriptionCancelled,
+        subscription.subscription_id.clone(),
+    )
+    .await;
+
+    Ok(subscription)
+}
+
+/// Charges the customer for the billing period starting at the end of the current period, using
+/// the mandate of the subscription.
+///
+/// A payment which has already been made for the current billing attempt is synced instead of
+/// being made again.
+#[instrument(skip_all, fields(subscription_id = %subscription.subscription_id))]
+pub async fn perform_subscription_billing(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    subscription: storage::Subscription,
+) -> RouterResult<(storage::Subscription, BillingTaskSchedule)> {
+    if subscription.status == enums::SubscriptionStatus::Cancelled {
+        return Ok((
+            subscription,
+            BillingTaskSchedule::Finish(business_status::REVOKED),
+        ));
+    }
+
+    if subscription.cancel_at_period_end {
+        let subscription =
+            cancel_subscription_and_notify(state, merchant_account, &key_store, subscription)
+                .await?;
+        return Ok((
+            subscription,
+            BillingTaskSchedule::Finish(business_status::COMPLETED_BY_PT),
+        ));
+    }
+
+    let plan = state
+        .store
+        .find_plan_by_merchant_id_plan_id(&subscription.merchant_id, &subscription.plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PlanNotFound {
+            plan_id: subscription.plan_id.clone(),
+        })?;
+    let payment_id = utils::get_billing_payment_id(&subscription)?;
+
+    let existing_payment = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await;
+    let payment_status = match existing_payment {
+        Ok(payment_intent)
+            if BillingPaymentOutcome::from(payment_intent.status)
+                != BillingPaymentOutcome::Pending =>
+        {
+            Some(payment_intent.status)
+        }
+        Ok(_) => Some(
+            Box::pin(sync_billing_payment(
+                state,
+                &merchant_account,
+                &key_store,
+                &payment_id,
+            ))
+            .await?,
+        ),
+        Err(error) if error.current_context().is_db_not_found() => {
+            Box::pin(create_billing_payment(
+                state,
+                &merchant_account,
+                &key_store,
+                &subscription,
+                &plan,
+                &payment_id,
+            ))
+            .await?
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment of the billing attempt")?,
+    };
+
+    Box::pin(apply_billing_payment_outcome(
+        state,
+        merchant_account,
+        key_store,
+        subscription,
+        &plan,
+        payment_id,
+        payment_status,
+    ))
+    .await
+}
+
+/// Applies the outcome of a billing payment reported by an incoming webhook, instead of waiting
+/// for the billing task to sync the payment.
+#[instrument(skip_all, fields(subscription_id = %subscription.subscription_id))]
+pub async fn handle_billing_payment_webhook(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    subscription: storage::Subscription,
+    payment_id: &common_utils::id_type::PaymentId,
+) -> RouterResult<storage::Subscription> {
+    // The outcome of the payments of earlier billing attempts has already been applied
+    if subscription.status == enums::SubscriptionStatus::Cancelled
+        || utils::get_billing_payment_id(&subscription)? != *payment_id
+    {
+        return Ok(subscription);
+    }
+
+    let payment_status = Box::pin(sync_billing_payment(
+        state,
+        &merchant_account,
+        &key_store,
+        payment_id,
+    ))
+    .await?;
+    if BillingPaymentOutcome::from(payment_status) == BillingPaymentOutcome::Pending {
+        return Ok(subscription);
+    }
+
+    let db = &*state.store;
+    let plan = db
+        .find_plan_by_merchant_id_plan_id(&subscription.merchant_id, &subscription.plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PlanNotFound {
+            plan_id: subscription.plan_id.clone(),
+        })?;
+    let task_id =
+        utils::get_billing_task_id(&subscription.subscription_id, &subscription.merchant_id);
+
+    let (subscription, task_schedule) = Box::pin(apply_billing_payment_outcome(
+        state,
+        merchant_account,
+        key_store,
+        subscription,
+        &plan,
+        payment_id.to_owned(),
+        Some(payment_status),
+    ))
+    .await?;
+
+    if let Some(process) = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the subscription billing task")?
+    {
+        update_billing_task(db, process, task_schedule)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule the subscription billing task")?;
+    }
+
+    Ok(subscription)
+}
+
+pub async fn update_billing_task(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    task_schedule: BillingTaskSchedule,
+) -> CustomResult<(), errors::StorageError> {
+    match task_schedule {
+        BillingTaskSchedule::NextPeriod(schedule_time) => {
+            db.as_scheduler()
+                .reset_process(process, schedule_time)
+                .await
+        }
+        BillingTaskSchedule::Retry(schedule_time) => {
+            db.as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+        }
+        BillingTaskSchedule::Finish(business_status) => {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status)
+                .await
+        }
+    }
+}
+
+/// Returns `None` if the payment could not be created, which is treated as a failed billing
+/// attempt.
+async fn create_billing_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+    plan: &storage::Plan,
+    payment_id: &common_utils::id_type::PaymentId,
+) -> RouterResult<Option<enums::IntentStatus>> {
+    let request = api::PaymentsRequest {
+        payment_id: Some(api_models::payments::PaymentIdType::PaymentIntentId(
+            payment_id.to_owned(),
+        )),
+        amount: Some(plan.amount.into()),
+        currency: Some(plan.currency),
+        capture_method: Some(enums::CaptureMethod::Automatic),
+        confirm: Some(true),
+        customer_id: Some(subscription.customer_id.clone()),
+        off_session: Some(true),
+        mandate_id: Some(subscription.mandate_id.clone()),
+        profile_id: subscription.profile_id.clone(),
+        description: Some(format!(
+            "Subscription {} to plan {}",
+            subscription.subscription_id, plan.name
+        )),
+        ..Default::default()
+    };
+
+    let response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        subscription.profile_id.clone(),
+        key_store.clone(),
+        payments::PaymentCreate,
+        request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await;
+
+    match response {
+        Ok(response) => get_payments_response(response).map(|response| Some(response.status)),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to create the payment for the billing attempt"
+            );
+            Ok(None)
+        }
+    }
+}
+
+async fn sync_billing_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &common_utils::id_type::PaymentId,
+) -> RouterResult<enums::IntentStatus> {
+    let request = api::PaymentsRetrieveRequest {
+        resource_id: api_models::payments::PaymentIdType::PaymentIntentId(payment_id.to_owned()),
+        merchant_id: Some(merchant_account.get_id().to_owned()),
+        force_sync: true,
+        ..Default::default()
+    };
+
+    let response = Box::pin(payments::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::PSync>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        payments::PaymentStatus,
+        request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?;
+
+    get_payments_response(response).map(|response| response.status)
+}
+
+fn get_payments_response(
+    response: services::ApplicationResponse<api::PaymentsResponse>,
+) -> RouterResult<api::PaymentsResponse> {
+    match response {
+        services::ApplicationResponse::Json(response)
+        | services::ApplicationResponse::JsonWithHeaders((response, _)) => Ok(response),
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to get the payments response in json"),
+    }
+}
+
+async fn apply_billing_payment_outcome(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    subscription: storage::Subscription,
+    plan: &storage::Plan,
+    payment_id: common_utils::id_type::PaymentId,
+    payment_status: Option<enums::IntentStatus>,
+) -> RouterResult<(storage::Subscription, BillingTaskSchedule)> {
+    let db = &*state.store;
+    let latest_payment_id = payment_status.map(|_| payment_id.clone());
+
+    match payment_status.map_or(BillingPaymentOutcome::Failed, BillingPaymentOutcome::from) {
+        BillingPaymentOutcome::Succeeded => {
+            let billed_period_count = subscription.billed_period_count.saturating_add(1);
+            let current_period_end = utils::get_billing_period_end(
+                subscription.billing_anchor,
+                plan.billing_interval,
+                plan.billing_interval_count,
+                billed_period_count,
+            )?;
+            let subscription = update_subscription_in_db(
+                db,
+                subscription.clone(),
+                storage::SubscriptionUpdate::RenewalUpdate {
+                    current_period_start: subscription.current_period_end,
+                    current_period_end,
+                    billed_period_count,
+                    latest_payment_id,
+                },
+            )
+            .await?;
+
+            utils::trigger_subscription_webhook(
+                state,
+                merchant_account,
+                &key_store,
+                &subscription,
+                enums::EventType::SubscriptionRenewed,
+                payment_id.get_string_repr().to_owned(),
+            )
+            .await;
+
+            Ok((
+                subscription,
+                BillingTaskSchedule::NextPeriod(current_period_end),
+            ))
+        }
+
+        BillingPaymentOutcome::Pending => {
+            let subscription = if subscription.latest_payment_id.as_ref() != Some(&payment_id) {
+                update_subscription_in_db(
+                    db,
+                    subscription,
+                    storage::SubscriptionUpdate::LatestPaymentUpdate {
+                        latest_payment_id: payment_id,
+                    },
+                )
+                .await?
+            } else {
+                subscription
+            };
+            let sync_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                BILLING_PAYMENT_SYNC_INTERVAL_IN_SECONDS,
+            ));
+
+            Ok((subscription, BillingTaskSchedule::Retry(sync_time)))
+        }
+
+        BillingPaymentOutcome::Failed => {
+            let failed_payment_count = subscription.failed_payment_count.saturating_add(1);
+            let subscription = update_subscription_in_db(
+                db,
+                subscription,
+                storage::SubscriptionUpdate::PaymentFailureUpdate {
+                    status: enums::SubscriptionStatus::PastDue,
+                    failed_payment_count,
+                    latest_payment_id,
+                },
+            )
+            .await?;
+
+            utils::trigger_subscription_webhook(
+                state,
+                merchant_account.clone(),
+                &key_store,
+                &subscription,
+                enums::EventType::SubscriptionPaymentFailed,
+                payment_id.get_string_repr().to_owned(),
+            )
+            .await;
+
+            match subscription_billing::get_billing_retry_schedule_time(
+                db,
+                &subscription.merchant_id,
+                failed_payment_count.saturating_sub(1),
+            )
+            .await
+            {
+                Some(retry_time) => Ok((subscription, BillingTaskSchedule::Retry(retry_time))),
+                None => {
+                    logger::info!(
+                        "Payment retries exhausted for the billing period, cancelling subscription"
+                    );
+                    let subscription = cancel_subscription_and_notify(
+                        state,
+                        merchant_account,
+                        &key_store,
+                        subscription,
+                    )
+                    .await?;
+
+                    Ok((
+                        subscription,
+                        BillingTaskSchedule::Finish(business_status::RETRIES_EXCEEDED),
+                    ))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use api_models::subscription as subscription_api;
+
+use crate::types::{storage, transformers::ForeignFrom};
+
+impl ForeignFrom<storage::Plan> for subscription_api::PlanResponse {
+    fn foreign_from(plan: storage::Plan) -> Self {
+        Self {
+            plan_id: plan.plan_id,
+            merchant_id: plan.merchant_id,
+            name: plan.name,
+            description: plan.description,
+            amount: plan.amount,
+            currency: plan.currency,
+            billing_interval: plan.billing_interval,
+            billing_interval_count: plan.billing_interval_count,
+            trial_period_days: plan.trial_period_days,
+            metadata: plan.metadata,
+            is_active: plan.is_active,
+            created_at: plan.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Subscription> for subscription_api::SubscriptionResponse {
+    fn foreign_from(subscription: storage::Subscription) -> Self {
+        Self {
+            subscription_id: subscription.subscription_id,
+            merchant_id: subscription.merchant_id,
+            customer_id: subscription.customer_id,
+            plan_id: subscription.plan_id,
+            mandate_id: subscription.mandate_id,
+            profile_id: subscription.profile_id,
+            status: subscription.status,
+            current_period_start: subscription.current_period_start,
+            current_period_end: subscription.current_period_end,
+            trial_end: subscription.trial_end,
+            cancel_at_period_end: subscription.cancel_at_period_end,
+            cancelled_at: subscription.cancelled_at,
+            failed_payment_count: subscription.failed_payment_count,
+            latest_payment_id: subscription.latest_payment_id,
+            metadata: subscription.metadata,
+            created_at: subscription.created_at,
+        }
+    }
+}
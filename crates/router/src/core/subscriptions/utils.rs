@@ -0,0 +1,384 @@
+use api_models::{subscription as subscription_api, webhooks};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use time::PrimitiveDateTime;
+
+use super::SUBSCRIPTION_BILLING_TASK;
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    types::{
+        domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+/// Returns the time which is `count` billing intervals after `start`.
+///
+/// Monthly and yearly intervals are clamped to the last day of the resulting month, so that a
+/// subscription started on January 31st is next billed on the last day of February.
+pub fn add_billing_intervals(
+    start: PrimitiveDateTime,
+    billing_interval: enums::BillingInterval,
+    count: i32,
+) -> RouterResult<PrimitiveDateTime> {
+    match billing_interval {
+        enums::BillingInterval::Day => start.checked_add(time::Duration::days(count.into())),
+        enums::BillingInterval::Week => start.checked_add(time::Duration::weeks(count.into())),
+        enums::BillingInterval::Month => add_months(start, count),
+        enums::BillingInterval::Year => count
+            .checked_mul(12)
+            .and_then(|months| add_months(start, months)),
+    }
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to compute the end of the billing period")
+}
+
+/// Returns the end of the `period_number`th billing period of a subscription.
+///
+/// Period ends are always computed from the billing anchor instead of the previous period end, so
+/// that a day which was clamped in a short month is restored in the following months (January 31st,
+/// February 29th, March 31st and so on).
+pub fn get_billing_period_end(
+    billing_anchor: PrimitiveDateTime,
+    billing_interval: enums::BillingInterval,
+    billing_interval_count: i32,
+    period_number: i32,
+) -> RouterResult<PrimitiveDateTime> {
+    let count = billing_interval_count
+        .checked_mul(period_number)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Billing interval count overflowed")?;
+
+    add_billing_intervals(billing_anchor, billing_interval, count)
+}
+
+fn add_months(start: PrimitiveDateTime, months: i32) -> Option<PrimitiveDateTime> {
+    let date = start.date();
+    let month_index = date
+        .year()
+        .checked_mul(12)?
+        .checked_add(i32::from(u8::from(date.month())) - 1)?
+        .checked_add(months)?;
+    let year = month_index.div_euclid(12);
+    let month = time::Month::try_from(u8::try_from(month_index.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = date.day().min(time::util::days_in_year_month(year, month));
+
+    time::Date::from_calendar_date(year, month, day)
+        .ok()
+        .map(|date| PrimitiveDateTime::new(date, start.time()))
+}
+
+/// The payment ID used for the current billing attempt of the subscription.
+///
+/// The ID is derived from the billing period and the attempt number, so that a billing run which
+/// is retried after a crash syncs the payment it had already created instead of charging the
+/// customer again.
+pub fn get_billing_payment_id(
+    subscription: &storage::Subscription,
+) -> RouterResult<common_utils::id_type::PaymentId> {
+    let payment_id = format!(
+        "{}_{}_{}",
+        subscription.subscription_id,
+        subscription
+            .current_period_end
+            .assume_utc()
+            .unix_timestamp(),
+        subscription.failed_payment_count.saturating_add(1)
+    );
+
+    common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Owned(payment_id))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct the payment ID for the billing attempt")
+}
+
+pub fn get_billing_task_id(
+    subscription_id: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::SubscriptionBillingWorkflow,
+        SUBSCRIPTION_BILLING_TASK,
+        subscription_id,
+        merchant_id,
+    )
+}
+
+/// Validates that the mandate exists, is active and belongs to the customer of the subscription.
+pub async fn validate_mandate_for_subscription(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    mandate_id: &str,
+    customer_id: &common_utils::id_type::CustomerId,
+) -> RouterResult<()> {
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(
+            merchant_account.get_id(),
+            mandate_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    if mandate.customer_id != *customer_id {
+        return Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: "mandate does not belong to the customer".into(),
+        }));
+    }
+
+    if mandate.mandate_status != enums::MandateStatus::Active {
+        return Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: format!("mandate is in {} status", mandate.mandate_status),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Resolves the business profile for a new subscription, falling back to the default profile of
+/// the merchant.
+pub async fn get_subscription_profile_id(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+) -> RouterResult<Option<common_utils::id_type::ProfileId>> {
+    match profile_id {
+        Some(profile_id) => crate::core::utils::validate_and_get_business_profile(
+            &*state.store,
+            &state.into(),
+            key_store,
+            Some(&profile_id),
+            merchant_account.get_id(),
+        )
+        .await
+        .map(|business_profile| business_profile.map(|profile| profile.get_id().to_owned())),
+        None => Ok(merchant_account.default_profile.clone()),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn add_billing_task_to_process_tracker(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::SubscriptionBillingWorkflow;
+    let tag = ["SUBSCRIPTIONS"];
+    let process_tracker_id =
+        get_billing_task_id(&subscription.subscription_id, &subscription.merchant_id);
+    let tracking_data = storage::SubscriptionBillingTrackingData {
+        subscription_id: subscription.subscription_id.clone(),
+        merchant_id: subscription.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SUBSCRIPTION_BILLING_TASK,
+        runner,
+        tag,
+        tracking_data,
+        subscription.current_period_end,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct subscription billing process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .inspect(|_| {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "SubscriptionBilling")]),
+            );
+        })
+        .inspect_err(|_| {
+            metrics::TASK_ADDITION_FAILURES_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "SubscriptionBilling")]),
+            );
+        })
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting subscription billing task in process_tracker")?;
+
+    Ok(())
+}
+
+/// Finishes the billing task of a subscription which has been cancelled.
+#[instrument(skip_all)]
+pub async fn revoke_billing_task(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+) -> RouterResult<()> {
+    let task_ids = vec![get_billing_task_id(
+        &subscription.subscription_id,
+        &subscription.merchant_id,
+    )];
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::StatusUpdate {
+        status: enums::ProcessTrackerStatus::Finish,
+        business_status: Some(String::from(business_status::REVOKED)),
+    };
+
+    db.process_tracker_update_process_status_by_ids(task_ids, updated_process_tracker_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to revoke subscription billing task")?;
+
+    Ok(())
+}
+
+/// Triggers an outgoing webhook for a subscription lifecycle event.
+///
+/// `primary_object_id` has to be unique for every occurrence of an event type, since outgoing
+/// webhooks are deduplicated on it. Events which can occur once per billing attempt use the ID of
+/// the billing payment, while the rest use the subscription ID.
+///
+/// Failures are only logged, the subscription has already been updated at this point.
+#[instrument(skip_all)]
+pub async fn trigger_subscription_webhook(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+    event_type: enums::EventType,
+    primary_object_id: String,
+) {
+    let result = create_subscription_event(
+        state,
+        merchant_account,
+        key_store,
+        subscription,
+        event_type,
+        primary_object_id,
+    )
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            subscription_id = %subscription.subscription_id,
+            %event_type,
+            "Failed to trigger outgoing webhook for subscription"
+        );
+    }
+}
+
+async fn create_subscription_event(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+    event_type: enums::EventType,
+    primary_object_id: String,
+) -> RouterResult<()> {
+    let Some(profile_id) = subscription.profile_id.as_ref() else {
+        logger::warn!(
+            subscription_id = %subscription.subscription_id,
+            "Outgoing webhook not sent as the subscription is not associated with a business profile"
+        );
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let subscription_response =
+        subscription_api::SubscriptionResponse::foreign_from(subscription.clone());
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        key_store,
+        event_type,
+        enums::EventClass::Subscriptions,
+        primary_object_id,
+        enums::EventObjectType::SubscriptionDetails,
+        webhooks::OutgoingWebhookContent::SubscriptionDetails(Box::new(subscription_response)),
+        Some(subscription.created_at),
+    ))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_add_billing_intervals_clamps_to_end_of_month() {
+        let start = datetime!(2024-01-31 10:00);
+
+        assert_eq!(
+            add_billing_intervals(start, enums::BillingInterval::Month, 1).unwrap(),
+            datetime!(2024-02-29 10:00)
+        );
+        assert_eq!(
+            add_billing_intervals(start, enums::BillingInterval::Month, 3).unwrap(),
+            datetime!(2024-04-30 10:00)
+        );
+        assert_eq!(
+            add_billing_intervals(datetime!(2024-02-29 10:00), enums::BillingInterval::Year, 1)
+                .unwrap(),
+            datetime!(2025-02-28 10:00)
+        );
+    }
+
+    #[test]
+    fn test_get_billing_period_end_does_not_drift_across_renewals() {
+        let billing_anchor = datetime!(2024-01-31 10:00);
+
+        let period_ends = (1..=5)
+            .map(|period_number| {
+                get_billing_period_end(
+                    billing_anchor,
+                    enums::BillingInterval::Month,
+                    1,
+                    period_number,
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            period_ends,
+            vec![
+                datetime!(2024-02-29 10:00),
+                datetime!(2024-03-31 10:00),
+                datetime!(2024-04-30 10:00),
+                datetime!(2024-05-31 10:00),
+                datetime!(2024-06-30 10:00),
+            ]
+        );
+        assert_eq!(
+            get_billing_period_end(billing_anchor, enums::BillingInterval::Month, 3, 2).unwrap(),
+            datetime!(2024-07-31 10:00)
+        );
+    }
+
+    #[test]
+    fn test_add_billing_intervals_crosses_year_boundary() {
+        let start = datetime!(2024-11-15 00:00);
+
+        assert_eq!(
+            add_billing_intervals(start, enums::BillingInterval::Month, 2).unwrap(),
+            datetime!(2025-01-15 00:00)
+        );
+        assert_eq!(
+            add_billing_intervals(start, enums::BillingInterval::Week, 7).unwrap(),
+            datetime!(2025-01-03 00:00)
+        );
+    }
+}
@@ -1122,6 +1122,105 @@ pub async fn construct_defend_dispute_router_data<'a>(
     Ok(router_data)
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn construct_dispute_sync_router_data<'a>(
+    state: &'a SessionState,
+    payment_intent: &'a storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+) -> RouterResult<types::DisputeSyncRouterData> {
+    let connector_id = &dispute.connector;
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payment_intent")?
+        .clone();
+
+    let merchant_connector_account = helpers::get_merchant_connector_account(
+        state,
+        merchant_account.get_id(),
+        None,
+        key_store,
+        &profile_id,
+        connector_id,
+        payment_attempt.merchant_connector_id.as_ref(),
+    )
+    .await?;
+
+    let test_mode: Option<bool> = merchant_connector_account.is_test_mode_on();
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let payment_method = payment_attempt
+        .payment_method
+        .get_required_value("payment_method_type")?;
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.get_id().clone(),
+        connector: connector_id.to_string(),
+        payment_id: payment_attempt.payment_id.get_string_repr().to_owned(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        status: payment_attempt.status,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: payment_intent.return_url.clone(),
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        amount_captured: payment_intent
+            .amount_captured
+            .map(|amt| amt.get_amount_as_i64()),
+        minor_amount_captured: payment_intent.amount_captured,
+        payment_method_status: None,
+        request: types::DisputeSyncData {
+            dispute_id: dispute.dispute_id.clone(),
+            connector_dispute_id: dispute.connector_dispute_id.clone(),
+            dispute_stage: dispute.dispute_stage,
+            dispute_status: dispute.dispute_status,
+        },
+        response: Err(ErrorResponse::get_not_implemented()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        customer_id: None,
+        connector_customer: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_request_reference_id: get_connector_request_reference_id(
+            &state.conf,
+            merchant_account.get_id(),
+            payment_attempt,
+        ),
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode,
+        connector_api_version: None,
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        refund_id: None,
+        dispute_id: Some(dispute.dispute_id.clone()),
+        connector_response: None,
+        integrity_check: Ok(()),
+        additional_merchant_data: None,
+        header_payload: None,
+    };
+    Ok(router_data)
+}
+
 #[instrument(skip_all)]
 pub async fn construct_retrieve_file_router_data<'a>(
     state: &'a SessionState,
@@ -1577,6 +1676,12 @@ impl GetProfileId for diesel_models::routing_algorithm::RoutingProfileMetadata {
     }
 }
 
+impl GetProfileId for diesel_models::routing_algorithm::RoutingAlgorithm {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
 impl GetProfileId for domain::Profile {
     fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
         Some(self.get_id())
@@ -1,10 +1,16 @@
 mod incoming;
+#[cfg(feature = "olap")]
+pub mod incoming_webhook_events;
 mod outgoing;
 pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
+pub mod webhook_endpoints;
+#[cfg(feature = "olap")]
 pub mod webhook_events;
 
+#[cfg(feature = "olap")]
+pub(crate) use self::incoming::replay_incoming_webhook_event;
 pub(crate) use self::{
     incoming::incoming_webhooks_wrapper,
     outgoing::{
@@ -1,5 +1,6 @@
 use std::{str::FromStr, time::Instant};
 
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
 use actix_web::FromRequest;
 #[cfg(feature = "payouts")]
 use api_models::payouts as payout_models;
@@ -7,14 +8,21 @@ use api_models::{
     payments::HeaderPayload,
     webhooks::{self, WebhookResponseTracker},
 };
-use common_utils::{errors::ReportSwitchExt, events::ApiEventsType};
+use common_utils::{
+    errors::ReportSwitchExt,
+    events::ApiEventsType,
+    ext_traits::{Encode, StringExt},
+    type_name,
+    types::keymanager::{Identifier, KeyManagerState},
+};
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
     router_request_types::VerifyWebhookSourceRequestData,
     router_response_types::{VerifyWebhookSourceResponseData, VerifyWebhookStatus},
+    type_encryption::{crypto_operation, CryptoOperation},
 };
 use hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails;
-use masking::ExposeInterface;
+use masking::{ExposeInterface, PeekInterface, Secret};
 use router_env::{instrument, metrics::add_attributes, tracing, tracing_actix_web::RequestId};
 
 use super::{types, utils, MERCHANT_ID};
@@ -23,7 +31,7 @@ use crate::{
     core::{
         api_locking,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
-        metrics, payments, refunds, utils as core_utils,
+        metrics, payments, refunds, subscriptions, utils as core_utils,
         webhooks::utils::construct_webhook_router_data,
     },
     db::StorageInterface,
@@ -63,17 +71,39 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     body: actix_web::web::Bytes,
 ) -> RouterResponse<serde_json::Value> {
     let start_instant = Instant::now();
+    let request_details = IncomingWebhookRequestDetails {
+        method: req.method().clone(),
+        uri: req.uri().clone(),
+        headers: req.headers(),
+        query_params: req.query_string().to_string(),
+        body: &body,
+    };
+    let incoming_webhook_result = Box::pin(incoming_webhooks_core::<W>(
+        state.clone(),
+        req_state,
+        request_details,
+        merchant_account.clone(),
+        key_store.clone(),
+        connector_name_or_mca_id,
+    ))
+    .await;
+
+    // Failing to store the webhook should not affect the response sent to the connector
+    if let Err(error) = store_incoming_webhook_event(
+        &state,
+        &key_store,
+        connector_name_or_mca_id,
+        req,
+        &body,
+        &incoming_webhook_result,
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to store incoming webhook event");
+    }
+
     let (application_response, webhooks_response_tracker, serialized_req) =
-        Box::pin(incoming_webhooks_core::<W>(
-            state.clone(),
-            req_state,
-            req,
-            merchant_account.clone(),
-            key_store,
-            connector_name_or_mca_id,
-            body.clone(),
-        ))
-        .await?;
+        incoming_webhook_result?;
 
     logger::info!(incoming_webhook_payload = ?serialized_req);
 
@@ -120,11 +150,10 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
 async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     state: SessionState,
     req_state: ReqState,
-    req: &actix_web::HttpRequest,
+    request_details: IncomingWebhookRequestDetails<'_>,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     connector_name_or_mca_id: &str,
-    body: actix_web::web::Bytes,
 ) -> errors::RouterResult<(
     services::ApplicationResponse<serde_json::Value>,
     WebhookResponseTracker,
@@ -140,13 +169,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             merchant_account.get_id().get_string_repr().to_owned(),
         )],
     );
-    let mut request_details = IncomingWebhookRequestDetails {
-        method: req.method().clone(),
-        uri: req.uri().clone(),
-        headers: req.headers(),
-        query_params: req.query_string().to_string(),
-        body: &body,
-    };
+    let mut request_details = request_details;
 
     // Fetch the merchant connector account to get the webhooks source secret
     // `webhooks source secret` is a secret shared between the merchant and connector
@@ -466,6 +489,16 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             .await
             .attach_printable("Incoming webhook flow for payouts failed")?,
 
+            api::WebhookFlow::Subscription => Box::pin(subscriptions_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                key_store,
+                webhook_details,
+                source_verified,
+            ))
+            .await
+            .attach_printable("Incoming webhook flow for subscriptions failed")?,
+
             _ => Err(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
         }
@@ -493,6 +526,226 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     Ok((response, webhook_effect, serialized_request))
 }
 
+/// Re-runs the incoming webhook flow on the request stored in the specified incoming webhook
+/// event, and records the outcome of the replay in the incoming webhook event.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub(crate) async fn replay_incoming_webhook_event(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    incoming_webhook_event: domain::IncomingWebhookEvent,
+) -> errors::RouterResult<domain::IncomingWebhookEvent> {
+    let request_metadata: types::IncomingWebhookRequestMetadata = incoming_webhook_event
+        .request_details
+        .get_inner()
+        .peek()
+        .parse_struct("IncomingWebhookRequestMetadata")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse stored incoming webhook request details")?;
+
+    let (method, uri, headers) = get_stored_incoming_webhook_request_parts(&request_metadata)?;
+
+    let request_details = IncomingWebhookRequestDetails {
+        method,
+        uri,
+        headers: &headers,
+        query_params: request_metadata.query_params,
+        body: incoming_webhook_event.request_body.get_inner().peek(),
+    };
+    let incoming_webhook_result = Box::pin(incoming_webhooks_core::<webhooks::OutgoingWebhook>(
+        state.clone(),
+        req_state,
+        request_details,
+        merchant_account,
+        key_store.clone(),
+        &incoming_webhook_event.connector_name_or_mca_id,
+    ))
+    .await;
+
+    let (status, webhook_effect, error_message) =
+        get_incoming_webhook_outcome(&incoming_webhook_result);
+    let incoming_webhook_event_update = storage::IncomingWebhookEventUpdate::ReplayUpdate {
+        status,
+        webhook_effect,
+        error_message,
+        replay_count: incoming_webhook_event.replay_count.saturating_add(1),
+    };
+
+    state
+        .store
+        .update_incoming_webhook_event_by_merchant_id_webhook_event_id(
+            &(&state).into(),
+            &incoming_webhook_event.merchant_id,
+            &incoming_webhook_event.webhook_event_id,
+            incoming_webhook_event_update,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)
+        .attach_printable("Failed to update incoming webhook event after replay")
+}
+
+#[instrument(skip_all)]
+async fn store_incoming_webhook_event(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    connector_name_or_mca_id: &str,
+    req: &actix_web::HttpRequest,
+    body: &[u8],
+    incoming_webhook_result: &errors::RouterResult<(
+        services::ApplicationResponse<serde_json::Value>,
+        WebhookResponseTracker,
+        serde_json::Value,
+    )>,
+) -> errors::RouterResult<()> {
+    let key_manager_state: &KeyManagerState = &state.into();
+    let incoming_webhook_event = construct_incoming_webhook_event(
+        key_manager_state,
+        key_store,
+        connector_name_or_mca_id,
+        get_incoming_webhook_request_metadata(req),
+        body,
+        get_incoming_webhook_outcome(incoming_webhook_result),
+    )
+    .await?;
+
+    state
+        .store
+        .insert_incoming_webhook_event(key_manager_state, incoming_webhook_event, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert incoming webhook event")?;
+
+    Ok(())
+}
+
+/// Header values are stored as raw bytes, since connectors may send header values which are not
+/// valid UTF-8, and signatures have to be verified against the exact values that were received.
+fn get_incoming_webhook_request_metadata(
+    req: &actix_web::HttpRequest,
+) -> types::IncomingWebhookRequestMetadata {
+    types::IncomingWebhookRequestMetadata {
+        method: req.method().to_string(),
+        uri: req.uri().to_string(),
+        query_params: req.query_string().to_string(),
+        headers: req
+            .headers()
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.as_bytes().to_vec()))
+            .collect(),
+    }
+}
+
+#[cfg(feature = "olap")]
+fn get_stored_incoming_webhook_request_parts(
+    request_metadata: &types::IncomingWebhookRequestMetadata,
+) -> errors::RouterResult<(actix_web::http::Method, actix_web::http::Uri, HeaderMap)> {
+    let method = actix_web::http::Method::from_bytes(request_metadata.method.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse stored incoming webhook request method")?;
+    let uri = actix_web::http::Uri::from_str(&request_metadata.uri)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse stored incoming webhook request URI")?;
+    let mut headers = HeaderMap::new();
+    for (name, value) in &request_metadata.headers {
+        headers.append(
+            HeaderName::from_str(name)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse stored incoming webhook request header name")?,
+            HeaderValue::from_bytes(value)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse stored incoming webhook request header value")?,
+        );
+    }
+
+    Ok((method, uri, headers))
+}
+
+async fn construct_incoming_webhook_event(
+    key_manager_state: &KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    connector_name_or_mca_id: &str,
+    request_metadata: types::IncomingWebhookRequestMetadata,
+    body: &[u8],
+    (status, webhook_effect, error_message): (
+        enums::IncomingWebhookEventStatus,
+        Option<serde_json::Value>,
+        Option<String>,
+    ),
+) -> errors::RouterResult<domain::IncomingWebhookEvent> {
+    let request_details = crypto_operation(
+        key_manager_state,
+        type_name!(domain::IncomingWebhookEvent),
+        CryptoOperation::Encrypt(
+            request_metadata
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode incoming webhook request details")
+                .map(Secret::new)?,
+        ),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt incoming webhook request details")?;
+
+    // The body is stored exactly as received, so that the signature of the webhook can still be
+    // verified when it is replayed
+    let request_body = crypto_operation(
+        key_manager_state,
+        type_name!(domain::IncomingWebhookEvent),
+        CryptoOperation::Encrypt(Secret::new(body.to_vec())),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt incoming webhook request body")?;
+
+    let now = common_utils::date_time::now();
+    Ok(domain::IncomingWebhookEvent {
+        webhook_event_id: common_utils::generate_time_ordered_id("iwh"),
+        merchant_id: key_store.merchant_id.clone(),
+        connector_name_or_mca_id: connector_name_or_mca_id.to_owned(),
+        request_details,
+        request_body,
+        status,
+        webhook_effect,
+        error_message,
+        replay_count: 0,
+        created_at: now,
+        modified_at: now,
+    })
+}
+
+fn get_incoming_webhook_outcome<T>(
+    incoming_webhook_result: &errors::RouterResult<(T, WebhookResponseTracker, serde_json::Value)>,
+) -> (
+    enums::IncomingWebhookEventStatus,
+    Option<serde_json::Value>,
+    Option<String>,
+) {
+    match incoming_webhook_result {
+        Ok((_, webhook_effect, _)) => (
+            enums::IncomingWebhookEventStatus::Processed,
+            serde_json::to_value(webhook_effect)
+                .map_err(|error| logger::error!(?error, "Failed to serialize webhook effect"))
+                .ok(),
+            None,
+        ),
+        Err(error) => (
+            enums::IncomingWebhookEventStatus::Failed,
+            None,
+            Some(error.current_context().to_string()),
+        ),
+    }
+}
+
 #[instrument(skip_all)]
 async fn payments_incoming_webhook_flow(
     state: SessionState,
@@ -724,6 +977,18 @@ async fn payouts_incoming_webhook_flow(
                 )
             })?;
 
+        // Payouts created for refunds settle the refund
+        if let Err(error) = refunds::routing::update_refund_from_payout(
+            &state,
+            &merchant_account,
+            &payout_data.payouts,
+            updated_payout_attempt.status,
+        )
+        .await
+        {
+            logger::error!(?error, "Failed to update the refund of the payout");
+        }
+
         let event_type: Option<enums::EventType> = updated_payout_attempt.status.foreign_into();
 
         // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
@@ -947,11 +1212,30 @@ async fn get_or_update_dispute_object(
                 dispute_amount: dispute_details.amount.parse::<i64>().unwrap_or(0),
                 organization_id: organization_id.clone(),
             };
-            state
+            let dispute = state
                 .store
                 .insert_dispute(new_dispute.clone())
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+            #[cfg(feature = "v1")]
+            if crate::core::disputes::should_sync_dispute(
+                &dispute.connector,
+                dispute.dispute_status,
+            ) {
+                crate::core::disputes::add_dispute_sync_task_to_process_tracker(db, &dispute)
+                    .await
+                    .map_err(|error| {
+                        logger::error!(
+                            ?error,
+                            dispute_id = %dispute.dispute_id,
+                            "Failed to add dispute sync task to process tracker"
+                        )
+                    })
+                    .ok();
+            }
+
+            Ok(dispute)
         }
         Some(dispute) => {
             logger::info!("Dispute Already exists, Updating the dispute details");
@@ -1244,6 +1528,59 @@ async fn mandates_incoming_webhook_flow(
     }
 }
 
+/// Handles webhooks for the payments made for the billing periods of subscriptions
+#[instrument(skip_all)]
+async fn subscriptions_incoming_webhook_flow(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    if source_verified {
+        let db = &*state.store;
+        let payment_id = match webhook_details.object_reference_id {
+            webhooks::ObjectReferenceId::PaymentId(ref payment_id_type) => {
+                get_payment_id(
+                    db,
+                    payment_id_type,
+                    merchant_account.get_id(),
+                    merchant_account.storage_scheme,
+                )
+                .await?
+            }
+            _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+                .attach_printable("received a non-payment id for retrieving subscription")?,
+        };
+        let subscription = db
+            .find_subscription_by_merchant_id_latest_payment_id(
+                merchant_account.get_id(),
+                &payment_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+        let updated_subscription = Box::pin(subscriptions::handle_billing_payment_webhook(
+            &state,
+            merchant_account,
+            key_store,
+            subscription,
+            &payment_id,
+        ))
+        .await?;
+
+        Ok(WebhookResponseTracker::Subscription {
+            subscription_id: updated_subscription.subscription_id,
+            status: updated_subscription.status,
+        })
+    } else {
+        logger::error!("Webhook source verification failed for subscriptions webhook flow");
+        Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 async fn frm_incoming_webhook_flow(
@@ -1715,3 +2052,131 @@ async fn fetch_optional_mca_and_connector(
         Ok((None, connector, connector_name))
     }
 }
+
+#[cfg(all(test, feature = "olap"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::sync::Arc;
+
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{
+        db::{
+            incoming_webhook_event::IncomingWebhookEventInterface,
+            merchant_key_store::MerchantKeyStoreInterface, MasterKeyInterface, MockDb,
+        },
+        routes::{
+            self,
+            app::{settings::Settings, StorageImpl},
+        },
+    };
+
+    #[tokio::test]
+    async fn test_stored_incoming_webhook_is_replayed_with_original_bytes() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::PostgresqlTest,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        let state = &Arc::new(app_state)
+            .get_session_state("public", || {})
+            .unwrap();
+        let key_manager_state = &state.into();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let master_key = mockdb.get_master_key();
+        mockdb
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.clone()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: datetime!(2024-10-01 0:00),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let key_store = mockdb
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        // Neither the signature header nor the body are valid UTF-8
+        let signature = HeaderValue::from_bytes(b"t=1729000000,v1=\xe9\x8f\xff").unwrap();
+        let body = b"<notification><name>\xe9t\xe9</name></notification>".to_vec();
+        let req = actix_web::test::TestRequest::post()
+            .uri("/webhooks/merchant_1/stripe?source=test")
+            .insert_header(("stripe-signature", signature.clone()))
+            .to_http_request();
+
+        let incoming_webhook_event = construct_incoming_webhook_event(
+            key_manager_state,
+            &key_store,
+            "stripe",
+            get_incoming_webhook_request_metadata(&req),
+            &body,
+            (enums::IncomingWebhookEventStatus::Processed, None, None),
+        )
+        .await
+        .unwrap();
+        let webhook_event_id = incoming_webhook_event.webhook_event_id.clone();
+        mockdb
+            .insert_incoming_webhook_event(key_manager_state, incoming_webhook_event, &key_store)
+            .await
+            .unwrap();
+
+        let stored_incoming_webhook_event = mockdb
+            .find_incoming_webhook_event_by_merchant_id_webhook_event_id(
+                key_manager_state,
+                &merchant_id,
+                &webhook_event_id,
+                &key_store,
+            )
+            .await
+            .unwrap();
+        let request_metadata: types::IncomingWebhookRequestMetadata = stored_incoming_webhook_event
+            .request_details
+            .get_inner()
+            .peek()
+            .parse_struct("IncomingWebhookRequestMetadata")
+            .unwrap();
+        let (method, uri, headers) =
+            get_stored_incoming_webhook_request_parts(&request_metadata).unwrap();
+
+        assert_eq!(method, actix_web::http::Method::POST);
+        assert_eq!(uri.path(), "/webhooks/merchant_1/stripe");
+        assert_eq!(request_metadata.query_params, "source=test");
+        assert_eq!(headers.get("stripe-signature"), Some(&signature));
+        assert_eq!(
+            stored_incoming_webhook_event
+                .request_body
+                .get_inner()
+                .peek(),
+            &body
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::{app::ReqState, SessionState},
+    services::ApplicationResponse,
+    types::{api, domain},
+};
+
+const INCOMING_WEBHOOK_EVENTS_LIST_MAX_LIMIT: u16 = 100;
+
+#[instrument(skip(state))]
+pub async fn list_incoming_webhook_events(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    constraints: api::webhook_events::IncomingWebhookEventListConstraints,
+) -> RouterResponse<Vec<api::webhook_events::IncomingWebhookEventListItemResponse>> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = get_key_store(&state, &merchant_id).await?;
+
+    let limit = match constraints.limit {
+        Some(limit) if limit <= INCOMING_WEBHOOK_EVENTS_LIST_MAX_LIMIT => Ok(limit),
+        Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`limit` must be a number less than {INCOMING_WEBHOOK_EVENTS_LIST_MAX_LIMIT}"
+            ),
+        }),
+        None => Ok(INCOMING_WEBHOOK_EVENTS_LIST_MAX_LIMIT),
+    }?;
+    let offset = match constraints.offset {
+        Some(offset) if offset > 0 => Some(i64::from(offset)),
+        _ => None,
+    };
+
+    let incoming_webhook_events = store
+        .list_incoming_webhook_events_by_merchant_id_constraints(
+            key_manager_state,
+            &merchant_id,
+            constraints.connector,
+            constraints.status,
+            constraints.created_after,
+            constraints.created_before,
+            Some(i64::from(limit)),
+            offset,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list incoming webhook events with specified constraints")?;
+
+    Ok(ApplicationResponse::Json(
+        incoming_webhook_events
+            .into_iter()
+            .map(api::webhook_events::IncomingWebhookEventListItemResponse::from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_incoming_webhook_event(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    webhook_event_id: String,
+) -> RouterResponse<api::webhook_events::IncomingWebhookEventRetrieveResponse> {
+    let key_store = get_key_store(&state, &merchant_id).await?;
+    let incoming_webhook_event = state
+        .store
+        .find_incoming_webhook_event_by_merchant_id_webhook_event_id(
+            &(&state).into(),
+            &merchant_id,
+            &webhook_event_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::IncomingWebhookEventRetrieveResponse::try_from(
+            incoming_webhook_event,
+        )?,
+    ))
+}
+
+/// Re-runs the incoming webhook flow on the stored webhook request. The response contains the
+/// outcome of the replay, even if processing the webhook failed again.
+#[instrument(skip(state, req_state))]
+pub async fn replay_incoming_webhook_event(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_id: common_utils::id_type::MerchantId,
+    webhook_event_id: String,
+) -> RouterResponse<api::webhook_events::IncomingWebhookEventRetrieveResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = get_key_store(&state, &merchant_id).await?;
+
+    let merchant_account = store
+        .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let incoming_webhook_event = store
+        .find_incoming_webhook_event_by_merchant_id_webhook_event_id(
+            key_manager_state,
+            &merchant_id,
+            &webhook_event_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    let replayed_incoming_webhook_event = Box::pin(super::replay_incoming_webhook_event(
+        state.clone(),
+        req_state,
+        merchant_account,
+        key_store,
+        incoming_webhook_event,
+    ))
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::IncomingWebhookEventRetrieveResponse::try_from(
+            replayed_incoming_webhook_event,
+        )?,
+    ))
+}
+
+async fn get_key_store(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> errors::RouterResult<domain::MerchantKeyStore> {
+    let store = state.store.as_ref();
+    store
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+}
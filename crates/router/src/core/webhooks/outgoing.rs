@@ -61,24 +61,131 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
-
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    if !state.conf.webhooks.outgoing_enabled {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %primary_object_id,
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
+        );
+        return Ok(());
+    }
+
+    let is_profile_webhook_url_configured =
+        get_webhook_url_from_business_profile(&business_profile).is_ok_and(|url| !url.is_empty());
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &business_profile.merchant_id,
+            business_profile.get_id(),
+        )
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to list webhook endpoints for business profile"
+            );
+        })
+        .unwrap_or_default();
+
+    let webhook_targets = get_webhook_targets(
+        is_profile_webhook_url_configured,
+        webhook_endpoints,
+        event_type,
+    );
+
+    if webhook_targets.is_empty() {
         logger::debug!(
             business_profile_id=?business_profile.get_id(),
-            %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            %primary_object_id,
+            "Merchant webhook URL could not be obtained and no webhook endpoints are subscribed to \
+             the event type; skipping outgoing webhooks for event"
         );
         return Ok(());
     }
 
+    let mut result = Ok(());
+    for webhook_endpoint in webhook_targets {
+        let webhook_endpoint_id = webhook_endpoint
+            .as_ref()
+            .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone());
+
+        if let Err(error) = Box::pin(create_event_and_trigger_webhook_to_target(
+            state.clone(),
+            &merchant_account,
+            business_profile.clone(),
+            merchant_key_store,
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            webhook_endpoint,
+        ))
+        .await
+        {
+            logger::error!(
+                ?error,
+                ?webhook_endpoint_id,
+                "Failed to trigger outgoing webhook for event"
+            );
+            result = Err(error);
+        }
+    }
+
+    result
+}
+
+/// Obtains the targets of the webhook for an event, `None` standing for the webhook URL configured
+/// in the business profile. The webhook URL configured in the business profile receives all
+/// events, while the webhook endpoints only receive the events they are subscribed to.
+fn get_webhook_targets(
+    is_profile_webhook_url_configured: bool,
+    webhook_endpoints: Vec<storage::WebhookEndpoint>,
+    event_type: enums::EventType,
+) -> Vec<Option<storage::WebhookEndpoint>> {
+    is_profile_webhook_url_configured
+        .then_some(None)
+        .into_iter()
+        .chain(
+            webhook_endpoints
+                .into_iter()
+                .filter(|webhook_endpoint| webhook_endpoint.is_subscribed_to(event_type))
+                .map(Some),
+        )
+        .collect()
+}
+
+/// Creates the event for a single webhook target, and triggers the webhook in the background.
+/// The target is either a webhook endpoint, or the webhook URL configured in the business profile
+/// when `webhook_endpoint` is `None`.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_webhook_to_target(
+    state: SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: domain::Profile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    webhook_endpoint: Option<storage::WebhookEndpoint>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let webhook_endpoint_id = webhook_endpoint
+        .as_ref()
+        .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone());
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_type,
+        delivery_attempt,
+        webhook_endpoint_id.as_deref(),
+    );
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -91,10 +198,14 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_account, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let request_content = get_outgoing_webhook_request(
+        merchant_account,
+        outgoing_webhook,
+        &business_profile,
+        webhook_endpoint.as_ref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -133,6 +244,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         response: None,
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
+        webhook_endpoint_id,
     };
 
     let event_insert_result = state
@@ -244,7 +356,7 @@ async fn trigger_webhook_to_merchant(
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+        get_webhook_url(&state, &business_profile, &event).await,
         process_tracker.clone(),
     ) {
         (Ok(webhook_url), _) => Ok(webhook_url),
@@ -580,6 +692,50 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
+/// Obtains the URL to which the webhook for the event has to be sent, which is the URL of the
+/// webhook endpoint the event was created for, or the webhook URL configured in the business
+/// profile otherwise.
+async fn get_webhook_url(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    event: &domain::Event,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let Some(webhook_endpoint_id) = event.webhook_endpoint_id.as_deref() else {
+        return get_webhook_url_from_business_profile(business_profile);
+    };
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(
+            &business_profile.merchant_id,
+            webhook_endpoint_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointNotFound)
+        .attach_printable_lazy(|| {
+            format!("Failed to find webhook endpoint with ID `{webhook_endpoint_id}`")
+        })?;
+
+    get_webhook_endpoint_url(webhook_endpoint)
+}
+
+/// Obtains the URL of a webhook endpoint, refusing to deliver webhooks to disabled endpoints.
+fn get_webhook_endpoint_url(
+    webhook_endpoint: storage::WebhookEndpoint,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    if !webhook_endpoint.is_enabled {
+        return Err(report!(errors::WebhooksFlowError::WebhookEndpointDisabled))
+            .attach_printable_lazy(|| {
+                format!(
+                    "Webhook endpoint with ID `{}` is disabled",
+                    webhook_endpoint.endpoint_id
+                )
+            });
+    }
+
+    Ok(webhook_endpoint.url.expose())
+}
+
 fn get_webhook_url_from_business_profile(
     business_profile: &domain::Profile,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
@@ -600,11 +756,13 @@ pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    webhook_endpoint: Option<&storage::WebhookEndpoint>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        webhook_endpoint: Option<&storage::WebhookEndpoint>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![(
             reqwest::header::CONTENT_TYPE.to_string(),
@@ -612,7 +770,10 @@ pub(crate) fn get_outgoing_webhook_request(
         )];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+        // Webhooks sent to webhook endpoints are signed using the secret of the endpoint
+        let payment_response_hash_key = webhook_endpoint
+            .map(|webhook_endpoint| webhook_endpoint.secret.peek().clone())
+            .or_else(|| business_profile.payment_response_hash_key.clone());
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -649,12 +810,17 @@ pub(crate) fn get_outgoing_webhook_request(
 
     match merchant_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint,
         ),
     }
 }
@@ -928,6 +1094,11 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription_response) => {
+                Self::Subscription {
+                    subscription_id: subscription_response.subscription_id.clone(),
+                }
+            }
         }
     }
 }
@@ -972,5 +1143,126 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::Subscription { subscription_id } => {
+            OutgoingWebhookEventContent::Subscription {
+                subscription_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_webhook_endpoint(
+        endpoint_id: &str,
+        enabled_events: Vec<enums::EventType>,
+        is_enabled: bool,
+    ) -> storage::WebhookEndpoint {
+        let now = common_utils::date_time::now();
+        storage::WebhookEndpoint {
+            endpoint_id: endpoint_id.to_string(),
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_1",
+            ))
+            .unwrap(),
+            profile_id: common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1"))
+                .unwrap(),
+            url: Secret::new(format!("https://example.com/webhooks/{endpoint_id}")),
+            description: None,
+            secret: Secret::new("secret".to_string()),
+            enabled_events,
+            is_enabled,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    fn get_target_endpoint_ids(targets: &[Option<storage::WebhookEndpoint>]) -> Vec<Option<&str>> {
+        targets
+            .iter()
+            .map(|target| {
+                target
+                    .as_ref()
+                    .map(|webhook_endpoint| webhook_endpoint.endpoint_id.as_str())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_webhook_endpoints_only_receive_subscribed_events() {
+        let webhook_endpoints = || {
+            vec![
+                get_webhook_endpoint("whe_1", vec![enums::EventType::PaymentSucceeded], true),
+                get_webhook_endpoint(
+                    "whe_2",
+                    vec![
+                        enums::EventType::PaymentFailed,
+                        enums::EventType::RefundSucceeded,
+                    ],
+                    true,
+                ),
+            ]
+        };
+
+        let targets = get_webhook_targets(
+            false,
+            webhook_endpoints(),
+            enums::EventType::PaymentSucceeded,
+        );
+        assert_eq!(get_target_endpoint_ids(&targets), vec![Some("whe_1")]);
+
+        let targets = get_webhook_targets(
+            false,
+            webhook_endpoints(),
+            enums::EventType::RefundSucceeded,
+        );
+        assert_eq!(get_target_endpoint_ids(&targets), vec![Some("whe_2")]);
+
+        let targets =
+            get_webhook_targets(false, webhook_endpoints(), enums::EventType::DisputeOpened);
+        assert!(targets.is_empty());
+
+        // The webhook URL configured in the business profile receives every event
+        let targets =
+            get_webhook_targets(true, webhook_endpoints(), enums::EventType::DisputeOpened);
+        assert_eq!(get_target_endpoint_ids(&targets), vec![None]);
+    }
+
+    #[test]
+    fn test_disabled_webhook_endpoints_are_skipped() {
+        let webhook_endpoints = vec![
+            get_webhook_endpoint("whe_1", vec![enums::EventType::PaymentSucceeded], false),
+            get_webhook_endpoint("whe_2", vec![enums::EventType::PaymentSucceeded], true),
+        ];
+
+        let targets =
+            get_webhook_targets(true, webhook_endpoints, enums::EventType::PaymentSucceeded);
+        assert_eq!(get_target_endpoint_ids(&targets), vec![None, Some("whe_2")]);
+
+        // Retries of events created for an endpoint disabled since are not delivered either
+        let error = get_webhook_endpoint_url(get_webhook_endpoint(
+            "whe_1",
+            vec![enums::EventType::PaymentSucceeded],
+            false,
+        ))
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::WebhooksFlowError::WebhookEndpointDisabled
+        ));
+
+        assert_eq!(
+            get_webhook_endpoint_url(get_webhook_endpoint(
+                "whe_2",
+                vec![enums::EventType::PaymentSucceeded],
+                true,
+            ))
+            .unwrap(),
+            "https://example.com/webhooks/whe_2"
+        );
+    }
+}
@@ -6,6 +6,17 @@ use serde::Serialize;
 
 use crate::{core::errors, headers, services::request::Maskable, types::storage::enums};
 
+/// The method, URI, query parameters and headers of an incoming webhook request. These are stored
+/// (encrypted) along with the request body, so that the webhook can be replayed later.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct IncomingWebhookRequestMetadata {
+    pub method: String,
+    pub uri: String,
+    pub query_params: String,
+    /// Header values as raw bytes, since they are not guaranteed to be valid UTF-8
+    pub headers: Vec<(String, Vec<u8>)>,
+}
+
 pub struct OutgoingWebhookPayloadWithSignature {
     pub payload: Secret<String>,
     pub signature: Option<String>,
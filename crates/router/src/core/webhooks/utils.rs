@@ -131,12 +131,18 @@ pub(crate) fn get_idempotent_event_id(
     primary_object_id: &str,
     event_type: types::storage::enums::EventType,
     delivery_attempt: types::storage::enums::WebhookDeliveryAttempt,
+    webhook_endpoint_id: Option<&str>,
 ) -> String {
     use crate::types::storage::enums::WebhookDeliveryAttempt;
 
     const EVENT_ID_SUFFIX_LENGTH: usize = 8;
 
-    let common_prefix = format!("{primary_object_id}_{event_type}");
+    let common_prefix = match webhook_endpoint_id {
+        Some(webhook_endpoint_id) => {
+            format!("{primary_object_id}_{event_type}_{webhook_endpoint_id}")
+        }
+        None => format!("{primary_object_id}_{event_type}"),
+    };
     match delivery_attempt {
         WebhookDeliveryAttempt::InitialAttempt => common_prefix,
         WebhookDeliveryAttempt::AutomaticRetry | WebhookDeliveryAttempt::ManualRetry => {
@@ -149,3 +155,147 @@ pub(crate) fn get_idempotent_event_id(
 pub(crate) fn generate_event_id() -> String {
     common_utils::generate_time_ordered_id("evt")
 }
+
+/// Builds the event for retrying the delivery of `event_to_retry`. The retry is delivered to the
+/// same target as the event being retried, be it a webhook endpoint or the webhook URL configured
+/// in the business profile.
+pub(crate) fn get_retry_event(
+    event_to_retry: domain::Event,
+    initial_attempt_id: Option<String>,
+    merchant_id: common_utils::id_type::MerchantId,
+    business_profile_id: common_utils::id_type::ProfileId,
+    delivery_attempt: types::storage::enums::WebhookDeliveryAttempt,
+) -> domain::Event {
+    let idempotent_event_id = get_idempotent_event_id(
+        &event_to_retry.primary_object_id,
+        event_to_retry.event_type,
+        delivery_attempt,
+        event_to_retry.webhook_endpoint_id.as_deref(),
+    );
+
+    domain::Event {
+        event_id: generate_event_id(),
+        event_type: event_to_retry.event_type,
+        event_class: event_to_retry.event_class,
+        is_webhook_notified: false,
+        primary_object_id: event_to_retry.primary_object_id,
+        primary_object_type: event_to_retry.primary_object_type,
+        created_at: common_utils::date_time::now(),
+        merchant_id: Some(merchant_id),
+        business_profile_id: Some(business_profile_id),
+        primary_object_created_at: event_to_retry.primary_object_created_at,
+        idempotent_event_id: Some(idempotent_event_id),
+        initial_attempt_id,
+        request: event_to_retry.request,
+        response: None,
+        delivery_attempt: Some(delivery_attempt),
+        metadata: event_to_retry.metadata,
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::types::storage::enums;
+
+    fn get_event(webhook_endpoint_id: Option<&str>) -> domain::Event {
+        let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+        let event_id = generate_event_id();
+        domain::Event {
+            event_id: event_id.clone(),
+            event_type: enums::EventType::PaymentSucceeded,
+            event_class: enums::EventClass::Payments,
+            is_webhook_notified: false,
+            primary_object_id: "pay_1".to_string(),
+            primary_object_type: enums::EventObjectType::PaymentDetails,
+            created_at: common_utils::date_time::now(),
+            merchant_id: None,
+            business_profile_id: None,
+            primary_object_created_at: None,
+            idempotent_event_id: Some(get_idempotent_event_id(
+                "pay_1",
+                enums::EventType::PaymentSucceeded,
+                delivery_attempt,
+                webhook_endpoint_id,
+            )),
+            initial_attempt_id: Some(event_id),
+            request: None,
+            response: None,
+            delivery_attempt: Some(delivery_attempt),
+            metadata: None,
+            webhook_endpoint_id: webhook_endpoint_id.map(str::to_string),
+        }
+    }
+
+    fn get_retry_event_of(
+        event: domain::Event,
+        delivery_attempt: enums::WebhookDeliveryAttempt,
+    ) -> domain::Event {
+        let initial_attempt_id = event.initial_attempt_id.clone();
+        get_retry_event(
+            event,
+            initial_attempt_id,
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap(),
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap(),
+            delivery_attempt,
+        )
+    }
+
+    #[test]
+    fn test_retries_stay_on_their_original_webhook_endpoint() {
+        for delivery_attempt in [
+            enums::WebhookDeliveryAttempt::AutomaticRetry,
+            enums::WebhookDeliveryAttempt::ManualRetry,
+        ] {
+            let event = get_event(Some("whe_1"));
+            let initial_idempotent_event_id = event.idempotent_event_id.clone().unwrap();
+            let retry_event = get_retry_event_of(event, delivery_attempt);
+
+            assert_eq!(retry_event.webhook_endpoint_id.as_deref(), Some("whe_1"));
+            assert_eq!(retry_event.delivery_attempt, Some(delivery_attempt));
+            // Retries for different endpoints do not share idempotent event IDs
+            assert!(retry_event
+                .idempotent_event_id
+                .unwrap()
+                .starts_with(&initial_idempotent_event_id));
+        }
+    }
+
+    #[test]
+    fn test_retries_of_profile_webhooks_stay_on_the_profile_webhook_url() {
+        let retry_event = get_retry_event_of(
+            get_event(None),
+            enums::WebhookDeliveryAttempt::AutomaticRetry,
+        );
+
+        assert_eq!(retry_event.webhook_endpoint_id, None);
+        assert!(retry_event
+            .idempotent_event_id
+            .unwrap()
+            .starts_with("pay_1_"));
+    }
+
+    #[test]
+    fn test_idempotent_event_ids_differ_between_webhook_endpoints() {
+        let idempotent_event_id = |webhook_endpoint_id| {
+            get_idempotent_event_id(
+                "pay_1",
+                enums::EventType::PaymentSucceeded,
+                enums::WebhookDeliveryAttempt::InitialAttempt,
+                webhook_endpoint_id,
+            )
+        };
+
+        assert_ne!(
+            idempotent_event_id(Some("whe_1")),
+            idempotent_event_id(Some("whe_2"))
+        );
+        assert_ne!(
+            idempotent_event_id(Some("whe_1")),
+            idempotent_event_id(None)
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use api_models::webhook_endpoints as webhook_endpoint_api;
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use masking::Secret;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage, transformers::ForeignFrom},
+    utils,
+};
+
+const MAX_WEBHOOK_ENDPOINTS_PER_PROFILE: usize = 16;
+
+#[instrument(skip_all)]
+pub async fn create_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: webhook_endpoint_api::WebhookEndpointCreateRequest,
+) -> RouterResponse<webhook_endpoint_api::WebhookEndpointResponse> {
+    let db = state.store.as_ref();
+    let business_profile =
+        validate_and_get_business_profile(&state, &key_store, &profile_id).await?;
+    validate_enabled_events(&request.enabled_events)?;
+
+    let existing_webhook_endpoints = db
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &business_profile.merchant_id,
+            business_profile.get_id(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints")?;
+    if existing_webhook_endpoints.len() >= MAX_WEBHOOK_ENDPOINTS_PER_PROFILE {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "A business profile can have at most {MAX_WEBHOOK_ENDPOINTS_PER_PROFILE} webhook endpoints"
+            ),
+        }));
+    }
+
+    let now = common_utils::date_time::now();
+    let webhook_endpoint_new = storage::WebhookEndpointNew {
+        endpoint_id: utils::generate_id(consts::ID_LENGTH, "whe"),
+        merchant_id: business_profile.merchant_id.clone(),
+        profile_id: business_profile.get_id().to_owned(),
+        url: Secret::new(request.url.to_string()),
+        description: request.description,
+        secret: request.secret.unwrap_or_else(|| {
+            Secret::new(common_utils::crypto::generate_cryptographically_secure_random_string(64))
+        }),
+        enabled_events: request.enabled_events,
+        is_enabled: request.is_enabled.unwrap_or(true),
+        created_at: now,
+        modified_at: now,
+    };
+
+    let webhook_endpoint = db
+        .insert_webhook_endpoint(webhook_endpoint_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert webhook endpoint")?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_api::WebhookEndpointResponse::foreign_from(webhook_endpoint),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_webhook_endpoints(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<Vec<webhook_endpoint_api::WebhookEndpointResponse>> {
+    let business_profile =
+        validate_and_get_business_profile(&state, &key_store, &profile_id).await?;
+
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &business_profile.merchant_id,
+            business_profile.get_id(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints")?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints
+            .into_iter()
+            .map(webhook_endpoint_api::WebhookEndpointResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: webhook_endpoint_api::WebhookEndpointId,
+) -> RouterResponse<webhook_endpoint_api::WebhookEndpointResponse> {
+    let webhook_endpoint =
+        find_webhook_endpoint(&state, &key_store, &profile_id, &request.endpoint_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_api::WebhookEndpointResponse::foreign_from(webhook_endpoint),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: webhook_endpoint_api::WebhookEndpointUpdateRequest,
+) -> RouterResponse<webhook_endpoint_api::WebhookEndpointResponse> {
+    let webhook_endpoint =
+        find_webhook_endpoint(&state, &key_store, &profile_id, &request.endpoint_id).await?;
+    if let Some(enabled_events) = request.enabled_events.as_ref() {
+        validate_enabled_events(enabled_events)?;
+    }
+
+    let webhook_endpoint_update = storage::WebhookEndpointUpdate {
+        url: request.url.map(|url| Secret::new(url.to_string())),
+        description: request.description,
+        secret: request.secret,
+        enabled_events: request.enabled_events,
+        is_enabled: request.is_enabled,
+    };
+
+    let updated_webhook_endpoint = state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            &webhook_endpoint.merchant_id,
+            &webhook_endpoint.endpoint_id,
+            webhook_endpoint_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookEndpointNotFound {
+            endpoint_id: webhook_endpoint.endpoint_id.clone(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_api::WebhookEndpointResponse::foreign_from(updated_webhook_endpoint),
+    ))
+}
+
+/// Deletes the webhook endpoint. Events which have already been created for the endpoint are
+/// retained, but their deliveries are no longer retried.
+#[instrument(skip_all)]
+pub async fn delete_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: webhook_endpoint_api::WebhookEndpointId,
+) -> RouterResponse<webhook_endpoint_api::WebhookEndpointResponse> {
+    let webhook_endpoint =
+        find_webhook_endpoint(&state, &key_store, &profile_id, &request.endpoint_id).await?;
+
+    let deleted_webhook_endpoint = state
+        .store
+        .delete_webhook_endpoint_by_merchant_id_endpoint_id(
+            &webhook_endpoint.merchant_id,
+            &webhook_endpoint.endpoint_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookEndpointNotFound {
+            endpoint_id: webhook_endpoint.endpoint_id.clone(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_api::WebhookEndpointResponse::foreign_from(deleted_webhook_endpoint),
+    ))
+}
+
+async fn validate_and_get_business_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            &key_store.merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
+
+async fn find_webhook_endpoint(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    endpoint_id: &str,
+) -> RouterResult<storage::WebhookEndpoint> {
+    let business_profile = validate_and_get_business_profile(state, key_store, profile_id).await?;
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(
+            &business_profile.merchant_id,
+            endpoint_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookEndpointNotFound {
+            endpoint_id: endpoint_id.to_owned(),
+        })?;
+
+    // Webhook endpoints of other profiles of the merchant are not accessible
+    if webhook_endpoint.profile_id != *business_profile.get_id() {
+        return Err(report!(errors::ApiErrorResponse::WebhookEndpointNotFound {
+            endpoint_id: endpoint_id.to_owned(),
+        }));
+    }
+
+    Ok(webhook_endpoint)
+}
+
+fn validate_enabled_events(enabled_events: &[storage::enums::EventType]) -> RouterResult<()> {
+    if enabled_events.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`enabled_events` must contain at least one event type".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_webhook_endpoints_must_subscribe_to_an_event_type() {
+        assert!(validate_enabled_events(&[]).is_err());
+        assert!(validate_enabled_events(&[storage::enums::EventType::PaymentSucceeded]).is_ok());
+    }
+}
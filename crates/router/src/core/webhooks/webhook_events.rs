@@ -191,32 +191,15 @@ pub async fn retry_delivery_attempt(
         .attach_printable("Failed to find business profile")?;
 
     let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
-    let new_event_id = super::utils::generate_event_id();
-    let idempotent_event_id = super::utils::get_idempotent_event_id(
-        &event_to_retry.primary_object_id,
-        event_to_retry.event_type,
+    let initial_attempt_id = event_to_retry.initial_attempt_id.clone();
+    let new_event = super::utils::get_retry_event(
+        event_to_retry,
+        initial_attempt_id,
+        business_profile.merchant_id.clone(),
+        business_profile.get_id().to_owned(),
         delivery_attempt,
     );
-
-    let now = common_utils::date_time::now();
-    let new_event = domain::Event {
-        event_id: new_event_id.clone(),
-        event_type: event_to_retry.event_type,
-        event_class: event_to_retry.event_class,
-        is_webhook_notified: false,
-        primary_object_id: event_to_retry.primary_object_id,
-        primary_object_type: event_to_retry.primary_object_type,
-        created_at: now,
-        merchant_id: Some(business_profile.merchant_id.clone()),
-        business_profile_id: Some(business_profile.get_id().to_owned()),
-        primary_object_created_at: event_to_retry.primary_object_created_at,
-        idempotent_event_id: Some(idempotent_event_id),
-        initial_attempt_id: event_to_retry.initial_attempt_id,
-        request: event_to_retry.request,
-        response: None,
-        delivery_attempt: Some(delivery_attempt),
-        metadata: event_to_retry.metadata,
-    };
+    let new_event_id = new_event.event_id.clone();
 
     let event = store
         .insert_event(key_manager_state, new_event, &key_store)
@@ -9,6 +9,7 @@ pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_fee_schedule;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -16,9 +17,11 @@ pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod fx_rate_snapshot;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
+pub mod incoming_webhook_event;
 pub mod kafka_store;
 pub mod locker_mock_up;
 pub mod mandate;
@@ -28,15 +31,20 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod plan;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
+pub mod routing_activation_history;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
+use common_enums::enums::MerchantStorageScheme;
 use common_utils::id_type;
 use diesel_models::{
     fraud_check::{FraudCheck, FraudCheckUpdate},
@@ -89,12 +97,14 @@ pub trait StorageInterface:
     + api_keys::ApiKeyInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
+    + connector_fee_schedule::ConnectorFeeScheduleInterface
     + capture::CaptureInterface
     + customers::CustomerInterface
     + dashboard_metadata::DashboardMetadataInterface
     + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
+    + incoming_webhook_event::IncomingWebhookEventInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
     + locker_mock_up::LockerMockUpInterface
@@ -116,10 +126,16 @@ pub trait StorageInterface:
     + merchant_key_store::MerchantKeyStoreInterface
     + MasterKeyInterface
     + payment_link::PaymentLinkInterface
+    + plan::PlanInterface
+    + subscription::SubscriptionInterface
+    + webhook_endpoint::WebhookEndpointInterface
+    + fx_rate_snapshot::FxRateSnapshotInterface
     + RedisConnInterface
     + RequestIdStore
+    + SoftKillModeStore
     + business_profile::ProfileInterface
     + OrganizationInterface
+    + routing_activation_history::RoutingActivationHistoryInterface
     + routing_algorithm::RoutingAlgorithmInterface
     + gsm::GsmInterface
     + unified_translations::UnifiedTranslationsInterface
@@ -239,6 +255,23 @@ impl RequestIdStore for Store {
     }
 }
 
+/// Stores which can stop the KV writes of a request, for merchants moving from KV to Postgres
+pub trait SoftKillModeStore {
+    fn enable_soft_kill_mode(&mut self) {}
+}
+
+impl SoftKillModeStore for MockDb {}
+
+#[cfg(feature = "kv_store")]
+impl SoftKillModeStore for Store {
+    fn enable_soft_kill_mode(&mut self) {
+        Self::enable_soft_kill_mode(self)
+    }
+}
+
+#[cfg(not(feature = "kv_store"))]
+impl SoftKillModeStore for Store {}
+
 pub async fn get_and_deserialize_key<T>(
     db: &dyn StorageInterface,
     key: &str,
@@ -264,13 +297,23 @@ impl RequestIdStore for KafkaStore {
     }
 }
 
+impl SoftKillModeStore for KafkaStore {
+    fn enable_soft_kill_mode(&mut self) {
+        SoftKillModeStore::enable_soft_kill_mode(&mut self.diesel_store)
+    }
+}
+
 #[async_trait::async_trait]
 impl FraudCheckInterface for KafkaStore {
     async fn insert_fraud_check_response(
         &self,
         new: storage::FraudCheckNew,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, StorageError> {
-        let frm = self.diesel_store.insert_fraud_check_response(new).await?;
+        let frm = self
+            .diesel_store
+            .insert_fraud_check_response(new, storage_scheme)
+            .await?;
         if let Err(er) = self
             .kafka_producer
             .log_fraud_check(&frm, None, self.tenant_id.clone())
@@ -284,10 +327,11 @@ impl FraudCheckInterface for KafkaStore {
         &self,
         this: FraudCheck,
         fraud_check: FraudCheckUpdate,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, StorageError> {
         let frm = self
             .diesel_store
-            .update_fraud_check_response_with_attempt_id(this, fraud_check)
+            .update_fraud_check_response_with_attempt_id(this, fraud_check, storage_scheme)
             .await?;
         if let Err(er) = self
             .kafka_producer
@@ -302,10 +346,11 @@ impl FraudCheckInterface for KafkaStore {
         &self,
         payment_id: id_type::PaymentId,
         merchant_id: id_type::MerchantId,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, StorageError> {
         let frm = self
             .diesel_store
-            .find_fraud_check_by_payment_id(payment_id, merchant_id)
+            .find_fraud_check_by_payment_id(payment_id, merchant_id, storage_scheme)
             .await?;
         if let Err(er) = self
             .kafka_producer
@@ -320,10 +365,11 @@ impl FraudCheckInterface for KafkaStore {
         &self,
         payment_id: id_type::PaymentId,
         merchant_id: id_type::MerchantId,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Option<FraudCheck>, StorageError> {
         let frm = self
             .diesel_store
-            .find_fraud_check_by_payment_id_if_present(payment_id, merchant_id)
+            .find_fraud_check_by_payment_id_if_present(payment_id, merchant_id, storage_scheme)
             .await?;
 
         if let Some(fraud_check) = frm.clone() {
@@ -231,6 +231,9 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            permissions: api_key.permissions,
+            profile_id: api_key.profile_id,
+            ip_allowlist: api_key.ip_allowlist,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -256,6 +259,8 @@ impl ApiKeyInterface for MockDb {
                 description,
                 expires_at,
                 last_used,
+                permissions,
+                ip_allowlist,
             } => {
                 if let Some(name) = name {
                     key_to_update.name = name;
@@ -270,6 +275,12 @@ impl ApiKeyInterface for MockDb {
                 if last_used.is_some() {
                     key_to_update.last_used = last_used
                 }
+                if let Some(permissions) = permissions {
+                    key_to_update.permissions = permissions;
+                }
+                if let Some(ip_allowlist) = ip_allowlist {
+                    key_to_update.ip_allowlist = ip_allowlist;
+                }
             }
             storage::ApiKeyUpdate::LastUsedUpdate { last_used } => {
                 key_to_update.last_used = Some(last_used);
@@ -408,6 +419,9 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                permissions: None,
+                profile_id: None,
+                ip_allowlist: None,
             })
             .await
             .unwrap();
@@ -423,6 +437,9 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                permissions: None,
+                profile_id: None,
+                ip_allowlist: None,
             })
             .await
             .unwrap();
@@ -505,6 +522,9 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            permissions: None,
+            profile_id: None,
+            ip_allowlist: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
@@ -32,15 +32,22 @@ pub trait CaptureInterface {
 
 #[cfg(feature = "kv_store")]
 mod storage {
-    use error_stack::report;
+    use common_utils::ext_traits::Encode;
+    use diesel_models::kv;
+    use error_stack::{report, ResultExt};
+    use redis_interface::HsetnxReply;
     use router_env::{instrument, tracing};
+    use storage_impl::redis::kv_store::{
+        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+    };
 
     use super::CaptureInterface;
     use crate::{
         connection,
-        core::errors::{self, CustomResult},
+        core::errors::{self, utils::RedisErrorExt, CustomResult},
         services::Store,
         types::storage::{capture::*, enums},
+        utils::db_utils,
     };
 
     #[async_trait::async_trait]
@@ -49,16 +56,58 @@ mod storage {
         async fn insert_capture(
             &self,
             capture: CaptureNew,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Capture, errors::StorageError> {
-            let db_call = || async {
-                let conn = connection::pg_connection_write(self).await?;
-                capture
-                    .insert(&conn)
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, Capture>(
+                self,
+                storage_scheme,
+                Op::Insert,
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    capture
+                        .insert(&conn)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let merchant_id = capture.merchant_id.clone();
+                    let payment_id = capture.payment_id.clone();
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id: &merchant_id,
+                        payment_id: &payment_id,
+                    };
+                    let key_str = key.to_string();
+                    let created_capture = Capture::from(&capture);
+                    let field = format!("capture_{}", created_capture.capture_id);
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::Capture(capture),
+                        },
+                    };
+
+                    match kv_wrapper::<Capture, _, _>(
+                        self,
+                        KvOperation::<Capture>::HSetNx(&field, &created_capture, redis_entry),
+                        key,
+                    )
                     .await
-                    .map_err(|error| report!(errors::StorageError::from(error)))
-            };
-            db_call().await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "capture",
+                            key: Some(created_capture.capture_id),
+                        }
+                        .into()),
+                        Ok(HsetnxReply::KeySet) => Ok(created_capture),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
         }
 
         #[instrument(skip_all)]
@@ -66,15 +115,61 @@ mod storage {
             &self,
             this: Capture,
             capture: CaptureUpdate,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Capture, errors::StorageError> {
-            let db_call = || async {
-                let conn = connection::pg_connection_write(self).await?;
-                this.update_with_capture_id(&conn, capture)
-                    .await
-                    .map_err(|error| report!(errors::StorageError::from(error)))
+            let merchant_id = this.merchant_id.clone();
+            let payment_id = this.payment_id.clone();
+            let key = PartitionKey::MerchantIdPaymentId {
+                merchant_id: &merchant_id,
+                payment_id: &payment_id,
             };
-            db_call().await
+            let field = format!("capture_{}", this.capture_id);
+            // Captures do not record the storage scheme they were written with, the storage
+            // scheme of the merchant is used in its place
+            let updated_by = storage_scheme.to_string();
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, Capture>(
+                self,
+                storage_scheme,
+                Op::Update(key.clone(), &field, Some(&updated_by)),
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    this.update_with_capture_id(&conn, capture)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key_str = key.to_string();
+                    let updated_capture = capture.clone().apply_changeset(this.clone());
+
+                    let redis_value = updated_capture
+                        .encode_to_string_of_json()
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::CaptureUpdate(kv::CaptureUpdateMems {
+                                orig: this,
+                                update_data: capture,
+                            }),
+                        },
+                    };
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::Hset::<Capture>((&field, redis_value), redis_entry),
+                        key,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_capture)
+                }
+            }
         }
 
         #[instrument(skip_all)]
@@ -83,9 +178,9 @@ mod storage {
             merchant_id: &common_utils::id_type::MerchantId,
             payment_id: &common_utils::id_type::PaymentId,
             authorized_attempt_id: &str,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<Capture>, errors::StorageError> {
-            let db_call = || async {
+            let database_call = || async {
                 let conn = connection::pg_connection_read(self).await?;
                 Capture::find_all_by_merchant_id_payment_id_authorized_attempt_id(
                     merchant_id,
@@ -96,7 +191,48 @@ mod storage {
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
             };
-            db_call().await
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, Capture>(
+                self,
+                storage_scheme,
+                Op::Find,
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id,
+                        payment_id,
+                    };
+
+                    let redis_fut = async {
+                        kv_wrapper::<Capture, _, _>(
+                            self,
+                            KvOperation::<Capture>::Scan("capture_*"),
+                            key,
+                        )
+                        .await?
+                        .try_into_scan()
+                        .map(|captures| {
+                            captures
+                                .into_iter()
+                                .filter(|capture| {
+                                    capture.authorized_attempt_id == authorized_attempt_id
+                                })
+                                .collect()
+                        })
+                    };
+
+                    let mut captures = Box::pin(db_utils::find_all_combined_kv_database(
+                        redis_fut,
+                        database_call,
+                        None,
+                    ))
+                    .await?;
+                    captures.sort_by_key(|capture| capture.created_at);
+                    Ok(captures)
+                }
+            }
         }
     }
 }
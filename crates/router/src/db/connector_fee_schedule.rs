@@ -0,0 +1,228 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorFeeScheduleInterface {
+    async fn insert_connector_fee_schedule(
+        &self,
+        connector_fee_schedule: storage::ConnectorFeeScheduleNew,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError>;
+
+    async fn find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError>;
+
+    async fn list_connector_fee_schedules_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::ConnectorFeeSchedule>, errors::StorageError>;
+
+    async fn update_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        connector_fee_schedule_update: storage::ConnectorFeeScheduleUpdate,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError>;
+
+    async fn delete_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorFeeScheduleInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_connector_fee_schedule(
+        &self,
+        connector_fee_schedule: storage::ConnectorFeeScheduleNew,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        connector_fee_schedule
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorFeeSchedule::find_by_merchant_id_merchant_connector_id(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_connector_fee_schedules_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::ConnectorFeeSchedule>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorFeeSchedule::list_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        connector_fee_schedule_update: storage::ConnectorFeeScheduleUpdate,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorFeeSchedule::update_by_merchant_id_merchant_connector_id(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+            connector_fee_schedule_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorFeeSchedule::delete_by_merchant_id_merchant_connector_id(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorFeeScheduleInterface for MockDb {
+    async fn insert_connector_fee_schedule(
+        &self,
+        _connector_fee_schedule: storage::ConnectorFeeScheduleNew,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_connector_fee_schedules_by_profile_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::ConnectorFeeSchedule>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        _connector_fee_schedule_update: storage::ConnectorFeeScheduleUpdate,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorFeeScheduleInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_connector_fee_schedule(
+        &self,
+        connector_fee_schedule: storage::ConnectorFeeScheduleNew,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        self.diesel_store
+            .insert_connector_fee_schedule(connector_fee_schedule)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        self.diesel_store
+            .find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+                merchant_id,
+                merchant_connector_id,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_connector_fee_schedules_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::ConnectorFeeSchedule>, errors::StorageError> {
+        self.diesel_store
+            .list_connector_fee_schedules_by_profile_id(profile_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        connector_fee_schedule_update: storage::ConnectorFeeScheduleUpdate,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        self.diesel_store
+            .update_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+                merchant_id,
+                merchant_connector_id,
+                connector_fee_schedule_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        self.diesel_store
+            .delete_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+                merchant_id,
+                merchant_connector_id,
+            )
+            .await
+    }
+}
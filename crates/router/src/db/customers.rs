@@ -43,6 +43,7 @@ where
         &self,
         customer_id: &id_type::CustomerId,
         merchant_id: &id_type::MerchantId,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<bool, errors::StorageError>;
 
     #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -166,7 +167,7 @@ mod storage {
         connection,
         core::{
             customers::REDACTED,
-            errors::{self, CustomResult},
+            errors::{self, utils::RedisErrorExt, CustomResult},
         },
         services::Store,
         types::{
@@ -219,14 +220,22 @@ mod storage {
                     Box::pin(db_utils::try_redis_get_else_try_database_get(
                         // check for ValueNotFound
                         async {
-                            kv_wrapper(
+                            match kv_wrapper(
                                 self,
                                 KvOperation::<diesel_models::Customer>::HGet(&field),
                                 key,
                             )
-                            .await?
-                            .try_into_hget()
-                            .map(Some)
+                            .await
+                            {
+                                // The customer has been deleted, but not yet from the database
+                                Err(error)
+                                    if *error.current_context()
+                                        == errors::RedisError::ValueDeleted =>
+                                {
+                                    Ok(None)
+                                }
+                                result => result?.try_into_hget().map(Some),
+                            }
                         },
                         database_call,
                     ))
@@ -293,14 +302,22 @@ mod storage {
                     Box::pin(db_utils::try_redis_get_else_try_database_get(
                         // check for ValueNotFound
                         async {
-                            kv_wrapper(
+                            match kv_wrapper(
                                 self,
                                 KvOperation::<diesel_models::Customer>::HGet(&field),
                                 key,
                             )
-                            .await?
-                            .try_into_hget()
-                            .map(Some)
+                            .await
+                            {
+                                // The customer has been deleted, but not yet from the database
+                                Err(error)
+                                    if *error.current_context()
+                                        == errors::RedisError::ValueDeleted =>
+                                {
+                                    Ok(None)
+                                }
+                                result => result?.try_into_hget().map(Some),
+                            }
                         },
                         database_call,
                     ))
@@ -814,15 +831,76 @@ mod storage {
             &self,
             customer_id: &id_type::CustomerId,
             merchant_id: &id_type::MerchantId,
+            storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<bool, errors::StorageError> {
             let conn = connection::pg_connection_write(self).await?;
-            storage_types::Customer::delete_by_customer_id_merchant_id(
-                &conn,
-                customer_id,
+            let database_call = || async {
+                storage_types::Customer::delete_by_customer_id_merchant_id(
+                    &conn,
+                    customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let key = PartitionKey::MerchantIdCustomerId {
                 merchant_id,
-            )
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
+                customer_id,
+            };
+            let field = format!("cust_{}", customer_id.get_string_repr());
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, diesel_models::Customer>(
+                self,
+                storage_scheme,
+                Op::Delete(key.clone(), &field),
+            ))
+            .await;
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let key_str = key.to_string();
+
+                    // Customers which are no longer in Redis are deleted from the database
+                    let customer = match kv_wrapper::<diesel_models::Customer, _, _>(
+                        self,
+                        KvOperation::<diesel_models::Customer>::HGet(&field),
+                        key.clone(),
+                    )
+                    .await
+                    {
+                        Ok(result) => result
+                            .try_into_hget()
+                            .change_context(errors::StorageError::KVError)?,
+                        Err(error) if *error.current_context() == errors::RedisError::NotFound => {
+                            return database_call().await;
+                        }
+                        Err(error) => return Err(error.to_redis_failed_response(&key_str)),
+                    };
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Delete {
+                            deletable: kv::Deletable::Customer(kv::CustomerDeleteMems {
+                                orig: customer.clone(),
+                            }),
+                        },
+                    };
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::<diesel_models::Customer>::Delete(
+                            &field,
+                            &customer,
+                            redis_entry,
+                        ),
+                        key,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_delete()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(true)
+                }
+            }
         }
 
         #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -1264,6 +1342,7 @@ mod storage {
             &self,
             customer_id: &id_type::CustomerId,
             merchant_id: &id_type::MerchantId,
+            _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<bool, errors::StorageError> {
             let conn = connection::pg_connection_write(self).await?;
             storage_types::Customer::delete_by_customer_id_merchant_id(
@@ -1512,6 +1591,7 @@ impl CustomerInterface for MockDb {
         &self,
         _customer_id: &id_type::CustomerId,
         _merchant_id: &id_type::MerchantId,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<bool, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
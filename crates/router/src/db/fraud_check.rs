@@ -1,87 +1,342 @@
-use diesel_models::fraud_check::{self as storage, FraudCheck, FraudCheckUpdate};
-use error_stack::report;
-use router_env::{instrument, tracing};
+use diesel_models::fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate};
 
 use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
-    services::Store,
+    types::storage::enums::MerchantStorageScheme,
 };
 
 #[async_trait::async_trait]
 pub trait FraudCheckInterface {
     async fn insert_fraud_check_response(
         &self,
-        new: storage::FraudCheckNew,
+        new: FraudCheckNew,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, errors::StorageError>;
 
     async fn update_fraud_check_response_with_attempt_id(
         &self,
         this: FraudCheck,
         fraud_check: FraudCheckUpdate,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, errors::StorageError>;
 
     async fn find_fraud_check_by_payment_id(
         &self,
         payment_id: common_utils::id_type::PaymentId,
         merchant_id: common_utils::id_type::MerchantId,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, errors::StorageError>;
 
     async fn find_fraud_check_by_payment_id_if_present(
         &self,
         payment_id: common_utils::id_type::PaymentId,
         merchant_id: common_utils::id_type::MerchantId,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Option<FraudCheck>, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl FraudCheckInterface for Store {
-    #[instrument(skip_all)]
-    async fn insert_fraud_check_response(
-        &self,
-        new: storage::FraudCheckNew,
-    ) -> CustomResult<FraudCheck, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        new.insert(&conn)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
-    }
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::ext_traits::Encode;
+    use diesel_models::{
+        fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate},
+        kv,
+    };
+    use error_stack::{report, ResultExt};
+    use redis_interface::HsetnxReply;
+    use router_env::{instrument, tracing};
+    use storage_impl::redis::kv_store::{
+        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+    };
 
-    #[instrument(skip_all)]
-    async fn update_fraud_check_response_with_attempt_id(
-        &self,
-        this: FraudCheck,
-        fraud_check: FraudCheckUpdate,
-    ) -> CustomResult<FraudCheck, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        this.update_with_attempt_id(&conn, fraud_check)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
-    }
+    use super::FraudCheckInterface;
+    use crate::{
+        connection,
+        core::errors::{self, utils::RedisErrorExt, CustomResult},
+        services::Store,
+        types::storage::enums::MerchantStorageScheme,
+        utils::db_utils,
+    };
 
-    #[instrument(skip_all)]
-    async fn find_fraud_check_by_payment_id(
-        &self,
-        payment_id: common_utils::id_type::PaymentId,
-        merchant_id: common_utils::id_type::MerchantId,
-    ) -> CustomResult<FraudCheck, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        FraudCheck::get_with_payment_id(&conn, payment_id, merchant_id)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
+    #[async_trait::async_trait]
+    impl FraudCheckInterface for Store {
+        #[instrument(skip_all)]
+        async fn insert_fraud_check_response(
+            &self,
+            new: FraudCheckNew,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<FraudCheck, errors::StorageError> {
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, FraudCheck>(
+                self,
+                storage_scheme,
+                Op::Insert,
+            ))
+            .await;
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    new.insert(&conn)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                MerchantStorageScheme::RedisKv => {
+                    let merchant_id = new.merchant_id.clone();
+                    let payment_id = new.payment_id.clone();
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id: &merchant_id,
+                        payment_id: &payment_id,
+                    };
+                    let key_str = key.to_string();
+                    let created_fraud_check = FraudCheck::from(&new);
+                    let field = format!("frm_{}", payment_id.get_string_repr());
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::FraudCheck(new),
+                        },
+                    };
+
+                    match kv_wrapper::<FraudCheck, _, _>(
+                        self,
+                        KvOperation::<FraudCheck>::HSetNx(
+                            &field,
+                            &created_fraud_check,
+                            redis_entry,
+                        ),
+                        key,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "fraud_check",
+                            key: Some(created_fraud_check.frm_id),
+                        }
+                        .into()),
+                        Ok(HsetnxReply::KeySet) => Ok(created_fraud_check),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn update_fraud_check_response_with_attempt_id(
+            &self,
+            this: FraudCheck,
+            fraud_check: FraudCheckUpdate,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<FraudCheck, errors::StorageError> {
+            let merchant_id = this.merchant_id.clone();
+            let payment_id = this.payment_id.clone();
+            let key = PartitionKey::MerchantIdPaymentId {
+                merchant_id: &merchant_id,
+                payment_id: &payment_id,
+            };
+            let field = format!("frm_{}", payment_id.get_string_repr());
+            // Without an `updated_by` on the fraud check, the storage scheme of the merchant
+            // decides whether the fraud check may still be in Redis
+            let updated_by = storage_scheme.to_string();
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, FraudCheck>(
+                self,
+                storage_scheme,
+                Op::Update(key.clone(), &field, Some(&updated_by)),
+            ))
+            .await;
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    this.update_with_attempt_id(&conn, fraud_check)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                MerchantStorageScheme::RedisKv => {
+                    let key_str = key.to_string();
+                    let updated_fraud_check = fraud_check.clone().apply_changeset(this.clone());
+
+                    let redis_value = updated_fraud_check
+                        .encode_to_string_of_json()
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::FraudCheckUpdate(kv::FraudCheckUpdateMems {
+                                orig: this,
+                                update_data: fraud_check,
+                            }),
+                        },
+                    };
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::Hset::<FraudCheck>((&field, redis_value), redis_entry),
+                        key,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_fraud_check)
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn find_fraud_check_by_payment_id(
+            &self,
+            payment_id: common_utils::id_type::PaymentId,
+            merchant_id: common_utils::id_type::MerchantId,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<FraudCheck, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_write(self).await?;
+                FraudCheck::get_with_payment_id(&conn, payment_id.clone(), merchant_id.clone())
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, FraudCheck>(
+                self,
+                storage_scheme,
+                Op::Find,
+            ))
+            .await;
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id: &merchant_id,
+                        payment_id: &payment_id,
+                    };
+                    let field = format!("frm_{}", payment_id.get_string_repr());
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(self, KvOperation::<FraudCheck>::HGet(&field), key)
+                                .await?
+                                .try_into_hget()
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn find_fraud_check_by_payment_id_if_present(
+            &self,
+            payment_id: common_utils::id_type::PaymentId,
+            merchant_id: common_utils::id_type::MerchantId,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Option<FraudCheck>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_write(self).await?;
+                FraudCheck::get_with_payment_id_if_present(
+                    &conn,
+                    payment_id.clone(),
+                    merchant_id.clone(),
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, FraudCheck>(
+                self,
+                storage_scheme,
+                Op::Find,
+            ))
+            .await;
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id: &merchant_id,
+                        payment_id: &payment_id,
+                    };
+                    let field = format!("frm_{}", payment_id.get_string_repr());
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(self, KvOperation::<FraudCheck>::HGet(&field), key)
+                                .await?
+                                .try_into_hget()
+                                .map(Some)
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
     }
+}
 
-    #[instrument(skip_all)]
-    async fn find_fraud_check_by_payment_id_if_present(
-        &self,
-        payment_id: common_utils::id_type::PaymentId,
-        merchant_id: common_utils::id_type::MerchantId,
-    ) -> CustomResult<Option<FraudCheck>, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        FraudCheck::get_with_payment_id_if_present(&conn, payment_id, merchant_id)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use diesel_models::fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate};
+    use error_stack::report;
+    use router_env::{instrument, tracing};
+
+    use super::FraudCheckInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::enums::MerchantStorageScheme,
+    };
+
+    #[async_trait::async_trait]
+    impl FraudCheckInterface for Store {
+        #[instrument(skip_all)]
+        async fn insert_fraud_check_response(
+            &self,
+            new: FraudCheckNew,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<FraudCheck, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            new.insert(&conn)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn update_fraud_check_response_with_attempt_id(
+            &self,
+            this: FraudCheck,
+            fraud_check: FraudCheckUpdate,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<FraudCheck, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            this.update_with_attempt_id(&conn, fraud_check)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_fraud_check_by_payment_id(
+            &self,
+            payment_id: common_utils::id_type::PaymentId,
+            merchant_id: common_utils::id_type::MerchantId,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<FraudCheck, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            FraudCheck::get_with_payment_id(&conn, payment_id, merchant_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_fraud_check_by_payment_id_if_present(
+            &self,
+            payment_id: common_utils::id_type::PaymentId,
+            merchant_id: common_utils::id_type::MerchantId,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Option<FraudCheck>, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            FraudCheck::get_with_payment_id_if_present(&conn, payment_id, merchant_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -89,7 +344,8 @@ impl FraudCheckInterface for Store {
 impl FraudCheckInterface for MockDb {
     async fn insert_fraud_check_response(
         &self,
-        _new: storage::FraudCheckNew,
+        _new: FraudCheckNew,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
@@ -97,6 +353,7 @@ impl FraudCheckInterface for MockDb {
         &self,
         _this: FraudCheck,
         _fraud_check: FraudCheckUpdate,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
@@ -104,6 +361,7 @@ impl FraudCheckInterface for MockDb {
         &self,
         _payment_id: common_utils::id_type::PaymentId,
         _merchant_id: common_utils::id_type::MerchantId,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
@@ -112,6 +370,7 @@ impl FraudCheckInterface for MockDb {
         &self,
         _payment_id: common_utils::id_type::PaymentId,
         _merchant_id: common_utils::id_type::MerchantId,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Option<FraudCheck>, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
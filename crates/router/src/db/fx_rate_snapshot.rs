@@ -0,0 +1,90 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait FxRateSnapshotInterface {
+    async fn insert_fx_rate_snapshot(
+        &self,
+        fx_rate_snapshot: storage::FxRateSnapshotNew,
+    ) -> CustomResult<storage::FxRateSnapshot, errors::StorageError>;
+
+    async fn find_fx_rate_snapshot_by_snapshot_id(
+        &self,
+        snapshot_id: &str,
+    ) -> CustomResult<storage::FxRateSnapshot, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl FxRateSnapshotInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_fx_rate_snapshot(
+        &self,
+        fx_rate_snapshot: storage::FxRateSnapshotNew,
+    ) -> CustomResult<storage::FxRateSnapshot, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        fx_rate_snapshot
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_fx_rate_snapshot_by_snapshot_id(
+        &self,
+        snapshot_id: &str,
+    ) -> CustomResult<storage::FxRateSnapshot, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::FxRateSnapshot::find_by_snapshot_id(&conn, snapshot_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl FxRateSnapshotInterface for MockDb {
+    async fn insert_fx_rate_snapshot(
+        &self,
+        _fx_rate_snapshot: storage::FxRateSnapshotNew,
+    ) -> CustomResult<storage::FxRateSnapshot, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_fx_rate_snapshot_by_snapshot_id(
+        &self,
+        _snapshot_id: &str,
+    ) -> CustomResult<storage::FxRateSnapshot, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl FxRateSnapshotInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_fx_rate_snapshot(
+        &self,
+        fx_rate_snapshot: storage::FxRateSnapshotNew,
+    ) -> CustomResult<storage::FxRateSnapshot, errors::StorageError> {
+        self.diesel_store
+            .insert_fx_rate_snapshot(fx_rate_snapshot)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_fx_rate_snapshot_by_snapshot_id(
+        &self,
+        snapshot_id: &str,
+    ) -> CustomResult<storage::FxRateSnapshot, errors::StorageError> {
+        self.diesel_store
+            .find_fx_rate_snapshot_by_snapshot_id(snapshot_id)
+            .await
+    }
+}
@@ -0,0 +1,355 @@
+use common_utils::types::keymanager::KeyManagerState;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage,
+    },
+};
+
+#[async_trait::async_trait]
+pub trait IncomingWebhookEventInterface
+where
+    domain::IncomingWebhookEvent: Conversion<
+        DstType = storage::IncomingWebhookEvent,
+        NewDstType = storage::IncomingWebhookEventNew,
+    >,
+{
+    async fn insert_incoming_webhook_event(
+        &self,
+        state: &KeyManagerState,
+        incoming_webhook_event: domain::IncomingWebhookEvent,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError>;
+
+    async fn find_incoming_webhook_event_by_merchant_id_webhook_event_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_event_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_incoming_webhook_events_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector_name_or_mca_id: Option<String>,
+        status: Option<storage::enums::IncomingWebhookEventStatus>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookEvent>, errors::StorageError>;
+
+    async fn update_incoming_webhook_event_by_merchant_id_webhook_event_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_event_id: &str,
+        incoming_webhook_event_update: storage::IncomingWebhookEventUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookEventInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_incoming_webhook_event(
+        &self,
+        state: &KeyManagerState,
+        incoming_webhook_event: domain::IncomingWebhookEvent,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        incoming_webhook_event
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_incoming_webhook_event_by_merchant_id_webhook_event_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_event_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookEvent::find_by_merchant_id_webhook_event_id(
+            &conn,
+            merchant_id,
+            webhook_event_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(
+            state,
+            merchant_key_store.key.get_inner(),
+            merchant_key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn list_incoming_webhook_events_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector_name_or_mca_id: Option<String>,
+        status: Option<storage::enums::IncomingWebhookEventStatus>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookEvent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let incoming_webhook_events =
+            storage::IncomingWebhookEvent::list_by_merchant_id_constraints(
+                &conn,
+                merchant_id,
+                connector_name_or_mca_id,
+                status,
+                created_after,
+                created_before,
+                limit,
+                offset,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+        let mut domain_incoming_webhook_events = Vec::with_capacity(incoming_webhook_events.len());
+        for incoming_webhook_event in incoming_webhook_events.into_iter() {
+            domain_incoming_webhook_events.push(
+                incoming_webhook_event
+                    .convert(
+                        state,
+                        merchant_key_store.key.get_inner(),
+                        merchant_key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+        Ok(domain_incoming_webhook_events)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_incoming_webhook_event_by_merchant_id_webhook_event_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_event_id: &str,
+        incoming_webhook_event_update: storage::IncomingWebhookEventUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::IncomingWebhookEvent::update_by_merchant_id_webhook_event_id(
+            &conn,
+            merchant_id,
+            webhook_event_id,
+            incoming_webhook_event_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(
+            state,
+            merchant_key_store.key.get_inner(),
+            merchant_key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookEventInterface for MockDb {
+    async fn insert_incoming_webhook_event(
+        &self,
+        state: &KeyManagerState,
+        incoming_webhook_event: domain::IncomingWebhookEvent,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError> {
+        let mut locked_incoming_webhook_events = self.incoming_webhook_events.lock().await;
+
+        let stored_incoming_webhook_event = Conversion::convert(incoming_webhook_event)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+
+        locked_incoming_webhook_events.push(stored_incoming_webhook_event.clone());
+
+        stored_incoming_webhook_event
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn find_incoming_webhook_event_by_merchant_id_webhook_event_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_event_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError> {
+        let incoming_webhook_event = self
+            .incoming_webhook_events
+            .lock()
+            .await
+            .iter()
+            .find(|incoming_webhook_event| {
+                incoming_webhook_event.merchant_id == *merchant_id
+                    && incoming_webhook_event.webhook_event_id == webhook_event_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No incoming webhook event available with merchant_id = {merchant_id:?} and webhook_event_id = {webhook_event_id}"
+                ))
+            })?;
+
+        incoming_webhook_event
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn list_incoming_webhook_events_by_merchant_id_constraints(
+        &self,
+        _state: &KeyManagerState,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _connector_name_or_mca_id: Option<String>,
+        _status: Option<storage::enums::IncomingWebhookEventStatus>,
+        _created_after: Option<time::PrimitiveDateTime>,
+        _created_before: Option<time::PrimitiveDateTime>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookEvent>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_incoming_webhook_event_by_merchant_id_webhook_event_id(
+        &self,
+        _state: &KeyManagerState,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _webhook_event_id: &str,
+        _incoming_webhook_event_update: storage::IncomingWebhookEventUpdate,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookEventInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_incoming_webhook_event(
+        &self,
+        state: &KeyManagerState,
+        incoming_webhook_event: domain::IncomingWebhookEvent,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError> {
+        self.diesel_store
+            .insert_incoming_webhook_event(state, incoming_webhook_event, merchant_key_store)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_incoming_webhook_event_by_merchant_id_webhook_event_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_event_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError> {
+        self.diesel_store
+            .find_incoming_webhook_event_by_merchant_id_webhook_event_id(
+                state,
+                merchant_id,
+                webhook_event_id,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_incoming_webhook_events_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector_name_or_mca_id: Option<String>,
+        status: Option<storage::enums::IncomingWebhookEventStatus>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookEvent>, errors::StorageError> {
+        self.diesel_store
+            .list_incoming_webhook_events_by_merchant_id_constraints(
+                state,
+                merchant_id,
+                connector_name_or_mca_id,
+                status,
+                created_after,
+                created_before,
+                limit,
+                offset,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_incoming_webhook_event_by_merchant_id_webhook_event_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_event_id: &str,
+        incoming_webhook_event_update: storage::IncomingWebhookEventUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookEvent, errors::StorageError> {
+        self.diesel_store
+            .update_incoming_webhook_event_by_merchant_id_webhook_event_id(
+                state,
+                merchant_id,
+                webhook_event_id,
+                incoming_webhook_event_update,
+                merchant_key_store,
+            )
+            .await
+    }
+}
@@ -354,9 +354,10 @@ impl CustomerInterface for KafkaStore {
         &self,
         customer_id: &id_type::CustomerId,
         merchant_id: &id_type::MerchantId,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<bool, errors::StorageError> {
         self.diesel_store
-            .delete_customer_by_customer_id_merchant_id(customer_id, merchant_id)
+            .delete_customer_by_customer_id_merchant_id(customer_id, merchant_id, storage_scheme)
             .await
     }
 
@@ -1985,6 +1986,7 @@ impl PaymentMethodInterface for KafkaStore {
         key_store: &domain::MerchantKeyStore,
         merchant_id: &id_type::MerchantId,
         payment_method_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::PaymentMethod, errors::StorageError> {
         self.diesel_store
             .delete_payment_method_by_merchant_id_payment_method_id(
@@ -1992,6 +1994,7 @@ impl PaymentMethodInterface for KafkaStore {
                 key_store,
                 merchant_id,
                 payment_method_id,
+                storage_scheme,
             )
             .await
     }
@@ -2283,6 +2286,15 @@ impl ProcessTrackerInterface for KafkaStore {
         self.diesel_store.find_process_by_id(id).await
     }
 
+    async fn find_unfinished_recurring_process_by_name(
+        &self,
+        name: &str,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_unfinished_recurring_process_by_name(name)
+            .await
+    }
+
     async fn update_process(
         &self,
         this: storage::ProcessTracker,
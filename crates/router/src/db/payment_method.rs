@@ -136,6 +136,7 @@ pub trait PaymentMethodInterface {
         key_store: &domain::MerchantKeyStore,
         merchant_id: &id_type::MerchantId,
         payment_method_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::PaymentMethod, errors::StorageError>;
 }
 
@@ -720,20 +721,23 @@ mod storage {
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
             let conn = connection::pg_connection_read(self).await?;
-            let database_call = || async {
-                storage_types::PaymentMethod::find_by_customer_id_merchant_id_status(
-                    &conn,
-                    customer_id,
-                    merchant_id,
-                    status,
-                    limit,
-                )
-                .await
-                .map_err(|error| report!(errors::StorageError::from(error)))
+            let database_call = |limit| {
+                let conn = &conn;
+                async move {
+                    storage_types::PaymentMethod::find_by_customer_id_merchant_id_status(
+                        conn,
+                        customer_id,
+                        merchant_id,
+                        status,
+                        limit,
+                    )
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))
+                }
             };
 
             let payment_methods = match storage_scheme {
-                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::PostgresOnly => database_call(limit).await,
                 MerchantStorageScheme::RedisKv => {
                     let key = PartitionKey::MerchantIdCustomerId {
                         merchant_id,
@@ -742,6 +746,16 @@ mod storage {
 
                     let pattern = "payment_method_id_*";
 
+                    let deleted_unique_keys = kv_wrapper::<storage_types::PaymentMethod, _, _>(
+                        self,
+                        KvOperation::<storage_types::PaymentMethod>::ScanDeleted(pattern),
+                        key.clone(),
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key.to_string()))?
+                    .try_into_scandeleted()
+                    .change_context(errors::StorageError::KVError)?;
+
                     let redis_fut = async {
                         let kv_result = kv_wrapper::<storage_types::PaymentMethod, _, _>(
                             self,
@@ -758,8 +772,9 @@ mod storage {
                         })
                     };
 
-                    Box::pin(db_utils::find_all_combined_kv_database(
+                    Box::pin(db_utils::find_all_combined_kv_database_without_deleted(
                         redis_fut,
+                        deleted_unique_keys,
                         database_call,
                         limit,
                     ))
@@ -795,22 +810,109 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             merchant_id: &id_type::MerchantId,
             payment_method_id: &str,
+            storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<domain::PaymentMethod, errors::StorageError> {
             let conn = connection::pg_connection_write(self).await?;
-            storage_types::PaymentMethod::delete_by_merchant_id_payment_method_id(
-                &conn,
-                merchant_id,
-                payment_method_id,
-            )
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))?
-            .convert(
-                state,
-                key_store.key.get_inner(),
-                key_store.merchant_id.clone().into(),
-            )
-            .await
-            .change_context(errors::StorageError::DecryptionError)
+            let database_call = || async {
+                storage_types::PaymentMethod::delete_by_merchant_id_payment_method_id(
+                    &conn,
+                    merchant_id,
+                    payment_method_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let delete_pm = || async {
+                match storage_scheme {
+                    MerchantStorageScheme::PostgresOnly => database_call().await,
+                    MerchantStorageScheme::RedisKv => {
+                        let lookup_id = format!("payment_method_{}", payment_method_id);
+                        let lookup = fallback_reverse_lookup_not_found!(
+                            self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                                .await,
+                            database_call().await
+                        );
+
+                        let key = PartitionKey::CombinationKey {
+                            combination: &lookup.pk_id,
+                        };
+                        let storage_scheme =
+                            Box::pin(decide_storage_scheme::<_, storage_types::PaymentMethod>(
+                                self,
+                                storage_scheme,
+                                Op::Delete(key.clone(), &lookup.sk_id),
+                            ))
+                            .await;
+                        if storage_scheme == MerchantStorageScheme::PostgresOnly {
+                            return database_call().await;
+                        }
+
+                        // Payment methods which are no longer in Redis are deleted from the
+                        // database
+                        let payment_method = match kv_wrapper::<storage_types::PaymentMethod, _, _>(
+                            self,
+                            KvOperation::<storage_types::PaymentMethod>::HGet(&lookup.sk_id),
+                            key.clone(),
+                        )
+                        .await
+                        {
+                            Ok(result) => result
+                                .try_into_hget()
+                                .change_context(errors::StorageError::KVError)?,
+                            Err(error)
+                                if *error.current_context() == errors::RedisError::NotFound =>
+                            {
+                                return database_call().await;
+                            }
+                            Err(error) => return Err(error.to_redis_failed_response(&lookup.pk_id)),
+                        };
+
+                        if payment_method.merchant_id != *merchant_id {
+                            return Err(errors::StorageError::ValueNotFound(format!(
+                                "Payment method does not exist for key {}",
+                                lookup.pk_id
+                            ))
+                            .into());
+                        }
+
+                        let redis_entry = kv::TypedSql {
+                            op: kv::DBOperation::Delete {
+                                deletable: kv::Deletable::PaymentMethod(
+                                    kv::PaymentMethodDeleteMems {
+                                        orig: payment_method.clone(),
+                                    },
+                                ),
+                            },
+                        };
+
+                        kv_wrapper::<(), _, _>(
+                            self,
+                            KvOperation::<storage_types::PaymentMethod>::Delete(
+                                &lookup.sk_id,
+                                &payment_method,
+                                redis_entry,
+                            ),
+                            key,
+                        )
+                        .await
+                        .map_err(|err| err.to_redis_failed_response(&lookup.pk_id))?
+                        .try_into_delete()
+                        .change_context(errors::StorageError::KVError)?;
+
+                        Ok(payment_method)
+                    }
+                }
+            };
+
+            delete_pm()
+                .await?
+                .convert(
+                    state,
+                    key_store.key.get_inner(),
+                    key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)
         }
 
         // Soft delete, Check if KV stuff is needed here
@@ -1176,6 +1278,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             merchant_id: &id_type::MerchantId,
             payment_method_id: &str,
+            _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<domain::PaymentMethod, errors::StorageError> {
             let conn = connection::pg_connection_write(self).await?;
             storage_types::PaymentMethod::delete_by_merchant_id_payment_method_id(
@@ -1492,6 +1595,7 @@ impl PaymentMethodInterface for MockDb {
         key_store: &domain::MerchantKeyStore,
         merchant_id: &id_type::MerchantId,
         payment_method_id: &str,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::PaymentMethod, errors::StorageError> {
         let mut payment_methods = self.payment_methods.lock().await;
         match payment_methods
@@ -0,0 +1,173 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PlanInterface {
+    async fn insert_plan(
+        &self,
+        plan: storage::PlanNew,
+    ) -> CustomResult<storage::Plan, errors::StorageError>;
+
+    async fn find_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::Plan, errors::StorageError>;
+
+    async fn list_plans_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Plan>, errors::StorageError>;
+
+    async fn update_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        plan_update: storage::PlanUpdate,
+    ) -> CustomResult<storage::Plan, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PlanInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_plan(
+        &self,
+        plan: storage::PlanNew,
+    ) -> CustomResult<storage::Plan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        plan.insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::Plan, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Plan::find_by_merchant_id_plan_id(&conn, merchant_id, plan_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_plans_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Plan>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Plan::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        plan_update: storage::PlanUpdate,
+    ) -> CustomResult<storage::Plan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Plan::update_by_merchant_id_plan_id(&conn, merchant_id, plan_id, plan_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PlanInterface for MockDb {
+    async fn insert_plan(
+        &self,
+        _plan: storage::PlanNew,
+    ) -> CustomResult<storage::Plan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_plan_by_merchant_id_plan_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _plan_id: &str,
+    ) -> CustomResult<storage::Plan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_plans_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::Plan>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_plan_by_merchant_id_plan_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _plan_id: &str,
+        _plan_update: storage::PlanUpdate,
+    ) -> CustomResult<storage::Plan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PlanInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_plan(
+        &self,
+        plan: storage::PlanNew,
+    ) -> CustomResult<storage::Plan, errors::StorageError> {
+        self.diesel_store.insert_plan(plan).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::Plan, errors::StorageError> {
+        self.diesel_store
+            .find_plan_by_merchant_id_plan_id(merchant_id, plan_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_plans_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Plan>, errors::StorageError> {
+        self.diesel_store
+            .list_plans_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        plan_update: storage::PlanUpdate,
+    ) -> CustomResult<storage::Plan, errors::StorageError> {
+        self.diesel_store
+            .update_plan_by_merchant_id_plan_id(merchant_id, plan_id, plan_update)
+            .await
+    }
+}
@@ -0,0 +1,177 @@
+use async_bb8_diesel::AsyncConnection;
+use common_utils::types::keymanager::KeyManagerState;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage,
+    },
+};
+
+#[async_trait::async_trait]
+pub trait RoutingActivationHistoryInterface {
+    async fn list_routing_activation_history_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        algorithm_for: &storage::enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RoutingActivationHistory>, errors::StorageError>;
+
+    /// Updates the profile and records the change made to its active routing algorithm in a
+    /// single transaction
+    async fn update_profile_with_routing_activation(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        current_state: domain::Profile,
+        profile_update: domain::ProfileUpdate,
+        routing_activation: storage::RoutingActivationHistoryNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingActivationHistory), errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RoutingActivationHistoryInterface for Store {
+    #[instrument(skip_all)]
+    async fn list_routing_activation_history_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        algorithm_for: &storage::enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RoutingActivationHistory>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RoutingActivationHistory::list_by_profile_id_algorithm_for(
+            &conn,
+            profile_id,
+            algorithm_for,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_profile_with_routing_activation(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        current_state: domain::Profile,
+        profile_update: domain::ProfileUpdate,
+        routing_activation: storage::RoutingActivationHistoryNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingActivationHistory), errors::StorageError>
+    {
+        let conn = connection::pg_connection_write(self).await?;
+        let current_state = Conversion::convert(current_state)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+
+        let (profile, routing_activation) = conn
+            .transaction_async(|conn| async move {
+                // Returning `DatabaseConnectionError` after logging the actual error, as the
+                // underlying error cannot be obtained from the `error_stack::Report`
+                let profile = current_state
+                    .update_by_profile_id(
+                        &conn,
+                        storage::ProfileUpdateInternal::from(profile_update),
+                    )
+                    .await
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to update the routing algorithm of profile");
+                        errors::StorageError::DatabaseConnectionError
+                    })?;
+                let routing_activation =
+                    routing_activation.insert(&conn).await.map_err(|error| {
+                        logger::error!(?error, "Failed to insert routing activation history");
+                        errors::StorageError::DatabaseConnectionError
+                    })?;
+
+                Ok::<_, errors::StorageError>((profile, routing_activation))
+            })
+            .await?;
+
+        let profile = profile
+            .convert(
+                key_manager_state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)?;
+
+        Ok((profile, routing_activation))
+    }
+}
+
+#[async_trait::async_trait]
+impl RoutingActivationHistoryInterface for MockDb {
+    async fn list_routing_activation_history_by_profile_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _algorithm_for: &storage::enums::TransactionType,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::RoutingActivationHistory>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_profile_with_routing_activation(
+        &self,
+        _key_manager_state: &KeyManagerState,
+        _merchant_key_store: &domain::MerchantKeyStore,
+        _current_state: domain::Profile,
+        _profile_update: domain::ProfileUpdate,
+        _routing_activation: storage::RoutingActivationHistoryNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingActivationHistory), errors::StorageError>
+    {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl RoutingActivationHistoryInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn list_routing_activation_history_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        algorithm_for: &storage::enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RoutingActivationHistory>, errors::StorageError> {
+        self.diesel_store
+            .list_routing_activation_history_by_profile_id(profile_id, algorithm_for, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_profile_with_routing_activation(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        current_state: domain::Profile,
+        profile_update: domain::ProfileUpdate,
+        routing_activation: storage::RoutingActivationHistoryNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingActivationHistory), errors::StorageError>
+    {
+        self.diesel_store
+            .update_profile_with_routing_activation(
+                key_manager_state,
+                merchant_key_store,
+                current_state,
+                profile_update,
+                routing_activation,
+            )
+            .await
+    }
+}
@@ -0,0 +1,232 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait SubscriptionInterface {
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscription_by_merchant_id_latest_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        latest_payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError>;
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::find_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_by_merchant_id_latest_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        latest_payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::find_by_merchant_id_latest_payment_id(
+            &conn,
+            merchant_id,
+            latest_payment_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::list_by_merchant_id(&conn, merchant_id, customer_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Subscription::update_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+            subscription_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for MockDb {
+    async fn insert_subscription(
+        &self,
+        _subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_by_merchant_id_latest_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _latest_payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: Option<&common_utils::id_type::CustomerId>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+        _subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store.insert_subscription(subscription).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_by_merchant_id_latest_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        latest_payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_by_merchant_id_latest_payment_id(merchant_id, latest_payment_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        self.diesel_store
+            .list_subscriptions_by_merchant_id(merchant_id, customer_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+                subscription_update,
+            )
+            .await
+    }
+}
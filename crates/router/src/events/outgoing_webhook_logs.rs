@@ -50,6 +50,10 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    Subscription {
+        subscription_id: String,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -87,6 +91,13 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::SubscriptionDetails(subscription_payload) => {
+                Some(OutgoingWebhookEventContent::Subscription {
+                    subscription_id: subscription_payload.subscription_id.clone(),
+                    content: masking::masked_serialize(&subscription_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
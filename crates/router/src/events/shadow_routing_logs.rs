@@ -0,0 +1,146 @@
+use api_models::routing::RoutableConnectorChoice;
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use super::EventType;
+use crate::services::kafka::KafkaMessage;
+
+/// A payment for which the shadow routing algorithm of the profile made a different decision from
+/// the active algorithm
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ShadowRoutingEvent {
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    payment_id: common_utils::id_type::PaymentId,
+    attempt_id: String,
+    shadow_algorithm_id: common_utils::id_type::RoutingId,
+    active_connector: Option<String>,
+    active_merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    active_rule_name: Option<String>,
+    shadow_connector: Option<String>,
+    shadow_merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    shadow_rule_name: Option<String>,
+    is_connector_divergent: bool,
+    is_rule_divergent: bool,
+    created_at_timestamp: i128,
+}
+
+impl ShadowRoutingEvent {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        merchant_id: common_utils::id_type::MerchantId,
+        profile_id: common_utils::id_type::ProfileId,
+        payment_id: common_utils::id_type::PaymentId,
+        attempt_id: String,
+        shadow_algorithm_id: common_utils::id_type::RoutingId,
+        active_connector: Option<RoutableConnectorChoice>,
+        active_rule_name: Option<String>,
+        shadow_connector: Option<RoutableConnectorChoice>,
+        shadow_rule_name: Option<String>,
+    ) -> Self {
+        let (active_connector, active_merchant_connector_id) =
+            split_connector_choice(active_connector);
+        let (shadow_connector, shadow_merchant_connector_id) =
+            split_connector_choice(shadow_connector);
+
+        Self {
+            merchant_id,
+            profile_id,
+            payment_id,
+            attempt_id,
+            shadow_algorithm_id,
+            is_connector_divergent: active_connector != shadow_connector
+                || active_merchant_connector_id != shadow_merchant_connector_id,
+            is_rule_divergent: active_rule_name != shadow_rule_name,
+            active_connector,
+            active_merchant_connector_id,
+            active_rule_name,
+            shadow_connector,
+            shadow_merchant_connector_id,
+            shadow_rule_name,
+            created_at_timestamp: OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000,
+        }
+    }
+
+    pub fn is_divergent(&self) -> bool {
+        self.is_connector_divergent || self.is_rule_divergent
+    }
+}
+
+fn split_connector_choice(
+    choice: Option<RoutableConnectorChoice>,
+) -> (
+    Option<String>,
+    Option<common_utils::id_type::MerchantConnectorAccountId>,
+) {
+    choice.map_or((None, None), |choice| {
+        (
+            Some(choice.connector.to_string()),
+            choice.merchant_connector_id,
+        )
+    })
+}
+
+impl KafkaMessage for ShadowRoutingEvent {
+    fn event_type(&self) -> EventType {
+        EventType::ShadowRoutingLogs
+    }
+
+    fn key(&self) -> String {
+        format!("{}_{}", self.merchant_id.get_string_repr(), self.attempt_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::{enums::RoutableConnectors, routing::RoutableChoiceKind};
+
+    use super::*;
+
+    fn get_shadow_routing_event(
+        active: (RoutableConnectors, Option<&str>),
+        shadow: (RoutableConnectors, Option<&str>),
+    ) -> ShadowRoutingEvent {
+        let get_choice = |connector| RoutableConnectorChoice {
+            choice_kind: RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: None,
+        };
+
+        ShadowRoutingEvent::new(
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap(),
+            common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("profile_1"))
+                .unwrap(),
+            common_utils::id_type::PaymentId::try_from(std::borrow::Cow::from("pay_1")).unwrap(),
+            "pay_1_1".to_string(),
+            common_utils::id_type::RoutingId::try_from(std::borrow::Cow::from("routing_1"))
+                .unwrap(),
+            Some(get_choice(active.0)),
+            active.1.map(ToOwned::to_owned),
+            Some(get_choice(shadow.0)),
+            shadow.1.map(ToOwned::to_owned),
+        )
+    }
+
+    #[test]
+    fn test_shadow_routing_event_divergence() {
+        assert!(!get_shadow_routing_event(
+            (RoutableConnectors::Stripe, Some("rule_1")),
+            (RoutableConnectors::Stripe, Some("rule_1")),
+        )
+        .is_divergent());
+        assert!(get_shadow_routing_event(
+            (RoutableConnectors::Stripe, Some("rule_1")),
+            (RoutableConnectors::Adyen, Some("rule_1")),
+        )
+        .is_divergent());
+        assert!(get_shadow_routing_event(
+            (RoutableConnectors::Stripe, Some("rule_1")),
+            (RoutableConnectors::Stripe, Some("rule_2")),
+        )
+        .is_divergent());
+    }
+}
@@ -86,6 +86,9 @@ pub mod headers {
     pub const X_APP_ID: &str = "x-app-id";
     pub const X_REDIRECT_URI: &str = "x-redirect-uri";
     pub const X_TENANT_ID: &str = "x-tenant-id";
+    pub const X_RATE_LIMIT_LIMIT: &str = "x-ratelimit-limit";
+    pub const X_RATE_LIMIT_REMAINING: &str = "x-ratelimit-remaining";
+    pub const X_RATE_LIMIT_RESET: &str = "x-ratelimit-reset";
 }
 
 pub mod pii {
@@ -137,7 +140,9 @@ pub fn mk_app(
         {
             server_app = server_app
                 .service(routes::Refunds::server(state.clone()))
-                .service(routes::Mandates::server(state.clone()));
+                .service(routes::Mandates::server(state.clone()))
+                .service(routes::Plans::server(state.clone()))
+                .service(routes::Subscriptions::server(state.clone()));
         }
     }
 
@@ -175,7 +180,8 @@ pub fn mk_app(
                 .service(routes::User::server(state.clone()))
                 .service(routes::ConnectorOnboarding::server(state.clone()))
                 .service(routes::Verify::server(state.clone()))
-                .service(routes::WebhookEvents::server(state.clone()));
+                .service(routes::WebhookEvents::server(state.clone()))
+                .service(routes::IncomingWebhookEvents::server(state.clone()));
         }
     }
 
@@ -217,19 +223,9 @@ pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> Applicatio
     logger::debug!(startup_config=?conf);
     let server = conf.server.clone();
     let (tx, rx) = oneshot::channel();
-    let api_client = Box::new(
-        services::ProxyClient::new(
-            conf.proxy.clone(),
-            services::proxy_bypass_urls(
-                conf.key_manager.get_inner(),
-                &conf.locker,
-                &conf.proxy.bypass_proxy_urls,
-            ),
-        )
-        .map_err(|error| {
-            errors::ApplicationError::ApiClientError(error.current_context().clone())
-        })?,
-    );
+    let api_client = services::create_api_client(&conf).map_err(|error| {
+        errors::ApplicationError::ApiClientError(error.current_context().clone())
+    })?;
     let state = Box::pin(AppState::new(conf, tx, api_client)).await;
     let request_body_limit = server.request_body_limit;
 
@@ -353,6 +349,7 @@ pub fn get_application_builder(
             errors::error_handlers::custom_error_handlers,
         ))
         .wrap(middleware::default_response_headers())
+        .wrap(middleware::RateLimitHeaders)
         .wrap(middleware::RequestId)
         .wrap(cors::cors(cors))
         // this middleware works only for Http1.1 requests
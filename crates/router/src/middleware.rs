@@ -5,7 +5,7 @@ use router_env::{
     tracing::{field::Empty, Instrument},
 };
 
-use crate::headers;
+use crate::{core::rate_limiter::RateLimitStatus, headers};
 /// Middleware to include request ID in response header.
 pub struct RequestId;
 
@@ -76,6 +76,94 @@ where
     }
 }
 
+/// Middleware to include the rate limit status of the request in the response headers, along with
+/// `Retry-After` for throttled requests.
+pub struct RateLimitHeaders;
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for RateLimitHeaders
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = RateLimitHeadersMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RateLimitHeadersMiddleware { service }))
+    }
+}
+
+pub struct RateLimitHeadersMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for RateLimitHeadersMiddleware<S>
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let response_fut = self.service.call(req);
+
+        Box::pin(
+            async move {
+                let mut response = response_fut.await?;
+                // The status is populated in the request extensions by `server_wrap`, only for
+                // the APIs which have a rate limit configured
+                let rate_limit_status = response
+                    .request()
+                    .extensions()
+                    .get::<RateLimitStatus>()
+                    .cloned();
+
+                if let Some(rate_limit_status) = rate_limit_status {
+                    let response_headers = response.headers_mut();
+                    response_headers.insert(
+                        http::header::HeaderName::from_static(headers::X_RATE_LIMIT_LIMIT),
+                        http::HeaderValue::from(rate_limit_status.limit),
+                    );
+                    response_headers.insert(
+                        http::header::HeaderName::from_static(headers::X_RATE_LIMIT_REMAINING),
+                        http::HeaderValue::from(rate_limit_status.remaining),
+                    );
+                    response_headers.insert(
+                        http::header::HeaderName::from_static(headers::X_RATE_LIMIT_RESET),
+                        http::HeaderValue::from(rate_limit_status.reset_after_seconds),
+                    );
+                    if rate_limit_status.is_throttled {
+                        response_headers.insert(
+                            http::header::RETRY_AFTER,
+                            http::HeaderValue::from(rate_limit_status.reset_after_seconds),
+                        );
+                    }
+                }
+
+                Ok(response)
+            }
+            .in_current_span(),
+        )
+    }
+}
+
 /// Middleware for attaching default response headers. Headers with the same key already set in a
 /// response will not be overwritten.
 pub fn default_response_headers() -> actix_web::middleware::DefaultHeaders {
@@ -395,3 +483,95 @@ where
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::{test, web, App, HttpMessage, HttpRequest, HttpResponse};
+
+    use super::*;
+
+    async fn get_response(
+        rate_limit_status: Option<RateLimitStatus>,
+    ) -> actix_web::dev::ServiceResponse {
+        let app = test::init_service(App::new().wrap(RateLimitHeaders).route(
+            "/",
+            web::get().to(move |req: HttpRequest| {
+                let rate_limit_status = rate_limit_status.clone();
+                async move {
+                    if let Some(rate_limit_status) = rate_limit_status {
+                        req.extensions_mut().insert(rate_limit_status);
+                    }
+                    HttpResponse::Ok().finish()
+                }
+            }),
+        ))
+        .await;
+
+        test::call_service(&app, test::TestRequest::get().uri("/").to_request()).await
+    }
+
+    #[actix_rt::test]
+    async fn test_rate_limit_headers_for_allowed_request() {
+        let response = get_response(Some(RateLimitStatus {
+            limit: 100,
+            remaining: 42,
+            reset_after_seconds: 15,
+            is_throttled: false,
+        }))
+        .await;
+        let response_headers = response.headers();
+
+        assert_eq!(
+            response_headers.get(headers::X_RATE_LIMIT_LIMIT).unwrap(),
+            "100"
+        );
+        assert_eq!(
+            response_headers
+                .get(headers::X_RATE_LIMIT_REMAINING)
+                .unwrap(),
+            "42"
+        );
+        assert_eq!(
+            response_headers.get(headers::X_RATE_LIMIT_RESET).unwrap(),
+            "15"
+        );
+        assert!(response_headers.get(http::header::RETRY_AFTER).is_none());
+    }
+
+    #[actix_rt::test]
+    async fn test_rate_limit_headers_for_throttled_request() {
+        let response = get_response(Some(RateLimitStatus {
+            limit: 100,
+            remaining: 0,
+            reset_after_seconds: 15,
+            is_throttled: true,
+        }))
+        .await;
+        let response_headers = response.headers();
+
+        assert_eq!(
+            response_headers
+                .get(headers::X_RATE_LIMIT_REMAINING)
+                .unwrap(),
+            "0"
+        );
+        assert_eq!(
+            response_headers.get(http::header::RETRY_AFTER).unwrap(),
+            "15"
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_rate_limit_headers_without_rate_limit() {
+        let response = get_response(None).await;
+        let response_headers = response.headers();
+
+        assert!(response_headers.get(headers::X_RATE_LIMIT_LIMIT).is_none());
+        assert!(response_headers
+            .get(headers::X_RATE_LIMIT_REMAINING)
+            .is_none());
+        assert!(response_headers.get(headers::X_RATE_LIMIT_RESET).is_none());
+        assert!(response_headers.get(http::header::RETRY_AFTER).is_none());
+    }
+}
@@ -22,6 +22,8 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod incoming_webhook_events;
 pub mod lock_utils;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
@@ -46,6 +48,8 @@ pub mod recon;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod subscriptions;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
@@ -55,6 +59,8 @@ pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
+#[cfg(all(feature = "olap", feature = "v1"))]
 pub mod webhook_events;
 #[cfg(feature = "v1")]
 pub mod webhooks;
@@ -72,9 +78,13 @@ pub use self::app::{
     Refunds, SessionState, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
+pub use self::app::{
+    Blocklist, IncomingWebhookEvents, Organization, Routing, Verify, WebhookEvents,
+};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+pub use self::app::{Plans, Subscriptions};
 #[cfg(all(
     feature = "stripe",
     any(feature = "v1", feature = "v2"),
@@ -2,7 +2,7 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use common_enums::EntityType;
 use router_env::{instrument, tracing, Flow};
 
-use super::app::AppState;
+use super::{app::AppState, lock_utils};
 use crate::{
     core::{admin::*, api_locking},
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -861,6 +861,44 @@ pub async fn merchant_account_kv_status(
     .await
 }
 
+/// Merchant Account - Migrate Storage Scheme
+///
+/// Migrate the Merchant Account between Postgres and KV
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantStorageSchemeMigration))]
+pub async fn merchant_account_kv_migrate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::admin::StorageSchemeMigrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantStorageSchemeMigration;
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = path.into_inner();
+
+    // Concurrent migration steps for the merchant could interleave their checks and switches
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payload.merchant_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| {
+            crate::core::storage_scheme_migration::migrate_storage_scheme(state, payload)
+        },
+        &auth::AdminApiAuth,
+        locking_action,
+    ))
+    .await
+}
+
 /// Merchant Account - KV Status
 ///
 /// Toggle KV mode for the Merchant Account
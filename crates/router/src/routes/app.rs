@@ -2,7 +2,9 @@ use std::{collections::HashMap, sync::Arc};
 
 use actix_web::{web, Scope};
 #[cfg(all(feature = "olap", feature = "v1"))]
-use api_models::routing::RoutingRetrieveQuery;
+use api_models::routing::{
+    FallbackRoutingKind, RoutingActivationHistoryQuery, RoutingRetrieveQuery,
+};
 #[cfg(feature = "olap")]
 use common_enums::TransactionType;
 #[cfg(feature = "partial-auth")]
@@ -58,8 +60,10 @@ use super::{
 use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_events};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, payments::*};
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::{incoming_webhook_events, webhook_endpoints};
 #[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
-use super::{mandates::*, refunds::*};
+use super::{mandates::*, refunds::*, subscriptions::*};
 #[cfg(feature = "olap")]
 pub use crate::analytics::opensearch::OpenSearchClient;
 #[cfg(feature = "olap")]
@@ -652,6 +656,10 @@ impl Forex {
             .service(
                 web::resource("/convert_from_minor").route(web::get().to(currency::convert_forex)),
             )
+            .service(
+                web::resource("/snapshots/{snapshot_id}")
+                    .route(web::get().to(currency::retrieve_forex_snapshot)),
+            )
     }
 }
 
@@ -721,6 +729,37 @@ impl Routing {
                     )
                 },
             )))
+            .service(
+                web::resource("/dsl").route(web::post().to(|state, req, payload| {
+                    routing::routing_create_dsl_config(
+                        state,
+                        req,
+                        payload,
+                        &TransactionType::Payment,
+                    )
+                })),
+            )
+            .service(web::resource("/simulate").route(web::post().to(routing::routing_simulate)))
+            .service(
+                web::resource("/explain_eligibility")
+                    .route(web::post().to(routing::routing_explain_eligibility)),
+            )
+            .service(web::resource("/history").route(web::get().to(
+                |state, req, query: web::Query<RoutingActivationHistoryQuery>| {
+                    routing::routing_retrieve_activation_history(
+                        state,
+                        req,
+                        query,
+                        &TransactionType::Payment,
+                    )
+                },
+            )))
+            .service(web::resource("/diff").route(web::get().to(routing::routing_diff_configs)))
+            .service(
+                web::resource("/rollback").route(web::post().to(|state, req, payload| {
+                    routing::routing_rollback_config(state, req, payload, &TransactionType::Payment)
+                })),
+            )
             .service(
                 web::resource("/default")
                     .route(web::get().to(|state, req| {
@@ -744,6 +783,20 @@ impl Routing {
                     routing::routing_unlink_config(state, req, payload, &TransactionType::Payment)
                 })),
             )
+            .service(
+                web::resource("/shadow/deactivate")
+                    .route(web::post().to(routing::routing_unlink_shadow_config)),
+            )
+            .service(web::resource("/refund/deactivate").route(web::post().to(
+                |state, req, payload| {
+                    routing::routing_unlink_fallback_config(
+                        state,
+                        req,
+                        payload,
+                        &FallbackRoutingKind::Refund,
+                    )
+                },
+            )))
             .service(
                 web::resource("/decision")
                     .route(web::put().to(routing::upsert_decision_manager_config))
@@ -858,6 +911,50 @@ impl Routing {
                         )
                     },
                 )))
+                .service(
+                    web::resource("/payouts/{algorithm_id}/retry/activate").route(web::post().to(
+                        |state, req, path| {
+                            routing::routing_link_fallback_config(
+                                state,
+                                req,
+                                path,
+                                &FallbackRoutingKind::PayoutRetry,
+                            )
+                        },
+                    )),
+                )
+                .service(
+                    web::resource("/payouts/retry/deactivate").route(web::post().to(
+                        |state, req, payload| {
+                            routing::routing_unlink_fallback_config(
+                                state,
+                                req,
+                                payload,
+                                &FallbackRoutingKind::PayoutRetry,
+                            )
+                        },
+                    )),
+                )
+                .service(web::resource("/payouts/history").route(web::get().to(
+                    |state, req, query: web::Query<RoutingActivationHistoryQuery>| {
+                        routing::routing_retrieve_activation_history(
+                            state,
+                            req,
+                            query,
+                            &TransactionType::Payout,
+                        )
+                    },
+                )))
+                .service(web::resource("/payouts/rollback").route(web::post().to(
+                    |state, req, payload| {
+                        routing::routing_rollback_config(
+                            state,
+                            req,
+                            payload,
+                            &TransactionType::Payout,
+                        )
+                    },
+                )))
                 .service(
                     web::resource("/payouts/default/profile/{profile_id}").route(web::post().to(
                         |state, req, path, payload| {
@@ -887,12 +984,32 @@ impl Routing {
                 web::resource("/{algorithm_id}")
                     .route(web::get().to(routing::routing_retrieve_config)),
             )
+            .service(
+                web::resource("/{algorithm_id}/dsl")
+                    .route(web::get().to(routing::routing_retrieve_dsl_config)),
+            )
             .service(
                 web::resource("/{algorithm_id}/activate").route(web::post().to(
                     |state, req, path| {
                         routing::routing_link_config(state, req, path, &TransactionType::Payment)
                     },
                 )),
+            )
+            .service(
+                web::resource("/{algorithm_id}/shadow/activate")
+                    .route(web::post().to(routing::routing_link_shadow_config)),
+            )
+            .service(
+                web::resource("/{algorithm_id}/refund/activate").route(web::post().to(
+                    |state, req, path| {
+                        routing::routing_link_fallback_config(
+                            state,
+                            req,
+                            path,
+                            &FallbackRoutingKind::Refund,
+                        )
+                    },
+                )),
             );
         route
     }
@@ -1265,6 +1382,10 @@ impl MerchantAccount {
                     .route(web::post().to(admin::merchant_account_toggle_kv))
                     .route(web::get().to(admin::merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/kv/migrate")
+                    .route(web::post().to(admin::merchant_account_kv_migrate)),
+            )
             .service(
                 web::resource("/transfer")
                     .route(web::post().to(admin::merchant_account_transfer_keys)),
@@ -1329,6 +1450,12 @@ impl MerchantConnectorAccount {
                         .route(web::get().to(connector_retrieve))
                         .route(web::post().to(connector_update))
                         .route(web::delete().to(connector_delete)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/{merchant_connector_id}/fee_schedule")
+                        .route(web::get().to(super::routing::connector_fee_schedule_retrieve))
+                        .route(web::put().to(super::routing::connector_fee_schedule_upsert))
+                        .route(web::delete().to(super::routing::connector_fee_schedule_delete)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -1379,6 +1506,45 @@ impl Mandates {
     }
 }
 
+pub struct Plans;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl Plans {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/plans")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(create_plan)))
+            .service(web::resource("/list").route(web::get().to(list_plans)))
+            .service(
+                web::resource("/{plan_id}")
+                    .route(web::get().to(retrieve_plan))
+                    .route(web::post().to(update_plan)),
+            )
+            .service(web::resource("/{plan_id}/deactivate").route(web::post().to(deactivate_plan)))
+    }
+}
+
+pub struct Subscriptions;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(create_subscription)))
+            .service(web::resource("/list").route(web::get().to(list_subscriptions)))
+            .service(
+                web::resource("/{subscription_id}")
+                    .route(web::get().to(retrieve_subscription))
+                    .route(web::post().to(update_subscription)),
+            )
+            .service(
+                web::resource("/{subscription_id}/cancel")
+                    .route(web::post().to(cancel_subscription)),
+            )
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(all(feature = "oltp", feature = "v1"))]
@@ -1528,6 +1694,9 @@ impl Disputes {
             .service(
                 web::resource("/{dispute_id}").route(web::get().to(disputes::retrieve_dispute)),
             )
+            .service(
+                web::resource("/{dispute_id}/sync").route(web::get().to(disputes::sync_dispute)),
+            )
     }
 }
 
@@ -1683,21 +1852,58 @@ impl Profile {
             .service(
                 web::scope("/{profile_id}")
                     .service(
-                        web::scope("/dynamic_routing").service(
-                            web::scope("/success_based")
-                                .service(
-                                    web::resource("/toggle").route(
+                        web::scope("/dynamic_routing")
+                            .service(
+                                web::scope("/success_based")
+                                    .service(web::resource("/toggle").route(
                                         web::post().to(routing::toggle_success_based_routing),
-                                    ),
-                                )
-                                .service(web::resource("/config/{algorithm_id}").route(
-                                    web::patch().to(|state, req, path, payload| {
-                                        routing::success_based_routing_update_configs(
-                                            state, req, path, payload,
-                                        )
-                                    }),
-                                )),
-                        ),
+                                    ))
+                                    .service(web::resource("/config/{algorithm_id}").route(
+                                        web::patch().to(|state, req, path, payload| {
+                                            routing::success_based_routing_update_configs(
+                                                state, req, path, payload,
+                                            )
+                                        }),
+                                    )),
+                            )
+                            .service(
+                                web::scope("/least_cost")
+                                    .service(
+                                        web::resource("/toggle").route(
+                                            web::post().to(routing::toggle_least_cost_routing),
+                                        ),
+                                    )
+                                    .service(web::resource("/config/{algorithm_id}").route(
+                                        web::patch().to(|state, req, path, payload| {
+                                            routing::least_cost_routing_update_configs(
+                                                state, req, path, payload,
+                                            )
+                                        }),
+                                    )),
+                            )
+                            .service(
+                                web::scope("/elimination")
+                                    .service(
+                                        web::resource("/toggle").route(
+                                            web::post().to(routing::toggle_elimination_routing),
+                                        ),
+                                    )
+                                    .service(web::resource("/config/{algorithm_id}").route(
+                                        web::patch().to(|state, req, path, payload| {
+                                            routing::elimination_routing_update_configs(
+                                                state, req, path, payload,
+                                            )
+                                        }),
+                                    ))
+                                    .service(
+                                        web::resource("/status").route(
+                                            web::get().to(routing::elimination_status_retrieve),
+                                        ),
+                                    )
+                                    .service(web::resource("/override").route(
+                                        web::post().to(routing::elimination_state_override),
+                                    )),
+                            ),
                     )
                     .service(
                         web::resource("")
@@ -1712,6 +1918,31 @@ impl Profile {
                     .service(
                         web::resource("/toggle_connector_agnostic_mit")
                             .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                    )
+                    .service(
+                        web::scope("/webhook_endpoints")
+                            .service(
+                                web::resource("")
+                                    .route(
+                                        web::post().to(webhook_endpoints::create_webhook_endpoint),
+                                    )
+                                    .route(
+                                        web::get().to(webhook_endpoints::list_webhook_endpoints),
+                                    ),
+                            )
+                            .service(
+                                web::resource("/{endpoint_id}")
+                                    .route(
+                                        web::get().to(webhook_endpoints::retrieve_webhook_endpoint),
+                                    )
+                                    .route(
+                                        web::post().to(webhook_endpoints::update_webhook_endpoint),
+                                    )
+                                    .route(
+                                        web::delete()
+                                            .to(webhook_endpoints::delete_webhook_endpoint),
+                                    ),
+                            ),
                     ),
             )
     }
@@ -2055,6 +2286,30 @@ impl ConnectorOnboarding {
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct IncomingWebhookEvents;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl IncomingWebhookEvents {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/incoming_webhook_events/{merchant_id}")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("")
+                    .route(web::get().to(incoming_webhook_events::list_incoming_webhook_events)),
+            )
+            .service(
+                web::scope("/{webhook_event_id}")
+                    .service(web::resource("").route(
+                        web::get().to(incoming_webhook_events::retrieve_incoming_webhook_event),
+                    ))
+                    .service(web::resource("replay").route(
+                        web::post().to(incoming_webhook_events::replay_incoming_webhook_event),
+                    )),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct WebhookEvents;
 
@@ -36,7 +36,10 @@ pub async fn add_entry_to_blocklist(
             blocklist::add_entry_to_blocklist(state, auth.merchant_account, body)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::MerchantAccountWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::MerchantAccountWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -75,7 +78,10 @@ pub async fn remove_entry_from_blocklist(
             blocklist::remove_entry_from_blocklist(state, auth.merchant_account, body)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::MerchantAccountWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::MerchantAccountWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -116,7 +122,10 @@ pub async fn list_blocked_payment_methods(
             blocklist::list_blocklist_entries(state, auth.merchant_account, query)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::MerchantAccountRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::MerchantAccountRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -157,7 +166,10 @@ pub async fn toggle_blocklist_guard(
             blocklist::toggle_blocklist_guard(state, auth.merchant_account, query)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::MerchantAccountWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::MerchantAccountWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -34,17 +34,20 @@ pub async fn convert_forex(
     let amount = params.amount;
     let to_currency = &params.to_currency;
     let from_currency = &params.from_currency;
+    let snapshot_id = &params.snapshot_id;
     Box::pin(api::server_wrap(
         flow,
         state.clone(),
         &req,
         (),
-        |state, _, _, _| {
+        |state, auth: auth::AuthenticationData, _, _| {
             currency::convert_forex(
                 state,
+                auth.merchant_account,
                 amount.get_amount_as_i64(),
                 to_currency.to_string(),
                 from_currency.to_string(),
+                snapshot_id.clone(),
             )
         },
         auth::auth_type(
@@ -56,3 +59,28 @@ pub async fn convert_forex(
     ))
     .await
 }
+
+pub async fn retrieve_forex_snapshot(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RetrieveForexSnapshot;
+    let snapshot_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _auth: auth::AuthenticationData, _, _| {
+            currency::retrieve_forex_snapshot(state, snapshot_id.clone())
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -25,7 +25,10 @@ pub async fn customers_create(
         json_payload.into_inner(),
         |state, auth, req, _| create_customer(state, auth.merchant_account, auth.key_store, req),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::CustomerWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::CustomerWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -142,7 +145,10 @@ pub async fn customers_list(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::CustomerRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::CustomerRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -181,7 +187,10 @@ pub async fn customers_update(
             )
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuthWithPermission {
+                permission: Permission::CustomerWrite,
+                minimum_entity_level: EntityType::Merchant,
+            },
             &auth::JWTAuth {
                 permission: Permission::CustomerWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -219,7 +228,10 @@ pub async fn customers_update(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::CustomerWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::CustomerWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -248,7 +260,10 @@ pub async fn customers_delete(
         payload,
         |state, auth, req, _| delete_customer(state, auth.merchant_account, req, auth.key_store),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::CustomerWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::CustomerWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -280,7 +295,10 @@ pub async fn customers_delete(
         payload,
         |state, auth, req, _| delete_customer(state, auth.merchant_account, req, auth.key_store),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::CustomerWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::CustomerWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -318,7 +336,10 @@ pub async fn get_customer_mandates(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::MandateRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::MandateRead,
                 minimum_entity_level: EntityType::Merchant,
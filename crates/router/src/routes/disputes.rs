@@ -48,7 +48,10 @@ pub async fn retrieve_dispute(
             disputes::retrieve_dispute(state, auth.merchant_account, auth.profile_id, req)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeRead,
                 minimum_entity_level: EntityType::Profile,
@@ -100,7 +103,10 @@ pub async fn retrieve_disputes_list(
             disputes::retrieve_disputes_list(state, auth.merchant_account, None, req)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -158,7 +164,10 @@ pub async fn retrieve_disputes_list_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeRead,
                 minimum_entity_level: EntityType::Profile,
@@ -191,7 +200,10 @@ pub async fn get_disputes_filters(state: web::Data<AppState>, req: HttpRequest)
         (),
         |state, auth, _, _| disputes::get_filters_for_disputes(state, auth.merchant_account, None),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -233,7 +245,10 @@ pub async fn get_disputes_filters_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeRead,
                 minimum_entity_level: EntityType::Profile,
@@ -285,7 +300,10 @@ pub async fn accept_dispute(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeWrite,
                 minimum_entity_level: EntityType::Profile,
@@ -296,6 +314,60 @@ pub async fn accept_dispute(
     ))
     .await
 }
+/// Disputes - Sync Dispute
+#[utoipa::path(
+    get,
+    path = "/disputes/{dispute_id}/sync",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute was synced successfully", body = DisputeResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Sync a Dispute",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesSync))]
+pub async fn sync_dispute(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DisputesSync;
+    let dispute_id = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        dispute_id,
+        |state, auth, req, _| {
+            disputes::sync_dispute(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::DisputeRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Disputes - Submit Dispute Evidence
 #[utoipa::path(
     post,
@@ -331,7 +403,10 @@ pub async fn submit_dispute_evidence(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeWrite,
                 minimum_entity_level: EntityType::Profile,
@@ -385,7 +460,10 @@ pub async fn attach_dispute_evidence(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeWrite,
                 minimum_entity_level: EntityType::Profile,
@@ -431,7 +509,10 @@ pub async fn retrieve_dispute_evidence(
             disputes::retrieve_dispute_evidence(state, auth.merchant_account, auth.profile_id, req)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeRead,
                 minimum_entity_level: EntityType::Profile,
@@ -472,7 +553,10 @@ pub async fn delete_dispute_evidence(
         json_payload.into_inner(),
         |state, auth, req, _| disputes::delete_evidence(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeWrite,
                 minimum_entity_level: EntityType::Profile,
@@ -502,7 +586,10 @@ pub async fn get_disputes_aggregate(
             disputes::get_aggregates_for_disputes(state, auth.merchant_account, None, req)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -537,7 +624,10 @@ pub async fn get_disputes_aggregate_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::DisputeRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::DisputeRead,
                 minimum_entity_level: EntityType::Profile,
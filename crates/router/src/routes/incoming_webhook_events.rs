@@ -0,0 +1,136 @@
+use actix_web::{web, HttpRequest, Responder};
+use common_enums::EntityType;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, webhooks::incoming_webhook_events},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::api::webhook_events::{
+        IncomingWebhookEventListConstraints, IncomingWebhookEventListRequestInternal,
+        IncomingWebhookEventRequestInternal,
+    },
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookEventList))]
+pub async fn list_incoming_webhook_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query: web::Query<IncomingWebhookEventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookEventList;
+    let merchant_id = path.into_inner();
+    let constraints = query.into_inner();
+
+    let request_internal = IncomingWebhookEventListRequestInternal {
+        merchant_id: merchant_id.clone(),
+        constraints,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            incoming_webhook_events::list_incoming_webhook_events(
+                state,
+                request_internal.merchant_id,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::WebhookEventRead,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookEventRetrieve))]
+pub async fn retrieve_incoming_webhook_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookEventRetrieve;
+    let (merchant_id, webhook_event_id) = path.into_inner();
+
+    let request_internal = IncomingWebhookEventRequestInternal {
+        merchant_id: merchant_id.clone(),
+        webhook_event_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            incoming_webhook_events::retrieve_incoming_webhook_event(
+                state,
+                request_internal.merchant_id,
+                request_internal.webhook_event_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::WebhookEventRead,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookEventReplay))]
+pub async fn replay_incoming_webhook_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookEventReplay;
+    let (merchant_id, webhook_event_id) = path.into_inner();
+
+    let request_internal = IncomingWebhookEventRequestInternal {
+        merchant_id: merchant_id.clone(),
+        webhook_event_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, req_state| {
+            incoming_webhook_events::replay_incoming_webhook_event(
+                state,
+                req_state,
+                request_internal.merchant_id,
+                request_internal.webhook_event_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::WebhookEventWrite,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,7 +1,8 @@
 use router_env::Flow;
 
-#[derive(Clone, Debug, strum::Display)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, strum::Display, serde::Deserialize)]
 #[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
 pub enum ApiIdentifier {
     Payments,
     Refunds,
@@ -14,6 +15,7 @@ pub enum ApiIdentifier {
     Ephemeral,
     Health,
     Mandates,
+    Subscriptions,
     PaymentMethods,
     PaymentMethodAuth,
     Payouts,
@@ -47,6 +49,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
+            | Flow::MerchantStorageSchemeMigration
             | Flow::MerchantAccountList => Self::MerchantAccount,
 
             Flow::OrganizationCreate | Flow::OrganizationRetrieve | Flow::OrganizationUpdate => {
@@ -54,9 +57,20 @@ impl From<Flow> for ApiIdentifier {
             }
 
             Flow::RoutingCreateConfig
+            | Flow::RoutingCreateDslConfig
             | Flow::RoutingLinkConfig
+            | Flow::RoutingLinkShadowConfig
             | Flow::RoutingUnlinkConfig
+            | Flow::RoutingUnlinkShadowConfig
+            | Flow::RoutingLinkFallbackConfig
+            | Flow::RoutingUnlinkFallbackConfig
             | Flow::RoutingRetrieveConfig
+            | Flow::RoutingRetrieveDslConfig
+            | Flow::RoutingSimulate
+            | Flow::RoutingExplainEligibility
+            | Flow::RoutingRetrieveActivationHistory
+            | Flow::RoutingDiffConfigs
+            | Flow::RoutingRollbackConfig
             | Flow::RoutingRetrieveActiveConfig
             | Flow::RoutingRetrieveDefaultConfig
             | Flow::RoutingRetrieveDictionary
@@ -67,9 +81,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DecisionManagerRetrieveConfig
             | Flow::ToggleDynamicRouting
             | Flow::UpdateDynamicRoutingConfigs
+            | Flow::EliminationStatusRetrieve
+            | Flow::EliminationStateOverride
             | Flow::DecisionManagerUpsertConfig => Self::Routing,
 
-            Flow::RetrieveForexFlow => Self::Forex,
+            Flow::RetrieveForexFlow | Flow::RetrieveForexSnapshot => Self::Forex,
 
             Flow::AddToBlocklist => Self::Blocklist,
             Flow::DeleteFromBlocklist => Self::Blocklist,
@@ -80,7 +96,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
-            | Flow::MerchantConnectorsList => Self::MerchantConnector,
+            | Flow::MerchantConnectorsList
+            | Flow::ConnectorFeeScheduleUpsert
+            | Flow::ConnectorFeeScheduleRetrieve
+            | Flow::ConnectorFeeScheduleDelete => Self::MerchantConnector,
 
             Flow::ConfigKeyCreate
             | Flow::ConfigKeyFetch
@@ -100,6 +119,17 @@ impl From<Flow> for ApiIdentifier {
             Flow::DeepHealthCheck | Flow::HealthCheck => Self::Health,
             Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
 
+            Flow::PlansCreate
+            | Flow::PlansRetrieve
+            | Flow::PlansList
+            | Flow::PlansUpdate
+            | Flow::PlansDeactivate
+            | Flow::SubscriptionsCreate
+            | Flow::SubscriptionsRetrieve
+            | Flow::SubscriptionsList
+            | Flow::SubscriptionsUpdate
+            | Flow::SubscriptionsCancel => Self::Subscriptions,
+
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
             | Flow::PaymentMethodsList
@@ -162,7 +192,15 @@ impl From<Flow> for ApiIdentifier {
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
+            | Flow::IncomingWebhookEventList
+            | Flow::IncomingWebhookEventRetrieve
+            | Flow::IncomingWebhookEventReplay => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
@@ -173,6 +211,7 @@ impl From<Flow> for ApiIdentifier {
             Flow::DisputesRetrieve
             | Flow::DisputesList
             | Flow::DisputesFilters
+            | Flow::DisputesSync
             | Flow::DisputesEvidenceSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
@@ -115,7 +115,10 @@ pub async fn retrieve_mandates_list(
             mandate::retrieve_mandates_list(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::MandateRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::MandateRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -13,6 +13,7 @@ counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
 // API Level Metrics
 counter_metric!(REQUESTS_RECEIVED, GLOBAL_METER);
 counter_metric!(REQUEST_STATUS, GLOBAL_METER);
+counter_metric!(RATE_LIMITED_REQUESTS, GLOBAL_METER); // No. of requests throttled by the rate limiter
 histogram_metric!(REQUEST_TIME, GLOBAL_METER);
 histogram_metric!(EXTERNAL_REQUEST_TIME, GLOBAL_METER);
 
@@ -847,7 +847,10 @@ pub async fn list_countries_currencies_for_connector_payment_method(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::MerchantConnectorAccountWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::MerchantConnectorAccountWrite,
                 minimum_entity_level: EntityType::Profile,
@@ -16,6 +16,7 @@ use crate::{
     self as app,
     core::{
         errors::{self, http_not_implemented},
+        idempotency,
         payments::{self, PaymentRedirectFlow},
     },
     routes::lock_utils,
@@ -44,6 +45,12 @@ pub async fn payments_create(
         return http_not_implemented();
     };
 
+    let idempotency_input =
+        match idempotency::IdempotencyInput::from_headers(req.headers(), flow.clone(), &payload) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     if let Err(err) = get_or_generate_payment_id(&mut payload) {
         return api::log_and_return_error_response(err);
     }
@@ -68,7 +75,10 @@ pub async fn payments_create(
             .unwrap_or_default(),
     );
 
-    let locking_action = payload.get_locking_input(flow.clone());
+    let locking_action = idempotency::get_locking_action(
+        idempotency_input.as_ref(),
+        payload.get_locking_input(flow.clone()),
+    );
 
     Box::pin(api::server_wrap(
         flow,
@@ -76,22 +86,36 @@ pub async fn payments_create(
         &req,
         payload,
         |state, auth, req, req_state| {
-            authorize_verify_select::<_>(
-                payments::PaymentCreate,
+            let header_payload = header_payload.clone();
+            idempotency::call_idempotently(
                 state,
-                req_state,
-                auth.merchant_account,
-                auth.profile_id,
-                auth.key_store,
-                header_payload.clone(),
-                req,
-                api::AuthFlow::Merchant,
+                auth.merchant_account.get_id().clone(),
+                idempotency_input.clone(),
+                move |state| {
+                    authorize_verify_select::<_>(
+                        payments::PaymentCreate,
+                        state,
+                        req_state,
+                        auth.merchant_account,
+                        auth.profile_id,
+                        auth.key_store,
+                        header_payload,
+                        req,
+                        api::AuthFlow::Merchant,
+                    )
+                },
             )
         },
         match env::which() {
-            env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuth),
+            env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PaymentWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             _ => auth::auth_type(
-                &auth::HeaderAuth(auth::ApiKeyAuth),
+                &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                    permission: Permission::PaymentWrite,
+                    minimum_entity_level: EntityType::Profile,
+                }),
                 &auth::JWTAuth {
                     permission: Permission::PaymentWrite,
                     minimum_entity_level: EntityType::Profile,
@@ -870,7 +894,10 @@ pub async fn payments_list(
             payments::list_payments(state, auth.merchant_account, None, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PaymentRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::PaymentRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -906,7 +933,10 @@ pub async fn profile_payments_list(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PaymentRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::PaymentRead,
                 minimum_entity_level: EntityType::Profile,
@@ -1135,9 +1165,15 @@ pub async fn payments_approve(
             )
         },
         match env::which() {
-            env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuth),
+            env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PaymentWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             _ => auth::auth_type(
-                &auth::HeaderAuth(auth::ApiKeyAuth),
+                &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                    permission: Permission::PaymentWrite,
+                    minimum_entity_level: EntityType::Profile,
+                }),
                 &auth::JWTAuth {
                     permission: Permission::PaymentWrite,
                     minimum_entity_level: EntityType::Profile,
@@ -1200,9 +1236,15 @@ pub async fn payments_reject(
             )
         },
         match env::which() {
-            env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuth),
+            env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PaymentWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             _ => auth::auth_type(
-                &auth::HeaderAuth(auth::ApiKeyAuth),
+                &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                    permission: Permission::PaymentWrite,
+                    minimum_entity_level: EntityType::Profile,
+                }),
                 &auth::JWTAuth {
                     permission: Permission::PaymentWrite,
                     minimum_entity_level: EntityType::Profile,
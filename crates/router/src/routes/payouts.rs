@@ -9,7 +9,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{api_locking, payouts::*},
+    core::{api_locking, idempotency, payouts::*},
     headers::ACCEPT_LANGUAGE,
     services::{
         api,
@@ -36,17 +36,44 @@ pub async fn payouts_create(
 ) -> HttpResponse {
     let flow = Flow::PayoutsCreate;
     let locale = get_locale_from_header(req.headers());
+    let payload = json_payload.into_inner();
+
+    let idempotency_input =
+        match idempotency::IdempotencyInput::from_headers(req.headers(), flow.clone(), &payload) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+    let locking_action = idempotency::get_locking_action(
+        idempotency_input.as_ref(),
+        api_locking::LockAction::NotApplicable,
+    );
 
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth, req, _| {
-            payouts_create_core(state, auth.merchant_account, auth.key_store, req, &locale)
+            let locale = locale.clone();
+            idempotency::call_idempotently(
+                state,
+                auth.merchant_account.get_id().clone(),
+                idempotency_input.clone(),
+                move |state| async move {
+                    payouts_create_core(
+                        state,
+                        auth.merchant_account,
+                        auth.profile_id,
+                        auth.key_store,
+                        req,
+                        &locale,
+                    )
+                    .await
+                },
+            )
         },
         &auth::HeaderAuth(auth::ApiKeyAuth),
-        api_locking::LockAction::NotApplicable,
+        locking_action,
     ))
     .await
 }
@@ -82,7 +109,10 @@ pub async fn payouts_retrieve(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PayoutRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::PayoutRead,
                 minimum_entity_level: EntityType::Profile,
@@ -235,7 +265,10 @@ pub async fn payouts_list(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PayoutRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::PayoutRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -275,7 +308,10 @@ pub async fn payouts_list_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PayoutRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::PayoutRead,
                 minimum_entity_level: EntityType::Profile,
@@ -315,7 +351,10 @@ pub async fn payouts_list_by_filter(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PayoutRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::PayoutRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -355,7 +394,10 @@ pub async fn payouts_list_by_filter_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PayoutRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::PayoutRead,
                 minimum_entity_level: EntityType::Profile,
@@ -388,7 +430,10 @@ pub async fn payouts_list_available_filters_for_merchant(
             payouts_list_available_filters_core(state, auth.merchant_account, None, req, &locale)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PayoutRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::PayoutRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -427,7 +472,10 @@ pub async fn payouts_list_available_filters_for_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::PayoutRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::PayoutRead,
                 minimum_entity_level: EntityType::Profile,
@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{api_locking, refunds::*},
+    core::{api_locking, idempotency, refunds::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds,
 };
@@ -32,29 +32,51 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let payload = json_payload.into_inner();
+
+    let idempotency_input =
+        match idempotency::IdempotencyInput::from_headers(req.headers(), flow.clone(), &payload) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+    let locking_action = idempotency::get_locking_action(
+        idempotency_input.as_ref(),
+        api_locking::LockAction::NotApplicable,
+    );
+
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth, req, _| {
-            refund_create_core(
+            idempotency::call_idempotently(
                 state,
-                auth.merchant_account,
-                auth.profile_id,
-                auth.key_store,
-                req,
+                auth.merchant_account.get_id().clone(),
+                idempotency_input.clone(),
+                move |state| {
+                    refund_create_core(
+                        state,
+                        auth.merchant_account,
+                        auth.profile_id,
+                        auth.key_store,
+                        req,
+                    )
+                },
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RefundWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RefundWrite,
                 minimum_entity_level: EntityType::Profile,
             },
             req.headers(),
         ),
-        api_locking::LockAction::NotApplicable,
+        locking_action,
     ))
     .await
 }
@@ -111,7 +133,10 @@ pub async fn refunds_retrieve(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RefundRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RefundRead,
                 minimum_entity_level: EntityType::Profile,
@@ -239,7 +264,10 @@ pub async fn refunds_list(
         payload.into_inner(),
         |state, auth, req, _| refund_list(state, auth.merchant_account, None, req),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RefundRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RefundRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -287,7 +315,10 @@ pub async fn refunds_list_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RefundRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RefundRead,
                 minimum_entity_level: EntityType::Profile,
@@ -328,7 +359,10 @@ pub async fn refunds_filter_list(
         payload.into_inner(),
         |state, auth, req, _| refund_filter_list(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RefundRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RefundRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -364,7 +398,10 @@ pub async fn get_refunds_filters(state: web::Data<AppState>, req: HttpRequest) -
         (),
         |state, auth, _, _| get_filters_for_refunds(state, auth.merchant_account, None),
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RefundRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RefundRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -409,7 +446,10 @@ pub async fn get_refunds_filters_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RefundRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RefundRead,
                 minimum_entity_level: EntityType::Profile,
@@ -439,7 +479,10 @@ pub async fn get_refunds_aggregates(
             get_aggregates_for_refunds(state, auth.merchant_account, None, req)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RefundRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RefundRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -497,7 +540,10 @@ pub async fn get_refunds_aggregate_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RefundRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RefundRead,
                 minimum_entity_level: EntityType::Profile,
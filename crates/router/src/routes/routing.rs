@@ -42,7 +42,56 @@ pub async fn routing_create_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::RoutingWrite,
+            minimum_entity_level: EntityType::Profile,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_create_dsl_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingDslConfigRequest>,
+    transaction_type: &enums::TransactionType,
+) -> impl Responder {
+    let flow = Flow::RoutingCreateDslConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            routing::create_routing_algorithm_from_dsl(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                payload,
+                transaction_type,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RoutingWrite,
                 minimum_entity_level: EntityType::Profile,
@@ -73,7 +122,7 @@ pub async fn routing_link_config(
         state,
         &req,
         path.into_inner(),
-        |state, auth: auth::AuthenticationData, algorithm, _| {
+        |state, (auth, auth_type): auth::AuthenticationDataWithType, algorithm, _| {
             routing::link_routing_config(
                 state,
                 auth.merchant_account,
@@ -81,11 +130,105 @@ pub async fn routing_link_config(
                 auth.profile_id,
                 algorithm,
                 transaction_type,
+                auth_type.get_actor_id().map(ToOwned::to_owned),
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::AuthWithType(auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            })),
+            &auth::AuthWithType(auth::JWTAuth {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::AuthWithType(auth::JWTAuth {
+            permission: Permission::RoutingWrite,
+            minimum_entity_level: EntityType::Profile,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_link_shadow_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::RoutingId>,
+) -> impl Responder {
+    let flow = Flow::RoutingLinkShadowConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, algorithm, _| {
+            routing::link_shadow_routing_config(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                algorithm,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::RoutingWrite,
+            minimum_entity_level: EntityType::Profile,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_link_fallback_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::RoutingId>,
+    fallback_kind: &routing_types::FallbackRoutingKind,
+) -> impl Responder {
+    let flow = Flow::RoutingLinkFallbackConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, algorithm, _| {
+            routing::link_fallback_routing_config(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                algorithm,
+                *fallback_kind,
             )
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RoutingWrite,
                 minimum_entity_level: EntityType::Profile,
@@ -134,7 +277,10 @@ pub async fn routing_link_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Merchant,
+            },
             &auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
                 required_permission: Permission::RoutingWrite,
@@ -178,7 +324,143 @@ pub async fn routing_retrieve_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::RoutingRead,
+            minimum_entity_level: EntityType::Profile,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_dsl_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::RoutingId>,
+) -> impl Responder {
+    let algorithm_id = path.into_inner();
+    let flow = Flow::RoutingRetrieveDslConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        algorithm_id,
+        |state, auth: auth::AuthenticationData, algorithm_id, _| {
+            routing::retrieve_routing_algorithm_dsl(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                algorithm_id,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::RoutingRead,
+            minimum_entity_level: EntityType::Profile,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_simulate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingSimulationRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingSimulate;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            routing::simulate_routing_algorithm(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                payload,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::RoutingRead,
+            minimum_entity_level: EntityType::Profile,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_explain_eligibility(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::EligibilityExplanationRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingExplainEligibility;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            routing::explain_connector_eligibility(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                payload,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RoutingRead,
                 minimum_entity_level: EntityType::Profile,
@@ -220,7 +502,10 @@ pub async fn list_routing_configs(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RoutingRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -262,7 +547,10 @@ pub async fn list_routing_configs_for_profile(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RoutingRead,
                 minimum_entity_level: EntityType::Profile,
@@ -305,7 +593,10 @@ pub async fn routing_unlink_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Merchant,
+            },
             &auth::JWTAuthProfileFromRoute {
                 profile_id: path,
                 required_permission: Permission::RoutingWrite,
@@ -338,7 +629,7 @@ pub async fn routing_unlink_config(
         state,
         &req,
         payload.into_inner(),
-        |state, auth: auth::AuthenticationData, payload_req, _| {
+        |state, (auth, auth_type): auth::AuthenticationDataWithType, payload_req, _| {
             routing::unlink_routing_config(
                 state,
                 auth.merchant_account,
@@ -346,28 +637,258 @@ pub async fn routing_unlink_config(
                 payload_req,
                 auth.profile_id,
                 transaction_type,
+                auth_type.get_actor_id().map(ToOwned::to_owned),
             )
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
-            &auth::JWTAuth {
+            &auth::AuthWithType(auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
                 permission: Permission::RoutingWrite,
                 minimum_entity_level: EntityType::Profile,
-            },
+            })),
+            &auth::AuthWithType(auth::JWTAuth {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             req.headers(),
         ),
         #[cfg(feature = "release")]
-        &auth::JWTAuth {
+        &auth::AuthWithType(auth::JWTAuth {
             permission: Permission::RoutingWrite,
             minimum_entity_level: EntityType::Profile,
-        },
+        }),
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
 
-#[cfg(all(feature = "olap", feature = "v2"))]
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_unlink_shadow_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<routing_types::RoutingConfigRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingUnlinkShadowConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload_req, _| {
+            routing::unlink_shadow_routing_config(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload_req,
+                auth.profile_id,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::RoutingWrite,
+            minimum_entity_level: EntityType::Profile,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_unlink_fallback_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<routing_types::RoutingConfigRequest>,
+    fallback_kind: &routing_types::FallbackRoutingKind,
+) -> impl Responder {
+    let flow = Flow::RoutingUnlinkFallbackConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload_req, _| {
+            routing::unlink_fallback_routing_config(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload_req,
+                auth.profile_id,
+                *fallback_kind,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::RoutingWrite,
+            minimum_entity_level: EntityType::Profile,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_activation_history(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<routing_types::RoutingActivationHistoryQuery>,
+    transaction_type: &enums::TransactionType,
+) -> impl Responder {
+    let flow = Flow::RoutingRetrieveActivationHistory;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query_params, _| {
+            routing::retrieve_routing_activation_history(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                query_params,
+                transaction_type,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::RoutingRead,
+            minimum_entity_level: EntityType::Profile,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_diff_configs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<routing_types::RoutingAlgorithmDiffQuery>,
+) -> impl Responder {
+    let flow = Flow::RoutingDiffConfigs;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query_params, _| {
+            routing::diff_routing_algorithms(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                query_params,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::RoutingRead,
+            minimum_entity_level: EntityType::Profile,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_rollback_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingRollbackRequest>,
+    transaction_type: &enums::TransactionType,
+) -> impl Responder {
+    let flow = Flow::RoutingRollbackConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, (auth, auth_type): auth::AuthenticationDataWithType, payload, _| {
+            routing::rollback_routing_config(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+                auth.profile_id,
+                transaction_type,
+                auth_type.get_actor_id().map(ToOwned::to_owned),
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::AuthWithType(auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            })),
+            &auth::AuthWithType(auth::JWTAuth {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::AuthWithType(auth::JWTAuth {
+            permission: Permission::RoutingWrite,
+            minimum_entity_level: EntityType::Profile,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v2"))]
 #[instrument(skip_all)]
 pub async fn routing_update_default_config(
     state: web::Data<AppState>,
@@ -395,7 +916,10 @@ pub async fn routing_update_default_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RoutingWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -435,7 +959,10 @@ pub async fn routing_update_default_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RoutingWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -475,7 +1002,10 @@ pub async fn routing_retrieve_default_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: path,
                 required_permission: Permission::RoutingRead,
@@ -511,7 +1041,10 @@ pub async fn routing_retrieve_default_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RoutingRead,
                 minimum_entity_level: EntityType::Profile,
@@ -551,7 +1084,10 @@ pub async fn upsert_surcharge_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::SurchargeDecisionManagerWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::SurchargeDecisionManagerWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -588,7 +1124,10 @@ pub async fn delete_surcharge_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::SurchargeDecisionManagerWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::SurchargeDecisionManagerWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -625,7 +1164,10 @@ pub async fn retrieve_surcharge_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::SurchargeDecisionManagerRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::SurchargeDecisionManagerRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -665,7 +1207,10 @@ pub async fn upsert_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::SurchargeDecisionManagerRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::SurchargeDecisionManagerRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -703,7 +1248,10 @@ pub async fn delete_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::SurchargeDecisionManagerWrite,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::SurchargeDecisionManagerWrite,
                 minimum_entity_level: EntityType::Merchant,
@@ -737,7 +1285,10 @@ pub async fn retrieve_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::SurchargeDecisionManagerRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::SurchargeDecisionManagerRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -783,7 +1334,10 @@ pub async fn routing_retrieve_linked_config(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::HeaderAuth(auth::ApiKeyAuth),
+                &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                    permission: Permission::RoutingRead,
+                    minimum_entity_level: EntityType::Profile,
+                }),
                 &auth::JWTAuthProfileFromRoute {
                     profile_id,
                     required_permission: Permission::RoutingRead,
@@ -818,7 +1372,10 @@ pub async fn routing_retrieve_linked_config(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::HeaderAuth(auth::ApiKeyAuth),
+                &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                    permission: Permission::RoutingRead,
+                    minimum_entity_level: EntityType::Profile,
+                }),
                 &auth::JWTAuth {
                     permission: Permission::RoutingRead,
                     minimum_entity_level: EntityType::Profile,
@@ -868,7 +1425,10 @@ pub async fn routing_retrieve_linked_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
                 required_permission: Permission::RoutingRead,
@@ -909,7 +1469,10 @@ pub async fn routing_retrieve_default_config_for_profiles(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RoutingRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -918,7 +1481,10 @@ pub async fn routing_retrieve_default_config_for_profiles(
         ),
         #[cfg(feature = "release")]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::RoutingRead,
                 minimum_entity_level: EntityType::Merchant,
@@ -960,7 +1526,10 @@ pub async fn routing_update_default_config_for_profile(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
                 required_permission: Permission::RoutingWrite,
@@ -1010,7 +1579,10 @@ pub async fn toggle_success_based_routing(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
                 required_permission: Permission::RoutingWrite,
@@ -1056,3 +1628,418 @@ pub async fn success_based_routing_update_configs(
     ))
     .await
 }
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn toggle_least_cost_routing(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<routing_types::ToggleLeastCostRoutingQuery>,
+    path: web::Path<routing_types::ToggleLeastCostRoutingPath>,
+) -> impl Responder {
+    let flow = Flow::ToggleDynamicRouting;
+    let wrapper = routing_types::ToggleLeastCostRoutingWrapper {
+        status: query.into_inner().status,
+        profile_id: path.into_inner().profile_id,
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        wrapper.clone(),
+        |state,
+         auth: auth::AuthenticationData,
+         wrapper: routing_types::ToggleLeastCostRoutingWrapper,
+         _| {
+            routing::toggle_least_cost_routing(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                wrapper.status,
+                wrapper.profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: wrapper.profile_id,
+                required_permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn least_cost_routing_update_configs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<routing_types::LeastCostRoutingUpdateConfigQuery>,
+    json_payload: web::Json<routing_types::LeastCostRoutingConfig>,
+) -> impl Responder {
+    let flow = Flow::UpdateDynamicRoutingConfigs;
+    let path = path.into_inner();
+    let routing_payload_wrapper = routing_types::LeastCostRoutingPayloadWrapper {
+        updated_config: json_payload.into_inner(),
+        algorithm_id: path.algorithm_id,
+        profile_id: path.profile_id.clone(),
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        routing_payload_wrapper,
+        |state,
+         auth: auth::AuthenticationData,
+         wrapper: routing_types::LeastCostRoutingPayloadWrapper,
+         _| async {
+            Box::pin(routing::least_cost_routing_update_configs(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                wrapper.updated_config,
+                wrapper.algorithm_id,
+                wrapper.profile_id,
+            ))
+            .await
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: path.profile_id,
+                required_permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn toggle_elimination_routing(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<routing_types::ToggleEliminationRoutingQuery>,
+    path: web::Path<routing_types::EliminationRoutingPath>,
+) -> impl Responder {
+    let flow = Flow::ToggleDynamicRouting;
+    let wrapper = routing_types::ToggleEliminationRoutingWrapper {
+        status: query.into_inner().status,
+        profile_id: path.into_inner().profile_id,
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        wrapper.clone(),
+        |state,
+         auth: auth::AuthenticationData,
+         wrapper: routing_types::ToggleEliminationRoutingWrapper,
+         _| {
+            routing::toggle_elimination_routing(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                wrapper.status,
+                wrapper.profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: wrapper.profile_id,
+                required_permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn elimination_routing_update_configs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<routing_types::EliminationRoutingUpdateConfigQuery>,
+    json_payload: web::Json<routing_types::EliminationRoutingConfig>,
+) -> impl Responder {
+    let flow = Flow::UpdateDynamicRoutingConfigs;
+    let path = path.into_inner();
+    let routing_payload_wrapper = routing_types::EliminationRoutingPayloadWrapper {
+        updated_config: json_payload.into_inner(),
+        algorithm_id: path.algorithm_id,
+        profile_id: path.profile_id.clone(),
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        routing_payload_wrapper,
+        |state,
+         auth: auth::AuthenticationData,
+         wrapper: routing_types::EliminationRoutingPayloadWrapper,
+         _| async {
+            Box::pin(routing::elimination_routing_update_configs(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                wrapper.updated_config,
+                wrapper.algorithm_id,
+                wrapper.profile_id,
+            ))
+            .await
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: path.profile_id,
+                required_permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::EliminationStatusRetrieve))]
+pub async fn elimination_status_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<routing_types::EliminationRoutingPath>,
+) -> impl Responder {
+    let flow = Flow::EliminationStatusRetrieve;
+    let path = path.into_inner();
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.clone(),
+        |state, auth: auth::AuthenticationData, path: routing_types::EliminationRoutingPath, _| {
+            routing::retrieve_elimination_status(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                path.profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: path.profile_id,
+                required_permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::EliminationStateOverride))]
+pub async fn elimination_state_override(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<routing_types::EliminationRoutingPath>,
+    json_payload: web::Json<routing_types::EliminationOverrideRequest>,
+) -> impl Responder {
+    let flow = Flow::EliminationStateOverride;
+    let wrapper = routing_types::EliminationOverrideWrapper {
+        profile_id: path.into_inner().profile_id,
+        request: json_payload.into_inner(),
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        wrapper.clone(),
+        |state,
+         auth: auth::AuthenticationData,
+         wrapper: routing_types::EliminationOverrideWrapper,
+         _| {
+            routing::override_elimination_state(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                wrapper.profile_id,
+                wrapper.request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: wrapper.profile_id,
+                required_permission: Permission::RoutingWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorFeeScheduleUpsert))]
+pub async fn connector_fee_schedule_upsert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+    json_payload: web::Json<routing_types::ConnectorFeeSchedule>,
+) -> impl Responder {
+    let flow = Flow::ConnectorFeeScheduleUpsert;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            routing::upsert_connector_fee_schedule(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                merchant_connector_id.clone(),
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorAccountWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorFeeScheduleRetrieve))]
+pub async fn connector_fee_schedule_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::ConnectorFeeScheduleRetrieve;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = routing_types::ConnectorFeeScheduleId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            routing::retrieve_connector_fee_schedule(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                payload.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorAccountRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorFeeScheduleDelete))]
+pub async fn connector_fee_schedule_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::ConnectorFeeScheduleDelete;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = routing_types::ConnectorFeeScheduleId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            routing::delete_connector_fee_schedule(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                payload.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorAccountWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,223 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::subscription as subscription_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, subscriptions},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PlansCreate))]
+pub async fn create_plan(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_api::PlanCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PlansCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| subscriptions::create_plan(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PlansRetrieve))]
+pub async fn retrieve_plan(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PlansRetrieve;
+    let plan_id = subscription_api::PlanId {
+        plan_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        plan_id,
+        |state, auth, req, _| subscriptions::retrieve_plan(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PlansList))]
+pub async fn list_plans(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<subscription_api::PlanListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PlansList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth, req, _| subscriptions::list_plans(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PlansUpdate))]
+pub async fn update_plan(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_api::PlanUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PlansUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.plan_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| subscriptions::update_plan(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PlansDeactivate))]
+pub async fn deactivate_plan(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PlansDeactivate;
+    let plan_id = subscription_api::PlanId {
+        plan_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        plan_id,
+        |state, auth, req, _| subscriptions::deactivate_plan(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsCreate))]
+pub async fn create_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_api::SubscriptionCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionsCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            subscriptions::create_subscription(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsRetrieve))]
+pub async fn retrieve_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionsRetrieve;
+    let subscription_id = subscription_api::SubscriptionId {
+        subscription_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        subscription_id,
+        |state, auth, req, _| {
+            subscriptions::retrieve_subscription(state, auth.merchant_account, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsList))]
+pub async fn list_subscriptions(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<subscription_api::SubscriptionListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth, req, _| subscriptions::list_subscriptions(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsUpdate))]
+pub async fn update_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_api::SubscriptionUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionsUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.subscription_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| subscriptions::update_subscription(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsCancel))]
+pub async fn cancel_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscription_api::SubscriptionCancelRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionsCancel;
+    let mut payload = json_payload.into_inner();
+    payload.subscription_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            subscriptions::cancel_subscription(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -32,7 +32,10 @@ pub async fn apple_pay_merchant_registration(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::MerchantAccountWrite,
+                minimum_entity_level: EntityType::Profile,
+            }),
             &auth::JWTAuth {
                 permission: Permission::MerchantAccountWrite,
                 minimum_entity_level: EntityType::Profile,
@@ -67,7 +70,10 @@ pub async fn retrieve_apple_pay_verified_domains(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::ApiKeyAuthWithPermission {
+                permission: Permission::MerchantAccountRead,
+                minimum_entity_level: EntityType::Merchant,
+            }),
             &auth::JWTAuth {
                 permission: Permission::MerchantAccountRead,
                 minimum_entity_level: EntityType::Merchant,
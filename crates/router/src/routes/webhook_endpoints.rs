@@ -0,0 +1,214 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::webhook_endpoints as webhook_endpoint_api;
+use common_enums::EntityType;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, webhooks::webhook_endpoints},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointCreate))]
+pub async fn create_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<webhook_endpoint_api::WebhookEndpointCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            webhook_endpoints::create_webhook_endpoint(
+                state,
+                auth.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointList))]
+pub async fn list_webhook_endpoints(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth, profile_id, _| {
+            webhook_endpoints::list_webhook_endpoints(state, auth.key_store, profile_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id,
+                required_permission: Permission::MerchantAccountRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointRetrieve))]
+pub async fn retrieve_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointRetrieve;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+    let payload = webhook_endpoint_api::WebhookEndpointId { endpoint_id };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            webhook_endpoints::retrieve_webhook_endpoint(
+                state,
+                auth.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: Permission::MerchantAccountRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointUpdate))]
+pub async fn update_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+    json_payload: web::Json<webhook_endpoint_api::WebhookEndpointUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointUpdate;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.endpoint_id = endpoint_id;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            webhook_endpoints::update_webhook_endpoint(
+                state,
+                auth.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointDelete))]
+pub async fn delete_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointDelete;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+    let payload = webhook_endpoint_api::WebhookEndpointId { endpoint_id };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            webhook_endpoints::delete_webhook_endpoint(
+                state,
+                auth.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -17,7 +17,7 @@ use actix_web::{
     http::header::{HeaderName, HeaderValue},
     web, FromRequest, HttpRequest, HttpResponse, Responder, ResponseError,
 };
-pub use client::{proxy_bypass_urls, ApiClient, MockApiClient, ProxyClient};
+pub use client::{create_api_client, proxy_bypass_urls, ApiClient, MockApiClient, ProxyClient};
 pub use common_enums::enums::PaymentAction;
 pub use common_utils::request::{ContentType, Method, Request, RequestBuilder};
 use common_utils::{
@@ -62,7 +62,7 @@ use crate::{
     core::{
         api_locking,
         errors::{self, CustomResult},
-        payments,
+        payments, rate_limiter, storage_scheme_migration,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -683,6 +683,33 @@ pub enum AuthFlow {
     Merchant,
 }
 
+/// Records the rate limit status of the request for the `RateLimitHeaders` middleware to populate
+/// the response headers, failing throttled requests. Requests counted against several limits
+/// report the most restrictive of them.
+fn enforce_rate_limit(
+    request: &HttpRequest,
+    rate_limit_status: Option<rate_limiter::RateLimitStatus>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let Some(rate_limit_status) = rate_limit_status else {
+        return Ok(());
+    };
+    let rate_limit_status = match request.extensions().get::<rate_limiter::RateLimitStatus>() {
+        Some(recorded_status) => recorded_status.clone().most_restrictive(rate_limit_status),
+        None => rate_limit_status,
+    };
+    let is_throttled = rate_limit_status.is_throttled;
+    let retry_after_seconds = rate_limit_status.reset_after_seconds;
+    request.extensions_mut().insert(rate_limit_status);
+
+    if is_throttled {
+        Err(report!(errors::ApiErrorResponse::RateLimitExceeded {
+            retry_after_seconds
+        }))
+    } else {
+        Ok(())
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(
     skip(request, payload, state, func, api_auth, incoming_request_header),
@@ -765,6 +792,12 @@ where
         .event_context
         .record_info(("tenant_id".to_string(), tenant_id.to_string()));
 
+    // Requests made with an API key are counted before authentication, so that throttled
+    // requests do not cost an API key lookup
+    let rate_limit_status =
+        rate_limiter::check_api_key_rate_limit(&session_state, flow, request.headers()).await;
+    enforce_rate_limit(request, rate_limit_status).switch()?;
+
     // Currently auth failures are not recorded as API events
     let (auth_out, auth_type) = api_auth
         .authenticate_and_fetch(request.headers(), &session_state)
@@ -782,6 +815,20 @@ where
 
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
+    let rate_limit_status = rate_limiter::check_merchant_rate_limit(
+        &session_state,
+        flow,
+        request.headers(),
+        &auth_type,
+    )
+    .await;
+    enforce_rate_limit(request, rate_limit_status).switch()?;
+
+    // Merchants moving from KV to Postgres have their KV writes stopped until they are drained
+    if let Some(merchant_id) = auth_type.get_merchant_id() {
+        storage_scheme_migration::stop_kv_writes_if_draining(&mut session_state, merchant_id).await;
+    }
+
     let output = {
         lock_action
             .clone()
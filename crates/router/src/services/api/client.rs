@@ -3,19 +3,23 @@ use std::time::Duration;
 use base64::Engine;
 use error_stack::ResultExt;
 use http::{HeaderValue, Method};
+use hyperswitch_interfaces::secrets_interface::secret_state::SecretState;
 use masking::{ExposeInterface, PeekInterface};
 use once_cell::sync::OnceCell;
 use reqwest::multipart::Form;
 use router_env::tracing_actix_web::RequestId;
 
+pub use self::mock::MockApiClient;
 use super::{request::Maskable, Request};
 use crate::{
-    configs::settings::{Locker, Proxy},
+    configs::settings::{Locker, MockApiClientMode, Proxy, Settings},
     consts::{BASE64_ENGINE, LOCKER_HEALTH_CALL_PATH},
     core::errors::{ApiClientError, CustomResult},
     routes::{app::settings::KeyManagerConfig, SessionState},
 };
 
+mod mock;
+
 static NON_PROXIED_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
 static PROXIED_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
 
@@ -224,6 +228,25 @@ where
 
 dyn_clone::clone_trait_object!(ApiClient);
 
+/// Creates the client used by the application for outgoing requests, which is the mock client if
+/// requests are configured to be recorded or replayed
+pub fn create_api_client<S: SecretState>(
+    conf: &Settings<S>,
+) -> CustomResult<Box<dyn ApiClient>, ApiClientError> {
+    if conf.mock_api_client.mode != MockApiClientMode::Passthrough {
+        return Ok(Box::new(MockApiClient));
+    }
+
+    Ok(Box::new(ProxyClient::new(
+        conf.proxy.clone(),
+        proxy_bypass_urls(
+            conf.key_manager.get_inner(),
+            &conf.locker,
+            &conf.proxy.bypass_proxy_urls,
+        ),
+    )?))
+}
+
 #[derive(Clone)]
 pub struct ProxyClient {
     proxy_client: reqwest::Client,
@@ -402,54 +425,3 @@ impl ApiClient for ProxyClient {
 
     fn add_flow_name(&mut self, _flow_name: String) {}
 }
-
-///
-/// Api client for testing sending request
-///
-#[derive(Clone)]
-pub struct MockApiClient;
-
-#[async_trait::async_trait]
-impl ApiClient for MockApiClient {
-    fn request(
-        &self,
-        _method: Method,
-        _url: String,
-    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
-        // [#2066]: Add Mock implementation for ApiClient
-        Err(ApiClientError::UnexpectedState.into())
-    }
-
-    fn request_with_certificate(
-        &self,
-        _method: Method,
-        _url: String,
-        _certificate: Option<masking::Secret<String>>,
-        _certificate_key: Option<masking::Secret<String>>,
-    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
-        // [#2066]: Add Mock implementation for ApiClient
-        Err(ApiClientError::UnexpectedState.into())
-    }
-
-    async fn send_request(
-        &self,
-        _state: &SessionState,
-        _request: Request,
-        _option_timeout_secs: Option<u64>,
-        _forward_to_kafka: bool,
-    ) -> CustomResult<reqwest::Response, ApiClientError> {
-        // [#2066]: Add Mock implementation for ApiClient
-        Err(ApiClientError::UnexpectedState.into())
-    }
-
-    fn add_request_id(&mut self, _request_id: RequestId) {
-        // [#2066]: Add Mock implementation for ApiClient
-    }
-
-    fn get_request_id(&self) -> Option<String> {
-        // [#2066]: Add Mock implementation for ApiClient
-        None
-    }
-
-    fn add_flow_name(&mut self, _flow_name: String) {}
-}
@@ -0,0 +1,527 @@
+use std::path::{Path, PathBuf};
+
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    request::{Request, RequestContent},
+};
+use error_stack::{report, ResultExt};
+use http::Method;
+use masking::{ErasedMaskSerialize, PeekInterface};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use router_env::tracing_actix_web::RequestId;
+
+use super::{ApiClient, RequestBuilder};
+use crate::{
+    configs::settings::{MockApiClientConfig, MockApiClientMode},
+    core::errors::{ApiClientError, CustomResult},
+    logger,
+    routes::SessionState,
+};
+
+const MASKED_HEADER_VALUE: &str = "*** masked ***";
+
+/// Response headers which do not apply to the recorded body, which is stored decoded
+const SKIPPED_RESPONSE_HEADERS: [&str; 3] =
+    ["content-encoding", "content-length", "transfer-encoding"];
+
+/// Patterns of values which differ between runs wherever they appear in a request, along with the
+/// placeholders they are replaced with when matching requests with fixtures
+static VOLATILE_VALUE_PATTERNS: Lazy<Vec<(Result<Regex, regex::Error>, &str)>> = Lazy::new(|| {
+    vec![
+        (
+            Regex::new(
+                r"[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+            ),
+            "{uuid}",
+        ),
+        (
+            Regex::new(r"\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:?\d{2})?"),
+            "{timestamp}",
+        ),
+    ]
+});
+
+///
+/// Api client for testing sending request. Depending on the `mock_api_client` configuration,
+/// requests are either sent as is, recorded to fixtures along with their responses, or have their
+/// responses replayed from the recorded fixtures.
+///
+#[derive(Clone)]
+pub struct MockApiClient;
+
+/// A recorded request and its response. Requests are matched with fixtures by the file name, which
+/// is derived from the method, URL and body of the request by [`get_fixture_path`].
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct Fixture {
+    request: FixtureRequest,
+    response: FixtureResponse,
+}
+
+/// The request of a fixture, with secrets masked. It is only recorded for reference, and is not
+/// used for matching requests.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct FixtureRequest {
+    method: common_utils::request::Method,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<serde_json::Value>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct FixtureResponse {
+    status_code: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl FixtureRequest {
+    fn new(request: &Request) -> Self {
+        let mut headers = request
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if value.is_masked() {
+                    MASKED_HEADER_VALUE.to_string()
+                } else {
+                    value.clone().into_inner()
+                };
+                (name.clone(), value)
+            })
+            .collect::<Vec<_>>();
+        headers.sort();
+
+        let body = request.body.as_ref().and_then(|body| match body {
+            RequestContent::Json(payload)
+            | RequestContent::FormUrlEncoded(payload)
+            | RequestContent::Xml(payload) => (**payload)
+                .masked_serialize()
+                .map_err(|error| logger::error!(?error))
+                .ok(),
+            RequestContent::FormData(_) | RequestContent::RawBytes(_) => None,
+        });
+
+        // The query parameters may contain secrets
+        let url = reqwest::Url::parse(&request.url)
+            .map(|mut url| {
+                url.set_query(None);
+                url.to_string()
+            })
+            .unwrap_or_default();
+
+        Self {
+            method: request.method,
+            url,
+            headers,
+            body,
+        }
+    }
+}
+
+impl FixtureResponse {
+    async fn from_response(response: reqwest::Response) -> CustomResult<Self, ApiClientError> {
+        let status_code = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter(|(name, _)| !SKIPPED_RESPONSE_HEADERS.contains(&name.as_str()))
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_string()))
+            })
+            .collect();
+        let body = response
+            .text()
+            .await
+            .change_context(ApiClientError::ResponseDecodingFailed)?;
+
+        Ok(Self {
+            status_code,
+            headers,
+            body,
+        })
+    }
+
+    fn into_response(self) -> CustomResult<reqwest::Response, ApiClientError> {
+        self.headers
+            .into_iter()
+            .fold(
+                http::Response::builder().status(self.status_code),
+                |response, (name, value)| response.header(name, value),
+            )
+            .body(self.body)
+            .map(reqwest::Response::from)
+            .change_context(ApiClientError::ResponseDecodingFailed)
+            .attach_printable("Failed to construct response from fixture")
+    }
+}
+
+/// Checks whether the query parameter or body field is configured to be left out when matching
+/// requests with fixtures. Nested form fields such as `metadata[order_id]` are identified by their
+/// innermost name.
+fn is_volatile_field(name: &str, volatile_fields: &[String]) -> bool {
+    let name = name
+        .rsplit('[')
+        .next()
+        .unwrap_or(name)
+        .trim_end_matches(']');
+
+    volatile_fields.iter().any(|field| field == name)
+}
+
+/// Replaces the values which differ between runs wherever they appear, such as random UUIDs and
+/// timestamps, with placeholders
+fn normalize_volatile_values(value: &str) -> String {
+    VOLATILE_VALUE_PATTERNS
+        .iter()
+        .fold(
+            value.to_string(),
+            |value, (pattern, placeholder)| match pattern {
+                Ok(pattern) => pattern.replace_all(&value, *placeholder).into_owned(),
+                Err(error) => {
+                    logger::error!(?error, "Invalid volatile value pattern");
+                    value
+                }
+            },
+        )
+}
+
+fn remove_volatile_fields(value: &mut serde_json::Value, volatile_fields: &[String]) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            fields.retain(|name, _| !volatile_fields.contains(name));
+            fields
+                .values_mut()
+                .for_each(|value| remove_volatile_fields(value, volatile_fields));
+        }
+        serde_json::Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| remove_volatile_fields(value, volatile_fields)),
+        serde_json::Value::Null
+        | serde_json::Value::Bool(_)
+        | serde_json::Value::Number(_)
+        | serde_json::Value::String(_) => {}
+    }
+}
+
+fn get_normalized_url(
+    request: &Request,
+    volatile_fields: &[String],
+) -> CustomResult<reqwest::Url, ApiClientError> {
+    let mut url =
+        reqwest::Url::parse(&request.url).change_context(ApiClientError::UrlEncodingFailed)?;
+    let query_pairs = url
+        .query_pairs()
+        .filter(|(name, _)| !is_volatile_field(name, volatile_fields))
+        .map(|(name, value)| (name.into_owned(), normalize_volatile_values(&value)))
+        .collect::<Vec<_>>();
+
+    if query_pairs.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(query_pairs);
+    }
+
+    Ok(url)
+}
+
+fn get_normalized_body(request: &Request, volatile_fields: &[String]) -> Vec<u8> {
+    let body = match request.body.as_ref() {
+        Some(RequestContent::RawBytes(payload)) => return payload.clone(),
+        Some(body @ RequestContent::Json(_)) => {
+            let body = body.get_inner_value();
+            serde_json::from_str::<serde_json::Value>(body.peek())
+                .map(|mut value| {
+                    remove_volatile_fields(&mut value, volatile_fields);
+                    value.to_string()
+                })
+                .unwrap_or_else(|_| body.peek().clone())
+        }
+        Some(body @ RequestContent::FormUrlEncoded(_)) => {
+            let body = body.get_inner_value();
+            url::form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(
+                    url::form_urlencoded::parse(body.peek().as_bytes())
+                        .filter(|(name, _)| !is_volatile_field(name, volatile_fields))
+                        .map(|(name, value)| (name, normalize_volatile_values(&value))),
+                )
+                .finish()
+        }
+        Some(body) => body.get_inner_value().peek().clone(),
+        None => String::new(),
+    };
+
+    normalize_volatile_values(&body).into_bytes()
+}
+
+/// Derives the path of the fixture of a request from its method, URL and body, leaving out the
+/// configured volatile fields and normalizing the values which differ between runs, so that the
+/// request made for the same flow in another run is matched with the same fixture
+fn get_fixture_path(
+    config: &MockApiClientConfig,
+    request: &Request,
+) -> CustomResult<PathBuf, ApiClientError> {
+    let url = get_normalized_url(request, &config.volatile_fields)?;
+    let body = get_normalized_body(request, &config.volatile_fields);
+    let message = [
+        request.method.to_string().as_bytes(),
+        b"\n",
+        normalize_volatile_values(url.as_str()).as_bytes(),
+        b"\n",
+        body.as_slice(),
+    ]
+    .concat();
+    let digest = Sha256
+        .generate_digest(&message)
+        .change_context(ApiClientError::UnexpectedState)?;
+
+    Ok(Path::new(&config.fixtures_path)
+        .join(url.host_str().unwrap_or_default())
+        .join(format!("{}.json", hex::encode(digest))))
+}
+
+async fn record_fixture(
+    fixture_path: &Path,
+    fixture: &Fixture,
+) -> CustomResult<(), ApiClientError> {
+    let contents = serde_json::to_vec_pretty(fixture)
+        .change_context(ApiClientError::FixtureRecordingFailed)?;
+    if let Some(directory) = fixture_path.parent() {
+        tokio::fs::create_dir_all(directory)
+            .await
+            .change_context(ApiClientError::FixtureRecordingFailed)?;
+    }
+
+    tokio::fs::write(fixture_path, contents)
+        .await
+        .change_context(ApiClientError::FixtureRecordingFailed)
+        .attach_printable_lazy(|| format!("Failed to write fixture {}", fixture_path.display()))
+}
+
+/// Records the response to the request in its fixture, returning the response even if it could
+/// not be recorded
+async fn record_response(
+    fixture_path: &Path,
+    fixture_request: FixtureRequest,
+    response: reqwest::Response,
+) -> CustomResult<reqwest::Response, ApiClientError> {
+    let fixture = Fixture {
+        request: fixture_request,
+        response: FixtureResponse::from_response(response).await?,
+    };
+
+    if let Err(error) = record_fixture(fixture_path, &fixture).await {
+        logger::error!(?error);
+    }
+    fixture.response.into_response()
+}
+
+async fn replay_response(fixture_path: &Path) -> CustomResult<reqwest::Response, ApiClientError> {
+    find_fixture(fixture_path).await?.response.into_response()
+}
+
+async fn find_fixture(fixture_path: &Path) -> CustomResult<Fixture, ApiClientError> {
+    let contents = tokio::fs::read(fixture_path).await.map_err(|error| {
+        report!(ApiClientError::FixtureNotFound).attach_printable(format!(
+            "Failed to read fixture {}: {error}",
+            fixture_path.display()
+        ))
+    })?;
+
+    serde_json::from_slice(&contents)
+        .change_context(ApiClientError::ResponseDecodingFailed)
+        .attach_printable_lazy(|| format!("Failed to parse fixture {}", fixture_path.display()))
+}
+
+#[async_trait::async_trait]
+impl ApiClient for MockApiClient {
+    fn request(
+        &self,
+        _method: Method,
+        _url: String,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        // Only requests sent through `send_request()` can be recorded and replayed
+        Err(ApiClientError::UnexpectedState.into())
+    }
+
+    fn request_with_certificate(
+        &self,
+        _method: Method,
+        _url: String,
+        _certificate: Option<masking::Secret<String>>,
+        _certificate_key: Option<masking::Secret<String>>,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        // Only requests sent through `send_request()` can be recorded and replayed
+        Err(ApiClientError::UnexpectedState.into())
+    }
+
+    async fn send_request(
+        &self,
+        state: &SessionState,
+        request: Request,
+        option_timeout_secs: Option<u64>,
+        _forward_to_kafka: bool,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let mock_api_client = &state.conf.mock_api_client;
+        match mock_api_client.mode {
+            MockApiClientMode::Passthrough => {
+                crate::services::send_request(state, request, option_timeout_secs).await
+            }
+            MockApiClientMode::Record => {
+                let fixture_path = get_fixture_path(mock_api_client, &request)?;
+                let fixture_request = FixtureRequest::new(&request);
+                let response =
+                    crate::services::send_request(state, request, option_timeout_secs).await?;
+                record_response(&fixture_path, fixture_request, response).await
+            }
+            MockApiClientMode::Replay => {
+                let fixture_path = get_fixture_path(mock_api_client, &request)?;
+                logger::info!(
+                    method = ?request.method,
+                    fixture = %fixture_path.display(),
+                    "Replaying recorded response"
+                );
+                replay_response(&fixture_path).await
+            }
+        }
+    }
+
+    fn add_request_id(&mut self, _request_id: RequestId) {}
+
+    fn get_request_id(&self) -> Option<String> {
+        None
+    }
+
+    fn add_flow_name(&mut self, _flow_name: String) {}
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_config(fixtures_path: &Path) -> MockApiClientConfig {
+        MockApiClientConfig {
+            mode: MockApiClientMode::Replay,
+            fixtures_path: fixtures_path.to_string_lossy().into_owned(),
+            volatile_fields: vec!["reference".to_string()],
+        }
+    }
+
+    /// Builds a payment request as made in a run, with a reference, request ID and timestamp
+    /// which differ between runs
+    fn get_payment_request(amount: i64, reference: &str) -> Request {
+        let mut request = Request::new(
+            common_utils::request::Method::Post,
+            &format!(
+                "https://api.example.com/v1/payments?request_id={}",
+                uuid::Uuid::new_v4()
+            ),
+        );
+        request.set_body(RequestContent::Json(Box::new(serde_json::json!({
+            "amount": amount,
+            "currency": "USD",
+            "reference": reference,
+            "metadata": {
+                "created_at": common_utils::date_time::now().to_string(),
+                "idempotency_key": uuid::Uuid::new_v4().to_string(),
+            },
+        }))));
+        request
+    }
+
+    #[test]
+    fn test_fixture_path_ignores_volatile_values() {
+        let config = get_config(Path::new("fixtures"));
+
+        let fixture_path = get_fixture_path(&config, &get_payment_request(1000, "pay_1")).unwrap();
+        let other_run_fixture_path =
+            get_fixture_path(&config, &get_payment_request(1000, "pay_2")).unwrap();
+        let other_amount_fixture_path =
+            get_fixture_path(&config, &get_payment_request(2000, "pay_1")).unwrap();
+
+        assert_eq!(fixture_path, other_run_fixture_path);
+        assert_ne!(fixture_path, other_amount_fixture_path);
+        assert!(fixture_path.starts_with("fixtures/api.example.com"));
+    }
+
+    #[test]
+    fn test_fixture_path_ignores_nested_volatile_form_fields() {
+        let config = get_config(Path::new("fixtures"));
+        let get_form_request = |reference: &str| {
+            let mut request = Request::new(
+                common_utils::request::Method::Post,
+                "https://api.example.com/v1/payment_intents",
+            );
+            request.set_body(RequestContent::FormUrlEncoded(Box::new(
+                serde_json::json!({
+                    "amount": "1000",
+                    "metadata[reference]": reference,
+                }),
+            )));
+            request
+        };
+
+        assert_eq!(
+            get_fixture_path(&config, &get_form_request("pay_1")).unwrap(),
+            get_fixture_path(&config, &get_form_request("pay_2")).unwrap()
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_record_then_replay() {
+        let fixtures_path =
+            std::env::temp_dir().join(format!("mock_api_client_{}", uuid::Uuid::new_v4()));
+        let config = get_config(&fixtures_path);
+
+        let recorded_request = get_payment_request(1000, "pay_1");
+        let recorded_fixture_path = get_fixture_path(&config, &recorded_request).unwrap();
+        let response = http::Response::builder()
+            .status(201)
+            .header("content-type", "application/json")
+            .body(r#"{"id":"txn_123","status":"succeeded"}"#)
+            .map(reqwest::Response::from)
+            .unwrap();
+        let recorded_response = record_response(
+            &recorded_fixture_path,
+            FixtureRequest::new(&recorded_request),
+            response,
+        )
+        .await
+        .unwrap();
+        assert_eq!(recorded_response.status().as_u16(), 201);
+
+        // The same request made in another run is replayed from the recorded fixture
+        let replayed_fixture_path =
+            get_fixture_path(&config, &get_payment_request(1000, "pay_2")).unwrap();
+        assert_eq!(replayed_fixture_path, recorded_fixture_path);
+
+        let replayed_response = replay_response(&replayed_fixture_path).await.unwrap();
+        assert_eq!(replayed_response.status().as_u16(), 201);
+        assert_eq!(
+            replayed_response
+                .headers()
+                .get("content-type")
+                .and_then(|value| value.to_str().ok()),
+            Some("application/json")
+        );
+        assert_eq!(
+            replayed_response.text().await.unwrap(),
+            r#"{"id":"txn_123","status":"succeeded"}"#
+        );
+
+        // A different request has no fixture to be replayed from
+        let missing_fixture_path =
+            get_fixture_path(&config, &get_payment_request(2000, "pay_1")).unwrap();
+        let error = replay_response(&missing_fixture_path).await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            ApiClientError::FixtureNotFound
+        ));
+
+        tokio::fs::remove_dir_all(&fixtures_path).await.unwrap();
+    }
+}
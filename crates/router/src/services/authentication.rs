@@ -1,3 +1,5 @@
+use std::net::IpAddr;
+
 use actix_web::http::header::HeaderMap;
 #[cfg(all(
     any(feature = "v2", feature = "v1"),
@@ -160,6 +162,25 @@ impl AuthenticationType {
             | Self::NoAuth => None,
         }
     }
+
+    /// Returns the id of the API key or the user the request was authenticated with
+    pub fn get_actor_id(&self) -> Option<&str> {
+        match self {
+            Self::ApiKey { key_id, .. } => Some(key_id),
+            Self::OrganizationJwt { user_id, .. }
+            | Self::MerchantJwtWithProfileId { user_id, .. }
+            | Self::UserJwt { user_id }
+            | Self::SinglePurposeJwt { user_id, .. }
+            | Self::SinglePurposeOrLoginJwt { user_id, .. } => Some(user_id),
+            Self::MerchantJwt { user_id, .. } => user_id.as_deref(),
+            Self::AdminApiKey
+            | Self::AdminApiAuthWithMerchantId { .. }
+            | Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => None,
+        }
+    }
 }
 
 #[cfg(feature = "olap")]
@@ -296,6 +317,16 @@ where
 #[derive(Debug)]
 pub struct ApiKeyAuth;
 
+/// API key authentication for endpoints which require the specified permission. API keys which are
+/// restricted to a set of permissions can only be used if they are allowed this permission, while
+/// [`ApiKeyAuth`] rejects such API keys altogether. API keys restricted to a business profile can
+/// only be used on endpoints at the profile level, as merchant level endpoints are not scoped to a
+/// profile, and are rejected by [`ApiKeyAuth`] as well.
+pub struct ApiKeyAuthWithPermission {
+    pub permission: Permission,
+    pub minimum_entity_level: EntityType,
+}
+
 pub struct NoAuth;
 
 #[cfg(feature = "partial-auth")]
@@ -305,6 +336,13 @@ impl GetAuthType for ApiKeyAuth {
     }
 }
 
+#[cfg(feature = "partial-auth")]
+impl GetAuthType for ApiKeyAuthWithPermission {
+    fn get_auth_type(&self) -> detached::PayloadType {
+        detached::PayloadType::ApiKey
+    }
+}
+
 //
 // # Header Auth
 //
@@ -359,75 +397,191 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
-        let api_key = get_api_key(request_headers)
-            .change_context(errors::ApiErrorResponse::Unauthorized)?
-            .trim();
-        if api_key.is_empty() {
-            return Err(errors::ApiErrorResponse::Unauthorized)
-                .attach_printable("API key is empty");
-        }
+        authenticate_api_key(request_headers, state, None).await
+    }
+}
 
-        let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
-            let config = state.conf();
-            config.api_keys.get_inner().get_hash_key()?
-        };
-        let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for ApiKeyAuthWithPermission
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        authenticate_api_key(request_headers, state, Some(self)).await
+    }
+}
 
-        let stored_api_key = state
-            .store()
-            .find_api_key_by_hash_optional(hashed_api_key.into())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
-            .attach_printable("Failed to retrieve API key")?
-            .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
-            .attach_printable("Merchant not authenticated")?;
-
-        if stored_api_key
-            .expires_at
-            .map(|expires_at| expires_at < date_time::now())
-            .unwrap_or(false)
-        {
-            return Err(report!(errors::ApiErrorResponse::Unauthorized))
-                .attach_printable("API key has expired");
+async fn authenticate_api_key<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+    restrictions: Option<&ApiKeyAuthWithPermission>,
+) -> RouterResult<(AuthenticationData, AuthenticationType)>
+where
+    A: SessionStateInfo + Sync,
+{
+    let api_key = get_api_key(request_headers)
+        .change_context(errors::ApiErrorResponse::Unauthorized)?
+        .trim();
+    if api_key.is_empty() {
+        return Err(errors::ApiErrorResponse::Unauthorized).attach_printable("API key is empty");
+    }
+
+    let api_key = api_keys::PlaintextApiKey::from(api_key);
+    let hash_key = {
+        let config = state.conf();
+        config.api_keys.get_inner().get_hash_key()?
+    };
+    let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+
+    let stored_api_key = state
+        .store()
+        .find_api_key_by_hash_optional(hashed_api_key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
+        .attach_printable("Merchant not authenticated")?;
+
+    if stored_api_key
+        .expires_at
+        .map(|expires_at| expires_at < date_time::now())
+        .unwrap_or(false)
+    {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("API key has expired");
+    }
+
+    let trusted_proxy_count = state.conf().api_keys.get_inner().trusted_proxy_count;
+    validate_api_key_restrictions(
+        &stored_api_key,
+        request_headers,
+        trusted_proxy_count,
+        restrictions,
+    )?;
+
+    let key_manager_state = &(&state.session_state()).into();
+
+    let key_store = state
+        .store()
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &stored_api_key.merchant_id,
+            &state.store().get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+
+    let merchant = state
+        .store()
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &stored_api_key.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+
+    let auth = AuthenticationData {
+        merchant_account: merchant,
+        key_store,
+        // API keys restricted to a business profile can only access resources of that profile
+        profile_id: stored_api_key.profile_id,
+    };
+    Ok((
+        auth.clone(),
+        AuthenticationType::ApiKey {
+            merchant_id: auth.merchant_account.get_id().clone(),
+            key_id: stored_api_key.key_id,
+        },
+    ))
+}
+
+fn validate_api_key_restrictions(
+    stored_api_key: &storage::ApiKey,
+    request_headers: &HeaderMap,
+    trusted_proxy_count: usize,
+    restrictions: Option<&ApiKeyAuthWithPermission>,
+) -> RouterResult<()> {
+    if let Some(ip_allowlist) = stored_api_key.ip_allowlist.as_ref() {
+        let client_ip_address = get_client_ip_address(request_headers, trusted_proxy_count);
+        let is_ip_address_allowed = client_ip_address.is_some_and(|client_ip_address| {
+            ip_allowlist
+                .iter()
+                .filter_map(|ip_address| ip_address.parse::<IpAddr>().ok())
+                .any(|ip_address| ip_address == client_ip_address)
+        });
+
+        if !is_ip_address_allowed {
+            return Err(report!(errors::ApiErrorResponse::GenericUnauthorized {
+                message: "API key cannot be used from this IP address".to_string(),
+            }))
+            .attach_printable_lazy(|| {
+                format!("Request IP address {client_ip_address:?} is not in the API key allowlist")
+            });
         }
+    }
 
-        let key_manager_state = &(&state.session_state()).into();
+    // Endpoints which are not aware of entity levels are not scoped to a profile either
+    if stored_api_key.profile_id.is_some()
+        && !restrictions
+            .is_some_and(|restrictions| restrictions.minimum_entity_level == EntityType::Profile)
+    {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: "using an API key restricted to a business profile".to_string(),
+        }))
+        .attach_printable(
+            "Merchant level endpoint does not support API keys restricted to a profile",
+        );
+    }
 
-        let key_store = state
-            .store()
-            .get_merchant_key_store_by_merchant_id(
-                key_manager_state,
-                &stored_api_key.merchant_id,
-                &state.store().get_master_key().to_vec().into(),
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::Unauthorized)
-            .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+    if let Some(permissions) = stored_api_key.permissions.as_deref() {
+        let permissions = api_keys::parse_stored_permissions(permissions)?;
+        match restrictions.map(|restrictions| restrictions.permission) {
+            Some(required_permission) if permissions.contains(&required_permission) => {}
+            Some(required_permission) => {
+                return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                    resource: required_permission.to_string(),
+                }))
+                .attach_printable("API key is not allowed the required permission");
+            }
+            None => {
+                return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                    resource: "using an API key restricted to permissions".to_string(),
+                }))
+                .attach_printable("Endpoint does not support API keys restricted to permissions");
+            }
+        }
+    }
 
-        let merchant = state
-            .store()
-            .find_merchant_account_by_merchant_id(
-                key_manager_state,
-                &stored_api_key.merchant_id,
-                &key_store,
-            )
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+    Ok(())
+}
 
-        let auth = AuthenticationData {
-            merchant_account: merchant,
-            key_store,
-            profile_id: None,
-        };
-        Ok((
-            auth.clone(),
-            AuthenticationType::ApiKey {
-                merchant_id: auth.merchant_account.get_id().clone(),
-                key_id: stored_api_key.key_id,
-            },
-        ))
+/// Obtains the IP address of the client from the `X-Forwarded-For` header. Every proxy appends the
+/// address of its peer to the header, so that the entries before those of the trusted proxies are
+/// set by the client. The client IP address is hence the entry appended by the outermost trusted
+/// proxy, at `trusted_proxy_count` entries from the end of the header.
+fn get_client_ip_address(
+    request_headers: &HeaderMap,
+    trusted_proxy_count: usize,
+) -> Option<IpAddr> {
+    // The header is set by the client itself without trusted proxies
+    if trusted_proxy_count == 0 {
+        return None;
     }
+
+    let ip_addresses = request_headers
+        .get_all(headers::X_FORWARDED_FOR)
+        .filter_map(|header| header.to_str().ok())
+        .flat_map(|header| header.split(','))
+        .collect::<Vec<_>>();
+
+    let index = ip_addresses.len().checked_sub(trusted_proxy_count)?;
+    ip_addresses.get(index)?.trim().parse().ok()
 }
 
 #[cfg(not(feature = "partial-auth"))]
@@ -1623,6 +1777,34 @@ where
 
 pub type AuthenticationDataWithUserId = (AuthenticationData, String);
 
+/// The authentication data along with the type of the authentication, which identifies the API
+/// key or the user making the request
+pub type AuthenticationDataWithType = (AuthenticationData, AuthenticationType);
+
+/// Authenticates the request using the inner authentication, also providing the type of the
+/// authentication to the handler
+#[derive(Debug)]
+pub struct AuthWithType<I>(pub I);
+
+#[async_trait]
+impl<A, I> AuthenticateAndFetch<AuthenticationDataWithType, A> for AuthWithType<I>
+where
+    A: SessionStateInfo + Sync,
+    I: AuthenticateAndFetch<AuthenticationData, A> + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationDataWithType, AuthenticationType)> {
+        let (auth, auth_type) = self
+            .0
+            .authenticate_and_fetch(request_headers, state)
+            .await?;
+        Ok(((auth, auth_type.clone()), auth_type))
+    }
+}
+
 #[async_trait]
 impl<A> AuthenticateAndFetch<AuthenticationDataWithUserId, A> for JWTAuth
 where
@@ -2095,3 +2277,148 @@ where
         Ok((auth, auth_type))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::http::header::{HeaderName, HeaderValue};
+
+    use super::*;
+
+    fn api_key(
+        permissions: Option<Vec<Permission>>,
+        profile_id: Option<&'static str>,
+        ip_allowlist: Option<Vec<&str>>,
+    ) -> storage::ApiKey {
+        storage::ApiKey {
+            key_id: "key_test".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            name: "test".to_string(),
+            description: None,
+            hashed_api_key: String::from("hashed").into(),
+            prefix: "snd_test".to_string(),
+            created_at: date_time::now(),
+            expires_at: None,
+            last_used: None,
+            permissions: permissions.map(|permissions| {
+                permissions
+                    .into_iter()
+                    .map(|permission| permission.to_string())
+                    .collect()
+            }),
+            profile_id: profile_id.map(|profile_id| {
+                id_type::ProfileId::try_from(std::borrow::Cow::from(profile_id)).unwrap()
+            }),
+            ip_allowlist: ip_allowlist.map(|ip_allowlist| {
+                ip_allowlist
+                    .into_iter()
+                    .map(|ip_address| ip_address.to_string())
+                    .collect()
+            }),
+        }
+    }
+
+    fn forwarded_for(value: &'static str) -> HeaderMap {
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert(
+            HeaderName::from_static("x-forwarded-for"),
+            HeaderValue::from_static(value),
+        );
+        request_headers
+    }
+
+    fn restrictions(
+        permission: Permission,
+        minimum_entity_level: EntityType,
+    ) -> ApiKeyAuthWithPermission {
+        ApiKeyAuthWithPermission {
+            permission,
+            minimum_entity_level,
+        }
+    }
+
+    #[test]
+    fn test_client_ip_address_from_trusted_proxies() {
+        let request_headers = forwarded_for("203.0.113.7, 198.51.100.1, 10.0.0.1");
+
+        assert_eq!(
+            get_client_ip_address(&request_headers, 1),
+            Some("10.0.0.1".parse().unwrap())
+        );
+        assert_eq!(
+            get_client_ip_address(&request_headers, 2),
+            Some("198.51.100.1".parse().unwrap())
+        );
+        assert_eq!(get_client_ip_address(&request_headers, 4), None);
+        assert_eq!(get_client_ip_address(&request_headers, 0), None);
+    }
+
+    #[test]
+    fn test_forged_forwarded_for_entry_is_rejected() {
+        let stored_api_key = api_key(None, None, Some(vec!["203.0.113.7"]));
+
+        // The client prepends the allowed IP address, the proxy appends the actual one
+        let forged_headers = forwarded_for("203.0.113.7, 198.51.100.23");
+        assert!(validate_api_key_restrictions(&stored_api_key, &forged_headers, 1, None).is_err());
+
+        let request_headers = forwarded_for("203.0.113.7");
+        assert!(validate_api_key_restrictions(&stored_api_key, &request_headers, 1, None).is_ok());
+
+        // Without trusted proxies, the header is ignored altogether
+        assert!(validate_api_key_restrictions(&stored_api_key, &request_headers, 0, None).is_err());
+    }
+
+    #[test]
+    fn test_permission_restricted_api_key() {
+        let stored_api_key = api_key(Some(vec![Permission::RefundWrite]), None, None);
+        let request_headers = HeaderMap::new();
+
+        let refund_write = restrictions(Permission::RefundWrite, EntityType::Profile);
+        assert!(validate_api_key_restrictions(
+            &stored_api_key,
+            &request_headers,
+            0,
+            Some(&refund_write)
+        )
+        .is_ok());
+
+        let payment_write = restrictions(Permission::PaymentWrite, EntityType::Profile);
+        assert!(validate_api_key_restrictions(
+            &stored_api_key,
+            &request_headers,
+            0,
+            Some(&payment_write)
+        )
+        .is_err());
+
+        // Endpoints which are not aware of permissions reject restricted API keys
+        assert!(validate_api_key_restrictions(&stored_api_key, &request_headers, 0, None).is_err());
+    }
+
+    #[test]
+    fn test_profile_restricted_api_key() {
+        let stored_api_key = api_key(None, Some("pro_restricted"), None);
+        let request_headers = HeaderMap::new();
+
+        let profile_level = restrictions(Permission::PaymentRead, EntityType::Profile);
+        assert!(validate_api_key_restrictions(
+            &stored_api_key,
+            &request_headers,
+            0,
+            Some(&profile_level)
+        )
+        .is_ok());
+
+        let merchant_level = restrictions(Permission::PaymentRead, EntityType::Merchant);
+        assert!(validate_api_key_restrictions(
+            &stored_api_key,
+            &request_headers,
+            0,
+            Some(&merchant_level)
+        )
+        .is_err());
+
+        // Endpoints which are not aware of entity levels reject restricted API keys
+        assert!(validate_api_key_restrictions(&stored_api_key, &request_headers, 0, None).is_err());
+    }
+}
@@ -1,7 +1,16 @@
-use strum::Display;
+use strum::{Display, EnumString};
 
 #[derive(
-    PartialEq, Display, Clone, Debug, Copy, Eq, Hash, serde::Deserialize, serde::Serialize,
+    PartialEq,
+    Display,
+    EnumString,
+    Clone,
+    Debug,
+    Copy,
+    Eq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
 )]
 pub enum Permission {
     PaymentRead,
@@ -1,3 +1,3 @@
 pub use hyperswitch_interfaces::api::disputes_v2::{
-    AcceptDisputeV2, DefendDisputeV2, DisputeV2, SubmitEvidenceV2,
+    AcceptDisputeV2, DefendDisputeV2, DisputeV2, SubmitEvidenceV2, SyncDisputeV2,
 };
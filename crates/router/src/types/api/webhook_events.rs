@@ -1,6 +1,8 @@
 pub use api_models::webhook_events::{
     EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
-    EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
-    OutgoingWebhookResponseContent, WebhookDeliveryAttemptListRequestInternal,
-    WebhookDeliveryRetryRequestInternal,
+    EventListRequestInternal, EventRetrieveResponse, IncomingWebhookEventListConstraints,
+    IncomingWebhookEventListItemResponse, IncomingWebhookEventListRequestInternal,
+    IncomingWebhookEventRequestInternal, IncomingWebhookEventRetrieveResponse,
+    IncomingWebhookRequestContent, OutgoingWebhookRequestContent, OutgoingWebhookResponseContent,
+    WebhookDeliveryAttemptListRequestInternal, WebhookDeliveryRetryRequestInternal,
 };
@@ -0,0 +1,121 @@
+use common_utils::{
+    crypto::Encryptable,
+    type_name,
+    types::keymanager::{Identifier, KeyManagerState},
+};
+use diesel_models::enums::IncomingWebhookEventStatus;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use crate::{
+    errors::{CustomResult, ValidationError},
+    types::domain::types,
+};
+
+#[derive(Clone, Debug)]
+pub struct IncomingWebhookEvent {
+    pub webhook_event_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_name_or_mca_id: String,
+    /// The method, URI, query parameters and headers of the webhook request, as JSON
+    pub request_details: Encryptable<Secret<String>>,
+    /// The raw bytes of the webhook request body, exactly as received from the connector
+    pub request_body: Encryptable<Secret<Vec<u8>>>,
+    pub status: IncomingWebhookEventStatus,
+    pub webhook_effect: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    pub replay_count: i32,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl super::behaviour::Conversion for IncomingWebhookEvent {
+    type DstType = diesel_models::incoming_webhook_event::IncomingWebhookEvent;
+    type NewDstType = diesel_models::incoming_webhook_event::IncomingWebhookEventNew;
+
+    async fn convert(self) -> CustomResult<Self::DstType, ValidationError> {
+        Ok(
+            diesel_models::incoming_webhook_event::IncomingWebhookEvent {
+                webhook_event_id: self.webhook_event_id,
+                merchant_id: self.merchant_id,
+                connector_name_or_mca_id: self.connector_name_or_mca_id,
+                request_details: self.request_details.into(),
+                request_body: self.request_body.into(),
+                status: self.status,
+                webhook_effect: self.webhook_effect,
+                error_message: self.error_message,
+                replay_count: self.replay_count,
+                created_at: self.created_at,
+                modified_at: self.modified_at,
+            },
+        )
+    }
+
+    async fn convert_back(
+        state: &KeyManagerState,
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        key_manager_identifier: Identifier,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        let request_details = types::crypto_operation(
+            state,
+            type_name!(Self::DstType),
+            types::CryptoOperation::Decrypt(item.request_details),
+            key_manager_identifier.clone(),
+            key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .change_context(ValidationError::InvalidValue {
+            message: "Failed while decrypting incoming webhook request details".to_string(),
+        })?;
+        let request_body = types::crypto_operation(
+            state,
+            type_name!(Self::DstType),
+            types::CryptoOperation::Decrypt(item.request_body),
+            key_manager_identifier,
+            key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .change_context(ValidationError::InvalidValue {
+            message: "Failed while decrypting incoming webhook request body".to_string(),
+        })?;
+
+        Ok(Self {
+            webhook_event_id: item.webhook_event_id,
+            merchant_id: item.merchant_id,
+            connector_name_or_mca_id: item.connector_name_or_mca_id,
+            request_details,
+            request_body,
+            status: item.status,
+            webhook_effect: item.webhook_effect,
+            error_message: item.error_message,
+            replay_count: item.replay_count,
+            created_at: item.created_at,
+            modified_at: item.modified_at,
+        })
+    }
+
+    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
+        Ok(
+            diesel_models::incoming_webhook_event::IncomingWebhookEventNew {
+                webhook_event_id: self.webhook_event_id,
+                merchant_id: self.merchant_id,
+                connector_name_or_mca_id: self.connector_name_or_mca_id,
+                request_details: self.request_details.into(),
+                request_body: self.request_body.into(),
+                status: self.status,
+                webhook_effect: self.webhook_effect,
+                error_message: self.error_message,
+                replay_count: self.replay_count,
+                created_at: self.created_at,
+                modified_at: self.modified_at,
+            },
+        )
+    }
+}
@@ -9,6 +9,7 @@ pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_fee_schedule;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -17,8 +18,10 @@ pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod fx_rate_snapshot;
 pub mod generic_link;
 pub mod gsm;
+pub mod incoming_webhook_event;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -31,14 +34,18 @@ pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
+pub mod plan;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
+pub mod routing_activation_history;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use std::collections::HashMap;
 
@@ -61,12 +68,13 @@ pub use scheduler::db::process_tracker;
 pub use self::{
     address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, generic_link::*, gsm::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
-    user_role::*,
+    configs::*, connector_fee_schedule::*, customers::*, dashboard_metadata::*, dispute::*,
+    ephemeral_key::*, events::*, file::*, fraud_check::*, fx_rate_snapshot::*, generic_link::*,
+    gsm::*, incoming_webhook_event::*, locker_mock_up::*, mandate::*, merchant_account::*,
+    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
+    plan::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
+    routing_activation_history::*, routing_algorithm::*, subscription::*, unified_translations::*,
+    user::*, user_authentication_method::*, user_role::*, webhook_endpoint::*,
 };
 use crate::types::api::routing;
 
@@ -0,0 +1 @@
+pub use diesel_models::plan::{Plan, PlanNew, PlanUpdate};
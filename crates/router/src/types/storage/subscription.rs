@@ -0,0 +1,3 @@
+pub use diesel_models::subscription::{
+    Subscription, SubscriptionBillingTrackingData, SubscriptionNew, SubscriptionUpdate,
+};
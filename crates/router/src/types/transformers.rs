@@ -659,6 +659,23 @@ impl ForeignFrom<storage_enums::MandateStatus> for Option<storage_enums::EventTy
     }
 }
 
+impl ForeignFrom<storage_enums::SubscriptionStatus> for Option<storage_enums::EventType> {
+    fn foreign_from(value: storage_enums::SubscriptionStatus) -> Self {
+        match value {
+            storage_enums::SubscriptionStatus::PastDue => {
+                Some(storage_enums::EventType::SubscriptionPaymentFailed)
+            }
+            storage_enums::SubscriptionStatus::Cancelled => {
+                Some(storage_enums::EventType::SubscriptionCancelled)
+            }
+            // Both creation and renewal leave the subscription in these states, the event type
+            // cannot be determined from the status alone
+            storage_enums::SubscriptionStatus::Trialing
+            | storage_enums::SubscriptionStatus::Active => None,
+        }
+    }
+}
+
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::RefundStatus {
     type Error = errors::ValidationError;
 
@@ -845,6 +862,12 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permissions: api_key
+                .permissions
+                .as_deref()
+                .map(foreign_from_api_key_permissions),
+            profile_id: api_key.profile_id,
+            ip_allowlist: api_key.ip_allowlist,
         }
     }
 }
@@ -859,10 +882,30 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permissions: api_key
+                .permissions
+                .as_deref()
+                .map(foreign_from_api_key_permissions),
+            profile_id: api_key.profile_id,
+            ip_allowlist: api_key.ip_allowlist,
         }
     }
 }
 
+fn foreign_from_api_key_permissions(
+    permissions: &[String],
+) -> Vec<api_models::user_role::Permission> {
+    use std::str::FromStr;
+
+    use crate::services::authorization::permissions::Permission;
+
+    permissions
+        .iter()
+        .filter_map(|permission| Permission::from_str(permission).ok())
+        .map(Into::into)
+        .collect()
+}
+
 impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
     for diesel_models::api_keys::ApiKeyUpdate
 {
@@ -872,6 +915,15 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            // An empty list removes the restriction
+            permissions: api_key.permissions.map(|permissions| {
+                (!permissions.is_empty())
+                    .then(|| crate::core::api_keys::get_permissions_to_store(permissions))
+            }),
+            ip_allowlist: api_key.ip_allowlist.map(|ip_allowlist| {
+                (!ip_allowlist.is_empty())
+                    .then(|| ip_allowlist.iter().map(ToString::to_string).collect())
+            }),
         }
     }
 }
@@ -1765,6 +1817,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             event_class: item.event_class,
             is_delivery_successful: item.is_webhook_notified,
             initial_attempt_id,
+            webhook_endpoint_id: item.webhook_endpoint_id,
             created: item.created_at,
         })
     }
@@ -1809,6 +1862,81 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventRetrieveRespons
     }
 }
 
+impl From<domain::IncomingWebhookEvent>
+    for api_models::webhook_events::IncomingWebhookEventListItemResponse
+{
+    fn from(item: domain::IncomingWebhookEvent) -> Self {
+        Self {
+            webhook_event_id: item.webhook_event_id,
+            merchant_id: item.merchant_id,
+            connector: item.connector_name_or_mca_id,
+            status: item.status,
+            webhook_effect: item.webhook_effect,
+            error_message: item.error_message,
+            replay_count: item.replay_count,
+            created: item.created_at,
+            modified: item.modified_at,
+        }
+    }
+}
+
+impl TryFrom<domain::IncomingWebhookEvent>
+    for api_models::webhook_events::IncomingWebhookEventRetrieveResponse
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(item: domain::IncomingWebhookEvent) -> Result<Self, Self::Error> {
+        let request_metadata: crate::core::webhooks::types::IncomingWebhookRequestMetadata = item
+            .request_details
+            .peek()
+            .parse_struct("IncomingWebhookRequestMetadata")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse incoming webhook request information")?;
+        let request = api_models::webhook_events::IncomingWebhookRequestContent {
+            method: request_metadata.method,
+            uri: request_metadata.uri,
+            query_params: Secret::new(request_metadata.query_params),
+            headers: request_metadata
+                .headers
+                .into_iter()
+                .map(|(name, value)| {
+                    (
+                        name,
+                        Secret::new(String::from_utf8_lossy(&value).into_owned()),
+                    )
+                })
+                .collect(),
+            body: Secret::new(
+                String::from_utf8_lossy(item.request_body.get_inner().peek()).into_owned(),
+            ),
+        };
+
+        Ok(Self {
+            event_information: item.into(),
+            request,
+        })
+    }
+}
+
+#[cfg(feature = "olap")]
+impl ForeignFrom<storage::WebhookEndpoint>
+    for api_models::webhook_endpoints::WebhookEndpointResponse
+{
+    fn foreign_from(item: storage::WebhookEndpoint) -> Self {
+        Self {
+            endpoint_id: item.endpoint_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            url: item.url,
+            description: item.description,
+            secret: item.secret,
+            enabled_events: item.enabled_events,
+            is_enabled: item.is_enabled,
+            created: item.created_at,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::admin::AuthenticationConnectorDetails>
     for diesel_models::business_profile::AuthenticationConnectorDetails
 {
@@ -1,33 +1,33 @@
-use std::{collections::HashMap, ops::Deref, str::FromStr, sync::Arc, time::Duration};
+pub mod providers;
+
+use std::{str::FromStr, sync::Arc, time::Duration};
 
 use api_models::enums;
-use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
-use currency_conversion::types::{CurrencyFactors, ExchangeRates};
-use error_stack::ResultExt;
-use masking::PeekInterface;
+use common_utils::{
+    date_time,
+    errors::CustomResult,
+    events::ApiEventMetric,
+    ext_traits::{AsyncExt, Encode, ValueExt},
+    types::MinorUnit,
+};
+use currency_conversion::types::{ExchangeRates, MarkupConfig};
+use error_stack::{report, ResultExt};
 use once_cell::sync::Lazy;
 use redis_interface::DelReply;
-use rust_decimal::Decimal;
-use strum::IntoEnumIterator;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use tokio::{sync::RwLock, time::sleep};
 
-use crate::{
-    logger,
-    routes::app::settings::{Conversion, DefaultExchangeRates},
-    services, SessionState,
-};
+use crate::{consts, logger, types::storage, utils, SessionState};
 const REDIX_FOREX_CACHE_KEY: &str = "{forex_cache}_lock";
 const REDIX_FOREX_CACHE_DATA: &str = "{forex_cache}_data";
-const FOREX_API_TIMEOUT: u64 = 5;
-const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
-const FOREX_BASE_CURRENCY: &str = "&base=USD";
-const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
-const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FxExchangeRatesCacheEntry {
     data: Arc<ExchangeRates>,
     timestamp: i64,
+    /// The persisted snapshot of the rates, which conversions using the rates refer to
+    #[serde(default)]
+    snapshot_id: Option<String>,
 }
 
 static FX_EXCHANGE_RATES_CACHE: Lazy<RwLock<Option<FxExchangeRatesCacheEntry>>> =
@@ -71,34 +71,24 @@ pub enum ForexCacheError {
     RedisWriteError,
     #[error("Not able to acquire write lock")]
     WriteLockNotAcquired,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct ForexResponse {
-    pub rates: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct FallbackForexResponse {
-    pub quotes: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
-#[serde(transparent)]
-struct FloatDecimal(#[serde(with = "rust_decimal::serde::float")] Decimal);
-
-impl Deref for FloatDecimal {
-    type Target = Decimal;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+    #[error("Error persisting the rates snapshot")]
+    SnapshotWriteError,
 }
 
 impl FxExchangeRatesCacheEntry {
-    fn new(exchange_rate: ExchangeRates) -> Self {
+    fn new(exchange_rate: ExchangeRates, snapshot_id: Option<String>) -> Self {
         Self {
             data: Arc::new(exchange_rate),
             timestamp: date_time::now_unix_timestamp(),
+            snapshot_id,
+        }
+    }
+    /// Copy of the entry with the timestamp reset, for the local cache
+    fn refreshed(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            timestamp: date_time::now_unix_timestamp(),
+            snapshot_id: self.snapshot_id.clone(),
         }
     }
     fn is_expired(&self, call_delay: i64) -> bool {
@@ -144,32 +134,21 @@ async fn waited_fetch_and_update_caches(
     successive_fetch_and_save_forex(state, None).await
 }
 
-impl TryFrom<DefaultExchangeRates> for ExchangeRates {
+impl TryFrom<storage::FxRateSnapshot> for FxExchangeRatesCacheEntry {
     type Error = error_stack::Report<ForexCacheError>;
-    fn try_from(value: DefaultExchangeRates) -> Result<Self, Self::Error> {
-        let mut conversion_usable: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-        for (curr, conversion) in value.conversion {
-            let enum_curr = enums::Currency::from_str(curr.as_str())
-                .change_context(ForexCacheError::ConversionError)?;
-            conversion_usable.insert(enum_curr, CurrencyFactors::from(conversion));
-        }
-        let base_curr = enums::Currency::from_str(value.base_currency.as_str())
-            .change_context(ForexCacheError::ConversionError)?;
+    fn try_from(value: storage::FxRateSnapshot) -> Result<Self, Self::Error> {
+        let exchange_rates: ExchangeRates = value
+            .rates
+            .parse_value("ExchangeRates")
+            .change_context(ForexCacheError::ParsingError)?;
         Ok(Self {
-            base_currency: base_curr,
-            conversion: conversion_usable,
+            data: Arc::new(exchange_rates),
+            timestamp: value.created_at.assume_utc().unix_timestamp(),
+            snapshot_id: Some(value.snapshot_id),
         })
     }
 }
 
-impl From<Conversion> for CurrencyFactors {
-    fn from(value: Conversion) -> Self {
-        Self {
-            to_factor: value.to_factor,
-            from_factor: value.from_factor,
-        }
-    }
-}
 pub async fn get_forex_rates(
     state: &SessionState,
     call_delay: i64,
@@ -227,18 +206,10 @@ async fn successive_fetch_and_save_forex(
             let api_rates = fetch_forex_rates(state).await;
             match api_rates {
                 Ok(rates) => successive_save_data_to_redis_local(state, rates).await,
-                Err(error) => {
-                    // API not able to fetch data call secondary service
+                Err(error) => stale_redis_data.ok_or({
                     logger::error!(?error);
-                    let secondary_api_rates = fallback_fetch_forex_rates(state).await;
-                    match secondary_api_rates {
-                        Ok(rates) => Ok(successive_save_data_to_redis_local(state, rates).await?),
-                        Err(error) => stale_redis_data.ok_or({
-                            logger::error!(?error);
-                            ForexCacheError::ApiUnresponsive.into()
-                        }),
-                    }
-                }
+                    ForexCacheError::ApiUnresponsive.into()
+                }),
             }
         }
         Err(error) => stale_redis_data.ok_or({
@@ -272,10 +243,9 @@ async fn fallback_forex_redis_check(
     redis_data: FxExchangeRatesCacheEntry,
     call_delay: i64,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
-    match is_redis_expired(Some(redis_data.clone()).as_ref(), call_delay).await {
-        Some(redis_forex) => {
+    match is_redis_expired(Some(&redis_data), call_delay).await {
+        Some(exchange_rates) => {
             // Valid data present in redis
-            let exchange_rates = FxExchangeRatesCacheEntry::new(redis_forex.as_ref().clone());
             save_forex_to_local(exchange_rates.clone()).await?;
             Ok(exchange_rates)
         }
@@ -294,10 +264,8 @@ async fn handler_local_expired(
     match retrieve_forex_from_redis(state).await {
         Ok(redis_data) => {
             match is_redis_expired(redis_data.as_ref(), call_delay).await {
-                Some(redis_forex) => {
+                Some(exchange_rates) => {
                     // Valid data present in redis
-                    let exchange_rates =
-                        FxExchangeRatesCacheEntry::new(redis_forex.as_ref().clone());
                     save_forex_to_local(exchange_rates.clone()).await?;
                     Ok(exchange_rates)
                 }
@@ -315,126 +283,53 @@ async fn handler_local_expired(
     }
 }
 
+/// Fetches the rates from the first configured provider which responds successfully, persisting a
+/// snapshot of the rates
 async fn fetch_forex_rates(
     state: &SessionState,
-) -> Result<FxExchangeRatesCacheEntry, error_stack::Report<ForexCacheError>> {
-    let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
-
-    let forex_url: String = format!("{}{}{}", FOREX_BASE_URL, forex_api_key, FOREX_BASE_CURRENCY);
-    let forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&forex_url)
-        .build();
-
-    logger::info!(?forex_request);
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexCacheError::ApiUnresponsive)?;
-    let forex_response = response
-        .json::<ForexResponse>()
-        .await
-        .change_context(ForexCacheError::ParsingError)?;
-
-    logger::info!("{:?}", forex_response);
-
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match forex_response.rates.get(&enum_curr.to_string()) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!("Rates for {} not received from API", &enum_curr);
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+    for provider in providers::get_forex_rates_providers(state.conf.forex_api.get_inner()) {
+        match provider.fetch_rates(state).await {
+            Ok(rates) => {
+                // Rates are still served if the snapshot could not be persisted, but conversions
+                // using them cannot be reproduced
+                let snapshot_id = save_forex_snapshot(state, provider.name(), &rates)
+                    .await
+                    .map_err(|error| logger::error!(?error))
+                    .ok();
+                return Ok(FxExchangeRatesCacheEntry::new(rates, snapshot_id));
             }
-            None => {
-                logger::error!("Rates for {} not received from API", &enum_curr);
+            Err(error) => {
+                // Provider not able to fetch data, try the next provider
+                logger::error!(provider = provider.name(), ?error);
             }
-        };
+        }
     }
 
-    Ok(FxExchangeRatesCacheEntry::new(ExchangeRates::new(
-        enums::Currency::USD,
-        conversions,
-    )))
+    Err(ForexCacheError::ApiUnresponsive.into())
 }
 
-pub async fn fallback_fetch_forex_rates(
+async fn save_forex_snapshot(
     state: &SessionState,
-) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
-    let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
-
-    let fallback_forex_url: String =
-        format!("{}{}", FALLBACK_FOREX_BASE_URL, fallback_forex_api_key,);
-    let fallback_forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&fallback_forex_url)
-        .build();
-
-    logger::info!(?fallback_forex_request);
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            fallback_forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexCacheError::ApiUnresponsive)?;
-    let fallback_forex_response = response
-        .json::<FallbackForexResponse>()
-        .await
-        .change_context(ForexCacheError::ParsingError)?;
-
-    logger::info!("{:?}", fallback_forex_response);
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match fallback_forex_response.quotes.get(
-            format!(
-                "{}{}",
-                FALLBACK_FOREX_API_CURRENCY_PREFIX,
-                &enum_curr.to_string()
-            )
-            .as_str(),
-        ) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!("Rates for {} not received from API", &enum_curr);
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                logger::error!("Rates for {} not received from API", &enum_curr);
-            }
-        };
-    }
+    provider: &str,
+    rates: &ExchangeRates,
+) -> CustomResult<String, ForexCacheError> {
+    let fx_rate_snapshot = storage::FxRateSnapshotNew {
+        snapshot_id: utils::generate_id(consts::ID_LENGTH, "fxs"),
+        provider: provider.to_owned(),
+        base_currency: rates.base_currency,
+        rates: rates
+            .encode_to_value()
+            .change_context(ForexCacheError::ParsingError)?,
+        created_at: date_time::now(),
+    };
 
-    let rates =
-        FxExchangeRatesCacheEntry::new(ExchangeRates::new(enums::Currency::USD, conversions));
-    match acquire_redis_lock(state).await {
-        Ok(_) => Ok(successive_save_data_to_redis_local(state, rates).await?),
-        Err(e) => {
-            logger::error!(?e);
-            Ok(rates)
-        }
-    }
+    state
+        .store
+        .insert_fx_rate_snapshot(fx_rate_snapshot)
+        .await
+        .change_context(ForexCacheError::SnapshotWriteError)
+        .map(|fx_rate_snapshot| fx_rate_snapshot.snapshot_id)
 }
 
 async fn release_redis_lock(
@@ -499,44 +394,78 @@ async fn retrieve_forex_from_redis(
 async fn is_redis_expired(
     redis_cache: Option<&FxExchangeRatesCacheEntry>,
     call_delay: i64,
-) -> Option<Arc<ExchangeRates>> {
+) -> Option<FxExchangeRatesCacheEntry> {
     redis_cache.and_then(|cache| {
         if cache.timestamp + call_delay > date_time::now_unix_timestamp() {
-            Some(cache.data.clone())
+            Some(cache.refreshed())
         } else {
             None
         }
     })
 }
 
-pub async fn convert_currency(
-    state: SessionState,
+pub fn get_fx_markup_config_key(merchant_id: &common_utils::id_type::MerchantId) -> String {
+    format!("fx_markup_{}", merchant_id.get_string_repr())
+}
+
+/// Converts the amount with the rates and the markup configured for the merchant. The response
+/// records the snapshot of the rates along with the rate applied, allowing the conversion to be
+/// reproduced by specifying the snapshot.
+pub fn convert_currency(
+    rates: &FxExchangeRatesCacheEntry,
+    markup_config: &MarkupConfig,
     amount: i64,
     to_currency: String,
     from_currency: String,
 ) -> CustomResult<api_models::currency::CurrencyConversionResponse, ForexCacheError> {
-    let forex_api = state.conf.forex_api.get_inner();
-    let rates = get_forex_rates(
-        &state,
-        forex_api.call_delay,
-        forex_api.local_fetch_retry_delay,
-        forex_api.local_fetch_retry_count,
-    )
-    .await
-    .change_context(ForexCacheError::ApiError)?;
-
     let to_currency = enums::Currency::from_str(to_currency.as_str())
         .change_context(ForexCacheError::CurrencyNotAcceptable)?;
 
     let from_currency = enums::Currency::from_str(from_currency.as_str())
         .change_context(ForexCacheError::CurrencyNotAcceptable)?;
 
-    let converted_amount =
-        currency_conversion::conversion::convert(&rates.data, from_currency, to_currency, amount)
-            .change_context(ForexCacheError::ConversionError)?;
+    let conversion = currency_conversion::conversion::convert_with_markup(
+        &rates.data,
+        from_currency,
+        to_currency,
+        amount,
+        markup_config.get_markup_bps(from_currency, to_currency),
+    )
+    .change_context(ForexCacheError::ConversionError)?;
 
     Ok(api_models::currency::CurrencyConversionResponse {
-        converted_amount: converted_amount.to_string(),
+        converted_amount: conversion.converted_amount.to_string(),
         currency: to_currency.to_string(),
+        snapshot_id: rates.snapshot_id.clone(),
+        market_rate: conversion.market_rate.to_string(),
+        markup_bps: conversion.markup_bps,
+        applied_rate: conversion.applied_rate.to_string(),
     })
 }
+
+/// Converts the amount in the lowest denomination of `from_currency` to the lowest denomination of
+/// `to_currency` at the market rate, rounded to the nearest unit
+pub fn convert_minor_amount(
+    rates: &FxExchangeRatesCacheEntry,
+    amount: MinorUnit,
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+) -> CustomResult<MinorUnit, ForexCacheError> {
+    let converted_amount = currency_conversion::conversion::convert(
+        &rates.data,
+        from_currency,
+        to_currency,
+        amount.get_amount_as_i64(),
+    )
+    .change_context(ForexCacheError::ConversionError)?;
+
+    10_i64
+        .checked_pow(u32::from(
+            to_currency.number_of_digits_after_decimal_point(),
+        ))
+        .and_then(|minor_units| converted_amount.checked_mul(Decimal::from(minor_units)))
+        .and_then(|converted_amount| converted_amount.round().to_i64())
+        .map(MinorUnit::new)
+        .ok_or_else(|| report!(ForexCacheError::ConversionError))
+        .attach_printable("Converted amount is out of range")
+}
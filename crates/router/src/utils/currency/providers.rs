@@ -0,0 +1,247 @@
+use std::{collections::HashMap, ops::Deref, str::FromStr};
+
+use api_models::enums;
+use common_utils::{errors::CustomResult, ext_traits::ByteSliceExt};
+use currency_conversion::types::{CurrencyFactors, ExchangeRates};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use rust_decimal::Decimal;
+use strum::IntoEnumIterator;
+
+use super::ForexCacheError;
+use crate::{
+    logger,
+    routes::app::settings::{Conversion, DefaultExchangeRates, ForexApi, ForexProvider},
+    services, SessionState,
+};
+
+const FOREX_API_TIMEOUT: u64 = 5;
+const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
+const FOREX_BASE_CURRENCY: &str = "&base=USD";
+const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
+const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
+
+/// A source of exchange rates
+#[async_trait::async_trait]
+pub trait ForexRatesProvider: Send + Sync {
+    /// The name of the provider, recorded on the rate snapshots fetched from it
+    fn name(&self) -> &'static str;
+
+    async fn fetch_rates(
+        &self,
+        state: &SessionState,
+    ) -> CustomResult<ExchangeRates, ForexCacheError>;
+}
+
+/// Returns the configured providers, in the order in which they are to be tried
+pub fn get_forex_rates_providers(forex_api: &ForexApi) -> Vec<Box<dyn ForexRatesProvider>> {
+    forex_api
+        .providers
+        .iter()
+        .filter_map(|provider| -> Option<Box<dyn ForexRatesProvider>> {
+            match provider {
+                ForexProvider::OpenExchangeRates => Some(Box::new(OpenExchangeRates)),
+                ForexProvider::ApiLayer => Some(Box::new(ApiLayer)),
+                // The path is validated to be present when the provider is configured
+                ForexProvider::StaticFile => forex_api
+                    .static_rates_file
+                    .clone()
+                    .map(|path| -> Box<dyn ForexRatesProvider> { Box::new(StaticFile { path }) }),
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct ForexResponse {
+    pub rates: HashMap<String, FloatDecimal>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct FallbackForexResponse {
+    pub quotes: HashMap<String, FloatDecimal>,
+}
+
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+struct FloatDecimal(#[serde(with = "rust_decimal::serde::float")] Decimal);
+
+impl Deref for FloatDecimal {
+    type Target = Decimal;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Rates from openexchangerates, with USD as the base currency
+pub struct OpenExchangeRates;
+
+#[async_trait::async_trait]
+impl ForexRatesProvider for OpenExchangeRates {
+    fn name(&self) -> &'static str {
+        "open_exchange_rates"
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &SessionState,
+    ) -> CustomResult<ExchangeRates, ForexCacheError> {
+        let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
+        let forex_url: String =
+            format!("{}{}{}", FOREX_BASE_URL, forex_api_key, FOREX_BASE_CURRENCY);
+
+        let forex_response = send_forex_request(state, &forex_url)
+            .await?
+            .json::<ForexResponse>()
+            .await
+            .change_context(ForexCacheError::ParsingError)?;
+
+        logger::info!("{:?}", forex_response);
+
+        Ok(get_usd_based_exchange_rates(|currency| {
+            forex_response.rates.get(&currency.to_string()).copied()
+        }))
+    }
+}
+
+/// Rates from apilayer, with USD as the base currency
+pub struct ApiLayer;
+
+#[async_trait::async_trait]
+impl ForexRatesProvider for ApiLayer {
+    fn name(&self) -> &'static str {
+        "api_layer"
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &SessionState,
+    ) -> CustomResult<ExchangeRates, ForexCacheError> {
+        let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
+        let fallback_forex_url: String =
+            format!("{}{}", FALLBACK_FOREX_BASE_URL, fallback_forex_api_key);
+
+        let fallback_forex_response = send_forex_request(state, &fallback_forex_url)
+            .await?
+            .json::<FallbackForexResponse>()
+            .await
+            .change_context(ForexCacheError::ParsingError)?;
+
+        logger::info!("{:?}", fallback_forex_response);
+
+        Ok(get_usd_based_exchange_rates(|currency| {
+            fallback_forex_response
+                .quotes
+                .get(format!("{}{}", FALLBACK_FOREX_API_CURRENCY_PREFIX, currency).as_str())
+                .copied()
+        }))
+    }
+}
+
+/// Rates read from a JSON file, intended for offline and test environments. Unlike the other
+/// providers, the file may specify rates for currency pairs directly.
+pub struct StaticFile {
+    path: String,
+}
+
+#[async_trait::async_trait]
+impl ForexRatesProvider for StaticFile {
+    fn name(&self) -> &'static str {
+        "static_file"
+    }
+
+    async fn fetch_rates(
+        &self,
+        _state: &SessionState,
+    ) -> CustomResult<ExchangeRates, ForexCacheError> {
+        let contents = tokio::fs::read(&self.path)
+            .await
+            .change_context(ForexCacheError::LocalReadError)
+            .attach_printable_lazy(|| format!("Failed to read forex rates from {}", self.path))?;
+
+        contents
+            .parse_struct::<DefaultExchangeRates>("DefaultExchangeRates")
+            .change_context(ForexCacheError::ParsingError)?
+            .try_into()
+    }
+}
+
+async fn send_forex_request(
+    state: &SessionState,
+    url: &str,
+) -> CustomResult<reqwest::Response, ForexCacheError> {
+    let forex_request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(url)
+        .build();
+
+    logger::info!(?forex_request);
+    state
+        .api_client
+        .send_request(
+            &state.clone(),
+            forex_request,
+            Some(FOREX_API_TIMEOUT),
+            false,
+        )
+        .await
+        .change_context(ForexCacheError::ApiUnresponsive)
+}
+
+fn get_usd_based_exchange_rates(
+    get_rate: impl Fn(enums::Currency) -> Option<FloatDecimal>,
+) -> ExchangeRates {
+    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
+    for enum_curr in enums::Currency::iter() {
+        match get_rate(enum_curr) {
+            Some(rate) => {
+                let from_factor = match Decimal::new(1, 0).checked_div(*rate) {
+                    Some(rate) => rate,
+                    None => {
+                        logger::error!("Rates for {} not received from API", &enum_curr);
+                        continue;
+                    }
+                };
+                let currency_factors = CurrencyFactors::new(*rate, from_factor);
+                conversions.insert(enum_curr, currency_factors);
+            }
+            None => {
+                logger::error!("Rates for {} not received from API", &enum_curr);
+            }
+        };
+    }
+
+    ExchangeRates::new(enums::Currency::USD, conversions)
+}
+
+impl TryFrom<DefaultExchangeRates> for ExchangeRates {
+    type Error = error_stack::Report<ForexCacheError>;
+    fn try_from(value: DefaultExchangeRates) -> Result<Self, Self::Error> {
+        let mut conversion_usable: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
+        for (curr, conversion) in value.conversion {
+            let enum_curr = enums::Currency::from_str(curr.as_str())
+                .change_context(ForexCacheError::ConversionError)?;
+            conversion_usable.insert(enum_curr, CurrencyFactors::from(conversion));
+        }
+        let base_curr = enums::Currency::from_str(value.base_currency.as_str())
+            .change_context(ForexCacheError::ConversionError)?;
+        let mut exchange_rates = Self::new(base_curr, conversion_usable);
+        for direct_rate in value.direct_rates {
+            let from_currency = enums::Currency::from_str(direct_rate.from_currency.as_str())
+                .change_context(ForexCacheError::ConversionError)?;
+            let to_currency = enums::Currency::from_str(direct_rate.to_currency.as_str())
+                .change_context(ForexCacheError::ConversionError)?;
+            exchange_rates.insert_direct_rate(from_currency, to_currency, direct_rate.rate);
+        }
+        Ok(exchange_rates)
+    }
+}
+
+impl From<Conversion> for CurrencyFactors {
+    fn from(value: Conversion) -> Self {
+        Self {
+            to_factor: value.to_factor,
+            from_factor: value.from_factor,
+        }
+    }
+}
@@ -83,6 +83,45 @@ where
     }
 }
 
+/// Combines the rows of Redis and of the database like [`find_all_combined_kv_database`], leaving
+/// out the rows of the database deleted in Redis whose deletion has not been drained yet. The
+/// database call is given the limit raised by the number of deleted rows, so that leaving them
+/// out does not return fewer rows than asked for.
+pub async fn find_all_combined_kv_database_without_deleted<F, RFut, DFut, T>(
+    redis_fut: RFut,
+    deleted_unique_keys: HashSet<String>,
+    database_call: F,
+    limit: Option<i64>,
+) -> error_stack::Result<Vec<T>, errors::StorageError>
+where
+    T: UniqueConstraints,
+    F: FnOnce(Option<i64>) -> DFut,
+    RFut:
+        futures::Future<Output = error_stack::Result<Vec<T>, redis_interface::errors::RedisError>>,
+    DFut: futures::Future<Output = error_stack::Result<Vec<T>, errors::StorageError>>,
+{
+    let database_limit = limit.map(|limit| {
+        i64::try_from(deleted_unique_keys.len()).map_or(i64::MAX, |deleted_count| {
+            limit.saturating_add(deleted_count)
+        })
+    });
+    let database_call_without_deleted = || async {
+        database_call(database_limit).await.map(|db_rows| {
+            db_rows
+                .into_iter()
+                .filter(|row| !deleted_unique_keys.contains(&row.unique_constraints().concat()))
+                .collect()
+        })
+    };
+
+    let mut rows =
+        find_all_combined_kv_database(redis_fut, database_call_without_deleted, limit).await?;
+    if let Some(limit) = limit.and_then(|limit| usize::try_from(limit).ok()) {
+        rows.truncate(limit);
+    }
+    Ok(rows)
+}
+
 use std::collections::HashSet;
 
 use storage_impl::UniqueConstraints;
@@ -106,3 +145,87 @@ where
 
     kv_rows
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use error_stack::report;
+    use redis_interface::errors::RedisError;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Row {
+        id: &'static str,
+    }
+
+    #[async_trait::async_trait]
+    impl UniqueConstraints for Row {
+        fn unique_constraints(&self) -> Vec<String> {
+            vec![format!("row_{}", self.id)]
+        }
+        fn table_name(&self) -> &str {
+            "Row"
+        }
+    }
+
+    fn rows(ids: &[&'static str]) -> Vec<Row> {
+        ids.iter().map(|id| Row { id }).collect()
+    }
+
+    async fn redis_rows(ids: &[&'static str]) -> error_stack::Result<Vec<Row>, RedisError> {
+        if ids.is_empty() {
+            Err(report!(RedisError::NotFound))
+        } else {
+            Ok(rows(ids))
+        }
+    }
+
+    async fn database_rows(
+        ids: &[&'static str],
+    ) -> error_stack::Result<Vec<Row>, errors::StorageError> {
+        Ok(rows(ids))
+    }
+
+    #[actix_rt::test]
+    async fn test_combined_rows_leave_out_rows_deleted_in_redis() {
+        let deleted_unique_keys = HashSet::from(["row_2".to_string()]);
+
+        let combined_rows = find_all_combined_kv_database_without_deleted(
+            redis_rows(&["1"]),
+            deleted_unique_keys.clone(),
+            |_| database_rows(&["1", "2", "3"]),
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(combined_rows, rows(&["1", "3"]));
+
+        // Rows deleted in Redis are left out when only tombstones are left in Redis as well
+        let combined_rows = find_all_combined_kv_database_without_deleted(
+            redis_rows(&[]),
+            deleted_unique_keys,
+            |_| database_rows(&["2", "3"]),
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(combined_rows, rows(&["3"]));
+    }
+
+    #[actix_rt::test]
+    async fn test_deleted_rows_do_not_count_towards_the_limit() {
+        let combined_rows = find_all_combined_kv_database_without_deleted(
+            redis_rows(&[]),
+            HashSet::from(["row_1".to_string()]),
+            |limit| {
+                assert_eq!(limit, Some(3));
+                database_rows(&["1", "2", "3"])
+            },
+            Some(2),
+        )
+        .await
+        .unwrap();
+        assert_eq!(combined_rows, rows(&["2", "3"]));
+    }
+}
@@ -59,6 +59,57 @@ impl From<Permission> for user_role_api::Permission {
     }
 }
 
+impl From<user_role_api::Permission> for Permission {
+    fn from(value: user_role_api::Permission) -> Self {
+        match value {
+            user_role_api::Permission::PaymentRead => Self::PaymentRead,
+            user_role_api::Permission::PaymentWrite => Self::PaymentWrite,
+            user_role_api::Permission::RefundRead => Self::RefundRead,
+            user_role_api::Permission::RefundWrite => Self::RefundWrite,
+            user_role_api::Permission::ApiKeyRead => Self::ApiKeyRead,
+            user_role_api::Permission::ApiKeyWrite => Self::ApiKeyWrite,
+            user_role_api::Permission::MerchantAccountRead => Self::MerchantAccountRead,
+            user_role_api::Permission::MerchantAccountWrite => Self::MerchantAccountWrite,
+            user_role_api::Permission::MerchantConnectorAccountRead => {
+                Self::MerchantConnectorAccountRead
+            }
+            user_role_api::Permission::MerchantConnectorAccountWrite => {
+                Self::MerchantConnectorAccountWrite
+            }
+            user_role_api::Permission::RoutingRead => Self::RoutingRead,
+            user_role_api::Permission::RoutingWrite => Self::RoutingWrite,
+            user_role_api::Permission::DisputeRead => Self::DisputeRead,
+            user_role_api::Permission::DisputeWrite => Self::DisputeWrite,
+            user_role_api::Permission::MandateRead => Self::MandateRead,
+            user_role_api::Permission::MandateWrite => Self::MandateWrite,
+            user_role_api::Permission::CustomerRead => Self::CustomerRead,
+            user_role_api::Permission::CustomerWrite => Self::CustomerWrite,
+            user_role_api::Permission::Analytics => Self::Analytics,
+            user_role_api::Permission::ThreeDsDecisionManagerWrite => {
+                Self::ThreeDsDecisionManagerWrite
+            }
+            user_role_api::Permission::ThreeDsDecisionManagerRead => {
+                Self::ThreeDsDecisionManagerRead
+            }
+            user_role_api::Permission::SurchargeDecisionManagerWrite => {
+                Self::SurchargeDecisionManagerWrite
+            }
+            user_role_api::Permission::SurchargeDecisionManagerRead => {
+                Self::SurchargeDecisionManagerRead
+            }
+            user_role_api::Permission::UsersRead => Self::UsersRead,
+            user_role_api::Permission::UsersWrite => Self::UsersWrite,
+            user_role_api::Permission::MerchantAccountCreate => Self::MerchantAccountCreate,
+            user_role_api::Permission::WebhookEventRead => Self::WebhookEventRead,
+            user_role_api::Permission::WebhookEventWrite => Self::WebhookEventWrite,
+            user_role_api::Permission::PayoutRead => Self::PayoutRead,
+            user_role_api::Permission::PayoutWrite => Self::PayoutWrite,
+            user_role_api::Permission::GenerateReport => Self::GenerateReport,
+            user_role_api::Permission::ReconAdmin => Self::ReconAdmin,
+        }
+    }
+}
+
 pub fn validate_role_groups(groups: &[PermissionGroup]) -> UserResult<()> {
     if groups.is_empty() {
         return Err(report!(UserErrors::InvalidRoleOperation))
@@ -3,6 +3,8 @@ pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
 #[cfg(feature = "v1")]
+pub mod dispute_sync;
+#[cfg(feature = "v1")]
 pub mod outgoing_webhook_retry;
 #[cfg(feature = "v1")]
 pub mod payment_method_status_update;
@@ -10,4 +12,6 @@ pub mod payment_sync;
 #[cfg(feature = "v1")]
 pub mod refund_router;
 #[cfg(feature = "v1")]
+pub mod subscription_billing;
+#[cfg(feature = "v1")]
 pub mod tokenized_data;
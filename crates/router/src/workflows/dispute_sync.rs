@@ -0,0 +1,150 @@
+use common_utils::ext_traits::{StringExt, ValueExt};
+use error_stack::ResultExt;
+use router_env::tracing::{self, instrument};
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    types::process_data,
+    utils as scheduler_utils,
+};
+
+use crate::{
+    core::disputes,
+    db::StorageInterface,
+    errors, logger,
+    routes::SessionState,
+    types::storage::{self, DisputeSyncTrackingData},
+};
+
+pub struct DisputeSyncWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DisputeSyncWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: DisputeSyncTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeSyncTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await?;
+
+        // The dispute may have been closed by an incoming webhook since the last sync
+        let dispute = if disputes::should_sync_dispute(&dispute.connector, dispute.dispute_status) {
+            match Box::pin(disputes::sync_dispute_with_connector(
+                state,
+                merchant_account,
+                &key_store,
+                dispute.clone(),
+            ))
+            .await
+            {
+                Ok(updated_dispute) => updated_dispute,
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        dispute_id = %dispute.dispute_id,
+                        "Failed to sync dispute with connector, will be retried in the next sync"
+                    );
+                    dispute
+                }
+            }
+        } else {
+            dispute
+        };
+
+        disputes::update_dispute_sync_task(db, process, &dispute).await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Get the schedule time for the next sync of an open dispute, based on the number of syncs that
+/// have already been made. Returns `None` once the maximum number of syncs has been reached.
+///
+/// The schedule time can be configured in configs with this key: `pt_mapping_dispute_sync`.
+///
+/// ```json
+/// {
+///   "default_mapping": {
+///     "start_after": 86400,
+///     "frequencies": [[86400, 120]]
+///   },
+///   "custom_merchant_mapping": {
+///     "merchant_id1": {
+///       "start_after": 3600,
+///       "frequencies": [[43200, 240]]
+///     }
+///   }
+/// }
+/// ```
+#[instrument(skip_all)]
+pub(crate) async fn get_dispute_sync_schedule_time(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let key = "pt_mapping_dispute_sync";
+    let result = db
+        .find_config_by_key(key)
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("DisputeSyncProcessTrackerMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+    let mapping = result.map_or_else(
+        |error| {
+            if error.current_context().is_db_not_found() {
+                logger::debug!("Dispute sync config `{key}` not found, ignoring");
+            } else {
+                logger::error!(?error, "Failed to read dispute sync config `{key}`");
+            }
+            process_data::DisputeSyncProcessTrackerMapping::default()
+        },
+        |mapping| {
+            logger::debug!(?mapping, "Using custom dispute sync config");
+            mapping
+        },
+    );
+
+    let time_delta =
+        scheduler_utils::get_dispute_sync_schedule_time(mapping, merchant_id, retry_count);
+
+    scheduler_utils::get_time_from_delta(time_delta)
+}
@@ -65,13 +65,6 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             )
             .await?;
 
-        let event_id = webhooks_core::utils::generate_event_id();
-        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
-            &tracking_data.primary_object_id,
-            tracking_data.event_type,
-            delivery_attempt,
-        );
-
         let initial_event = match &tracking_data.initial_attempt_id {
             Some(initial_attempt_id) => {
                 db.find_event_by_merchant_id_event_id(
@@ -99,25 +92,14 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             }
         };
 
-        let now = common_utils::date_time::now();
-        let new_event = domain::Event {
-            event_id,
-            event_type: initial_event.event_type,
-            event_class: initial_event.event_class,
-            is_webhook_notified: false,
-            primary_object_id: initial_event.primary_object_id,
-            primary_object_type: initial_event.primary_object_type,
-            created_at: now,
-            merchant_id: Some(business_profile.merchant_id.clone()),
-            business_profile_id: Some(business_profile.get_id().to_owned()),
-            primary_object_created_at: initial_event.primary_object_created_at,
-            idempotent_event_id: Some(idempotent_event_id),
-            initial_attempt_id: Some(initial_event.event_id.clone()),
-            request: initial_event.request,
-            response: None,
-            delivery_attempt: Some(delivery_attempt),
-            metadata: initial_event.metadata,
-        };
+        let initial_attempt_id = Some(initial_event.event_id.clone());
+        let new_event = webhooks_core::utils::get_retry_event(
+            initial_event,
+            initial_attempt_id,
+            business_profile.merchant_id.clone(),
+            business_profile.get_id().to_owned(),
+            delivery_attempt,
+        );
 
         let event = db
             .insert_event(key_manager_state, new_event, &key_store)
@@ -182,6 +164,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             &merchant_account,
                             outgoing_webhook,
                             &business_profile,
+                            None,
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -346,6 +329,7 @@ async fn get_outgoing_webhook_content_and_event_type(
         mandates::MandateId,
         payments::{HeaderPayload, PaymentIdType, PaymentsResponse, PaymentsRetrieveRequest},
         refunds::{RefundResponse, RefundsRetrieveRequest},
+        subscription::SubscriptionResponse,
     };
 
     use crate::{
@@ -517,6 +501,27 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::Subscriptions => {
+            // Subscription events are always stored along with their request content, the current
+            // state of the subscription is only used as a fallback
+            let subscription = state
+                .store
+                .find_subscription_by_merchant_id_subscription_id(
+                    &tracking_data.merchant_id,
+                    &tracking_data.primary_object_id,
+                )
+                .await?;
+            let event_type = Option::<EventType>::foreign_from(subscription.status);
+            logger::debug!(current_resource_status=%subscription.status);
+
+            Ok((
+                OutgoingWebhookContent::SubscriptionDetails(Box::new(
+                    SubscriptionResponse::foreign_from(subscription),
+                )),
+                event_type,
+            ))
+        }
         #[cfg(feature = "payouts")]
         diesel_models::enums::EventClass::Payouts => {
             let payout_id = tracking_data.primary_object_id.clone();
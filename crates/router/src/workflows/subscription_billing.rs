@@ -0,0 +1,141 @@
+use common_utils::ext_traits::{StringExt, ValueExt};
+use error_stack::ResultExt;
+use router_env::tracing::{self, instrument};
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    types::process_data,
+    utils as scheduler_utils,
+};
+
+use crate::{
+    core::subscriptions,
+    db::StorageInterface,
+    errors, logger,
+    routes::SessionState,
+    types::storage::{self, SubscriptionBillingTrackingData},
+};
+
+pub struct SubscriptionBillingWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SubscriptionBillingWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: SubscriptionBillingTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SubscriptionBillingTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let subscription = db
+            .find_subscription_by_merchant_id_subscription_id(
+                &tracking_data.merchant_id,
+                &tracking_data.subscription_id,
+            )
+            .await?;
+
+        let (_, task_schedule) = Box::pin(subscriptions::perform_subscription_billing(
+            state,
+            merchant_account,
+            key_store,
+            subscription,
+        ))
+        .await?;
+
+        subscriptions::update_billing_task(db, process, task_schedule).await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Get the schedule time for retrying the payment of a billing period, based on the number of
+/// retries that have already been made. Returns `None` once the retries are exhausted, after which
+/// the subscription is cancelled.
+///
+/// The schedule time can be configured in configs with this key: `pt_mapping_subscription_billing`.
+///
+/// ```json
+/// {
+///   "default_mapping": {
+///     "start_after": 86400,
+///     "frequencies": [[259200, 2]]
+///   },
+///   "custom_merchant_mapping": {
+///     "merchant_id1": {
+///       "start_after": 3600,
+///       "frequencies": [[86400, 5]]
+///     }
+///   }
+/// }
+/// ```
+#[instrument(skip_all)]
+pub(crate) async fn get_billing_retry_schedule_time(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let key = "pt_mapping_subscription_billing";
+    let result = db
+        .find_config_by_key(key)
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("SubscriptionBillingRetryProcessTrackerMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+    let mapping = result.map_or_else(
+        |error| {
+            if error.current_context().is_db_not_found() {
+                logger::debug!("Subscription billing retry config `{key}` not found, ignoring");
+            } else {
+                logger::error!(
+                    ?error,
+                    "Failed to read subscription billing retry config `{key}`"
+                );
+            }
+            process_data::SubscriptionBillingRetryProcessTrackerMapping::default()
+        },
+        |mapping| {
+            logger::debug!(?mapping, "Using custom subscription billing retry config");
+            mapping
+        },
+    );
+
+    let time_delta = scheduler_utils::get_subscription_billing_retry_schedule_time(
+        mapping,
+        merchant_id,
+        retry_count,
+    );
+
+    scheduler_utils::get_time_from_delta(time_delta)
+}
@@ -55,7 +55,7 @@ pub enum Tag {
 }
 
 /// API Flow
-#[derive(Debug, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq, EnumString)]
 pub enum Flow {
     /// Health check
     HealthCheck,
@@ -87,8 +87,16 @@ pub enum Flow {
     MerchantConnectorsDelete,
     /// Merchant Connectors list flow.
     MerchantConnectorsList,
+    /// Connector fee schedule create or update flow.
+    ConnectorFeeScheduleUpsert,
+    /// Connector fee schedule retrieve flow.
+    ConnectorFeeScheduleRetrieve,
+    /// Connector fee schedule delete flow.
+    ConnectorFeeScheduleDelete,
     /// Merchant Transfer Keys
     MerchantTransferKey,
+    /// Merchant storage scheme migration flow.
+    MerchantStorageSchemeMigration,
     /// ConfigKey create flow.
     ConfigKeyCreate,
     /// ConfigKey fetch flow.
@@ -117,6 +125,26 @@ pub enum Flow {
     MandatesRevoke,
     /// Mandates list flow.
     MandatesList,
+    /// Plans create flow.
+    PlansCreate,
+    /// Plans retrieve flow.
+    PlansRetrieve,
+    /// Plans list flow.
+    PlansList,
+    /// Plans update flow.
+    PlansUpdate,
+    /// Plans deactivate flow.
+    PlansDeactivate,
+    /// Subscriptions create flow.
+    SubscriptionsCreate,
+    /// Subscriptions retrieve flow.
+    SubscriptionsRetrieve,
+    /// Subscriptions list flow.
+    SubscriptionsList,
+    /// Subscriptions update flow.
+    SubscriptionsUpdate,
+    /// Subscriptions cancel flow.
+    SubscriptionsCancel,
     /// Payment methods create flow.
     PaymentMethodsCreate,
     /// Payment methods migrate flow.
@@ -216,6 +244,8 @@ pub enum Flow {
     RefundsAggregate,
     // Retrieve forex flow.
     RetrieveForexFlow,
+    /// Retrieve forex rate snapshot flow.
+    RetrieveForexSnapshot,
     /// Toggles recon service for a merchant.
     ReconMerchantUpdate,
     /// Recon token request flow.
@@ -226,12 +256,34 @@ pub enum Flow {
     ReconVerifyToken,
     /// Routing create flow,
     RoutingCreateConfig,
+    /// Routing create config from the routing DSL
+    RoutingCreateDslConfig,
     /// Routing link config
     RoutingLinkConfig,
     /// Routing link config
     RoutingUnlinkConfig,
+    /// Routing link config as the shadow algorithm
+    RoutingLinkShadowConfig,
+    /// Routing unlink the shadow algorithm
+    RoutingUnlinkShadowConfig,
+    /// Routing link config as the refund or payout retry algorithm
+    RoutingLinkFallbackConfig,
+    /// Routing unlink the refund or payout retry algorithm
+    RoutingUnlinkFallbackConfig,
     /// Routing retrieve config
     RoutingRetrieveConfig,
+    /// Routing retrieve config in the routing DSL
+    RoutingRetrieveDslConfig,
+    /// Routing simulate algorithm against historical payments
+    RoutingSimulate,
+    /// Routing explain the eligibility of connectors for a payment
+    RoutingExplainEligibility,
+    /// Routing retrieve the activation history of a profile
+    RoutingRetrieveActivationHistory,
+    /// Routing compare two configs
+    RoutingDiffConfigs,
+    /// Routing roll back to the previously active config
+    RoutingRollbackConfig,
     /// Routing retrieve active config
     RoutingRetrieveActiveConfig,
     /// Routing retrieve default config
@@ -248,6 +300,10 @@ pub enum Flow {
     ToggleDynamicRouting,
     /// Update dynamic routing config
     UpdateDynamicRoutingConfigs,
+    /// Retrieve the elimination state of the connectors of a profile
+    EliminationStatusRetrieve,
+    /// Eliminate or restore a connector of a profile
+    EliminationStateOverride,
     /// Add record to blocklist
     AddToBlocklist,
     /// Delete record from blocklist
@@ -276,6 +332,8 @@ pub enum Flow {
     DisputesList,
     /// Dispute Filters flow
     DisputesFilters,
+    /// Dispute Sync flow
+    DisputesSync,
     /// Cards Info flow
     CardsInfo,
     /// Create File flow
@@ -496,6 +554,22 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Webhook endpoint create flow
+    WebhookEndpointCreate,
+    /// Webhook endpoint list flow
+    WebhookEndpointList,
+    /// Webhook endpoint retrieve flow
+    WebhookEndpointRetrieve,
+    /// Webhook endpoint update flow
+    WebhookEndpointUpdate,
+    /// Webhook endpoint delete flow
+    WebhookEndpointDelete,
+    /// List incoming webhook events flow
+    IncomingWebhookEventList,
+    /// Retrieve incoming webhook event flow
+    IncomingWebhookEventRetrieve,
+    /// Replay incoming webhook event flow
+    IncomingWebhookEventReplay,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
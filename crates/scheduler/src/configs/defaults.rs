@@ -7,6 +7,7 @@ impl Default for super::settings::SchedulerSettings {
             graceful_shutdown_interval: 60000,
             loop_interval: 5000,
             server: super::settings::Server::default(),
+            recurring_jobs: std::collections::HashMap::new(),
         }
     }
 }
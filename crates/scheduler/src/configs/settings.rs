@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+
+use diesel_models::process_tracker::{CatchUpPolicy, ProcessTrackerRunner};
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use serde::Deserialize;
 
@@ -10,6 +13,8 @@ pub struct SchedulerSettings {
     pub loop_interval: u64,
     pub graceful_shutdown_interval: u64,
     pub server: Server,
+    /// Recurring jobs seeded by the producer, keyed by the name of their task
+    pub recurring_jobs: HashMap<String, RecurringJob>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -37,3 +42,19 @@ pub struct ConsumerSettings {
     pub disabled: bool,
     pub consumer_group: String,
 }
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecurringJob {
+    pub runner: ProcessTrackerRunner,
+    /// Cron expression of the schedule of the job, in UTC
+    pub cron: String,
+    #[serde(default)]
+    pub catch_up_policy: CatchUpPolicy,
+    #[serde(default)]
+    pub tag: Vec<String>,
+    #[serde(default)]
+    pub tracking_data: serde_json::Value,
+    /// Stops seeding the job, leaving its already scheduled occurrence as is
+    #[serde(default)]
+    pub disabled: bool,
+}
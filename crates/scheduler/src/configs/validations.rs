@@ -21,6 +21,30 @@ impl super::settings::SchedulerSettings {
 
         self.server.validate()?;
 
+        self.recurring_jobs
+            .iter()
+            .try_for_each(|(name, job)| job.validate(name))?;
+
+        Ok(())
+    }
+}
+
+impl super::settings::RecurringJob {
+    pub fn validate(&self, name: &str) -> Result<(), ApplicationError> {
+        // The name of the job is stored as the name of its process tracker entries
+        common_utils::fp_utils::when(name.is_empty() || name.len() > 64, || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "recurring job name `{name}` must be between 1 and 64 characters long"
+            )))
+        })?;
+
+        crate::cron::CronSchedule::parse(&self.cron).map_err(|_| {
+            ApplicationError::InvalidConfigurationValueError(format!(
+                "invalid cron expression `{}` for the recurring job {name}",
+                self.cron
+            ))
+        })?;
+
         Ok(())
     }
 }
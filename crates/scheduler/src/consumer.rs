@@ -25,7 +25,8 @@ use super::env::logger;
 pub use super::workflows::ProcessTrackerWorkflow;
 use crate::{
     configs::settings::SchedulerSettings, db::process_tracker::ProcessTrackerInterface, errors,
-    metrics, utils as pt_utils, SchedulerAppState, SchedulerInterface, SchedulerSessionState,
+    metrics, recurring, utils as pt_utils, SchedulerAppState, SchedulerInterface,
+    SchedulerSessionState,
 };
 
 // Valid consumer business statuses
@@ -238,6 +239,20 @@ where
 {
     tracing::Span::current().record("workflow_id", Uuid::new_v4().to_string());
     logger::info!(pt.name=?process.name, pt.id=%process.id);
+
+    if recurring::is_skipped_occurrence(&process, common_utils::date_time::now()) {
+        logger::info!(pt.id=%process.id, "Skipping missed occurrence of recurring task");
+        return state
+            .get_db()
+            .as_scheduler()
+            .finish_process_with_business_status(
+                process,
+                storage::business_status::MISSED_OCCURRENCE_SKIPPED,
+            )
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+    }
+
     let res = workflow_selector
         .trigger_workflow(&state.clone(), process.clone())
         .await
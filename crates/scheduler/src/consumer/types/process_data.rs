@@ -82,3 +82,57 @@ impl Default for OutgoingWebhookRetryProcessTrackerMapping {
         }
     }
 }
+
+/// Configuration for retrying failed subscription payments (dunning).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubscriptionBillingRetryProcessTrackerMapping {
+    /// Default (fallback) retry configuration used when no merchant-specific retry configuration
+    /// exists.
+    pub default_mapping: RetryMapping,
+
+    /// Merchant-specific retry configuration.
+    pub custom_merchant_mapping: HashMap<common_utils::id_type::MerchantId, RetryMapping>,
+}
+
+impl Default for SubscriptionBillingRetryProcessTrackerMapping {
+    fn default() -> Self {
+        Self {
+            default_mapping: RetryMapping {
+                // 1st retry happens a day after the payment for the billing period failed
+                start_after: 60 * 60 * 24,
+
+                // 2nd and 3rd retries happen at intervals of 3 days each, after which the
+                // subscription is cancelled
+                frequencies: vec![(60 * 60 * 24 * 3, 2)],
+            },
+            custom_merchant_mapping: HashMap::new(),
+        }
+    }
+}
+
+/// Configuration for periodically syncing open disputes with the connector.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DisputeSyncProcessTrackerMapping {
+    /// Default (fallback) sync configuration used when no merchant-specific sync configuration
+    /// exists.
+    pub default_mapping: RetryMapping,
+
+    /// Merchant-specific sync configuration.
+    pub custom_merchant_mapping: HashMap<common_utils::id_type::MerchantId, RetryMapping>,
+}
+
+impl Default for DisputeSyncProcessTrackerMapping {
+    fn default() -> Self {
+        Self {
+            default_mapping: RetryMapping {
+                // 1st sync happens a day after the dispute is created
+                start_after: 60 * 60 * 24,
+
+                // Subsequent syncs happen once a day for 120 days, which covers the response
+                // window and the review period of the major card networks
+                frequencies: vec![(60 * 60 * 24, 120)],
+            },
+            custom_merchant_mapping: HashMap::new(),
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Cron expressions for recurring tasks
+//!
+//! Expressions have the five standard fields `minute hour day-of-month month day-of-week`, and are
+//! evaluated in UTC. A field is either `*`, a value, a range such as `1-5` or a comma separated
+//! list of these, optionally followed by a step such as `*/15` or `10-50/20`. The days of the week
+//! range from 0 to 7, with both 0 and 7 being Sunday. As with cron, when both the day of the month
+//! and the day of the week are restricted, a day matches when either of them matches.
+
+use common_utils::errors::CustomResult;
+use error_stack::report;
+use time::{Date, PrimitiveDateTime, Time};
+
+use crate::errors;
+
+/// Occurrences are looked up across a full cycle of leap years, so that expressions which only
+/// occur on February 29 are still found
+const MAX_LOOKAHEAD_DAYS: u16 = 4 * 366;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    is_day_of_month_restricted: bool,
+    is_day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> CustomResult<Self, errors::ProcessTrackerError> {
+        let fields = expression.split_whitespace().collect::<Vec<_>>();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields.as_slice() else {
+            return Err(report!(errors::ProcessTrackerError::InvalidCronExpression)
+                .attach_printable(format!(
+                    "Expected 5 fields in the cron expression `{expression}`, found {}",
+                    fields.len()
+                )));
+        };
+
+        // Sunday is both 0 and 7
+        let mut days_of_week_mask = parse_field(days_of_week, "day of week", 0, 7)?;
+        if days_of_week_mask & (1 << 7) != 0 {
+            days_of_week_mask = (days_of_week_mask & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            minutes: parse_field(minutes, "minute", 0, 59)?,
+            hours: parse_field(hours, "hour", 0, 23)?,
+            days_of_month: parse_field(days_of_month, "day of month", 1, 31)?,
+            months: parse_field(months, "month", 1, 12)?,
+            days_of_week: days_of_week_mask,
+            is_day_of_month_restricted: !days_of_month.starts_with('*'),
+            is_day_of_week_restricted: !days_of_week.starts_with('*'),
+        })
+    }
+
+    /// The first occurrence strictly after the given time, if there is one within the lookahead
+    pub fn next_after(&self, time: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        let start = time
+            .replace_time(Time::from_hms(time.hour(), time.minute(), 0).ok()?)
+            .checked_add(time::Duration::minutes(1))?;
+
+        let mut date = start.date();
+        for day in 0..MAX_LOOKAHEAD_DAYS {
+            if self.matches_date(date) {
+                let (hour, minute) = if day == 0 {
+                    (start.hour(), start.minute())
+                } else {
+                    (0, 0)
+                };
+                if let Some(time) = self.first_time_from(hour, minute) {
+                    return Some(PrimitiveDateTime::new(date, time));
+                }
+            }
+            date = date.next_day()?;
+        }
+
+        None
+    }
+
+    fn matches_date(&self, date: Date) -> bool {
+        let is_month = contains(self.months, u8::from(date.month()));
+        let is_day_of_month = contains(self.days_of_month, date.day());
+        let is_day_of_week = contains(self.days_of_week, date.weekday().number_days_from_sunday());
+
+        let is_day = if self.is_day_of_month_restricted && self.is_day_of_week_restricted {
+            is_day_of_month || is_day_of_week
+        } else {
+            is_day_of_month && is_day_of_week
+        };
+
+        is_month && is_day
+    }
+
+    fn first_time_from(&self, hour: u8, minute: u8) -> Option<Time> {
+        (hour..24)
+            .filter(|&candidate| contains(self.hours, candidate))
+            .find_map(|candidate| {
+                let first_minute = if candidate == hour { minute } else { 0 };
+                (first_minute..60)
+                    .find(|&candidate_minute| contains(self.minutes, candidate_minute))
+                    .and_then(|minute| Time::from_hms(candidate, minute, 0).ok())
+            })
+    }
+}
+
+fn contains(mask: u64, value: u8) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn parse_field(
+    field: &str,
+    name: &str,
+    min: u8,
+    max: u8,
+) -> CustomResult<u64, errors::ProcessTrackerError> {
+    let invalid_field = || {
+        report!(errors::ProcessTrackerError::InvalidCronExpression).attach_printable(format!(
+            "Invalid value `{field}` for the {name} field of the cron expression"
+        ))
+    };
+    let parse_value = |value: &str| value.parse::<u8>().map_err(|_| invalid_field());
+
+    let mut mask = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, Some(parse_value(step)?)),
+            None => (part, None),
+        };
+        let (start, end) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((start, end)) => (parse_value(start)?, parse_value(end)?),
+            // A value with a step runs up to the maximum, as in `5/15`
+            None => {
+                let value = parse_value(range)?;
+                (value, step.map_or(value, |_| max))
+            }
+        };
+        let step = step.unwrap_or(1);
+
+        if step == 0 || start < min || end > max || start > end {
+            return Err(invalid_field());
+        }
+        for value in (start..=end).step_by(usize::from(step)) {
+            mask |= 1 << value;
+        }
+    }
+
+    Ok(mask)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_next_after() {
+        let cases = [
+            (
+                "0 2 * * *",
+                datetime!(2024-10-17 01:59:59),
+                datetime!(2024-10-17 02:00),
+            ),
+            (
+                "0 2 * * *",
+                datetime!(2024-10-17 02:00),
+                datetime!(2024-10-18 02:00),
+            ),
+            (
+                "*/15 * * * *",
+                datetime!(2024-10-17 10:07),
+                datetime!(2024-10-17 10:15),
+            ),
+            (
+                "30 9 * * 1-5",
+                datetime!(2024-10-18 10:00),
+                datetime!(2024-10-21 09:30),
+            ),
+            (
+                "0 0 1 */3 *",
+                datetime!(2024-10-17 00:00),
+                datetime!(2025-01-01 00:00),
+            ),
+            (
+                "0 0 29 2 *",
+                datetime!(2024-03-01 00:00),
+                datetime!(2028-02-29 00:00),
+            ),
+            (
+                "0 12 13 * 5",
+                datetime!(2024-10-17 00:00),
+                datetime!(2024-10-18 12:00),
+            ),
+            (
+                "0 0 * * 7",
+                datetime!(2024-10-17 00:00),
+                datetime!(2024-10-20 00:00),
+            ),
+        ];
+
+        for (expression, time, expected) in cases {
+            let schedule = CronSchedule::parse(expression).unwrap();
+            assert_eq!(
+                schedule.next_after(time),
+                Some(expected),
+                "Unexpected next occurrence of `{expression}` after {time}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        for expression in [
+            "0 2 * *",
+            "60 * * * *",
+            "* * 0 * *",
+            "*/0 * * * *",
+            "5-1 * * * *",
+        ] {
+            assert!(
+                CronSchedule::parse(expression).is_err(),
+                "`{expression}` should not be parsed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_occurrence() {
+        let schedule = CronSchedule::parse("0 0 30 2 *").unwrap();
+        assert_eq!(schedule.next_after(datetime!(2024-10-17 00:00)), None);
+    }
+}
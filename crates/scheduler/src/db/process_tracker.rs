@@ -5,7 +5,7 @@ use error_stack::{report, ResultExt};
 use storage_impl::{connection, errors, mock_db::MockDb};
 use time::PrimitiveDateTime;
 
-use crate::{metrics, scheduler::Store};
+use crate::{env::logger, metrics, recurring, scheduler::Store};
 
 #[async_trait::async_trait]
 pub trait ProcessTrackerInterface: Send + Sync + 'static {
@@ -62,6 +62,11 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_unfinished_recurring_process_by_name(
+        &self,
+        name: &str,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -106,6 +111,16 @@ impl ProcessTrackerInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn find_unfinished_recurring_process_by_name(
+        &self,
+        name: &str,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_unfinished_recurring_process_by_name(&conn, name)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -168,15 +183,39 @@ impl ProcessTrackerInterface for Store {
         this: storage::ProcessTracker,
         business_status: &'static str,
     ) -> CustomResult<(), errors::StorageError> {
-        self.update_process(
-            this,
-            storage::ProcessTrackerUpdate::StatusUpdate {
-                status: storage_enums::ProcessTrackerStatus::Finish,
-                business_status: Some(String::from(business_status)),
-            },
-        )
-        .await
-        .attach_printable("Failed to update business status of process")?;
+        let update = storage::ProcessTrackerUpdate::StatusUpdate {
+            status: storage_enums::ProcessTrackerStatus::Finish,
+            business_status: Some(String::from(business_status)),
+        };
+
+        // A recurring task which cannot be rescheduled is still finished, the producer seeds it
+        // again if it is a registered job
+        let next_occurrence = recurring::next_occurrence(&this, common_utils::date_time::now())
+            .inspect_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to compute the next occurrence of the process"
+                );
+            })
+            .ok()
+            .flatten();
+
+        match next_occurrence {
+            Some(next_occurrence) => {
+                let conn = connection::pg_connection_write(self).await?;
+                this.update_and_insert_next_occurrence(&conn, update, next_occurrence)
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))
+                    .attach_printable(
+                        "Failed to finish process and schedule its next occurrence",
+                    )?;
+            }
+            None => {
+                self.update_process(this, update)
+                    .await
+                    .attach_printable("Failed to update business status of process")?;
+            }
+        }
         metrics::TASK_FINISHED.add(&metrics::CONTEXT, 1, &[]);
         Ok(())
     }
@@ -230,6 +269,25 @@ impl ProcessTrackerInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_unfinished_recurring_process_by_name(
+        &self,
+        name: &str,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        let optional = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .find(|process| {
+                process.name.as_deref() == Some(name)
+                    && process.cron.is_some()
+                    && process.status != storage_enums::ProcessTrackerStatus::Finish
+            })
+            .cloned();
+
+        Ok(optional)
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -249,6 +307,8 @@ impl ProcessTrackerInterface for MockDb {
             event: new.event,
             created_at: new.created_at,
             updated_at: new.updated_at,
+            cron: new.cron,
+            catch_up_policy: new.catch_up_policy,
         };
         processes.push(process.clone());
         Ok(process)
@@ -61,6 +61,8 @@ pub enum ProcessTrackerError {
     TypeConversionError,
     #[error("Tenant not found")]
     TenantNotFound,
+    #[error("Invalid cron expression")]
+    InvalidCronExpression,
 }
 
 #[macro_export]
@@ -1,11 +1,13 @@
 pub mod configs;
 pub mod consumer;
+pub mod cron;
 pub mod db;
 pub mod env;
 pub mod errors;
 pub mod flow;
 pub mod metrics;
 pub mod producer;
+pub mod recurring;
 pub mod scheduler;
 pub mod settings;
 pub mod utils;
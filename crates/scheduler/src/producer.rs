@@ -15,7 +15,7 @@ use super::{
     metrics,
 };
 use crate::{
-    configs::settings::SchedulerSettings, errors, flow::SchedulerFlow,
+    configs::settings::SchedulerSettings, errors, flow::SchedulerFlow, recurring,
     scheduler::SchedulerInterface, utils::*, SchedulerAppState, SchedulerSessionState,
 };
 
@@ -107,6 +107,8 @@ where
     T: SchedulerSessionState,
 {
     lock_acquire_release::<_, _, _>(state.get_db().as_scheduler(), settings, move || async {
+        recurring::seed_recurring_jobs(state.get_db().as_scheduler(), settings).await;
+
         let tasks = fetch_producer_tasks(state.get_db().as_scheduler(), settings).await?;
         debug!("Producer count of tasks {}", tasks.len());
 
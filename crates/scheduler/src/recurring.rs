@@ -0,0 +1,256 @@
+//! Recurring tasks
+//!
+//! A task recurs when its process tracker entry has a cron expression. Once an occurrence of the
+//! task is finished, whether it succeeded or failed, the next occurrence is inserted in the same
+//! transaction, so that a failure in between never breaks or duplicates the chain of occurrences.
+//! The workflows of recurring tasks hence finish their processes as usual, without rescheduling
+//! them.
+//!
+//! An occurrence is missed when the occurrence after it is already due by the time it is picked up,
+//! such as after the scheduler was down. The catch-up policy of the task decides whether a missed
+//! occurrence runs, and whether the occurrences missed in the meantime are run one after the other.
+//!
+//! The recurring jobs registered in the scheduler configuration are seeded by the producer, which
+//! inserts the next occurrence of every job without an unfinished occurrence, such as jobs whose
+//! next occurrence could not be computed.
+
+use common_utils::errors::CustomResult;
+use diesel_models::{
+    enums,
+    process_tracker::{business_status, CatchUpPolicy, ProcessTracker, ProcessTrackerNew},
+};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::SchedulerSettings, cron::CronSchedule, env::logger, errors,
+    SchedulerInterface,
+};
+
+/// Identifier of the occurrence of a recurring task, made up of the identifier of the task and the
+/// time of the occurrence
+pub fn occurrence_id(task_id: &str, schedule_time: PrimitiveDateTime) -> String {
+    format!("{task_id}_{}", schedule_time.assume_utc().unix_timestamp())
+}
+
+fn task_id(process: &ProcessTracker) -> &str {
+    process
+        .schedule_time
+        .map(|schedule_time| occurrence_id("", schedule_time))
+        .and_then(|suffix| process.id.strip_suffix(suffix.as_str()))
+        .unwrap_or(&process.id)
+}
+
+fn catch_up_policy(process: &ProcessTracker) -> CatchUpPolicy {
+    process
+        .catch_up_policy
+        .as_deref()
+        .and_then(|policy| policy.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Whether the occurrence of the recurring task is missed and is not to be run as per the
+/// catch-up policy of the task
+pub fn is_skipped_occurrence(process: &ProcessTracker, now: PrimitiveDateTime) -> bool {
+    let (Some(cron), Some(schedule_time)) = (process.cron.as_deref(), process.schedule_time) else {
+        return false;
+    };
+    if catch_up_policy(process) != CatchUpPolicy::Skip {
+        return false;
+    }
+
+    CronSchedule::parse(cron)
+        .inspect_err(|error| logger::error!(?error, pt.id = %process.id, "Invalid cron expression"))
+        .ok()
+        .and_then(|schedule| schedule.next_after(schedule_time))
+        .is_some_and(|following_occurrence| following_occurrence <= now)
+}
+
+/// The next occurrence of the recurring task, to be inserted when the process is finished. The
+/// next occurrence is scheduled whatever the business status of the process, so that a failed
+/// occurrence does not end the recurrence. Returns `None` for tasks which do not recur.
+pub fn next_occurrence(
+    process: &ProcessTracker,
+    now: PrimitiveDateTime,
+) -> CustomResult<Option<ProcessTrackerNew>, errors::ProcessTrackerError> {
+    let Some(cron) = process.cron.as_deref() else {
+        return Ok(None);
+    };
+
+    let policy = catch_up_policy(process);
+    let after = match (policy, process.schedule_time) {
+        (CatchUpPolicy::RunAll, Some(schedule_time)) => schedule_time,
+        (_, schedule_time) => schedule_time.map_or(now, |schedule_time| schedule_time.max(now)),
+    };
+    let Some(schedule_time) = CronSchedule::parse(cron)?.next_after(after) else {
+        logger::warn!(pt.id = %process.id, %cron, "Recurring task has no further occurrence");
+        return Ok(None);
+    };
+
+    Ok(Some(ProcessTrackerNew {
+        id: occurrence_id(task_id(process), schedule_time),
+        name: process.name.clone(),
+        tag: process.tag.clone(),
+        runner: process.runner.clone(),
+        retry_count: 0,
+        schedule_time: Some(schedule_time),
+        rule: process.rule.clone(),
+        tracking_data: process.tracking_data.clone(),
+        business_status: String::from(business_status::PENDING),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: now,
+        updated_at: now,
+        cron: Some(cron.to_owned()),
+        catch_up_policy: Some(policy.to_string()),
+    }))
+}
+
+/// Inserts the next occurrence of the recurring jobs of the configuration which have no unfinished
+/// occurrence
+pub async fn seed_recurring_jobs(db: &dyn SchedulerInterface, settings: &SchedulerSettings) {
+    let now = common_utils::date_time::now();
+
+    for (name, job) in settings
+        .recurring_jobs
+        .iter()
+        .filter(|(_, job)| !job.disabled)
+    {
+        let result = async {
+            let unfinished_occurrence = db
+                .find_unfinished_recurring_process_by_name(name)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?;
+            if unfinished_occurrence.is_some() {
+                return Ok(());
+            }
+
+            let Some(schedule_time) = CronSchedule::parse(&job.cron)?.next_after(now) else {
+                logger::warn!(job = %name, cron = %job.cron, "Recurring job has no occurrence");
+                return Ok(());
+            };
+            let process = ProcessTrackerNew::new(
+                occurrence_id(name, schedule_time),
+                name,
+                job.runner,
+                job.tag.clone(),
+                job.tracking_data.clone(),
+                schedule_time,
+            )
+            .change_context(errors::ProcessTrackerError::SerializationFailed)?
+            .with_cron(job.cron.clone(), job.catch_up_policy);
+
+            db.insert_process(process)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+            logger::info!(job = %name, %schedule_time, "Scheduled recurring job");
+
+            Ok::<_, error_stack::Report<errors::ProcessTrackerError>>(())
+        }
+        .await;
+
+        if let Err(error) = result {
+            logger::error!(?error, job = %name, "Failed to seed recurring job");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn recurring_process(
+        catch_up_policy: CatchUpPolicy,
+        schedule_time: PrimitiveDateTime,
+    ) -> ProcessTracker {
+        let new = ProcessTrackerNew::new(
+            occurrence_id("SETTLEMENT_REPORT", schedule_time),
+            "SETTLEMENT_REPORT",
+            diesel_models::process_tracker::ProcessTrackerRunner::ApiKeyExpiryWorkflow,
+            ["RECURRING"],
+            serde_json::Value::Null,
+            schedule_time,
+        )
+        .unwrap()
+        .with_cron("0 2 * * *", catch_up_policy);
+
+        ProcessTracker {
+            id: new.id,
+            name: new.name,
+            tag: new.tag,
+            runner: new.runner,
+            retry_count: new.retry_count,
+            schedule_time: new.schedule_time,
+            rule: new.rule,
+            tracking_data: new.tracking_data,
+            business_status: new.business_status,
+            status: new.status,
+            event: new.event,
+            created_at: new.created_at,
+            updated_at: new.updated_at,
+            cron: new.cron,
+            catch_up_policy: new.catch_up_policy,
+        }
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let schedule_time = datetime!(2024-10-14 02:00);
+        let now = datetime!(2024-10-17 10:00);
+        let cases = [
+            (CatchUpPolicy::RunAll, datetime!(2024-10-15 02:00)),
+            (CatchUpPolicy::RunOnce, datetime!(2024-10-18 02:00)),
+            (CatchUpPolicy::Skip, datetime!(2024-10-18 02:00)),
+        ];
+
+        for (policy, expected) in cases {
+            let process = recurring_process(policy, schedule_time);
+            let next = next_occurrence(&process, now).unwrap().unwrap();
+
+            assert_eq!(next.schedule_time, Some(expected), "{policy}");
+            assert_eq!(next.id, occurrence_id("SETTLEMENT_REPORT", expected));
+        }
+    }
+
+    #[test]
+    fn test_next_occurrence_after_failure() {
+        let mut process = recurring_process(CatchUpPolicy::RunOnce, datetime!(2024-10-17 02:00));
+        process.business_status = String::from(business_status::FAILURE);
+        let next = next_occurrence(&process, datetime!(2024-10-17 02:01))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(next.schedule_time, Some(datetime!(2024-10-18 02:00)));
+        assert_eq!(next.business_status, business_status::PENDING);
+        assert_eq!(next.retry_count, 0);
+    }
+
+    #[test]
+    fn test_no_next_occurrence_without_cron() {
+        let mut process = recurring_process(CatchUpPolicy::RunOnce, datetime!(2024-10-17 02:00));
+        process.cron = None;
+
+        assert!(next_occurrence(&process, datetime!(2024-10-17 02:01))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_skipped_occurrence() {
+        let process = recurring_process(CatchUpPolicy::Skip, datetime!(2024-10-14 02:00));
+        assert!(is_skipped_occurrence(&process, datetime!(2024-10-17 10:00)));
+        assert!(!is_skipped_occurrence(
+            &process,
+            datetime!(2024-10-14 02:05)
+        ));
+
+        let process = recurring_process(CatchUpPolicy::RunOnce, datetime!(2024-10-14 02:00));
+        assert!(!is_skipped_occurrence(
+            &process,
+            datetime!(2024-10-17 10:00)
+        ));
+    }
+}
@@ -354,6 +354,42 @@ pub fn get_outgoing_webhook_retry_schedule_time(
     }
 }
 
+pub fn get_subscription_billing_retry_schedule_time(
+    mapping: process_data::SubscriptionBillingRetryProcessTrackerMapping,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_count: i32,
+) -> Option<i32> {
+    let retry_mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
+        Some(map) => map.clone(),
+        None => mapping.default_mapping,
+    };
+
+    // For first retry, get the `start_after` time
+    if retry_count == 0 {
+        Some(retry_mapping.start_after)
+    } else {
+        get_delay(retry_count, &retry_mapping.frequencies)
+    }
+}
+
+pub fn get_dispute_sync_schedule_time(
+    mapping: process_data::DisputeSyncProcessTrackerMapping,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_count: i32,
+) -> Option<i32> {
+    let retry_mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
+        Some(map) => map.clone(),
+        None => mapping.default_mapping,
+    };
+
+    // For the first sync, get the `start_after` time
+    if retry_count == 0 {
+        Some(retry_mapping.start_after)
+    } else {
+        get_delay(retry_count, &retry_mapping.frequencies)
+    }
+}
+
 /// Get the delay based on the retry count
 pub fn get_delay<'a>(
     retry_count: i32,
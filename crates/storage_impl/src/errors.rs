@@ -133,9 +133,9 @@ pub trait RedisErrorExt {
 impl RedisErrorExt for error_stack::Report<RedisError> {
     fn to_redis_failed_response(self, key: &str) -> error_stack::Report<DataStorageError> {
         match self.current_context() {
-            RedisError::NotFound => self.change_context(DataStorageError::ValueNotFound(format!(
-                "Data does not exist for key {key}",
-            ))),
+            RedisError::NotFound | RedisError::ValueDeleted => self.change_context(
+                DataStorageError::ValueNotFound(format!("Data does not exist for key {key}",)),
+            ),
             RedisError::SetNxFailed | RedisError::SetAddMembersFailed => {
                 self.change_context(DataStorageError::DuplicateValue {
                     entity: "redis",
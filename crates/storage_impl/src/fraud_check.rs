@@ -0,0 +1,5 @@
+use diesel_models::fraud_check::FraudCheck;
+
+use crate::redis::kv_store::KvStorePartition;
+
+impl KvStorePartition for FraudCheck {}
@@ -6,11 +6,13 @@ use hyperswitch_domain_models::errors::{StorageError, StorageResult};
 use masking::StrongSecret;
 use redis::{kv_store::RedisConnInterface, pub_sub::PubSubInterface, RedisStore};
 mod address;
+mod capture;
 pub mod config;
 pub mod connection;
 pub mod customers;
 pub mod database;
 pub mod errors;
+mod fraud_check;
 mod lookup;
 pub mod mandate;
 pub mod metrics;
@@ -238,6 +240,12 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         format!("{{{}}}_{}", shard_key, self.drainer_stream_name)
     }
 
+    /// Applies the soft kill mode to the operations performed through this store, regardless of
+    /// the configuration of the application
+    pub fn enable_soft_kill_mode(&mut self) {
+        self.soft_kill_mode = true;
+    }
+
     pub async fn push_to_drainer_stream<R>(
         &self,
         redis_entry: diesel_models::kv::TypedSql,
@@ -389,6 +397,32 @@ impl UniqueConstraints for diesel_models::Refund {
     }
 }
 
+impl UniqueConstraints for diesel_models::capture::Capture {
+    fn unique_constraints(&self) -> Vec<String> {
+        vec![format!(
+            "capture_{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.capture_id
+        )]
+    }
+    fn table_name(&self) -> &str {
+        "Capture"
+    }
+}
+
+impl UniqueConstraints for diesel_models::fraud_check::FraudCheck {
+    fn unique_constraints(&self) -> Vec<String> {
+        vec![format!(
+            "frm_{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.payment_id.get_string_repr()
+        )]
+    }
+    fn table_name(&self) -> &str {
+        "FraudCheck"
+    }
+}
+
 impl UniqueConstraints for diesel_models::ReverseLookup {
     fn unique_constraints(&self) -> Vec<String> {
         vec![format!("reverselookup_{}", self.lookup_id)]
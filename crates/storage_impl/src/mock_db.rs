@@ -38,6 +38,7 @@ pub struct MockDb {
     pub ephemeral_keys: Arc<Mutex<Vec<store::EphemeralKey>>>,
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
+    pub incoming_webhook_events: Arc<Mutex<Vec<store::IncomingWebhookEvent>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
@@ -84,6 +85,7 @@ impl MockDb {
             ephemeral_keys: Default::default(),
             cards_info: Default::default(),
             events: Default::default(),
+            incoming_webhook_events: Default::default(),
             disputes: Default::default(),
             lockers: Default::default(),
             mandates: Default::default(),
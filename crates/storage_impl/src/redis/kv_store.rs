@@ -1,8 +1,8 @@
-use std::{fmt::Debug, sync::Arc};
+use std::{collections::HashSet, fmt::Debug, sync::Arc};
 
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, ext_traits::ByteSliceExt};
 use diesel_models::enums::MerchantStorageScheme;
-use error_stack::report;
+use error_stack::{report, ResultExt};
 use redis_interface::errors::RedisError;
 use router_derive::TryGetEnumVariant;
 use router_env::logger;
@@ -119,6 +119,64 @@ pub trait RedisConnInterface {
     ) -> error_stack::Result<Arc<redis_interface::RedisConnectionPool>, RedisError>;
 }
 
+/// The value left in place of a field deleted in Redis until the deletion has been drained to
+/// the database, so that reads of the field do not fall back to the row still in the database.
+/// The tombstone keeps the unique key of the deleted row, so that scans merged with the rows of
+/// the database can leave out the row still in the database as well.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Tombstone {
+    #[serde(rename = "__deleted__")]
+    pub unique_key: String,
+}
+
+impl Tombstone {
+    pub fn new<S: UniqueConstraints>(deleted: &S) -> Self {
+        Self {
+            unique_key: deleted.unique_constraints().concat(),
+        }
+    }
+
+    /// Parses the value of a field, returning `None` when the field holds a row
+    pub fn from_value(value: &[u8]) -> Option<Self> {
+        serde_json::from_slice(value).ok()
+    }
+}
+
+/// Deserializes the value of a field, tombstones reading as deleted values
+fn parse_hash_field<T: de::DeserializeOwned>(
+    value: &[u8],
+    type_name: &'static str,
+) -> CustomResult<T, RedisError> {
+    if value.is_empty() {
+        Err(report!(RedisError::NotFound))
+    } else if Tombstone::from_value(value).is_some() {
+        Err(report!(RedisError::ValueDeleted))
+    } else {
+        value
+            .parse_struct(type_name)
+            .change_context(RedisError::JsonDeserializationFailed)
+    }
+}
+
+/// Deserializes the rows held by scanned fields, skipping tombstones
+fn parse_scanned_rows<T: de::DeserializeOwned>(values: &[String]) -> Vec<T> {
+    values
+        .iter()
+        .filter(|value| Tombstone::from_value(value.as_bytes()).is_none())
+        .filter_map(|value| serde_json::from_str(value).ok())
+        .collect()
+}
+
+/// Returns the unique keys of the rows deleted in Redis among the scanned fields
+fn get_deleted_unique_keys(values: &[String]) -> HashSet<String> {
+    values
+        .iter()
+        .filter_map(|value| Tombstone::from_value(value.as_bytes()))
+        .map(|tombstone| tombstone.unique_key)
+        .collect()
+}
+
 /// An enum to represent what operation to do on
 pub enum KvOperation<'a, S: serde::Serialize + Debug> {
     Hset((&'a str, String), TypedSql),
@@ -127,6 +185,8 @@ pub enum KvOperation<'a, S: serde::Serialize + Debug> {
     HGet(&'a str),
     Get,
     Scan(&'a str),
+    ScanDeleted(&'a str),
+    Delete(&'a str, &'a S, TypedSql),
 }
 
 #[derive(TryGetEnumVariant)]
@@ -138,6 +198,8 @@ pub enum KvResult<T: de::DeserializeOwned> {
     SetNx(redis_interface::SetnxReply),
     HSetNx(redis_interface::HsetnxReply),
     Scan(Vec<T>),
+    ScanDeleted(HashSet<String>),
+    Delete(()),
 }
 
 impl<T> std::fmt::Display for KvOperation<'_, T>
@@ -152,6 +214,8 @@ where
             KvOperation::HGet(_) => f.write_str("Hget"),
             KvOperation::Get => f.write_str("Get"),
             KvOperation::Scan(_) => f.write_str("Scan"),
+            KvOperation::ScanDeleted(_) => f.write_str("ScanDeleted"),
+            KvOperation::Delete(_, _, _) => f.write_str("Delete"),
         }
     }
 }
@@ -192,24 +256,25 @@ where
             }
 
             KvOperation::HGet(field) => {
-                let result = redis_conn
-                    .get_hash_field_and_deserialize(&key, field, type_name)
-                    .await?;
-                Ok(KvResult::HGet(result))
+                let value = redis_conn.get_hash_field::<Vec<u8>>(&key, field).await?;
+
+                parse_hash_field(&value, type_name).map(KvResult::HGet)
             }
 
             KvOperation::Scan(pattern) => {
-                let result: Vec<T> = redis_conn
-                    .hscan_and_deserialize(&key, pattern, None)
-                    .await
-                    .and_then(|result| {
-                        if result.is_empty() {
-                            Err(report!(RedisError::NotFound))
-                        } else {
-                            Ok(result)
-                        }
-                    })?;
-                Ok(KvResult::Scan(result))
+                let values = redis_conn.hscan(&key, pattern, None).await?;
+                let result = parse_scanned_rows::<T>(&values);
+
+                if result.is_empty() {
+                    Err(report!(RedisError::NotFound))
+                } else {
+                    Ok(KvResult::Scan(result))
+                }
+            }
+
+            KvOperation::ScanDeleted(pattern) => {
+                let values = redis_conn.hscan(&key, pattern, None).await?;
+                Ok(KvResult::ScanDeleted(get_deleted_unique_keys(&values)))
             }
 
             KvOperation::HSetNx(field, value, sql) => {
@@ -254,6 +319,22 @@ where
                 let result = redis_conn.get_and_deserialize_key(&key, type_name).await?;
                 Ok(KvResult::Get(result))
             }
+
+            KvOperation::Delete(field, value, sql) => {
+                logger::debug!(kv_operation= %operation, field = field);
+
+                let tombstone = serde_json::to_string(&Tombstone::new(value))
+                    .change_context(RedisError::JsonSerializationFailed)?;
+                redis_conn
+                    .set_hash_fields(&key, (field, tombstone), Some(ttl.into()))
+                    .await?;
+
+                store
+                    .push_to_drainer_stream::<S>(sql, partition_key)
+                    .await?;
+
+                Ok(KvResult::Delete(()))
+            }
         }
     };
 
@@ -277,6 +358,7 @@ pub enum Op<'a> {
     Insert,
     Update(PartitionKey<'a>, &'a str, Option<&'a str>),
     Find,
+    Delete(PartitionKey<'a>, &'a str),
 }
 
 impl<'a> std::fmt::Display for Op<'a> {
@@ -287,6 +369,7 @@ impl<'a> std::fmt::Display for Op<'a> {
             Op::Update(p_key, _, updated_by) => {
                 f.write_str(&format!("update_{} for updated_by_{:?}", p_key, updated_by))
             }
+            Op::Delete(p_key, _) => f.write_str(&format!("delete_{}", p_key)),
         }
     }
 }
@@ -324,6 +407,16 @@ where
             }
 
             Op::Update(_, _, None) => MerchantStorageScheme::PostgresOnly,
+            // Rows still in Redis are deleted through Redis, so that the deletion is drained
+            // after the writes preceding it
+            Op::Delete(partition_key, field) => {
+                match kv_wrapper::<D, _, _>(store, KvOperation::<D>::HGet(field), partition_key)
+                    .await
+                {
+                    Ok(_) => MerchantStorageScheme::RedisKv,
+                    Err(_) => MerchantStorageScheme::PostgresOnly,
+                }
+            }
         };
 
         let type_name = std::any::type_name::<D>();
@@ -334,3 +427,65 @@ where
         storage_scheme
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Row {
+        id: String,
+    }
+
+    #[async_trait::async_trait]
+    impl UniqueConstraints for Row {
+        fn unique_constraints(&self) -> Vec<String> {
+            vec![format!("row_{}", self.id)]
+        }
+        fn table_name(&self) -> &str {
+            "Row"
+        }
+    }
+
+    fn row(id: &str) -> Row {
+        Row { id: id.to_string() }
+    }
+
+    fn tombstone(id: &str) -> String {
+        serde_json::to_string(&Tombstone::new(&row(id))).unwrap()
+    }
+
+    #[test]
+    fn test_tombstoned_field_reads_as_deleted() {
+        let result = parse_hash_field::<Row>(tombstone("1").as_bytes(), "Row");
+        assert_eq!(
+            *result.unwrap_err().current_context(),
+            RedisError::ValueDeleted
+        );
+
+        let result = parse_hash_field::<Row>(&[], "Row");
+        assert_eq!(*result.unwrap_err().current_context(), RedisError::NotFound);
+
+        let value = serde_json::to_string(&row("1")).unwrap();
+        assert_eq!(
+            parse_hash_field::<Row>(value.as_bytes(), "Row").unwrap(),
+            row("1")
+        );
+    }
+
+    #[test]
+    fn test_scans_skip_tombstones() {
+        let values = vec![
+            serde_json::to_string(&row("1")).unwrap(),
+            tombstone("2"),
+            serde_json::to_string(&row("3")).unwrap(),
+        ];
+
+        assert_eq!(parse_scanned_rows::<Row>(&values), vec![row("1"), row("3")]);
+        assert_eq!(
+            get_deleted_unique_keys(&values),
+            HashSet::from(["row_2".to_string()])
+        );
+    }
+}
@@ -142,5 +142,11 @@ export function setup_merchant_apikey() {
     }
     let uma_res = http.post(`http://router-server:8080/accounts/${merchant_id}`, JSON.stringify(update_merchant_account_payload), params);
 
+    // Enable KV for the merchant, so that the writes are drained to the database by the drainer
+    if (__ENV.LOADTEST_KV_ENABLED === "true") {
+        let kv_res = http.post(`http://router-server:8080/accounts/${merchant_id}/kv`, JSON.stringify({ "kv_enabled": true }), params);
+        check(kv_res, { "kv enabled": (r) => r.status === 200 });
+    }
+
     return { "api_key": api_key }
 }
@@ -20,14 +20,15 @@ exit_if_baseline_file_not_exist() {
   fi
 }
 
-while getopts r:s:ca flag
+while getopts r:s:cak flag
 do
   case "${flag}" in
     r) run_name=${OPTARG};;
     s) script=${OPTARG};;
     c) compare=true;;
     a) all_script=true;;
-    *) echo "usage: $0 [-r] [-c] [-s]" >&2
+    k) kv_enabled=true;;
+    *) echo "usage: $0 [-r] [-c] [-s] [-a] [-k]" >&2
        exit 1;;
   esac
 done
@@ -68,6 +69,7 @@ else
 fi
 
 export LOADTEST_RUN_NAME=$run_name
+export LOADTEST_KV_ENABLED=${kv_enabled:-false}
 
 if [ "$all_script" = true ]
 then
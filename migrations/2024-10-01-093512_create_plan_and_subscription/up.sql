@@ -0,0 +1,53 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS plan (
+    plan_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description VARCHAR(255),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval VARCHAR(32) NOT NULL,
+    billing_interval_count INTEGER NOT NULL DEFAULT 1,
+    trial_period_days INTEGER,
+    metadata JSONB,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS plan_merchant_id_plan_id_index ON plan (merchant_id, plan_id);
+
+CREATE TABLE IF NOT EXISTS subscription (
+    subscription_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    plan_id VARCHAR(64) NOT NULL,
+    mandate_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    current_period_start TIMESTAMP NOT NULL,
+    current_period_end TIMESTAMP NOT NULL,
+    trial_end TIMESTAMP,
+    cancel_at_period_end BOOLEAN NOT NULL DEFAULT FALSE,
+    cancelled_at TIMESTAMP,
+    failed_payment_count INTEGER NOT NULL DEFAULT 0,
+    latest_payment_id VARCHAR(64),
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS subscription_merchant_id_subscription_id_index ON subscription (merchant_id, subscription_id);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_customer_id_index ON subscription (merchant_id, customer_id);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_latest_payment_id_index ON subscription (merchant_id, latest_payment_id);
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'subscriptions';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'subscription_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_created';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_renewed';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_payment_failed';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_cancelled';
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE events ALTER COLUMN idempotent_event_id TYPE VARCHAR(64);
+
+ALTER TABLE events DROP COLUMN IF EXISTS webhook_endpoint_id;
+
+DROP TABLE IF EXISTS webhook_endpoint;
@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS webhook_endpoint (
+    endpoint_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    url TEXT NOT NULL,
+    description VARCHAR(255),
+    secret VARCHAR(255) NOT NULL,
+    enabled_events "EventType"[] NOT NULL,
+    is_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS webhook_endpoint_merchant_id_profile_id_index ON webhook_endpoint (merchant_id, profile_id);
+
+ALTER TABLE events ADD COLUMN IF NOT EXISTS webhook_endpoint_id VARCHAR(64) DEFAULT NULL;
+
+-- The idempotent event ID of deliveries to webhook endpoints includes the endpoint ID
+ALTER TABLE events ALTER COLUMN idempotent_event_id TYPE VARCHAR(255);
@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS incoming_webhook_event (
+    webhook_event_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector_name_or_mca_id VARCHAR(64) NOT NULL,
+    request_details BYTEA NOT NULL,
+    request_body BYTEA NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    webhook_effect JSONB,
+    error_message TEXT,
+    replay_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS incoming_webhook_event_merchant_id_created_at_index ON incoming_webhook_event (merchant_id, created_at);
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS fx_rate_snapshot (
+    snapshot_id VARCHAR(64) PRIMARY KEY,
+    provider VARCHAR(64) NOT NULL,
+    base_currency "Currency" NOT NULL,
+    rates JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
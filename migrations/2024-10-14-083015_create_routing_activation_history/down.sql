@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS routing_activation_history_profile_id_algorithm_for_index;
+
+DROP TABLE IF EXISTS routing_activation_history;
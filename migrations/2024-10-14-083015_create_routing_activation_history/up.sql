@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS routing_activation_history (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    algorithm_id VARCHAR(64),
+    previous_algorithm_id VARCHAR(64),
+    algorithm_for "TransactionType" NOT NULL,
+    activation_type VARCHAR(32) NOT NULL,
+    activated_by VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS routing_activation_history_profile_id_algorithm_for_index ON routing_activation_history (profile_id, algorithm_for, created_at DESC);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS connector_fee_schedule_profile_id_index;
+
+DROP TABLE IF EXISTS connector_fee_schedule;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE process_tracker
+DROP COLUMN IF EXISTS cron,
+DROP COLUMN IF EXISTS catch_up_policy;
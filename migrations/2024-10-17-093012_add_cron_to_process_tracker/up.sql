@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE process_tracker
+ADD COLUMN IF NOT EXISTS cron VARCHAR(255),
+ADD COLUMN IF NOT EXISTS catch_up_policy VARCHAR(32);
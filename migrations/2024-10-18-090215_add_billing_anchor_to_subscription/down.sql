@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE subscription
+DROP COLUMN IF EXISTS billing_anchor,
+DROP COLUMN IF EXISTS billed_period_count;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE subscription
+ADD COLUMN IF NOT EXISTS billing_anchor TIMESTAMP,
+ADD COLUMN IF NOT EXISTS billed_period_count INTEGER NOT NULL DEFAULT 0;
+
+UPDATE subscription SET billing_anchor = current_period_end WHERE billing_anchor IS NULL;
+
+ALTER TABLE subscription ALTER COLUMN billing_anchor SET NOT NULL;